@@ -2,7 +2,7 @@ use crate::{Autodiff, checkpoint::strategy::CheckpointStrategy, tensor::Autodiff
 use alloc::vec::Vec;
 
 use burn_tensor::{
-    Device, Distribution, Shape, TensorData,
+    Device, Distribution, IndexReduceMode, Shape, TensorData,
     backend::Backend,
     ops::{BoolTensor, IntTensor, IntTensorOps},
 };
@@ -189,6 +189,16 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_scatter(dim, tensor, indices, value)
     }
 
+    fn int_index_reduce(
+        dim: usize,
+        tensor: IntTensor<B>,
+        indices: IntTensor<B>,
+        value: IntTensor<B>,
+        mode: IndexReduceMode,
+    ) -> IntTensor<B> {
+        B::int_index_reduce(dim, tensor, indices, value, mode)
+    }
+
     fn int_select(tensor: IntTensor<B>, dim: usize, indices: IntTensor<B>) -> IntTensor<B> {
         B::int_select(tensor, dim, indices)
     }
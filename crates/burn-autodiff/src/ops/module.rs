@@ -54,6 +54,57 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate embedding backward.");
     }
 
+    fn cross_entropy_with_label_smoothing(
+        logits: AutodiffTensor<B>,
+        targets: IntTensor<B>,
+        smoothing: f64,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct CrossEntropyWithLabelSmoothing;
+
+        impl<B: Backend> Backward<B, 1> for CrossEntropyWithLabelSmoothing {
+            type State = (B::FloatTensorPrimitive, IntTensor<B>, f64);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (logits, targets, smoothing) = ops.state;
+
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::cross_entropy_with_label_smoothing_backward(logits, targets, smoothing, grad)
+                });
+            }
+        }
+
+        match CrossEntropyWithLabelSmoothing
+            .prepare::<C>([logits.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (logits.primitive.clone(), targets.clone(), smoothing),
+                B::cross_entropy_with_label_smoothing(logits.primitive, targets, smoothing),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::cross_entropy_with_label_smoothing(
+                logits.primitive,
+                targets,
+                smoothing,
+            )),
+        }
+    }
+
+    fn cross_entropy_with_label_smoothing_backward(
+        _logits: AutodiffTensor<B>,
+        _targets: IntTensor<B>,
+        _smoothing: f64,
+        _grad: AutodiffTensor<B>,
+    ) -> AutodiffTensor<B> {
+        panic!("Can't differentiate cross-entropy with label smoothing backward.");
+    }
+
     fn conv1d(
         x: AutodiffTensor<B>,
         weight: AutodiffTensor<B>,
@@ -20,7 +20,7 @@ use crate::{
 };
 
 use burn_tensor::{
-    Device, ElementConversion, Shape, TensorData, TensorMetadata,
+    Device, ElementConversion, IndexReduceMode, Shape, TensorData, TensorMetadata,
     backend::Backend,
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
 };
@@ -995,6 +995,159 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_index_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<B>,
+        value: FloatTensor<Self>,
+        mode: IndexReduceMode,
+    ) -> FloatTensor<Self> {
+        #[derive(Debug)]
+        struct IndexReduce;
+
+        type State<B> = (
+            usize,
+            IntTensor<B>,
+            FloatTensor<B>,
+            FloatTensor<B>,
+            FloatTensor<B>,
+            IndexReduceMode,
+        );
+
+        // The gradient is split evenly between the values equal to the selected extremum.
+        fn extremum_grads<B: Backend>(
+            state: State<B>,
+        ) -> (FloatTensor<B>, FloatTensor<B>, IntTensor<B>) {
+            let (dim, indices, lhs, rhs, output, _mode) = state;
+
+            let output_rhs = B::float_gather(dim, output.clone(), indices.clone());
+            let mask_lhs = B::bool_into_float(B::float_equal(lhs, output));
+            let mask_rhs = B::bool_into_float(B::float_equal(rhs, output_rhs));
+            let count = B::float_scatter(dim, mask_lhs.clone(), indices.clone(), mask_rhs.clone());
+
+            let grad_lhs = B::float_div(mask_lhs, count.clone());
+            let grad_rhs = B::float_div(mask_rhs, B::float_gather(dim, count, indices.clone()));
+
+            (grad_lhs, grad_rhs, indices)
+        }
+
+        // The gradient of each value is the product of all the other values reduced with it,
+        // computed from the product of the non-zero values so that zeros are handled.
+        fn prod_grads<B: Backend>(
+            state: State<B>,
+        ) -> (FloatTensor<B>, FloatTensor<B>, IntTensor<B>) {
+            let (dim, indices, lhs, rhs, _output, _mode) = state;
+
+            let zeros_lhs = B::float_equal_elem(lhs.clone(), 0.elem());
+            let zeros_rhs = B::float_equal_elem(rhs.clone(), 0.elem());
+            let lhs = B::float_mask_fill(lhs, zeros_lhs.clone(), 1.elem());
+            let rhs = B::float_mask_fill(rhs, zeros_rhs.clone(), 1.elem());
+
+            let output = B::float_index_reduce(
+                dim,
+                lhs.clone(),
+                indices.clone(),
+                rhs.clone(),
+                IndexReduceMode::Prod,
+            );
+            let num_zeros = B::float_scatter(
+                dim,
+                B::bool_into_float(zeros_lhs.clone()),
+                indices.clone(),
+                B::bool_into_float(zeros_rhs.clone()),
+            );
+
+            let others = |value, zeros, output: FloatTensor<B>, num_zeros: FloatTensor<B>| {
+                let non_zero = B::float_mask_fill(
+                    B::float_div(output.clone(), value),
+                    B::float_not_equal_elem(num_zeros.clone(), 0.elem()),
+                    0.elem(),
+                );
+                let zero = B::float_mask_fill(
+                    output,
+                    B::float_not_equal_elem(num_zeros, 1.elem()),
+                    0.elem(),
+                );
+                B::float_mask_where(non_zero, zeros, zero)
+            };
+
+            let grad_rhs = others(
+                rhs,
+                zeros_rhs,
+                B::float_gather(dim, output.clone(), indices.clone()),
+                B::float_gather(dim, num_zeros.clone(), indices.clone()),
+            );
+            let grad_lhs = others(lhs, zeros_lhs, output, num_zeros);
+
+            (grad_lhs, grad_rhs, indices)
+        }
+
+        impl<B: Backend> Backward<B, 2> for IndexReduce {
+            type State = State<B>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let dim = ops.state.0;
+                let (grad_lhs, grad_rhs, indices) = match ops.state.5 {
+                    IndexReduceMode::AMax | IndexReduceMode::AMin => extremum_grads::<B>(ops.state),
+                    IndexReduceMode::Prod => prod_grads::<B>(ops.state),
+                    IndexReduceMode::Sum => unreachable!("Sum is computed with scatter"),
+                };
+
+                binary::<B, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| B::float_mul(grad, grad_lhs),
+                    |grad| B::float_mul(B::float_gather(dim, grad, indices), grad_rhs),
+                );
+            }
+        }
+
+        if mode == IndexReduceMode::Sum {
+            return Self::float_scatter(dim, tensor, indices, value);
+        }
+
+        match IndexReduce
+            .prepare::<C>([tensor.node, value.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_index_reduce(
+                    dim,
+                    tensor.primitive.clone(),
+                    indices.clone(),
+                    value.primitive.clone(),
+                    mode,
+                );
+
+                prep.finish(
+                    (
+                        dim,
+                        indices,
+                        tensor.primitive,
+                        value.primitive,
+                        output.clone(),
+                        mode,
+                    ),
+                    output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_index_reduce(
+                dim,
+                tensor.primitive,
+                indices,
+                value.primitive,
+                mode,
+            )),
+        }
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -2116,12 +2269,15 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 self.nodes
                     .into_iter()
                     .zip(self.dim_sizes)
-                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
                     .for_each(|(node, dim_size)| {
-                        let mut ranges = ranges.clone();
-                        ranges[self.dim] = current_index..dim_size + current_index;
+                        let start = current_index;
+                        // The untracked tensors still offset the following ones
                         current_index += dim_size;
-                        grads.register::<B>(node.id, B::float_slice(grad.clone(), &ranges));
+                        if let Some(node) = node {
+                            let mut ranges = ranges.clone();
+                            ranges[self.dim] = start..current_index;
+                            grads.register::<B>(node.id, B::float_slice(grad.clone(), &ranges));
+                        }
                     });
             }
 
@@ -2555,7 +2711,53 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_cast(tensor: FloatTensor<Self>, dtype: burn_tensor::FloatDType) -> FloatTensor<Self> {
-        AutodiffTensor::new(B::float_cast(tensor.primitive, dtype))
+        #[derive(Debug)]
+        struct Cast;
+
+        #[derive(new, Debug)]
+        struct RetroCast<B: Backend> {
+            input_id: NodeID,
+            dtype: burn_tensor::FloatDType,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend> RetroForward for RetroCast<B> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let input = states.get_state::<B::FloatTensorPrimitive>(&self.input_id);
+                let out = B::float_cast(input, self.dtype);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend> Backward<B, 1> for Cast {
+            type State = burn_tensor::FloatDType;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                // The gradient has the dtype of the input
+                unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_cast(grad, ops.state)
+                });
+            }
+        }
+
+        match Cast
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroCast::<B>::new(tensor.node.id, dtype))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                tensor.primitive.dtype().into(),
+                B::float_cast(tensor.primitive, dtype),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cast(tensor.primitive, dtype)),
+        }
     }
 
     // TODO: Implement float_prod and float_sum
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_max_pool2d;
+    use burn_tensor::{Shape, Tensor, Tolerance};
+
+    #[test]
+    fn test_adaptive_max_pool2d_simple() {
+        let test = AdaptiveMaxPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 4,
+            width: 3,
+            output_size_1: 3,
+            output_size_2: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[[0., 0., 0.], [0., 1., 1.], [0., 1., 1.], [0., 1., 1.]]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_shared_max() {
+        let test = AdaptiveMaxPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            height: 2,
+            width: 2,
+            output_size_1: 3,
+            output_size_2: 3,
+        };
+
+        // The bins of the middle row and column overlap, so the maxima are selected by several
+        // output elements
+        test.assert_output(TestTensor::from_floats(
+            [[[[1., 2.], [2., 4.]]]],
+            &Default::default(),
+        ));
+    }
+
+    struct AdaptiveMaxPool2dTestCase {
+        batch_size: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        output_size_1: usize,
+        output_size_2: usize,
+    }
+
+    impl AdaptiveMaxPool2dTestCase {
+        fn assert_output(self, x_grad: TestTensor<4>) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
+            let device = Default::default();
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape::<4, _>(shape_x)
+                    .into_data(),
+                &device,
+            )
+            .require_grad();
+            let output = adaptive_max_pool2d(x.clone(), [self.output_size_1, self.output_size_2]);
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq::<FloatType>(&x_grad_actual.into_data(), Tolerance::default());
+        }
+    }
+}
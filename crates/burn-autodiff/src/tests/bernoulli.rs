@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(ad_bernoulli_logits)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_bernoulli_logits_straight_through() {
+        let device = Default::default();
+        let logits =
+            TestAutodiffTensor::<2>::from_data([[100.0, -100.0], [-100.0, 100.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let samples = logits.clone().bernoulli_logits_straight_through();
+        let output = samples.clone() * weights;
+        let grads = output.sum().backward();
+
+        let grad = logits.grad(&grads).unwrap();
+
+        samples
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 0.0], [0.0, 1.0]]), false);
+        grad.to_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0], [3.0, 4.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_bernoulli_logits_straight_through_infinite_logits() {
+        let device = Default::default();
+        let logits =
+            TestAutodiffTensor::<1>::from_data([f32::INFINITY, f32::NEG_INFINITY, 100.0], &device)
+                .require_grad();
+
+        let samples = logits.clone().bernoulli_logits_straight_through();
+        let grads = samples.clone().sum().backward();
+
+        let grad = logits.grad(&grads).unwrap();
+
+        samples
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 0.0, 1.0]), false);
+        grad.to_data()
+            .assert_eq(&TensorData::from([0.0, 0.0, 1.0]), false);
+    }
+}
@@ -0,0 +1,28 @@
+#[burn_tensor_testgen::testgen(ad_cast)]
+mod tests {
+    use super::*;
+    use burn_tensor::{DType, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_cast() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 7.0], [2.0, 3.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([[4.0, 7.0], [2.0, 3.0]], &device);
+        let dtype = tensor_1.dtype();
+
+        // The product is computed in another precision before being cast back
+        let tensor_3 = tensor_1.clone().cast(DType::F64) * tensor_2.cast(DType::F64);
+        assert_eq!(tensor_3.dtype(), DType::F64);
+        let grads = tensor_3.cast(dtype).sum().backward();
+
+        // The gradient flows back to the input with its dtype
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        assert_eq!(grad_1.dtype(), dtype);
+        grad_1.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([[4.0, 7.0], [2.0, 3.0]]),
+            Tolerance::default(),
+        );
+    }
+}
@@ -2,7 +2,7 @@
 mod tests {
     use super::*;
 
-    use burn_tensor::{Tensor, Tolerance, ops::FloatElem};
+    use burn_tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
 
     #[test]
@@ -81,4 +81,21 @@ mod tests {
         assert_eq!(tensor_1.dims(), grad_1.dims());
         assert_eq!(tensor_2.dims(), grad_2.dims());
     }
+
+    #[test]
+    fn should_diff_cat_after_untracked_tensor() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data([[1.0, 2.0]], &device);
+        let tensor_2 = TestAutodiffTensor::from_data([[3.0, 4.0, 5.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_data([[1.0, 2.0, 3.0, 4.0, 5.0]], &device);
+
+        let tensor_3 = TestAutodiffTensor::cat(vec![tensor_1, tensor_2.clone()], 1);
+        let grads = (tensor_3 * weights).sum().backward();
+
+        // The gradient of the tracked tensor is offset by the untracked tensor
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+        grad_2
+            .to_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[3.0, 4.0, 5.0]]), Tolerance::default());
+    }
 }
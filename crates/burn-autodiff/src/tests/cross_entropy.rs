@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(ad_cross_entropy_loss)]
 mod tests {
     use super::*;
-    use burn_tensor::{Tensor, TensorData, Tolerance, loss};
+    use burn_tensor::{Int, Tensor, TensorData, Tolerance, loss, module};
 
     #[test]
     fn test_cross_entropy_loss_grad() {
@@ -33,4 +33,32 @@ mod tests {
             .to_data()
             .assert_approx_eq::<FloatType>(&expected, tolerance);
     }
+
+    #[test]
+    fn test_cross_entropy_with_label_smoothing_grad() {
+        let data_logits = TensorData::from([[0.2, -1.3, 2.1], [1.5, 0.3, -0.7]]);
+        // The targets [2, 0] smoothed by 0.3 over the 3 classes
+        let data_smoothed = TensorData::from([[0.1, 0.1, 0.8], [0.8, 0.1, 0.1]]);
+
+        let device = Default::default();
+        let logits_1 = Tensor::<TestAutodiffBackend, 2>::from_data(data_logits.clone(), &device)
+            .require_grad();
+        let logits_2 =
+            Tensor::<TestAutodiffBackend, 2>::from_data(data_logits, &device).require_grad();
+        let targets = Tensor::<TestAutodiffBackend, 1, Int>::from_data([2, 0], &device);
+        let smoothed = Tensor::<TestAutodiffBackend, 2>::from_data(data_smoothed, &device);
+
+        let loss_1 = module::cross_entropy_with_label_smoothing(logits_1.clone(), targets, 0.3);
+        let loss_2 = loss::cross_entropy_with_logits(logits_2.clone(), smoothed);
+
+        let grads_1 = loss_1.mean().backward();
+        let grads_2 = loss_2.backward();
+        let grad_1 = logits_1.grad(&grads_1).unwrap();
+        let grad_2 = logits_2.grad(&grads_2).unwrap();
+
+        let tolerance = Tolerance::rel_abs(1e-4, 1e-4).set_half_precision_relative(5e-3);
+        grad_1
+            .to_data()
+            .assert_approx_eq::<FloatType>(&grad_2.to_data(), tolerance);
+    }
 }
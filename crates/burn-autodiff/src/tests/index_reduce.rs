@@ -0,0 +1,188 @@
+#[burn_tensor_testgen::testgen(ad_index_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{IndexReduceMode, Int, Tensor, TensorData};
+
+    fn index(data: TensorData) -> Tensor<TestAutodiffBackend, 1, Int> {
+        Tensor::from_data(data, &Default::default())
+    }
+
+    #[test]
+    fn test_index_reduce_amax_grad() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]),
+            &device,
+        )
+        .require_grad();
+        let source =
+            TestAutodiffTensor::from_data(TensorData::from([[6.0, -1.0], [2.0, 7.0]]), &device)
+                .require_grad();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data(
+            TensorData::from([[0, 2], [1, 1]]),
+            &device,
+        );
+        let weights = TestAutodiffTensor::from_data(
+            TensorData::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]),
+            &device,
+        );
+
+        let output = tensor
+            .clone()
+            .index_reduce(1, indices, source.clone(), IndexReduceMode::AMax);
+        let grads = output.mul(weights).sum().backward();
+
+        // Only the selected max positions receive a gradient
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([[0.0, 2.0, 3.0], [4.0, 0.0, 6.0]]), false);
+        source
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([[1.0, 0.0], [0.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn test_index_reduce_amax_grad_ties() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data(TensorData::from([1.0, 1.0]), &device)
+            .require_grad();
+        let source =
+            TestAutodiffTensor::from_data(TensorData::from([1.0, 0.0]), &device).require_grad();
+
+        let output = tensor.clone().index_reduce(
+            0,
+            index(TensorData::from([0, 1])),
+            source.clone(),
+            IndexReduceMode::AMax,
+        );
+        let grads = output.sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([0.5, 1.0]), false);
+        source
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([0.5, 0.0]), false);
+    }
+
+    #[test]
+    fn test_index_reduce_amin_grad() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data(TensorData::from([1.0, 2.0, 3.0]), &device)
+            .require_grad();
+        let source = TestAutodiffTensor::from_data(TensorData::from([5.0, 4.0, 0.0, 2.0]), &device)
+            .require_grad();
+
+        let output = tensor.clone().index_reduce(
+            0,
+            index(TensorData::from([1, 0, 1, 2])),
+            source.clone(),
+            IndexReduceMode::AMin,
+        );
+        let grads = output.sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([1.0, 0.0, 0.0]), false);
+        source
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([0.0, 0.0, 1.0, 1.0]), false);
+    }
+
+    #[test]
+    fn test_index_reduce_prod_grad() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data(TensorData::from([1.0, 2.0, 3.0]), &device)
+            .require_grad();
+        let source = TestAutodiffTensor::from_data(TensorData::from([5.0, 4.0, 3.0, 2.0]), &device)
+            .require_grad();
+
+        let output = tensor.clone().index_reduce(
+            0,
+            index(TensorData::from([1, 0, 1, 1])),
+            source.clone(),
+            IndexReduceMode::Prod,
+        );
+        let grads = output.sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([4.0, 30.0, 1.0]), false);
+        source
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([12.0, 1.0, 20.0, 30.0]), false);
+    }
+
+    #[test]
+    fn test_index_reduce_prod_grad_with_zeros() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data(TensorData::from([2.0, 0.0, 3.0]), &device)
+            .require_grad();
+        let source = TestAutodiffTensor::from_data(TensorData::from([5.0, 0.0, 1.0, 2.0]), &device)
+            .require_grad();
+
+        let output = tensor.clone().index_reduce(
+            0,
+            index(TensorData::from([1, 0, 1, 1])),
+            source.clone(),
+            IndexReduceMode::Prod,
+        );
+        let grads = output.sum().backward();
+
+        // A single zero receives the product of the other values, while the others receive zero
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([0.0, 10.0, 1.0]), false);
+        source
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([0.0, 2.0, 0.0, 0.0]), false);
+    }
+
+    #[test]
+    fn test_index_reduce_prod_grad_with_multiple_zeros() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<1>::from_data(TensorData::from([0.0]), &device).require_grad();
+        let source =
+            TestAutodiffTensor::from_data(TensorData::from([0.0, 3.0]), &device).require_grad();
+
+        let output = tensor.clone().index_reduce(
+            0,
+            index(TensorData::from([0, 0])),
+            source.clone(),
+            IndexReduceMode::Prod,
+        );
+        let grads = output.sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([0.0]), false);
+        source
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([0.0, 0.0]), false);
+    }
+}
@@ -3,13 +3,16 @@
 mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
 mod add;
 mod aggregation;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
+mod bernoulli;
 mod bridge;
 mod broadcast;
+mod cast;
 mod cat;
 mod ceil;
 mod checkpoint;
@@ -32,6 +35,7 @@ mod floor;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod index_reduce;
 mod log;
 mod log1p;
 mod log_sigmoid;
@@ -46,6 +50,7 @@ mod multithread;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
+mod norm;
 mod permute;
 mod pow;
 mod recip;
@@ -64,6 +69,7 @@ mod sort;
 mod sqrt;
 mod sub;
 mod tanh;
+mod to_device;
 mod transpose;
 
 #[macro_export]
@@ -179,6 +185,7 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_avg_pool2d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_adaptive_max_pool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
 
@@ -187,7 +194,9 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_multithread!();
         burn_autodiff::testgen_ad_add!();
         burn_autodiff::testgen_ad_aggregation!();
+        burn_autodiff::testgen_ad_bernoulli_logits!();
         burn_autodiff::testgen_ad_maxmin!();
+        burn_autodiff::testgen_ad_cast!();
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
@@ -197,6 +206,7 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
+        burn_autodiff::testgen_ad_index_reduce!();
         burn_autodiff::testgen_ad_select!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
@@ -204,6 +214,7 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
+        burn_autodiff::testgen_ad_norm!();
         burn_autodiff::testgen_ad_powf!();
         burn_autodiff::testgen_ad_recip!();
         burn_autodiff::testgen_ad_reshape!();
@@ -213,6 +224,7 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_sub!();
         burn_autodiff::testgen_ad_tanh!();
+        burn_autodiff::testgen_ad_to_device!();
         burn_autodiff::testgen_ad_round!();
         burn_autodiff::testgen_ad_floor!();
         burn_autodiff::testgen_ad_ceil!();
@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(ad_norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_diff_norm_frob() {
+        let data = TensorData::from([[3.0, 0.0], [-4.0, 12.0]]);
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let output = tensor.clone().norm_frob();
+        let grads = output.backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        // The gradient of the norm is the tensor divided by the norm (13)
+        let expected = TensorData::from([[3.0 / 13.0, 0.0], [-4.0 / 13.0, 12.0 / 13.0]]);
+        grad.to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
 
 impl<F: FloatCandleElement, I: IntCandleElement> ActivationOps<Self> for Candle<F, I> {
     fn gelu(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
-        CandleTensor::new(tensor.tensor.gelu().unwrap())
+        CandleTensor::new(tensor.tensor.gelu_erf().unwrap())
     }
 
     fn relu(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
@@ -424,7 +424,17 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         min: FloatElem<Self>,
         max: FloatElem<Self>,
     ) -> FloatTensor<Self> {
-        CandleTensor::new(tensor.tensor.clamp(min, max).unwrap())
+        let inner = |tensor: FloatTensor<Self>| -> candle_core::Result<FloatTensor<Self>> {
+            let clamped = tensor.tensor.clamp(min, max)?;
+
+            // The min and max of some devices return the non-NaN operand, while clamp must
+            // propagate NaN, the only value not equal to itself
+            let is_nan = tensor.tensor.ne(&tensor.tensor)?;
+            Ok(CandleTensor::new(
+                is_nan.where_cond(&tensor.tensor, &clamped)?,
+            ))
+        };
+        inner(tensor).unwrap()
     }
 
     fn float_recip(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
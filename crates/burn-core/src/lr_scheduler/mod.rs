@@ -16,6 +16,9 @@ pub mod cosine;
 /// Step learning rate scheduler
 pub mod step;
 
+/// Warmup cosine learning rate scheduler with a linear cooldown
+pub mod warmup_cosine;
+
 mod base;
 
 pub use base::*;
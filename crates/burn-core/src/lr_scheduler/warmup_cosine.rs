@@ -0,0 +1,238 @@
+use super::{LrScheduler, String};
+use crate as burn;
+use crate::{LearningRate, config::Config};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a [warmup cosine learning rate scheduler with a final
+/// cooldown](WarmupCosineLrScheduler).
+///
+/// This scheduler goes through three phases:
+///
+/// 1. The learning rate increases linearly from 0 to `initial_lr` during the first
+///    `warmup_steps` iterations.
+/// 2. It then follows a cosine function, which would reach `min_lr` after `total_steps`
+///    iterations.
+/// 3. During the last `cooldown_fraction` of the `total_steps` iterations, the cosine is replaced
+///    by a linear decay from its current value to `min_lr`.
+///
+/// After `total_steps` iterations, the learning rate stays at `min_lr`.
+#[derive(Config)]
+pub struct WarmupCosineLrSchedulerConfig {
+    // The learning rate reached at the end of the warmup.
+    initial_lr: LearningRate,
+    // The number of iterations of the linear warmup.
+    warmup_steps: usize,
+    // The total number of iterations, after which the learning rate is `min_lr`.
+    total_steps: usize,
+    // The final learning rate.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    // The fraction of `total_steps` spent in the linear cooldown.
+    #[config(default = 0.0)]
+    cooldown_fraction: f64,
+}
+
+impl WarmupCosineLrSchedulerConfig {
+    /// Initializes a [warmup cosine learning rate scheduler](WarmupCosineLrScheduler).
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if any of the following conditions is true:
+    ///
+    /// * `initial_lr` is out of range (0.0, 1.0]
+    /// * `min_lr` is out of range [0.0, `initial_lr`]
+    /// * `total_steps` is not greater than `warmup_steps`
+    /// * `cooldown_fraction` is out of range [0.0, 1.0), or the cooldown overlaps the warmup
+    pub fn init(&self) -> Result<WarmupCosineLrScheduler, String> {
+        if self.initial_lr <= 0. || self.initial_lr > 1. {
+            return Err("Initial learning rate must be greater than 0 and at most 1".into());
+        }
+        if self.min_lr < 0.0 || self.min_lr > self.initial_lr {
+            return Err(
+                "Minimum learning rate must be at least 0 and at most equal to the initial \
+                 learning rate"
+                    .into(),
+            );
+        }
+        if self.total_steps <= self.warmup_steps {
+            return Err(
+                "Total number of steps must be greater than the number of warmup steps".into(),
+            );
+        }
+        if !(0.0..1.0).contains(&self.cooldown_fraction) {
+            return Err("Cooldown fraction must be at least 0 and less than 1".into());
+        }
+
+        let cooldown_steps = (self.cooldown_fraction * self.total_steps as f64).round() as usize;
+        if cooldown_steps > self.total_steps - self.warmup_steps {
+            return Err("Cooldown must not overlap the warmup".into());
+        }
+
+        Ok(WarmupCosineLrScheduler {
+            min_lr: self.min_lr,
+            max_lr: self.initial_lr,
+            warmup_steps: self.warmup_steps,
+            cooldown_start: self.total_steps - cooldown_steps,
+            total_steps: self.total_steps,
+            current_iter: usize::MAX,
+        })
+    }
+}
+
+/// A learning rate scheduler with a linear warmup, a cosine decay and a linear cooldown, as used
+/// for the pre-training of large language models.
+///
+/// See [WarmupCosineLrSchedulerConfig] for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct WarmupCosineLrScheduler {
+    min_lr: LearningRate,
+    max_lr: LearningRate,
+    warmup_steps: usize,
+    cooldown_start: usize,
+    total_steps: usize,
+    current_iter: usize,
+}
+
+impl WarmupCosineLrScheduler {
+    fn cosine(&self, iter: usize) -> LearningRate {
+        let progress =
+            (iter - self.warmup_steps) as f64 / (self.total_steps - self.warmup_steps) as f64;
+
+        self.min_lr
+            + 0.5 * (self.max_lr - self.min_lr) * (1.0 + (progress * std::f64::consts::PI).cos())
+    }
+}
+
+impl LrScheduler for WarmupCosineLrScheduler {
+    type Record<B: Backend> = usize;
+
+    fn step(&mut self) -> LearningRate {
+        // Make current_iter overflow from usize::MAX to 0 on the first call, like the cosine
+        // annealing scheduler, and stop counting once the schedule is over.
+        self.current_iter = self.current_iter.wrapping_add(1).min(self.total_steps);
+        let iter = self.current_iter;
+
+        if iter < self.warmup_steps {
+            self.max_lr * iter as f64 / self.warmup_steps as f64
+        } else if iter <= self.cooldown_start {
+            self.cosine(iter)
+        } else {
+            let start_lr = self.cosine(self.cooldown_start);
+            let progress = (iter - self.cooldown_start) as f64
+                / (self.total_steps - self.cooldown_start) as f64;
+
+            start_lr + (self.min_lr - start_lr) * progress
+        }
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        self.current_iter
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        self.current_iter = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils;
+    use super::*;
+
+    #[test]
+    fn config_total_steps_too_low() {
+        let r = WarmupCosineLrSchedulerConfig::new(0.5, 10, 10).init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Total number of steps must be greater than the number of warmup steps",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn config_cooldown_overlaps_warmup() {
+        let r = WarmupCosineLrSchedulerConfig::new(0.5, 10, 20)
+            .with_cooldown_fraction(0.6)
+            .init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Cooldown must not overlap the warmup",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn test_lr_phases() {
+        const INITIAL_LR: LearningRate = 0.5;
+        const MIN_LR: LearningRate = 0.1;
+
+        let mut scheduler = WarmupCosineLrSchedulerConfig::new(INITIAL_LR, 10, 110)
+            .with_min_lr(MIN_LR)
+            .with_cooldown_fraction(0.2)
+            .init()
+            .unwrap();
+        let lrs: Vec<_> = (0..=112).map(|_| scheduler.step()).collect();
+
+        let assert_lr = |iter: usize, expected: LearningRate| {
+            assert!(
+                (lrs[iter] - expected).abs() < 1e-10,
+                "Learning rate {} at iteration {iter} should be {expected}",
+                lrs[iter],
+            );
+        };
+        // Warmup
+        assert_lr(0, 0.0);
+        assert_lr(5, INITIAL_LR / 2.0);
+        assert_lr(10, INITIAL_LR);
+        // Cosine decay, until the cooldown starts at iteration 88
+        assert_lr(60, (INITIAL_LR + MIN_LR) / 2.0);
+        // Linear cooldown, then constant
+        assert_lr(110, MIN_LR);
+        assert_lr(112, MIN_LR);
+
+        let cooldown = &lrs[88..=110];
+        let slope = cooldown[1] - cooldown[0];
+        assert!(
+            slope < 0.0,
+            "The cooldown should decrease the learning rate"
+        );
+        cooldown.windows(2).for_each(|lrs| {
+            assert!(
+                (lrs[1] - lrs[0] - slope).abs() < 1e-10,
+                "The cooldown should be linear"
+            );
+        });
+    }
+
+    #[test]
+    fn test_lr_without_cooldown() {
+        const INITIAL_LR: LearningRate = 0.5;
+        const MIN_LR: LearningRate = 0.1;
+
+        let scheduler = WarmupCosineLrSchedulerConfig::new(INITIAL_LR, 2, 4)
+            .with_min_lr(MIN_LR)
+            .init()
+            .unwrap();
+        let expected_lrs = [
+            0.0,                         // warmup
+            INITIAL_LR * 0.5,            // warmup
+            INITIAL_LR,                  // cos(0)
+            (INITIAL_LR + MIN_LR) * 0.5, // cos(PI/2)
+            MIN_LR,                      // cos(PI)
+            MIN_LR,                      // end of the schedule
+        ];
+        test_utils::check_lr_sequence(scheduler, expected_lrs);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let scheduler = WarmupCosineLrSchedulerConfig::new(1.0, 3, 12)
+            .with_cooldown_fraction(0.25)
+            .init()
+            .unwrap();
+        test_utils::check_save_load(scheduler, 6);
+    }
+}
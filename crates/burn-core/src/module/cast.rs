@@ -0,0 +1,18 @@
+use burn_tensor::{FloatDType, Tensor, backend::Backend};
+
+use crate::module::{ModuleMapper, ParamId};
+
+/// Casts the float tensors of a module to another floating point data type.
+///
+/// This can be used to store the parameters of a module with a lower precision, for example
+/// `FloatDType::F16`, on backends that support it.
+pub struct FloatCaster {
+    /// The data type of the float tensors.
+    pub dtype: FloatDType,
+}
+
+impl<B: Backend> ModuleMapper<B> for FloatCaster {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        tensor.cast(self.dtype)
+    }
+}
@@ -1,9 +1,15 @@
 mod base;
+mod cast;
 mod display;
 mod param;
 mod quantize;
+mod spec;
+mod summary;
 
 pub use base::*;
+pub use cast::*;
 pub use display::*;
 pub use param::*;
 pub use quantize::*;
+pub use spec::*;
+pub use summary::*;
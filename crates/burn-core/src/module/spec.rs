@@ -0,0 +1,27 @@
+use alloc::string::{String, ToString};
+use burn_tensor::DType;
+
+/// Description of an input or an output of a model, such as the ones of the forward pass of the
+/// models imported from ONNX.
+///
+/// Scalars have a rank of zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorSpec {
+    /// Name of the input or output.
+    pub name: String,
+    /// Number of dimensions.
+    pub rank: usize,
+    /// Data type of the elements.
+    pub dtype: DType,
+}
+
+impl TensorSpec {
+    /// Create the description of an input or an output.
+    pub fn new(name: &str, rank: usize, dtype: DType) -> Self {
+        Self {
+            name: name.to_string(),
+            rank,
+            dtype,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use super::{Module, ModuleVisitor, ParamId, extract_type_name};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use burn_tensor::{Shape, Tensor, backend::Backend};
+use core::fmt::Display;
+
+/// Summary of the parameters of a module, listing the type, the parameter shapes and the number
+/// of parameters of each of its submodules, similar to Keras' `model.summary()`.
+///
+/// # Example
+///
+/// ```no_run
+/// // Not necessary when using the burn crate directly.
+/// use burn_core as burn;
+///
+/// use burn::{module::ModuleSummary, nn::LinearConfig, tensor::backend::Backend};
+///
+/// fn summary<B: Backend>(device: &B::Device) -> ModuleSummary {
+///     let encoder = LinearConfig::new(16, 8).init::<B>(device);
+///     let decoder = LinearConfig::new(8, 16).init::<B>(device);
+///
+///     ModuleSummary::new()
+///         .add("encoder", &encoder)
+///         .add("decoder", &decoder)
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ModuleSummary {
+    entries: Vec<SummaryEntry>,
+}
+
+/// The parameters of a submodule in a [module summary](ModuleSummary).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryEntry {
+    /// Name of the submodule.
+    pub name: String,
+    /// Type of the submodule.
+    pub ty: String,
+    /// Shapes of the float parameters of the submodule, in the order they are visited.
+    pub shapes: Vec<Shape>,
+    /// Number of parameters of the submodule.
+    pub num_params: usize,
+}
+
+struct ShapeCollector<'a> {
+    shapes: &'a mut Vec<Shape>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ShapeCollector<'_> {
+    fn visit_float<const D: usize>(&mut self, _id: ParamId, tensor: &Tensor<B, D>) {
+        self.shapes.push(tensor.shape());
+    }
+}
+
+impl ModuleSummary {
+    /// Create an empty summary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a submodule to the summary.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the submodule, usually the name of its field.
+    /// * `module` - The submodule.
+    pub fn add<B: Backend, M: Module<B>>(mut self, name: &str, module: &M) -> Self {
+        let mut shapes = Vec::new();
+        module.visit(&mut ShapeCollector {
+            shapes: &mut shapes,
+        });
+
+        self.entries.push(SummaryEntry {
+            name: name.to_string(),
+            ty: extract_type_name::<M>().to_string(),
+            num_params: shapes.iter().map(Shape::num_elements).sum(),
+            shapes,
+        });
+        self
+    }
+
+    /// The submodules of the summary, in the order they were added.
+    pub fn entries(&self) -> &[SummaryEntry] {
+        &self.entries
+    }
+
+    /// The total number of parameters of the submodules.
+    pub fn num_params(&self) -> usize {
+        self.entries.iter().map(|entry| entry.num_params).sum()
+    }
+}
+
+impl Display for ModuleSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let header = ["Name", "Type", "Shapes", "Params"];
+        let rows = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let shapes = entry
+                    .shapes
+                    .iter()
+                    .map(|shape| format!("{:?}", shape.dims))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                [
+                    entry.name.clone(),
+                    entry.ty.clone(),
+                    shapes,
+                    entry.num_params.to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let mut widths = header.map(str::len);
+        for row in rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+        let separator = "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1));
+
+        let write_row = |f: &mut core::fmt::Formatter<'_>, row: [&str; 4]| {
+            let [name, ty, shapes, params] = row;
+            let [name_width, ty_width, shapes_width, params_width] = widths;
+            writeln!(
+                f,
+                "{name:<name_width$}  {ty:<ty_width$}  {shapes:<shapes_width$}  {params:>params_width$}"
+            )
+        };
+
+        write_row(f, header)?;
+        writeln!(f, "{separator}")?;
+        for row in rows.iter() {
+            write_row(f, row.each_ref().map(String::as_str))?;
+        }
+        writeln!(f, "{separator}")?;
+        write!(f, "Total params: {}", self.num_params())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::nn::LinearConfig;
+
+    #[test]
+    fn summary_should_list_submodules() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&device);
+        let linear_no_bias = LinearConfig::new(3, 2)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+
+        let summary = ModuleSummary::new()
+            .add("linear", &linear)
+            .add("linear_no_bias", &linear_no_bias);
+
+        assert_eq!(
+            summary.entries(),
+            [
+                SummaryEntry {
+                    name: "linear".to_string(),
+                    ty: "Linear".to_string(),
+                    shapes: alloc::vec![Shape::new([4, 3]), Shape::new([3])],
+                    num_params: 15,
+                },
+                SummaryEntry {
+                    name: "linear_no_bias".to_string(),
+                    ty: "Linear".to_string(),
+                    shapes: alloc::vec![Shape::new([3, 2])],
+                    num_params: 6,
+                },
+            ]
+        );
+        assert_eq!(
+            summary.num_params(),
+            linear.num_params() + linear_no_bias.num_params()
+        );
+    }
+
+    #[test]
+    fn display() {
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&Default::default());
+
+        let summary = ModuleSummary::new().add("linear", &linear);
+
+        assert_eq!(
+            alloc::format!("{summary}"),
+            "Name    Type    Shapes       Params\n\
+             -----------------------------------\n\
+             linear  Linear  [4, 3], [3]      15\n\
+             -----------------------------------\n\
+             Total params: 15"
+        );
+    }
+}
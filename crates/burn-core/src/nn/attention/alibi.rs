@@ -0,0 +1,396 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::Initializer;
+use crate::nn::attention::{MhaInput, MhaOutput, MultiHeadAttention, MultiHeadAttentionConfig};
+use crate::{
+    config::Config,
+    tensor::{Int, Tensor, backend::Backend},
+};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create an [ALiBi Multi Head Attention](ALiBiMultiHeadAttention) layer using
+/// the [init function](ALiBiMultiHeadAttentionConfig::init).
+#[derive(Config, Debug)]
+pub struct ALiBiMultiHeadAttentionConfig {
+    /// The size of each linear layer.
+    pub d_model: usize,
+    /// The number of heads.
+    pub n_heads: usize,
+    /// The dropout rate. Default: 0.1
+    #[config(default = 0.1)]
+    pub dropout: f64,
+    /// The minimum value a float can take. Default: -1.0e4
+    /// This is used to mask attention scores before calculating attention weights.
+    /// A value too low might result in NaN.
+    #[config(default = -1.0e4)]
+    pub min_float: f64,
+    /// Use "quiet softmax" instead of regular softmax.
+    #[config(default = false)]
+    pub quiet_softmax: bool,
+    /// The length of the prompt, attended bidirectionally, before the continuation attended
+    /// causally. When `None`, every position is attended and the bias is symmetric.
+    #[config(default = "None")]
+    pub prefix_len: Option<usize>,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The multihead attention module with the linear biases of
+/// [ALiBi](https://arxiv.org/abs/2108.12409) instead of position embeddings.
+///
+/// The score of a query attending a key is penalized by the [slope](alibi_slopes) of the head
+/// times their distance. The queries are the last positions of the keys, so a shorter query (the
+/// continuation being decoded) can attend a longer key (the prompt and the continuation).
+///
+/// With a [prefix length](ALiBiMultiHeadAttentionConfig::prefix_len), the positions of the
+/// prompt attend each other bidirectionally while the positions of the continuation can't attend
+/// the following ones, without having to build a mask for each prompt length.
+///
+/// Should be created with [ALiBiMultiHeadAttentionConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct ALiBiMultiHeadAttention<B: Backend> {
+    /// The attention module to which the biases are added.
+    pub mha: MultiHeadAttention<B>,
+    /// The length of the prompt attended bidirectionally.
+    pub prefix_len: Option<usize>,
+}
+
+impl<B: Backend> ModuleDisplay for ALiBiMultiHeadAttention<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("d_model", &self.mha.d_model)
+            .add("n_heads", &self.mha.n_heads)
+            .add("dropout", &self.mha.dropout.prob)
+            .add("prefix_len", &self.prefix_len)
+            .optional()
+    }
+}
+
+impl ALiBiMultiHeadAttentionConfig {
+    /// Initialize a new [ALiBi multihead attention](ALiBiMultiHeadAttention) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> ALiBiMultiHeadAttention<B> {
+        let mha = MultiHeadAttentionConfig::new(self.d_model, self.n_heads)
+            .with_dropout(self.dropout)
+            .with_min_float(self.min_float)
+            .with_quiet_softmax(self.quiet_softmax)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+
+        ALiBiMultiHeadAttention {
+            mha,
+            prefix_len: self.prefix_len,
+        }
+    }
+}
+
+impl<B: Backend> ALiBiMultiHeadAttention<B> {
+    /// Applies the forward pass on the input tensors.
+    ///
+    /// See [ALiBiMultiHeadAttention](ALiBiMultiHeadAttention) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
+        let [_, seq_length_1, _] = input.query.dims();
+        let [_, seq_length_2, _] = input.key.dims();
+        let bias = self.bias(seq_length_1, seq_length_2, &input.query.device());
+
+        self.mha.forward_biased(input, Some(bias))
+    }
+
+    /// The bias `[n_heads, seq_length_1, seq_length_2]` added to the attention scores.
+    fn bias(&self, seq_length_1: usize, seq_length_2: usize, device: &B::Device) -> Tensor<B, 3> {
+        let n_heads = self.mha.n_heads;
+        let offset = seq_length_2.saturating_sub(seq_length_1) as i64;
+
+        let query_positions =
+            Tensor::<B, 1, Int>::arange(offset..offset + seq_length_1 as i64, device)
+                .reshape([seq_length_1, 1]);
+        let key_positions =
+            Tensor::<B, 1, Int>::arange(0..seq_length_2 as i64, device).reshape([1, seq_length_2]);
+        let distances = (query_positions - key_positions.clone()).float();
+
+        let slopes = Tensor::<B, 1>::from_floats(alibi_slopes(n_heads).as_slice(), device)
+            .reshape([n_heads, 1, 1]);
+        let bias = distances.clone().abs().unsqueeze::<3>() * slopes.neg();
+
+        match self.prefix_len {
+            Some(prefix_len) => {
+                // The keys following the query are masked, unless both are in the prompt
+                let shape = [seq_length_1, seq_length_2];
+                let mask = distances
+                    .lower_elem(0)
+                    .bool_and(
+                        key_positions
+                            .greater_equal_elem(prefix_len as i64)
+                            .expand(shape),
+                    )
+                    .unsqueeze::<3>()
+                    .expand([n_heads, seq_length_1, seq_length_2]);
+
+                bias.mask_fill(mask, self.mha.min_float)
+            }
+            None => bias,
+        }
+    }
+}
+
+/// Returns the slopes of the linear biases added to the attention scores of each head by
+/// [ALiBi](https://arxiv.org/abs/2108.12409).
+///
+/// The slopes of `n` heads, `n` being a power of 2, are the geometric sequence starting at
+/// `2^(-8/n)` with that same ratio. For other head counts, the slopes of the closest lower power
+/// of 2 are followed by every other slope of the next power of 2, as done by the reference
+/// implementation (Appendix B of the paper).
+///
+/// # Arguments
+///
+/// * `num_heads` - The number of attention heads.
+///
+/// # Returns
+///
+/// The slope of each head.
+pub fn alibi_slopes(num_heads: usize) -> Vec<f32> {
+    if num_heads == 0 {
+        return Vec::new();
+    }
+
+    if num_heads.is_power_of_two() {
+        return alibi_slopes_power_of_two(num_heads).collect();
+    }
+
+    let closest_power_of_two = 1 << num_heads.ilog2();
+    let interleaved = alibi_slopes_power_of_two(2 * closest_power_of_two)
+        .step_by(2)
+        .take(num_heads - closest_power_of_two);
+
+    alibi_slopes_power_of_two(closest_power_of_two)
+        .chain(interleaved)
+        .collect()
+}
+
+fn alibi_slopes_power_of_two(num_heads: usize) -> impl Iterator<Item = f32> {
+    (1..=num_heads).map(move |i| 2.0f64.powf(-8.0 * i as f64 / num_heads as f64) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::nn::attention::generate_autoregressive_mask;
+    use crate::tensor::{Distribution, Shape, TensorData, Tolerance};
+
+    #[test]
+    fn slopes_8_heads() {
+        assert_eq!(
+            alibi_slopes(8),
+            [
+                0.5, 0.25, 0.125, 0.0625, 0.03125, 0.015625, 0.0078125, 0.00390625
+            ]
+        );
+    }
+
+    #[test]
+    fn slopes_12_heads() {
+        assert_eq!(
+            alibi_slopes(12),
+            [
+                0.5,
+                0.25,
+                0.125,
+                0.0625,
+                0.03125,
+                0.015625,
+                0.0078125,
+                0.00390625,
+                0.70710677,
+                0.35355338,
+                0.17677669,
+                0.088388346
+            ]
+        );
+    }
+
+    #[test]
+    fn slopes_16_heads() {
+        assert_eq!(
+            alibi_slopes(16),
+            [
+                0.70710677,
+                0.5,
+                0.35355338,
+                0.25,
+                0.17677669,
+                0.125,
+                0.088388346,
+                0.0625,
+                0.044194173,
+                0.03125,
+                0.022097087,
+                0.015625,
+                0.011048543,
+                0.0078125,
+                0.0055242716,
+                0.00390625
+            ]
+        );
+    }
+
+    #[test]
+    fn slopes_20_heads() {
+        assert_eq!(
+            alibi_slopes(20),
+            [
+                0.70710677,
+                0.5,
+                0.35355338,
+                0.25,
+                0.17677669,
+                0.125,
+                0.088388346,
+                0.0625,
+                0.044194173,
+                0.03125,
+                0.022097087,
+                0.015625,
+                0.011048543,
+                0.0078125,
+                0.0055242716,
+                0.00390625,
+                0.8408964,
+                0.59460354,
+                0.4204482,
+                0.29730177
+            ]
+        );
+    }
+
+    #[test]
+    fn slopes_few_heads() {
+        assert!(alibi_slopes(0).is_empty());
+        assert_eq!(alibi_slopes(1), [0.00390625]);
+        assert_eq!(alibi_slopes(3), [0.0625, 0.00390625, 0.25]);
+    }
+
+    #[test]
+    fn bias_is_symmetric_without_prefix() {
+        let device = Default::default();
+        let alibi = ALiBiMultiHeadAttentionConfig::new(8, 2).init::<TestBackend>(&device);
+
+        let bias = alibi.bias(3, 3, &device);
+
+        bias.into_data().assert_approx_eq::<f32>(
+            &TensorData::from([
+                [
+                    [0.0, -0.0625, -0.125],
+                    [-0.0625, 0.0, -0.0625],
+                    [-0.125, -0.0625, 0.0],
+                ],
+                [
+                    [0.0, -0.00390625, -0.0078125],
+                    [-0.00390625, 0.0, -0.00390625],
+                    [-0.0078125, -0.00390625, 0.0],
+                ],
+            ]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn prefix_len_zero_matches_causal_attention() {
+        let [batch_size, seq_length, d_model, n_heads] = [3, 5, 12, 4];
+        let device = Default::default();
+        let mut alibi = ALiBiMultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_prefix_len(Some(0))
+            .init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output_prefix = alibi.forward(MhaInput::self_attn(tensor.clone()));
+        alibi.prefix_len = None;
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let output_masked = alibi.forward(MhaInput::self_attn(tensor).mask_attn(mask_attn));
+
+        output_prefix
+            .context
+            .into_data()
+            .assert_approx_eq::<f32>(&output_masked.context.into_data(), Tolerance::default());
+        output_prefix
+            .weights
+            .into_data()
+            .assert_approx_eq::<f32>(&output_masked.weights.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn prefix_is_attended_bidirectionally() {
+        let [batch_size, seq_length_1, seq_length_2, d_model, n_heads] = [2, 5, 6, 12, 4];
+        let prefix_len = 3;
+        let device = Default::default();
+        let alibi = ALiBiMultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_prefix_len(Some(prefix_len))
+            .init::<TestBackend>(&device);
+        let key = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length_2, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let query = key
+            .clone()
+            .slice([0..batch_size, seq_length_2 - seq_length_1..seq_length_2]);
+
+        let weights = alibi
+            .forward(MhaInput::new(query, key.clone(), key))
+            .weights;
+
+        assert_eq!(
+            weights.shape(),
+            Shape::new([batch_size, n_heads, seq_length_1, seq_length_2])
+        );
+        // The continuation attends the whole prompt but not its own following positions
+        let weights = weights.into_data().to_vec::<f32>().unwrap();
+        let weight = |query: usize, key: usize| weights[query * seq_length_2 + key];
+        for query in 0..seq_length_1 {
+            let position = seq_length_2 - seq_length_1 + query;
+            for key in 0..seq_length_2 {
+                assert_eq!(
+                    weight(query, key) > 0.0,
+                    key < prefix_len || key <= position,
+                    "query {position} attending key {key}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn display() {
+        let config = ALiBiMultiHeadAttentionConfig::new(2, 4).with_prefix_len(Some(3));
+        let alibi = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", alibi),
+            "ALiBiMultiHeadAttention {d_model: 2, n_heads: 4, dropout: 0.1, prefix_len: 3, \
+            params: 24}"
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use crate as burn;
 
+use alloc::vec;
+
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
 use crate::nn::Initializer;
 use crate::nn::cache::TensorCache;
@@ -13,7 +15,7 @@ use crate::{
 use num_traits::Float;
 
 /// Configuration to create a [Multi Head Attention](MultiHeadAttention) layer using the [init function](MultiHeadAttentionConfig::init).
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct MultiHeadAttentionConfig {
     /// The size of each linear layer.
     pub d_model: usize,
@@ -102,9 +104,9 @@ impl<B: Backend> ModuleDisplay for MultiHeadAttention<B> {
 #[derive(Debug, Clone)]
 pub struct MhaInput<B: Backend> {
     /// Shape `[batch_size, seq_length_1, d_model]`
-    query: Tensor<B, 3>,
+    pub(crate) query: Tensor<B, 3>,
     /// Shape `[batch_size, seq_length_2, d_model]`
-    key: Tensor<B, 3>,
+    pub(crate) key: Tensor<B, 3>,
     /// Shape `[batch_size, seq_length_2, d_model]`
     value: Tensor<B, 3>,
     mask_pad: Option<Tensor<B, 2, Bool>>,
@@ -197,13 +199,26 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
+        self.forward_biased(input, None)
+    }
+
+    /// Applies the forward pass, adding the given bias of shape
+    /// `[n_heads, seq_length_1, seq_length_2]` to the attention scores of each item.
+    pub(crate) fn forward_biased(
+        &self,
+        input: MhaInput<B>,
+        bias: Option<Tensor<B, 3>>,
+    ) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
         let query = self.attention_linear(input.query, &self.query);
         let key = self.attention_linear(input.key, &self.key);
         let value = self.attention_linear(input.value, &self.value);
 
-        let attn_scores = self.attn_scores(query, key);
+        let mut attn_scores = self.attn_scores(query, key);
+        if let Some(bias) = bias {
+            attn_scores = attn_scores + bias.unsqueeze();
+        }
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
 
         let context = weights.clone().matmul(value);
@@ -249,6 +264,45 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
+    /// Applies the self-attention forward pass on the new positions only, attending to the keys
+    /// and values of the past positions stored in the [key-value cache](KvCache).
+    ///
+    /// The keys and values of the new positions are appended to the cache. The new positions
+    /// attend causally to each other, so a whole prompt can be processed in a single call before
+    /// decoding the following tokens one at a time.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward_cached(&self, cache: &mut KvCache<B>, query: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [batch_size, seq_length, d_model] = query.dims();
+        let past_length = cache.len();
+        let device = query.device();
+
+        let key = self.attention_linear(query.clone(), &self.key);
+        let value = self.attention_linear(query.clone(), &self.value);
+        let query = self.attention_linear(query, &self.query);
+        let (key, value) = cache.append(key, value);
+
+        let mask_attn = (seq_length > 1).then(|| {
+            let total_length = past_length + seq_length;
+            Tensor::<B, 2, Bool>::tril_mask([seq_length, total_length], past_length as i64, &device)
+                .unsqueeze::<3>()
+                .expand([batch_size, seq_length, total_length])
+        });
+
+        let attn_scores = self.attn_scores(query, key);
+        let weights = self.attn_weights(attn_scores, None, mask_attn);
+
+        let context = weights.matmul(value);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, d_model]);
+
+        self.output.forward(context)
+    }
+
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
         let attn_scores = query
             .matmul(key.transpose())
@@ -335,6 +389,67 @@ impl<B: Backend> MhaCache<B> {
     }
 }
 
+/// Key-value cache for [autoregressive self-attention](MultiHeadAttention::forward_cached).
+///
+/// Stores the projected keys and values of the past positions, so each decoding step only
+/// projects the new tokens.
+#[derive(Debug, Clone)]
+pub struct KvCache<B: Backend> {
+    /// Shape `[batch_size, n_heads, seq_length, d_k]`
+    key: Option<Tensor<B, 4>>,
+    /// Shape `[batch_size, n_heads, seq_length, d_k]`
+    value: Option<Tensor<B, 4>>,
+}
+
+impl<B: Backend> KvCache<B> {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self {
+            key: None,
+            value: None,
+        }
+    }
+
+    /// The number of cached positions.
+    pub fn len(&self) -> usize {
+        self.key.as_ref().map(|key| key.dims()[2]).unwrap_or(0)
+    }
+
+    /// Whether no position is cached.
+    pub fn is_empty(&self) -> bool {
+        self.key.is_none()
+    }
+
+    /// Remove all the cached positions, e.g. to start decoding a new sequence.
+    pub fn reset(&mut self) {
+        self.key = None;
+        self.value = None;
+    }
+
+    /// Append the keys and values of the new positions, returning those of all the positions.
+    fn append(&mut self, key: Tensor<B, 4>, value: Tensor<B, 4>) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let key = match self.key.take() {
+            Some(past) => Tensor::cat(vec![past, key], 2),
+            None => key,
+        };
+        let value = match self.value.take() {
+            Some(past) => Tensor::cat(vec![past, value], 2),
+            None => value,
+        };
+
+        self.key = Some(key.clone());
+        self.value = Some(value.clone());
+
+        (key, value)
+    }
+}
+
+impl<B: Backend> Default for KvCache<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
     pub fn forward<F: Fn(Tensor<B, 3>) -> Tensor<B, D>>(
         &mut self,
@@ -517,6 +632,91 @@ mod tests {
             );
     }
 
+    #[test]
+    fn test_forward_cached_step_by_step_should_match_batch_forward() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 12, 3];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let expected = mha
+            .forward(MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn))
+            .context;
+
+        let mut cache = KvCache::new();
+        let output = (0..seq_length)
+            .map(|i| {
+                let step = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+                mha.forward_cached(&mut cache, step)
+            })
+            .collect::<Vec<_>>();
+        let output = Tensor::cat(output, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FloatElem<TestBackend>>(
+                &expected.clone().into_data(),
+                Tolerance::rel_abs(1e-5, 1e-5),
+            );
+
+        // Processing the prompt at once before decoding gives the same result
+        cache.reset();
+        let prompt = mha.forward_cached(
+            &mut cache,
+            tensor.clone().slice([0..batch_size, 0..3, 0..d_model]),
+        );
+        let next = mha.forward_cached(
+            &mut cache,
+            tensor.slice([0..batch_size, 3..seq_length, 0..d_model]),
+        );
+        let output = Tensor::cat(vec![prompt, next], 1);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FloatElem<TestBackend>>(
+                &expected.into_data(),
+                Tolerance::rel_abs(1e-5, 1e-5),
+            );
+    }
+
+    #[test]
+    fn test_kv_cache_grows_with_each_step() {
+        let [batch_size, d_model, n_heads] = [2, 8, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let mut cache = KvCache::new();
+        assert!(cache.is_empty());
+
+        let prompt = Tensor::random([batch_size, 3, d_model], Distribution::Default, &device);
+        let output = mha.forward_cached(&mut cache, prompt);
+        assert_eq!(output.dims(), [batch_size, 3, d_model]);
+        assert_eq!(cache.len(), 3);
+
+        for i in 1..=2 {
+            let step = Tensor::random([batch_size, 1, d_model], Distribution::Default, &device);
+            let output = mha.forward_cached(&mut cache, step);
+            assert_eq!(output.dims(), [batch_size, 1, d_model]);
+            assert_eq!(cache.len(), 3 + i);
+        }
+        assert_eq!(
+            cache.key.as_ref().unwrap().dims(),
+            [batch_size, n_heads, 5, d_model / n_heads]
+        );
+        assert_eq!(
+            cache.value.as_ref().unwrap().dims(),
+            [batch_size, n_heads, 5, d_model / n_heads]
+        );
+
+        cache.reset();
+        assert!(cache.is_empty());
+        assert_eq!(cache.len(), 0);
+    }
+
     #[test]
     fn display() {
         let config = MultiHeadAttentionConfig::new(2, 4);
@@ -0,0 +1,234 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay};
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::{Linear, LinearConfig, PaddingConfig2d};
+use crate::tensor::activation::{relu, sigmoid};
+use crate::tensor::{Tensor, backend::Backend};
+
+/// The pooling of the spatial dimensions describing the channels in the channel attention of a
+/// [CBAM](Cbam) module.
+#[derive(Config, Debug, PartialEq, Eq, Copy)]
+pub enum PoolType {
+    /// Average pooling.
+    Avg,
+    /// Max pooling.
+    Max,
+}
+
+/// Configuration to create a [CBAM](Cbam) module using the [init function](CbamConfig::init).
+#[derive(Config, Debug)]
+pub struct CbamConfig {
+    /// The number of channels of the input.
+    pub channels: usize,
+    /// The factor by which the hidden layer of the channel attention reduces the channels.
+    #[config(default = 16)]
+    pub reduction: usize,
+    /// The poolings whose descriptors go through the channel attention, their attentions being
+    /// summed.
+    #[config(default = "vec![PoolType::Avg, PoolType::Max]")]
+    pub pool_types: Vec<PoolType>,
+    /// The size of the kernel of the convolution of the spatial attention.
+    #[config(default = 7)]
+    pub kernel_size: usize,
+}
+
+/// The Convolutional Block Attention Module, as described in the paper
+/// [CBAM: Convolutional Block Attention Module](https://arxiv.org/abs/1807.06521).
+///
+/// The input is gated by a channel attention followed by a spatial attention:
+///
+/// - The channel attention pools the spatial dimensions of the input with each
+///   [pool type](PoolType) and passes the descriptors through a shared bottleneck of two linear
+///   layers with a ReLU between them. The sigmoid of their sum scales each channel.
+/// - The spatial attention concatenates the average and the max over the channels and passes
+///   them through a convolution with a single output channel, whose sigmoid scales each pixel.
+///
+/// Should be created with [CbamConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Cbam<B: Backend> {
+    /// The linear layer reducing the channels in the channel attention.
+    pub fc1: Linear<B>,
+    /// The linear layer expanding the channels back in the channel attention.
+    pub fc2: Linear<B>,
+    /// The convolution of the spatial attention.
+    pub conv: Conv2d<B>,
+    /// The poolings of the channel attention.
+    pub pool_types: Ignored<Vec<PoolType>>,
+}
+
+impl<B: Backend> ModuleDisplay for Cbam<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [channels, hidden_channels] = self.fc1.weight.shape().dims();
+        let [_, _, kernel_size, _] = self.conv.weight.shape().dims();
+        content
+            .add("channels", &channels)
+            .add("hidden_channels", &hidden_channels)
+            .add("pool_types", &self.pool_types)
+            .add("kernel_size", &kernel_size)
+            .optional()
+    }
+}
+
+impl CbamConfig {
+    /// Initialize a new [CBAM](Cbam) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no pool types or if the kernel size is even.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Cbam<B> {
+        assert!(
+            !self.pool_types.is_empty(),
+            "CBAM requires at least one pool type"
+        );
+        assert!(
+            self.kernel_size % 2 != 0,
+            "The kernel size of the CBAM spatial attention should be odd, but got {}",
+            self.kernel_size
+        );
+
+        let hidden_channels = (self.channels / self.reduction).max(1);
+        let padding = self.kernel_size / 2;
+
+        Cbam {
+            fc1: LinearConfig::new(self.channels, hidden_channels).init(device),
+            fc2: LinearConfig::new(hidden_channels, self.channels).init(device),
+            conv: Conv2dConfig::new([2, 1], [self.kernel_size, self.kernel_size])
+                .with_padding(PaddingConfig2d::Explicit(padding, padding))
+                .init(device),
+            pool_types: Ignored(self.pool_types.clone()),
+        }
+    }
+}
+
+impl<B: Backend> Cbam<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [Cbam](Cbam) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels, _, _] = input.dims();
+
+        let attention = self
+            .pool_types
+            .iter()
+            .map(|pool_type| {
+                let x = match pool_type {
+                    PoolType::Avg => input.clone().mean_dim(2).mean_dim(3),
+                    PoolType::Max => input.clone().max_dim(2).max_dim(3),
+                };
+                let x = relu(self.fc1.forward(x.reshape([batch_size, channels])));
+
+                self.fc2.forward(x)
+            })
+            .reduce(|lhs, rhs| lhs + rhs)
+            .unwrap();
+        let x = input * sigmoid(attention).reshape([batch_size, channels, 1, 1]);
+
+        let descriptors = Tensor::cat(vec![x.clone().mean_dim(1), x.clone().max_dim(1)], 1);
+        let attention = sigmoid(self.conv.forward(descriptors));
+
+        x * attention
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::Distribution;
+
+    #[test]
+    fn output_shape_matches_input_shape() {
+        let device = Default::default();
+        let module = CbamConfig::new(32).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 32, 5, 6], Distribution::Default, &device);
+
+        let output = module.forward(input);
+
+        assert_eq!(output.dims(), [2, 32, 5, 6]);
+    }
+
+    #[test]
+    fn output_is_between_zero_and_input_max() {
+        let device = Default::default();
+
+        for pool_types in [
+            vec![PoolType::Avg, PoolType::Max],
+            vec![PoolType::Avg],
+            vec![PoolType::Max],
+        ] {
+            let module = CbamConfig::new(8)
+                .with_reduction(4)
+                .with_pool_types(pool_types)
+                .init::<TestBackend>(&device);
+            // Non-negative like the output of a ReLU
+            let input = Tensor::<TestBackend, 4>::random(
+                [3, 8, 6, 6],
+                Distribution::Uniform(0.0, 5.0),
+                &device,
+            );
+            let input_max = input.clone().max().into_scalar();
+
+            let output = module.forward(input.clone());
+
+            // Each value is scaled by the product of two gates in (0, 1)
+            assert!(output.clone().lower_equal(input).all().into_scalar());
+            assert!(output.clone().greater_equal_elem(0.0).all().into_scalar());
+            assert!(output.max().into_scalar() <= input_max);
+        }
+    }
+
+    #[test]
+    fn gates_do_not_increase_magnitude() {
+        let device = Default::default();
+        let module = CbamConfig::new(4)
+            .with_kernel_size(3)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 4, 5, 5], Distribution::Default, &device);
+
+        let output = module.forward(input.clone());
+
+        assert!(output.abs().lower_equal(input.abs()).all().into_scalar());
+    }
+
+    #[test]
+    #[should_panic = "CBAM requires at least one pool type"]
+    fn no_pool_types() {
+        let config = CbamConfig::new(8).with_pool_types(vec![]);
+        let _module = config.init::<TestBackend>(&Default::default());
+    }
+
+    #[test]
+    #[should_panic = "The kernel size of the CBAM spatial attention should be odd"]
+    fn even_kernel_size() {
+        let config = CbamConfig::new(8).with_kernel_size(4);
+        let _module = config.init::<TestBackend>(&Default::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = CbamConfig::new(16).with_reduction(4);
+        let module = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{module}"),
+            "Cbam {channels: 16, hidden_channels: 4, pool_types: [Avg, Max], kernel_size: 7, params: 247}"
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use alloc::vec;
+
+use crate as burn;
+
+use crate::{
+    config::Config,
+    module::{Content, DisplaySettings, Module, ModuleDisplay, Param},
+    nn::Initializer,
+    tensor::{
+        Tensor,
+        backend::Backend,
+        module::{conv1d, conv1d_with_padding},
+        ops::ConvOptions,
+    },
+};
+
+/// Configuration to create a [causal 1D convolution](CausalConv1d) layer using the
+/// [init function](CausalConv1dConfig::init).
+#[derive(Config, Debug)]
+pub struct CausalConv1dConfig {
+    /// The number of input channels.
+    pub channels_in: usize,
+    /// The number of output channels.
+    pub channels_out: usize,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// Spacing between kernel elements.
+    #[config(default = "1")]
+    pub dilation: usize,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0),fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Applies a causal 1D convolution over input tensors, where each output only depends on the
+/// current and the previous elements of the input.
+///
+/// The input is only padded on the left by `(kernel_size - 1) * dilation` zeros, so the output has
+/// the length of the input, as used by autoregressive models like WaveNet and temporal
+/// convolutional networks.
+///
+/// Should be created with [CausalConv1dConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct CausalConv1d<B: Backend> {
+    /// Tensor of shape `[channels_out, channels_in, kernel_size]`
+    pub weight: Param<Tensor<B, 3>>,
+    /// Tensor of shape `[channels_out]`
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// Size of the kernel.
+    pub kernel_size: usize,
+    /// Spacing between kernel elements.
+    pub dilation: usize,
+}
+
+/// The state of a [causal 1D convolution](CausalConv1d) processing a sequence chunk by chunk.
+pub struct CausalConv1dState<B: Backend> {
+    /// The last `(kernel_size - 1) * dilation` elements of the input seen so far, of shape
+    /// `[batch_size, channels_in, (kernel_size - 1) * dilation]`.
+    pub buffer: Tensor<B, 3>,
+}
+
+impl<B: Backend> CausalConv1dState<B> {
+    /// Initialize a new [causal 1D convolution state](CausalConv1dState).
+    pub fn new(buffer: Tensor<B, 3>) -> Self {
+        Self { buffer }
+    }
+}
+
+impl<B: Backend> ModuleDisplay for CausalConv1d<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("kernel_size", &self.kernel_size)
+            .add("dilation", &self.dilation)
+            .optional()
+    }
+}
+
+impl CausalConv1dConfig {
+    /// Initialize a new [causal conv1d](CausalConv1d) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> CausalConv1d<B> {
+        let shape = [self.channels_out, self.channels_in, self.kernel_size];
+
+        let fan_in: usize = self.channels_in * self.kernel_size;
+        let weight = self
+            .initializer
+            .init_with(shape, Some(fan_in), None, device);
+        let bias = self.bias.then(|| {
+            self.initializer
+                .init_with([self.channels_out], Some(fan_in), None, device)
+        });
+
+        CausalConv1d {
+            weight,
+            bias,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+        }
+    }
+}
+
+impl<B: Backend> CausalConv1d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [conv1d_with_padding](crate::tensor::module::conv1d_with_padding) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, length]`
+    /// - output: `[batch_size, channels_out, length]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        conv1d_with_padding(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            [(self.padding(), 0)],
+            self.options(),
+        )
+    }
+
+    /// Applies the forward pass on a chunk of the input sequence, such as a single step of
+    /// autoregressive generation, continuing from the state of the previous chunk.
+    ///
+    /// The chunks of a sequence give the same output as the [forward pass](CausalConv1d::forward)
+    /// over the whole sequence. Without state, the chunk is the start of the sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, chunk_length]`
+    /// - output: `[batch_size, channels_out, chunk_length]`
+    pub fn streaming_forward(
+        &self,
+        input: Tensor<B, 3>,
+        state: Option<CausalConv1dState<B>>,
+    ) -> (Tensor<B, 3>, CausalConv1dState<B>) {
+        let [batch_size, channels_in, length] = input.dims();
+        let padding = self.padding();
+
+        let device = input.device();
+
+        // A kernel with a single element doesn't depend on the previous elements
+        let (input, buffer) = if padding == 0 {
+            (input, Tensor::empty([batch_size, channels_in, 0], &device))
+        } else {
+            let buffer = state
+                .map(|state| state.buffer)
+                .unwrap_or_else(|| Tensor::zeros([batch_size, channels_in, padding], &device));
+            let input = Tensor::cat(vec![buffer, input], 2);
+            let buffer =
+                input
+                    .clone()
+                    .slice([0..batch_size, 0..channels_in, length..length + padding]);
+            (input, buffer)
+        };
+
+        let output = conv1d(
+            input,
+            self.weight.val(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            self.options(),
+        );
+
+        (output, CausalConv1dState::new(buffer))
+    }
+
+    /// The number of previous elements each output depends on.
+    fn padding(&self) -> usize {
+        (self.kernel_size - 1) * self.dilation
+    }
+
+    fn options(&self) -> ConvOptions<1> {
+        ConvOptions::new([1], [0], [self.dilation], 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::{Distribution, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn output_has_input_length() {
+        let device = Default::default();
+        let conv = CausalConv1dConfig::new(2, 3, 3)
+            .with_dilation(2)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 2, 7], Distribution::Default, &device);
+
+        let output = conv.forward(input);
+
+        assert_eq!(output.dims(), [2, 3, 7]);
+    }
+
+    #[test]
+    fn output_should_not_depend_on_future_inputs() {
+        let device = Default::default();
+        let conv = CausalConv1dConfig::new(2, 3, 3)
+            .with_dilation(2)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 2, 10], Distribution::Default, &device);
+        let step = 6;
+
+        // Change the inputs after the step
+        let future = Tensor::<TestBackend, 3>::random([2, 2, 4], Distribution::Default, &device);
+        let changed = input.clone().slice_assign([0..2, 0..2, step..10], future);
+
+        let output = conv.forward(input).slice([0..2, 0..3, 0..step]);
+        let output_changed = conv.forward(changed).slice([0..2, 0..3, 0..step]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&output_changed.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn forward_should_match_reference() {
+        let device = Default::default();
+        let conv = CausalConv1dConfig::new(1, 1, 2)
+            .with_dilation(2)
+            .with_initializer(Initializer::Ones)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0, 3.0, 4.0, 5.0]]], &device);
+
+        let output = conv.forward(input);
+
+        // Each output is the sum of the current input and the one two steps before
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[1.0f32, 2.0, 4.0, 6.0, 8.0]]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn streaming_forward_should_match_forward() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 3>::random([2, 2, 9], Distribution::Default, &device);
+
+        for kernel_size in [3, 1] {
+            let conv = CausalConv1dConfig::new(2, 3, kernel_size)
+                .with_dilation(2)
+                .init::<TestBackend>(&device);
+            let expected = conv.forward(input.clone());
+
+            // One step at a time, then in chunks
+            let mut state = None;
+            let mut outputs = vec![];
+            for step in 0..9 {
+                let chunk = input.clone().slice([0..2, 0..2, step..step + 1]);
+                let (output, new_state) = conv.streaming_forward(chunk, state);
+                outputs.push(output);
+                state = Some(new_state);
+            }
+            Tensor::cat(outputs, 2)
+                .into_data()
+                .assert_approx_eq::<FT>(&expected.to_data(), Tolerance::default());
+
+            let (output_start, state) =
+                conv.streaming_forward(input.clone().slice([0..2, 0..2, 0..5]), None);
+            let (output_end, _) =
+                conv.streaming_forward(input.clone().slice([0..2, 0..2, 5..9]), Some(state));
+            Tensor::cat(vec![output_start, output_end], 2)
+                .into_data()
+                .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+        }
+    }
+
+    #[test]
+    fn display() {
+        let config = CausalConv1dConfig::new(5, 5, 5);
+        let conv = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", conv),
+            "CausalConv1d {kernel_size: 5, dilation: 1, params: 130}"
+        );
+    }
+}
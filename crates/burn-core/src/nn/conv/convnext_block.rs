@@ -0,0 +1,180 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::{Initializer, LayerNorm, LayerNormConfig, Linear, LinearConfig, PaddingConfig2d};
+use crate::tensor::activation::gelu;
+use crate::tensor::{Tensor, backend::Backend};
+
+use super::{Conv2d, Conv2dConfig};
+
+/// The size of the kernel of the depthwise convolution of a [ConvNeXt block](ConvNeXtBlock).
+pub const CONVNEXT_KERNEL_SIZE: usize = 7;
+
+/// The factor by which the feed-forward layer of a [ConvNeXt block](ConvNeXtBlock) widens the
+/// channels.
+pub const CONVNEXT_EXPANSION: usize = 4;
+
+/// Configuration to create a [ConvNeXt block](ConvNeXtBlock) using the [init function](ConvNeXtBlockConfig::init).
+#[derive(Config, Debug)]
+pub struct ConvNeXtBlockConfig {
+    /// The number of channels of the input and output.
+    pub dim: usize,
+    /// The initial value of the layer scale. The layer scale is disabled when it isn't positive.
+    #[config(default = 1e-6)]
+    pub layer_scale_init: f64,
+    /// A value required for numerical stability of the layer norm.
+    #[config(default = 1e-6)]
+    pub epsilon: f64,
+}
+
+/// The residual block of [A ConvNet for the 2020s](https://arxiv.org/abs/2201.03545) (ConvNeXt).
+///
+/// The input goes through a `7x7` depthwise convolution, a layer norm over the channels and a
+/// feed-forward layer (`Linear -> GELU -> Linear`) four times wider than the input, whose output is
+/// scaled per channel by a learnable layer scale before being added back to the input.
+///
+/// Should be created using [ConvNeXtBlockConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct ConvNeXtBlock<B: Backend> {
+    /// The depthwise convolution mixing the spatial information of each channel.
+    pub dwconv: Conv2d<B>,
+    /// The layer norm over the channels.
+    pub norm: LayerNorm<B>,
+    /// The linear layer expanding the channels (pointwise convolution).
+    pub pwconv1: Linear<B>,
+    /// The linear layer projecting the channels back (pointwise convolution).
+    pub pwconv2: Linear<B>,
+    /// The learnable scale of each channel of shape `[dim]`, if enabled.
+    pub gamma: Option<Param<Tensor<B, 1>>>,
+}
+
+impl<B: Backend> ModuleDisplay for ConvNeXtBlock<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [dim, _] = self.pwconv1.weight.shape().dims();
+        content
+            .add("dim", &dim)
+            .add("layer_scale", &self.gamma.is_some())
+            .optional()
+    }
+}
+
+impl ConvNeXtBlockConfig {
+    /// Initialize a new [ConvNeXt block](ConvNeXtBlock) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> ConvNeXtBlock<B> {
+        let padding = CONVNEXT_KERNEL_SIZE / 2;
+        let d_hidden = CONVNEXT_EXPANSION * self.dim;
+
+        let gamma = (self.layer_scale_init > 0.0).then(|| {
+            Initializer::Constant {
+                value: self.layer_scale_init,
+            }
+            .init([self.dim], device)
+        });
+
+        ConvNeXtBlock {
+            dwconv: Conv2dConfig::new(
+                [self.dim, self.dim],
+                [CONVNEXT_KERNEL_SIZE, CONVNEXT_KERNEL_SIZE],
+            )
+            .with_groups(self.dim)
+            .with_padding(PaddingConfig2d::Explicit(padding, padding))
+            .init(device),
+            norm: LayerNormConfig::new(self.dim)
+                .with_epsilon(self.epsilon)
+                .init(device),
+            pwconv1: LinearConfig::new(self.dim, d_hidden).init(device),
+            pwconv2: LinearConfig::new(d_hidden, self.dim).init(device),
+            gamma,
+        }
+    }
+}
+
+impl<B: Backend> ConvNeXtBlock<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [ConvNeXtBlock](ConvNeXtBlock) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, dim, height, width]`
+    /// - output: `[batch_size, dim, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.dwconv.forward(input.clone());
+
+        // The layer norm and the feed-forward layer work on the channels last
+        let x = x.permute([0, 2, 3, 1]);
+        let x = self.norm.forward(x);
+        let x = self.pwconv1.forward(x);
+        let x = gelu(x);
+        let x = self.pwconv2.forward(x);
+        let x = match &self.gamma {
+            Some(gamma) => x.mul(gamma.val().unsqueeze()),
+            None => x,
+        };
+        let x = x.permute([0, 3, 1, 2]);
+
+        input.add(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::{Distribution, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn output_shape_matches_input_shape() {
+        let device = Default::default();
+        let block = ConvNeXtBlockConfig::new(8).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 8, 5, 6], Distribution::Default, &device);
+
+        let output = block.forward(input);
+
+        assert_eq!(output.dims(), [2, 8, 5, 6]);
+    }
+
+    #[test]
+    fn zero_layer_scale_is_identity() {
+        let device = Default::default();
+        let mut block = ConvNeXtBlockConfig::new(4).init::<TestBackend>(&device);
+        block.gamma = block
+            .gamma
+            .map(|gamma| gamma.map(|gamma| gamma.zeros_like()));
+        let input = Tensor::<TestBackend, 4>::random([1, 4, 3, 3], Distribution::Default, &device);
+
+        let output = block.forward(input.clone());
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&input.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn layer_scale_disabled() {
+        let config = ConvNeXtBlockConfig::new(4).with_layer_scale_init(0.0);
+        let block = config.init::<TestBackend>(&Default::default());
+
+        assert!(block.gamma.is_none());
+    }
+
+    #[test]
+    fn display() {
+        let config = ConvNeXtBlockConfig::new(4);
+        let block = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", block),
+            "ConvNeXtBlock {dim: 4, layer_scale: true, params: 360}"
+        );
+    }
+}
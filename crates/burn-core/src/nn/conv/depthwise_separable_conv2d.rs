@@ -0,0 +1,221 @@
+use alloc::format;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::{Initializer, PaddingConfig2d};
+use crate::tensor::{Tensor, backend::Backend};
+
+use super::{Conv2d, Conv2dConfig};
+
+/// Configuration to create a [depthwise separable 2D convolution](DepthwiseSeparableConv2d) layer,
+/// using the [init function](DepthwiseSeparableConv2dConfig::init).
+#[derive(Config, Debug)]
+pub struct DepthwiseSeparableConv2dConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The size of the kernel of the depthwise convolution.
+    pub kernel_size: [usize; 2],
+    /// The stride of the depthwise convolution.
+    #[config(default = "[1, 1]")]
+    pub stride: [usize; 2],
+    /// Spacing between kernel elements of the depthwise convolution.
+    #[config(default = "[1, 1]")]
+    pub dilation: [usize; 2],
+    /// The padding configuration of the depthwise convolution.
+    ///
+    /// ### Warning
+    /// Only symmetric padding is currently supported. As such, using `Same` padding with an even kernel
+    /// size is not supported as it will not produce the same output size.
+    #[config(default = "PaddingConfig2d::Valid")]
+    pub padding: PaddingConfig2d,
+    /// If bias should be added to the output of both convolutions.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0),fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Applies a depthwise separable 2D convolution over input tensors, as used by MobileNet,
+/// EfficientNet and ShuffleNet.
+///
+/// A depthwise convolution applies a kernel to each input channel separately (`groups` being the
+/// number of input channels), followed by a pointwise `1x1` convolution mixing the channels. This
+/// needs far fewer parameters and operations than a regular convolution with the same kernel.
+///
+/// Should be created with [DepthwiseSeparableConv2dConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct DepthwiseSeparableConv2d<B: Backend> {
+    /// The depthwise convolution applying a kernel to each input channel.
+    pub depthwise: Conv2d<B>,
+    /// The pointwise convolution mixing the channels.
+    pub pointwise: Conv2d<B>,
+}
+
+impl DepthwiseSeparableConv2dConfig {
+    /// Initialize a new [depthwise separable conv2d](DepthwiseSeparableConv2d) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> DepthwiseSeparableConv2d<B> {
+        let [channels_in, channels_out] = self.channels;
+
+        DepthwiseSeparableConv2d {
+            depthwise: Conv2dConfig::new([channels_in, channels_in], self.kernel_size)
+                .with_stride(self.stride)
+                .with_dilation(self.dilation)
+                .with_groups(channels_in)
+                .with_padding(self.padding.clone())
+                .with_bias(self.bias)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+            pointwise: Conv2dConfig::new([channels_in, channels_out], [1, 1])
+                .with_bias(self.bias)
+                .with_initializer(self.initializer.clone())
+                .init(device),
+        }
+    }
+}
+
+impl<B: Backend> ModuleDisplay for DepthwiseSeparableConv2d<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [channels_out, channels_in, _, _] = self.pointwise.weight.shape().dims();
+
+        // Since padding does not implement ModuleDisplay, we need to format it manually.
+        let padding_formatted = format!("{}", &self.depthwise.padding);
+
+        // Format the channels, stride, kernel_size and dilation as strings, formatted as arrays instead of indexed.
+        let channels = format!("{:?}", [channels_in, channels_out]);
+        let stride = format!("{:?}", self.depthwise.stride);
+        let kernel_size = format!("{:?}", self.depthwise.kernel_size);
+        let dilation = format!("{:?}", self.depthwise.dilation);
+
+        content
+            .add("channels", &channels)
+            .add("stride", &stride)
+            .add("kernel_size", &kernel_size)
+            .add("dilation", &dilation)
+            .add("padding", &padding_formatted)
+            .optional()
+    }
+}
+
+impl<B: Backend> DepthwiseSeparableConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [DepthwiseSeparableConv2d](DepthwiseSeparableConv2d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.depthwise.forward(input);
+        self.pointwise.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::{
+        Distribution, TensorData, Tolerance,
+        module::conv2d,
+        ops::{ConvOptions, FloatElem},
+    };
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn output_shape() {
+        let device = Default::default();
+        let conv = DepthwiseSeparableConv2dConfig::new([4, 6], [3, 3])
+            .with_stride([2, 2])
+            .with_padding(PaddingConfig2d::Explicit(1, 1))
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 4, 8, 10], Distribution::Default, &device);
+
+        let output = conv.forward(input);
+
+        assert_eq!(output.dims(), [2, 6, 4, 5]);
+    }
+
+    #[test]
+    fn parameters_shapes() {
+        let conv = DepthwiseSeparableConv2dConfig::new([4, 6], [3, 5])
+            .init::<TestBackend>(&Default::default());
+
+        assert_eq!(conv.depthwise.weight.dims(), [4, 1, 3, 5]);
+        assert_eq!(conv.depthwise.groups, 4);
+        assert_eq!(conv.pointwise.weight.dims(), [6, 4, 1, 1]);
+        // 4 * 3 * 5 + 4 depthwise and 6 * 4 + 6 pointwise parameters
+        assert_eq!(conv.num_params(), 64 + 30);
+    }
+
+    #[test]
+    fn forward_should_match_regular_convolution() {
+        let device = Default::default();
+        let conv = DepthwiseSeparableConv2dConfig::new([2, 3], [3, 3])
+            .with_padding(PaddingConfig2d::Explicit(1, 1))
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([1, 2, 5, 5], Distribution::Default, &device);
+
+        let output = conv.forward(input.clone());
+
+        // Without bias, the two convolutions are a regular convolution whose kernel mixing input
+        // channel `c` into output channel `o` is the depthwise kernel of `c` scaled by the pointwise
+        // weight of `(o, c)`.
+        let depthwise = conv.depthwise.weight.val().reshape([1, 2, 3, 3]);
+        let pointwise = conv.pointwise.weight.val().reshape([3, 2, 1, 1]);
+        let weight = depthwise * pointwise;
+        let expected = conv2d(
+            input,
+            weight,
+            None,
+            ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+        );
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+    }
+
+    #[test]
+    fn forward_should_match_reference() {
+        let device = Default::default();
+        let conv = DepthwiseSeparableConv2dConfig::new([2, 1], [2, 2])
+            .with_initializer(Initializer::Ones)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::from_floats(
+            [[[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]],
+            &device,
+        );
+
+        let output = conv.forward(input);
+
+        // Each channel is summed (10 + 1 and 26 + 1), then the channels are summed (37 + 1)
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[[[39.0f32]]]]), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = DepthwiseSeparableConv2dConfig::new([4, 6], [3, 3]);
+        let conv = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", conv),
+            "DepthwiseSeparableConv2d {channels: [4, 6], stride: [1, 1], kernel_size: [3, 3], \
+             dilation: [1, 1], padding: Valid, params: 70}"
+        );
+    }
+}
@@ -1,17 +1,27 @@
+mod causal_conv1d;
 mod conv1d;
 mod conv2d;
 mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod conv_transpose3d;
+mod convnext_block;
 mod deform_conv2d;
+mod depthwise_separable_conv2d;
+mod naf_block;
+mod sparse_conv2d;
 
 pub(crate) mod checks;
 
+pub use causal_conv1d::*;
 pub use conv_transpose1d::*;
 pub use conv_transpose2d::*;
 pub use conv_transpose3d::*;
 pub use conv1d::*;
 pub use conv2d::*;
 pub use conv3d::*;
+pub use convnext_block::*;
 pub use deform_conv2d::*;
+pub use depthwise_separable_conv2d::*;
+pub use naf_block::*;
+pub use sparse_conv2d::*;
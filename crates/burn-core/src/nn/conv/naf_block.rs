@@ -0,0 +1,287 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::{Initializer, PaddingConfig2d};
+use crate::tensor::{Tensor, backend::Backend};
+
+use super::{Conv2d, Conv2dConfig};
+
+/// Configuration to create a [NAFBlock](NAFBlock) using the [init function](NAFBlockConfig::init).
+#[derive(Config, Debug)]
+pub struct NAFBlockConfig {
+    /// The number of channels of the input and output.
+    pub width: usize,
+    /// The factor by which the depthwise convolution widens the channels. Default: 2
+    #[config(default = 2)]
+    pub dw_expand: usize,
+    /// The factor by which the feed-forward layer widens the channels. Default: 2
+    #[config(default = 2)]
+    pub ffn_expand: usize,
+    /// A value required for numerical stability of the layer norms.
+    #[config(default = 1e-6)]
+    pub epsilon: f64,
+}
+
+/// The block of [Simple Baselines for Image Restoration](https://arxiv.org/abs/2204.04676)
+/// (NAFNet), a Nonlinear Activation Free network.
+///
+/// The block is made of two residual branches whose outputs are scaled per channel by learnable
+/// factors initialized to zero, so that the block is the identity at initialization:
+///
+/// - A layer norm over the channels, a pointwise convolution widening the channels by
+///   `dw_expand`, a `3x3` depthwise convolution, a simple gate, a simplified channel attention
+///   and a pointwise convolution back to `width` channels.
+/// - A layer norm over the channels, a pointwise convolution widening the channels by
+///   `ffn_expand`, a simple gate and a pointwise convolution back to `width` channels.
+///
+/// The simple gate replaces the activation functions: it splits the channels in two halves and
+/// multiplies them, which also halves the input channels of the next convolution. The layer norms
+/// only have a learnable weight, without bias.
+///
+/// Should be created using [NAFBlockConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct NAFBlock<B: Backend> {
+    /// The pointwise convolution widening the channels of the first branch.
+    pub conv1: Conv2d<B>,
+    /// The depthwise convolution mixing the spatial information of each channel.
+    pub conv2: Conv2d<B>,
+    /// The pointwise convolution projecting the channels of the first branch back.
+    pub conv3: Conv2d<B>,
+    /// The pointwise convolution of the simplified channel attention, applied to the channels
+    /// averaged over the spatial dimensions.
+    pub sca: Conv2d<B>,
+    /// The pointwise convolution widening the channels of the feed-forward branch.
+    pub conv4: Conv2d<B>,
+    /// The pointwise convolution projecting the channels of the feed-forward branch back.
+    pub conv5: Conv2d<B>,
+    /// The learnable weight of the layer norm of the first branch of shape `[width]`.
+    pub norm1: Param<Tensor<B, 1>>,
+    /// The learnable weight of the layer norm of the feed-forward branch of shape `[width]`.
+    pub norm2: Param<Tensor<B, 1>>,
+    /// The learnable scale of each channel of the first branch of shape `[width]`.
+    pub beta: Param<Tensor<B, 1>>,
+    /// The learnable scale of each channel of the feed-forward branch of shape `[width]`.
+    pub gamma: Param<Tensor<B, 1>>,
+    /// A value required for numerical stability of the layer norms.
+    pub epsilon: f64,
+}
+
+impl<B: Backend> ModuleDisplay for NAFBlock<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [dw_channels, width, _, _] = self.conv1.weight.shape().dims();
+        let [ffn_channels, _, _, _] = self.conv4.weight.shape().dims();
+        content
+            .add("width", &width)
+            .add("dw_expand", &(dw_channels / width))
+            .add("ffn_expand", &(ffn_channels / width))
+            .optional()
+    }
+}
+
+impl NAFBlockConfig {
+    /// Initialize a new [NAFBlock](NAFBlock) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widened channels can't be split in two halves by the simple gates.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> NAFBlock<B> {
+        let dw_channels = self.dw_expand * self.width;
+        let ffn_channels = self.ffn_expand * self.width;
+        assert!(
+            dw_channels % 2 == 0 && ffn_channels % 2 == 0,
+            "The widened channels of the NAF block should be even, but got {dw_channels} and {ffn_channels}"
+        );
+
+        let pointwise =
+            |channels_in, channels_out| Conv2dConfig::new([channels_in, channels_out], [1, 1]);
+
+        NAFBlock {
+            conv1: pointwise(self.width, dw_channels).init(device),
+            conv2: Conv2dConfig::new([dw_channels, dw_channels], [3, 3])
+                .with_groups(dw_channels)
+                .with_padding(PaddingConfig2d::Explicit(1, 1))
+                .init(device),
+            conv3: pointwise(dw_channels / 2, self.width).init(device),
+            sca: pointwise(dw_channels / 2, dw_channels / 2).init(device),
+            conv4: pointwise(self.width, ffn_channels).init(device),
+            conv5: pointwise(ffn_channels / 2, self.width).init(device),
+            norm1: Initializer::Ones.init([self.width], device),
+            norm2: Initializer::Ones.init([self.width], device),
+            beta: Initializer::Zeros.init([self.width], device),
+            gamma: Initializer::Zeros.init([self.width], device),
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> NAFBlock<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [NAFBlock](NAFBlock) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height, width]`, with `channels` the configured width
+    /// - output: `[batch_size, channels, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.layer_norm(input.clone(), &self.norm1);
+        let x = self.conv1.forward(x);
+        let x = self.conv2.forward(x);
+        let x = simple_gate(x);
+        let attention = self.sca.forward(x.clone().mean_dim(2).mean_dim(3));
+        let x = self.conv3.forward(x * attention);
+        let y = input + x * self.beta.val().reshape([1, -1, 1, 1]);
+
+        let x = self.layer_norm(y.clone(), &self.norm2);
+        let x = self.conv4.forward(x);
+        let x = simple_gate(x);
+        let x = self.conv5.forward(x);
+
+        y + x * self.gamma.val().reshape([1, -1, 1, 1])
+    }
+
+    /// The number of floating point operations of the forward pass for an input of the given
+    /// spatial size, counting the multiply-accumulate operations of the convolutions and the
+    /// multiplications of the gates and the channel attention.
+    pub fn num_flops(&self, height: usize, width: usize) -> usize {
+        let num_pixels = height * width;
+        let conv_flops = |conv: &Conv2d<B>| conv.weight.shape().num_elements() * num_pixels;
+        let [dw_channels, _, _, _] = self.conv1.weight.dims();
+        let [ffn_channels, _, _, _] = self.conv4.weight.dims();
+
+        let convs = [
+            &self.conv1,
+            &self.conv2,
+            &self.conv3,
+            &self.conv4,
+            &self.conv5,
+        ]
+        .map(conv_flops)
+        .iter()
+        .sum::<usize>();
+        // The attention is computed on the averaged channels
+        let attention = self.sca.weight.shape().num_elements() + dw_channels / 2 * num_pixels;
+        let gates = (dw_channels + ffn_channels) / 2 * num_pixels;
+
+        convs + attention + gates
+    }
+
+    /// Normalizes the channels of each pixel, scaled by the given weight.
+    fn layer_norm(&self, input: Tensor<B, 4>, weight: &Param<Tensor<B, 1>>) -> Tensor<B, 4> {
+        let (var, mean) = input.clone().var_mean_bias(1);
+        let input_normalized = input.sub(mean).div(var.add_scalar(self.epsilon).sqrt());
+
+        input_normalized.mul(weight.val().reshape([1, -1, 1, 1]))
+    }
+}
+
+/// Splits the channels in two halves and multiplies them.
+fn simple_gate<B: Backend>(input: Tensor<B, 4>) -> Tensor<B, 4> {
+    let [x1, x2] = input.chunk(2, 1).try_into().unwrap();
+
+    x1 * x2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::{Distribution, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn output_shape_matches_input_shape() {
+        let device = Default::default();
+        let block = NAFBlockConfig::new(8).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 8, 5, 6], Distribution::Default, &device);
+
+        let output = block.forward(input);
+
+        assert_eq!(output.dims(), [2, 8, 5, 6]);
+    }
+
+    #[test]
+    fn initialized_block_is_identity() {
+        let device = Default::default();
+        let block = NAFBlockConfig::new(4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([1, 4, 3, 3], Distribution::Default, &device);
+
+        let output = block.forward(input.clone());
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&input.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn num_flops() {
+        let device = Default::default();
+        let block = NAFBlockConfig::new(4).init::<TestBackend>(&device);
+
+        // Convolutions: (32 + 72 + 16 + 32 + 16) weights for each of the 4 pixels, attention: 16
+        // weights and 4 channels for each pixel, gates: 4 + 4 channels for each pixel
+        assert_eq!(block.num_flops(2, 2), 672 + 32 + 32);
+    }
+
+    #[test]
+    fn fewer_flops_than_gelu_block() {
+        let device = Default::default();
+        let (width, height, image_width) = (16, 32, 24);
+        let config = NAFBlockConfig::new(width);
+        let block = config.init::<TestBackend>(&device);
+        let (dw_channels, ffn_channels) = (config.dw_expand * width, config.ffn_expand * width);
+
+        // The convolutions of the equivalent block with GELU activations instead of the simple
+        // gates, which keep all the channels
+        let pointwise = |channels_in, channels_out| {
+            Conv2dConfig::new([channels_in, channels_out], [1, 1]).init::<TestBackend>(&device)
+        };
+        let depthwise = Conv2dConfig::new([dw_channels, dw_channels], [3, 3])
+            .with_groups(dw_channels)
+            .init::<TestBackend>(&device);
+        let convs = [
+            pointwise(width, dw_channels),
+            depthwise,
+            pointwise(dw_channels, width),
+            pointwise(width, ffn_channels),
+            pointwise(ffn_channels, width),
+        ];
+        let sca = pointwise(dw_channels, dw_channels);
+
+        // Only the convolutions are counted, not the activations and the attention products
+        let num_pixels = height * image_width;
+        let gelu_conv_flops = convs
+            .iter()
+            .map(|conv| conv.weight.shape().num_elements() * num_pixels)
+            .sum::<usize>()
+            + sca.weight.shape().num_elements();
+
+        assert!(block.num_flops(height, image_width) < gelu_conv_flops);
+    }
+
+    #[test]
+    #[should_panic = "The widened channels of the NAF block should be even"]
+    fn odd_widened_channels() {
+        let config = NAFBlockConfig::new(3).with_dw_expand(1);
+        let _block = config.init::<TestBackend>(&Default::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = NAFBlockConfig::new(4);
+        let block = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", block),
+            "NAFBlock {width: 4, dw_expand: 2, ffn_expand: 2, params: 236}"
+        );
+    }
+}
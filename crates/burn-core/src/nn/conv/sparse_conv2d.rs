@@ -0,0 +1,377 @@
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::Initializer;
+use crate::tensor::Int;
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+
+/// Configuration to create a [sparse 2D convolution](SparseConv2d) layer, using the [init function](SparseConv2dConfig::init).
+#[derive(Config, Debug)]
+pub struct SparseConv2dConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The size of the kernel.
+    ///
+    /// ### Warning
+    /// The kernel is centered on each active location, so only odd kernel sizes are supported.
+    pub kernel_size: [usize; 2],
+    /// Spacing between kernel elements.
+    #[config(default = "[1, 1]")]
+    pub dilation: [usize; 2],
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0),fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Applies a 2D convolution over sparse inputs.
+///
+/// The input is given as a list of active locations along with their features. The output is
+/// only computed at those same locations (a submanifold convolution), so the sparsity pattern is
+/// preserved. The result at each active location is identical to a dense [Conv2d](crate::nn::conv::Conv2d)
+/// with a stride of one and symmetric padding, applied to an input that is zero everywhere else.
+///
+/// The features of neighboring locations are gathered for each kernel offset, then combined with
+/// a single matrix multiplication, which avoids materializing the dense input.
+///
+/// Should be created with [SparseConv2dConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct SparseConv2d<B: Backend> {
+    /// Tensor of shape `[channels_out, channels_in, kernel_size_1, kernel_size_2]`
+    pub weight: Param<Tensor<B, 4>>,
+    /// Tensor of shape `[channels_out]`
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// Size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// Spacing between kernel elements.
+    pub dilation: [usize; 2],
+}
+
+impl SparseConv2dConfig {
+    /// Initialize a new [sparse conv2d](SparseConv2d) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SparseConv2d<B> {
+        assert!(
+            self.kernel_size.iter().all(|size| size % 2 == 1),
+            "Sparse convolution only supports odd kernel sizes, got {:?}",
+            self.kernel_size
+        );
+
+        let shape = [
+            self.channels[1],
+            self.channels[0],
+            self.kernel_size[0],
+            self.kernel_size[1],
+        ];
+
+        let k = self.kernel_size.iter().product::<usize>();
+        let fan_in = self.channels[0] * k;
+        let fan_out = self.channels[1] * k;
+
+        let weight = self
+            .initializer
+            .init_with(shape, Some(fan_in), Some(fan_out), device);
+        let mut bias = None;
+
+        if self.bias {
+            bias = Some(self.initializer.init_with(
+                [self.channels[1]],
+                Some(fan_in),
+                Some(fan_out),
+                device,
+            ));
+        }
+
+        SparseConv2d {
+            weight,
+            bias,
+            kernel_size: self.kernel_size,
+            dilation: self.dilation,
+        }
+    }
+}
+
+impl<B: Backend> ModuleDisplay for SparseConv2d<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        // Format the kernel_size and dilation as strings, formatted as arrays instead of indexed.
+        let kernel_size = format!("{:?}", self.kernel_size);
+        let dilation = format!("{:?}", self.dilation);
+
+        content
+            .add("kernel_size", &kernel_size)
+            .add("dilation", &dilation)
+            .optional()
+    }
+}
+
+impl<B: Backend> SparseConv2d<B> {
+    /// Applies the forward pass on the active locations.
+    ///
+    /// Each row of `coordinates` holds the `[batch, height, width]` position of an active location,
+    /// and the matching row of `features` holds its input channels. Coordinates are expected to be
+    /// unique and non-negative.
+    ///
+    /// # Shapes
+    ///
+    /// - coordinates: `[num_active, 3]`
+    /// - features: `[num_active, channels_in]`
+    /// - output: `[num_active, channels_out]`
+    pub fn forward(&self, coordinates: Tensor<B, 2, Int>, features: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [num_active, channels_in] = features.dims();
+        let [channels_out, _channels_in, kernel_height, kernel_width] = self.weight.dims();
+        let device = features.device();
+
+        if num_active == 0 {
+            return Tensor::empty([0, channels_out], &device);
+        }
+
+        let padding_height = self.dilation[0] * (kernel_height - 1) / 2;
+        let padding_width = self.dilation[1] * (kernel_width - 1) / 2;
+
+        // The lookup grid is padded so that every neighbor offset stays inside its own row and batch.
+        let extents = coordinates
+            .clone()
+            .max_dim(0)
+            .into_data()
+            .iter::<i64>()
+            .collect::<Vec<_>>();
+        let batch_size = extents[0] as usize + 1;
+        let height = extents[1] as usize + 1 + 2 * padding_height;
+        let width = extents[2] as usize + 1 + 2 * padding_width;
+
+        let batch = coordinates.clone().narrow(1, 0, 1);
+        let row = coordinates.clone().narrow(1, 1, 1) + padding_height as i64;
+        let col = coordinates.narrow(1, 2, 1) + padding_width as i64;
+        let positions =
+            (batch * (height * width) as i64 + row * width as i64 + col).reshape([num_active]);
+
+        // Maps each grid position to one plus the index of its active location, or zero when inactive.
+        let grid = Tensor::<B, 1, Int>::zeros([batch_size * height * width], &device).scatter(
+            0,
+            positions.clone(),
+            Tensor::arange(1..num_active as i64 + 1, &device),
+        );
+
+        // The first row holds the (zero) features of every inactive location.
+        let features = Tensor::cat(
+            alloc::vec![Tensor::zeros([1, channels_in], &device), features],
+            0,
+        );
+
+        let mut gathered = Vec::with_capacity(kernel_height * kernel_width);
+        for kh in 0..kernel_height {
+            for kw in 0..kernel_width {
+                let offset = (kh * self.dilation[0]) as i64 - padding_height as i64;
+                let offset =
+                    offset * width as i64 + (kw * self.dilation[1]) as i64 - padding_width as i64;
+                let neighbors = grid.clone().select(0, positions.clone() + offset);
+
+                gathered.push(features.clone().select(0, neighbors));
+            }
+        }
+
+        // [num_active, kernel_height * kernel_width * channels_in]
+        let gathered = Tensor::cat(gathered, 1);
+        let weight = self
+            .weight
+            .val()
+            .permute([2, 3, 1, 0])
+            .reshape([kernel_height * kernel_width * channels_in, channels_out]);
+        let output = gathered.matmul(weight);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_tensor::Tolerance;
+    use burn_tensor::ops::FloatElem;
+
+    use super::*;
+    use crate::TestBackend;
+    use crate::nn::PaddingConfig2d;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::tensor::{Bool, Distribution, TensorData};
+    type FT = FloatElem<TestBackend>;
+
+    const COORDINATES: [[i64; 3]; 6] = [
+        [0, 0, 0],
+        [0, 1, 1],
+        [0, 1, 2],
+        [0, 3, 4],
+        [1, 2, 2],
+        [1, 4, 0],
+    ];
+    const SHAPE: [usize; 3] = [2, 5, 5];
+
+    fn coordinates() -> Tensor<TestBackend, 2, Int> {
+        Tensor::from_data(COORDINATES, &Default::default())
+    }
+
+    /// Scatters `[num_active, channels]` features into a dense `[batch, channels, height, width]` tensor.
+    fn to_dense(features: Tensor<TestBackend, 2>) -> Tensor<TestBackend, 4> {
+        let [_, channels] = features.dims();
+        let [batch_size, height, width] = SHAPE;
+        let values = features.into_data().iter::<f32>().collect::<Vec<_>>();
+        let mut dense = alloc::vec![0.0f32; batch_size * channels * height * width];
+
+        for (i, [b, h, w]) in COORDINATES.iter().enumerate() {
+            for c in 0..channels {
+                let index = ((*b as usize * channels + c) * height + *h as usize) * width;
+                dense[index + *w as usize] = values[i * channels + c];
+            }
+        }
+
+        Tensor::from_data(
+            TensorData::new(dense, [batch_size, channels, height, width]),
+            &Default::default(),
+        )
+    }
+
+    fn is_active(b: usize, h: usize, w: usize) -> bool {
+        COORDINATES
+            .iter()
+            .any(|c| c == &[b as i64, h as i64, w as i64])
+    }
+
+    fn assert_matches_dense(config: SparseConv2dConfig) {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let sparse = config.init::<TestBackend>(&device);
+        let padding = [
+            config.dilation[0] * (config.kernel_size[0] - 1) / 2,
+            config.dilation[1] * (config.kernel_size[1] - 1) / 2,
+        ];
+        let mut dense = Conv2dConfig::new(config.channels, config.kernel_size)
+            .with_dilation(config.dilation)
+            .with_padding(PaddingConfig2d::Explicit(padding[0], padding[1]))
+            .init::<TestBackend>(&device);
+        dense.weight = sparse.weight.clone();
+        dense.bias = sparse.bias.clone();
+
+        let features = Tensor::<TestBackend, 2>::random(
+            [COORDINATES.len(), config.channels[0]],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = sparse.forward(coordinates(), features.clone());
+        let expected = dense.forward(to_dense(features));
+        let dense_shape = expected.shape();
+        let [batch_size, height, width] = SHAPE;
+        let mask = Tensor::<TestBackend, 4, Bool>::from_data(
+            TensorData::new(
+                (0..batch_size * height * width)
+                    .map(|i| is_active(i / (height * width), i / width % height, i % width))
+                    .collect::<Vec<_>>(),
+                [batch_size, 1, height, width],
+            ),
+            &device,
+        );
+        let expected = expected.mask_fill(mask.bool_not().expand(dense_shape), 0.0);
+
+        to_dense(output)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+    }
+
+    #[test]
+    fn active_locations_match_dense() {
+        assert_matches_dense(SparseConv2dConfig::new([3, 4], [3, 3]));
+    }
+
+    #[test]
+    fn active_locations_match_dense_with_dilation() {
+        assert_matches_dense(SparseConv2dConfig::new([2, 3], [3, 5]).with_dilation([2, 1]));
+    }
+
+    #[test]
+    fn inactive_locations_are_not_computed() {
+        let device = Default::default();
+        let initializer = Initializer::Constant { value: 1.0 };
+        let sparse = SparseConv2dConfig::new([2, 3], [3, 3])
+            .with_bias(false)
+            .with_initializer(initializer.clone())
+            .init::<TestBackend>(&device);
+        let dense = Conv2dConfig::new([2, 3], [3, 3])
+            .with_padding(PaddingConfig2d::Explicit(1, 1))
+            .with_bias(false)
+            .with_initializer(initializer)
+            .init::<TestBackend>(&device);
+        let features = Tensor::<TestBackend, 2>::ones([COORDINATES.len(), 2], &device);
+
+        // The sparse convolution only yields one row per active location.
+        let output = sparse.forward(coordinates(), features.clone());
+        assert_eq!(output.dims(), [COORDINATES.len(), 3]);
+        let output = to_dense(output)
+            .into_data()
+            .iter::<f32>()
+            .collect::<Vec<_>>();
+
+        // The dense convolution spreads the features to the inactive neighbors of the active
+        // locations, where the sparse one leaves the output empty.
+        let expected = dense.forward(to_dense(features));
+        let expected = expected.into_data().iter::<f32>().collect::<Vec<_>>();
+        let [batch_size, height, width] = SHAPE;
+        let mut spread = 0;
+        for b in 0..batch_size {
+            for c in 0..3 {
+                for h in 0..height {
+                    for w in 0..width {
+                        let index = ((b * 3 + c) * height + h) * width + w;
+                        if is_active(b, h, w) {
+                            assert_eq!(output[index], expected[index]);
+                        } else if expected[index] != 0.0 {
+                            spread += 1;
+                            assert_eq!(
+                                output[index], 0.0,
+                                "The inactive location ({b}, {h}, {w}) should not be computed"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        assert!(
+            spread > 0,
+            "The dense convolution should reach inactive locations"
+        );
+    }
+
+    #[test]
+    #[should_panic = "Sparse convolution only supports odd kernel sizes"]
+    fn even_kernel_is_invalid() {
+        let device = Default::default();
+        let _ = SparseConv2dConfig::new([2, 2], [2, 3]).init::<TestBackend>(&device);
+    }
+
+    #[test]
+    fn display() {
+        let config = SparseConv2dConfig::new([5, 1], [3, 3]);
+        let conv = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", conv),
+            "SparseConv2d {kernel_size: [3, 3], dilation: [1, 1], params: 46}"
+        );
+    }
+}
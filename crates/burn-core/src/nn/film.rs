@@ -0,0 +1,187 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::{Tensor, backend::Backend};
+
+use super::{Initializer, Linear, LinearConfig};
+
+/// Configuration to create a [FiLM](FiLM) layer using the [init function](FiLMConfig::init).
+#[derive(Config, Debug)]
+pub struct FiLMConfig {
+    /// The size of the modulated features.
+    pub feature_dim: usize,
+    /// The size of the conditioning features.
+    pub condition_dim: usize,
+    /// If the projections are initialized to the identity modulation, with `gamma = 1` and
+    /// `beta = 0` whatever the condition. Default: false
+    #[config(default = false)]
+    pub identity_init: bool,
+    /// The type of function used to initialize the projections, unless `identity_init` is set.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Applies Feature-wise Linear Modulation, as described in the paper
+/// [FiLM: Visual Reasoning with a General Conditioning Layer](https://arxiv.org/abs/1709.07871).
+///
+/// `FiLM(x, c) = gamma(c) * x + beta(c)`, where the scale `gamma` and the shift `beta` of each
+/// feature are linear projections of the conditioning input `c`.
+///
+/// Should be created with [FiLMConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct FiLM<B: Backend> {
+    /// The projection of the condition to the scale of each feature.
+    pub gamma: Linear<B>,
+    /// The projection of the condition to the shift of each feature.
+    pub beta: Linear<B>,
+}
+
+impl<B: Backend> ModuleDisplay for FiLM<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [condition_dim, feature_dim] = self.gamma.weight.shape().dims();
+        content
+            .add("feature_dim", &feature_dim)
+            .add("condition_dim", &condition_dim)
+            .optional()
+    }
+}
+
+impl FiLMConfig {
+    /// Initialize a new [FiLM](FiLM) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> FiLM<B> {
+        if !self.identity_init {
+            let projection = || {
+                LinearConfig::new(self.condition_dim, self.feature_dim)
+                    .with_initializer(self.initializer.clone())
+                    .init(device)
+            };
+            return FiLM {
+                gamma: projection(),
+                beta: projection(),
+            };
+        }
+
+        // Zero weights, the modulation only comes from the biases
+        let projection = LinearConfig::new(self.condition_dim, self.feature_dim)
+            .with_initializer(Initializer::Zeros);
+        let mut gamma = projection.init(device);
+        gamma.bias = Some(Initializer::Ones.init([self.feature_dim], device));
+
+        FiLM {
+            gamma,
+            beta: projection.init(device),
+        }
+    }
+}
+
+impl<B: Backend> FiLM<B> {
+    /// Modulates the features with the scale and shift projected from the condition.
+    ///
+    /// # Shapes
+    ///
+    /// - features: `[batch_size, feature_dim, *]`, e.g. `[batch_size, channels, height, width]`
+    ///   for feature maps
+    /// - condition: `[batch_size, condition_dim]`
+    /// - output: `[batch_size, feature_dim, *]`
+    pub fn forward<const D: usize>(
+        &self,
+        features: Tensor<B, D>,
+        condition: Tensor<B, 2>,
+    ) -> Tensor<B, D> {
+        let dims = features.dims();
+        // The modulation of each feature is broadcast over the other dimensions
+        let mut shape = [1; D];
+        shape[0] = dims[0];
+        shape[1] = dims[1];
+
+        let gamma = self.gamma.forward(condition.clone()).reshape(shape);
+        let beta = self.beta.forward(condition).reshape(shape);
+
+        gamma * features + beta
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::{Distribution, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn identity_init_returns_the_features() {
+        let device = Default::default();
+        let film = FiLMConfig::new(4, 3)
+            .with_identity_init(true)
+            .init::<TestBackend>(&device);
+        let features =
+            Tensor::<TestBackend, 4>::random([2, 4, 5, 5], Distribution::Default, &device);
+        let condition = Tensor::<TestBackend, 2>::random([2, 3], Distribution::Default, &device);
+
+        let output = film.forward(features.clone(), condition);
+
+        output.into_data().assert_eq(&features.into_data(), true);
+    }
+
+    #[test]
+    fn modulation_is_per_sample_and_feature() {
+        let device = Default::default();
+        let mut film = FiLMConfig::new(2, 1)
+            .with_identity_init(true)
+            .init::<TestBackend>(&device);
+        // gamma = 1 + 2c for the first feature, beta = -c for the second one
+        film.gamma.weight = film
+            .gamma
+            .weight
+            .map(|_| Tensor::from_floats([[2.0, 0.0]], &device));
+        film.beta.weight = film
+            .beta
+            .weight
+            .map(|_| Tensor::from_floats([[0.0, -1.0]], &device));
+        let features = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 2.0], [3.0, 4.0]], [[1.0, 2.0], [3.0, 4.0]]],
+            &device,
+        );
+        let condition = Tensor::<TestBackend, 2>::from_floats([[0.0], [1.0]], &device);
+
+        let output = film.forward(features, condition);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[1.0, 2.0], [3.0, 4.0]], [[3.0, 6.0], [2.0, 3.0]]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn output_shape_matches_features_shape() {
+        let device = Default::default();
+        let film = FiLMConfig::new(8, 6).init::<TestBackend>(&device);
+        let features = Tensor::<TestBackend, 2>::random([3, 8], Distribution::Default, &device);
+        let condition = Tensor::<TestBackend, 2>::random([3, 6], Distribution::Default, &device);
+
+        let output = film.forward(features, condition);
+
+        assert_eq!(output.dims(), [3, 8]);
+    }
+
+    #[test]
+    fn display() {
+        let config = FiLMConfig::new(8, 6);
+        let film = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{film}"),
+            "FiLM {feature_dim: 8, condition_dim: 6, params: 112}"
+        );
+    }
+}
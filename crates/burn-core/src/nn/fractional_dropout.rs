@@ -0,0 +1,248 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [FractionalDropout1d](FractionalDropout1d) layer using the
+/// [init function](FractionalDropout1dConfig::init).
+#[derive(Config, Debug)]
+pub struct FractionalDropout1dConfig {
+    /// The probability of randomly zeroes some channels of the input tensor during training.
+    pub prob: f64,
+}
+
+/// Configuration to create a [FractionalDropout2d](FractionalDropout2d) layer using the
+/// [init function](FractionalDropout2dConfig::init).
+#[derive(Config, Debug)]
+pub struct FractionalDropout2dConfig {
+    /// The probability of randomly zeroes some channels of the input tensor during training.
+    pub prob: f64,
+}
+
+/// Set at random some entire channels of the input tensor to zero during training.
+///
+/// Unlike [Dropout](crate::nn::Dropout), which drops individual elements, a single mask value is
+/// drawn for each channel and broadcast over the length, so whole channels (e.g. the frequency
+/// bands of an audio feature map) are dropped.
+///
+/// The input is also scaled during training to `1 / (1 - prob_keep)`.
+///
+/// Should be created with [FractionalDropout1dConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct FractionalDropout1d {
+    /// The probability of randomly zeroes some channels of the input tensor during training.
+    pub prob: f64,
+}
+
+/// Set at random some entire channels of the input tensor to zero during training.
+///
+/// Unlike [Dropout](crate::nn::Dropout), which drops individual elements, a single mask value is
+/// drawn for each channel and broadcast over the height and the width, so whole feature maps are
+/// dropped.
+///
+/// The input is also scaled during training to `1 / (1 - prob_keep)`.
+///
+/// Should be created with [FractionalDropout2dConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct FractionalDropout2d {
+    /// The probability of randomly zeroes some channels of the input tensor during training.
+    pub prob: f64,
+}
+
+impl FractionalDropout1dConfig {
+    /// Initialize a new [fractional dropout 1d](FractionalDropout1d) module.
+    pub fn init(&self) -> FractionalDropout1d {
+        check_prob(self.prob);
+        FractionalDropout1d { prob: self.prob }
+    }
+}
+
+impl FractionalDropout2dConfig {
+    /// Initialize a new [fractional dropout 2d](FractionalDropout2d) module.
+    pub fn init(&self) -> FractionalDropout2d {
+        check_prob(self.prob);
+        FractionalDropout2d { prob: self.prob }
+    }
+}
+
+fn check_prob(prob: f64) {
+    if !(0.0..=1.0).contains(&prob) {
+        panic!("Dropout probability should be between 0 and 1, but got {prob}");
+    }
+}
+
+impl FractionalDropout1d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [FractionalDropout1d](FractionalDropout1d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, length]`
+    /// - output: `[batch_size, channels, length]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [batch_size, channels, _] = input.dims();
+        channel_dropout(input, [batch_size, channels, 1], self.prob)
+    }
+}
+
+impl FractionalDropout2d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [FractionalDropout2d](FractionalDropout2d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height, width]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels, _, _] = input.dims();
+        channel_dropout(input, [batch_size, channels, 1, 1], self.prob)
+    }
+}
+
+/// Multiply the input by a dropout mask of the given shape broadcast over the spatial dimensions.
+fn channel_dropout<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    mask_shape: [usize; D],
+    prob: f64,
+) -> Tensor<B, D> {
+    if !B::ad_enabled() || prob == 0.0 {
+        return input;
+    }
+
+    let prob_keep = 1.0 - prob;
+    let mask = Tensor::<B, D>::random(
+        mask_shape,
+        Distribution::Bernoulli(prob_keep),
+        &input.device(),
+    );
+    let x = input * mask;
+
+    x * (1.0 / prob_keep)
+}
+
+impl ModuleDisplay for FractionalDropout1d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("prob", &self.prob).optional()
+    }
+}
+
+impl ModuleDisplay for FractionalDropout2d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("prob", &self.prob).optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    /// Whether each channel of the flattened `[channels, size]` values is either all zeros or
+    /// all equal to `scale`.
+    #[cfg(feature = "std")]
+    fn assert_whole_channels(values: &[f32], size: usize, scale: f32) {
+        let mut num_dropped = 0;
+        for channel in values.chunks(size) {
+            if channel.iter().all(|value| *value == 0.0) {
+                num_dropped += 1;
+            } else {
+                assert!(
+                    channel.iter().all(|value| (*value - scale).abs() < 1e-5),
+                    "Channel partially dropped: {channel:?}"
+                );
+            }
+        }
+
+        let num_channels = values.len() / size;
+        assert!(num_dropped > 0 && num_dropped < num_channels);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_1d_should_zero_whole_channels() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 3>::ones(Shape::new([8, 16, 10]), &Default::default());
+        let dropout = FractionalDropout1dConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor);
+
+        let values = output.into_data().to_vec::<f32>().unwrap();
+        assert_whole_channels(&values, 10, 2.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_2d_should_zero_whole_channels() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 4>::ones(Shape::new([8, 16, 5, 4]), &Default::default());
+        let dropout = FractionalDropout2dConfig::new(0.25).init();
+
+        let output = dropout.forward(tensor);
+
+        let values = output.into_data().to_vec::<f32>().unwrap();
+        assert_whole_channels(&values, 20, 1.0 / 0.75);
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let device = Default::default();
+        let tensor_1d = Tensor::<TestBackend, 3>::ones(Shape::new([8, 16, 10]), &device);
+        let tensor_2d = Tensor::<TestBackend, 4>::ones(Shape::new([8, 16, 5, 4]), &device);
+
+        let output_1d = FractionalDropout1dConfig::new(0.5)
+            .init()
+            .forward(tensor_1d.clone());
+        let output_2d = FractionalDropout2dConfig::new(0.5)
+            .init()
+            .forward(tensor_2d.clone());
+
+        assert_eq!(tensor_1d.to_data(), output_1d.to_data());
+        assert_eq!(tensor_2d.to_data(), output_2d.to_data());
+    }
+
+    #[test]
+    fn display() {
+        let layer_1d = FractionalDropout1dConfig::new(0.5).init();
+        let layer_2d = FractionalDropout2dConfig::new(0.5).init();
+
+        assert_eq!(
+            alloc::format!("{}", layer_1d),
+            "FractionalDropout1d {prob: 0.5}"
+        );
+        assert_eq!(
+            alloc::format!("{}", layer_2d),
+            "FractionalDropout2d {prob: 0.5}"
+        );
+    }
+
+    #[test]
+    #[should_panic = "Dropout probability should be between 0 and 1,"]
+    fn fractional_dropout_prob_invalid() {
+        let config = FractionalDropout2dConfig::new(1.5);
+        let _layer = config.init();
+    }
+}
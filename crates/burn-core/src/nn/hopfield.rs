@@ -0,0 +1,194 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::tensor::activation::softmax;
+use crate::tensor::{Tensor, backend::Backend};
+
+use super::Initializer;
+
+/// Configuration to create a [Hopfield](Hopfield) layer using the [init function](HopfieldConfig::init).
+#[derive(Config, Debug)]
+pub struct HopfieldConfig {
+    /// The number of stored patterns.
+    pub num_patterns: usize,
+    /// The size of the stored patterns and of the queries.
+    pub query_dim: usize,
+    /// The inverse temperature of the retrieval. Higher values retrieve the single nearest
+    /// pattern, lower values retrieve averages of similar patterns. Default: 1.0
+    #[config(default = 1.0)]
+    pub beta: f64,
+    /// The type of function used to initialize the stored patterns.
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// Modern Hopfield network layer with learnable stored patterns, as described in the paper
+/// [Hopfield Networks is All You Need](https://arxiv.org/abs/2008.02217).
+///
+/// `Hopfield(q) = softmax(beta * q P^T) P`, where `P` holds one stored pattern per row. A single
+/// update retrieves the pattern nearest to the query, as long as the patterns are well separated
+/// with respect to `beta`.
+///
+/// Should be created with [HopfieldConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Hopfield<B: Backend> {
+    /// The stored patterns, of shape `[num_patterns, query_dim]`.
+    pub patterns: Param<Tensor<B, 2>>,
+    /// The inverse temperature of the retrieval.
+    pub beta: f64,
+}
+
+impl<B: Backend> ModuleDisplay for Hopfield<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [num_patterns, query_dim] = self.patterns.shape().dims();
+        content
+            .add("num_patterns", &num_patterns)
+            .add("query_dim", &query_dim)
+            .add("beta", &self.beta)
+            .optional()
+    }
+}
+
+impl HopfieldConfig {
+    /// Initialize a new [Hopfield](Hopfield) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Hopfield<B> {
+        let patterns = self.initializer.init_with(
+            [self.num_patterns, self.query_dim],
+            Some(self.query_dim),
+            Some(self.num_patterns),
+            device,
+        );
+
+        Hopfield {
+            patterns,
+            beta: self.beta,
+        }
+    }
+}
+
+impl<B: Backend> Hopfield<B> {
+    /// Retrieves the combination of stored patterns associated with each query.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, query_dim]`
+    /// - output: `[batch_size, query_dim]`
+    pub fn forward(&self, query: Tensor<B, 2>) -> Tensor<B, 2> {
+        let patterns = self.patterns.val();
+        let scores = query
+            .matmul(patterns.clone().transpose())
+            .mul_scalar(self.beta);
+
+        softmax(scores, 1).matmul(patterns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::{Distribution, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn hopfield_with_patterns(
+        patterns: [[f32; 4]; 3],
+        beta: f64,
+        device: &<TestBackend as Backend>::Device,
+    ) -> Hopfield<TestBackend> {
+        let mut hopfield = HopfieldConfig::new(3, 4)
+            .with_beta(beta)
+            .init::<TestBackend>(device);
+        hopfield.patterns = hopfield
+            .patterns
+            .map(|_| Tensor::from_floats(patterns, device));
+        hopfield
+    }
+
+    #[test]
+    fn retrieves_stored_patterns() {
+        let device = Default::default();
+        let patterns = [
+            [1.0, 1.0, -1.0, -1.0],
+            [1.0, -1.0, 1.0, -1.0],
+            [-1.0, 1.0, 1.0, -1.0],
+        ];
+        let hopfield = hopfield_with_patterns(patterns, 10.0, &device);
+
+        let output = hopfield.forward(Tensor::from_floats(patterns, &device));
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from(patterns), Tolerance::default());
+    }
+
+    #[test]
+    fn retrieves_nearest_pattern_from_noisy_query() {
+        let device = Default::default();
+        let patterns = [
+            [1.0, 1.0, -1.0, -1.0],
+            [1.0, -1.0, 1.0, -1.0],
+            [-1.0, 1.0, 1.0, -1.0],
+        ];
+        let hopfield = hopfield_with_patterns(patterns, 10.0, &device);
+        // Perturbed and rescaled versions of the second and third patterns
+        let query = Tensor::<TestBackend, 2>::from_floats(
+            [[0.8, -1.2, 0.9, -0.7], [-0.5, 0.4, 0.6, -0.3]],
+            &device,
+        );
+
+        let output = hopfield.forward(query);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([patterns[1], patterns[2]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn low_beta_averages_the_patterns() {
+        let device = Default::default();
+        let patterns = [
+            [1.0, 1.0, -1.0, -1.0],
+            [1.0, -1.0, 1.0, -1.0],
+            [-1.0, 1.0, 1.0, -1.0],
+        ];
+        let hopfield = hopfield_with_patterns(patterns, 0.0, &device);
+
+        let output = hopfield.forward(Tensor::from_floats([patterns[0]], &device));
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0, -1.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn output_shape_matches_query_shape() {
+        let device = Default::default();
+        let hopfield = HopfieldConfig::new(16, 8).init::<TestBackend>(&device);
+        let query = Tensor::<TestBackend, 2>::random([5, 8], Distribution::Default, &device);
+
+        let output = hopfield.forward(query);
+
+        assert_eq!(output.dims(), [5, 8]);
+    }
+
+    #[test]
+    fn display() {
+        let config = HopfieldConfig::new(16, 8).with_beta(0.5);
+        let hopfield = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{hopfield}"),
+            "Hopfield {num_patterns: 16, query_dim: 8, beta: 0.5, params: 128}"
+        );
+    }
+}
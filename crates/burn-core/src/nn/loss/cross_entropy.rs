@@ -1,6 +1,7 @@
 use crate as burn;
 
 use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::nn::loss::Reduction;
 use crate::tensor::activation::log_softmax;
 use crate::tensor::{Bool, Int, Tensor, backend::Backend};
 use crate::{config::Config, module::Module};
@@ -136,6 +137,15 @@ impl<B: Backend> CrossEntropyLoss<B> {
         targets: Tensor<B, 1, Int>,
         alpha: f32,
     ) -> Tensor<B, 1> {
+        if self.logits && self.weights.is_none() && self.pad_tokens.is_none() {
+            return cross_entropy_with_label_smoothing(
+                logits,
+                targets,
+                alpha as f64,
+                Reduction::Mean,
+            );
+        }
+
         let mask = self.padding_mask(&targets);
         let tensor = if self.logits {
             log_softmax(logits, 1)
@@ -242,6 +252,36 @@ impl<B: Backend> CrossEntropyLoss<B> {
     }
 }
 
+/// Compute the cross-entropy between the logits and the targets with label smoothing, reduced to
+/// a single loss value.
+///
+/// The targets are smoothed to `(1 - smoothing) * one_hot(target) + smoothing / num_classes`. The
+/// loss and its gradient are computed by a
+/// [single operation](crate::tensor::module::cross_entropy_with_label_smoothing), without
+/// materializing the smoothed targets, which [CrossEntropyLoss] also uses when it has no weights
+/// and no pad tokens.
+///
+/// `Reduction::Auto` behaves as `Reduction::Mean`.
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, num_targets]`
+/// - targets: `[batch_size]`
+/// - output: `[1]`
+pub fn cross_entropy_with_label_smoothing<B: Backend>(
+    logits: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+    smoothing: f64,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    let loss =
+        crate::tensor::module::cross_entropy_with_label_smoothing(logits, targets, smoothing);
+    match reduction {
+        Reduction::Mean | Reduction::Auto => loss.mean(),
+        Reduction::Sum => loss.sum(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -452,6 +492,38 @@ mod tests {
             .assert_approx_eq::<FT>(&loss_2.into_data(), Tolerance::default());
     }
 
+    #[test]
+    fn test_cross_entropy_with_label_smoothing_zero_matches_cross_entropy() {
+        let (logits, targets, _) = setup!();
+        let loss_1 = cross_entropy_with_label_smoothing(
+            logits.clone(),
+            targets.clone(),
+            0.0,
+            Reduction::Mean,
+        );
+        let loss_2 = CrossEntropyLossConfig::new()
+            .init(&logits.device())
+            .forward(logits, targets);
+
+        loss_1
+            .into_data()
+            .assert_approx_eq::<FT>(&loss_2.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_cross_entropy_with_label_smoothing_sum() {
+        let (logits, targets, targets_logits) = setup!();
+        let loss_1 =
+            cross_entropy_with_label_smoothing(logits.clone(), targets, 0.2, Reduction::Sum);
+
+        let smoothed_targets = targets_logits * 0.8 + 0.2 / 5.0;
+        let loss_2 = (log_softmax(logits, 1) * smoothed_targets).sum().neg();
+
+        loss_1
+            .into_data()
+            .assert_approx_eq::<FT>(&loss_2.into_data(), Tolerance::default());
+    }
+
     #[test]
     fn display() {
         let config = CrossEntropyLossConfig::new()
@@ -0,0 +1,222 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Hinge embedding loss](HingeEmbeddingLoss).
+#[derive(Config, Debug)]
+pub struct HingeEmbeddingLossConfig {
+    /// The margin under which the inputs of the dissimilar pairs are penalized.
+    #[config(default = 1.0)]
+    pub margin: f64,
+}
+
+impl HingeEmbeddingLossConfig {
+    /// Initialize [Hinge embedding loss](HingeEmbeddingLoss).
+    pub fn init(&self) -> HingeEmbeddingLoss {
+        HingeEmbeddingLoss {
+            margin: self.margin,
+        }
+    }
+}
+
+/// Calculate the hinge embedding loss between the inputs (usually distances between pairs of
+/// embeddings) and the targets indicating if the pairs are similar (`1`) or dissimilar (`-1`).
+///
+/// The loss for each element is given by
+///
+/// ```text
+/// L(x, y) = x                     if y =  1
+/// L(x, y) = max(0, margin - x)    if y = -1
+/// ```
+///
+/// This loss is used for one-class classification and to learn embeddings where similar pairs are
+/// close and dissimilar pairs are at least `margin` apart.
+///
+/// See also the functional form [hinge_embedding_loss].
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct HingeEmbeddingLoss {
+    /// The margin under which the inputs of the dissimilar pairs are penalized.
+    pub margin: f64,
+}
+
+impl ModuleDisplay for HingeEmbeddingLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("margin", &self.margin).optional()
+    }
+}
+
+impl HingeEmbeddingLoss {
+    /// Compute the loss element-wise for the inputs and targets, then reduce
+    /// to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        inputs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        hinge_embedding_loss(inputs, targets, self.margin, reduction)
+    }
+
+    /// Compute the loss element-wise for the inputs and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: [...dims]
+    /// - targets: [...dims]
+    /// - output: [...dims]
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        inputs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        // Like PyTorch, targets other than 1 and -1 get both terms
+        let margin_clamp = inputs.clone().neg().add_scalar(self.margin).clamp_min(0.0);
+        let zeros = inputs.zeros_like();
+
+        let loss_dissimilar = zeros
+            .clone()
+            .mask_where(targets.clone().not_equal_elem(1.0), margin_clamp);
+        let loss_similar = zeros.mask_where(targets.not_equal_elem(-1.0), inputs);
+
+        loss_dissimilar.add(loss_similar)
+    }
+}
+
+/// Compute the [hinge embedding loss](HingeEmbeddingLoss) of the inputs and targets with the given
+/// margin, reduced to a single loss value.
+///
+/// `Reduction::Auto` behaves as `Reduction::Mean`.
+///
+/// # Shapes
+///
+/// - input: \[...dims\]
+/// - target: \[...dims\]
+/// - output: \[1\]
+pub fn hinge_embedding_loss<const D: usize, B: Backend>(
+    input: Tensor<B, D>,
+    target: Tensor<B, D>,
+    margin: f64,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    let loss = HingeEmbeddingLoss { margin }.forward_no_reduction(input, target);
+    match reduction {
+        Reduction::Mean | Reduction::Auto => loss.mean(),
+        Reduction::Sum => loss.sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::TensorData;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_hinge_embedding_loss() {
+        let device = Default::default();
+        let inputs = TestTensor::<2>::from_data([[0.2, 0.5], [1.5, 0.0]], &device);
+        let targets = TestTensor::<2>::from_data([[1.0, -1.0], [-1.0, 1.0]], &device);
+
+        let loss = HingeEmbeddingLossConfig::new().init();
+        let loss_no_reduction = loss.forward_no_reduction(inputs.clone(), targets.clone());
+        let loss_mean = loss.forward(inputs.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = loss.forward(inputs, targets, Reduction::Sum);
+
+        let expected = TensorData::from([[0.2, 0.5], [0.0, 0.0]]);
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+
+        let expected = TensorData::from([0.175]);
+        loss_mean
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+
+        let expected = TensorData::from([0.7]);
+        loss_sum
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn test_hinge_embedding_loss_separated_embeddings_is_zero() {
+        let device = Default::default();
+        // The similar pairs are at distance 0 and the dissimilar ones beyond the margin
+        let inputs = TestTensor::<1>::from_data([0.0, 2.5, 0.0, 3.0], &device);
+        let targets = TestTensor::<1>::from_data([1.0, -1.0, 1.0, -1.0], &device);
+
+        let loss = hinge_embedding_loss(inputs, targets, 2.0, Reduction::Mean);
+
+        loss.into_data().assert_eq(&TensorData::from([0.0]), false);
+    }
+
+    #[test]
+    fn test_hinge_embedding_loss_overlapping_embeddings_is_positive() {
+        let device = Default::default();
+        // A dissimilar pair is closer than a similar one
+        let inputs = TestTensor::<1>::from_data([1.0, 0.5], &device);
+        let targets = TestTensor::<1>::from_data([1.0, -1.0], &device);
+
+        let loss = hinge_embedding_loss(inputs, targets, 2.0, Reduction::Sum);
+
+        // 1.0 + (2.0 - 0.5)
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([2.5]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_hinge_embedding_loss_gradients() {
+        type TestAutodiffTensor = Tensor<crate::TestAutodiffBackend, 1>;
+
+        let device = Default::default();
+        let inputs = TestAutodiffTensor::from_data([0.5, 0.5, 1.5, 2.0], &device).require_grad();
+        let targets = TestAutodiffTensor::from_data([1.0, -1.0, -1.0, 0.0], &device);
+
+        let loss = HingeEmbeddingLossConfig::new().init();
+        let loss = loss.forward(inputs.clone(), targets, Reduction::Sum);
+
+        let grads = loss.backward();
+        let grads_inputs = inputs.grad(&grads).unwrap();
+
+        // The similar pairs pull, the dissimilar pairs under the margin push and the others don't
+        // contribute, except the invalid targets getting both terms
+        let expected = TensorData::from([1.0, -1.0, 0.0, 1.0]);
+        grads_inputs
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = HingeEmbeddingLossConfig::new().with_margin(0.5);
+        let loss = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "HingeEmbeddingLoss {margin: 0.5}"
+        );
+    }
+}
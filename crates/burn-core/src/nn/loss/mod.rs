@@ -1,5 +1,6 @@
 mod binary_cross_entropy;
 mod cross_entropy;
+mod hinge_embedding;
 mod huber;
 mod mse;
 mod poisson;
@@ -7,6 +8,7 @@ mod reduction;
 
 pub use binary_cross_entropy::*;
 pub use cross_entropy::*;
+pub use hinge_embedding::*;
 pub use huber::*;
 pub use mse::*;
 pub use poisson::*;
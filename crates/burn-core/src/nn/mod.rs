@@ -19,40 +19,64 @@ pub mod transformer;
 /// Interpolate module
 pub mod interpolate;
 
+mod cbam;
 mod dropout;
 mod embedding;
+mod film;
+mod fractional_dropout;
 mod gelu;
 mod hard_sigmoid;
+mod hopfield;
 mod initializer;
 mod leaky_relu;
 mod linear;
+mod multi_scale_fusion;
 mod norm;
 mod padding;
 mod pos_encoding;
 mod prelu;
+mod relative_position_bias;
 mod relu;
 mod rnn;
 mod rope_encoding;
 mod sigmoid;
+mod sincos_pos_encoding;
+mod sinusoidal_pos_encoding;
+mod squeeze_excitation;
+mod stochastic_depth;
 mod swiglu;
 mod tanh;
+mod time_embedding;
 mod unfold;
+mod vision_transformer;
 
+pub use cbam::*;
 pub use dropout::*;
 pub use embedding::*;
+pub use film::*;
+pub use fractional_dropout::*;
 pub use gelu::*;
 pub use hard_sigmoid::*;
+pub use hopfield::*;
 pub use initializer::*;
 pub use leaky_relu::*;
 pub use linear::*;
+pub use multi_scale_fusion::*;
 pub use norm::*;
 pub use padding::*;
 pub use pos_encoding::*;
 pub use prelu::*;
+pub use relative_position_bias::*;
 pub use relu::*;
 pub use rnn::*;
 pub use rope_encoding::*;
 pub use sigmoid::*;
+pub use sincos_pos_encoding::*;
+pub use sinusoidal_pos_encoding::*;
+pub use squeeze_excitation::*;
+pub use stochastic_depth::*;
 pub use swiglu::*;
 pub use tanh::*;
+pub use time_embedding::*;
 pub use unfold::*;
+pub use vision_transformer::*;
@@ -0,0 +1,264 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Ignored, Module, Param};
+use crate::nn::{
+    BatchNorm, BatchNormConfig, Initializer,
+    conv::{Conv2d, Conv2dConfig},
+    interpolate::InterpolateMode,
+};
+use crate::tensor::activation::relu;
+use crate::tensor::module::interpolate;
+use crate::tensor::ops::InterpolateOptions;
+use crate::tensor::{Tensor, backend::Backend};
+
+/// How the feature maps of the different scales are merged by a
+/// [multi-scale fusion](MultiScaleFusion) module.
+#[derive(Config, Debug, PartialEq, Eq, Copy)]
+pub enum FusionMode {
+    /// The feature maps are summed, as in FPN.
+    Sum,
+    /// The feature maps are averaged.
+    Average,
+    /// The feature maps are summed with learnable non-negative weights normalised to sum to one,
+    /// as in BiFPN.
+    FastNormalised,
+}
+
+/// Configuration to create a [multi-scale fusion](MultiScaleFusion) module using the
+/// [init function](MultiScaleFusionConfig::init).
+#[derive(Config, Debug)]
+pub struct MultiScaleFusionConfig {
+    /// The number of channels of the feature map of each scale.
+    pub scales: Vec<usize>,
+    /// The number of channels of the output.
+    pub out_channels: usize,
+    /// How the feature maps are merged.
+    pub fusion_mode: FusionMode,
+    /// If a batch norm is applied after the convolution of each scale.
+    #[config(default = false)]
+    pub batch_norm: bool,
+    /// The interpolation mode used to resize the feature maps to the output resolution.
+    #[config(default = "InterpolateMode::Nearest")]
+    pub interpolate_mode: InterpolateMode,
+    /// A value added to the sum of the weights of the [fast normalised](FusionMode::FastNormalised)
+    /// fusion for numerical stability.
+    #[config(default = 1e-4)]
+    pub epsilon: f64,
+}
+
+/// Merges feature maps at different spatial scales into a single feature map, as in the feature
+/// pyramid networks FPN ([Lin et al., 2016](https://arxiv.org/abs/1612.03144)) and BiFPN
+/// ([Tan et al., 2019](https://arxiv.org/abs/1911.09070)).
+///
+/// The feature map of each scale goes through a `1x1` convolution to the output channels and an
+/// optional batch norm, and is resized to the resolution of the first feature map before the
+/// feature maps are merged according to the [fusion mode](FusionMode).
+///
+/// Should be created with [MultiScaleFusionConfig].
+#[derive(Module, Debug)]
+pub struct MultiScaleFusion<B: Backend> {
+    /// The convolution of each scale.
+    pub convs: Vec<Conv2d<B>>,
+    /// The batch norm of each scale, if enabled.
+    pub norms: Option<Vec<BatchNorm<B, 2>>>,
+    /// The weight of each scale of shape `[num_scales]`, for the
+    /// [fast normalised](FusionMode::FastNormalised) fusion.
+    pub weights: Option<Param<Tensor<B, 1>>>,
+    /// How the feature maps are merged.
+    pub fusion_mode: Ignored<FusionMode>,
+    /// The interpolation mode used to resize the feature maps.
+    pub interpolate_mode: Ignored<InterpolateMode>,
+    /// A value added to the sum of the weights for numerical stability.
+    pub epsilon: f64,
+}
+
+impl MultiScaleFusionConfig {
+    /// Initialize a new [multi-scale fusion](MultiScaleFusion) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no scales.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> MultiScaleFusion<B> {
+        assert!(
+            !self.scales.is_empty(),
+            "multi-scale fusion requires at least one scale"
+        );
+
+        let convs = self
+            .scales
+            .iter()
+            .map(|&channels| Conv2dConfig::new([channels, self.out_channels], [1, 1]).init(device))
+            .collect();
+        let norms = self.batch_norm.then(|| {
+            self.scales
+                .iter()
+                .map(|_| BatchNormConfig::new(self.out_channels).init(device))
+                .collect()
+        });
+        let weights = (self.fusion_mode == FusionMode::FastNormalised)
+            .then(|| Initializer::Ones.init([self.scales.len()], device));
+
+        MultiScaleFusion {
+            convs,
+            norms,
+            weights,
+            fusion_mode: Ignored(self.fusion_mode),
+            interpolate_mode: Ignored(self.interpolate_mode.clone()),
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> MultiScaleFusion<B> {
+    /// Applies the forward pass on the feature maps of each scale.
+    ///
+    /// The output has the resolution of the first feature map.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: `[batch_size, scales[i], height_i, width_i]` for each scale `i`
+    /// - output: `[batch_size, out_channels, height_0, width_0]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of feature maps doesn't match the number of scales.
+    pub fn forward(&self, inputs: Vec<Tensor<B, 4>>) -> Tensor<B, 4> {
+        assert_eq!(
+            inputs.len(),
+            self.convs.len(),
+            "expected a feature map for each of the {} scales",
+            self.convs.len()
+        );
+
+        let [_, _, height, width] = inputs[0].dims();
+        let features = inputs
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let x = self.convs[i].forward(x);
+                let x = match &self.norms {
+                    Some(norms) => norms[i].forward(x),
+                    None => x,
+                };
+                let [_, _, x_height, x_width] = x.dims();
+
+                if [x_height, x_width] == [height, width] {
+                    x
+                } else {
+                    let options = InterpolateOptions::new(self.interpolate_mode.0.clone().into());
+                    interpolate(x, [height, width], options)
+                }
+            })
+            .collect::<Vec<_>>();
+        let num_scales = features.len();
+
+        // [num_scales, batch_size, out_channels, height, width]
+        let features = Tensor::stack::<5>(features, 0);
+
+        let fused = match self.fusion_mode.0 {
+            FusionMode::Sum => features.sum_dim(0),
+            FusionMode::Average => features.mean_dim(0),
+            FusionMode::FastNormalised => {
+                let weights = relu(
+                    self.weights
+                        .as_ref()
+                        .expect("fast normalised fusion requires weights")
+                        .val(),
+                );
+                let weights = weights.clone() / (weights.sum() + self.epsilon).unsqueeze();
+
+                (features * weights.reshape([num_scales, 1, 1, 1, 1])).sum_dim(0)
+            }
+        };
+
+        fused.squeeze(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::{Distribution, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn pyramid(device: &<TestBackend as Backend>::Device) -> Vec<Tensor<TestBackend, 4>> {
+        [[2, 8, 16, 16], [2, 16, 8, 8], [2, 32, 4, 4]]
+            .into_iter()
+            .map(|shape| Tensor::random(shape, Distribution::Default, device))
+            .collect()
+    }
+
+    #[test]
+    fn merges_three_scales_into_finest_resolution() {
+        let device = Default::default();
+
+        for fusion_mode in [
+            FusionMode::Sum,
+            FusionMode::Average,
+            FusionMode::FastNormalised,
+        ] {
+            let fusion = MultiScaleFusionConfig::new(vec![8, 16, 32], 12, fusion_mode)
+                .with_batch_norm(true)
+                .init::<TestBackend>(&device);
+
+            let output = fusion.forward(pyramid(&device));
+
+            assert_eq!(output.dims(), [2, 12, 16, 16]);
+        }
+    }
+
+    #[test]
+    fn equal_fast_normalised_weights_average_the_scales() {
+        let device = Default::default();
+        let fusion = MultiScaleFusionConfig::new(vec![8, 16, 32], 12, FusionMode::FastNormalised)
+            .with_epsilon(0.0)
+            .init::<TestBackend>(&device);
+        let mut average = fusion.clone();
+        average.fusion_mode = Ignored(FusionMode::Average);
+        let inputs = pyramid(&device);
+
+        let output = fusion.forward(inputs.clone());
+        let expected = average.forward(inputs);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-5));
+    }
+
+    #[test]
+    fn sum_is_number_of_scales_times_average() {
+        let device = Default::default();
+        let fusion = MultiScaleFusionConfig::new(vec![4, 4], 6, FusionMode::Sum)
+            .init::<TestBackend>(&device);
+        let mut average = fusion.clone();
+        average.fusion_mode = Ignored(FusionMode::Average);
+        let inputs = vec![
+            Tensor::random([1, 4, 6, 6], Distribution::Default, &device),
+            Tensor::random([1, 4, 3, 3], Distribution::Default, &device),
+        ];
+
+        let output = fusion.forward(inputs.clone());
+        let expected = average.forward(inputs) * 2;
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic = "expected a feature map for each of the 3 scales"]
+    fn missing_scale_panics() {
+        let device = Default::default();
+        let fusion = MultiScaleFusionConfig::new(vec![8, 16, 32], 12, FusionMode::Sum)
+            .init::<TestBackend>(&device);
+
+        let mut inputs = pyramid(&device);
+        inputs.pop();
+
+        fusion.forward(inputs);
+    }
+}
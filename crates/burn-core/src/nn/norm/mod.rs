@@ -3,9 +3,11 @@ mod group;
 mod instance;
 mod layer;
 mod rms;
+mod weight;
 
 pub use batch::*;
 pub use group::*;
 pub use instance::*;
 pub use layer::*;
 pub use rms::*;
+pub use weight::*;
@@ -0,0 +1,204 @@
+use alloc::format;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+
+/// Configuration to create a [Weight Norm](WeightNorm) reparameterization using the
+/// [init function](WeightNormConfig::init).
+#[derive(Config)]
+pub struct WeightNormConfig {
+    /// The dimension of the weight kept by the norm, usually the output dimension. Default: 0
+    #[config(default = 0)]
+    pub dim: usize,
+}
+
+impl WeightNormConfig {
+    /// Initialize a new [Weight Norm](WeightNorm) reparameterization of the given weight.
+    ///
+    /// The magnitude `g` is initialized to the norm of the weight and the direction `v` to the
+    /// weight itself, so the reparameterized weight is initially equal to the given weight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dim` is not a dimension of the weight.
+    pub fn init<B: Backend, const D: usize>(&self, weight: Tensor<B, D>) -> WeightNorm<B, D> {
+        assert!(
+            self.dim < D,
+            "dim ({}) must be a dimension of the weight of rank {D}.",
+            self.dim
+        );
+
+        let g = weight.clone().norm_except_dim(self.dim, 2.0);
+
+        WeightNorm {
+            g: Param::from_tensor(g),
+            v: Param::from_tensor(weight),
+            dim: self.dim,
+        }
+    }
+}
+
+/// Applies [Weight Normalization](https://arxiv.org/abs/1602.07868) to a weight, decoupling its
+/// magnitude from its direction.
+///
+/// `W = g * v / ||v||`
+///
+/// Where:
+/// - `W` is the weight
+/// - `g` is the learnable magnitude, with a size of 1 for every dimension except `dim`
+/// - `v` is the learnable direction, with the shape of the weight
+/// - `||v||` is the L2 norm of `v` over all the dimensions except `dim`
+///
+/// The weight is meant to be used with the functional API, e.g.
+/// [conv1d](crate::tensor::module::conv1d) or [conv2d](crate::tensor::module::conv2d).
+///
+/// Should be created using the [WeightNormConfig](WeightNormConfig) configuration.
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct WeightNorm<B: Backend, const D: usize> {
+    /// The magnitude of the weight.
+    pub g: Param<Tensor<B, D>>,
+    /// The direction of the weight.
+    pub v: Param<Tensor<B, D>>,
+    /// The dimension of the weight kept by the norm.
+    pub dim: usize,
+}
+
+impl<B: Backend, const D: usize> WeightNorm<B, D> {
+    /// Computes the weight from its magnitude and direction.
+    ///
+    /// See the [WeightNorm](WeightNorm) documentation for more information.
+    pub fn weight(&self) -> Tensor<B, D> {
+        self.v.val().unit_normalize(self.dim) * self.g.val()
+    }
+}
+
+impl<B: Backend, const D: usize> ModuleDisplay for WeightNorm<B, D> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("shape", &format!("{:?}", self.v.dims()))
+            .add("dim", &self.dim)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::TensorData;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn weight_norm_preserves_initial_weight() {
+        let device = Default::default();
+        let weight =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 2.0], [0.0, 3.0, -4.0]], &device);
+
+        let module = WeightNormConfig::new().init(weight.clone());
+
+        module
+            .g
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[3.0], [5.0]]), Tolerance::default());
+        module
+            .weight()
+            .into_data()
+            .assert_approx_eq::<FT>(&weight.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn weight_norm_decouples_magnitude() {
+        let device = Default::default();
+        let weight =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 2.0], [0.0, 3.0, -4.0]], &device);
+        let mut module = WeightNormConfig::new().init(weight);
+
+        // Scaling the direction doesn't change the weight, only the magnitude does
+        module.v = module.v.map(|v| v * 10.0);
+        module.g = Param::from_data([[1.0], [2.0]], &device);
+
+        let expected = TensorData::from([[1.0 / 3.0, -2.0 / 3.0, 2.0 / 3.0], [0.0, 1.2, -1.6]]);
+        module
+            .weight()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn weight_norm_gradients() {
+        let device = Default::default();
+        let weight =
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[3.0, 4.0], [0.0, 2.0]], &device);
+        let module = WeightNormConfig::new().with_dim(1).init(weight);
+
+        let grads = module.weight().sum().backward();
+        let g_grad = module.g.grad(&grads).unwrap();
+        let v_grad = module.v.grad(&grads).unwrap();
+
+        // d(sum(W))/dg = sum(v / ||v||) over the normalized dimension
+        g_grad.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, (4.0 + 2.0) / 20f32.sqrt()]]),
+            Tolerance::default(),
+        );
+        // d(sum(W))/dv = g / ||v|| * (1 - v * sum(v) / ||v||^2), with g = ||v|| initially
+        v_grad.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([
+                [1.0 - 3.0 * 3.0 / 9.0, 1.0 - 4.0 * 6.0 / 20.0],
+                [1.0, 1.0 - 2.0 * 6.0 / 20.0],
+            ]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn weight_norm_record_round_trip() {
+        let device = Default::default();
+        let weight = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0], [2.0, 0.5]], &device);
+        let module = WeightNormConfig::new().init(weight);
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(module.clone().into_record(), ()).unwrap();
+        let record = recorder.load(bytes, &device).unwrap();
+        let loaded = WeightNormConfig::new()
+            .init(Tensor::<TestBackend, 2>::zeros([2, 2], &device))
+            .load_record(record);
+
+        loaded
+            .g
+            .val()
+            .into_data()
+            .assert_eq(&module.g.val().into_data(), true);
+        loaded
+            .v
+            .val()
+            .into_data()
+            .assert_eq(&module.v.val().into_data(), true);
+    }
+
+    #[test]
+    fn display() {
+        let weight = Tensor::<TestBackend, 2>::zeros([4, 3], &Default::default());
+        let module = WeightNormConfig::new().init(weight);
+
+        assert_eq!(
+            format!("{}", module),
+            "WeightNorm {shape: [4, 3], dim: 0, params: 16}"
+        );
+    }
+}
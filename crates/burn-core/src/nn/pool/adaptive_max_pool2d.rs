@@ -0,0 +1,79 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+
+use crate::tensor::module::adaptive_max_pool2d;
+
+/// Configuration to create a [2D adaptive max pooling](AdaptiveMaxPool2d) layer using the [init function](AdaptiveMaxPool2dConfig::init).
+#[derive(Config, Debug)]
+pub struct AdaptiveMaxPool2dConfig {
+    /// The size of the output.
+    pub output_size: [usize; 2],
+}
+
+/// Applies a 2D adaptive max pooling over input tensors.
+///
+/// Should be created with [AdaptiveMaxPool2dConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct AdaptiveMaxPool2d {
+    /// The size of the output.
+    pub output_size: [usize; 2],
+}
+
+impl ModuleDisplay for AdaptiveMaxPool2d {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let output_size = alloc::format!("{:?}", self.output_size);
+
+        content.add("output_size", &output_size).optional()
+    }
+}
+
+impl AdaptiveMaxPool2dConfig {
+    /// Initialize a new [adaptive max pool 2d](AdaptiveMaxPool2d) module.
+    pub fn init(&self) -> AdaptiveMaxPool2d {
+        AdaptiveMaxPool2d {
+            output_size: self.output_size,
+        }
+    }
+}
+
+impl AdaptiveMaxPool2d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [adaptive_max_pool2d](crate::tensor::module::adaptive_max_pool2d) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height_in, width_in]`
+    /// - output: `[batch_size, channels, height_out, width_out]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        adaptive_max_pool2d(input, self.output_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let config = AdaptiveMaxPool2dConfig::new([3, 3]);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "AdaptiveMaxPool2d {output_size: [3, 3]}"
+        );
+    }
+}
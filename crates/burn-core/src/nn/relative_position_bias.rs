@@ -0,0 +1,264 @@
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::Initializer;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+
+/// Configuration to create a [RelativePositionBias](RelativePositionBias) layer using the [init function](RelativePositionBiasConfig::init).
+#[derive(Config, Debug)]
+pub struct RelativePositionBiasConfig {
+    /// The number of attention heads, each having its own bias.
+    pub num_heads: usize,
+
+    /// The number of buckets the relative positions are mapped to.
+    #[config(default = 32)]
+    pub num_buckets: usize,
+
+    /// The distance from which all the relative positions share the same bucket.
+    #[config(default = 128)]
+    pub max_distance: usize,
+
+    /// If the keys can attend to the queries on both sides (e.g. encoder self-attention), in which
+    /// case half of the buckets are used for each direction.
+    #[config(default = true)]
+    pub bidirectional: bool,
+
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// Learned relative position bias added to the attention logits, as introduced in
+/// [Exploring the Limits of Transfer Learning with a Unified Text-to-Text Transformer](https://arxiv.org/abs/1910.10683) (T5).
+///
+/// The relative position of each key with respect to each query is mapped to a bucket: small
+/// distances each have their own bucket while larger ones share logarithmically bigger buckets, up
+/// to `max_distance`. Each bucket has a learned bias per attention head.
+///
+/// Should be created using [RelativePositionBiasConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct RelativePositionBias<B: Backend> {
+    /// The learnable bias of each bucket of shape `[num_buckets, num_heads]`.
+    pub weight: Param<Tensor<B, 2>>,
+    /// The distance from which all the relative positions share the same bucket.
+    pub max_distance: usize,
+    /// If the relative positions are bucketed in both directions.
+    pub bidirectional: bool,
+}
+
+impl<B: Backend> ModuleDisplay for RelativePositionBias<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [num_buckets, num_heads] = self.weight.shape().dims();
+        content
+            .add("num_heads", &num_heads)
+            .add("num_buckets", &num_buckets)
+            .add("max_distance", &self.max_distance)
+            .add("bidirectional", &self.bidirectional)
+            .optional()
+    }
+}
+
+impl RelativePositionBiasConfig {
+    /// Initialize a new [RelativePositionBias](RelativePositionBias) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> RelativePositionBias<B> {
+        let weight = self
+            .initializer
+            .init([self.num_buckets, self.num_heads], device);
+
+        RelativePositionBias {
+            weight,
+            max_distance: self.max_distance,
+            bidirectional: self.bidirectional,
+        }
+    }
+}
+
+impl<B: Backend> RelativePositionBias<B> {
+    /// Computes the bias of each attention head for every query and key position.
+    ///
+    /// # Shapes
+    ///
+    /// * output: `[num_heads, query_len, key_len]`
+    pub fn forward(&self, query_len: usize, key_len: usize) -> Tensor<B, 3> {
+        let [_, num_heads] = self.weight.dims();
+        let buckets = self.relative_position_bucket(query_len, key_len);
+
+        self.weight
+            .val()
+            .select(0, buckets.reshape([query_len * key_len]))
+            .reshape([query_len, key_len, num_heads])
+            .permute([2, 0, 1])
+    }
+
+    /// Maps the relative position `key - query` of every query and key position to its bucket.
+    ///
+    /// # Shapes
+    ///
+    /// * output: `[query_len, key_len]`
+    pub fn relative_position_bucket(&self, query_len: usize, key_len: usize) -> Tensor<B, 2, Int> {
+        let [num_buckets, _] = self.weight.dims();
+        let device = self.weight.device();
+
+        let query = Tensor::<B, 1, Int>::arange(0..query_len as i64, &device).unsqueeze_dim(1);
+        let key = Tensor::<B, 1, Int>::arange(0..key_len as i64, &device).unsqueeze_dim(0);
+        let relative_position: Tensor<B, 2, Int> = key - query;
+
+        let (num_buckets, offset, distance) = if self.bidirectional {
+            // The keys after the query use the upper half of the buckets
+            let num_buckets = num_buckets / 2;
+            let offset = relative_position.clone().greater_elem(0).int() * num_buckets as i64;
+            (num_buckets, offset, relative_position.abs())
+        } else {
+            // The keys after the query share the bucket of the query
+            let offset = relative_position.zeros_like();
+            (num_buckets, offset, relative_position.neg().clamp_min(0))
+        };
+
+        // Half of the buckets are for the exact distances, the other half for the log-spaced ones
+        let max_exact = num_buckets / 2;
+        let is_small = distance.clone().lower_elem(max_exact as i64);
+        let log_ratio = (self.max_distance as f32 / max_exact as f32).ln();
+        let large = (distance.clone().float().clamp_min(max_exact as f32) / max_exact as f32)
+            .log()
+            .div_scalar(log_ratio)
+            .mul_scalar((num_buckets - max_exact) as f32)
+            .int()
+            .add_scalar(max_exact as i64)
+            .clamp_max(num_buckets as i64 - 1);
+
+        offset + large.mask_where(is_small, distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::TensorData;
+
+    #[test]
+    fn buckets_bidirectional() {
+        let bias = RelativePositionBiasConfig::new(1)
+            .with_num_buckets(8)
+            .with_max_distance(16)
+            .init::<TestBackend>(&Default::default());
+
+        let buckets = bias.relative_position_bucket(6, 6);
+
+        buckets.into_data().assert_eq(
+            &TensorData::from([
+                [0, 5, 6, 6, 6, 6],
+                [1, 0, 5, 6, 6, 6],
+                [2, 1, 0, 5, 6, 6],
+                [2, 2, 1, 0, 5, 6],
+                [2, 2, 2, 1, 0, 5],
+                [2, 2, 2, 2, 1, 0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn buckets_unidirectional() {
+        let bias = RelativePositionBiasConfig::new(1)
+            .with_num_buckets(8)
+            .with_max_distance(16)
+            .with_bidirectional(false)
+            .init::<TestBackend>(&Default::default());
+
+        let buckets = bias.relative_position_bucket(6, 6);
+
+        buckets.into_data().assert_eq(
+            &TensorData::from([
+                [0, 0, 0, 0, 0, 0],
+                [1, 0, 0, 0, 0, 0],
+                [2, 1, 0, 0, 0, 0],
+                [3, 2, 1, 0, 0, 0],
+                [4, 3, 2, 1, 0, 0],
+                [4, 4, 3, 2, 1, 0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn buckets_match_t5() {
+        // Reference values from `T5Attention._relative_position_bucket` of HuggingFace
+        // Transformers with the default 32 buckets and a max distance of 128
+        let distances = [0, 1, 7, 8, 9, 15, 16, 31, 32, 63, 64, 127, 128, 199];
+        let device = Default::default();
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints(distances, &device);
+        for (bidirectional, expected_after, expected_before) in [
+            (
+                true,
+                [0, 17, 23, 24, 24, 25, 26, 27, 28, 29, 30, 31, 31, 31],
+                [0, 1, 7, 8, 8, 9, 10, 11, 12, 13, 14, 15, 15, 15],
+            ),
+            (
+                false,
+                [0; 14],
+                [0, 1, 7, 8, 9, 15, 16, 21, 21, 26, 26, 31, 31, 31],
+            ),
+        ] {
+            let bias = RelativePositionBiasConfig::new(1)
+                .with_bidirectional(bidirectional)
+                .init::<TestBackend>(&device);
+            let buckets = bias.relative_position_bucket(200, 200);
+
+            // Keys after the first query, and keys before the last query
+            let after = buckets.clone().slice([0..1, 0..200]).reshape([200]);
+            let before = buckets.slice([199..200, 0..200]).reshape([200]);
+            after
+                .select(0, indices.clone())
+                .into_data()
+                .assert_eq(&TensorData::from(expected_after), false);
+            before
+                .select(0, indices.clone().neg().add_scalar(199))
+                .into_data()
+                .assert_eq(&TensorData::from(expected_before), false);
+        }
+    }
+
+    #[test]
+    fn forward_gathers_bias_per_head() {
+        let device = Default::default();
+        let mut bias = RelativePositionBiasConfig::new(2)
+            .with_num_buckets(4)
+            .init::<TestBackend>(&device);
+        bias.weight = Param::from_data(
+            [[0.0, 10.0], [1.0, 11.0], [2.0, 12.0], [3.0, 13.0]],
+            &device,
+        );
+
+        let output = bias.forward(2, 3);
+
+        // Buckets [[0, 3, 3], [1, 0, 3]] (2 buckets per direction, 1 exact distance)
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [[0.0, 3.0, 3.0], [1.0, 0.0, 3.0]],
+                [[10.0, 13.0, 13.0], [11.0, 10.0, 13.0]],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn display() {
+        let config = RelativePositionBiasConfig::new(8);
+        let bias = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", bias),
+            "RelativePositionBias {num_heads: 8, num_buckets: 32, max_distance: 128, bidirectional: true, params: 256}"
+        );
+    }
+}
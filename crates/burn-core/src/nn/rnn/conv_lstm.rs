@@ -0,0 +1,342 @@
+use alloc::{format, vec, vec::Vec};
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::nn::Initializer;
+use crate::nn::PaddingConfig2d;
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::rnn::LstmState;
+use crate::tensor::Tensor;
+use crate::tensor::activation;
+use crate::tensor::backend::Backend;
+
+/// Configuration to create a [ConvLstm](ConvLstm) module using the [init function](ConvLstmConfig::init).
+#[derive(Config, Debug)]
+pub struct ConvLstmConfig {
+    /// The number of channels of the input.
+    pub input_dim: usize,
+    /// The number of channels of the hidden state of each layer.
+    pub hidden_dim: usize,
+    /// The size of the kernel of the convolutions, which must be odd to preserve the spatial size.
+    pub kernel_size: [usize; 2],
+    /// The number of stacked layers, each layer taking the hidden states of the previous one as
+    /// input. Default: 1
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// If the time dimension comes after the batch dimension, with inputs and outputs of shape
+    /// `[batch_size, seq_length, channels, height, width]`, or before it otherwise. Default: true
+    #[config(default = true)]
+    pub batch_first: bool,
+    /// If a bias should be applied by the convolutions. Default: true
+    #[config(default = true)]
+    pub bias: bool,
+    /// If the hidden states of every layer are returned, or only the ones of the last layer.
+    /// Default: false
+    #[config(default = false)]
+    pub return_all_layers: bool,
+    /// ConvLstm initializer
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0),fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// A single layer of a [ConvLstm](ConvLstm), computing the four gates of the LSTM with one
+/// convolution over the input and the hidden state.
+#[derive(Module, Debug)]
+pub struct ConvLstmCell<B: Backend> {
+    /// The convolution of the concatenated input and hidden state, producing the input, forget,
+    /// output and cell gates.
+    pub conv: Conv2d<B>,
+    /// The number of channels of the hidden state.
+    pub hidden_dim: usize,
+}
+
+impl<B: Backend> ConvLstmCell<B> {
+    /// Applies a single time step.
+    ///
+    /// ## Parameters:
+    /// - input: The input of shape `[batch_size, channels, height, width]`.
+    /// - state: The cell state and hidden state, of shape `[batch_size, hidden_dim, height, width]`.
+    ///
+    /// ## Returns:
+    /// The updated state.
+    pub fn forward(&self, input: Tensor<B, 4>, state: LstmState<B, 4>) -> LstmState<B, 4> {
+        let gates = self.conv.forward(Tensor::cat(vec![input, state.hidden], 1));
+        let [input_gate, forget_gate, output_gate, cell_gate] = gates
+            .chunk(4, 1)
+            .try_into()
+            .expect("The convolution should produce four gates");
+
+        let cell = activation::sigmoid(forget_gate) * state.cell
+            + activation::sigmoid(input_gate) * cell_gate.tanh();
+        let hidden = activation::sigmoid(output_gate) * cell.clone().tanh();
+
+        LstmState::new(cell, hidden)
+    }
+}
+
+/// The ConvLstm module, a stacked LSTM whose input and recurrent transforms are convolutions,
+/// preserving the spatial structure of the input.
+///
+/// Introduced in the paper: [Convolutional LSTM Network: A Machine Learning Approach for Precipitation Nowcasting](https://arxiv.org/abs/1506.04214).
+///
+/// Should be created with [ConvLstmConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct ConvLstm<B: Backend> {
+    /// The layers, the first one taking the input and the others the hidden states of the
+    /// previous layer.
+    pub cells: Vec<ConvLstmCell<B>>,
+    /// If the time dimension comes after the batch dimension.
+    pub batch_first: bool,
+    /// If the hidden states of every layer are returned.
+    pub return_all_layers: bool,
+}
+
+impl<B: Backend> ModuleDisplay for ConvLstm<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, input_dim, kernel_height, kernel_width] = self.cells[0].conv.weight.shape().dims();
+        let hidden_dim = self.cells[0].hidden_dim;
+        let kernel_size = format!("{:?}", [kernel_height, kernel_width]);
+
+        content
+            .add("input_dim", &(input_dim - hidden_dim))
+            .add("hidden_dim", &hidden_dim)
+            .add("kernel_size", &kernel_size)
+            .add("num_layers", &self.cells.len())
+            .add("batch_first", &self.batch_first)
+            .add("return_all_layers", &self.return_all_layers)
+            .optional()
+    }
+}
+
+impl ConvLstmConfig {
+    /// Initialize a new [ConvLstm](ConvLstm) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no layer or if the kernel size is even.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> ConvLstm<B> {
+        assert!(self.num_layers > 0, "ConvLstm needs at least one layer");
+        assert!(
+            self.kernel_size.iter().all(|size| size % 2 == 1),
+            "ConvLstm needs an odd kernel size to preserve the spatial size, got {:?}",
+            self.kernel_size
+        );
+
+        let cells = (0..self.num_layers)
+            .map(|layer| {
+                let input_dim = match layer {
+                    0 => self.input_dim,
+                    _ => self.hidden_dim,
+                };
+                let conv = Conv2dConfig::new(
+                    [input_dim + self.hidden_dim, 4 * self.hidden_dim],
+                    self.kernel_size,
+                )
+                .with_padding(PaddingConfig2d::Same)
+                .with_bias(self.bias)
+                .with_initializer(self.initializer.clone())
+                .init(device);
+
+                ConvLstmCell {
+                    conv,
+                    hidden_dim: self.hidden_dim,
+                }
+            })
+            .collect();
+
+        ConvLstm {
+            cells,
+            batch_first: self.batch_first,
+            return_all_layers: self.return_all_layers,
+        }
+    }
+}
+
+impl<B: Backend> ConvLstm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// ## Parameters:
+    /// - input: The input sequence of shape `[batch_size, seq_length, input_dim, height, width]`,
+    ///   or `[seq_length, batch_size, input_dim, height, width]` if not `batch_first`.
+    /// - state: An optional initial `LstmState` for each layer, of shape
+    ///   `[batch_size, hidden_dim, height, width]`.
+    ///   If no initial state is provided, these tensors are initialized to zeros.
+    ///
+    /// ## Returns:
+    /// - outputs: The hidden states of the sequence, with the layout of the input and
+    ///   `hidden_dim` channels. Only the ones of the last layer unless `return_all_layers`.
+    /// - states: The final state of each layer, or only of the last layer unless
+    ///   `return_all_layers`.
+    pub fn forward(
+        &self,
+        input: Tensor<B, 5>,
+        state: Option<Vec<LstmState<B, 4>>>,
+    ) -> (Vec<Tensor<B, 5>>, Vec<LstmState<B, 4>>) {
+        let [dim_0, dim_1, _, height, width] = input.dims();
+        let (batch_size, time_dim) = match self.batch_first {
+            true => (dim_0, 1),
+            false => (dim_1, 0),
+        };
+        let device = input.device();
+
+        let states = match state {
+            Some(states) => {
+                assert_eq!(
+                    states.len(),
+                    self.cells.len(),
+                    "ConvLstm needs an initial state for each layer"
+                );
+                states
+            }
+            None => self
+                .cells
+                .iter()
+                .map(|cell| {
+                    let shape = [batch_size, cell.hidden_dim, height, width];
+                    LstmState::new(Tensor::zeros(shape, &device), Tensor::zeros(shape, &device))
+                })
+                .collect(),
+        };
+
+        let mut layer_input = input;
+        let mut outputs = Vec::with_capacity(self.cells.len());
+        let mut final_states = Vec::with_capacity(self.cells.len());
+
+        for (cell, mut state) in self.cells.iter().zip(states) {
+            let mut hidden_states = Vec::new();
+            for input_t in layer_input.iter_dim(time_dim) {
+                state = cell.forward(input_t.squeeze(time_dim), state);
+                hidden_states.push(state.hidden.clone());
+            }
+
+            layer_input = Tensor::stack(hidden_states, time_dim);
+            outputs.push(layer_input.clone());
+            final_states.push(state);
+        }
+
+        if !self.return_all_layers {
+            outputs.drain(..outputs.len() - 1);
+            final_states.drain(..final_states.len() - 1);
+        }
+
+        (outputs, final_states)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::{Distribution, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn forward_returns_the_hidden_state_of_each_frame() {
+        let device = Default::default();
+        let conv_lstm = ConvLstmConfig::new(3, 8, [3, 3])
+            .with_num_layers(2)
+            .init::<TestBackend>(&device);
+        // Four frames of 3 channels
+        let input =
+            Tensor::<TestBackend, 5>::random([2, 4, 3, 6, 5], Distribution::Default, &device);
+
+        let (outputs, states) = conv_lstm.forward(input, None);
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].dims(), [2, 4, 8, 6, 5]);
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].hidden.dims(), [2, 8, 6, 5]);
+        assert_eq!(states[0].cell.dims(), [2, 8, 6, 5]);
+
+        // The last hidden state is the one of the last frame
+        outputs[0]
+            .clone()
+            .slice([0..2, 3..4, 0..8, 0..6, 0..5])
+            .reshape([2, 8, 6, 5])
+            .into_data()
+            .assert_eq(&states[0].hidden.clone().into_data(), true);
+    }
+
+    #[test]
+    fn return_all_layers_time_first() {
+        let device = Default::default();
+        let conv_lstm = ConvLstmConfig::new(3, 4, [3, 1])
+            .with_num_layers(3)
+            .with_batch_first(false)
+            .with_return_all_layers(true)
+            .init::<TestBackend>(&device);
+        let input =
+            Tensor::<TestBackend, 5>::random([4, 2, 3, 5, 5], Distribution::Default, &device);
+
+        let (outputs, states) = conv_lstm.forward(input, None);
+
+        assert_eq!(outputs.len(), 3);
+        assert_eq!(states.len(), 3);
+        for output in outputs {
+            assert_eq!(output.dims(), [4, 2, 4, 5, 5]);
+        }
+    }
+
+    #[test]
+    fn cell_with_1x1_kernel_matches_lstm_equations() {
+        let device = Default::default();
+        let cell = ConvLstmConfig::new(1, 1, [1, 1])
+            .init::<TestBackend>(&device)
+            .cells
+            .remove(0);
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[0.5]]]], &device);
+        let state = LstmState::new(
+            Tensor::from_floats([[[[0.2]]]], &device),
+            Tensor::from_floats([[[[-0.3]]]], &device),
+        );
+
+        let weight = cell.conv.weight.val().into_data().to_vec::<f32>().unwrap();
+        let bias = cell.conv.bias.as_ref().unwrap().val();
+        let bias = bias.into_data().to_vec::<f32>().unwrap();
+        let gate = |i: usize| weight[2 * i] * 0.5 + weight[2 * i + 1] * -0.3 + bias[i];
+        let sigmoid = |x: f32| 1.0 / (1.0 + (-x).exp());
+        let expected_cell = sigmoid(gate(1)) * 0.2 + sigmoid(gate(0)) * gate(3).tanh();
+        let expected_hidden = sigmoid(gate(2)) * expected_cell.tanh();
+
+        let state = cell.forward(input, state);
+
+        state.cell.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[[expected_cell]]]]),
+            Tolerance::default(),
+        );
+        state.hidden.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[[[expected_hidden]]]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn even_kernel_size() {
+        let device = Default::default();
+        let _ = ConvLstmConfig::new(3, 4, [2, 3]).init::<TestBackend>(&device);
+    }
+
+    #[test]
+    fn display() {
+        let config = ConvLstmConfig::new(3, 8, [3, 3]).with_num_layers(2);
+        let conv_lstm = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{conv_lstm}"),
+            "ConvLstm {input_dim: 3, hidden_dim: 8, kernel_size: [3, 3], num_layers: 2, batch_first: true, return_all_layers: false, params: 7840}"
+        );
+    }
+}
@@ -0,0 +1,484 @@
+use alloc::vec;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::conv::{Conv1d, Conv1dConfig};
+use crate::nn::{Linear, LinearConfig, PaddingConfig1d, RmsNorm, RmsNormConfig};
+use crate::tensor::activation::{silu, softplus};
+use crate::tensor::{Bool, Distribution, Tensor, backend::Backend};
+
+/// Configuration to create a [Mamba2 block](Mamba2Block) using the
+/// [init function](Mamba2BlockConfig::init).
+#[derive(Config, Debug)]
+pub struct Mamba2BlockConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the state of each head. Default: 128
+    #[config(default = 128)]
+    pub d_state: usize,
+    /// The size of the kernel of the causal depthwise convolution. Default: 4
+    #[config(default = 4)]
+    pub d_conv: usize,
+    /// The factor by which the inner dimension expands the model dimension. Default: 2
+    #[config(default = 2)]
+    pub expand: usize,
+    /// The size of each head, the inner dimension being split in heads. Default: 64
+    #[config(default = 64)]
+    pub head_dim: usize,
+    /// A value required for numerical stability of the gated norm. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+}
+
+/// The block of [Transformers are SSMs](https://arxiv.org/abs/2405.21060) (Mamba2), a selective
+/// state-space model whose state transition is a scalar per head, known as the Structured State
+/// Space Duality (SSD).
+///
+/// The input is projected to a gate `z`, the inputs `x`, `B` and `C` of the state-space model and a
+/// time step per head. `x`, `B` and `C` go through a causal depthwise convolution and a SiLU
+/// activation, then the state of each head evolves as
+///
+/// `h_t = exp(dt_t * A) * h_{t-1} + dt_t * x_t * B_t^T` and `y_t = h_t * C_t + D * x_t`.
+///
+/// The output is normalized with an RMS norm gated by `silu(z)` and projected back to `d_model`.
+///
+/// The [forward pass](Mamba2Block::forward) computes the whole sequence at once in the dual
+/// quadratic form, whose decays are prefix sums computed with a parallel scan, while the
+/// [step](Mamba2Block::step) computes one element at a time in the recurrent form.
+///
+/// Should be created with [Mamba2BlockConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Mamba2Block<B: Backend> {
+    /// The projection of the input to the gate, the state-space inputs and the time steps.
+    pub in_proj: Linear<B>,
+    /// The causal depthwise convolution of `x`, `B` and `C`.
+    pub conv1d: Conv1d<B>,
+    /// The logarithm of the opposite of the state transition `A` of each head.
+    pub a_log: Param<Tensor<B, 1>>,
+    /// The bias of the time step of each head, applied before a softplus.
+    pub dt_bias: Param<Tensor<B, 1>>,
+    /// The skip connection `D` of each head.
+    pub d: Param<Tensor<B, 1>>,
+    /// The RMS norm of the output gated by `silu(z)`.
+    pub norm: RmsNorm<B>,
+    /// The projection of the output back to `d_model`.
+    pub out_proj: Linear<B>,
+    /// The size of the state of each head.
+    pub d_state: usize,
+    /// The size of each head.
+    pub head_dim: usize,
+}
+
+/// The state of a [Mamba2 block](Mamba2Block) processing a sequence one element at a time.
+#[derive(Clone, Debug)]
+pub struct Mamba2State<B: Backend> {
+    /// The last `d_conv - 1` inputs of the convolution, of shape
+    /// `[batch_size, d_inner + 2 * d_state, d_conv - 1]`.
+    pub conv: Tensor<B, 3>,
+    /// The state of each head, of shape `[batch_size, num_heads, head_dim, d_state]`.
+    pub ssm: Tensor<B, 4>,
+}
+
+impl<B: Backend> Mamba2State<B> {
+    /// Initialize a new [Mamba2 state](Mamba2State).
+    pub fn new(conv: Tensor<B, 3>, ssm: Tensor<B, 4>) -> Self {
+        Self { conv, ssm }
+    }
+}
+
+impl<B: Backend> ModuleDisplay for Mamba2Block<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [d_inner, d_model] = self.out_proj.weight.shape().dims();
+        let [_, _, d_conv] = self.conv1d.weight.shape().dims();
+        content
+            .add("d_model", &d_model)
+            .add("d_state", &self.d_state)
+            .add("d_conv", &d_conv)
+            .add("expand", &(d_inner / d_model))
+            .add("head_dim", &self.head_dim)
+            .optional()
+    }
+}
+
+impl Mamba2BlockConfig {
+    /// Initialize a new [Mamba2 block](Mamba2Block).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner dimension can't be split in heads of `head_dim`.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Mamba2Block<B> {
+        let d_inner = self.expand * self.d_model;
+        assert!(
+            d_inner % self.head_dim == 0,
+            "The inner dimension of the Mamba2 block should be a multiple of the head dimension, but got {d_inner} and {}",
+            self.head_dim
+        );
+        let num_heads = d_inner / self.head_dim;
+        let conv_channels = d_inner + 2 * self.d_state;
+
+        // A in [-16, -1] and the time step log-uniform in [0.001, 0.1], as the reference
+        // implementation
+        let a_log = Tensor::random([num_heads], Distribution::Uniform(1.0, 16.0), device).log();
+        let dt = Tensor::<B, 1>::random(
+            [num_heads],
+            Distribution::Uniform(0.001f64.ln(), 0.1f64.ln()),
+            device,
+        )
+        .exp();
+        // The inverse of the softplus
+        let dt_bias = dt.clone() + (-(dt.neg().exp()) + 1.0).log();
+
+        Mamba2Block {
+            in_proj: LinearConfig::new(self.d_model, 2 * d_inner + 2 * self.d_state + num_heads)
+                .with_bias(false)
+                .init(device),
+            conv1d: Conv1dConfig::new(conv_channels, conv_channels, self.d_conv)
+                .with_groups(conv_channels)
+                .with_padding(PaddingConfig1d::Explicit(self.d_conv - 1))
+                .init(device),
+            a_log: Param::from_tensor(a_log),
+            dt_bias: Param::from_tensor(dt_bias),
+            d: Param::from_tensor(Tensor::ones([num_heads], device)),
+            norm: RmsNormConfig::new(d_inner)
+                .with_epsilon(self.epsilon)
+                .init(device),
+            out_proj: LinearConfig::new(d_inner, self.d_model)
+                .with_bias(false)
+                .init(device),
+            d_state: self.d_state,
+            head_dim: self.head_dim,
+        }
+    }
+}
+
+impl<B: Backend> Mamba2Block<B> {
+    /// Applies the forward pass on the input tensor, in the parallel form.
+    ///
+    /// See [Mamba2Block](Mamba2Block) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [batch_size, seq_length, _] = input.dims();
+        let [num_heads] = self.d.dims();
+        let device = input.device();
+
+        let [z, xbc, dt] = self.in_projections(input);
+        // The convolution pads both sides, only the first outputs are causal
+        let xbc = self
+            .conv1d
+            .forward(xbc.swap_dims(1, 2))
+            .narrow(2, 0, seq_length)
+            .swap_dims(1, 2);
+        let [x, b, c] = self.split_conv_channels(silu(xbc));
+        let x = x.reshape([batch_size, seq_length, num_heads, self.head_dim]);
+
+        // [batch_size, num_heads, seq_length]
+        let dt = self.time_steps(dt).swap_dims(1, 2);
+        let decays = prefix_sum(dt.clone() * self.a().reshape([1, num_heads, 1]), 2);
+        // The decay from each element j to each element i after it
+        let segments = decays.clone().unsqueeze_dim::<4>(3) - decays.unsqueeze_dim(2);
+        let mask = Tensor::<B, 2, Bool>::tril_mask([seq_length, seq_length], 0, &device)
+            .unsqueeze::<4>()
+            .expand([batch_size, num_heads, seq_length, seq_length]);
+        let decays = segments.mask_fill(mask, f32::NEG_INFINITY).exp();
+
+        let scores = c.matmul(b.swap_dims(1, 2)).unsqueeze_dim(1);
+        let inputs = x.clone().swap_dims(1, 2) * dt.unsqueeze_dim(3);
+        let y = (decays * scores).matmul(inputs).swap_dims(1, 2);
+        let y = y + x * self.d.val().reshape([1, 1, num_heads, 1]);
+
+        self.output(
+            y.reshape([batch_size, seq_length, num_heads * self.head_dim]),
+            z,
+        )
+    }
+
+    /// Applies the block on a single element of the input sequence, in the recurrent form,
+    /// continuing from the state of the previous element.
+    ///
+    /// The steps over a sequence give the same output as the [forward pass](Mamba2Block::forward).
+    /// Without state, the element is the start of the sequence.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, d_model]`
+    /// - output: `[batch_size, d_model]`
+    pub fn step(
+        &self,
+        input: Tensor<B, 2>,
+        state: Option<Mamba2State<B>>,
+    ) -> (Tensor<B, 2>, Mamba2State<B>) {
+        let [batch_size, _] = input.dims();
+        let [num_heads] = self.d.dims();
+        let [conv_channels, _, d_conv] = self.conv1d.weight.dims();
+        let device = input.device();
+
+        let state = state.unwrap_or_else(|| {
+            Mamba2State::new(
+                Tensor::zeros([batch_size, conv_channels, d_conv - 1], &device),
+                Tensor::zeros(
+                    [batch_size, num_heads, self.head_dim, self.d_state],
+                    &device,
+                ),
+            )
+        });
+
+        let [z, xbc, dt] = self.in_projections(input.unsqueeze_dim(1));
+        let window = Tensor::cat(vec![state.conv, xbc.swap_dims(1, 2)], 2);
+        let conv_state = window.clone().narrow(2, 1, d_conv - 1);
+        let weight = self.conv1d.weight.val().reshape([1, conv_channels, d_conv]);
+        let mut xbc = (window * weight).sum_dim(2);
+        if let Some(bias) = &self.conv1d.bias {
+            xbc = xbc + bias.val().reshape([1, conv_channels, 1]);
+        }
+        let [x, b, c] = self.split_conv_channels(silu(xbc.swap_dims(1, 2)));
+        let x = x.reshape([batch_size, num_heads, self.head_dim, 1]);
+        let b = b.reshape([batch_size, 1, 1, self.d_state]);
+        let c = c.reshape([batch_size, 1, 1, self.d_state]);
+
+        let dt = self.time_steps(dt).reshape([batch_size, num_heads, 1, 1]);
+        let decay = (dt.clone() * self.a().reshape([1, num_heads, 1, 1])).exp();
+        let ssm = decay * state.ssm + dt * x.clone() * b;
+
+        let y = (ssm.clone() * c).sum_dim(3);
+        let y = y + x * self.d.val().reshape([1, num_heads, 1, 1]);
+        let output = self.output(y.reshape([batch_size, 1, num_heads * self.head_dim]), z);
+
+        (output.squeeze(1), Mamba2State::new(conv_state, ssm))
+    }
+
+    /// The state transition `A` of each head.
+    fn a(&self) -> Tensor<B, 1> {
+        self.a_log.val().exp().neg()
+    }
+
+    /// Projects the input to the gate, the inputs of the convolution and the raw time steps.
+    fn in_projections(&self, input: Tensor<B, 3>) -> [Tensor<B, 3>; 3] {
+        let [num_heads] = self.d.dims();
+        let d_inner = num_heads * self.head_dim;
+        let projected = self.in_proj.forward(input);
+
+        [
+            projected.clone().narrow(2, 0, d_inner),
+            projected
+                .clone()
+                .narrow(2, d_inner, d_inner + 2 * self.d_state),
+            projected.narrow(2, 2 * d_inner + 2 * self.d_state, num_heads),
+        ]
+    }
+
+    /// Splits the outputs of the convolution in `x`, `B` and `C`.
+    fn split_conv_channels(&self, xbc: Tensor<B, 3>) -> [Tensor<B, 3>; 3] {
+        let [_, _, conv_channels] = xbc.dims();
+        let d_inner = conv_channels - 2 * self.d_state;
+
+        [
+            xbc.clone().narrow(2, 0, d_inner),
+            xbc.clone().narrow(2, d_inner, self.d_state),
+            xbc.narrow(2, d_inner + self.d_state, self.d_state),
+        ]
+    }
+
+    /// The positive time step of each element and head.
+    fn time_steps(&self, dt: Tensor<B, 3>) -> Tensor<B, 3> {
+        softplus(dt + self.dt_bias.val().unsqueeze(), 1.0)
+    }
+
+    /// Normalizes the output of the heads gated by `silu(z)` and projects it back to `d_model`.
+    fn output(&self, y: Tensor<B, 3>, z: Tensor<B, 3>) -> Tensor<B, 3> {
+        self.out_proj.forward(self.norm.forward(y * silu(z)))
+    }
+}
+
+/// The inclusive prefix sum along the given dimension, computed with the parallel scan of
+/// Hillis and Steele in a logarithmic number of shifted additions.
+fn prefix_sum<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
+    let length = tensor.dims()[dim];
+    let device = tensor.device();
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < length {
+        let mut shape = output.dims();
+        shape[dim] = offset;
+        let shifted = Tensor::cat(
+            vec![
+                Tensor::zeros(shape, &device),
+                output.clone().narrow(dim, 0, length - offset),
+            ],
+            dim,
+        );
+        output = output + shifted;
+        offset *= 2;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{ElementConversion, TensorData, Tolerance, ops::FloatElem};
+    use crate::{TestAutodiffBackend, TestBackend};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn output_shape_matches_input_shape() {
+        let device = Default::default();
+        let block = Mamba2BlockConfig::new(16)
+            .with_d_state(8)
+            .with_head_dim(8)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 16], Distribution::Default, &device);
+
+        let output = block.forward(input);
+
+        assert_eq!(output.dims(), [2, 5, 16]);
+    }
+
+    #[test]
+    fn prefix_sum_matches_sequential_sum() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2>::random([3, 13], Distribution::Default, &device);
+
+        let output = prefix_sum(input.clone(), 1);
+
+        let mut sum = Tensor::<TestBackend, 2>::zeros([3, 1], &device);
+        let mut expected = vec![];
+        for i in 0..13 {
+            sum = sum + input.clone().narrow(1, i, 1);
+            expected.push(sum.clone());
+        }
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&Tensor::cat(expected, 1).into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn recurrent_form_matches_parallel_form() {
+        let device = Default::default();
+        let block = Mamba2BlockConfig::new(8)
+            .with_d_state(4)
+            .with_head_dim(4)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 64, 8], Distribution::Default, &device);
+
+        let expected = block.forward(input.clone());
+
+        let mut state = None;
+        let mut outputs = vec![];
+        for step in 0..64 {
+            let (output, new_state) =
+                block.step(input.clone().narrow(1, step, 1).squeeze::<2>(1), state);
+            outputs.push(output.unsqueeze_dim::<3>(1));
+            state = Some(new_state);
+        }
+        Tensor::cat(outputs, 1)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::rel_abs(1e-4, 1e-5));
+    }
+
+    #[test]
+    fn output_should_not_depend_on_future_inputs() {
+        let device = Default::default();
+        let block = Mamba2BlockConfig::new(8)
+            .with_d_state(4)
+            .with_head_dim(4)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([1, 10, 8], Distribution::Default, &device);
+        let future = Tensor::<TestBackend, 3>::random([1, 4, 8], Distribution::Default, &device);
+        let changed = input.clone().slice_assign([0..1, 6..10, 0..8], future);
+
+        let output = block.forward(input).narrow(1, 0, 6);
+        let output_changed = block.forward(changed).narrow(1, 0, 6);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&output_changed.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn gradients_match_finite_differences() {
+        let device = Default::default();
+        let block = Mamba2BlockConfig::new(4)
+            .with_d_state(2)
+            .with_head_dim(2)
+            .init::<TestAutodiffBackend>(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([1, 6, 4], Distribution::Default, &device);
+
+        let grads = block.forward(input.clone()).sum().backward();
+        let a_log_grad = block.a_log.grad(&grads).unwrap();
+        let dt_bias_grad = block.dt_bias.grad(&grads).unwrap();
+
+        // Central differences of the sum of the output for each parameter of each head
+        let perturbed_sum = |a_log_delta, dt_bias_delta| {
+            let mut block = block.clone();
+            block.a_log = block.a_log.map(|a_log| a_log + a_log_delta);
+            block.dt_bias = block.dt_bias.map(|dt_bias| dt_bias + dt_bias_delta);
+            block
+                .forward(input.clone())
+                .sum()
+                .into_scalar()
+                .elem::<f32>()
+        };
+        let epsilon = 1e-2;
+        let [num_heads] = block.a_log.dims();
+        let zeros = Tensor::<TestAutodiffBackend, 1>::zeros([num_heads], &device);
+        let mut expected_a_log = vec![];
+        let mut expected_dt_bias = vec![];
+        for head in 0..num_heads {
+            let mut values = vec![0.0; num_heads];
+            values[head] = epsilon;
+            let delta = Tensor::from_data(TensorData::new(values, [num_heads]), &device);
+            expected_a_log.push(
+                (perturbed_sum(delta.clone(), zeros.clone())
+                    - perturbed_sum(delta.clone().neg(), zeros.clone()))
+                    / (2.0 * epsilon),
+            );
+            expected_dt_bias.push(
+                (perturbed_sum(zeros.clone(), delta.clone())
+                    - perturbed_sum(zeros.clone(), delta.neg()))
+                    / (2.0 * epsilon),
+            );
+        }
+
+        let tolerance = Tolerance::rel_abs(1e-2, 1e-3);
+        a_log_grad
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::new(expected_a_log, [num_heads]), tolerance);
+        dt_bias_grad
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::new(expected_dt_bias, [num_heads]), tolerance);
+    }
+
+    #[test]
+    #[should_panic = "The inner dimension of the Mamba2 block should be a multiple of the head dimension"]
+    fn head_dim_not_dividing_inner_dim() {
+        let config = Mamba2BlockConfig::new(5).with_head_dim(4);
+        let _block = config.init::<TestBackend>(&Default::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = Mamba2BlockConfig::new(8).with_d_state(4).with_head_dim(4);
+        let block = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{block}"),
+            "Mamba2Block {d_model: 8, d_state: 4, d_conv: 4, expand: 2, head_dim: 4, params: 628}"
+        );
+    }
+}
@@ -1,10 +1,18 @@
 mod gate_controller;
 
+/// Convolutional Long Short-Term Memory module.
+pub mod conv_lstm;
+
 /// Gated Recurrent Unit module.
 pub mod gru;
 
 /// Long Short-Term Memory module.
 pub mod lstm;
 
+/// Mamba2 state-space block.
+pub mod mamba2;
+
+pub use conv_lstm::*;
 pub use gate_controller::*;
 pub use lstm::*;
+pub use mamba2::*;
@@ -0,0 +1,333 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay};
+use crate::nn::interpolate::InterpolateMode;
+
+use crate::tensor::Tensor;
+use crate::tensor::TensorData;
+use crate::tensor::backend::Backend;
+use crate::tensor::module::interpolate;
+use crate::tensor::ops::InterpolateOptions;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create a [SinCos2dPositionalEncoding](SinCos2dPositionalEncoding) layer using the [init function](SinCos2dPositionalEncodingConfig::init).
+#[derive(Config, Debug)]
+pub struct SinCos2dPositionalEncodingConfig {
+    /// The size of each vector, which must be a multiple of 4.
+    pub d_model: usize,
+
+    /// The height of the grid of positions.
+    pub max_h: usize,
+
+    /// The width of the grid of positions.
+    pub max_w: usize,
+
+    /// The interpolation used to rescale the encoding to another grid size, if any.
+    #[config(default = "None")]
+    pub interpolation: Option<InterpolateMode>,
+
+    /// The temperature scaling the frequencies of the sinusoids.
+    #[config(default = "10_000")]
+    pub temperature: usize,
+}
+
+/// 2D sinusoidal positional encoding layer for vision transformers.
+///
+/// The first half of the encoding of each position of the grid holds the sinusoids of its column
+/// and the second half the sinusoids of its row, each half being laid out as the sines followed
+/// by the cosines of the frequencies. The encoding is added to the patch embeddings of shape
+/// `[batch_size, height * width, d_model]`, flattened row by row.
+///
+/// Without interpolation, the encoding of the positions of a grid smaller than `[max_h, max_w]` is
+/// used as is. With an interpolation, the encoding is instead rescaled to the grid of the input,
+/// as done to fine-tune a model at a higher resolution.
+///
+/// The reference implementation can be found here:
+/// [MAE](https://github.com/facebookresearch/mae/blob/main/util/pos_embed.py)
+///
+/// Should be created using [SinCos2dPositionalEncodingConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct SinCos2dPositionalEncoding<B: Backend> {
+    /// The sinusoids of the positions of the grid of shape `[1, max_h * max_w, d_model]`.
+    pub sinusoids: Tensor<B, 3>,
+    /// The height of the grid of positions.
+    pub max_h: usize,
+    /// The width of the grid of positions.
+    pub max_w: usize,
+    /// The interpolation used to rescale the encoding to another grid size, if any.
+    pub interpolation: Ignored<Option<InterpolateMode>>,
+}
+
+impl<B: Backend> ModuleDisplay for SinCos2dPositionalEncoding<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, _, d_model] = self.sinusoids.shape().dims();
+        content
+            .add("d_model", &d_model)
+            .add("max_h", &self.max_h)
+            .add("max_w", &self.max_w)
+            .add("interpolation", &self.interpolation)
+            .optional()
+    }
+}
+
+impl SinCos2dPositionalEncodingConfig {
+    /// Initialize a new [SinCos2dPositionalEncoding](SinCos2dPositionalEncoding) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SinCos2dPositionalEncoding<B> {
+        let sinusoids = generate_sinusoids_2d::<B>(
+            self.max_h,
+            self.max_w,
+            self.d_model,
+            self.temperature,
+            device,
+        )
+        .unsqueeze::<3>();
+
+        SinCos2dPositionalEncoding {
+            sinusoids,
+            max_h: self.max_h,
+            max_w: self.max_w,
+            interpolation: Ignored(self.interpolation.clone()),
+        }
+    }
+}
+
+impl<B: Backend> SinCos2dPositionalEncoding<B> {
+    /// Applies the forward pass on the input tensor by adding the encoding of the positions of
+    /// the `[height, width]` grid of the input.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[batch_size, height * width, d_model]`
+    /// * output: `[batch_size, height * width, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the input sequence length isn't the number of positions of the grid.
+    /// * Panics if the input d_model is not equal to the d_model of the sinusoids.
+    /// * Panics if the grid is larger than `[max_h, max_w]` and no interpolation is configured.
+    pub fn forward(&self, input: Tensor<B, 3>, grid_size: [usize; 2]) -> Tensor<B, 3> {
+        let [_, seq_length, d_model_input] = input.dims();
+        let [_, _, d_model] = self.sinusoids.dims();
+        let [height, width] = grid_size;
+
+        assert!(
+            seq_length == height * width,
+            "length({seq_length}) must be equal to the number of positions of the grid({height}x{width})",
+        );
+        assert!(
+            d_model_input == d_model,
+            "d_model({d_model_input}) of the input must be equal to d_model of encoding({d_model})",
+        );
+
+        if grid_size == [self.max_h, self.max_w] {
+            return input + self.sinusoids.clone();
+        }
+
+        let grid = self
+            .sinusoids
+            .clone()
+            .reshape([1, self.max_h, self.max_w, d_model]);
+        let grid = match &self.interpolation.0 {
+            Some(mode) => {
+                let grid = interpolate(
+                    grid.permute([0, 3, 1, 2]),
+                    grid_size,
+                    InterpolateOptions::new(mode.clone().into()),
+                );
+                grid.permute([0, 2, 3, 1])
+            }
+            None => {
+                assert!(
+                    height <= self.max_h && width <= self.max_w,
+                    "grid({height}x{width}) must fit in the grid of the encoding({}x{}) without interpolation",
+                    self.max_h,
+                    self.max_w,
+                );
+                grid.slice([0..1, 0..height, 0..width])
+            }
+        };
+
+        input + grid.reshape([1, seq_length, d_model])
+    }
+}
+
+/// Returns the 2D sinusoids of the positions of a grid used by vision transformers.
+///
+/// The reference implementation can be found here:
+/// [MAE](https://github.com/facebookresearch/mae/blob/main/util/pos_embed.py)
+///
+/// # Arguments
+///
+/// * `height` - The height of the grid.
+/// * `width` - The width of the grid.
+/// * `d_model` - The size of each vector.
+/// * `temperature` - The temperature scaling the frequencies.
+///
+/// # Returns
+///
+/// A tensor of shape [height * width, d_model] containing the sinusoids of the positions of the
+/// grid, flattened row by row.
+pub fn generate_sinusoids_2d<B: Backend>(
+    height: usize,
+    width: usize,
+    d_model: usize,
+    temperature: usize,
+    device: &B::Device,
+) -> Tensor<B, 2> {
+    assert!(d_model % 4 == 0, "d_model must be a multiple of 4");
+
+    // Each coordinate is encoded with a quarter of the vector for the sines and the cosines
+    let num_frequencies = d_model / 4;
+    let frequencies: Vec<f32> = (0..num_frequencies)
+        .map(|k| 1.0 / (temperature as f32).powf(k as f32 / num_frequencies as f32))
+        .collect();
+
+    let encode = |position: usize, row: &mut Vec<f32>| {
+        row.extend(frequencies.iter().map(|f| (position as f32 * f).sin()));
+        row.extend(frequencies.iter().map(|f| (position as f32 * f).cos()));
+    };
+
+    let mut sinusoids = Vec::with_capacity(height * width * d_model);
+    for y in 0..height {
+        for x in 0..width {
+            encode(x, &mut sinusoids);
+            encode(y, &mut sinusoids);
+        }
+    }
+
+    let data = TensorData::new(sinusoids, [height * width, d_model]);
+
+    Tensor::<B, 2>::from_data(data, device)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::{Int, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    /// The encoding of MAE for a `[2, 3]` grid with `d_model` of 8.
+    fn reference() -> TensorData {
+        TensorData::from([
+            [
+                0.00000f32, 0.00000, 1.00000, 1.00000, 0.00000, 0.00000, 1.00000, 1.00000,
+            ],
+            [
+                0.84147, 0.01000, 0.54030, 0.99995, 0.00000, 0.00000, 1.00000, 1.00000,
+            ],
+            [
+                0.90930, 0.02000, -0.41615, 0.99980, 0.00000, 0.00000, 1.00000, 1.00000,
+            ],
+            [
+                0.00000, 0.00000, 1.00000, 1.00000, 0.84147, 0.01000, 0.54030, 0.99995,
+            ],
+            [
+                0.84147, 0.01000, 0.54030, 0.99995, 0.84147, 0.01000, 0.54030, 0.99995,
+            ],
+            [
+                0.90930, 0.02000, -0.41615, 0.99980, 0.84147, 0.01000, 0.54030, 0.99995,
+            ],
+        ])
+    }
+
+    #[test]
+    fn test_sinusoids_2d_match_reference() {
+        let sinusoids = generate_sinusoids_2d::<TestBackend>(2, 3, 8, 10_000, &Default::default());
+
+        sinusoids
+            .into_data()
+            .assert_approx_eq::<FT>(&reference(), Tolerance::absolute(1e-4));
+    }
+
+    #[test]
+    fn test_module_adds_encoding() {
+        let device = Default::default();
+        let pe = SinCos2dPositionalEncodingConfig::new(8, 2, 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::ones([2, 6, 8], &device);
+
+        let output = pe.forward(input, [2, 3]);
+
+        let expected = Tensor::<TestBackend, 2>::from_data(reference(), &device)
+            .add_scalar(1.0)
+            .unsqueeze::<3>()
+            .repeat_dim(0, 2);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-4));
+    }
+
+    #[test]
+    fn test_smaller_grid_uses_its_positions() {
+        let device = Default::default();
+        let pe = SinCos2dPositionalEncodingConfig::new(8, 4, 5).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::zeros([1, 6, 8], &device);
+
+        let output = pe.forward(input, [2, 3]);
+
+        output
+            .squeeze::<2>(0)
+            .into_data()
+            .assert_approx_eq::<FT>(&reference(), Tolerance::absolute(1e-4));
+    }
+
+    #[test]
+    fn test_interpolation_rescales_encoding() {
+        let device = Default::default();
+        let pe = SinCos2dPositionalEncodingConfig::new(8, 2, 2)
+            .with_interpolation(Some(InterpolateMode::Linear))
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::zeros([1, 9, 8], &device);
+
+        let output = pe.forward(input, [3, 3]).squeeze::<2>(0);
+
+        // The corners keep their encoding, the center is the average of the corners
+        let corners = pe.sinusoids.clone().squeeze::<2>(0);
+        let center = corners.clone().mean_dim(0);
+        let expected = Tensor::cat(vec![corners, center], 0);
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([0, 2, 6, 8, 4], &device);
+        output
+            .select(0, indices)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-4));
+    }
+
+    #[test]
+    #[should_panic = "without interpolation"]
+    fn test_larger_grid_without_interpolation_panics() {
+        let device = Default::default();
+        let pe = SinCos2dPositionalEncodingConfig::new(8, 2, 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::zeros([1, 12, 8], &device);
+
+        let _output = pe.forward(input, [3, 4]);
+    }
+
+    #[test]
+    #[should_panic = "d_model must be a multiple of 4"]
+    fn test_d_model_not_multiple_of_4_panics() {
+        let _pe =
+            SinCos2dPositionalEncodingConfig::new(6, 2, 3).init::<TestBackend>(&Default::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = SinCos2dPositionalEncodingConfig::new(8, 2, 3);
+        let pe = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", pe),
+            "SinCos2dPositionalEncoding {d_model: 8, max_h: 2, max_w: 3, interpolation: None}"
+        );
+    }
+}
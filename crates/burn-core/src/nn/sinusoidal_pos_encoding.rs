@@ -0,0 +1,216 @@
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::{Initializer, generate_sinusoids};
+
+use crate::tensor::Tensor;
+use crate::tensor::backend::Backend;
+
+/// Configuration to create a [SinusoidalPositionalEncoding](SinusoidalPositionalEncoding) layer using the [init function](SinusoidalPositionalEncodingConfig::init).
+#[derive(Config, Debug)]
+pub struct SinusoidalPositionalEncodingConfig {
+    /// The size of each vector.
+    pub d_model: usize,
+
+    /// Maximum sequence length to use.
+    #[config(default = "5_000")]
+    pub max_len: usize,
+
+    /// If a trainable amplitude should scale each frequency band.
+    #[config(default = false)]
+    pub learnable_amplitude: bool,
+
+    /// Max time scale to use.
+    #[config(default = "10_000")]
+    pub max_timescale: usize,
+}
+
+/// Sinusoidal positional encoding layer with an optional learnable amplitude.
+///
+/// The sinusoids are the same as the ones of [PositionalEncoding](crate::nn::PositionalEncoding),
+/// introduced in [Attention is all you need](https://arxiv.org/abs/1706.03762). When the amplitude
+/// is learnable, each sine and cosine component is scaled by its own trainable parameter
+/// (initialized to one) before being added to the input, letting the model learn how much each
+/// frequency band contributes.
+///
+/// Should be created using [SinusoidalPositionalEncodingConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct SinusoidalPositionalEncoding<B: Backend> {
+    /// The sinusoids used to add positional information to the input embeddings.
+    pub sinusoids: Tensor<B, 3>,
+    /// The amplitude of each frequency band of shape `[d_model]`, if learnable.
+    pub amplitude: Option<Param<Tensor<B, 1>>>,
+    /// The maximum sequence length to use.
+    pub max_len: usize,
+}
+
+impl<B: Backend> ModuleDisplay for SinusoidalPositionalEncoding<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, _, d_model] = self.sinusoids.shape().dims();
+        content
+            .add("d_model", &d_model)
+            .add("max_len", &self.max_len)
+            .add("learnable_amplitude", &self.amplitude.is_some())
+            .optional()
+    }
+}
+
+impl SinusoidalPositionalEncodingConfig {
+    /// Initialize a new [SinusoidalPositionalEncoding](SinusoidalPositionalEncoding) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SinusoidalPositionalEncoding<B> {
+        let sinusoids =
+            generate_sinusoids::<B>(self.max_len, self.d_model, self.max_timescale, device)
+                .unsqueeze::<3>();
+
+        let amplitude = self
+            .learnable_amplitude
+            .then(|| Initializer::Ones.init([self.d_model], device));
+
+        SinusoidalPositionalEncoding {
+            sinusoids,
+            amplitude,
+            max_len: self.max_len,
+        }
+    }
+}
+
+impl<B: Backend> SinusoidalPositionalEncoding<B> {
+    /// Applies the forward pass on the input tensor by adding the (scaled) sinusoids to the input.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[batch_size, seq_length, d_model]`
+    /// * output: `[batch_size, seq_length, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the input sequence length is greater than the maximum sequence length.
+    /// * Panics if the input d_model is not equal to the d_model of the sinusoids.
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_, seq_length, d_model_input] = input.dims();
+        let [_, max_len, d_model] = self.sinusoids.dims();
+
+        assert!(
+            max_len >= seq_length,
+            "max_len({max_len}) must be greater or equal than length({seq_length})",
+        );
+        assert!(
+            d_model_input == d_model,
+            "d_model({d_model_input}) of the input must be equal to d_model of encoding({d_model})",
+        );
+
+        let sinusoids = self
+            .sinusoids
+            .clone()
+            .slice([0..1, 0..seq_length, 0..d_model]);
+        let sinusoids = match &self.amplitude {
+            Some(amplitude) => sinusoids * amplitude.val().unsqueeze(),
+            None => sinusoids,
+        };
+
+        input + sinusoids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::PositionalEncodingConfig;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::{Distribution, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn matches_positional_encoding() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 3>::random([2, 7, 6], Distribution::Default, &device);
+        let expected = PositionalEncodingConfig::new(6)
+            .init::<TestBackend>(&device)
+            .forward(input.clone());
+
+        for learnable_amplitude in [false, true] {
+            let pe = SinusoidalPositionalEncodingConfig::new(6)
+                .with_learnable_amplitude(learnable_amplitude)
+                .init::<TestBackend>(&device);
+
+            // The amplitude is initialized to one
+            pe.forward(input.clone())
+                .into_data()
+                .assert_approx_eq::<FT>(&expected.to_data(), Tolerance::default());
+        }
+    }
+
+    #[test]
+    fn amplitude_scales_frequency_bands() {
+        let device = Default::default();
+        let mut pe = SinusoidalPositionalEncodingConfig::new(4)
+            .with_max_len(8)
+            .with_learnable_amplitude(true)
+            .init::<TestBackend>(&device);
+        pe.amplitude = Some(Param::from_data([2.0, 0.0, 1.0, -1.0], &device));
+
+        let output = pe.forward(Tensor::zeros([1, 2, 4], &device));
+
+        let sinusoids = generate_sinusoids::<TestBackend>(2, 4, 10_000, &device);
+        let expected = sinusoids
+            * Tensor::<TestBackend, 1>::from_floats([2.0, 0.0, 1.0, -1.0], &device).unsqueeze();
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.unsqueeze::<3>().into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn amplitude_is_trainable() {
+        let device = Default::default();
+        let pe = SinusoidalPositionalEncodingConfig::new(4)
+            .with_learnable_amplitude(true)
+            .init::<TestAutodiffBackend>(&device);
+
+        let output = pe.forward(Tensor::zeros([3, 2, 4], &device));
+        let grads = output.sum().backward();
+        let grad = pe.amplitude.unwrap().grad(&grads).unwrap();
+
+        // The gradient of each band is the sum of its sinusoids over the batch
+        let expected = generate_sinusoids::<TestBackend>(2, 4, 10_000, &device).sum_dim(0) * 3;
+        grad.into_data()
+            .assert_approx_eq::<FT>(&expected.reshape([4]).into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn fixed_encoding_has_no_params() {
+        let pe =
+            SinusoidalPositionalEncodingConfig::new(4).init::<TestBackend>(&Default::default());
+
+        assert!(pe.amplitude.is_none());
+        assert_eq!(pe.num_params(), 0);
+    }
+
+    #[test]
+    #[should_panic = "max_len(4) must be greater or equal than length(5)"]
+    fn sequence_longer_than_max_len() {
+        let device = Default::default();
+        let pe = SinusoidalPositionalEncodingConfig::new(4)
+            .with_max_len(4)
+            .init::<TestBackend>(&device);
+
+        let _ = pe.forward(Tensor::zeros([1, 5, 4], &device));
+    }
+
+    #[test]
+    fn display() {
+        let config = SinusoidalPositionalEncodingConfig::new(4).with_learnable_amplitude(true);
+        let pe = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", pe),
+            "SinusoidalPositionalEncoding {d_model: 4, max_len: 5000, learnable_amplitude: true, params: 4}"
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay};
+use crate::nn::{Linear, LinearConfig};
+use crate::tensor::activation::{relu, sigmoid, silu};
+use crate::tensor::{Tensor, backend::Backend};
+
+/// The activation function between the two linear layers of a
+/// [squeeze-and-excitation](SqueezeExcitation) module.
+#[derive(Config, Debug, PartialEq, Eq, Copy)]
+pub enum SqueezeExcitationActivation {
+    /// The rectified linear unit, as in the original squeeze-and-excitation networks.
+    Relu,
+    /// The sigmoid linear unit, as in EfficientNet.
+    Silu,
+}
+
+/// Configuration to create a [squeeze-and-excitation](SqueezeExcitation) module using the
+/// [init function](SqueezeExcitationConfig::init).
+#[derive(Config, Debug)]
+pub struct SqueezeExcitationConfig {
+    /// The number of channels of the input.
+    pub channels: usize,
+    /// The number of channels of the bottleneck, usually `channels` divided by a reduction ratio
+    /// of 16.
+    pub squeeze_channels: usize,
+    /// The activation function of the bottleneck.
+    #[config(default = "SqueezeExcitationActivation::Relu")]
+    pub activation: SqueezeExcitationActivation,
+}
+
+/// Recalibrates the channels of a feature map, as described in the paper
+/// [Squeeze-and-Excitation Networks](https://arxiv.org/abs/1709.01507).
+///
+/// The channels are averaged over the spatial dimensions (squeeze) and go through a bottleneck of
+/// two linear layers with an activation between them, whose output is gated by a sigmoid to scale
+/// each channel of the input (excitation).
+///
+/// Should be created with [SqueezeExcitationConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct SqueezeExcitation<B: Backend> {
+    /// The linear layer reducing the channels to the bottleneck.
+    pub fc1: Linear<B>,
+    /// The linear layer expanding the bottleneck back to the channels.
+    pub fc2: Linear<B>,
+    /// The activation function of the bottleneck.
+    pub activation: Ignored<SqueezeExcitationActivation>,
+}
+
+impl<B: Backend> ModuleDisplay for SqueezeExcitation<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [channels, squeeze_channels] = self.fc1.weight.shape().dims();
+        content
+            .add("channels", &channels)
+            .add("squeeze_channels", &squeeze_channels)
+            .add("activation", &self.activation)
+            .optional()
+    }
+}
+
+impl SqueezeExcitationConfig {
+    /// Initialize a new [squeeze-and-excitation](SqueezeExcitation) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SqueezeExcitation<B> {
+        SqueezeExcitation {
+            fc1: LinearConfig::new(self.channels, self.squeeze_channels).init(device),
+            fc2: LinearConfig::new(self.squeeze_channels, self.channels).init(device),
+            activation: Ignored(self.activation),
+        }
+    }
+}
+
+impl<B: Backend> SqueezeExcitation<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [SqueezeExcitation](SqueezeExcitation) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height, width]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels, _, _] = input.dims();
+
+        let x = input
+            .clone()
+            .mean_dim(2)
+            .mean_dim(3)
+            .reshape([batch_size, channels]);
+        let x = self.fc1.forward(x);
+        let x = match self.activation.0 {
+            SqueezeExcitationActivation::Relu => relu(x),
+            SqueezeExcitationActivation::Silu => silu(x),
+        };
+        let scale = sigmoid(self.fc2.forward(x));
+
+        input * scale.reshape([batch_size, channels, 1, 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::module::Param;
+    use crate::tensor::{Distribution, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn output_shape_matches_input_shape() {
+        let device = Default::default();
+        let module = SqueezeExcitationConfig::new(16, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 16, 5, 6], Distribution::Default, &device);
+
+        let output = module.forward(input);
+
+        assert_eq!(output.dims(), [2, 16, 5, 6]);
+    }
+
+    #[test]
+    fn output_is_between_zero_and_input_max() {
+        let device = Default::default();
+
+        for activation in [
+            SqueezeExcitationActivation::Relu,
+            SqueezeExcitationActivation::Silu,
+        ] {
+            let module = SqueezeExcitationConfig::new(8, 2)
+                .with_activation(activation)
+                .init::<TestBackend>(&device);
+            // Non-negative like the output of a ReLU
+            let input = Tensor::<TestBackend, 4>::random(
+                [3, 8, 4, 4],
+                Distribution::Uniform(0.0, 5.0),
+                &device,
+            );
+            let input_max = input.clone().max().into_scalar();
+
+            let output = module.forward(input.clone());
+
+            // Each channel is scaled by a factor in (0, 1)
+            assert!(output.clone().lower_equal(input).all().into_scalar());
+            assert!(output.clone().greater_equal_elem(0.0).all().into_scalar());
+            assert!(output.max().into_scalar() <= input_max);
+        }
+    }
+
+    #[test]
+    fn channels_are_scaled_by_the_gate() {
+        let device = Default::default();
+        let mut module = SqueezeExcitationConfig::new(2, 1).init::<TestBackend>(&device);
+        module.fc1.weight = Param::from_data([[1.0], [1.0]], &device);
+        module.fc1.bias = Some(Param::from_data([0.0], &device));
+        module.fc2.weight = Param::from_data([[1.0, 1.0]], &device);
+        module.fc2.bias = Some(Param::from_data([0.0, -1.0], &device));
+        let input = Tensor::<TestBackend, 4>::from_floats(
+            [[[[1.0, 2.0], [3.0, 2.0]], [[-1.0, 0.0], [1.0, 0.0]]]],
+            &device,
+        );
+
+        let output = module.forward(input.clone());
+
+        // The channel means are 2 and 0, so the gates are sigmoid(2) and sigmoid(1)
+        let scale = Tensor::<TestBackend, 1>::from_floats([0.880797, 0.731059], &device);
+        let expected = input * scale.reshape([1, 2, 1, 1]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = SqueezeExcitationConfig::new(8, 2);
+        let module = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{module}"),
+            "SqueezeExcitation {channels: 8, squeeze_channels: 2, activation: Relu, params: 42}"
+        );
+    }
+}
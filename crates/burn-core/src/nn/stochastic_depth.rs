@@ -0,0 +1,174 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [StochasticDepth](StochasticDepth) layer using the
+/// [init function](StochasticDepthConfig::init).
+#[derive(Config, Debug)]
+pub struct StochasticDepthConfig {
+    /// The probability of dropping the residual branch of each sample during training.
+    pub drop_prob: f64,
+}
+
+/// Drop at random the entire residual branch of some samples during training, also known as
+/// drop-path.
+///
+/// This is the regularization of residual networks described in the paper
+/// [Deep Networks with Stochastic Depth](https://arxiv.org/abs/1603.09382): each sample skips the
+/// block with the probability `drop_prob`, so the network is shallower on average during
+/// training. Like the paper, the residual isn't rescaled during training, but scaled by the
+/// survival probability `1 - drop_prob` during inference.
+///
+/// Should be created with [StochasticDepthConfig].
+#[derive(Module, Clone, Debug)]
+#[module(custom_display)]
+pub struct StochasticDepth {
+    /// The probability of dropping the residual branch of each sample during training.
+    pub drop_prob: f64,
+}
+
+impl StochasticDepthConfig {
+    /// Initialize a new [stochastic depth](StochasticDepth) module.
+    pub fn init(&self) -> StochasticDepth {
+        if !(0.0..=1.0).contains(&self.drop_prob) {
+            panic!(
+                "Drop probability should be between 0 and 1, but got {}",
+                self.drop_prob
+            );
+        }
+        StochasticDepth {
+            drop_prob: self.drop_prob,
+        }
+    }
+}
+
+impl StochasticDepth {
+    /// Adds the residual branch to the input, dropping it for some samples during training.
+    ///
+    /// See [StochasticDepth](StochasticDepth) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ...]`
+    /// - residual: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn forward<B: Backend, const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        residual: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let prob_keep = 1.0 - self.drop_prob;
+
+        if !B::ad_enabled() {
+            return input + residual * prob_keep;
+        }
+        if self.drop_prob == 0.0 {
+            return input + residual;
+        }
+
+        // A single mask value per sample, broadcast over the other dimensions
+        let mut mask_shape = [1; D];
+        mask_shape[0] = residual.dims()[0];
+        let mask = Tensor::<B, D>::random(
+            mask_shape,
+            Distribution::Bernoulli(prob_keep),
+            &residual.device(),
+        );
+
+        input + residual * mask
+    }
+}
+
+impl ModuleDisplay for StochasticDepth {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("drop_prob", &self.drop_prob).optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    type FT = FloatElem<TestBackend>;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_whole_samples() {
+        let device = Default::default();
+        let input = Tensor::<TestAutodiffBackend, 4>::zeros(Shape::new([32, 3, 4, 4]), &device);
+        let residual = Tensor::ones(Shape::new([32, 3, 4, 4]), &device);
+        let layer = StochasticDepthConfig::new(0.5).init();
+
+        let output = layer.forward(input, residual);
+
+        let values = output.into_data().to_vec::<f32>().unwrap();
+        let mut num_dropped = 0;
+        for sample in values.chunks(3 * 4 * 4) {
+            if sample.iter().all(|value| *value == 0.0) {
+                num_dropped += 1;
+            } else {
+                assert!(
+                    sample.iter().all(|value| *value == 1.0),
+                    "Sample partially dropped: {sample:?}"
+                );
+            }
+        }
+        assert!(num_dropped > 0 && num_dropped < 32);
+    }
+
+    #[test]
+    fn without_ad_backend_should_scale_residual() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 0.0], [0.5, 2.0]]],
+            &device,
+        );
+        let residual = Tensor::<TestBackend, 3>::from_floats(
+            [[[4.0, -4.0], [2.0, 0.0]], [[1.0, 8.0], [-2.0, 6.0]]],
+            &device,
+        );
+        let layer = StochasticDepthConfig::new(0.25).init();
+
+        let output = layer.forward(input.clone(), residual.clone());
+
+        let expected = residual * 0.75 + input;
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = StochasticDepthConfig::new(0.1);
+        let layer = config.init();
+
+        assert_eq!(
+            alloc::format!("{}", layer),
+            "StochasticDepth {drop_prob: 0.1}"
+        );
+    }
+
+    #[test]
+    #[should_panic = "Drop probability should be between 0 and 1,"]
+    fn drop_prob_invalid() {
+        let config = StochasticDepthConfig::new(1.5);
+        let _layer = config.init();
+    }
+}
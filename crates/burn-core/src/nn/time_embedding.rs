@@ -0,0 +1,227 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::activation::silu;
+use crate::tensor::{Int, Tensor, backend::Backend};
+
+use super::{Initializer, Linear, LinearConfig};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create a [DiffusionTimeEmbedding](DiffusionTimeEmbedding) layer using the
+/// [init function](DiffusionTimeEmbeddingConfig::init).
+#[derive(Config, Debug)]
+pub struct DiffusionTimeEmbeddingConfig {
+    /// The size of the sinusoidal encoding and of the embedding. Must be even.
+    pub dim: usize,
+    /// The period of the lowest frequency of the sinusoids. Default: 10000
+    #[config(default = "10_000")]
+    pub max_period: usize,
+    /// The type of function used to initialize the linear layer parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Embeds the noise timesteps of diffusion models, as done by
+/// [DDPM](https://arxiv.org/abs/2006.11239) and [DDIM](https://arxiv.org/abs/2010.02502).
+///
+/// Each timestep `t` is encoded by the sines followed by the cosines of `t * w_k`, with the
+/// frequencies `w_k = max_period^(-k / (dim / 2))` decreasing geometrically, and the encoding is
+/// fed through two linear layers with a SiLU activation between them.
+///
+/// Should be created with [DiffusionTimeEmbeddingConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct DiffusionTimeEmbedding<B: Backend> {
+    /// The linear layer applied to the sinusoidal encoding.
+    pub linear_1: Linear<B>,
+    /// The linear layer applied after the activation.
+    pub linear_2: Linear<B>,
+    /// The period of the lowest frequency of the sinusoids.
+    pub max_period: usize,
+}
+
+impl<B: Backend> ModuleDisplay for DiffusionTimeEmbedding<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [dim, _] = self.linear_1.weight.shape().dims();
+        content
+            .add("dim", &dim)
+            .add("max_period", &self.max_period)
+            .optional()
+    }
+}
+
+impl DiffusionTimeEmbeddingConfig {
+    /// Initialize a new [DiffusionTimeEmbedding](DiffusionTimeEmbedding) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> DiffusionTimeEmbedding<B> {
+        assert!(
+            self.dim % 2 == 0,
+            "dim({}) of the time embedding must be even",
+            self.dim
+        );
+
+        let linear = || {
+            LinearConfig::new(self.dim, self.dim)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        DiffusionTimeEmbedding {
+            linear_1: linear(),
+            linear_2: linear(),
+            max_period: self.max_period,
+        }
+    }
+}
+
+impl<B: Backend> DiffusionTimeEmbedding<B> {
+    /// Applies the forward pass on the timesteps.
+    ///
+    /// # Shapes
+    ///
+    /// - timesteps: `[batch_size]`
+    /// - output: `[batch_size, dim]`
+    pub fn forward(&self, timesteps: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+        let x = self.sinusoids(timesteps);
+        let x = silu(self.linear_1.forward(x));
+
+        self.linear_2.forward(x)
+    }
+
+    /// The sinusoidal encoding of the timesteps, before the linear layers.
+    ///
+    /// # Shapes
+    ///
+    /// - timesteps: `[batch_size]`
+    /// - output: `[batch_size, dim]`
+    pub fn sinusoids(&self, timesteps: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+        let [dim, _] = self.linear_1.weight.dims();
+        let half = dim / 2;
+        let device = timesteps.device();
+
+        let frequencies = Tensor::<B, 1, Int>::arange(0..half as i64, &device)
+            .float()
+            .mul_scalar(-(self.max_period as f64).ln() / half as f64)
+            .exp()
+            .unsqueeze_dim(0);
+        let args = timesteps.float().unsqueeze_dim(1) * frequencies;
+
+        Tensor::cat([args.clone().sin(), args.cos()].to_vec(), 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec::Vec;
+    use burn_tensor::{Shape, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn forward_shape() {
+        let device = Default::default();
+        let embedding = DiffusionTimeEmbeddingConfig::new(8).init::<TestBackend>(&device);
+
+        let output = embedding.forward(Tensor::from_ints([0, 10, 999], &device));
+
+        assert_eq!(output.shape(), Shape::new([3, 8]));
+    }
+
+    #[test]
+    fn sinusoids_match_reference() {
+        let device = Default::default();
+        let embedding = DiffusionTimeEmbeddingConfig::new(4)
+            .with_max_period(100)
+            .init::<TestBackend>(&device);
+
+        let output = embedding.sinusoids(Tensor::from_ints([0, 1, 50], &device));
+
+        // The frequencies are 1 and 0.1
+        let expected = TensorData::from([
+            [0.0, 0.0, 1.0, 1.0],
+            [0.84147098, 0.09983342, 0.54030231, 0.99500417],
+            [-0.26237485, -0.95892427, 0.96496603, 0.28366219],
+        ]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn embedding_changes_continuously() {
+        let device = Default::default();
+        let dim = 16;
+        let embedding = DiffusionTimeEmbeddingConfig::new(dim).init::<TestBackend>(&device);
+        let timesteps = Tensor::<TestBackend, 1, Int>::arange(0..101, &device);
+
+        let sinusoids = embedding.sinusoids(timesteps);
+        let steps = sinusoids.clone().slice(1..101) - sinusoids.slice(0..100);
+        let steps = steps.powi_scalar(2).sum_dim(1).sqrt();
+
+        // The sinusoids are Lipschitz with the norm of the frequencies, at most the unit frequency
+        // times the square root of the number of frequencies
+        let bound = ((dim / 2) as f32).sqrt();
+        let steps = steps.into_data().to_vec::<f32>().unwrap();
+        assert!(steps.iter().all(|&step| step > 0.0 && step <= bound));
+    }
+
+    #[test]
+    fn frequency_band_is_rotation_invariant() {
+        let device = Default::default();
+        let (dim, band, shift) = (8, 1, 7);
+        let embedding = DiffusionTimeEmbeddingConfig::new(dim).init::<TestBackend>(&device);
+        let timesteps = [0, 3, 42, 500, 999];
+
+        let sinusoids = |timesteps: Vec<i32>| {
+            embedding
+                .sinusoids(Tensor::from_ints(timesteps.as_slice(), &device))
+                .select(1, Tensor::from_ints([band, band + dim as i32 / 2], &device))
+        };
+        let pairs = sinusoids(timesteps.to_vec());
+        let shifted = sinusoids(timesteps.iter().map(|t| t + shift).collect());
+
+        // A shift of the timesteps rotates the (sin, cos) pair of each band by the same angle, so
+        // the pairs stay on the unit circle and their product only depends on the shift
+        let frequency = 10_000f32.powf(-(band as f32) / (dim / 2) as f32);
+        let expected = TensorData::from([(frequency * shift as f32).cos(); 5]);
+        (pairs.clone() * shifted)
+            .sum_dim(1)
+            .reshape([5])
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::rel_abs(1e-4, 1e-4));
+        pairs
+            .powi_scalar(2)
+            .sum_dim(1)
+            .reshape([5])
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([1.0; 5]), Tolerance::rel_abs(1e-4, 1e-4));
+    }
+
+    #[test]
+    #[should_panic = "dim(5) of the time embedding must be even"]
+    fn odd_dim() {
+        DiffusionTimeEmbeddingConfig::new(5).init::<TestBackend>(&Default::default());
+    }
+
+    #[test]
+    fn display() {
+        let config = DiffusionTimeEmbeddingConfig::new(4);
+        let embedding = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{embedding}"),
+            "DiffusionTimeEmbedding {dim: 4, max_period: 10000, params: 40}"
+        );
+    }
+}
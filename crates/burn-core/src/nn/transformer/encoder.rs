@@ -2,7 +2,7 @@ use crate::tensor::Bool;
 use alloc::vec::Vec;
 
 use super::{PositionWiseFeedForward, PositionWiseFeedForwardConfig};
-use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay};
 use crate::{
     self as burn,
     nn::{Initializer, attention::MhaCache, cache::TensorCache},
@@ -30,9 +30,11 @@ pub struct TransformerEncoderConfig {
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     pub dropout: f64,
-    /// Layer norm will be applied first instead of after the other modules.
-    #[config(default = false)]
-    pub norm_first: bool,
+    /// Where the layer norms are applied relative to the other modules, e.g. first with a
+    /// [pre-norm](NormPlacement::Pre) or with a [sandwich norm](NormPlacement::Sandwich).
+    /// Default: [Post](NormPlacement::Post)
+    #[config(default = "NormPlacement::Post")]
+    pub norm_placement: NormPlacement,
     /// Use "quiet softmax" instead of regular softmax.
     ///
     /// - Usage may improve performance by allowing attention heads to deposit no information (if the sequence contains no information relevant to that head).
@@ -48,6 +50,27 @@ pub struct TransformerEncoderConfig {
     pub initializer: Initializer,
 }
 
+/// Placement of the layer normalization in a [transformer encoder](TransformerEncoder) layer.
+#[derive(Config, Debug, PartialEq, Eq, Copy)]
+pub enum NormPlacement {
+    /// Layer norm is applied on the input of each sub-layer (pre-norm).
+    Pre,
+    /// Layer norm is applied after each residual connection (post-norm).
+    Post,
+    /// Layer norm is applied on both the input and the output of each sub-layer, before the
+    /// residual connection.
+    ///
+    /// Reference: <https://arxiv.org/abs/2105.13290>
+    Sandwich,
+}
+
+impl NormPlacement {
+    /// Whether the input of each sub-layer is normalized.
+    fn norm_first(&self) -> bool {
+        matches!(self, NormPlacement::Pre | NormPlacement::Sandwich)
+    }
+}
+
 /// The transformer encoder module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
 ///
 /// # Params
@@ -76,8 +99,8 @@ pub struct TransformerEncoder<B: Backend> {
     /// The dropout rate. Default: 0.1
     pub dropout: f64,
 
-    /// Layer norm will be applied first instead of after the other modules.
-    pub norm_first: bool,
+    /// Where the layer norms are applied relative to the other modules.
+    pub norm_placement: Ignored<NormPlacement>,
 
     /// Use "quiet softmax" instead of regular softmax.
     pub quiet_softmax: bool,
@@ -97,7 +120,7 @@ impl<B: Backend> ModuleDisplay for TransformerEncoder<B> {
             .add("n_heads", &self.n_heads)
             .add("n_layers", &self.n_layers)
             .add("dropout", &self.dropout)
-            .add("norm_first", &self.norm_first)
+            .add("norm_placement", &self.norm_placement)
             .add("quiet_softmax", &self.quiet_softmax)
             .optional()
     }
@@ -147,10 +170,22 @@ impl TransformerEncoderConfig {
             n_heads: self.n_heads,
             n_layers: self.n_layers,
             dropout: self.dropout,
-            norm_first: self.norm_first,
+            norm_placement: Ignored(self.norm_placement),
             quiet_softmax: self.quiet_softmax,
         }
     }
+
+    /// Apply the layer norms first instead of after the other modules.
+    ///
+    /// Sets the [pre-norm](NormPlacement::Pre) placement when `norm_first` is true and the
+    /// [post-norm](NormPlacement::Post) placement otherwise.
+    #[deprecated(note = "Use `with_norm_placement` instead.")]
+    pub fn with_norm_first(self, norm_first: bool) -> Self {
+        self.with_norm_placement(match norm_first {
+            true => NormPlacement::Pre,
+            false => NormPlacement::Post,
+        })
+    }
 }
 
 impl<B: Backend> TransformerEncoder<B> {
@@ -210,8 +245,10 @@ pub struct TransformerEncoderLayer<B: Backend> {
     pwff: PositionWiseFeedForward<B>,
     norm_1: LayerNorm<B>,
     norm_2: LayerNorm<B>,
+    norm_sandwich_1: Option<LayerNorm<B>>,
+    norm_sandwich_2: Option<LayerNorm<B>>,
     dropout: Dropout,
-    norm_first: bool,
+    norm_placement: Ignored<NormPlacement>,
 }
 
 impl<B: Backend> TransformerEncoderLayer<B> {
@@ -223,6 +260,14 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             .init(device);
         let norm_1 = LayerNormConfig::new(config.d_model).init(device);
         let norm_2 = LayerNormConfig::new(config.d_model).init(device);
+        let norm_placement = config.norm_placement;
+        let (norm_sandwich_1, norm_sandwich_2) = match norm_placement {
+            NormPlacement::Sandwich => (
+                Some(LayerNormConfig::new(config.d_model).init(device)),
+                Some(LayerNormConfig::new(config.d_model).init(device)),
+            ),
+            _ => (None, None),
+        };
         let dropout = DropoutConfig::new(config.dropout).init();
         let pwff = PositionWiseFeedForwardConfig::new(config.d_model, config.d_ff)
             .with_initializer(config.initializer.clone())
@@ -233,9 +278,11 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             mha,
             norm_1,
             norm_2,
+            norm_sandwich_1,
+            norm_sandwich_2,
             pwff,
             dropout,
-            norm_first: config.norm_first,
+            norm_placement: Ignored(norm_placement),
         }
     }
 
@@ -250,7 +297,7 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         let mut residual_path = x.clone();
 
         // Normalize.
-        if self.norm_first {
+        if self.norm_placement.norm_first() {
             residual_path = self.norm_2.forward(residual_path)
         }
 
@@ -262,14 +309,19 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         if let Some(mask_attn) = mask_attn {
             input_mhs = input_mhs.mask_attn(mask_attn);
         }
-        let residual_path = self.mha.forward(input_mhs).context;
+        let mut residual_path = self.mha.forward(input_mhs).context;
+
+        // Normalize the sub-layer output (sandwich norm).
+        if let Some(norm) = &self.norm_sandwich_2 {
+            residual_path = norm.forward(residual_path)
+        }
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
 
         // Feed forward residual path.
         // Normalize.
-        let residual_path = if self.norm_first {
+        let residual_path = if self.norm_placement.norm_first() {
             self.norm_1.forward(x.clone())
         } else {
             x = self.norm_1.forward(x);
@@ -277,13 +329,19 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         };
 
         // Feed forward.
-        let residual_path = self.pwff.forward(residual_path);
+        let mut residual_path = self.pwff.forward(residual_path);
+
+        // Normalize the sub-layer output (sandwich norm).
+        if let Some(norm) = &self.norm_sandwich_1 {
+            residual_path = norm.forward(residual_path)
+        }
+
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
 
         // Main path.
         // Normalize.
-        if !self.norm_first {
+        if !self.norm_placement.norm_first() {
             x = self.norm_2.forward(x)
         }
 
@@ -302,7 +360,7 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         let mut residual_path = x.clone();
 
         // Normalize.
-        if self.norm_first {
+        if self.norm_placement.norm_first() {
             residual_path = cache
                 .norm_2
                 .forward_autoregressive(residual_path, 1, |x| self.norm_2.forward(x))
@@ -316,14 +374,21 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         if let Some(mask_attn) = mask_attn {
             input_mhs = input_mhs.mask_attn(mask_attn);
         }
-        let residual_path = self.mha.forward_cache(input_mhs, &mut cache.mha).context;
+        let mut residual_path = self.mha.forward_cache(input_mhs, &mut cache.mha).context;
+
+        // Normalize the sub-layer output (sandwich norm).
+        if let Some(norm) = &self.norm_sandwich_2 {
+            residual_path = cache
+                .norm_sandwich_2
+                .forward_autoregressive(residual_path, 1, |x| norm.forward(x))
+        }
 
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
 
         // Feed forward residual path.
         // Normalize.
-        let residual_path = if self.norm_first {
+        let residual_path = if self.norm_placement.norm_first() {
             cache
                 .norm_1
                 .forward_autoregressive(x.clone(), 1, |x| self.norm_1.forward(x))
@@ -335,15 +400,23 @@ impl<B: Backend> TransformerEncoderLayer<B> {
         };
 
         // Feed forward.
-        let residual_path = cache
+        let mut residual_path = cache
             .pwff
             .forward_autoregressive(residual_path, 1, |x| self.pwff.forward(x));
+
+        // Normalize the sub-layer output (sandwich norm).
+        if let Some(norm) = &self.norm_sandwich_1 {
+            residual_path = cache
+                .norm_sandwich_1
+                .forward_autoregressive(residual_path, 1, |x| norm.forward(x))
+        }
+
         let residual_path = self.dropout.forward(residual_path);
         let mut x = x + residual_path;
 
         // Main path.
         // Normalize.
-        if !self.norm_first {
+        if !self.norm_placement.norm_first() {
             x = cache
                 .norm_2
                 .forward_autoregressive(x, 1, |x| self.norm_2.forward(x))
@@ -358,6 +431,8 @@ struct TransformerEncoderLayerAutoregressiveCache<B: Backend> {
     pwff: TensorCache<B, 3>,
     norm_1: TensorCache<B, 3>,
     norm_2: TensorCache<B, 3>,
+    norm_sandwich_1: TensorCache<B, 3>,
+    norm_sandwich_2: TensorCache<B, 3>,
 }
 
 impl<B: Backend> TransformerEncoderLayerAutoregressiveCache<B> {
@@ -367,6 +442,8 @@ impl<B: Backend> TransformerEncoderLayerAutoregressiveCache<B> {
             pwff: TensorCache::empty(),
             norm_1: TensorCache::empty(),
             norm_2: TensorCache::empty(),
+            norm_sandwich_1: TensorCache::empty(),
+            norm_sandwich_2: TensorCache::empty(),
         }
     }
 }
@@ -392,7 +469,7 @@ impl<B: Backend> TransformerEncoderAutoregressiveCache<B> {
 mod tests {
     use super::*;
     use crate::tensor::Distribution;
-    use crate::{TestBackend, nn::attention::generate_autoregressive_mask};
+    use crate::{TestAutodiffBackend, TestBackend, nn::attention::generate_autoregressive_mask};
     use burn_tensor::{Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
 
@@ -401,7 +478,7 @@ mod tests {
         let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
         test_autoregressive(
             TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
-                .with_norm_first(false),
+                .with_norm_placement(NormPlacement::Post),
         )
     }
 
@@ -409,10 +486,112 @@ mod tests {
     fn test_autoregressive_norm_first() {
         let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
         test_autoregressive(
-            TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers).with_norm_first(true),
+            TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_norm_placement(NormPlacement::Pre),
+        )
+    }
+
+    #[test]
+    fn test_autoregressive_norm_sandwich() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        test_autoregressive(
+            TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+                .with_norm_placement(NormPlacement::Sandwich),
         )
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn test_norm_first_sets_the_norm_placement() {
+        let config = TransformerEncoderConfig::new(12, 24, 2, 1);
+
+        assert_eq!(config.norm_placement, NormPlacement::Post);
+        assert_eq!(
+            config.clone().with_norm_first(true).norm_placement,
+            NormPlacement::Pre
+        );
+        assert_eq!(
+            config
+                .with_norm_placement(NormPlacement::Sandwich)
+                .with_norm_first(false)
+                .norm_placement,
+            NormPlacement::Post
+        );
+    }
+
+    #[test]
+    fn test_norm_sandwich_differs_from_pre_and_post() {
+        let [batch_size, seq_length, d_model, d_ff, n_heads, num_layers] = [2, 4, 12, 24, 2, 2];
+        let device = Default::default();
+        let sandwich = TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+            .with_dropout(0.0)
+            .with_norm_placement(NormPlacement::Sandwich)
+            .init::<TestBackend>(&device);
+
+        // Same weights, only the norm placement differs.
+        let with_placement = |placement: NormPlacement| {
+            let mut transformer = sandwich.clone();
+            for layer in transformer.layers.iter_mut() {
+                layer.norm_placement = Ignored(placement);
+                layer.norm_sandwich_1 = None;
+                layer.norm_sandwich_2 = None;
+            }
+            transformer
+        };
+        let pre = with_placement(NormPlacement::Pre);
+        let post = with_placement(NormPlacement::Post);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = sandwich.forward(TransformerEncoderInput::new(tensor.clone()));
+        let output_pre = pre.forward(TransformerEncoderInput::new(tensor.clone()));
+        let output_post = post.forward(TransformerEncoderInput::new(tensor));
+
+        let diff_pre = (output.clone() - output_pre).abs().max().into_scalar();
+        let diff_post = (output - output_post).abs().max().into_scalar();
+        assert!(diff_pre > 1e-3, "Sandwich norm should differ from pre-norm");
+        assert!(
+            diff_post > 1e-3,
+            "Sandwich norm should differ from post-norm"
+        );
+    }
+
+    #[test]
+    fn test_norm_sandwich_gradients() {
+        let [batch_size, seq_length, d_model, d_ff, n_heads, num_layers] = [2, 4, 12, 24, 2, 1];
+        let device = Default::default();
+        let transformer = TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+            .with_norm_placement(NormPlacement::Sandwich)
+            .init::<TestAutodiffBackend>(&device);
+
+        let tensor = Tensor::<TestAutodiffBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let output = transformer.forward(TransformerEncoderInput::new(tensor));
+        let grads = output.mul_scalar(2.0).powi_scalar(2).sum().backward();
+
+        let layer = &transformer.layers[0];
+        let norms = [
+            &layer.norm_1,
+            &layer.norm_2,
+            layer.norm_sandwich_1.as_ref().unwrap(),
+            layer.norm_sandwich_2.as_ref().unwrap(),
+        ];
+        for norm in norms {
+            let grad = norm
+                .gamma
+                .grad(&grads)
+                .expect("Norm gamma should have a gradient");
+            assert!(grad.abs().sum().into_scalar() > 0.0);
+        }
+    }
+
     fn test_autoregressive(config: TransformerEncoderConfig) {
         let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
         let device = Default::default();
@@ -454,7 +633,7 @@ mod tests {
         assert_eq!(
             alloc::format!("{}", transformer),
             "TransformerEncoder {d_model: 2, d_ff: 4, n_heads: 2, \
-            n_layers: 3, dropout: 0.1, norm_first: false, quiet_softmax: false, params: 162}"
+            n_layers: 3, dropout: 0.1, norm_placement: Post, quiet_softmax: false, params: 162}"
         );
     }
 }
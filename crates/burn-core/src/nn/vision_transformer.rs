@@ -0,0 +1,236 @@
+use alloc::vec;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, Param};
+use crate::nn::{
+    Dropout, DropoutConfig, Initializer, LayerNorm, LayerNormConfig, Linear, LinearConfig,
+    conv::{Conv2d, Conv2dConfig},
+    transformer::{
+        NormPlacement, TransformerEncoder, TransformerEncoderConfig, TransformerEncoderInput,
+    },
+};
+use crate::tensor::{Tensor, backend::Backend};
+
+/// Configuration to create a [vision transformer](VisionTransformer) using the
+/// [init function](VisionTransformerConfig::init).
+///
+/// The configurations of the models of the paper can be created with [tiny](Self::tiny),
+/// [small](Self::small) and [base](Self::base).
+#[derive(Config, Debug)]
+pub struct VisionTransformerConfig {
+    /// The height and width of the input images.
+    pub image_size: usize,
+    /// The height and width of the patches, which must divide the image size.
+    pub patch_size: usize,
+    /// The number of transformer encoder layers.
+    pub num_layers: usize,
+    /// The size of the patch embeddings.
+    pub d_model: usize,
+    /// The number of attention heads.
+    pub num_heads: usize,
+    /// The size of the hidden layer of the feed-forward networks.
+    pub mlp_dim: usize,
+    /// The number of classes of the classification head.
+    pub num_classes: usize,
+    /// The number of channels of the input images.
+    #[config(default = 3)]
+    pub channels: usize,
+    /// The dropout rate.
+    #[config(default = 0.0)]
+    pub dropout: f64,
+}
+
+/// The vision transformer (ViT) from the paper
+/// [An Image is Worth 16x16 Words](https://arxiv.org/abs/2010.11929).
+///
+/// The images are split into patches which are linearly embedded. A learned class token is
+/// prepended to the patch embeddings, learned position embeddings are added, and the sequence is
+/// processed by a pre-norm [transformer encoder](TransformerEncoder). The classification head is
+/// applied to the final embedding of the class token.
+///
+/// Should be created with [VisionTransformerConfig].
+#[derive(Module, Debug)]
+pub struct VisionTransformer<B: Backend> {
+    /// The convolution embedding each patch, with a kernel and a stride of the patch size.
+    pub patch_embedding: Conv2d<B>,
+    /// The class token of shape `[1, 1, d_model]`.
+    pub class_token: Param<Tensor<B, 3>>,
+    /// The position embeddings of shape `[1, num_patches + 1, d_model]`.
+    pub position_embedding: Param<Tensor<B, 3>>,
+    /// The dropout applied to the embeddings.
+    pub dropout: Dropout,
+    /// The transformer encoder.
+    pub encoder: TransformerEncoder<B>,
+    /// The layer norm applied to the output of the encoder.
+    pub norm: LayerNorm<B>,
+    /// The classification head.
+    pub head: Linear<B>,
+}
+
+impl VisionTransformerConfig {
+    /// The configuration of ViT-Ti/16 for `224x224` images.
+    pub fn tiny(num_classes: usize) -> Self {
+        Self::new(224, 16, 12, 192, 3, 768, num_classes)
+    }
+
+    /// The configuration of ViT-S/16 for `224x224` images.
+    pub fn small(num_classes: usize) -> Self {
+        Self::new(224, 16, 12, 384, 6, 1536, num_classes)
+    }
+
+    /// The configuration of ViT-B/16 for `224x224` images.
+    pub fn base(num_classes: usize) -> Self {
+        Self::new(224, 16, 12, 768, 12, 3072, num_classes)
+    }
+
+    /// The number of patches of an image.
+    pub fn num_patches(&self) -> usize {
+        (self.image_size / self.patch_size).pow(2)
+    }
+
+    /// Initialize a new [vision transformer](VisionTransformer) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch size doesn't divide the image size.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> VisionTransformer<B> {
+        assert!(
+            self.image_size % self.patch_size == 0,
+            "image size({}) must be a multiple of the patch size({})",
+            self.image_size,
+            self.patch_size
+        );
+
+        let patch_size = [self.patch_size, self.patch_size];
+        let embedding_init = Initializer::Normal {
+            mean: 0.0,
+            std: 0.02,
+        };
+
+        VisionTransformer {
+            patch_embedding: Conv2dConfig::new([self.channels, self.d_model], patch_size)
+                .with_stride(patch_size)
+                .init(device),
+            class_token: Initializer::Zeros.init([1, 1, self.d_model], device),
+            position_embedding: embedding_init
+                .init([1, self.num_patches() + 1, self.d_model], device),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            encoder: TransformerEncoderConfig::new(
+                self.d_model,
+                self.mlp_dim,
+                self.num_heads,
+                self.num_layers,
+            )
+            .with_dropout(self.dropout)
+            .with_norm_placement(NormPlacement::Pre)
+            .init(device),
+            norm: LayerNormConfig::new(self.d_model)
+                .with_epsilon(1e-6)
+                .init(device),
+            head: LinearConfig::new(self.d_model, self.num_classes).init(device),
+        }
+    }
+}
+
+impl<B: Backend> VisionTransformer<B> {
+    /// Applies the forward pass on the images, returning the logits of the classes.
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, image_size, image_size]`
+    /// - output: `[batch_size, num_classes]`
+    pub fn forward(&self, images: Tensor<B, 4>) -> Tensor<B, 2> {
+        let features = self.forward_features(images);
+        let [batch_size, _, d_model] = features.dims();
+
+        let class_embedding = features
+            .slice([0..batch_size, 0..1])
+            .reshape([batch_size, d_model]);
+
+        self.head.forward(class_embedding)
+    }
+
+    /// Applies the forward pass on the images without the classification head, returning the
+    /// final embeddings of the class token followed by the ones of the patches.
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, image_size, image_size]`
+    /// - output: `[batch_size, num_patches + 1, d_model]`
+    pub fn forward_features(&self, images: Tensor<B, 4>) -> Tensor<B, 3> {
+        let [batch_size, _, _, _] = images.dims();
+
+        // [batch_size, d_model, height / patch_size, width / patch_size] to
+        // [batch_size, num_patches, d_model]
+        let patches = self
+            .patch_embedding
+            .forward(images)
+            .flatten::<3>(2, 3)
+            .swap_dims(1, 2);
+        let [_, _, d_model] = patches.dims();
+
+        let class_token = self.class_token.val().expand([batch_size, 1, d_model]);
+        let x = Tensor::cat(vec![class_token, patches], 1) + self.position_embedding.val();
+        let x = self.dropout.forward(x);
+
+        let x = self.encoder.forward(TransformerEncoderInput::new(x));
+
+        self.norm.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::tensor::Distribution;
+
+    #[test]
+    fn tiny_output_shapes() {
+        let device = Default::default();
+        let vit = VisionTransformerConfig {
+            image_size: 32,
+            ..VisionTransformerConfig::tiny(10)
+        }
+        .init::<TestBackend>(&device);
+        let images =
+            Tensor::<TestBackend, 4>::random([2, 3, 32, 32], Distribution::Default, &device);
+
+        let features = vit.forward_features(images.clone());
+        let output = vit.forward(images);
+
+        assert_eq!(features.dims(), [2, 5, 192]);
+        assert_eq!(output.dims(), [2, 10]);
+    }
+
+    #[test]
+    fn tiny_num_params() {
+        let vit = VisionTransformerConfig::tiny(1000).init::<TestBackend>(&Default::default());
+
+        // Same as the ViT-Ti/16 of timm
+        assert_eq!(vit.num_params(), 5_717_416);
+    }
+
+    #[test]
+    fn flexible_patch_size() {
+        let device = Default::default();
+        let config = VisionTransformerConfig::new(12, 4, 2, 16, 2, 32, 3).with_channels(1);
+        let vit = config.init::<TestBackend>(&device);
+        let images =
+            Tensor::<TestBackend, 4>::random([1, 1, 12, 12], Distribution::Default, &device);
+
+        let features = vit.forward_features(images);
+
+        assert_eq!(config.num_patches(), 9);
+        assert_eq!(features.dims(), [1, 10, 16]);
+    }
+
+    #[test]
+    #[should_panic = "must be a multiple of the patch size"]
+    fn patch_size_not_dividing_image_size_panics() {
+        let _vit = VisionTransformerConfig::new(10, 4, 1, 8, 2, 16, 2)
+            .init::<TestBackend>(&Default::default());
+    }
+}
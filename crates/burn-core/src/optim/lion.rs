@@ -0,0 +1,260 @@
+use crate::{
+    self as burn, LearningRate, grad_clipping::GradientClippingConfig, module::AutodiffModule,
+    record::Record,
+};
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{Tensor, backend::AutodiffBackend};
+use burn_tensor::{backend::Backend, ops::Device};
+
+/// Lion configuration.
+#[derive(Config)]
+pub struct LionConfig {
+    /// Interpolation factor between the momentum and the gradient used for the update.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Decay factor of the momentum.
+    #[config(default = 0.99)]
+    beta_2: f32,
+    /// Decoupled weight decay, applied as in [AdamW](crate::optim::AdamW).
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Lion (EvoLved Sign Momentum) optimizer as described in the paper
+/// [Symbolic Discovery of Optimization Algorithms](https://arxiv.org/abs/2302.06675).
+///
+/// The update only uses the sign of an interpolation between the momentum and the gradient, so
+/// every parameter moves by the same magnitude. Only the momentum is tracked, making the state
+/// half the size of [Adam](crate::optim::Adam)'s. Lion usually needs a learning rate 3-10x
+/// smaller than AdamW.
+#[derive(Clone)]
+pub struct Lion {
+    beta_1: f32,
+    beta_2: f32,
+    weight_decay: f32,
+}
+
+/// Lion state.
+#[derive(Record, Clone, new)]
+pub struct LionState<B: Backend, const D: usize> {
+    /// The exponential moving average of the gradients.
+    pub momentum: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lion {
+    type State<const D: usize> = LionState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let momentum = match state {
+            Some(state) => state.momentum,
+            None => grad.zeros_like(),
+        };
+
+        let update = momentum
+            .clone()
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1))
+            .sign();
+        let momentum = momentum
+            .mul_scalar(self.beta_2)
+            .add(grad.mul_scalar(1.0 - self.beta_2));
+
+        let tensor = if self.weight_decay > 0.0 {
+            tensor.clone() - tensor.mul_scalar(lr).mul_scalar(self.weight_decay)
+        } else {
+            tensor
+        };
+
+        (
+            tensor - update.mul_scalar(lr),
+            Some(LionState::new(momentum)),
+        )
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+impl LionConfig {
+    /// Initialize Lion optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> OptimizerAdaptor<Lion, M, B> {
+        let optim = Lion {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            weight_decay: self.weight_decay,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn_tensor::Tolerance;
+    use burn_tensor::ops::FloatElem;
+
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::nn::loss::CrossEntropyLossConfig;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Distribution, Int, TensorData};
+    use crate::{TestAutodiffBackend, nn};
+
+    type FT = FloatElem<TestAutodiffBackend>;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[derive(Module, Debug)]
+    struct Quadratic<B: Backend> {
+        weight: Param<Tensor<B, 1>>,
+    }
+
+    impl<B: Backend> Quadratic<B> {
+        /// `sum((w - target)^2)`, minimized at `w = target`.
+        fn loss(&self, target: &Tensor<B, 1>) -> Tensor<B, 1> {
+            (self.weight.val() - target.clone()).powi_scalar(2).sum()
+        }
+    }
+
+    #[test]
+    fn test_lion_converges_on_quadratic() {
+        let device = Default::default();
+        let target = Tensor::<TestAutodiffBackend, 1>::from_floats([1.5, -0.75, 0.2], &device);
+        let mut model = Quadratic {
+            weight: Param::from_tensor(Tensor::zeros([3], &device)),
+        };
+        let mut optimizer = LionConfig::new().init();
+
+        for iteration in 0..1000 {
+            // Decaying the learning rate damps the oscillations around the minimum
+            let lr = LEARNING_RATE * (1.0 - iteration as f64 / 1000.0);
+            let grads = model.loss(&target).backward();
+            let grads = GradientsParams::from_grads(grads, &model);
+            model = optimizer.step(lr, model, grads);
+        }
+
+        model
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&target.into_data(), Tolerance::absolute(2e-2));
+    }
+
+    #[test]
+    fn test_lion_optimizer_with_numbers() {
+        let device = Default::default();
+        let target = Tensor::<TestAutodiffBackend, 1>::from_floats([1.0, -1.0, 0.0], &device);
+        let mut model = Quadratic {
+            weight: Param::from_data([0.0, 0.0, 0.5], &device),
+        };
+        let mut optimizer = LionConfig::new().with_weight_decay(0.5).init();
+
+        // Gradient 2 * (w - target) = [-2, 2, 1], the update is its sign
+        let grads = model.loss(&target).backward();
+        let grads = GradientsParams::from_grads(grads, &model);
+        model = optimizer.step(LEARNING_RATE, model, grads);
+
+        // Decayed weight [0, 0, 0.5 * (1 - 0.01 * 0.5)] minus the update
+        model.weight.val().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.01, -0.01, 0.4875]),
+            Tolerance::default(),
+        );
+
+        // The gradient [-1.98, 1.98, 0.975] keeps the sign of the momentum
+        let grads = model.loss(&target).backward();
+        let grads = GradientsParams::from_grads(grads, &model);
+        model = optimizer.step(LEARNING_RATE, model, grads);
+
+        model.weight.val().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.01995, -0.01995, 0.4750625]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_lion_state_is_one_tensor_per_param() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 4).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = LionConfig::new().init();
+
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let mut record = optimizer.to_record();
+        assert_eq!(record.len(), 2);
+
+        let weight_state = record.remove(&linear.weight.id).unwrap().into_state::<2>();
+        let bias_state = record
+            .remove(&linear.bias.as_ref().unwrap().id)
+            .unwrap()
+            .into_state::<1>();
+
+        // The momentum is the only tensor of the state, with the shape of the parameter
+        assert_eq!(weight_state.momentum.dims(), [6, 4]);
+        assert_eq!(bias_state.momentum.dims(), [4]);
+    }
+
+    #[test]
+    fn test_lion_trains_mnist_scale_classifier() {
+        // A linear classifier with the dimensions of MNIST (784 pixels, 10 classes), trained on
+        // noisy samples of one random prototype per class.
+        TestAutodiffBackend::seed(0);
+        let device = Default::default();
+        let prototypes =
+            Tensor::<TestAutodiffBackend, 2>::random([10, 784], Distribution::Default, &device);
+        let targets =
+            Tensor::<TestAutodiffBackend, 1, Int>::arange(0..64, &device).remainder_scalar(10);
+        let inputs = prototypes.select(0, targets.clone())
+            + Tensor::random([64, 784], Distribution::Normal(0.0, 0.1), &device);
+
+        let mut model = nn::LinearConfig::new(784, 10).init(&device);
+        let mut optimizer = LionConfig::new().with_weight_decay(0.1).init();
+        let loss_fn = CrossEntropyLossConfig::new().init(&device);
+
+        let loss = |model: &nn::Linear<TestAutodiffBackend>| {
+            loss_fn.forward(model.forward(inputs.clone()), targets.clone())
+        };
+        let initial_loss = loss(&model).into_scalar();
+
+        for _ in 0..20 {
+            let grads = loss(&model).backward();
+            let grads = GradientsParams::from_grads(grads, &model);
+            model = optimizer.step(1e-3, model, grads);
+        }
+
+        let final_loss = loss(&model).into_scalar();
+        assert!(
+            final_loss < initial_loss * 0.1,
+            "The loss should decrease from {initial_loss}, got {final_loss}"
+        );
+        let predictions = model.forward(inputs).argmax(1).squeeze::<1>(1);
+        let correct = predictions.equal(targets).int().sum().into_scalar();
+        assert!(
+            correct >= 60,
+            "The classifier should fit the samples, got {correct}/64 correct"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::module::{Module, ParamId, list_param_ids};
+use burn_tensor::backend::Backend;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A group of parameters optimized with a scaled learning rate, similar to the `param_groups` of
+/// PyTorch optimizers.
+///
+/// The parameters of the group are updated with `lr * lr_scale`, e.g. to fine-tune a pretrained
+/// backbone with a lower learning rate than the head. Groups are registered on an optimizer with
+/// [with_param_group](crate::optim::adaptor::OptimizerAdaptor::with_param_group), the parameters
+/// outside of any group use the learning rate unchanged.
+#[derive(Clone, Debug, new)]
+pub struct ParamGroup {
+    /// The ids of the parameters of the group.
+    pub params: Vec<ParamId>,
+    /// The factor applied to the learning rate for the parameters of the group.
+    pub lr_scale: f64,
+}
+
+impl ParamGroup {
+    /// Creates a group with all the parameters of the given module.
+    pub fn from_module<M: Module<B>, B: Backend>(module: &M, lr_scale: f64) -> Self {
+        Self::new(list_param_ids(module), lr_scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        TestAutodiffBackend,
+        nn::{Linear, LinearConfig},
+        optim::{AdamConfig, GradientsParams, Optimizer, SgdConfig},
+    };
+    use burn_tensor::{Distribution, ElementConversion, Tensor, Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestAutodiffBackend>;
+
+    const LEARNING_RATE: f64 = 0.1;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        backbone: Linear<B>,
+        head: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                backbone: LinearConfig::new(4, 4).init(device),
+                head: LinearConfig::new(4, 4).init(device),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            self.head.forward(self.backbone.forward(input))
+        }
+    }
+
+    fn distance<B: Backend>(before: &Linear<B>, after: &Linear<B>) -> f32 {
+        (after.weight.val() - before.weight.val())
+            .abs()
+            .max()
+            .into_scalar()
+            .elem()
+    }
+
+    #[test]
+    fn sgd_scales_lr_per_group() {
+        let device = Default::default();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+        let mut optim = SgdConfig::new()
+            .init()
+            .with_param_group(ParamGroup::from_module(&model.backbone, 0.01));
+
+        let input = Tensor::random([2, 4], Distribution::Default, &device);
+        let grads = model.forward(input).sum().backward();
+        let grad_backbone = model.backbone.weight.grad(&grads).unwrap();
+        let grad_head = model.head.weight.grad(&grads).unwrap();
+        let grads = GradientsParams::from_grads(grads, &model);
+
+        let updated = optim.step(LEARNING_RATE, model.clone(), grads);
+
+        let backbone_distance = distance(&model.backbone, &updated.backbone);
+        let head_distance = distance(&model.head, &updated.head);
+        assert!(
+            backbone_distance < head_distance,
+            "The backbone should move less ({backbone_distance}) than the head ({head_distance})"
+        );
+
+        // Plain SGD steps with the scaled and the unscaled learning rates
+        let expected_backbone =
+            model.backbone.weight.val().inner() - grad_backbone.mul_scalar(LEARNING_RATE * 0.01);
+        let expected_head = model.head.weight.val().inner() - grad_head.mul_scalar(LEARNING_RATE);
+        updated
+            .backbone
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_backbone.into_data(), Tolerance::default());
+        updated
+            .head
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_head.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn zero_lr_scale_freezes_group() {
+        let device = Default::default();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+        let mut optim = AdamConfig::new()
+            .init()
+            .with_param_group(ParamGroup::from_module(&model.backbone, 0.0));
+
+        let input = Tensor::random([2, 4], Distribution::Default, &device);
+        let grads = model.forward(input).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &model);
+        let updated = optim.step(LEARNING_RATE, model.clone(), grads);
+
+        assert_eq!(distance(&model.backbone, &updated.backbone), 0.0);
+        assert!(distance(&model.head, &updated.head) > 0.0);
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     LearningRate,
     grad_clipping::GradientClipping,
     module::{AutodiffModule, ModuleMapper, ParamId},
-    optim::{GradientsParams, Optimizer},
+    optim::{GradientsParams, Optimizer, ParamGroup},
 };
 use burn_tensor::{Tensor, backend::AutodiffBackend};
 use core::marker::PhantomData;
@@ -22,6 +22,7 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    lr_scales: HashMap<ParamId, f64>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +37,7 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            lr_scales: HashMap::new(),
         }
     }
 }
@@ -60,6 +62,24 @@ where
         self
     }
 
+    /// Adds a group of parameters updated with a scaled learning rate.
+    ///
+    /// When a parameter belongs to multiple groups, the last group added is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_param_group(mut self, group: ParamGroup) -> Self {
+        for id in group.params {
+            self.lr_scales.insert(id, group.lr_scale);
+        }
+        self
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -81,6 +101,7 @@ where
             &mut grads,
             lr,
             self.grad_clipping.as_ref(),
+            &self.lr_scales,
         );
         module.map(&mut mapper)
     }
@@ -108,6 +129,7 @@ where
     lr: LearningRate,
     phantom: PhantomData<M>,
     grad_clipping: Option<&'a GradientClipping>,
+    lr_scales: &'a HashMap<ParamId, f64>,
 }
 
 impl<M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'_, M, B, O>
@@ -130,8 +152,14 @@ where
                 grad
             };
 
+            // The parameters outside of any group use the learning rate unchanged
+            let lr = match self.lr_scales.get(&id) {
+                Some(lr_scale) => self.lr * lr_scale,
+                None => self.lr,
+            };
+
             let (tensor, state) = self.optimizer.step(
-                self.lr,
+                lr,
                 tensor.inner(),
                 clipped_grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
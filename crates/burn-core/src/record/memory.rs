@@ -103,7 +103,15 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkBytesRecorder<S>
 mod tests {
     use super::*;
     use crate::{
-        TestBackend, module::Module, nn, record::FullPrecisionSettings, tensor::backend::Backend,
+        TestBackend,
+        module::{Module, Quantizer},
+        nn,
+        record::{FullPrecisionSettings, HalfPrecisionSettings},
+        tensor::{
+            DType,
+            backend::Backend,
+            quantization::{Calibration, QuantPropagation, QuantScheme},
+        },
     };
 
     #[test]
@@ -134,6 +142,43 @@ mod tests {
         assert_eq!(bytes1, bytes2_after);
     }
 
+    #[test]
+    fn test_can_save_and_load_quantized_bin_format() {
+        test_can_save_and_load_quantized(BinBytesRecorder::<FullPrecisionSettings>::default());
+        test_can_save_and_load_quantized(BinBytesRecorder::<HalfPrecisionSettings>::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_can_save_and_load_quantized_named_mpk_format() {
+        test_can_save_and_load_quantized(NamedMpkBytesRecorder::<FullPrecisionSettings>::default());
+        test_can_save_and_load_quantized(NamedMpkBytesRecorder::<HalfPrecisionSettings>::default());
+    }
+
+    fn test_can_save_and_load_quantized<Recorder>(recorder: Recorder)
+    where
+        Recorder: BytesRecorder<TestBackend, Vec<u8>>,
+    {
+        let device = Default::default();
+        let mut quantizer = Quantizer {
+            calibration: Calibration::MinMax,
+            scheme: QuantScheme::default().set_propagation(QuantPropagation::Propagate),
+        };
+        let model = create_model::<TestBackend>(&device).quantize_weights(&mut quantizer);
+
+        let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+        let model_after = create_model::<TestBackend>(&device)
+            .load_record(recorder.load(bytes, &device).unwrap());
+
+        // The quantized values, parameters and scheme are loaded as is
+        let expected = model.weight.val().into_data();
+        let actual = model_after.weight.val().into_data();
+        assert_eq!(actual.dtype, DType::QFloat(quantizer.scheme));
+        assert_eq!(actual.dtype, expected.dtype);
+        assert_eq!(actual.shape, expected.shape);
+        assert_eq!(actual.as_bytes(), expected.as_bytes());
+    }
+
     pub fn create_model<B: Backend>(device: &B::Device) -> nn::Linear<B> {
         nn::LinearConfig::new(32, 32).with_bias(true).init(device)
     }
@@ -601,6 +601,6 @@ unary_func!(log1p, Line::<C>::log1p, Float);
 unary_func!(cos, Line::<C>::cos, Float);
 unary_func!(sin, Line::<C>::sin, Float);
 unary_func!(tanh, Line::<C>::tanh, Float);
-unary_func!(erf, Line::<C>::erf, Float);
+unary_func!(erf, burn_tensor::cube_math::erf::<C>, Float);
 unary_func!(recip, Line::<C>::recip, Float);
 unary_func!(abs, Line::<C>::abs, Numeric);
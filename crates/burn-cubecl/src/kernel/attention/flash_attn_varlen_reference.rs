@@ -0,0 +1,142 @@
+use crate::{
+    CubeRuntime, FloatElement, IntElement, ops::numeric::zeros_device, tensor::CubeTensor,
+};
+use burn_tensor::{Shape, ops::FlashAttnVarlenOptions};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// Each unit computes the attention output of one query row, accumulating the weighted values
+/// directly in the output with an online softmax, so the scores are never materialized.
+///
+/// This is a reference kernel, not a flash attention kernel: the keys and values aren't tiled in
+/// shared memory, and each unit loops serially over the keys of its sequence and over the head
+/// dimension. It only avoids the padded scores of the fallback implementation.
+#[cube(launch)]
+fn flash_attn_varlen_reference_kernel<F: Float, I: Int>(
+    q: &Tensor<F>,
+    k: &Tensor<F>,
+    v: &Tensor<F>,
+    cu_seqlens_q: &Tensor<I>,
+    cu_seqlens_k: &Tensor<I>,
+    output: &mut Tensor<F>,
+    softmax_scale: f32,
+    #[comptime] causal: bool,
+) {
+    let row = ABSOLUTE_POS;
+    if row >= output.shape(0) {
+        terminate!();
+    }
+
+    let head_dim = q.shape(1);
+    let value_dim = v.shape(1);
+    let (stride_cu_q, stride_cu_k) = (cu_seqlens_q.stride(0), cu_seqlens_k.stride(0));
+
+    // The sequence of the row is the last one starting at or before it, found by binary search
+    // since the offsets are sorted: the sequence `low` starts at or before the row, while the
+    // sequence `high` starts after it
+    let mut low = 0;
+    let mut high = cu_seqlens_q.shape(0) - 1;
+    loop {
+        if low + 1 >= high {
+            break;
+        }
+        let mid = (low + high) / 2;
+        if u32::cast_from(cu_seqlens_q[mid * stride_cu_q]) <= row {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    let seq = low;
+
+    let q_start = u32::cast_from(cu_seqlens_q[seq * stride_cu_q]);
+    let q_end = u32::cast_from(cu_seqlens_q[(seq + 1) * stride_cu_q]);
+    let k_start = u32::cast_from(cu_seqlens_k[seq * stride_cu_k]);
+    let k_end = u32::cast_from(cu_seqlens_k[(seq + 1) * stride_cu_k]);
+
+    let mut k_stop = k_end;
+    if causal {
+        // Bottom-right aligned, the query at `pos` sees the first `pos + 1 + len_k - len_q` keys
+        let limit = row - q_start + 1 + k_end - k_start;
+        let len_q = q_end - q_start;
+        if limit > len_q {
+            k_stop = Min::min(k_start + limit - len_q, k_end);
+        } else {
+            k_stop = k_start;
+        }
+    }
+
+    let scale = F::cast_from(softmax_scale);
+    let mut max = F::min_value();
+    let mut sum = F::new(0.0);
+
+    for j in k_start..k_stop {
+        let mut score = F::new(0.0);
+        for d in 0..head_dim {
+            score += q[row * q.stride(0) + d * q.stride(1)] * k[j * k.stride(0) + d * k.stride(1)];
+        }
+        score *= scale;
+
+        let new_max = Max::max(max, score);
+        let correction = F::exp(max - new_max);
+        let weight = F::exp(score - new_max);
+        sum = sum * correction + weight;
+
+        for c in 0..value_dim {
+            let index = row * output.stride(0) + c * output.stride(1);
+            let value = v[j * v.stride(0) + c * v.stride(1)];
+            output[index] = output[index] * correction + weight * value;
+        }
+        max = new_max;
+    }
+
+    // Queries without any key keep a zero output
+    if sum > F::new(0.0) {
+        for c in 0..value_dim {
+            let index = row * output.stride(0) + c * output.stride(1);
+            output[index] = output[index] / sum;
+        }
+    }
+}
+
+/// Variable-length attention computed by the reference kernel, one query row per unit.
+///
+/// The kernel doesn't apply dropout, the options are expected to have a zero dropout.
+pub(crate) fn flash_attn_varlen_reference<R: CubeRuntime, F: FloatElement, I: IntElement>(
+    q: CubeTensor<R>,
+    k: CubeTensor<R>,
+    v: CubeTensor<R>,
+    cu_seqlens_q: CubeTensor<R>,
+    cu_seqlens_k: CubeTensor<R>,
+    options: FlashAttnVarlenOptions,
+) -> CubeTensor<R> {
+    let [total_q, head_dim] = q.shape.dims();
+    let [_, value_dim] = v.shape.dims();
+    let scale = options
+        .softmax_scale
+        .unwrap_or(1.0 / (head_dim as f64).sqrt());
+
+    let output = zeros_device::<R, F>(
+        q.client.clone(),
+        q.device.clone(),
+        Shape::new([total_q, value_dim]),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(total_q, cube_dim);
+
+    flash_attn_varlen_reference_kernel::launch::<F, I, R>(
+        &q.client,
+        cube_count,
+        cube_dim,
+        q.as_tensor_arg::<F>(1),
+        k.as_tensor_arg::<F>(1),
+        v.as_tensor_arg::<F>(1),
+        cu_seqlens_q.as_tensor_arg::<I>(1),
+        cu_seqlens_k.as_tensor_arg::<I>(1),
+        output.as_tensor_arg::<F>(1),
+        ScalarArg::new(scale as f32),
+        options.causal,
+    );
+
+    output
+}
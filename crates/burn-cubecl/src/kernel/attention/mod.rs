@@ -0,0 +1,3 @@
+mod flash_attn_varlen_reference;
+
+pub(crate) use flash_attn_varlen_reference::*;
@@ -26,11 +26,15 @@ pub(crate) fn clamp<R: CubeRuntime, E: CubeElement>(
 
         fn execute(input: Line<N>, options: &Self::Options) -> Line<N> {
             let line_size = input.size();
-            Line::clamp(
+            let clamped = Line::clamp(
                 input,
                 Line::empty(line_size).fill(options.min_value),
                 Line::empty(line_size).fill(options.max_value),
-            )
+            );
+
+            // The clamp of some targets returns a bound for NaN, which is the only value not
+            // equal to itself
+            select_many(input.not_equal(input), input, clamped)
         }
     }
 
@@ -4,6 +4,7 @@ use crate::{
     kernel::{self},
     tensor::CubeTensor,
 };
+use burn_tensor::IndexReduceMode;
 use cubecl::prelude::*;
 use cubecl::{CubeDim, calculate_cube_count_elemwise};
 
@@ -13,6 +14,7 @@ fn scatter_kernel<T: Numeric, I: Int>(
     indices: &Tensor<I>,
     value: &Tensor<T>,
     dim: &u32,
+    #[comptime] mode: IndexReduceMode,
 ) {
     let stride_input = input.stride(*dim);
     let shape_value = value.shape(*dim);
@@ -59,9 +61,18 @@ fn scatter_kernel<T: Numeric, I: Int>(
         let mut index_input = stride_input * result_indices;
         index_input += offset_input;
 
-        let mut result_input = input[index_input];
-        result_input += result_value;
-        input[index_input] = result_input;
+        // Each position of the input is only reduced into by a single unit, which iterates over the
+        // dimension sequentially, so the reduction doesn't need atomics.
+        let result_input = input[index_input];
+        if comptime![mode == IndexReduceMode::Prod] {
+            input[index_input] = result_input * result_value;
+        } else if comptime![mode == IndexReduceMode::AMax] {
+            input[index_input] = Max::max(result_input, result_value);
+        } else if comptime![mode == IndexReduceMode::AMin] {
+            input[index_input] = Min::min(result_input, result_value);
+        } else {
+            input[index_input] = result_input + result_value;
+        }
     }
 }
 
@@ -70,6 +81,18 @@ pub(crate) fn scatter<R: CubeRuntime, E: CubeElement, I: IntElement>(
     tensor: CubeTensor<R>,
     indices: CubeTensor<R>,
     value: CubeTensor<R>,
+) -> CubeTensor<R> {
+    index_reduce::<R, E, I>(dim, tensor, indices, value, IndexReduceMode::Sum)
+}
+
+/// Reduce the values into the tensor at the given indices along `dim`, which generalizes
+/// [scatter] to the other [reduction modes](IndexReduceMode).
+pub(crate) fn index_reduce<R: CubeRuntime, E: CubeElement, I: IntElement>(
+    dim: usize,
+    tensor: CubeTensor<R>,
+    indices: CubeTensor<R>,
+    value: CubeTensor<R>,
+    mode: IndexReduceMode,
 ) -> CubeTensor<R> {
     let ndims = tensor.shape.num_dims();
     let mut indices = kernel::into_contiguous(indices);
@@ -113,6 +136,7 @@ pub(crate) fn scatter<R: CubeRuntime, E: CubeElement, I: IntElement>(
             indices.as_tensor_arg::<I>(1),
             value.as_tensor_arg::<E>(1),
             ScalarArg::new(dim as u32),
+            mode,
         )
     }
     tensor
@@ -22,6 +22,8 @@ pub(crate) use unary_numeric::*;
 pub use burn_common::PLANE_DIM_APPROX;
 pub use cubecl::Kernel;
 
+/// Attention kernels
+pub mod attention;
 /// Convolution kernels
 pub mod conv;
 /// Interpolation kernels
@@ -0,0 +1,131 @@
+use crate::CubeRuntime;
+use crate::tensor::CubeTensor;
+use burn_tensor::DType;
+use burn_tensor::quantization::{QuantInputType, QuantLevel, QuantMode, QuantScheme};
+use cubecl::calculate_cube_count_elemwise;
+use cubecl::prelude::*;
+
+use super::{QParams, QTensor, create_quantized_output, unpack_i8s};
+
+#[cube]
+fn quantize_bound(value: f32, scale: f32, offset: i32) -> i32 {
+    // x_q = clamp(round(x / scale + offset), a, b)
+    let value = i32::cast_from(f32::round(value / scale)) + offset;
+    Max::max(Min::min(value, 127), -128)
+}
+
+#[cube]
+fn clamp_i8s(value: u32, min: i32, max: i32) -> u32 {
+    let values = unpack_i8s(value);
+    let mut v_packed = 0;
+
+    #[unroll]
+    for i in 0..4 {
+        let value = Max::max(Min::min(values[i], max), min);
+        // NOTE: we add 256 before casting to unsigned to correctly represent negative values
+        v_packed |= (u32::cast_from(value + 256) & 0xFF) << (8 * i);
+    }
+    v_packed
+}
+
+#[cube(launch_unchecked)]
+fn clamp_per_tensor_int8_kernel(
+    input: &QTensor,
+    min: f32,
+    max: f32,
+    output: &mut QTensor,
+    #[comptime] scheme: QuantScheme,
+    #[comptime] num_qparams: u32,
+    #[comptime] clamp_min: bool,
+    #[comptime] clamp_max: bool,
+) {
+    if ABSOLUTE_POS >= input.len() {
+        terminate!();
+    }
+
+    // The qparams are unchanged, since the clamped values are on the same quantization grid
+    if ABSOLUTE_POS >= input.len() - num_qparams {
+        output[ABSOLUTE_POS] = input[ABSOLUTE_POS];
+        terminate!();
+    }
+
+    let qparams = QParams::new(scheme);
+    let (scale, offset) = qparams.values(input);
+
+    let mut q_min = -128;
+    let mut q_max = 127;
+    if comptime![clamp_min] {
+        q_min = quantize_bound(min, scale, offset);
+    }
+    if comptime![clamp_max] {
+        q_max = quantize_bound(max, scale, offset);
+    }
+
+    // Input line size is fixed to 1
+    output[ABSOLUTE_POS] = Line::new(clamp_i8s(input[ABSOLUTE_POS][0], q_min, q_max));
+}
+
+/// Clamp the quantized values of the tensor, without dequantizing it.
+///
+/// The bounds are quantized with the tensor parameters, so the output keeps the same quantization
+/// parameters.
+pub fn clamp_quantized<R: CubeRuntime>(
+    tensor: CubeTensor<R>,
+    min: Option<f32>,
+    max: Option<f32>,
+) -> CubeTensor<R> {
+    let DType::QFloat(scheme) = tensor.dtype else {
+        panic!(
+            "Invalid dtype (expected DType::QFloat, got {:?})",
+            tensor.dtype
+        )
+    };
+    let num_qparams = match scheme {
+        QuantScheme {
+            level: QuantLevel::Tensor,
+            mode: QuantMode::Symmetric,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => 1,
+        QuantScheme {
+            level: QuantLevel::Tensor,
+            mode: QuantMode::Affine,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => 2,
+    };
+
+    // Four int8 values packed in a single u32, followed by the qparams
+    let num_elems = usize::div_ceil(tensor.shape.num_elements(), 4) + num_qparams as usize;
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    let client = tensor.client.clone();
+    let mut output = create_quantized_output(
+        client.clone(),
+        tensor.shape.num_elements(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+        scheme,
+    );
+    // The values are clamped element-wise, so the packed layout is the same as the input
+    output.strides = tensor.strides.clone();
+
+    unsafe {
+        clamp_per_tensor_int8_kernel::launch_unchecked::<R>(
+            &client,
+            cube_count,
+            cube_dim,
+            tensor.as_array_arg::<u32>(1),
+            ScalarArg::new(min.unwrap_or(0.0)),
+            ScalarArg::new(max.unwrap_or(0.0)),
+            output.as_array_arg::<u32>(1),
+            scheme,
+            num_qparams,
+            min.is_some(),
+            max.is_some(),
+        )
+    };
+
+    output
+}
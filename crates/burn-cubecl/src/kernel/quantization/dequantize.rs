@@ -13,6 +13,12 @@ fn dequantize_symmetric_int8<F: Float>(value: Line<i32>, scale: f32) -> Line<F>
     Line::cast_from(scale) * Line::cast_from(value)
 }
 
+#[cube]
+fn dequantize_affine_int8<F: Float>(value: Line<i32>, scale: f32, offset: i32) -> Line<F> {
+    // x = scale * (x_q - offset)
+    Line::cast_from(scale) * Line::cast_from(value - Line::cast_from(offset))
+}
+
 #[cube]
 fn extract_i8(value: u32, offset: u32) -> i32 {
     // Extract 8-bit segment
@@ -24,7 +30,7 @@ fn extract_i8(value: u32, offset: u32) -> i32 {
 }
 
 #[cube]
-fn unpack_i8s(value: u32) -> Line<i32> {
+pub(crate) fn unpack_i8s(value: u32) -> Line<i32> {
     let mut line = Line::empty(4_u32);
     // Extract each 8-bit segment
     line[0] = extract_i8(value, 0);
@@ -65,6 +71,36 @@ fn dequantize_per_tensor_symmetric_int8_kernel(
     }
 }
 
+#[cube(launch_unchecked)]
+fn dequantize_per_tensor_affine_int8_kernel(
+    input: &QTensor,
+    output: &mut Tensor<Line<f32>>,
+    #[comptime] scheme: QuantScheme,
+) {
+    // Last two positions contain the qparams
+    if ABSOLUTE_POS >= input.len() - 2 {
+        terminate!();
+    }
+
+    let qparams = QParams::new(scheme);
+    let (scale, offset) = qparams.values(input);
+
+    let value = input[ABSOLUTE_POS];
+
+    // Input line size is fixed to 1
+    if comptime!(output.line_size() == 4) {
+        output[ABSOLUTE_POS] = dequantize_affine_int8(unpack_i8s(value[0]), scale, offset);
+    } else {
+        // For very small inputs where number of elements < 4, the output line size is 1
+        let out = dequantize_affine_int8::<f32>(unpack_i8s(value[0]), scale, offset);
+
+        #[unroll]
+        for j in 0..out.size() {
+            output[ABSOLUTE_POS * out.size() + j] = Line::cast_from(out[j]);
+        }
+    }
+}
+
 /// Convert the tensor back to a higher precision data type.
 pub fn dequantize<R, F>(tensor: CubeTensor<R>) -> CubeTensor<R>
 where
@@ -110,6 +146,23 @@ where
                     )
                 };
             }
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => {
+                unsafe {
+                    dequantize_per_tensor_affine_int8_kernel::launch_unchecked::<R>(
+                        &client,
+                        cube_count,
+                        cube_dim,
+                        tensor.as_array_arg::<u32>(line_size_in),
+                        output.as_tensor_arg::<F>(line_size_out),
+                        scheme,
+                    )
+                };
+            }
         }
     }
 
@@ -1,7 +1,9 @@
+mod clamp;
 mod dequantize;
 mod qtensor;
 mod quantize;
 
+pub use clamp::*;
 pub use dequantize::*;
 pub use qtensor::*;
 pub use quantize::*;
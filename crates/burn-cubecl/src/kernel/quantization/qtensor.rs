@@ -31,6 +31,17 @@ impl QParams {
                 q_type: QuantInputType::QInt8,
                 ..
             } => (f32::reinterpret(tensor[len - 1][tensor.line_size() - 1]), 0),
+            // Affine quantization contains the offset before the scaling factor, assuming a line
+            // size of 1
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => (
+                f32::reinterpret(tensor[len - 1][0]),
+                i32::reinterpret(tensor[len - 2][0]),
+            ),
         }
     }
 }
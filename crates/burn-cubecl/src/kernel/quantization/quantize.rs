@@ -50,6 +50,39 @@ fn quantize_symmetric_int8_packed(
     pack_i8s_to_u32s(value)
 }
 
+#[cube]
+fn quantize_affine_int8<F: Float>(
+    value: Line<F>,
+    scale: f32,
+    offset: i32,
+    range_min: F,
+    range_max: F,
+) -> Line<u32> {
+    // x_q = clamp(round(x / scale + offset), a, b)
+    // NOTE: we add 256 before casting to unsigned to correctly represent negative values
+    Line::cast_from(
+        Line::clamp(
+            Line::round(value / Line::cast_from(scale)) + Line::cast_from(offset),
+            Line::new(range_min),
+            Line::new(range_max),
+        ) + Line::cast_from(comptime!(256f32)),
+    )
+}
+
+#[cube]
+fn quantize_affine_int8_packed(
+    input: Line<f32>,
+    scale: f32,
+    offset: i32,
+    range_min: f32,
+    range_max: f32,
+) -> u32 {
+    // Assuming a line size of 4 (equal to the number of values packed)
+    let value = quantize_affine_int8::<f32>(input, scale, offset, range_min, range_max);
+    // Shift and combine into u32
+    pack_i8s_to_u32s(value)
+}
+
 #[cube(launch_unchecked)]
 fn quantize_per_tensor_symmetric_int8_kernel(
     input: &Tensor<Line<f32>>,
@@ -85,7 +118,49 @@ fn quantize_per_tensor_symmetric_int8_kernel(
     }
 }
 
-fn create_quantized_output<R: CubeRuntime>(
+#[cube(launch_unchecked)]
+fn quantize_per_tensor_affine_int8_kernel<I: Int>(
+    input: &Tensor<Line<f32>>,
+    scale: &Tensor<f32>,
+    offset: &Tensor<I>,
+    range_min: f32,
+    range_max: f32,
+    output: &mut Array<u32>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        terminate!();
+    }
+
+    let scale = scale[0];
+    let offset = i32::cast_from(offset[0]);
+
+    // Cast the offset and scale to u32 and write the values in the output
+    if ABSOLUTE_POS == output.len() - 1 {
+        output[ABSOLUTE_POS] = u32::reinterpret(scale);
+        terminate!();
+    }
+    if ABSOLUTE_POS == output.len() - 2 {
+        output[ABSOLUTE_POS] = u32::reinterpret(offset);
+        terminate!();
+    }
+
+    if comptime!(input.line_size() == 4) {
+        output[ABSOLUTE_POS] =
+            quantize_affine_int8_packed(input[ABSOLUTE_POS], scale, offset, range_min, range_max);
+    } else {
+        // line size 1
+        let num_packed = comptime!(4);
+        let mut values = Line::<f32>::empty(num_packed);
+        #[unroll]
+        for i in 0..num_packed {
+            values[i] = input[ABSOLUTE_POS * num_packed + i][0];
+        }
+        output[ABSOLUTE_POS] =
+            quantize_affine_int8_packed(values, scale, offset, range_min, range_max);
+    }
+}
+
+pub(crate) fn create_quantized_output<R: CubeRuntime>(
     client: ComputeClient<R::Server, R::Channel>,
     num_input_elems: usize,
     device: R::Device,
@@ -103,6 +178,12 @@ fn create_quantized_output<R: CubeRuntime>(
             q_type: QuantInputType::QInt8,
             ..
         } => core::mem::size_of::<f32>(),
+        QuantScheme {
+            level: QuantLevel::Tensor,
+            mode: QuantMode::Affine,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => core::mem::size_of::<f32>() + core::mem::size_of::<i32>(),
     };
 
     let handle = client.empty(output_elems_size + qparams_size);
@@ -120,6 +201,7 @@ pub fn quantize<R, F, I>(
     tensor: CubeTensor<R>,
     scheme: &QuantScheme,
     scale: CubeTensor<R>,
+    offset: Option<CubeTensor<R>>,
 ) -> CubeTensor<R>
 where
     R: CubeRuntime,
@@ -168,6 +250,31 @@ where
                 )
             };
         }
+        QuantScheme {
+            level: QuantLevel::Tensor,
+            mode: QuantMode::Affine,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => {
+            let ndims = tensor.shape.num_dims();
+            let dummy_array = vec![1; ndims];
+            let offset = offset.expect("Affine quantization requires an offset");
+
+            unsafe {
+                quantize_per_tensor_affine_int8_kernel::launch_unchecked::<I, R>(
+                    &client,
+                    cube_count,
+                    cube_dim,
+                    tensor.as_tensor_arg::<F>(line_size),
+                    // Ignore shape and stride
+                    TensorArg::from_raw_parts::<F>(&scale.handle, &dummy_array, &dummy_array, 1),
+                    TensorArg::from_raw_parts::<I>(&offset.handle, &dummy_array, &dummy_array, 1),
+                    ScalarArg::new(i8::MIN as f32),
+                    ScalarArg::new(i8::MAX as f32),
+                    output.as_array_arg::<u32>(1),
+                )
+            };
+        }
     }
 
     output
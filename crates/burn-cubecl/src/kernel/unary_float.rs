@@ -1,4 +1,5 @@
 use crate::{CubeRuntime, element::CubeElement, ops::numeric::empty_device, tensor::CubeTensor};
+use burn_tensor::cube_math::erf;
 use cubecl::{
     calculate_cube_count_elemwise, linalg::tensor::index_offset_with_layout, prelude::*,
     tensor_line_size_parallel,
@@ -168,7 +169,7 @@ pub(crate) mod unary_basic {
                 BasicFloatUnaryKind::Round => Line::round(input),
                 BasicFloatUnaryKind::Floor => Line::floor(input),
                 BasicFloatUnaryKind::Ceil => Line::ceil(input),
-                BasicFloatUnaryKind::Erf => Line::erf(input),
+                BasicFloatUnaryKind::Erf => erf(input),
                 BasicFloatUnaryKind::Recip => Line::recip(input),
             }
         }
@@ -43,6 +43,18 @@ pub(crate) fn to_device<R: CubeRuntime>(
     tensor.to_client(client, device.clone())
 }
 
+pub(crate) async fn to_device_async<R: CubeRuntime>(
+    tensor: CubeTensor<R>,
+    device: &R::Device,
+) -> CubeTensor<R> {
+    if &tensor.device == device {
+        return tensor;
+    }
+
+    let client = R::client(device);
+    tensor.to_client_async(client, device.clone()).await
+}
+
 pub(crate) fn empty<R: CubeRuntime, E: CubeElement>(
     shape: Shape,
     device: &R::Device,
@@ -149,6 +161,26 @@ pub(crate) fn expand<R: CubeRuntime>(tensor: CubeTensor<R>, target_shape: Shape)
     }
 }
 
+/// Repeat each element along the given dimension, with a single copy into the output.
+pub(crate) fn repeat_interleave<R: CubeRuntime>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+    repeats: usize,
+) -> CubeTensor<R> {
+    let mut shape = tensor.shape.clone();
+    let mut unsqueezed = shape.dims.clone();
+    unsqueezed.insert(dim + 1, 1);
+    let mut expanded = shape.dims.clone();
+    expanded.insert(dim + 1, repeats);
+    shape.dims[dim] *= repeats;
+
+    // The copies are a broadcasted view until the reshape makes the tensor contiguous.
+    let tensor = reshape(tensor, Shape::from(unsqueezed));
+    let tensor = expand(tensor, Shape::from(expanded));
+
+    reshape(tensor, shape)
+}
+
 /// Reshape a jit tensor to a new shape
 pub fn reshape<R: CubeRuntime>(tensor: CubeTensor<R>, shape: Shape) -> CubeTensor<R> {
     // TODO: Not force standard layout all the time (improve performance).
@@ -43,6 +43,13 @@ where
         super::to_device(tensor, device)
     }
 
+    async fn bool_to_device_async(
+        tensor: BoolTensor<Self>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self> {
+        super::to_device_async(tensor, device).await
+    }
+
     fn bool_reshape(tensor: BoolTensor<Self>, shape: Shape) -> BoolTensor<Self> {
         super::reshape(tensor, shape)
     }
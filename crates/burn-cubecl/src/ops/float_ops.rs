@@ -1,4 +1,4 @@
-use super::{expand, numeric, permute};
+use super::{expand, numeric, permute, repeat_interleave};
 use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
 use crate::kernel::unary_basic::BasicFloatUnaryKind;
 use crate::kernel::{
@@ -11,7 +11,7 @@ use crate::{
     kernel::matmul::{MatmulStrategy, matmul},
 };
 use burn_tensor::ops::{BoolTensor, Device, FloatElem, FloatTensor, IntTensor};
-use burn_tensor::{DType, ElementConversion, FloatDType};
+use burn_tensor::{DType, ElementConversion, FloatDType, IndexReduceMode};
 use burn_tensor::{Distribution, Shape, TensorData, ops::FloatTensorOps};
 use cubecl::prelude::*;
 use cubecl::reduce::ReducePrecision;
@@ -68,6 +68,13 @@ where
         super::to_device(tensor, device)
     }
 
+    async fn float_to_device_async(
+        tensor: FloatTensor<Self>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self> {
+        super::to_device_async(tensor, device).await
+    }
+
     fn float_empty(shape: Shape, device: &Device<Self>) -> FloatTensor<Self> {
         super::empty::<R, F>(shape, device)
     }
@@ -209,6 +216,20 @@ where
         )
     }
 
+    fn float_index_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+        mode: IndexReduceMode,
+    ) -> FloatTensor<Self> {
+        execute_with_dtype!(
+            float(tensor.dtype, value.dtype),
+            E,
+            kernel::index_reduce::<R, E, I>(dim, tensor, indices, value, mode)
+        )
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -618,6 +639,14 @@ where
         expand(tensor, shape)
     }
 
+    fn float_repeat_interleave(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        repeats: usize,
+    ) -> FloatTensor<Self> {
+        repeat_interleave(tensor, dim, repeats)
+    }
+
     fn float_flip(tensor: FloatTensor<Self>, axes: &[usize]) -> FloatTensor<Self> {
         execute_with_dtype!(
             float(tensor.dtype),
@@ -1,6 +1,6 @@
 use self::unary_basic_int::BasicIntUnaryKind;
 
-use super::{expand, numeric, permute};
+use super::{expand, numeric, permute, repeat_interleave};
 use crate::kernel::{
     BitwiseShlOp, BitwiseShrOp, NumericUnaryOp, NumericUnaryOpFamily, launch_binop_int,
     launch_scalar_binop_int, launch_unary_numeric, reduce, unary_basic_int,
@@ -10,8 +10,8 @@ use crate::{
     element::BoolElement,
     kernel::prng::{random_bernoulli, random_normal, random_uniform},
 };
-use burn_tensor::DType;
 use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
+use burn_tensor::{DType, IndexReduceMode};
 use burn_tensor::{Distribution, ElementConversion, Shape, TensorData, ops::IntTensorOps};
 use cubecl::frontend::Numeric;
 use cubecl::prelude::*;
@@ -51,6 +51,13 @@ where
         super::to_device(tensor, device)
     }
 
+    async fn int_to_device_async(
+        tensor: IntTensor<Self>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self> {
+        super::to_device_async(tensor, device).await
+    }
+
     fn int_reshape(tensor: IntTensor<Self>, shape: Shape) -> IntTensor<Self> {
         super::reshape(tensor, shape)
     }
@@ -100,6 +107,16 @@ where
         kernel::scatter::<R, I, I>(dim, tensor, indices, value)
     }
 
+    fn int_index_reduce(
+        dim: usize,
+        tensor: IntTensor<Self>,
+        indices: IntTensor<Self>,
+        value: IntTensor<Self>,
+        mode: IndexReduceMode,
+    ) -> IntTensor<Self> {
+        kernel::index_reduce::<R, I, I>(dim, tensor, indices, value, mode)
+    }
+
     fn int_select(
         tensor: IntTensor<Self>,
         dim: usize,
@@ -355,6 +372,14 @@ where
         expand(tensor, shape)
     }
 
+    fn int_repeat_interleave(
+        tensor: IntTensor<Self>,
+        dim: usize,
+        repeats: usize,
+    ) -> IntTensor<Self> {
+        repeat_interleave(tensor, dim, repeats)
+    }
+
     fn int_flip(tensor: IntTensor<Self>, axes: &[usize]) -> IntTensor<Self> {
         kernel::flip::<R, I, BT>(tensor, axes)
     }
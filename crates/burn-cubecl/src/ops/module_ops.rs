@@ -7,8 +7,9 @@ use crate::{
     },
 };
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions, InterpolateOptions,
-    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    ConvOptions, ConvTransposeOptions, DeformConv2dBackward, DeformConvOptions,
+    FlashAttnVarlenOptions, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    attention,
 };
 use burn_tensor::ops::{FloatTensor, IntTensor};
 
@@ -206,4 +207,34 @@ where
     ) -> FloatTensor<Self> {
         kernel::interpolate::interpolate_backward::<R, F>(x, grad, output_size, options)
     }
+
+    fn flash_attn_varlen(
+        q: FloatTensor<Self>,
+        k: FloatTensor<Self>,
+        v: FloatTensor<Self>,
+        cu_seqlens_q: IntTensor<Self>,
+        cu_seqlens_k: IntTensor<Self>,
+        options: FlashAttnVarlenOptions,
+    ) -> FloatTensor<Self> {
+        // The reference kernel doesn't apply dropout, so it falls back to the padded implementation
+        if options.dropout > 0.0 {
+            return attention::flash_attn_varlen::<Self>(
+                q,
+                k,
+                v,
+                cu_seqlens_q,
+                cu_seqlens_k,
+                options,
+            );
+        }
+
+        kernel::attention::flash_attn_varlen_reference::<R, F, I>(
+            q,
+            k,
+            v,
+            cu_seqlens_q,
+            cu_seqlens_k,
+            options,
+        )
+    }
 }
@@ -1,8 +1,8 @@
 use std::ops::Range;
 
 use burn_tensor::{
-    DType, Device, Shape, TensorData, TensorPrimitive,
-    ops::{FloatTensor, FloatTensorOps, IntTensor, QTensorOps, QuantizedTensor},
+    DType, Device, ElementConversion, Shape, TensorData, TensorPrimitive,
+    ops::{FloatElem, FloatTensor, FloatTensorOps, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{
         QTensorPrimitive, QuantInputType, QuantLevel, QuantMode, QuantPropagation, QuantScheme,
         QuantizationParametersPrimitive,
@@ -54,7 +54,7 @@ where
             DType::QFloat(scheme) => match scheme {
                 QuantScheme {
                     level: QuantLevel::Tensor,
-                    mode: QuantMode::Symmetric,
+                    mode: QuantMode::Symmetric | QuantMode::Affine,
                     q_type: QuantInputType::QInt8,
                     ..
                 } => {
@@ -77,7 +77,7 @@ where
         scheme: &QuantScheme,
         qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self> {
-        kernel::quantization::quantize::<R, F, I>(tensor, scheme, qparams.scale)
+        kernel::quantization::quantize::<R, F, I>(tensor, scheme, qparams.scale, qparams.offset)
     }
 
     fn dequantize(tensor: QuantizedTensor<Self>) -> FloatTensor<Self> {
@@ -177,6 +177,50 @@ where
             QuantPropagation::Inhibit => TensorPrimitive::Float(out),
         }
     }
+
+    fn q_clamp_min(tensor: QuantizedTensor<Self>, min: FloatElem<Self>) -> TensorPrimitive<Self> {
+        let out = kernel::quantization::clamp_quantized(tensor, Some(min.elem()), None);
+        q_propagate::<R, F, I, BT>(out)
+    }
+
+    fn q_clamp_max(tensor: QuantizedTensor<Self>, max: FloatElem<Self>) -> TensorPrimitive<Self> {
+        let out = kernel::quantization::clamp_quantized(tensor, None, Some(max.elem()));
+        q_propagate::<R, F, I, BT>(out)
+    }
+
+    fn q_clamp(
+        tensor: QuantizedTensor<Self>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> TensorPrimitive<Self> {
+        let out = kernel::quantization::clamp_quantized(tensor, Some(min.elem()), Some(max.elem()));
+        q_propagate::<R, F, I, BT>(out)
+    }
+
+    fn q_relu(tensor: QuantizedTensor<Self>) -> TensorPrimitive<Self> {
+        // Zero is quantized exactly to the zero-point, so the negative values are clamped to it
+        let out = kernel::quantization::clamp_quantized(tensor, Some(0.0), None);
+        q_propagate::<R, F, I, BT>(out)
+    }
+}
+
+/// Returns the output of an operation on the quantized values, dequantized unless the scheme
+/// propagates quantization.
+fn q_propagate<R, F, I, BT>(
+    tensor: QuantizedTensor<CubeBackend<R, F, I, BT>>,
+) -> TensorPrimitive<CubeBackend<R, F, I, BT>>
+where
+    R: CubeRuntime,
+    F: FloatElement,
+    I: IntElement,
+    BT: BoolElement,
+{
+    match tensor.scheme().propagation {
+        QuantPropagation::Propagate => TensorPrimitive::QFloat(tensor),
+        QuantPropagation::Inhibit => {
+            TensorPrimitive::Float(CubeBackend::<R, F, I, BT>::dequantize(tensor))
+        }
+    }
 }
 
 fn both_matches_symmetric_qint8(lhs: &QuantScheme, rhs: &QuantScheme) -> bool {
@@ -330,6 +330,29 @@ where
         }
     }
 
+    /// Change the context of the current tensor without blocking on the read of its data, and
+    /// return the newly transferred tensor.
+    pub async fn to_client_async(
+        &self,
+        client: ComputeClient<R::Server, R::Channel>,
+        device: R::Device,
+    ) -> Self {
+        let bytes = self
+            .client
+            .read_async(vec![self.handle.clone().binding()])
+            .await;
+        let handle = client.create(&bytes[0]);
+
+        Self {
+            client,
+            handle,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            device,
+            dtype: self.dtype,
+        }
+    }
+
     /// Return the reference to a tensor handle.
     pub fn as_handle_ref(&self) -> TensorHandleRef<'_, R> {
         TensorHandleRef {
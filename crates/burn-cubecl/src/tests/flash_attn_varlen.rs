@@ -0,0 +1,122 @@
+#[burn_tensor_testgen::testgen(flash_attn_varlen)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::flash_attn_varlen;
+    use burn_tensor::{Distribution, ElementConversion, Int, Tensor, backend::Backend};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn flash_attn_varlen_reference_kernel_should_match_reference_backend() {
+        same_as_reference(&[5, 1, 37, 12], &[9, 4, 37, 2], false);
+    }
+
+    #[test]
+    fn flash_attn_varlen_reference_kernel_should_match_reference_backend_causal() {
+        same_as_reference(&[5, 1, 37, 12], &[9, 4, 37, 2], true);
+    }
+
+    #[test]
+    fn flash_attn_varlen_with_dropout_should_rescale_the_kept_weights() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let [seqlen, head_dim, value_dim] = [64, 8, 4];
+        let dropout = 0.5;
+        let q =
+            Tensor::<TestBackend, 2>::random([seqlen, head_dim], Distribution::Default, &device);
+        let k =
+            Tensor::<TestBackend, 2>::random([seqlen, head_dim], Distribution::Default, &device);
+        // With constant values, each output element is the sum of the kept attention weights
+        let v = Tensor::<TestBackend, 2>::ones([seqlen, value_dim], &device);
+        let cu_seqlens = Tensor::<TestBackend, 1, Int>::from_ints([0, seqlen as i32], &device);
+
+        let output = flash_attn_varlen(
+            q,
+            k,
+            v,
+            cu_seqlens.clone(),
+            cu_seqlens,
+            seqlen,
+            seqlen,
+            dropout,
+            None,
+            false,
+        );
+
+        // The same weights are dropped for every value column
+        let first_column = output.clone().narrow(1, 0, 1);
+        output.clone().into_data().assert_approx_eq::<FT>(
+            &first_column.clone().repeat_dim(1, value_dim).into_data(),
+            Tolerance::default(),
+        );
+        // Some weights are dropped, but the kept ones are rescaled by `1 / (1 - dropout)`
+        let deviation = first_column
+            .clone()
+            .sub_scalar(1.0)
+            .abs()
+            .max()
+            .into_scalar()
+            .elem::<f32>();
+        assert!(
+            deviation > 1e-3,
+            "Dropout should change the attention output"
+        );
+        let mean = first_column.mean().into_scalar().elem::<f32>();
+        assert!(
+            (mean - 1.0).abs() < 0.1,
+            "The kept weights should be rescaled, got a mean of {mean}"
+        );
+    }
+
+    fn same_as_reference(seqlens_q: &[usize], seqlens_k: &[usize], causal: bool) {
+        TestBackend::seed(0);
+        let [head_dim, value_dim] = [16, 8];
+        let cu_seqlens = |seqlens: &[usize]| {
+            let mut offsets = vec![0i32];
+            for len in seqlens {
+                offsets.push(offsets[offsets.len() - 1] + *len as i32);
+            }
+            offsets
+        };
+        let (cu_seqlens_q, cu_seqlens_k) = (cu_seqlens(seqlens_q), cu_seqlens(seqlens_k));
+        let total_q = *cu_seqlens_q.last().unwrap() as usize;
+        let total_k = *cu_seqlens_k.last().unwrap() as usize;
+        let max_q = *seqlens_q.iter().max().unwrap();
+        let max_k = *seqlens_k.iter().max().unwrap();
+
+        let test_device = Default::default();
+        let q = Tensor::<TestBackend, 2>::random(
+            [total_q, head_dim],
+            Distribution::Default,
+            &test_device,
+        );
+        let k = Tensor::<TestBackend, 2>::random(
+            [total_k, head_dim],
+            Distribution::Default,
+            &test_device,
+        );
+        let v = Tensor::<TestBackend, 2>::random(
+            [total_k, value_dim],
+            Distribution::Default,
+            &test_device,
+        );
+        let cu_q = Tensor::<TestBackend, 1, Int>::from_ints(cu_seqlens_q.as_slice(), &test_device);
+        let cu_k = Tensor::<TestBackend, 1, Int>::from_ints(cu_seqlens_k.as_slice(), &test_device);
+
+        let ref_device = Default::default();
+        let q_ref = Tensor::<ReferenceBackend, 2>::from_data(q.to_data(), &ref_device);
+        let k_ref = Tensor::<ReferenceBackend, 2>::from_data(k.to_data(), &ref_device);
+        let v_ref = Tensor::<ReferenceBackend, 2>::from_data(v.to_data(), &ref_device);
+        let cu_q_ref = Tensor::<ReferenceBackend, 1, Int>::from_data(cu_q.to_data(), &ref_device);
+        let cu_k_ref = Tensor::<ReferenceBackend, 1, Int>::from_data(cu_k.to_data(), &ref_device);
+
+        let actual = flash_attn_varlen(q, k, v, cu_q, cu_k, max_q, max_k, 0.0, None, causal);
+        let expected = flash_attn_varlen(
+            q_ref, k_ref, v_ref, cu_q_ref, cu_k_ref, max_q, max_k, 0.0, None, causal,
+        );
+
+        expected
+            .into_data()
+            .assert_approx_eq::<FT>(&actual.into_data(), Tolerance::default());
+    }
+}
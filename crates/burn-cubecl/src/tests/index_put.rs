@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(index_put)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Int, Tensor, backend::Backend};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn index_put_should_write_the_last_value_of_repeated_coordinates() {
+        same_as_reference([8, 16], 4096, false);
+    }
+
+    #[test]
+    fn index_put_should_accumulate_repeated_coordinates() {
+        same_as_reference([8, 16], 4096, true);
+    }
+
+    fn same_as_reference(shape: [usize; 2], num_indices: usize, accumulate: bool) {
+        TestBackend::seed(0);
+        let test_device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::random(shape, Distribution::Default, &test_device);
+        let values =
+            Tensor::<TestBackend, 1>::random([num_indices], Distribution::Default, &test_device);
+        // Many more coordinates than elements, so that each element is written multiple times
+        let coordinates = shape.map(|size| {
+            Tensor::<TestBackend, 1, Int>::from_data(
+                Tensor::<TestBackend, 1>::random(
+                    [num_indices],
+                    Distribution::Uniform(0., size as f64),
+                    &test_device,
+                )
+                .into_data(),
+                &test_device,
+            )
+            .unsqueeze_dim::<2>(1)
+        });
+        let indices = Tensor::cat(coordinates.to_vec(), 1);
+        let ref_device = Default::default();
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &ref_device);
+        let values_ref = Tensor::<ReferenceBackend, 1>::from_data(values.to_data(), &ref_device);
+        let indices_ref =
+            Tensor::<ReferenceBackend, 2, Int>::from_data(indices.to_data(), &ref_device);
+
+        let actual = tensor.index_put(indices, values, accumulate);
+        let expected = tensor_ref.index_put(indices_ref, values_ref, accumulate);
+
+        expected
+            .into_data()
+            .assert_approx_eq::<FT>(&actual.into_data(), Tolerance::default());
+    }
+}
@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(index_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, IndexReduceMode, Int, Tensor, backend::Backend};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn index_reduce_prod_should_work_with_multiple_workgroups_2d_dim0() {
+        same_as_reference(0, [256, 32], [64, 32], IndexReduceMode::Prod);
+    }
+
+    #[test]
+    fn index_reduce_amax_should_work_with_multiple_workgroups_2d_dim1() {
+        same_as_reference(1, [32, 256], [32, 512], IndexReduceMode::AMax);
+    }
+
+    #[test]
+    fn index_reduce_amin_should_work_with_multiple_workgroups_3d_dim1() {
+        same_as_reference(1, [6, 256, 6], [6, 512, 6], IndexReduceMode::AMin);
+    }
+
+    #[test]
+    fn index_reduce_amax_should_work_with_multiple_workgroups_3d_dim2() {
+        same_as_reference(2, [6, 6, 256], [6, 6, 32], IndexReduceMode::AMax);
+    }
+
+    fn same_as_reference<const D: usize>(
+        dim: usize,
+        shape: [usize; D],
+        shape_value: [usize; D],
+        mode: IndexReduceMode,
+    ) {
+        TestBackend::seed(0);
+        let test_device = Default::default();
+        let tensor = Tensor::<TestBackend, D>::random(shape, Distribution::Default, &test_device);
+        let value =
+            Tensor::<TestBackend, D>::random(shape_value, Distribution::Default, &test_device);
+        // Fewer or more values than positions along `dim`, so that positions are repeated
+        let indices = Tensor::<TestBackend, 1, Int>::from_data(
+            Tensor::<TestBackend, 1>::random(
+                [shape_value.iter().product()],
+                Distribution::Uniform(0., shape[dim] as f64),
+                &test_device,
+            )
+            .into_data(),
+            &test_device,
+        )
+        .reshape(shape_value);
+        let ref_device = Default::default();
+        let tensor_ref = Tensor::<ReferenceBackend, D>::from_data(tensor.to_data(), &ref_device);
+        let value_ref = Tensor::<ReferenceBackend, D>::from_data(value.to_data(), &ref_device);
+        let indices_ref =
+            Tensor::<ReferenceBackend, D, Int>::from_data(indices.to_data(), &ref_device);
+
+        let actual = tensor.index_reduce(dim, indices, value, mode);
+        let expected = tensor_ref.index_reduce(dim, indices_ref, value_ref, mode);
+
+        expected
+            .into_data()
+            .assert_approx_eq::<FT>(&actual.into_data(), Tolerance::default());
+    }
+}
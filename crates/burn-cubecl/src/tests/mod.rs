@@ -9,7 +9,10 @@ mod conv2d;
 mod conv3d;
 mod conv_transpose2d;
 mod conv_transpose3d;
+mod flash_attn_varlen;
 mod gather;
+mod index_put;
+mod index_reduce;
 mod mask_fill;
 mod mask_where;
 mod matmul;
@@ -56,6 +59,8 @@ macro_rules! testgen_all {
                 burn_cubecl::testgen_repeat_dim!();
                 burn_cubecl::testgen_gather!();
                 burn_cubecl::testgen_scatter!();
+                burn_cubecl::testgen_index_reduce!();
+                burn_cubecl::testgen_index_put!();
 
                 burn_cubecl::testgen_select!();
                 burn_cubecl::testgen_select_assign!();
@@ -81,6 +86,8 @@ macro_rules! testgen_all {
 
                 burn_cubecl::testgen_reduce!();
 
+                burn_cubecl::testgen_flash_attn_varlen!();
+
                 burn_cubecl::testgen_quantization!();
             }
         }
@@ -127,6 +134,8 @@ macro_rules! testgen_jit {
         burn_tensor::testgen_scheme!();
         burn_tensor::testgen_quantize!();
         burn_tensor::testgen_q_data!();
+        burn_tensor::testgen_q_clamp!();
+        burn_tensor::testgen_q_relu!();
     }
 }
 
@@ -9,7 +9,8 @@ use crate::{
 };
 use burn_ir::*;
 use burn_tensor::{
-    Device, Distribution, Element, ElementConversion, Shape, TensorData, TensorMetadata,
+    Device, Distribution, Element, ElementConversion, IndexReduceMode, Shape, TensorData,
+    TensorMetadata,
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor, binary_ops_shape},
 };
 use std::{marker::PhantomData, ops::Range};
@@ -725,6 +726,63 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_index_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: IntTensor<Self>,
+        value: FloatTensor<Self>,
+        mode: IndexReduceMode,
+    ) -> FloatTensor<Self> {
+        #[derive(new)]
+        struct IndexReduceOps<B: FusionBackend> {
+            desc: IndexReduceOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for IndexReduceOps<B> {
+            fn execute(&self, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B>(&self.desc.tensor);
+                let indices = handles.get_int_tensor::<B>(&self.desc.indices);
+                let value = handles.get_float_tensor::<B>(&self.desc.value);
+
+                let output = B::float_index_reduce(
+                    self.desc.dim,
+                    tensor,
+                    indices,
+                    value,
+                    self.desc.mode.clone().into(),
+                );
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = tensor.stream;
+        let stream_2 = indices.stream;
+        let stream_3 = value.stream;
+        let dtype = tensor.dtype;
+        let shape: Vec<usize> = tensor.shape.clone();
+        let out = tensor.client.tensor_uninitialized(shape, dtype);
+
+        let desc = IndexReduceOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            indices: indices.into_ir(),
+            value: value.into_ir(),
+            mode: mode.into(),
+            out: out.to_ir_out(),
+        };
+        // Check that both float tensors have the same type
+        check_binary_op_types(&desc.tensor, &desc.value).unwrap();
+        out.client.register(
+            vec![stream_1, stream_2, stream_3],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::IndexReduce(desc.clone())),
+            IndexReduceOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -823,6 +823,16 @@ impl<E: Element> RelativeOpsScalar<E> for NumericOperationIr<E> {
                 value: desc.value.to_relative(converter),
                 out: desc.out.to_relative(converter),
             }),
+            NumericOperationIr::IndexReduce(desc) => {
+                NumericOperationIr::IndexReduce(IndexReduceOpIr {
+                    tensor: desc.tensor.to_relative(converter),
+                    dim: desc.dim,
+                    indices: desc.indices.to_relative(converter),
+                    value: desc.value.to_relative(converter),
+                    mode: desc.mode.clone(),
+                    out: desc.out.to_relative(converter),
+                })
+            }
             NumericOperationIr::Select(desc) => NumericOperationIr::Select(SelectOpIr {
                 tensor: desc.tensor.to_relative(converter),
                 dim: desc.dim,
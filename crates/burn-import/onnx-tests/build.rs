@@ -8,13 +8,20 @@ fn main() {
     ModelGen::new()
         .input("tests/add/add.onnx")
         .input("tests/add/add_int.onnx")
+        .input("tests/and/and.onnx")
         .input("tests/argmax/argmax.onnx")
+        .input("tests/attention/attention.onnx")
+        .input("tests/attention/attention_present.onnx")
         .input("tests/avg_pool1d/avg_pool1d.onnx")
         .input("tests/avg_pool2d/avg_pool2d.onnx")
         .input("tests/batch_norm/batch_norm.onnx")
         .input("tests/cast/cast.onnx")
+        .input("tests/cast/cast_saturate.onnx")
+        .input("tests/cast/cast_shape_to_tensor.onnx")
         .input("tests/clip/clip.onnx")
         .input("tests/concat/concat.onnx")
+        .input("tests/concat_broadcast/concat_broadcast.onnx")
+        .input("tests/concat_shapes/concat_shapes.onnx")
         .input("tests/constant/constant_f32.onnx")
         .input("tests/constant/constant_f64.onnx")
         .input("tests/constant/constant_i32.onnx")
@@ -24,11 +31,15 @@ fn main() {
         .input("tests/conv1d/conv1d.onnx")
         .input("tests/conv2d/conv2d.onnx")
         .input("tests/conv3d/conv3d.onnx")
+        .input("tests/conv_integer/conv_integer.onnx")
+        .input("tests/conv_batch_norm/conv_batch_norm.onnx")
         .input("tests/conv_transpose1d/conv_transpose1d.onnx")
         .input("tests/conv_transpose2d/conv_transpose2d.onnx")
         .input("tests/conv_transpose3d/conv_transpose3d.onnx")
+        .input("tests/convnext_block/convnext_block.onnx")
         .input("tests/cos/cos.onnx")
         .input("tests/cosh/cosh.onnx")
+        .input("tests/depthwise_separable_conv2d/depthwise_separable_conv2d.onnx")
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout.onnx")
         .input("tests/equal/equal.onnx")
@@ -44,6 +55,7 @@ fn main() {
         .input("tests/gather/gather_2d_idx.onnx")
         .input("tests/gather/gather_scalar.onnx")
         .input("tests/gather/gather_shape.onnx")
+        .input("tests/gather/gather_shape_scalar.onnx")
         .input("tests/gather/gather_scalar_out.onnx")
         .input("tests/gather_elements/gather_elements.onnx")
         .input("tests/gelu/gelu.onnx")
@@ -57,6 +69,10 @@ fn main() {
         .input("tests/greater_or_equal/greater_or_equal.onnx")
         .input("tests/greater_or_equal/greater_or_equal_scalar.onnx")
         .input("tests/hard_sigmoid/hard_sigmoid.onnx")
+        .input("tests/hardmax/hardmax.onnx")
+        .input("tests/hinge_embedding_loss/hinge_embedding_loss.onnx")
+        .input("tests/identity/identity.onnx")
+        .input("tests/if/if_else.onnx")
         .input("tests/layer_norm/layer_norm.onnx")
         .input("tests/leaky_relu/leaky_relu.onnx")
         .input("tests/less/less.onnx")
@@ -66,21 +82,27 @@ fn main() {
         .input("tests/linear/linear.onnx")
         .input("tests/log/log.onnx")
         .input("tests/log_softmax/log_softmax.onnx")
+        .input("tests/log_softmax/log_softmax_axis_neg1.onnx")
         .input("tests/mask_where/mask_where.onnx")
         .input("tests/mask_where/mask_where_broadcast.onnx")
         .input("tests/mask_where/mask_where_scalar_x.onnx")
         .input("tests/mask_where/mask_where_scalar_y.onnx")
         .input("tests/mask_where/mask_where_all_scalar.onnx")
         .input("tests/matmul/matmul.onnx")
+        .input("tests/matmul_add/matmul_add.onnx")
+        .input("tests/matmul_vector/matmul_vector.onnx")
         .input("tests/max/max.onnx")
         .input("tests/maxpool1d/maxpool1d.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
+        .input("tests/maxpool2d_adaptive/maxpool2d_adaptive.onnx")
         .input("tests/min/min.onnx")
         .input("tests/mean/mean.onnx")
         .input("tests/mul/mul.onnx")
         .input("tests/neg/neg.onnx")
         .input("tests/not/not.onnx")
         .input("tests/one_hot/one_hot.onnx")
+        .input("tests/optional/optional.onnx")
+        .input("tests/or/or.onnx")
         .input("tests/pad/pad.onnx")
         .input("tests/pow/pow.onnx")
         .input("tests/pow/pow_int.onnx")
@@ -92,29 +114,45 @@ fn main() {
         .input("tests/range/range.onnx")
         .input("tests/recip/recip.onnx")
         .input("tests/reduce_max/reduce_max.onnx")
+        .input("tests/reduce_max/reduce_max_multi_axis.onnx")
         .input("tests/reduce_mean/reduce_mean.onnx")
         .input("tests/reduce_min/reduce_min.onnx")
         .input("tests/reduce_prod/reduce_prod.onnx")
         .input("tests/reduce_sum/reduce_sum.onnx")
         .input("tests/relu/relu.onnx")
+        .input("tests/repeat_interleave/repeat_interleave.onnx")
         .input("tests/reshape/reshape.onnx")
+        .input("tests/reshape_allowzero/reshape_allowzero.onnx")
+        .input("tests/reshape_infer_neg1/reshape_infer_neg1.onnx")
+        .input("tests/reshape_runtime/reshape_runtime.onnx")
         .input("tests/resize/resize_with_sizes.onnx")
         .input("tests/resize/resize_1d_linear_scale.onnx")
         .input("tests/resize/resize_1d_nearest_scale.onnx")
         .input("tests/resize/resize_2d_bicubic_scale.onnx")
         .input("tests/resize/resize_2d_bilinear_scale.onnx")
         .input("tests/resize/resize_2d_nearest_scale.onnx")
+        .input("tests/resize/resize_runtime_scales.onnx")
+        .input("tests/resize/resize_runtime_sizes.onnx")
+        .input("tests/scatter_elements/scatter_elements.onnx")
         .input("tests/shape/shape.onnx")
+        .input("tests/shape/shape_sliced.onnx")
+        .input("tests/shape_arithmetic/shape_arithmetic.onnx")
         .input("tests/sigmoid/sigmoid.onnx")
         .input("tests/sign/sign.onnx")
         .input("tests/sin/sin.onnx")
+        .input("tests/relative_position_bias/relative_position_bias.onnx")
+        .input("tests/sinusoidal_pos_encoding/sinusoidal_pos_encoding.onnx")
         .input("tests/sinh/sinh.onnx")
         .input("tests/slice/slice.onnx")
         .input("tests/slice/slice_shape.onnx")
         .input("tests/softmax/softmax.onnx")
+        .input("tests/softmax/softmax_axis2_3d.onnx")
+        .input("tests/softmax_cross_entropy_loss/softmax_cross_entropy_loss.onnx")
         .input("tests/sqrt/sqrt.onnx")
         .input("tests/squeeze/squeeze_multiple.onnx")
         .input("tests/squeeze/squeeze.onnx")
+        .input("tests/squeeze_excitation/squeeze_excitation.onnx")
+        .input("tests/stack/stack.onnx")
         .input("tests/sub/sub.onnx")
         .input("tests/sub/sub_int.onnx")
         .input("tests/sum/sum.onnx")
@@ -126,12 +164,24 @@ fn main() {
         .input("tests/trilu/trilu_upper.onnx")
         .input("tests/trilu/trilu_lower.onnx")
         .input("tests/transpose/transpose.onnx")
+        .input("tests/transpose/transpose_perm.onnx")
+        .input("tests/trt_plugin/trt_plugin_layer_norm.onnx")
         .input("tests/unsqueeze/unsqueeze_runtime_axes.onnx")
         .input("tests/unsqueeze/unsqueeze_like.onnx")
+        .input("tests/unsqueeze/unsqueeze_scalar_to_shape.onnx")
+        .input("tests/unsqueeze/unsqueeze_scalar_gather.onnx")
+        .input("tests/xor/xor.onnx")
         .input("tests/split/split.onnx")
         .out_dir("model/")
         .run_from_script();
 
+    // Unknown TensorRT plugins are only accepted when explicitly allowed.
+    ModelGen::new()
+        .input("tests/trt_plugin/trt_plugin_unknown.onnx")
+        .out_dir("model/")
+        .allow_unknown_plugins(true)
+        .run_from_script();
+
     // The following tests are used to generate the model with different record types.
     // (e.g. bincode, pretty_json, etc.) Do not need to add new tests here, just use the default
     // record type to the ModelGen::new() call above.
@@ -203,5 +253,17 @@ fn main() {
         .record_type(RecordType::Bincode)
         .run_from_script();
 
+    ModelGen::new()
+        .input("tests/linear/linear.onnx")
+        .out_dir("model/half_precision_params/")
+        .half_precision_params(true)
+        .run_from_script();
+
+    ModelGen::new()
+        .input("tests/reshape/reshape.onnx")
+        .out_dir("model/intermediates/")
+        .intermediate("reshape1_out1")
+        .run_from_script();
+
     // panic!("Purposefully failing build to output logs.");
 }
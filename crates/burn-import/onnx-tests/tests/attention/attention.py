@@ -0,0 +1,143 @@
+#!/usr/bin/env python3
+
+# used to generate model: attention.onnx
+
+# `com.microsoft.Attention` contrib operator, as found in transformer models exported with
+# Optimum for ONNX Runtime. The model is exported using onnx directly, and the expected output
+# is computed with a reference implementation of the operator.
+
+import math
+
+import onnx
+import onnx.helper
+
+BATCH_SIZE = 2
+SEQ_LENGTH = 3
+HIDDEN_SIZE = 8
+NUM_HEADS = 2
+
+WEIGHT = [
+    [((i * 3 * HIDDEN_SIZE + j) % 7 - 3) * 0.1 for j in range(3 * HIDDEN_SIZE)]
+    for i in range(HIDDEN_SIZE)
+]
+BIAS = [(j % 5 - 2) * 0.05 for j in range(3 * HIDDEN_SIZE)]
+MASK_INDEX = [[1, 1, 1], [1, 1, 0]]
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[
+            onnx.helper.make_operatorsetid("", 16),
+            onnx.helper.make_operatorsetid("com.microsoft", 1),
+        ],
+        graph=onnx.helper.make_graph(name="main_graph", nodes=[
+            onnx.helper.make_node(
+                "Attention",
+                inputs=["input1", "weight", "bias", "mask_index"],
+                outputs=["output1"],
+                name="/Attention",
+                domain="com.microsoft",
+                num_heads=NUM_HEADS,
+            ),
+        ],
+        inputs=[
+            onnx.helper.make_value_info(
+                name="input1",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.FLOAT,
+                    shape=[BATCH_SIZE, SEQ_LENGTH, HIDDEN_SIZE],
+                ),
+            ),
+            onnx.helper.make_value_info(
+                name="mask_index",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.INT32, shape=[BATCH_SIZE, SEQ_LENGTH]
+                ),
+            ),
+        ],
+        outputs=[
+            onnx.helper.make_value_info(
+                name="output1",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.FLOAT,
+                    shape=[BATCH_SIZE, SEQ_LENGTH, HIDDEN_SIZE],
+                ),
+            )
+        ],
+        initializer=[
+            onnx.helper.make_tensor(
+                "weight", onnx.TensorProto.FLOAT, [HIDDEN_SIZE, 3 * HIDDEN_SIZE], WEIGHT
+            ),
+            onnx.helper.make_tensor("bias", onnx.TensorProto.FLOAT, [3 * HIDDEN_SIZE], BIAS),
+        ]),
+    )
+
+
+def test_input():
+    return [
+        [
+            [((b * 24 + s * 8 + h) % 11 - 5) * 0.1 for h in range(HIDDEN_SIZE)]
+            for s in range(SEQ_LENGTH)
+        ]
+        for b in range(BATCH_SIZE)
+    ]
+
+
+def reference(x):
+    head_size = HIDDEN_SIZE // NUM_HEADS
+    output = []
+    for b in range(BATCH_SIZE):
+        qkv = [
+            [
+                sum(x[b][s][i] * WEIGHT[i][j] for i in range(HIDDEN_SIZE)) + BIAS[j]
+                for j in range(3 * HIDDEN_SIZE)
+            ]
+            for s in range(SEQ_LENGTH)
+        ]
+        out = [[0.0] * HIDDEN_SIZE for _ in range(SEQ_LENGTH)]
+        for h in range(NUM_HEADS):
+            q = [row[h * head_size:(h + 1) * head_size] for row in qkv]
+            k = [row[HIDDEN_SIZE + h * head_size:HIDDEN_SIZE + (h + 1) * head_size] for row in qkv]
+            v = [
+                row[2 * HIDDEN_SIZE + h * head_size:2 * HIDDEN_SIZE + (h + 1) * head_size]
+                for row in qkv
+            ]
+            for i in range(SEQ_LENGTH):
+                scores = [
+                    sum(a * c for a, c in zip(q[i], k[j])) / math.sqrt(head_size)
+                    if MASK_INDEX[b][j] else -10000.0
+                    for j in range(SEQ_LENGTH)
+                ]
+                top = max(scores)
+                weights = [math.exp(score - top) for score in scores]
+                total = sum(weights)
+                for d in range(head_size):
+                    out[i][h * head_size + d] = sum(
+                        weights[j] / total * v[j][d] for j in range(SEQ_LENGTH)
+                    )
+        output.append(out)
+    return output
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "attention.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+    print(f"Finished exporting model to {file_name}")
+
+    # Output some test data for use in the test
+    x = test_input()
+    output = [[[round(v, 5) for v in row] for row in batch] for batch in reference(x)]
+    print(f"Test input data: {x}")
+    print(f"Test mask index: {MASK_INDEX}")
+    print(f"Test output data: {output}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+
+# used to generate model: cast_saturate.onnx
+
+# Float to integer casts whose inputs fall outside of the target integer range.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def cast_node(name, to):
+    return onnx.helper.make_node(
+        "Cast",
+        inputs=["input1"],
+        outputs=[name],
+        name=f"/Cast_{name}",
+        to=to,
+    )
+
+
+def output_info(name, elem_type):
+    return onnx.helper.make_value_info(
+        name=name,
+        type_proto=onnx.helper.make_tensor_type_proto(elem_type=elem_type, shape=[8]),
+    )
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(name="main_graph", nodes=[
+            cast_node("output1", onnx.TensorProto.INT8),
+            cast_node("output2", onnx.TensorProto.INT16),
+            cast_node("output3", onnx.TensorProto.INT32),
+        ],
+        inputs=[
+            onnx.helper.make_value_info(
+                name="input1",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.FLOAT, shape=[8]
+                ),
+            ),
+        ],
+        outputs=[
+            output_info("output1", onnx.TensorProto.INT8),
+            output_info("output2", onnx.TensorProto.INT16),
+            output_info("output3", onnx.TensorProto.INT32),
+        ]),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "cast_saturate.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+
+if __name__ == "__main__":
+    main()
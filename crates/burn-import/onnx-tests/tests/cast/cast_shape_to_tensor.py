@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+
+# used to generate model: cast_shape_to_tensor.onnx
+
+# Casts the shape of the first input to an int64 tensor, which is used to expand the second input.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node("Shape", ["input1"], ["shape1"], name="/Shape"),
+                onnx.helper.make_node(
+                    "Cast", ["shape1"], ["output2"], name="/Cast", to=onnx.TensorProto.INT64
+                ),
+                onnx.helper.make_node(
+                    "Expand", ["input2", "output2"], ["output1"], name="/Expand"
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 4, 3]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="input2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[1, 3]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 4, 3]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="output2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT64, shape=[3]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "cast_shape_to_tensor.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,61 @@
+#!/usr/bin/env python3
+
+# used to generate model: concat_broadcast.onnx
+
+# Concat along axis 1 of inputs of shape [1, 1, 3] and [2, 4, 3]. The leading size-1 dimension of
+# the first input is broadcast to match the second input, producing an output of shape [2, 5, 3].
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "Concat", ["input1", "input2"], ["output1"], name="/Concat", axis=1
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[1, 1, 3]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="input2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 4, 3]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 5, 3]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "concat_broadcast.onnx"
+
+    # Not checked: the ONNX spec requires the non-concat dimensions to match exactly
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,76 @@
+#!/usr/bin/env python3
+
+# used to generate model: concat_shapes.onnx
+
+# Builds a target shape by concatenating a [2] piece of the first input's shape with the [3, 4]
+# shape of the second input, and reshapes the third input to it. The concatenation is computed on
+# the shape values instead of tensors.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "Shape", ["input1"], ["shape1"], name="/Shape", end=1
+                ),
+                onnx.helper.make_node("Shape", ["input2"], ["shape2"], name="/Shape_1"),
+                onnx.helper.make_node(
+                    "Concat", ["shape1", "shape2"], ["shape3"], name="/Concat", axis=0
+                ),
+                onnx.helper.make_node(
+                    "Reshape", ["input3", "shape3"], ["output1"], name="/Reshape"
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 5]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="input2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[3, 4]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="input3",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[6, 4]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 3, 4]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "concat_shapes.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,144 @@
+#!/usr/bin/env python3
+
+# used to generate model: conv_batch_norm.onnx
+
+# Convolutions followed by a batch norm in inference mode, as exported for `nn.Conv2d` and
+# `nn.Conv1d` followed by `nn.BatchNorm2d` and `nn.BatchNorm1d`. The batch norm following a
+# convolution whose output is only used by it is folded into the convolution, while the
+# convolution also returned by the model isn't fused, so both outputs can be compared.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+CHANNELS_IN = 2
+CHANNELS_OUT = 3
+
+W_2D = [float((i * 7) % 5 - 2) / 4.0 for i in range(CHANNELS_OUT * CHANNELS_IN * 3 * 3)]
+B_2D = [0.5, -0.25, 1.0]
+W_1D = [float((i * 3) % 7 - 3) / 4.0 for i in range(CHANNELS_OUT * CHANNELS_IN * 3)]
+
+SCALE = [1.5, 0.5, -2.0]
+SHIFT = [0.25, -1.0, 0.5]
+MEAN = [0.5, -0.5, 1.0]
+VAR = [4.0, 0.25, 1.0]
+
+
+def tensor(name, dims, values):
+    return onnx.helper.make_tensor(name, onnx.TensorProto.FLOAT, dims, values)
+
+
+def main():
+    nodes = [
+        onnx.helper.make_node(
+            "Conv",
+            ["x", "w_2d", "b_2d"],
+            ["conv_2d"],
+            name="/conv2d/Conv",
+            kernel_shape=[3, 3],
+            pads=[1, 1, 1, 1],
+        ),
+        onnx.helper.make_node(
+            "BatchNormalization",
+            ["conv_2d", "scale", "shift", "mean", "var"],
+            ["output_fused"],
+            name="/norm2d/BatchNormalization",
+            epsilon=1e-5,
+        ),
+        onnx.helper.make_node(
+            "Conv",
+            ["x", "w_2d", "b_2d"],
+            ["conv_shared"],
+            name="/conv2d_1/Conv",
+            kernel_shape=[3, 3],
+            pads=[1, 1, 1, 1],
+        ),
+        onnx.helper.make_node(
+            "BatchNormalization",
+            ["conv_shared", "scale", "shift", "mean", "var"],
+            ["output_unfused"],
+            name="/norm2d_1/BatchNormalization",
+            epsilon=1e-5,
+        ),
+        # Without bias
+        onnx.helper.make_node(
+            "Conv", ["x_1d", "w_1d"], ["conv_1d"], name="/conv1d/Conv", kernel_shape=[3]
+        ),
+        onnx.helper.make_node(
+            "BatchNormalization",
+            ["conv_1d", "scale", "shift", "mean", "var"],
+            ["output_1d"],
+            name="/norm1d/BatchNormalization",
+            epsilon=1e-5,
+        ),
+    ]
+
+    graph = onnx.helper.make_graph(
+        nodes,
+        "main_graph",
+        [
+            onnx.helper.make_tensor_value_info("x", onnx.TensorProto.FLOAT, [2, 2, 4, 4]),
+            onnx.helper.make_tensor_value_info("x_1d", onnx.TensorProto.FLOAT, [2, 2, 5]),
+        ],
+        [
+            onnx.helper.make_tensor_value_info(
+                "output_fused", onnx.TensorProto.FLOAT, [2, 3, 4, 4]
+            ),
+            onnx.helper.make_tensor_value_info(
+                "output_unfused", onnx.TensorProto.FLOAT, [2, 3, 4, 4]
+            ),
+            onnx.helper.make_tensor_value_info(
+                "conv_shared", onnx.TensorProto.FLOAT, [2, 3, 4, 4]
+            ),
+            onnx.helper.make_tensor_value_info(
+                "output_1d", onnx.TensorProto.FLOAT, [2, 3, 3]
+            ),
+        ],
+        initializer=[
+            tensor("w_2d", [CHANNELS_OUT, CHANNELS_IN, 3, 3], W_2D),
+            tensor("b_2d", [CHANNELS_OUT], B_2D),
+            tensor("w_1d", [CHANNELS_OUT, CHANNELS_IN, 3], W_1D),
+            tensor("scale", [CHANNELS_OUT], SCALE),
+            tensor("shift", [CHANNELS_OUT], SHIFT),
+            tensor("mean", [CHANNELS_OUT], MEAN),
+            tensor("var", [CHANNELS_OUT], VAR),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        graph, opset_imports=[onnx.helper.make_operatorsetid("", 16)]
+    )
+    onnx.checker.check_model(model)
+
+    onnx_name = "conv_batch_norm.onnx"
+    onnx.save(model, onnx_name)
+    print(f"Finished exporting model to {onnx_name}")
+
+    # Output some test data for use in the test
+    x_1d = [[[float(c * 5 + i) / 10.0 for i in range(5)] for c in range(CHANNELS_IN)]] * 2
+    output_1d = [
+        [
+            [
+                (
+                    sum(
+                        W_1D[(o * CHANNELS_IN + c) * 3 + k] * x_1d[n][c][i + k]
+                        for c in range(CHANNELS_IN)
+                        for k in range(3)
+                    )
+                    - MEAN[o]
+                )
+                * SCALE[o]
+                / (VAR[o] + 1e-5) ** 0.5
+                + SHIFT[o]
+                for i in range(3)
+            ]
+            for o in range(CHANNELS_OUT)
+        ]
+        for n in range(2)
+    ]
+    print(f"Test input data: {x_1d}")
+    print(f"Test output data: {output_1d}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,131 @@
+#!/usr/bin/env python3
+
+# used to generate model: conv_integer.onnx
+
+# An 8-bit integer convolution laid out like the 3x3 convolutions of the last stage of a quantized
+# ResNet-18 (512 uint8 input channels, int8 weights with per output channel zero points), with
+# fewer output channels and a smaller input to keep the test small. Each output sums 4608
+# products, and the first output channel is saturated so the int32 accumulator exceeds 2^24 and
+# can't be computed exactly with f32.
+# The model is exported using onnx directly and the expected int32 accumulator is the output of
+# ONNX Runtime, which must match the reference implementation below, following the ONNX
+# ConvInteger definition.
+
+import numpy as np
+import onnx
+import onnx.helper
+import onnxruntime
+
+IN_CHANNELS, OUT_CHANNELS, SIZE, KERNEL, STRIDE, PAD = 512, 2, 3, 3, 1, 1
+X_ZERO_POINT = 0
+W_ZERO_POINTS = [-128, 1]
+
+
+def input_values():
+    return [(i * 37 + 11) % 251 for i in range(IN_CHANNELS * SIZE * SIZE)]
+
+
+def weight_values():
+    channel_size = IN_CHANNELS * KERNEL * KERNEL
+    saturated = [127] * channel_size
+    return saturated + [(i * 53 + 7) % 255 - 127 for i in range(channel_size)]
+
+
+def reference(x, w):
+    out_size = (SIZE + 2 * PAD - KERNEL) // STRIDE + 1
+    output = []
+    for m in range(OUT_CHANNELS):
+        for oy in range(out_size):
+            for ox in range(out_size):
+                acc = 0
+                for c in range(IN_CHANNELS):
+                    for ky in range(KERNEL):
+                        for kx in range(KERNEL):
+                            y, x_ = oy * STRIDE + ky - PAD, ox * STRIDE + kx - PAD
+                            # The padding is filled with the input zero point
+                            if 0 <= y < SIZE and 0 <= x_ < SIZE:
+                                value = x[(c * SIZE + y) * SIZE + x_]
+                            else:
+                                value = X_ZERO_POINT
+                            weight = w[((m * IN_CHANNELS + c) * KERNEL + ky) * KERNEL + kx]
+                            acc += (value - X_ZERO_POINT) * (weight - W_ZERO_POINTS[m])
+                output.append(acc)
+    return output
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "ConvInteger",
+                    ["input1", "weight", "x_zero_point", "w_zero_point"],
+                    ["output1"],
+                    name="/ConvInteger",
+                    kernel_shape=[KERNEL, KERNEL],
+                    strides=[STRIDE, STRIDE],
+                    pads=[PAD, PAD, PAD, PAD],
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.UINT8,
+                        shape=[1, IN_CHANNELS, SIZE, SIZE],
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT32, shape=[1, OUT_CHANNELS, SIZE, SIZE]
+                    ),
+                ),
+            ],
+            initializer=[
+                onnx.helper.make_tensor(
+                    "weight",
+                    onnx.TensorProto.INT8,
+                    [OUT_CHANNELS, IN_CHANNELS, KERNEL, KERNEL],
+                    weight_values(),
+                ),
+                onnx.helper.make_tensor("x_zero_point", onnx.TensorProto.UINT8, [], [X_ZERO_POINT]),
+                onnx.helper.make_tensor(
+                    "w_zero_point", onnx.TensorProto.INT8, [OUT_CHANNELS], W_ZERO_POINTS
+                ),
+            ],
+        ),
+    )
+
+
+def run_onnx_runtime(file_name):
+    session = onnxruntime.InferenceSession(file_name)
+    x = np.array(input_values(), dtype=np.uint8).reshape(1, IN_CHANNELS, SIZE, SIZE)
+    (output,) = session.run(None, {"input1": x})
+    return output.flatten().tolist()
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "conv_integer.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+    expected = run_onnx_runtime(file_name)
+    assert expected == reference(input_values(), weight_values()), (
+        "The reference doesn't match ONNX Runtime"
+    )
+    print("Test output data: {}".format(expected))
+
+
+if __name__ == "__main__":
+    main()
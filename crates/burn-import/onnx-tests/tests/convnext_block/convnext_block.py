@@ -0,0 +1,282 @@
+#!/usr/bin/env python3
+
+# used to generate model: convnext_block.onnx
+
+# Equivalent to the `Block` of the reference ConvNeXt implementation (facebookresearch/ConvNeXt)
+# exported with PyTorch: a 7x7 depthwise convolution, a permutation to channels last, a layer
+# norm, a feed-forward layer with an exact GELU (decomposed with Erf), a layer scale, a
+# permutation back to channels first and the residual connection.
+# The model is exported using onnx directly.
+
+import math
+
+import onnx
+import onnx.helper
+
+DIM = 2
+HIDDEN = 4 * DIM
+KERNEL = 7
+HEIGHT = 3
+WIDTH = 3
+EPSILON = 1e-6
+
+
+def dwconv_weight(c, i, j):
+    return ((c * KERNEL * KERNEL + i * KERNEL + j) % 5 - 2) * 0.1
+
+
+def dwconv_bias(c):
+    return 0.1 * c
+
+
+def norm_scale(c):
+    return 1.0 + 0.5 * c
+
+
+def norm_bias(c):
+    return 0.1 * c - 0.05
+
+
+def pwconv1_weight(i, o):
+    return ((i * HIDDEN + o) % 7 - 3) * 0.1
+
+
+def pwconv1_bias(o):
+    return 0.01 * o
+
+
+def pwconv2_weight(i, o):
+    return ((i * DIM + o) % 5 - 2) * 0.2
+
+
+def pwconv2_bias(o):
+    return [0.1, -0.1][o]
+
+
+def gamma(c):
+    return [0.5, 2.0][c]
+
+
+def block_input(c, h, w):
+    return (c * HEIGHT * WIDTH + h * WIDTH + w) / 9.0
+
+
+def forward():
+    pad = KERNEL // 2
+    output = []
+    for h in range(HEIGHT):
+        for w in range(WIDTH):
+            # Depthwise convolution
+            x = []
+            for c in range(DIM):
+                value = dwconv_bias(c)
+                for i in range(KERNEL):
+                    for j in range(KERNEL):
+                        hi, wj = h + i - pad, w + j - pad
+                        if 0 <= hi < HEIGHT and 0 <= wj < WIDTH:
+                            value += dwconv_weight(c, i, j) * block_input(c, hi, wj)
+                x.append(value)
+
+            # Layer norm over the channels
+            mean = sum(x) / DIM
+            var = sum((v - mean) ** 2 for v in x) / DIM
+            x = [
+                (v - mean) / math.sqrt(var + EPSILON) * norm_scale(c) + norm_bias(c)
+                for c, v in enumerate(x)
+            ]
+
+            # Feed-forward layer
+            hidden = [
+                sum(x[i] * pwconv1_weight(i, o) for i in range(DIM)) + pwconv1_bias(o)
+                for o in range(HIDDEN)
+            ]
+            hidden = [v * 0.5 * (1.0 + math.erf(v / math.sqrt(2.0))) for v in hidden]
+            x = [
+                sum(hidden[i] * pwconv2_weight(i, o) for i in range(HIDDEN)) + pwconv2_bias(o)
+                for o in range(DIM)
+            ]
+
+            output.append([block_input(c, h, w) + gamma(c) * v for c, v in enumerate(x)])
+
+    return [[[output[h * WIDTH + w][c] for w in range(WIDTH)] for h in range(HEIGHT)] for c in range(DIM)]
+
+
+def constant(name, value):
+    return onnx.helper.make_node(
+        "Constant",
+        [],
+        [name],
+        name=name,
+        value=onnx.helper.make_tensor(name, onnx.TensorProto.FLOAT, [], [value]),
+    )
+
+
+def tensor(name, shape, values):
+    return onnx.helper.make_tensor(name, onnx.TensorProto.FLOAT, shape, values)
+
+
+def build_model():
+    shape = [1, DIM, HEIGHT, WIDTH]
+
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 17)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "Conv",
+                    ["input1", "dwconv.weight", "dwconv.bias"],
+                    ["/dwconv/Conv_output_0"],
+                    name="/dwconv/Conv",
+                    dilations=[1, 1],
+                    group=DIM,
+                    kernel_shape=[KERNEL, KERNEL],
+                    pads=[KERNEL // 2] * 4,
+                    strides=[1, 1],
+                ),
+                onnx.helper.make_node(
+                    "Transpose",
+                    ["/dwconv/Conv_output_0"],
+                    ["/Transpose_output_0"],
+                    name="/Transpose",
+                    perm=[0, 2, 3, 1],
+                ),
+                onnx.helper.make_node(
+                    "LayerNormalization",
+                    ["/Transpose_output_0", "norm.weight", "norm.bias"],
+                    ["/norm/LayerNormalization_output_0"],
+                    name="/norm/LayerNormalization",
+                    axis=-1,
+                    epsilon=EPSILON,
+                ),
+                onnx.helper.make_node(
+                    "MatMul",
+                    ["/norm/LayerNormalization_output_0", "pwconv1.weight"],
+                    ["/pwconv1/MatMul_output_0"],
+                    name="/pwconv1/MatMul",
+                ),
+                onnx.helper.make_node(
+                    "Add",
+                    ["pwconv1.bias", "/pwconv1/MatMul_output_0"],
+                    ["/pwconv1/Add_output_0"],
+                    name="/pwconv1/Add",
+                ),
+                constant("/act/Constant_output_0", math.sqrt(2.0)),
+                onnx.helper.make_node(
+                    "Div",
+                    ["/pwconv1/Add_output_0", "/act/Constant_output_0"],
+                    ["/act/Div_output_0"],
+                    name="/act/Div",
+                ),
+                onnx.helper.make_node(
+                    "Erf", ["/act/Div_output_0"], ["/act/Erf_output_0"], name="/act/Erf"
+                ),
+                constant("/act/Constant_1_output_0", 1.0),
+                onnx.helper.make_node(
+                    "Add",
+                    ["/act/Erf_output_0", "/act/Constant_1_output_0"],
+                    ["/act/Add_output_0"],
+                    name="/act/Add",
+                ),
+                onnx.helper.make_node(
+                    "Mul",
+                    ["/pwconv1/Add_output_0", "/act/Add_output_0"],
+                    ["/act/Mul_output_0"],
+                    name="/act/Mul",
+                ),
+                constant("/act/Constant_2_output_0", 0.5),
+                onnx.helper.make_node(
+                    "Mul",
+                    ["/act/Mul_output_0", "/act/Constant_2_output_0"],
+                    ["/act/Mul_1_output_0"],
+                    name="/act/Mul_1",
+                ),
+                onnx.helper.make_node(
+                    "MatMul",
+                    ["/act/Mul_1_output_0", "pwconv2.weight"],
+                    ["/pwconv2/MatMul_output_0"],
+                    name="/pwconv2/MatMul",
+                ),
+                onnx.helper.make_node(
+                    "Add",
+                    ["pwconv2.bias", "/pwconv2/MatMul_output_0"],
+                    ["/pwconv2/Add_output_0"],
+                    name="/pwconv2/Add",
+                ),
+                onnx.helper.make_node(
+                    "Mul", ["gamma", "/pwconv2/Add_output_0"], ["/Mul_output_0"], name="/Mul"
+                ),
+                onnx.helper.make_node(
+                    "Transpose",
+                    ["/Mul_output_0"],
+                    ["/Transpose_1_output_0"],
+                    name="/Transpose_1",
+                    perm=[0, 3, 1, 2],
+                ),
+                onnx.helper.make_node(
+                    "Add", ["input1", "/Transpose_1_output_0"], ["output1"], name="/Add"
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=shape
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=shape
+                    ),
+                ),
+            ],
+            initializer=[
+                tensor(
+                    "dwconv.weight",
+                    [DIM, 1, KERNEL, KERNEL],
+                    [
+                        dwconv_weight(c, i, j)
+                        for c in range(DIM)
+                        for i in range(KERNEL)
+                        for j in range(KERNEL)
+                    ],
+                ),
+                tensor("dwconv.bias", [DIM], [dwconv_bias(c) for c in range(DIM)]),
+                tensor("norm.weight", [DIM], [norm_scale(c) for c in range(DIM)]),
+                tensor("norm.bias", [DIM], [norm_bias(c) for c in range(DIM)]),
+                tensor(
+                    "pwconv1.weight",
+                    [DIM, HIDDEN],
+                    [pwconv1_weight(i, o) for i in range(DIM) for o in range(HIDDEN)],
+                ),
+                tensor("pwconv1.bias", [HIDDEN], [pwconv1_bias(o) for o in range(HIDDEN)]),
+                tensor(
+                    "pwconv2.weight",
+                    [HIDDEN, DIM],
+                    [pwconv2_weight(i, o) for i in range(HIDDEN) for o in range(DIM)],
+                ),
+                tensor("pwconv2.bias", [DIM], [pwconv2_bias(o) for o in range(DIM)]),
+                tensor("gamma", [DIM], [gamma(c) for c in range(DIM)]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "convnext_block.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+    print("Output: {}".format(forward()))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,133 @@
+#!/usr/bin/env python3
+
+# used to generate model: depthwise_separable_conv2d.onnx
+
+# Depthwise convolutions followed by pointwise convolutions, as exported for the depthwise
+# separable convolutions of MobileNet and EfficientNet. The convolutions whose depthwise output is
+# only used by the pointwise convolution are fused into a depthwise separable convolution, while
+# the ones whose depthwise output is also returned by the model aren't, so both outputs can be
+# compared. The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+CHANNELS_IN = 2
+CHANNELS_OUT = 3
+SIZE = 4
+
+W_DW = [float((i * 7) % 5 - 2) / 4.0 for i in range(CHANNELS_IN * 3 * 3)]
+B_DW = [0.5, -0.25]
+W_PW = [float((i * 3) % 7 - 3) / 4.0 for i in range(CHANNELS_OUT * CHANNELS_IN)]
+B_PW = [0.25, -1.0, 0.5]
+
+
+def tensor(name, dims, values):
+    return onnx.helper.make_tensor(name, onnx.TensorProto.FLOAT, dims, values)
+
+
+def depthwise(inputs, output, name):
+    return onnx.helper.make_node(
+        "Conv",
+        inputs,
+        [output],
+        name=name,
+        group=CHANNELS_IN,
+        kernel_shape=[3, 3],
+        pads=[1, 1, 1, 1],
+    )
+
+
+def pointwise(inputs, output, name):
+    return onnx.helper.make_node("Conv", inputs, [output], name=name, kernel_shape=[1, 1])
+
+
+def main():
+    nodes = [
+        depthwise(["x", "w_dw", "b_dw"], "dw", "/depthwise/Conv"),
+        pointwise(["dw", "w_pw", "b_pw"], "output_fused", "/pointwise/Conv"),
+        depthwise(["x", "w_dw", "b_dw"], "dw_shared", "/depthwise_1/Conv"),
+        pointwise(["dw_shared", "w_pw", "b_pw"], "output_unfused", "/pointwise_1/Conv"),
+        # Without depthwise bias
+        depthwise(["x", "w_dw"], "dw_no_bias", "/depthwise_2/Conv"),
+        pointwise(["dw_no_bias", "w_pw", "b_pw"], "output_no_bias", "/pointwise_2/Conv"),
+    ]
+
+    shape_in = [1, CHANNELS_IN, SIZE, SIZE]
+    shape_out = [1, CHANNELS_OUT, SIZE, SIZE]
+    graph = onnx.helper.make_graph(
+        nodes,
+        "main_graph",
+        [onnx.helper.make_tensor_value_info("x", onnx.TensorProto.FLOAT, shape_in)],
+        [
+            onnx.helper.make_tensor_value_info(
+                "output_fused", onnx.TensorProto.FLOAT, shape_out
+            ),
+            onnx.helper.make_tensor_value_info(
+                "output_unfused", onnx.TensorProto.FLOAT, shape_out
+            ),
+            onnx.helper.make_tensor_value_info(
+                "dw_shared", onnx.TensorProto.FLOAT, shape_in
+            ),
+            onnx.helper.make_tensor_value_info(
+                "output_no_bias", onnx.TensorProto.FLOAT, shape_out
+            ),
+        ],
+        initializer=[
+            tensor("w_dw", [CHANNELS_IN, 1, 3, 3], W_DW),
+            tensor("b_dw", [CHANNELS_IN], B_DW),
+            tensor("w_pw", [CHANNELS_OUT, CHANNELS_IN, 1, 1], W_PW),
+            tensor("b_pw", [CHANNELS_OUT], B_PW),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        graph, opset_imports=[onnx.helper.make_operatorsetid("", 16)]
+    )
+    onnx.checker.check_model(model)
+
+    onnx_name = "depthwise_separable_conv2d.onnx"
+    onnx.save(model, onnx_name)
+    print(f"Finished exporting model to {onnx_name}")
+
+    # Output some test data for use in the test
+    x = [
+        [[float((c * SIZE + i) * SIZE + j) / 10.0 for j in range(SIZE)] for i in range(SIZE)]
+        for c in range(CHANNELS_IN)
+    ]
+
+    def pixel(c, i, j):
+        if 0 <= i < SIZE and 0 <= j < SIZE:
+            return x[c][i][j]
+        return 0.0
+
+    dw = [
+        [
+            [
+                sum(
+                    W_DW[c * 9 + ki * 3 + kj] * pixel(c, i + ki - 1, j + kj - 1)
+                    for ki in range(3)
+                    for kj in range(3)
+                )
+                for j in range(SIZE)
+            ]
+            for i in range(SIZE)
+        ]
+        for c in range(CHANNELS_IN)
+    ]
+    output_no_bias = [
+        [
+            [
+                sum(W_PW[o * CHANNELS_IN + c] * dw[c][i][j] for c in range(CHANNELS_IN))
+                + B_PW[o]
+                for j in range(SIZE)
+            ]
+            for i in range(SIZE)
+        ]
+        for o in range(CHANNELS_OUT)
+    ]
+    print(f"Test input data: {x}")
+    print(f"Test output data: {output_no_bias}")
+
+
+if __name__ == "__main__":
+    main()
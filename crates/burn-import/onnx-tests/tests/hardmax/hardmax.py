@@ -0,0 +1,54 @@
+#!/usr/bin/env python3
+
+# used to generate model: hardmax.onnx
+
+# Hardmax of a [3, 4] input along the last axis (the default since opset 13), producing a one-hot
+# at the position of the first maximum of each row.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node("Hardmax", ["input1"], ["output1"], name="/Hardmax"),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[3, 4]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[3, 4]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "hardmax.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
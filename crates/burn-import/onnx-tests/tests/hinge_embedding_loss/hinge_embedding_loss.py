@@ -0,0 +1,131 @@
+#!/usr/bin/env python3
+
+# used to generate model: hinge_embedding_loss.onnx
+
+# Equivalent to `torch.nn.functional.hinge_embedding_loss(input, target, margin=2.0)` exported
+# with PyTorch, which decomposes the loss into
+# `where(target != 1, clamp_min(margin - input, 0), 0) + where(target != -1, input, 0)` followed
+# by its mean.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+MARGIN = 2.0
+SHAPE = [2, 3]
+
+INPUT = [[0.0, 2.5, 0.5], [1.0, 0.2, 3.0]]
+TARGET = [[1.0, -1.0, -1.0], [1.0, -1.0, -1.0]]
+
+
+def forward():
+    losses = [
+        x if y == 1.0 else max(0.0, MARGIN - x)
+        for xs, ys in zip(INPUT, TARGET)
+        for x, y in zip(xs, ys)
+    ]
+    return sum(losses) / len(losses)
+
+
+def constant(name, value):
+    return onnx.helper.make_node(
+        "Constant",
+        [],
+        [name],
+        name=name,
+        value=onnx.helper.make_tensor(name, onnx.TensorProto.FLOAT, [], [value]),
+    )
+
+
+def masked_where(index, condition_value, value, output):
+    suffix = "" if index == 0 else f"_{index}"
+    return [
+        constant(f"/Constant_{2 * index + 2}_output_0", condition_value),
+        onnx.helper.make_node(
+            "Equal",
+            ["target", f"/Constant_{2 * index + 2}_output_0"],
+            [f"/Equal{suffix}_output_0"],
+            name=f"/Equal{suffix}",
+        ),
+        onnx.helper.make_node(
+            "Not", [f"/Equal{suffix}_output_0"], [f"/Not{suffix}_output_0"], name=f"/Not{suffix}"
+        ),
+        onnx.helper.make_node(
+            "Where",
+            [f"/Not{suffix}_output_0", value, "/Constant_3_output_0"],
+            [output],
+            name=f"/Where{suffix}",
+        ),
+    ]
+
+
+def build_model():
+    nodes = [
+        constant("/Constant_output_0", MARGIN),
+        onnx.helper.make_node(
+            "Sub", ["/Constant_output_0", "input"], ["/Sub_output_0"], name="/Sub"
+        ),
+        constant("/Constant_1_output_0", 0.0),
+        onnx.helper.make_node(
+            "Clip",
+            ["/Sub_output_0", "/Constant_1_output_0"],
+            ["/Clip_output_0"],
+            name="/Clip",
+        ),
+    ]
+    where_margin = masked_where(0, 1.0, "/Clip_output_0", "/Where_output_0")
+    # The zeros are shared by both masks
+    where_margin.insert(3, constant("/Constant_3_output_0", 0.0))
+    nodes += where_margin
+    nodes += masked_where(1, -1.0, "input", "/Where_1_output_0")
+    nodes += [
+        onnx.helper.make_node(
+            "Add", ["/Where_output_0", "/Where_1_output_0"], ["/Add_output_0"], name="/Add"
+        ),
+        onnx.helper.make_node(
+            "ReduceMean", ["/Add_output_0"], ["output"], name="/ReduceMean", keepdims=0
+        ),
+    ]
+
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=nodes,
+            inputs=[
+                onnx.helper.make_value_info(
+                    name=name,
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=SHAPE
+                    ),
+                )
+                for name in ["input", "target"]
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "hinge_embedding_loss.onnx"
+
+    onnx.checker.check_model(onnx_model)
+    onnx.save(onnx_model, file_name)
+    print(f"Finished exporting model to {file_name}")
+
+    print(f"Test input data: {INPUT}")
+    print(f"Test target data: {TARGET}")
+    print(f"Test output data: {forward()}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,60 @@
+#!/usr/bin/env python3
+
+# used to generate model: identity.onnx
+
+# The Identity node forwards the output of a Shape node, so the graph output is a shape
+# and not a tensor. The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(name="main_graph", nodes=[
+            onnx.helper.make_node(
+                "Shape",
+                inputs=["input1"],
+                outputs=["shape1"],
+                name="/Shape"
+            ),
+            onnx.helper.make_node(
+                "Identity",
+                inputs=["shape1"],
+                outputs=["output1"],
+                name="/Identity"
+            ),
+        ],
+        inputs=[
+            onnx.helper.make_value_info(
+                name="input1",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.FLOAT, shape=[2, 3, 4]
+                ),
+            ),
+        ],
+        outputs=[
+            onnx.helper.make_value_info(
+                name="output1",
+                type_proto=onnx.helper.make_tensor_type_proto(
+                    elem_type=onnx.TensorProto.INT64, shape=[3]
+                ),
+            )
+        ]),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "identity.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+
+if __name__ == "__main__":
+    main()
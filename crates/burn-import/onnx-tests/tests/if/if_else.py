@@ -0,0 +1,83 @@
+#!/usr/bin/env python3
+
+# used to generate model: if_else.onnx
+
+# Selects between two linear layers with a boolean input: `y = x @ W1^T + b1` when `cond` is
+# true, `y = x @ W2^T + b2` otherwise. The weights are initializers of the main graph used by
+# the branches.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def linear_branch(name, weight, bias):
+    return onnx.helper.make_graph(
+        name=name,
+        nodes=[
+            onnx.helper.make_node(
+                "Gemm",
+                ["x", weight, bias],
+                [f"{name}_out"],
+                name=f"/{name}/Gemm",
+                alpha=1.0,
+                beta=1.0,
+                transB=1,
+            ),
+        ],
+        inputs=[],
+        outputs=[
+            onnx.helper.make_tensor_value_info(f"{name}_out", onnx.TensorProto.FLOAT, [2, 2]),
+        ],
+    )
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "If",
+                    ["cond"],
+                    ["y"],
+                    name="/If",
+                    then_branch=linear_branch("then_branch", "W1", "b1"),
+                    else_branch=linear_branch("else_branch", "W2", "b2"),
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_tensor_value_info("x", onnx.TensorProto.FLOAT, [2, 3]),
+                onnx.helper.make_tensor_value_info("cond", onnx.TensorProto.BOOL, []),
+            ],
+            outputs=[
+                onnx.helper.make_tensor_value_info("y", onnx.TensorProto.FLOAT, [2, 2]),
+            ],
+            initializer=[
+                onnx.helper.make_tensor(
+                    "W1", onnx.TensorProto.FLOAT, [2, 3], [1.0, 0.0, 1.0, 0.0, 1.0, 1.0]
+                ),
+                onnx.helper.make_tensor("b1", onnx.TensorProto.FLOAT, [2], [0.5, -0.5]),
+                onnx.helper.make_tensor(
+                    "W2", onnx.TensorProto.FLOAT, [2, 3], [-1.0, 0.0, 3.0, 2.0, -2.0, 0.0]
+                ),
+                onnx.helper.make_tensor("b2", onnx.TensorProto.FLOAT, [2], [1.0, 1.0]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "if_else.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,57 @@
+#!/usr/bin/env python3
+
+# used to generate model: log_softmax_axis_neg1.onnx
+
+# LogSoftmax of a [2, 2, 3] input along the last axis, given as -1. Equivalent to
+# `torch.nn.functional.log_softmax(x, dim=-1)`, following the opset 13 semantics where the axis is
+# used directly instead of coercing the input to 2D around it.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "LogSoftmax", ["input1"], ["output1"], name="/LogSoftmax", axis=-1
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 2, 3]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 2, 3]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "log_softmax_axis_neg1.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+
+# used to generate model: matmul_add.onnx
+
+# Products of runtime tensors followed by the addition of a bias, `torch.matmul(x, w) + b` and
+# `x_2d @ w + b` with `x_2d` of rank 2 exported as a Gemm node. The MatMul and Gemm nodes whose product is only used
+# by the Add node are fused with it, while the product also returned by the model isn't (its bias `b_3d` has the
+# values of `b` with the rank of the product, as the unfused Add node doesn't broadcast ranks).
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+X = [[[float(i * 4 + j) / 10.0 for j in range(4)] for i in range(3)] for _ in range(2)]
+W = [[float(i - j) / 4.0 for j in range(5)] for i in range(4)]
+B = [0.5, -1.0, 2.0, 0.0, 1.5]
+
+
+def matmul(x, w):
+    return [[sum(a * w[k][j] for k, a in enumerate(row)) for j in range(len(w[0]))] for row in x]
+
+
+def main():
+    nodes = [
+        onnx.helper.make_node("MatMul", ["x", "w"], ["product"], name="/MatMul"),
+        onnx.helper.make_node("Add", ["product", "b"], ["output_fused"], name="/Add"),
+        onnx.helper.make_node("MatMul", ["x", "w"], ["product_shared"], name="/MatMul_1"),
+        onnx.helper.make_node(
+            "Add", ["product_shared", "b_3d"], ["output_unfused"], name="/Add_1"
+        ),
+        onnx.helper.make_node("Gemm", ["x_2d", "w"], ["product_gemm"], name="/Gemm"),
+        onnx.helper.make_node("Add", ["b", "product_gemm"], ["output_gemm"], name="/Add_2"),
+    ]
+
+    graph = onnx.helper.make_graph(
+        nodes,
+        "main_graph",
+        [
+            onnx.helper.make_tensor_value_info("x", onnx.TensorProto.FLOAT, [2, 3, 4]),
+            onnx.helper.make_tensor_value_info("x_2d", onnx.TensorProto.FLOAT, [3, 4]),
+            onnx.helper.make_tensor_value_info("w", onnx.TensorProto.FLOAT, [4, 5]),
+            onnx.helper.make_tensor_value_info("b", onnx.TensorProto.FLOAT, [5]),
+            onnx.helper.make_tensor_value_info("b_3d", onnx.TensorProto.FLOAT, [1, 1, 5]),
+        ],
+        [
+            onnx.helper.make_tensor_value_info(
+                "output_fused", onnx.TensorProto.FLOAT, [2, 3, 5]
+            ),
+            onnx.helper.make_tensor_value_info(
+                "output_unfused", onnx.TensorProto.FLOAT, [2, 3, 5]
+            ),
+            onnx.helper.make_tensor_value_info(
+                "product_shared", onnx.TensorProto.FLOAT, [2, 3, 5]
+            ),
+            onnx.helper.make_tensor_value_info(
+                "output_gemm", onnx.TensorProto.FLOAT, [3, 5]
+            ),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        graph, opset_imports=[onnx.helper.make_operatorsetid("", 16)]
+    )
+    onnx.checker.check_model(model)
+
+    onnx_name = "matmul_add.onnx"
+    onnx.save(model, onnx_name)
+    print(f"Finished exporting model to {onnx_name}")
+
+    # Output some test data for use in the test
+    output = [[[p + b for p, b in zip(row, B)] for row in matmul(x, W)] for x in X]
+    print(f"Test input data: {X}, {X[0]}, {W}, {B}")
+    print(f"Test output data: {output}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+
+# used to generate model: matmul_vector.onnx
+
+# Equivalent to `torch.matmul(u, x), torch.matmul(x, v)` with a 1D `u` and `v` on each side of a
+# 4D `x` whose last two dimensions differ, so the vector operands must be expanded to a row and a
+# column respectively.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+X_SHAPE = [2, 3, 4, 5]
+
+
+def main():
+    nodes = [
+        onnx.helper.make_node("MatMul", ["u", "x"], ["output_vm"], name="/MatMul"),
+        onnx.helper.make_node("MatMul", ["x", "v"], ["output_mv"], name="/MatMul_1"),
+    ]
+
+    graph = onnx.helper.make_graph(
+        nodes,
+        "main_graph",
+        [
+            onnx.helper.make_tensor_value_info("x", onnx.TensorProto.FLOAT, X_SHAPE),
+            onnx.helper.make_tensor_value_info("u", onnx.TensorProto.FLOAT, [4]),
+            onnx.helper.make_tensor_value_info("v", onnx.TensorProto.FLOAT, [5]),
+        ],
+        [
+            onnx.helper.make_tensor_value_info(
+                "output_vm", onnx.TensorProto.FLOAT, [2, 3, 5]
+            ),
+            onnx.helper.make_tensor_value_info(
+                "output_mv", onnx.TensorProto.FLOAT, [2, 3, 4]
+            ),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        graph, opset_imports=[onnx.helper.make_operatorsetid("", 16)]
+    )
+    onnx.checker.check_model(model)
+
+    onnx_name = "matmul_vector.onnx"
+    onnx.save(model, onnx_name)
+    print(f"Finished exporting model to {onnx_name}")
+
+    # Output some test data for use in the test
+    x = [float(i) for i in range(120)]
+    u = [float(i) for i in range(4)]
+    v = [float(i) for i in range(5)]
+    matrices = [x[i : i + 20] for i in range(0, 120, 20)]
+    output_vm = [
+        [sum(u[r] * m[r * 5 + c] for r in range(4)) for c in range(5)] for m in matrices
+    ]
+    output_mv = [
+        [sum(m[r * 5 + c] * v[c] for c in range(5)) for r in range(4)] for m in matrices
+    ]
+    print(f"Test output data: {output_vm}, {output_mv}")
+
+
+if __name__ == "__main__":
+    main()
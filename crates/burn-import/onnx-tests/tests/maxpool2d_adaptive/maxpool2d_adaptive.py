@@ -0,0 +1,79 @@
+#!/usr/bin/env python3
+
+# used to generate model: maxpool2d_adaptive.onnx
+
+# Two MaxPool nodes whose windows tile their input exactly: a 2x2 kernel with an equal stride,
+# which is kept as max pooling, and a kernel spanning the whole input with `auto_pad=VALID`, which
+# is imported as global adaptive max pooling, equivalent to `nn.AdaptiveMaxPool2d(1)`.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+INPUT = [
+    [1.0, 9.0, 3.0, 4.0, -2.0, 0.0],
+    [5.0, 2.0, 8.0, 7.0, 6.0, -1.0],
+    [0.0, 3.0, 2.0, 11.0, 4.0, 5.0],
+    [4.0, 10.0, -3.0, 1.0, 2.0, 12.0],
+]
+
+
+def main():
+    nodes = [
+        onnx.helper.make_node(
+            "MaxPool",
+            ["input"],
+            ["output_tiled"],
+            name="/MaxPool",
+            kernel_shape=[2, 2],
+            strides=[2, 2],
+            pads=[0, 0, 0, 0],
+            dilations=[1, 1],
+        ),
+        onnx.helper.make_node(
+            "MaxPool",
+            ["input"],
+            ["output_global"],
+            name="/MaxPool_1",
+            kernel_shape=[4, 6],
+            auto_pad="VALID",
+        ),
+    ]
+
+    graph = onnx.helper.make_graph(
+        nodes,
+        "main_graph",
+        [onnx.helper.make_tensor_value_info("input", onnx.TensorProto.FLOAT, [1, 1, 4, 6])],
+        [
+            onnx.helper.make_tensor_value_info(
+                "output_tiled", onnx.TensorProto.FLOAT, [1, 1, 2, 3]
+            ),
+            onnx.helper.make_tensor_value_info(
+                "output_global", onnx.TensorProto.FLOAT, [1, 1, 1, 1]
+            ),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        graph, opset_imports=[onnx.helper.make_operatorsetid("", 16)]
+    )
+    onnx.checker.check_model(model)
+
+    onnx_name = "maxpool2d_adaptive.onnx"
+    onnx.save(model, onnx_name)
+    print(f"Finished exporting model to {onnx_name}")
+
+    # Output some test data for use in the test
+    output_tiled = [
+        [
+            max(INPUT[r][c] for r in range(2 * i, 2 * i + 2) for c in range(2 * j, 2 * j + 2))
+            for j in range(3)
+        ]
+        for i in range(2)
+    ]
+    output_global = max(max(row) for row in INPUT)
+    print(f"Test output data: {output_tiled}, {output_global}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,67 @@
+#!/usr/bin/env python3
+
+# used to generate model: optional.onnx
+
+# Wraps a [2, 3] input in an optional, queries whether it holds an element and applies relu to the
+# unwrapped element.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node("Optional", ["input1"], ["optional1"], name="/Optional"),
+                onnx.helper.make_node(
+                    "OptionalHasElement", ["optional1"], ["output1"], name="/OptionalHasElement"
+                ),
+                onnx.helper.make_node(
+                    "OptionalGetElement", ["optional1"], ["element1"], name="/OptionalGetElement"
+                ),
+                onnx.helper.make_node("Relu", ["element1"], ["output2"], name="/Relu"),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 3]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.BOOL, shape=[]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="output2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 3]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "optional.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,60 @@
+#!/usr/bin/env python3
+
+# used to generate model: reduce_max_multi_axis.onnx
+
+# Reduces over two axes in a single node, with and without keeping the reduced dims:
+#   - ReduceMax, keepdims=1, axes=[1, 2]
+#   - ReduceMax, keepdims=0, axes=[1, -1]
+#   - ReduceMin, keepdims=0, axes=[0, 2]
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "ReduceMax", ["input1"], ["output1"], name="/ReduceMax", axes=[1, 2], keepdims=1
+                ),
+                onnx.helper.make_node(
+                    "ReduceMax",
+                    ["input1"],
+                    ["output2"],
+                    name="/ReduceMax_1",
+                    axes=[1, -1],
+                    keepdims=0,
+                ),
+                onnx.helper.make_node(
+                    "ReduceMin", ["input1"], ["output3"], name="/ReduceMin", axes=[0, 2], keepdims=0
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_tensor_value_info("input1", onnx.TensorProto.FLOAT, [2, 3, 4]),
+            ],
+            outputs=[
+                onnx.helper.make_tensor_value_info("output1", onnx.TensorProto.FLOAT, [2, 1, 1]),
+                onnx.helper.make_tensor_value_info("output2", onnx.TensorProto.FLOAT, [2]),
+                onnx.helper.make_tensor_value_info("output3", onnx.TensorProto.FLOAT, [3]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "reduce_max_multi_axis.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+
+if __name__ == "__main__":
+    main()
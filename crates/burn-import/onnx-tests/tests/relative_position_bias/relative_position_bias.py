@@ -0,0 +1,117 @@
+#!/usr/bin/env python3
+
+# used to generate model: relative_position_bias.onnx
+
+# Equivalent to `T5Attention.compute_bias` of HuggingFace Transformers exported with constant
+# folding: the relative position buckets are folded into constant (flattened) indices used to
+# gather the bias of each head, which is then reshaped and permuted to [1, heads, query, key] and
+# added to the attention scores.
+# The model is exported using onnx directly.
+
+import math
+
+import onnx
+import onnx.helper
+
+NUM_BUCKETS = 8
+MAX_DISTANCE = 6
+NUM_HEADS = 2
+QUERY_LEN = 4
+KEY_LEN = 5
+
+
+def relative_position_bucket(relative_position, num_buckets, max_distance):
+    # `T5Attention._relative_position_bucket` for a bidirectional attention
+    num_buckets //= 2
+    bucket = num_buckets if relative_position > 0 else 0
+    relative_position = abs(relative_position)
+
+    max_exact = num_buckets // 2
+    if relative_position < max_exact:
+        return bucket + relative_position
+
+    large = max_exact + int(
+        math.log(relative_position / max_exact)
+        / math.log(max_distance / max_exact)
+        * (num_buckets - max_exact)
+    )
+    return bucket + min(large, num_buckets - 1)
+
+
+def buckets():
+    return [
+        relative_position_bucket(key - query, NUM_BUCKETS, MAX_DISTANCE)
+        for query in range(QUERY_LEN)
+        for key in range(KEY_LEN)
+    ]
+
+
+def build_model():
+    scores_shape = [1, NUM_HEADS, QUERY_LEN, KEY_LEN]
+    # The bias of each bucket is `bucket + 10 * head`
+    weight = [bucket + 10.0 * head for bucket in range(NUM_BUCKETS) for head in range(NUM_HEADS)]
+
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node("Gather", ["weight", "buckets"], ["values"], name="/Gather"),
+                onnx.helper.make_node(
+                    "Reshape", ["values", "shape"], ["reshaped"], name="/Reshape"
+                ),
+                onnx.helper.make_node(
+                    "Transpose", ["reshaped"], ["permuted"], name="/Transpose", perm=[2, 0, 1]
+                ),
+                onnx.helper.make_node(
+                    "Unsqueeze", ["permuted", "axes"], ["bias"], name="/Unsqueeze"
+                ),
+                onnx.helper.make_node("Add", ["input1", "bias"], ["output1"], name="/Add"),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=scores_shape
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=scores_shape
+                    ),
+                ),
+            ],
+            initializer=[
+                onnx.helper.make_tensor(
+                    "weight", onnx.TensorProto.FLOAT, [NUM_BUCKETS, NUM_HEADS], weight
+                ),
+                onnx.helper.make_tensor(
+                    "buckets", onnx.TensorProto.INT64, [QUERY_LEN * KEY_LEN], buckets()
+                ),
+                onnx.helper.make_tensor(
+                    "shape", onnx.TensorProto.INT64, [3], [QUERY_LEN, KEY_LEN, NUM_HEADS]
+                ),
+                onnx.helper.make_tensor("axes", onnx.TensorProto.INT64, [1], [0]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "relative_position_bias.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+    print("Buckets: {}".format(buckets()))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,93 @@
+#!/usr/bin/env python3
+
+# used to generate model: repeat_interleave.onnx
+
+# `RepeatInterleave` is a custom operator of the `ai.onnx.contrib` domain, repeating each element
+# of its input along the `axis` attribute by the counts of its `repeats` input, like
+# `torch.repeat_interleave`.
+# - output1: the [2, 3] input repeated by constant counts [1, 0, 2] along axis 1, then by a
+#   constant scalar count of 2 along axis -2.
+# - output2: the [2, 3] input repeated by the runtime counts of input2 along axis 0.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def make_repeat_interleave_node(inputs, outputs, name, axis):
+    return onnx.helper.make_node(
+        "RepeatInterleave",
+        inputs,
+        outputs,
+        name=name,
+        domain="ai.onnx.contrib",
+        axis=axis,
+    )
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[
+            onnx.helper.make_operatorsetid("", 16),
+            onnx.helper.make_operatorsetid("ai.onnx.contrib", 1),
+        ],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                make_repeat_interleave_node(
+                    ["input1", "repeats1"], ["repeat1_out1"], "/RepeatInterleave", 1
+                ),
+                make_repeat_interleave_node(
+                    ["repeat1_out1", "repeats2"], ["output1"], "/RepeatInterleave_1", -2
+                ),
+                make_repeat_interleave_node(
+                    ["input1", "input2"], ["output2"], "/RepeatInterleave_2", 0
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 3]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="input2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT64, shape=[2]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[4, 3]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="output2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[None, 3]
+                    ),
+                ),
+            ],
+            initializer=[
+                onnx.helper.make_tensor("repeats1", onnx.TensorProto.INT64, [3], [1, 0, 2]),
+                onnx.helper.make_tensor("repeats2", onnx.TensorProto.INT64, [], [2]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "repeat_interleave.onnx"
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
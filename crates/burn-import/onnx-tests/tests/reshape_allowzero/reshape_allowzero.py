@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+
+# used to generate model: reshape_allowzero.onnx
+
+# Reshape with both values of `allowzero`:
+# - allowzero=0: a 0 in the shape copies the input dimension ([2, 3, 4] -> [0, 12] = [2, 12])
+# - allowzero=1: a 0 in the shape is a zero-size dimension ([0, 3] -> [3, 0])
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def tensor_value_info(name, shape):
+    return onnx.helper.make_value_info(
+        name=name,
+        type_proto=onnx.helper.make_tensor_type_proto(
+            elem_type=onnx.TensorProto.FLOAT, shape=shape
+        ),
+    )
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "Reshape", ["input1", "shape1"], ["output1"], name="/Reshape", allowzero=0
+                ),
+                onnx.helper.make_node(
+                    "Reshape", ["input2", "shape2"], ["output2"], name="/Reshape_1", allowzero=1
+                ),
+            ],
+            inputs=[
+                tensor_value_info("input1", [2, 3, 4]),
+                tensor_value_info("input2", [0, 3]),
+            ],
+            outputs=[
+                tensor_value_info("output1", [2, 12]),
+                tensor_value_info("output2", [3, 0]),
+            ],
+            initializer=[
+                onnx.helper.make_tensor("shape1", onnx.TensorProto.INT64, [2], [0, 12]),
+                onnx.helper.make_tensor("shape2", onnx.TensorProto.INT64, [2], [3, 0]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "reshape_allowzero.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+
+# used to generate model: reshape_infer_neg1.onnx
+
+# Reshape with a single inferred (-1) dimension: [2, 3, 4] -> [4, -1] = [4, 6],
+# then [4, 6] -> [-1] = [24].
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def tensor_value_info(name, shape):
+    return onnx.helper.make_value_info(
+        name=name,
+        type_proto=onnx.helper.make_tensor_type_proto(
+            elem_type=onnx.TensorProto.FLOAT, shape=shape
+        ),
+    )
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node("Reshape", ["input1", "shape1"], ["output1"], name="/Reshape"),
+                onnx.helper.make_node(
+                    "Reshape", ["output1", "shape2"], ["output2"], name="/Reshape_1"
+                ),
+            ],
+            inputs=[tensor_value_info("input1", [2, 3, 4])],
+            outputs=[
+                tensor_value_info("output1", [4, 6]),
+                tensor_value_info("output2", [24]),
+            ],
+            initializer=[
+                onnx.helper.make_tensor("shape1", onnx.TensorProto.INT64, [2], [4, -1]),
+                onnx.helper.make_tensor("shape2", onnx.TensorProto.INT64, [1], [-1]),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "reshape_infer_neg1.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
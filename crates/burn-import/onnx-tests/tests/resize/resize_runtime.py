@@ -0,0 +1,54 @@
+#!/usr/bin/env python3
+
+# used to generate models: onnx-tests/tests/resize/resize_runtime_sizes.onnx and
+# onnx-tests/tests/resize/resize_runtime_scales.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def export_resize_runtime(filename, input_shape, output_shape, target, target_type):
+    input_tensor = helper.make_tensor_value_info("input_tensor", TensorProto.FLOAT, input_shape)
+
+    # Like the exporters, only one of scales and sizes is provided and the other is left empty
+    target_tensor = helper.make_tensor_value_info(target, target_type, [4])
+    if target == "scales":
+        inputs = ["input_tensor", "", "scales", ""]
+    else:
+        inputs = ["input_tensor", "", "", "sizes"]
+
+    resize_node = helper.make_node(
+        "Resize",
+        name="resize_node",
+        inputs=inputs,
+        outputs=["output"],
+        mode="nearest",
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[resize_node],
+        name="ResizeGraph",
+        inputs=[input_tensor, target_tensor],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, output_shape)],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="resize",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, filename)
+
+
+def main() -> None:
+    export_resize_runtime(
+        "resize_runtime_sizes.onnx", [1, 1, 4, 4], [1, 1, 2, 2], "sizes", TensorProto.INT64
+    )
+    export_resize_runtime(
+        "resize_runtime_scales.onnx", [1, 1, 2, 2], [1, 1, 4, 4], "scales", TensorProto.FLOAT
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+
+# used to generate model: scatter_elements.onnx
+
+# ScatterElements with each of the supported reductions along the last axis.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def scatter_node(name, indices, reduction):
+    return onnx.helper.make_node(
+        "ScatterElements",
+        inputs=["data", indices, "updates"],
+        outputs=[name],
+        name=f"/ScatterElements_{reduction}",
+        axis=1,
+        reduction=reduction,
+    )
+
+
+def value_info(name, elem_type, shape):
+    return onnx.helper.make_value_info(
+        name=name,
+        type_proto=onnx.helper.make_tensor_type_proto(elem_type=elem_type, shape=shape),
+    )
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 18)],
+        graph=onnx.helper.make_graph(name="main_graph", nodes=[
+            # The replaced positions must be unique
+            scatter_node("output_none", "indices_unique", "none"),
+            scatter_node("output_add", "indices", "add"),
+            scatter_node("output_mul", "indices", "mul"),
+            scatter_node("output_max", "indices", "max"),
+            scatter_node("output_min", "indices", "min"),
+        ],
+        inputs=[
+            value_info("data", onnx.TensorProto.FLOAT, [2, 3]),
+            value_info("indices_unique", onnx.TensorProto.INT64, [2, 2]),
+            value_info("indices", onnx.TensorProto.INT64, [2, 2]),
+            value_info("updates", onnx.TensorProto.FLOAT, [2, 2]),
+        ],
+        outputs=[
+            value_info(name, onnx.TensorProto.FLOAT, [2, 3])
+            for name in ["output_none", "output_add", "output_mul", "output_max", "output_min"]
+        ]),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "scatter_elements.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+
+if __name__ == "__main__":
+    main()
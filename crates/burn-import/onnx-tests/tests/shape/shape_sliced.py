@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/shape/shape_sliced.onnx
+
+# The `start` and `end` attributes of Shape (opset 15) return a sub-range of the shape. The model
+# is exported using onnx directly, torch never sets them.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "Shape", ["input1"], ["output1"], name="/Shape", start=1, end=3
+                ),
+                onnx.helper.make_node(
+                    "Shape", ["input1"], ["output2"], name="/Shape_1", start=-3, end=-1
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 3, 4, 5]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT64, shape=[2]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="output2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT64, shape=[2]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "shape_sliced.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+
+# used to generate model: shape_arithmetic.onnx
+
+# Doubles the shape of the first input and reshapes the second input to it, the arithmetic on the
+# shape is computed on the shape values instead of tensors.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node("Shape", ["input1"], ["shape1"], name="/Shape"),
+                onnx.helper.make_node(
+                    "Constant",
+                    [],
+                    ["two"],
+                    name="/Constant",
+                    value=onnx.helper.make_tensor("two", onnx.TensorProto.INT64, [], [2]),
+                ),
+                onnx.helper.make_node("Mul", ["shape1", "two"], ["shape2"], name="/Mul"),
+                onnx.helper.make_node(
+                    "Reshape", ["input2", "shape2"], ["output1"], name="/Reshape"
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[1, 2, 3]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="input2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[6, 8]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 4, 6]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "shape_arithmetic.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,86 @@
+#!/usr/bin/env python3
+
+# used to generate model: sinusoidal_pos_encoding.onnx
+
+# Equivalent to the positional encoding of the PyTorch transformer tutorial exported with constant
+# folding, where the sin/cos of the positions are folded into a table added to the input.
+# A second Add with a constant that is not a sinusoids table must remain an Add node.
+# The model is exported using onnx directly.
+
+import math
+
+import onnx
+import onnx.helper
+
+SEQ_LENGTH = 5
+D_MODEL = 8
+
+
+def sinusoids():
+    values = []
+    for position in range(SEQ_LENGTH):
+        for k in range(0, D_MODEL, 2):
+            div_term = math.exp(k * -(math.log(10000.0) / D_MODEL))
+            values.append(math.sin(position * div_term))
+            values.append(math.cos(position * div_term))
+    return values
+
+
+def build_model():
+    shape = [1, SEQ_LENGTH, D_MODEL]
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node("Add", ["input1", "pe"], ["encoded"], name="/Add"),
+                onnx.helper.make_node(
+                    "Constant",
+                    [],
+                    ["bias"],
+                    name="/Constant",
+                    value=onnx.helper.make_tensor(
+                        "bias_value",
+                        onnx.TensorProto.FLOAT,
+                        shape,
+                        [0.5] * (SEQ_LENGTH * D_MODEL),
+                    ),
+                ),
+                onnx.helper.make_node("Add", ["encoded", "bias"], ["output1"], name="/Add_1"),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, SEQ_LENGTH, D_MODEL]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, SEQ_LENGTH, D_MODEL]
+                    ),
+                ),
+            ],
+            initializer=[
+                onnx.helper.make_tensor("pe", onnx.TensorProto.FLOAT, shape, sinusoids()),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "sinusoidal_pos_encoding.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+
+
+if __name__ == "__main__":
+    main()
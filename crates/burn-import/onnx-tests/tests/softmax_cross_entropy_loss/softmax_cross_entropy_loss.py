@@ -0,0 +1,98 @@
+#!/usr/bin/env python3
+
+# used to generate model: softmax_cross_entropy_loss.onnx
+
+# Computes the cross-entropy of [3, 4] scores with their class labels, both reduced to the mean
+# (the default) and for each sample.
+# The model is exported using onnx directly.
+
+import math
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "SoftmaxCrossEntropyLoss",
+                    ["scores", "labels"],
+                    ["output1"],
+                    name="/SoftmaxCrossEntropyLoss",
+                ),
+                onnx.helper.make_node(
+                    "SoftmaxCrossEntropyLoss",
+                    ["scores", "labels"],
+                    ["output2"],
+                    name="/SoftmaxCrossEntropyLoss_1",
+                    reduction="none",
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="scores",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[3, 4]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="labels",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT64, shape=[3]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="output2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[3]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def reference(scores, labels):
+    losses = [
+        math.log(sum(math.exp(score) for score in row)) - row[label]
+        for row, label in zip(scores, labels)
+    ]
+    return sum(losses) / len(losses), losses
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "softmax_cross_entropy_loss.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+    scores = [
+        [0.2, -1.3, 2.1, 0.4],
+        [1.5, 0.3, -0.7, -2.2],
+        [-0.4, 0.9, 0.1, 1.8],
+    ]
+    labels = [2, 0, 1]
+    mean, losses = reference(scores, labels)
+    print("Test input data: {}, {}".format(scores, labels))
+    print("Test output data: {}, {}".format(mean, losses))
+
+
+if __name__ == "__main__":
+    main()
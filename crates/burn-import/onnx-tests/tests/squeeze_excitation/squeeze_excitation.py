@@ -0,0 +1,130 @@
+#!/usr/bin/env python3
+
+# used to generate model: squeeze_excitation.onnx
+
+# Two squeeze-and-excitation modules as exported for torchvision.ops.SqueezeExcitation: a global
+# average pooling, a pointwise convolution reducing the channels, an activation, a pointwise
+# convolution expanding the channels back, a sigmoid and the multiplication of the input. The
+# first module uses a Relu, the second one a SiLU (Sigmoid -> Mul) and a first convolution without
+# bias. Both are fused into SqueezeExcitation nodes. The model is exported using onnx directly.
+
+import math
+
+import onnx
+import onnx.helper
+
+CHANNELS = 4
+SQUEEZE_CHANNELS = 2
+SIZE = 3
+
+W1 = [float((i * 5) % 7 - 3) / 4.0 for i in range(SQUEEZE_CHANNELS * CHANNELS)]
+B1 = [0.5, -0.25]
+W2 = [float((i * 3) % 5 - 2) / 2.0 for i in range(CHANNELS * SQUEEZE_CHANNELS)]
+B2 = [0.25, -0.5, 0.0, 1.0]
+
+
+def tensor(name, dims, values):
+    return onnx.helper.make_tensor(name, onnx.TensorProto.FLOAT, dims, values)
+
+
+def pointwise(inputs, output, name):
+    return onnx.helper.make_node("Conv", inputs, [output], name=name, kernel_shape=[1, 1])
+
+
+def squeeze_excitation(x, output, prefix, silu):
+    nodes = [
+        onnx.helper.make_node(
+            "GlobalAveragePool", [x], [f"{prefix}_pool"], name=f"/{prefix}/avgpool/GlobalAveragePool"
+        ),
+    ]
+    if silu:
+        nodes += [
+            pointwise([f"{prefix}_pool", "w1"], f"{prefix}_fc1", f"/{prefix}/fc1/Conv"),
+            onnx.helper.make_node(
+                "Sigmoid", [f"{prefix}_fc1"], [f"{prefix}_sig"], name=f"/{prefix}/activation/Sigmoid"
+            ),
+            onnx.helper.make_node(
+                "Mul",
+                [f"{prefix}_fc1", f"{prefix}_sig"],
+                [f"{prefix}_act"],
+                name=f"/{prefix}/activation/Mul",
+            ),
+        ]
+    else:
+        nodes += [
+            pointwise([f"{prefix}_pool", "w1", "b1"], f"{prefix}_fc1", f"/{prefix}/fc1/Conv"),
+            onnx.helper.make_node(
+                "Relu", [f"{prefix}_fc1"], [f"{prefix}_act"], name=f"/{prefix}/activation/Relu"
+            ),
+        ]
+    nodes += [
+        pointwise([f"{prefix}_act", "w2", "b2"], f"{prefix}_fc2", f"/{prefix}/fc2/Conv"),
+        onnx.helper.make_node(
+            "Sigmoid", [f"{prefix}_fc2"], [f"{prefix}_scale"], name=f"/{prefix}/scale_activation/Sigmoid"
+        ),
+        onnx.helper.make_node("Mul", [f"{prefix}_scale", x], [output], name=f"/{prefix}/Mul"),
+    ]
+    return nodes
+
+
+def reference(x, silu):
+    sigmoid = lambda v: 1.0 / (1.0 + math.exp(-v))
+    pooled = [sum(sum(row) for row in channel) / (SIZE * SIZE) for channel in x]
+    hidden = [
+        sum(W1[s * CHANNELS + c] * pooled[c] for c in range(CHANNELS)) + (0.0 if silu else B1[s])
+        for s in range(SQUEEZE_CHANNELS)
+    ]
+    hidden = [h * sigmoid(h) if silu else max(h, 0.0) for h in hidden]
+    scale = [
+        sigmoid(sum(W2[c * SQUEEZE_CHANNELS + s] * hidden[s] for s in range(SQUEEZE_CHANNELS)) + B2[c])
+        for c in range(CHANNELS)
+    ]
+    return [[[v * scale[c] for v in row] for row in channel] for c, channel in enumerate(x)]
+
+
+def main():
+    nodes = squeeze_excitation("x", "output_relu", "se_relu", silu=False)
+    nodes += squeeze_excitation("output_relu", "output_silu", "se_silu", silu=True)
+
+    shape = [1, CHANNELS, SIZE, SIZE]
+    graph = onnx.helper.make_graph(
+        nodes,
+        "main_graph",
+        [onnx.helper.make_tensor_value_info("x", onnx.TensorProto.FLOAT, shape)],
+        [
+            onnx.helper.make_tensor_value_info("output_relu", onnx.TensorProto.FLOAT, shape),
+            onnx.helper.make_tensor_value_info("output_silu", onnx.TensorProto.FLOAT, shape),
+        ],
+        initializer=[
+            tensor("w1", [SQUEEZE_CHANNELS, CHANNELS, 1, 1], W1),
+            tensor("b1", [SQUEEZE_CHANNELS], B1),
+            tensor("w2", [CHANNELS, SQUEEZE_CHANNELS, 1, 1], W2),
+            tensor("b2", [CHANNELS], B2),
+        ],
+    )
+
+    model = onnx.helper.make_model(
+        graph, opset_imports=[onnx.helper.make_operatorsetid("", 16)]
+    )
+    onnx.checker.check_model(model)
+
+    onnx_name = "squeeze_excitation.onnx"
+    onnx.save(model, onnx_name)
+    print(f"Finished exporting model to {onnx_name}")
+
+    # Output some test data for use in the test
+    x = [
+        [[float((c * SIZE + i) * SIZE + j) / 10.0 for j in range(SIZE)] for i in range(SIZE)]
+        for c in range(CHANNELS)
+    ]
+    output_relu = reference(x, silu=False)
+    output_silu = reference(output_relu, silu=True)
+    print(f"Test input data: {x}")
+    print(f"Test output relu: {[[round(v, 5) for v in row] for row in output_relu[0]]} ...")
+    print(f"Test output silu: {[[round(v, 5) for v in row] for row in output_silu[0]]} ...")
+    print(f"Channel scales relu: {[round(output_relu[c][0][1] / x[c][0][1], 5) for c in range(CHANNELS)]}")
+    print(f"Channel scales silu: {[round(output_silu[c][0][1] / output_relu[c][0][1], 5) for c in range(CHANNELS)]}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,86 @@
+#!/usr/bin/env python3
+
+# used to generate model: stack.onnx
+
+# Stacks three [2, 3] inputs along a new last dimension, as PyTorch exports `torch.stack`: each
+# input is unsqueezed with its own constant axes, then the results are concatenated along the new
+# axis. The nodes are imported as a single stack.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def axes_constant(index):
+    return onnx.helper.make_node(
+        "Constant",
+        [],
+        [f"axes{index}"],
+        name=f"/Constant_{index}",
+        value=onnx.helper.make_tensor(f"axes{index}", onnx.TensorProto.INT64, [1], [-1]),
+    )
+
+
+def unsqueeze(index):
+    return onnx.helper.make_node(
+        "Unsqueeze",
+        [f"input{index}", f"axes{index}"],
+        [f"unsqueezed{index}"],
+        name=f"/Unsqueeze_{index}",
+    )
+
+
+def build_model():
+    nodes = []
+    for index in range(1, 4):
+        nodes += [axes_constant(index), unsqueeze(index)]
+    nodes.append(
+        onnx.helper.make_node(
+            "Concat",
+            ["unsqueezed1", "unsqueezed2", "unsqueezed3"],
+            ["output1"],
+            name="/Concat",
+            axis=-1,
+        )
+    )
+
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=nodes,
+            inputs=[
+                onnx.helper.make_value_info(
+                    name=f"input{index}",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 3]
+                    ),
+                )
+                for index in range(1, 4)
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 3, 3]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "stack.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
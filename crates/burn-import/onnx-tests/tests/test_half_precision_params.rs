@@ -0,0 +1,107 @@
+// This test suite verifies that a model generated with half precision parameters stores its
+// parameters as f16 while computing in f32, producing the same outputs as the full precision model
+// (linear.onnx).
+// The candle backend is used since it supports f16 tensors on the CPU, so the test only runs with
+// the `candle` feature.
+#![cfg(feature = "candle")]
+
+pub mod linear {
+    include!(concat!(env!("OUT_DIR"), "/model/linear.rs"));
+}
+
+pub mod linear_half_precision_params {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/model/half_precision_params/linear.rs"
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::module::{FloatCaster, Module, ModuleVisitor, ParamId};
+    use burn::tensor::{
+        DType, Distribution, FloatDType, Tensor, Tolerance, backend::Backend as BackendTrait,
+    };
+
+    type Backend = burn::backend::Candle<f32, i64>;
+
+    /// Collects the data types of the float parameters of a module.
+    struct DTypes(Vec<DType>);
+
+    impl<B: BackendTrait> ModuleVisitor<B> for DTypes {
+        fn visit_float<const D: usize>(&mut self, _id: ParamId, tensor: &Tensor<B, D>) {
+            self.0.push(tensor.dtype());
+        }
+    }
+
+    #[test]
+    fn half_precision_params() {
+        let device = Default::default();
+        let model: linear::Model<Backend> = linear::Model::default();
+        let model_half: linear_half_precision_params::Model<Backend> =
+            linear_half_precision_params::Model::default();
+
+        let mut dtypes = DTypes(Vec::new());
+        model_half.visit(&mut dtypes);
+        assert!(!dtypes.0.is_empty());
+        assert!(dtypes.0.iter().all(|dtype| *dtype == DType::F16));
+
+        let input1 = Tensor::<Backend, 2>::random([4, 3], Distribution::Default, &device);
+        let input2 = Tensor::<Backend, 2>::random([2, 5], Distribution::Default, &device);
+        let input3 = Tensor::<Backend, 3>::random([3, 2, 7], Distribution::Default, &device);
+
+        let (output1, output2, output3) =
+            model.forward(input1.clone(), input2.clone(), input3.clone());
+        let (output1_half, output2_half, output3_half) = model_half.forward(input1, input2, input3);
+
+        // The outputs are returned in full precision
+        assert_eq!(output1_half.dtype(), DType::F32);
+
+        let tolerance = Tolerance::absolute(1e-2);
+        output1_half
+            .into_data()
+            .assert_approx_eq::<f32>(&output1.into_data(), tolerance);
+        output2_half
+            .into_data()
+            .assert_approx_eq::<f32>(&output2.into_data(), tolerance);
+        output3_half
+            .into_data()
+            .assert_approx_eq::<f32>(&output3.into_data(), tolerance);
+    }
+
+    #[test]
+    fn half_precision_params_compute_in_full_precision() {
+        let device = Default::default();
+        // Full precision model using the same f16 rounded parameters
+        let model: linear::Model<Backend> = linear::Model::<Backend>::default()
+            .map(&mut FloatCaster {
+                dtype: FloatDType::F16,
+            })
+            .map(&mut FloatCaster {
+                dtype: FloatDType::F32,
+            });
+        let model_half: linear_half_precision_params::Model<Backend> =
+            linear_half_precision_params::Model::default();
+
+        // The inputs are larger than the largest f16 value (65504), so the outputs only match when
+        // the forward pass is computed in f32.
+        let input1 = Tensor::<Backend, 2>::full([4, 3], 1e5, &device);
+        let input2 = Tensor::<Backend, 2>::full([2, 5], -2e5, &device);
+        let input3 = Tensor::<Backend, 3>::full([3, 2, 7], 3e5, &device);
+
+        let (output1, output2, output3) =
+            model.forward(input1.clone(), input2.clone(), input3.clone());
+        let (output1_half, output2_half, output3_half) = model_half.forward(input1, input2, input3);
+
+        let tolerance = Tolerance::relative(1e-5);
+        for (output_half, output) in [
+            (output1_half.into_data(), output1.into_data()),
+            (output2_half.into_data(), output2.into_data()),
+            (output3_half.into_data(), output3.into_data()),
+        ] {
+            assert!(output_half.iter::<f32>().all(f32::is_finite));
+            output_half.assert_approx_eq::<f32>(&output, tolerance);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// This test suite verifies that a model generated with intermediates returns the selected internal
+// tensors along with its outputs (reshape.onnx, where `reshape1_out1` is the first reshape).
+
+pub mod reshape_intermediates {
+    include!(concat!(env!("OUT_DIR"), "/model/intermediates/reshape.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::tensor::{Tensor, TensorData};
+
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    #[test]
+    fn forward_with_intermediates() {
+        let device = Default::default();
+        let model: reshape_intermediates::Model<Backend> =
+            reshape_intermediates::Model::new(&device);
+
+        let input = Tensor::<Backend, 1>::from_floats([0., 1., 2., 3.], &device);
+        let (output, intermediate) = model.forward_with_intermediates(input.clone());
+
+        // The intermediate is the [2, 2] input of the last reshape
+        assert_eq!(intermediate.dims(), [2, 2]);
+        intermediate
+            .to_data()
+            .assert_eq(&TensorData::from([[0f32, 1.], [2., 3.]]), true);
+
+        // The outputs are the same as the ones of the regular forward pass
+        assert_eq!(output.dims(), [1, 4]);
+        output
+            .to_data()
+            .assert_eq(&model.forward(input).to_data(), true);
+    }
+}
@@ -19,13 +19,20 @@ macro_rules! include_models {
 include_models!(
     add,
     add_int,
+    and,
     argmax,
+    attention,
+    attention_present,
     avg_pool1d,
     avg_pool2d,
     batch_norm,
     cast,
+    cast_saturate,
+    cast_shape_to_tensor,
     clip,
     concat,
+    concat_broadcast,
+    concat_shapes,
     constant_f32,
     constant_f64,
     constant_i32,
@@ -35,11 +42,15 @@ include_models!(
     conv1d,
     conv2d,
     conv3d,
+    conv_integer,
+    conv_batch_norm,
     conv_transpose1d,
     conv_transpose2d,
     conv_transpose3d,
+    convnext_block,
     cos,
     cosh,
+    depthwise_separable_conv2d,
     div,
     dropout,
     equal,
@@ -57,6 +68,7 @@ include_models!(
     gather_scalar,
     gather_scalar_out,
     gather_shape,
+    gather_shape_scalar,
     gelu,
     gemm,
     gemm_no_c,
@@ -68,6 +80,10 @@ include_models!(
     greater_or_equal_scalar,
     greater_scalar,
     hard_sigmoid,
+    hardmax,
+    hinge_embedding_loss,
+    identity,
+    if_else,
     layer_norm,
     leaky_relu,
     less,
@@ -77,21 +93,27 @@ include_models!(
     linear,
     log,
     log_softmax,
+    log_softmax_axis_neg1,
     mask_where,
     mask_where_all_scalar,
     mask_where_broadcast,
     mask_where_scalar_x,
     mask_where_scalar_y,
     matmul,
+    matmul_add,
+    matmul_vector,
     max,
     maxpool1d,
     maxpool2d,
+    maxpool2d_adaptive,
     mean,
     min,
     mul,
     neg,
     not,
     one_hot,
+    optional,
+    or,
     pad,
     pow,
     pow_int,
@@ -103,30 +125,46 @@ include_models!(
     range,
     recip,
     reduce_max,
+    reduce_max_multi_axis,
     reduce_mean,
     reduce_min,
     reduce_prod,
     reduce_sum,
+    relative_position_bias,
     relu,
+    repeat_interleave,
     reshape,
+    reshape_allowzero,
+    reshape_infer_neg1,
+    reshape_runtime,
     resize_1d_linear_scale,
     resize_1d_nearest_scale,
     resize_2d_bicubic_scale,
     resize_2d_bilinear_scale,
     resize_2d_nearest_scale,
+    resize_runtime_scales,
+    resize_runtime_sizes,
     resize_with_sizes,
+    scatter_elements,
     shape,
+    shape_sliced,
+    shape_arithmetic,
     sigmoid,
     sign,
     sin,
+    sinusoidal_pos_encoding,
     sinh,
     slice,
     slice_shape,
     softmax,
+    softmax_axis2_3d,
+    softmax_cross_entropy_loss,
     split,
     sqrt,
     squeeze,
+    squeeze_excitation,
     squeeze_multiple,
+    stack,
     sub,
     sub_int,
     sum,
@@ -136,10 +174,16 @@ include_models!(
     tile,
     topk,
     transpose,
+    transpose_perm,
     trilu_lower,
     trilu_upper,
+    trt_plugin_layer_norm,
+    trt_plugin_unknown,
     unsqueeze_like,
-    unsqueeze_runtime_axes
+    unsqueeze_runtime_axes,
+    unsqueeze_scalar_gather,
+    unsqueeze_scalar_to_shape,
+    xor
 );
 
 #[cfg(test)]
@@ -148,7 +192,8 @@ mod tests {
 
     use super::*;
 
-    use burn::tensor::{Bool, Int, Shape, Tensor, TensorData, Tolerance, ops::FloatElem};
+    use burn::module::TensorSpec;
+    use burn::tensor::{Bool, DType, Int, Shape, Tensor, TensorData, Tolerance, ops::FloatElem};
 
     use float_cmp::ApproxEq;
 
@@ -346,6 +391,112 @@ mod tests {
         output_mv.to_data().assert_eq(&expected_mv, true);
     }
 
+    #[test]
+    fn matmul_add() {
+        let model: matmul_add::Model<Backend> = matmul_add::Model::default();
+
+        let device = Default::default();
+        let x = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .reshape([1, 3, 4])
+            .float()
+            .div_scalar(10.0)
+            .repeat_dim(0, 2);
+        let x_2d = x.clone().slice([0..1]).squeeze(0);
+        let w = Tensor::<Backend, 2>::from_floats(
+            [
+                [0.0, -0.25, -0.5, -0.75, -1.0],
+                [0.25, 0.0, -0.25, -0.5, -0.75],
+                [0.5, 0.25, 0.0, -0.25, -0.5],
+                [0.75, 0.5, 0.25, 0.0, -0.25],
+            ],
+            &device,
+        );
+        let b = Tensor::<Backend, 1>::from_floats([0.5, -1.0, 2.0, 0.0, 1.5], &device);
+        let b_3d = b.clone().reshape([1, 1, 5]);
+
+        let (output_fused, output_unfused, _product, output_gemm) =
+            model.forward(x, x_2d, w, b, b_3d);
+        let expected = TensorData::from([
+            [0.85f32, -0.8, 2.05, -0.1, 1.25],
+            [1.45, -0.6, 1.85, -0.7, 0.25],
+            [2.05, -0.4, 1.65, -1.3, -0.75],
+        ]);
+
+        output_fused
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&output_unfused.into_data(), Tolerance::default());
+        output_fused
+            .slice([1..2])
+            .squeeze::<2>(0)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        output_gemm
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+
+        // The fused MatMul and Gemm nodes add the bias in the statement computing the product,
+        // while the product returned by the model is added to its bias in a separate statement
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/matmul_add.rs"));
+        let statements = code.split(';').collect::<Vec<_>>();
+        let products = statements
+            .iter()
+            .filter(|statement| statement.contains(".matmul("))
+            .collect::<Vec<_>>();
+        assert_eq!(products.len(), 3);
+        assert_eq!(
+            products
+                .iter()
+                .filter(|statement| statement.contains(".add(") || statement.contains(" + "))
+                .count(),
+            2
+        );
+        assert_eq!(code.matches(".add(").count(), 2);
+    }
+
+    #[test]
+    fn matmul_vector() {
+        let model: matmul_vector::Model<Backend> = matmul_vector::Model::default();
+
+        let device = Default::default();
+        let x = Tensor::<Backend, 1, Int>::arange(0..120, &device)
+            .reshape([2, 3, 4, 5])
+            .float();
+        let u = Tensor::<Backend, 1, Int>::arange(0..4, &device).float();
+        let v = Tensor::<Backend, 1, Int>::arange(0..5, &device).float();
+
+        let (output_vm, output_mv) = model.forward(x, u, v);
+        // vector-matrix `[4] @ [2, 3, 4, 5]` where the vector is expanded to a row
+        let expected_vm = TensorData::from([
+            [
+                [70f32, 76., 82., 88., 94.],
+                [190., 196., 202., 208., 214.],
+                [310., 316., 322., 328., 334.],
+            ],
+            [
+                [430., 436., 442., 448., 454.],
+                [550., 556., 562., 568., 574.],
+                [670., 676., 682., 688., 694.],
+            ],
+        ]);
+        // matrix-vector `[2, 3, 4, 5] @ [5]` where the vector is expanded to a column
+        let expected_mv = TensorData::from([
+            [
+                [30f32, 80., 130., 180.],
+                [230., 280., 330., 380.],
+                [430., 480., 530., 580.],
+            ],
+            [
+                [630., 680., 730., 780.],
+                [830., 880., 930., 980.],
+                [1030., 1080., 1130., 1180.],
+            ],
+        ]);
+
+        output_vm.to_data().assert_eq(&expected_vm, true);
+        output_mv.to_data().assert_eq(&expected_mv, true);
+    }
+
     #[test]
     fn concat_tensors() {
         // Initialize the model
@@ -362,6 +513,51 @@ mod tests {
         assert_eq!(output.shape(), expected);
     }
 
+    #[test]
+    fn concat_broadcast() {
+        // Initialize the model
+        let device = Default::default();
+        let model: concat_broadcast::Model<Backend> = concat_broadcast::Model::new(&device);
+
+        // Run the model, the leading size-1 dimension of input1 is broadcast to 2
+        let input1 = Tensor::<Backend, 3>::from_floats([[[1., 2., 3.]]], &device);
+        let input2 = Tensor::<Backend, 3>::zeros([2, 4, 3], &device);
+        let output = model.forward(input1, input2);
+
+        assert_eq!(output.shape(), Shape::from([2, 5, 3]));
+        let expected = Tensor::<Backend, 3>::from_floats([[[1., 2., 3.]]], &device)
+            .expand([2, 1, 3])
+            .into_data();
+        output
+            .clone()
+            .slice([0..2, 0..1, 0..3])
+            .into_data()
+            .assert_eq(&expected, true);
+        output
+            .slice([0..2, 1..5, 0..3])
+            .into_data()
+            .assert_eq(&TensorData::zeros::<f32, _>([2, 4, 3]), true);
+    }
+
+    #[test]
+    fn concat_shapes() {
+        let device = Default::default();
+        let model: concat_shapes::Model<Backend> = concat_shapes::Model::new(&device);
+
+        // The third input is reshaped to the [2] and [3, 4] shape pieces concatenated
+        let input1 = Tensor::<Backend, 2>::ones([2, 5], &device);
+        let input2 = Tensor::<Backend, 2>::ones([3, 4], &device);
+        let input3 = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .float()
+            .reshape([6, 4]);
+        let output = model.forward(input1, input2, input3.clone());
+
+        assert_eq!(output.dims(), [2, 3, 4]);
+        output
+            .to_data()
+            .assert_eq(&input3.reshape([2, 3, 4]).to_data(), true);
+    }
+
     #[test]
     fn conv1d() {
         // Initialize the model with weights (loaded from the exported file)
@@ -383,6 +579,82 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn conv_batch_norm() {
+        let model: conv_batch_norm::Model<Backend> = conv_batch_norm::Model::default();
+
+        let device = Default::default();
+        let x = Tensor::<Backend, 1, Int>::arange(0..64, &device)
+            .reshape([2, 2, 4, 4])
+            .float()
+            .div_scalar(10.0);
+        let x_1d = Tensor::<Backend, 1, Int>::arange(0..10, &device)
+            .reshape([1, 2, 5])
+            .float()
+            .div_scalar(10.0)
+            .repeat_dim(0, 2);
+
+        let (output_fused, output_unfused, _conv, output_1d) = model.forward(x, x_1d);
+        let expected_1d = TensorData::from([
+            [-0.14375f32, -0.1625, -0.18125],
+            [-0.00002, 0.04998, 0.09998],
+            [2.19999, 2.29999, 2.39999],
+        ]);
+
+        output_fused
+            .into_data()
+            .assert_approx_eq::<FT>(&output_unfused.into_data(), Tolerance::default());
+        output_1d
+            .slice([1..2])
+            .squeeze::<2>(0)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_1d, Tolerance::absolute(1e-5));
+
+        // Only the batch norm of the convolution returned by the model isn't fused
+        let code = include_str!(concat!(env!("OUT_DIR"), "/model/conv_batch_norm.rs"));
+        assert_eq!(code.matches("BatchNormConfig::new").count(), 1);
+    }
+
+    #[test]
+    fn depthwise_separable_conv2d() {
+        let model: depthwise_separable_conv2d::Model<Backend> =
+            depthwise_separable_conv2d::Model::default();
+
+        let device = Default::default();
+        let x = Tensor::<Backend, 1, Int>::arange(0..32, &device)
+            .reshape([1, 2, 4, 4])
+            .float()
+            .div_scalar(10.0);
+
+        let (output_fused, output_unfused, _depthwise, output_no_bias) = model.forward(x);
+        let expected_no_bias = TensorData::from([
+            [0.23125f32, 0.23125, 0.25, -0.03125],
+            [0.19375, 0.23125, 0.25, -0.10625],
+            [0.04375, 0.30625, 0.325, -0.10625],
+            [0.175, 0.68125, 0.71875, 0.60625],
+        ]);
+
+        output_fused
+            .into_data()
+            .assert_approx_eq::<FT>(&output_unfused.into_data(), Tolerance::default());
+        output_no_bias
+            .slice([0..1, 0..1])
+            .squeeze::<3>(0)
+            .squeeze::<2>(0)
+            .into_data()
+            .assert_approx_eq::<FT>(&expected_no_bias, Tolerance::absolute(1e-5));
+
+        // Only the convolutions whose depthwise output isn't returned by the model are fused
+        let code = include_str!(concat!(
+            env!("OUT_DIR"),
+            "/model/depthwise_separable_conv2d.rs"
+        ));
+        assert_eq!(
+            code.matches("DepthwiseSeparableConv2dConfig::new").count(),
+            2
+        );
+    }
+
     #[test]
     fn conv2d() {
         // Initialize the model with weights (loaded from the exported file)
@@ -405,6 +677,36 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn conv_integer() {
+        let device = Default::default();
+        let model: conv_integer::Model<Backend> = conv_integer::Model::default();
+
+        // Deterministic uint8 activations, see conv_integer.py
+        let input = Tensor::<Backend, 1, Int>::arange(0..4608, &device)
+            .mul_scalar(37)
+            .add_scalar(11)
+            .remainder_scalar(251)
+            .reshape([1, 512, 3, 3]);
+        let output = model.forward(input);
+
+        // The int32 accumulator of the ONNX ConvInteger definition, computed in conv_integer.py.
+        // The values of the first channel are odd and above 2^24, so they aren't exact in f32.
+        let expected = TensorData::from([[
+            [
+                [65243535i64, 97924335, 65364915],
+                [97850385, 146848125, 97904445],
+                [65287650, 97894500, 65217015],
+            ],
+            [
+                [-213179, -353682, -379357],
+                [-453640, -504973, -676344],
+                [-262171, -360411, -360718],
+            ],
+        ]]);
+        output.to_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn conv3d() {
         // Initialize the model with weights (loaded from the exported file)
@@ -427,6 +729,38 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn convnext_block() {
+        let device = Default::default();
+        let model: convnext_block::Model<Backend> = convnext_block::Model::default();
+
+        // The whole block is imported as a single module
+        assert!(alloc::format!("{model}").contains("ConvNeXtBlock"));
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..18, &device)
+            .float()
+            .div_scalar(9.0)
+            .reshape([1, 2, 3, 3]);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[
+            [
+                [0.03739f32, 0.14851, 0.28753],
+                [0.39864, 0.48184, 0.59296],
+                [0.70407, 0.81517, 0.92628],
+            ],
+            [
+                [0.69935, 0.81046, 1.23351],
+                [1.34462, 1.14380, 1.25491],
+                [1.36602, 1.47713, 1.58824],
+            ],
+        ]]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::rel_abs(1e-4, 1e-4));
+    }
+
     #[test]
     fn dropout() {
         let model: dropout::Model<Backend> = dropout::Model::default();
@@ -504,6 +838,33 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn gather_shape_scalar() {
+        let model: gather_shape_scalar::Model<Backend> = gather_shape_scalar::Model::default();
+
+        let device = Default::default();
+
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        // shape(input) = [2, 3], the last dim is selected with a negative index
+        let output: i64 = model.forward(input.clone(), 1);
+        assert_eq!(output, 3);
+
+        let output: i64 = model.forward(input, -2);
+        assert_eq!(output, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_shape_scalar_out_of_range() {
+        let model: gather_shape_scalar::Model<Backend> = gather_shape_scalar::Model::default();
+
+        let device = Default::default();
+
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        // The index is past the rank of the shape
+        let _output: i64 = model.forward(input, 2);
+    }
+
     #[test]
     fn gather_scalar() {
         let model: gather_scalar::Model<Backend> = gather_scalar::Model::default();
@@ -546,6 +907,55 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn scatter_elements() {
+        let device = Default::default();
+        let model: scatter_elements::Model<Backend> = scatter_elements::Model::new(&device);
+
+        let data = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let indices_unique = Tensor::<Backend, 2, Int>::from_ints([[2, 0], [1, 2]], &device);
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[0, 0], [1, 1]], &device);
+        let updates = Tensor::<Backend, 2>::from_floats([[10., -1.], [2., 3.]], &device);
+        let (output_none, output_add, output_mul, output_max, output_min) =
+            model.forward(data, indices_unique, indices, updates);
+
+        output_none
+            .to_data()
+            .assert_eq(&TensorData::from([[-1f32, 2., 10.], [4., 2., 3.]]), true);
+        output_add
+            .to_data()
+            .assert_eq(&TensorData::from([[10f32, 2., 3.], [4., 10., 6.]]), true);
+        output_mul
+            .to_data()
+            .assert_eq(&TensorData::from([[-10f32, 2., 3.], [4., 30., 6.]]), true);
+        output_max
+            .to_data()
+            .assert_eq(&TensorData::from([[10f32, 2., 3.], [4., 5., 6.]]), true);
+        output_min
+            .to_data()
+            .assert_eq(&TensorData::from([[-1f32, 2., 3.], [4., 2., 6.]]), true);
+    }
+
+    #[test]
+    fn scatter_elements_infinite_values() {
+        let device = Default::default();
+        let model: scatter_elements::Model<Backend> = scatter_elements::Model::new(&device);
+
+        let data = Tensor::<Backend, 2>::from_floats(
+            [[f32::INFINITY, 2., 3.], [4., f32::NEG_INFINITY, 6.]],
+            &device,
+        );
+        let indices_unique = Tensor::<Backend, 2, Int>::from_ints([[2, 0], [1, 2]], &device);
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[0, 0], [1, 1]], &device);
+        let updates = Tensor::<Backend, 2>::from_floats([[10., -1.], [2., 3.]], &device);
+        let (output_none, ..) = model.forward(data, indices_unique, indices, updates);
+
+        // The replaced infinite values don't leak into the output
+        output_none
+            .to_data()
+            .assert_eq(&TensorData::from([[-1f32, 2., 10.], [4., 2., 3.]]), true);
+    }
+
     #[test]
     fn graph_multiple_output_tracking() {
         // Initialize the model with weights (loaded from the exported file)
@@ -569,6 +979,195 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn attention() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: attention::Model<Backend> = attention::Model::default();
+
+        let device = Default::default();
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [
+                    [-0.5, -0.4, -0.3, -0.2, -0.1, 0.0, 0.1, 0.2],
+                    [0.3, 0.4, 0.5, -0.5, -0.4, -0.3, -0.2, -0.1],
+                    [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, -0.5, -0.4],
+                ],
+                [
+                    [-0.3, -0.2, -0.1, 0.0, 0.1, 0.2, 0.3, 0.4],
+                    [0.5, -0.5, -0.4, -0.3, -0.2, -0.1, 0.0, 0.1],
+                    [0.2, 0.3, 0.4, 0.5, -0.5, -0.4, -0.3, -0.2],
+                ],
+            ],
+            &device,
+        );
+        // The last position of the second sequence is padding
+        let mask_index = Tensor::<Backend, 2, Int>::from_ints([[1, 1, 1], [1, 1, 0]], &device);
+        let output = model.forward(input, mask_index);
+
+        assert_eq!(output.dims(), [2, 3, 8]);
+        let expected = TensorData::from([
+            [
+                [
+                    -0.11621f32,
+                    0.04367,
+                    0.03758,
+                    0.15373,
+                    -0.1248,
+                    0.01315,
+                    -0.05889,
+                    -0.01684,
+                ],
+                [
+                    -0.11932, 0.04573, 0.04366, 0.15286, -0.12504, 0.01319, -0.05888, -0.01706,
+                ],
+                [
+                    -0.11677, 0.04228, 0.04293, 0.15282, -0.1259, 0.01286, -0.05768, -0.01657,
+                ],
+            ],
+            [
+                [
+                    -0.06016, -0.14, 0.13016, 0.26032, 0.00127, -0.22127, -0.02085, 0.03958,
+                ],
+                [
+                    -0.06067, -0.14, 0.13067, 0.26134, 0.00223, -0.22223, -0.02149, 0.03926,
+                ],
+                [
+                    -0.06066, -0.14, 0.13066, 0.26131, -0.00156, -0.21844, -0.01896, 0.04052,
+                ],
+            ],
+        ]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::absolute(1e-4));
+    }
+
+    #[test]
+    fn attention_present() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: attention_present::Model<Backend> = attention_present::Model::default();
+
+        let device = Default::default();
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [
+                    [-0.5, -0.4, -0.3, -0.2, -0.1, 0.0, 0.1, 0.2],
+                    [0.3, 0.4, 0.5, -0.5, -0.4, -0.3, -0.2, -0.1],
+                    [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, -0.5, -0.4],
+                ],
+                [
+                    [-0.3, -0.2, -0.1, 0.0, 0.1, 0.2, 0.3, 0.4],
+                    [0.5, -0.5, -0.4, -0.3, -0.2, -0.1, 0.0, 0.1],
+                    [0.2, 0.3, 0.4, 0.5, -0.5, -0.4, -0.3, -0.2],
+                ],
+            ],
+            &device,
+        );
+        // The second sequence has a key length of 2, so its last position is padding
+        let key_lengths = Tensor::<Backend, 1, Int>::from_ints([3, 2], &device);
+        let (output, present) = model.forward(input, key_lengths);
+
+        assert_eq!(output.dims(), [2, 3, 8]);
+        let expected = TensorData::from([
+            [
+                [
+                    -0.11621f32,
+                    0.04367,
+                    0.03758,
+                    0.15373,
+                    -0.1248,
+                    0.01315,
+                    -0.05889,
+                    -0.01684,
+                ],
+                [
+                    -0.11932, 0.04573, 0.04366, 0.15286, -0.12504, 0.01319, -0.05888, -0.01706,
+                ],
+                [
+                    -0.11677, 0.04228, 0.04293, 0.15282, -0.1259, 0.01286, -0.05768, -0.01657,
+                ],
+            ],
+            [
+                [
+                    -0.06016, -0.14, 0.13016, 0.26032, 0.00127, -0.22127, -0.02085, 0.03958,
+                ],
+                [
+                    -0.06067, -0.14, 0.13067, 0.26134, 0.00223, -0.22223, -0.02149, 0.03926,
+                ],
+                [
+                    -0.06066, -0.14, 0.13066, 0.26131, -0.00156, -0.21844, -0.01896, 0.04052,
+                ],
+            ],
+        ]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::absolute(1e-4));
+
+        // The keys and values of all the positions, padding included
+        assert_eq!(present.dims(), [2, 2, 2, 3, 4]);
+        let expected = TensorData::from([
+            [
+                [
+                    [
+                        [0.15f32, 0.15, -0.24, -0.03],
+                        [-0.23, 0.07, -0.02, -0.28],
+                        [0.05, -0.12, 0.09, 0.13],
+                    ],
+                    [
+                        [0.04, -0.03, 0.11, 0.0],
+                        [0.09, 0.32, 0.2, -0.38],
+                        [0.03, -0.21, 0.18, -0.1],
+                    ],
+                ],
+                [
+                    [
+                        [0.11, 0.13, -0.24, -0.01],
+                        [-0.05, 0.05, -0.24, 0.07],
+                        [-0.21, 0.19, 0.2, 0.04],
+                    ],
+                    [
+                        [0.08, 0.03, 0.05, -0.04],
+                        [0.24, 0.27, -0.19, -0.2],
+                        [-0.26, 0.07, 0.12, -0.36],
+                    ],
+                ],
+            ],
+            [
+                [
+                    [
+                        [0.0, -0.14, 0.07, 0.14],
+                        [-0.08, 0.08, -0.18, 0.19],
+                        [-0.27, 0.19, 0.23, 0.13],
+                    ],
+                    [
+                        [-0.18, -0.04, 0.1, 0.1],
+                        [0.17, 0.05, -0.28, 0.02],
+                        [-0.36, 0.03, 0.0, -0.17],
+                    ],
+                ],
+                [
+                    [
+                        [-0.02, -0.14, 0.09, 0.18],
+                        [-0.1, -0.14, 0.17, 0.34],
+                        [0.04, 0.3, 0.14, -0.16],
+                    ],
+                    [
+                        [-0.12, -0.1, 0.06, 0.08],
+                        [0.12, -0.34, -0.1, 0.0],
+                        [-0.08, -0.03, -0.26, 0.14],
+                    ],
+                ],
+            ],
+        ]);
+
+        present
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::absolute(1e-4));
+    }
+
     #[test]
     fn globalavrpool_1d_2d() {
         // The model contains 1d and 2d global average pooling nodes
@@ -657,6 +1256,70 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn softmax_cross_entropy_loss() {
+        let device = Default::default();
+        let model: softmax_cross_entropy_loss::Model<Backend> =
+            softmax_cross_entropy_loss::Model::new(&device);
+
+        let scores = Tensor::<Backend, 2>::from_floats(
+            [
+                [0.2, -1.3, 2.1, 0.4],
+                [1.5, 0.3, -0.7, -2.2],
+                [-0.4, 0.9, 0.1, 1.8],
+            ],
+            &device,
+        );
+        let labels = Tensor::<Backend, 1, Int>::from_ints([2, 0, 1], &device);
+        let (output1, output2) = model.forward(scores, labels);
+
+        // Mean and per sample losses
+        output1
+            .to_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.701_545_7f32]), Tolerance::default());
+        output2.to_data().assert_approx_eq::<FT>(
+            &TensorData::from([0.311_612_5f32, 0.362_363_36, 1.430_661_4]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn softmax_axis2_3d() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: softmax_axis2_3d::Model<Backend> = softmax_axis2_3d::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]],
+                [[-1.0, 0.0, 1.0], [3.0, 1.0, 2.0]],
+            ],
+            &device,
+        );
+        let output = model.forward(input);
+
+        // Each row along the last axis is normalized independently
+        let expected = TensorData::from([
+            [
+                [0.09003057f32, 0.24472847, 0.66524096],
+                [0.33333334, 0.33333334, 0.33333334],
+            ],
+            [
+                [0.09003057, 0.24472847, 0.66524096],
+                [0.66524096, 0.09003057, 0.24472847],
+            ],
+        ]);
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        output
+            .sum_dim(2)
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::ones::<f32, _>([2, 2, 1]), Tolerance::default());
+    }
+
     #[test]
     fn log_softmax() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -680,6 +1343,39 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn log_softmax_axis_neg1() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: log_softmax_axis_neg1::Model<Backend> =
+            log_softmax_axis_neg1::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0.5, -1.5, 2.0], [4.0, 4.0, -2.0]],
+                [[-3.0, 1.0, 0.25], [2.0, 0.0, -3.0]],
+            ],
+            &device,
+        );
+        let output = model.forward(input);
+
+        // Same as `torch.nn.functional.log_softmax(input, dim=-1)`
+        let expected = TensorData::from([
+            [
+                [-1.725_802f32, -3.725_802, -0.225_802],
+                [-0.694_385_8, -0.694_385_8, -6.694_386],
+            ],
+            [
+                [-4.399_234, -0.399_233_9, -1.149_234],
+                [-0.132_845_2, -2.132_845_2, -5.132_845],
+            ],
+        ]);
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
     #[test]
     fn sqrt() {
         let device = Default::default();
@@ -777,6 +1473,41 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn maxpool2d_adaptive() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: maxpool2d_adaptive::Model<Backend> = maxpool2d_adaptive::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[[
+                [1., 9., 3., 4., -2., 0.],
+                [5., 2., 8., 7., 6., -1.],
+                [0., 3., 2., 11., 4., 5.],
+                [4., 10., -3., 1., 2., 12.],
+            ]]],
+            &device,
+        );
+        let (output_tiled, output_global) = model.forward(input);
+        let expected_tiled = TensorData::from([[[[9f32, 8., 6.], [10., 11., 12.]]]]);
+        let expected_global = TensorData::from([[[[12f32]]]]);
+
+        output_tiled.to_data().assert_eq(&expected_tiled, true);
+        output_global.to_data().assert_eq(&expected_global, true);
+
+        // The global pooling isn't tied to the exported input resolution
+        let input = Tensor::<Backend, 4>::from_floats([[[[1., 7.], [-3., 2.]]]], &device);
+        let (output_tiled, output_global) = model.forward(input);
+
+        output_tiled
+            .to_data()
+            .assert_eq(&TensorData::from([[[[7f32]]]]), true);
+        output_global
+            .to_data()
+            .assert_eq(&TensorData::from([[[[7f32]]]]), true);
+    }
+
     #[test]
     fn avg_pool1d() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -897,6 +1628,31 @@ mod tests {
         assert_eq!(output_value.to_data(), expected);
     }
 
+    #[test]
+    fn reduce_max_multi_axis() {
+        let device = Default::default();
+        let model: reduce_max_multi_axis::Model<Backend> =
+            reduce_max_multi_axis::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .mul_scalar(7)
+            .remainder_scalar(24)
+            .float()
+            .reshape([2, 3, 4]);
+        let (output_keepdims, output, output_min) = model.forward(input);
+
+        output_keepdims
+            .to_data()
+            .assert_eq(&TensorData::from([[[22f32]], [[23.0]]]), true);
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([22f32, 23.0]), true);
+        output_min
+            .to_data()
+            .assert_eq(&TensorData::from([0f32, 1.0, 3.0]), true);
+    }
+
     #[test]
     fn reduce_min() {
         let device = Default::default();
@@ -968,6 +1724,45 @@ mod tests {
         output_value.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn repeat_interleave() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: repeat_interleave::Model<Backend> = repeat_interleave::Model::new(&device);
+
+        // Run the model
+        let input1 = Tensor::<Backend, 2>::from_floats([[0., 1., 2.], [3., 4., 5.]], &device);
+        let input2 = Tensor::<Backend, 1, Int>::from_ints([0, 3], &device);
+        let (output1, output2) = model.forward(input1, input2);
+        let expected1 =
+            TensorData::from([[0f32, 2., 2.], [0., 2., 2.], [3., 5., 5.], [3., 5., 5.]]);
+        let expected2 = TensorData::from([[3f32, 4., 5.], [3., 4., 5.], [3., 4., 5.]]);
+
+        output1.to_data().assert_eq(&expected1, true);
+        output2.to_data().assert_eq(&expected2, true);
+    }
+
+    #[test]
+    fn repeat_interleave_specs() {
+        let input_specs = repeat_interleave::Model::<Backend>::input_specs();
+        let output_specs = repeat_interleave::Model::<Backend>::output_specs();
+
+        assert_eq!(
+            input_specs,
+            [
+                TensorSpec::new("input1", 2, DType::F32),
+                TensorSpec::new("input2", 1, DType::I64),
+            ]
+        );
+        assert_eq!(
+            output_specs,
+            [
+                TensorSpec::new("repeatinterleave2_out1", 2, DType::F32),
+                TensorSpec::new("repeatinterleave3_out1", 2, DType::F32),
+            ]
+        );
+    }
+
     #[test]
     fn reshape() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -982,6 +1777,75 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn reshape_allowzero() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: reshape_allowzero::Model<Backend> = reshape_allowzero::Model::new(&device);
+
+        // Run the model
+        let input1 = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .float()
+            .reshape([2, 3, 4]);
+        let input2 = Tensor::<Backend, 2>::empty([0, 3], &device);
+        let (output1, output2) = model.forward(input1.clone(), input2);
+
+        // allowzero=0 copies the input dimension, allowzero=1 keeps the zero-size dimension
+        output1
+            .to_data()
+            .assert_eq(&input1.reshape([2, 12]).to_data(), true);
+        assert_eq!(output2.dims(), [3, 0]);
+    }
+
+    #[test]
+    fn reshape_infer_neg1() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: reshape_infer_neg1::Model<Backend> = reshape_infer_neg1::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .float()
+            .reshape([2, 3, 4]);
+        let (output1, output2) = model.forward(input.clone());
+
+        assert_eq!(output1.dims(), [4, 6]);
+        output1
+            .to_data()
+            .assert_eq(&input.clone().reshape([4, 6]).to_data(), true);
+        output2
+            .to_data()
+            .assert_eq(&input.reshape([24]).to_data(), true);
+    }
+
+    #[test]
+    fn reshape_runtime() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: reshape_runtime::Model<Backend> = reshape_runtime::Model::new(&device);
+
+        // Run the model with the target shape as a runtime input
+        let input = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .float()
+            .reshape([2, 3, 4]);
+        let output = model.forward(
+            input.clone(),
+            Tensor::<Backend, 1, Int>::from_ints([4, -1], &device),
+        );
+        output
+            .to_data()
+            .assert_eq(&input.clone().reshape([4, 6]).to_data(), true);
+
+        // A zero copies the input dimension
+        let output = model.forward(
+            input.clone(),
+            Tensor::<Backend, 1, Int>::from_ints([0, -1], &device),
+        );
+        output
+            .to_data()
+            .assert_eq(&input.reshape([2, 12]).to_data(), true);
+    }
+
     #[test]
     fn resize_with_sizes() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -1006,6 +1870,50 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn resize_runtime_sizes() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: resize_runtime_sizes::Model<Backend> = resize_runtime_sizes::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[[
+                [0.0, 1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [8.0, 9.0, 10.0, 11.0],
+                [12.0, 13.0, 14.0, 15.0],
+            ]]],
+            &device,
+        );
+        let sizes = Tensor::<Backend, 1, Int>::from_ints([1, 1, 2, 2], &device);
+        let output = model.forward(input, sizes);
+        let expected = TensorData::from([[[[0.0f32, 2.0], [8.0, 10.0]]]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn resize_runtime_scales() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: resize_runtime_scales::Model<Backend> =
+            resize_runtime_scales::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+        let scales = Tensor::<Backend, 1>::from_floats([1.0, 1.0, 2.0, 2.0], &device);
+        let output = model.forward(input, scales);
+        let expected = TensorData::from([[[
+            [1.0f32, 1.0, 2.0, 2.0],
+            [1.0, 1.0, 2.0, 2.0],
+            [3.0, 3.0, 4.0, 4.0],
+            [3.0, 3.0, 4.0, 4.0],
+        ]]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn resize_with_scales_1d_linear() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -1157,6 +2065,69 @@ mod tests {
         assert_eq!(output, expected);
     }
 
+    #[test]
+    fn shape_arithmetic() {
+        let device = Default::default();
+        let model: shape_arithmetic::Model<Backend> = shape_arithmetic::Model::new(&device);
+
+        // The second input is reshaped to the doubled shape of the first one
+        let input1 = Tensor::<Backend, 3>::ones([1, 2, 3], &device);
+        let input2 = Tensor::<Backend, 1, Int>::arange(0..48, &device)
+            .float()
+            .reshape([6, 8]);
+        let output = model.forward(input1, input2.clone());
+
+        assert_eq!(output.dims(), [2, 4, 6]);
+        output
+            .to_data()
+            .assert_eq(&input2.reshape([2, 4, 6]).to_data(), true);
+    }
+
+    #[test]
+    fn shape_sliced() {
+        let device = Default::default();
+        let model: shape_sliced::Model<Backend> = shape_sliced::Model::new(&device);
+
+        // Run the model, both nodes select the middle dims with positive and negative axes
+        let input = Tensor::<Backend, 4>::ones([2, 3, 4, 5], &device);
+        let (output1, output2) = model.forward(input);
+        let expected = [3, 4];
+
+        assert_eq!(output1, expected);
+        assert_eq!(output2, expected);
+    }
+
+    #[test]
+    fn identity() {
+        let device = Default::default();
+        let model: identity::Model<Backend> = identity::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::ones([2, 3, 4], &device);
+        // The identity node preserves the shape type of its input
+        let output: [usize; 3] = model.forward(input);
+        let expected = [2, 3, 4];
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn if_else() {
+        let device = Default::default();
+        let model: if_else::Model<Backend> = if_else::Model::default();
+
+        let input = Tensor::<Backend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        // The then branch is `x @ W1^T + b1`
+        let output = model.forward(input.clone(), true);
+        let expected = TensorData::from([[4.5f32, 4.5], [10.5, 10.5]]);
+        output.to_data().assert_eq(&expected, true);
+
+        // The else branch is `x @ W2^T + b2`
+        let output = model.forward(input, false);
+        let expected = TensorData::from([[9f32, -1.0], [15.0, -1.0]]);
+        output.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn flatten() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -1357,6 +2328,48 @@ mod tests {
             .assert_approx_eq::<FT>(&expected, Tolerance::default());
     }
 
+    #[test]
+    fn hardmax() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: hardmax::Model<Backend> = hardmax::Model::new(&device);
+
+        // Run the model, the last two rows have ties
+        let input = Tensor::<Backend, 2>::from_floats(
+            [
+                [0.5, 2.0, -1.0, 1.5],
+                [1.0, 3.0, 3.0, 0.0],
+                [5.0, 1.0, 2.0, 5.0],
+            ],
+            &device,
+        );
+        let output = model.forward(input);
+        // The maximum is set to 1 on each row, keeping the first one on ties
+        let expected = TensorData::from([
+            [0f32, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+        ]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn hinge_embedding_loss() {
+        let device = Default::default();
+        let model: hinge_embedding_loss::Model<Backend> = hinge_embedding_loss::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[0.0, 2.5, 0.5], [1.0, 0.2, 3.0]], &device);
+        let target =
+            Tensor::<Backend, 2>::from_floats([[1.0, -1.0, -1.0], [1.0, -1.0, -1.0]], &device);
+        let output = model.forward(input, target);
+        let expected = TensorData::from([0.71667f32]);
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
     #[test]
     fn sin() {
         let device = Default::default();
@@ -1372,6 +2385,53 @@ mod tests {
             .assert_approx_eq::<FT>(&expected, Tolerance::rel_abs(1e-4, 1e-4));
     }
 
+    #[test]
+    fn sinusoidal_pos_encoding() {
+        let device = Default::default();
+        let model: sinusoidal_pos_encoding::Model<Backend> =
+            sinusoidal_pos_encoding::Model::default();
+
+        let input = Tensor::<Backend, 3>::ones([2, 5, 8], &device);
+        let output = model.forward(input.clone());
+
+        // The sinusoids table is recognized as a positional encoding, followed by a regular Add
+        let sinusoids = burn::nn::generate_sinusoids::<Backend>(5, 8, 10_000, &device);
+        let expected = input + sinusoids.unsqueeze::<3>() + 0.5;
+
+        output
+            .to_data()
+            .assert_approx_eq::<FT>(&expected.to_data(), Tolerance::rel_abs(1e-4, 1e-4));
+    }
+
+    #[test]
+    fn relative_position_bias() {
+        let device = Default::default();
+        let model: relative_position_bias::Model<Backend> =
+            relative_position_bias::Model::default();
+
+        let input = Tensor::<Backend, 4>::ones([1, 2, 4, 5], &device);
+        let output = model.forward(input);
+
+        // The bias of each head is `bucket + 10 * head`, with the buckets of the T5 reference
+        // implementation for 8 buckets and a max distance of 6
+        let expected = TensorData::from([[
+            [
+                [1f32, 6.0, 7.0, 7.0, 8.0],
+                [2.0, 1.0, 6.0, 7.0, 7.0],
+                [3.0, 2.0, 1.0, 6.0, 7.0],
+                [3.0, 3.0, 2.0, 1.0, 6.0],
+            ],
+            [
+                [11.0, 16.0, 17.0, 17.0, 18.0],
+                [12.0, 11.0, 16.0, 17.0, 17.0],
+                [13.0, 12.0, 11.0, 16.0, 17.0],
+                [13.0, 13.0, 12.0, 11.0, 16.0],
+            ],
+        ]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn sinh() {
         let device = Default::default();
@@ -1416,6 +2476,38 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn transpose_perm() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: transpose_perm::Model<Backend> = transpose_perm::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[0., 1., 2., 3.], [4., 5., 6., 7.], [8., 9., 10., 11.]],
+                [
+                    [12., 13., 14., 15.],
+                    [16., 17., 18., 19.],
+                    [20., 21., 22., 23.],
+                ],
+            ],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = TensorData::from([
+            [[0f32, 4., 8.], [1., 5., 9.], [2., 6., 10.], [3., 7., 11.]],
+            [
+                [12., 16., 20.],
+                [13., 17., 21.],
+                [14., 18., 22.],
+                [15., 19., 23.],
+            ],
+        ]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn equal_scalar_to_scalar_and_tensor_to_tensor() {
         // Initialize the model with weights (loaded from the exported file)
@@ -1505,6 +2597,25 @@ mod tests {
         assert!(expected_sum3.approx_eq(output_sum3, (1.0e-6, 2)));
     }
 
+    #[test]
+    fn linear_summary() {
+        use burn::module::Module;
+
+        let model: linear::Model<Backend> = linear::Model::default();
+
+        let summary = model.summary();
+
+        let names = summary
+            .entries()
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(names, ["gemm1", "matmul1", "matmul2"]);
+        // Linear 3x4 with bias, 5x6 without bias and 7x8 with bias
+        assert_eq!(summary.num_params(), 16 + 30 + 64);
+        assert_eq!(summary.num_params(), model.num_params());
+    }
+
     #[test]
     fn tan() {
         // Initialize the model
@@ -1786,6 +2897,80 @@ mod tests {
         output.assert_eq(&expected, true);
     }
 
+    #[test]
+    fn and() {
+        let device = Default::default();
+        let model: and::Model<Backend> = and::Model::new(&device);
+
+        let input1 = Tensor::<Backend, 4, Bool>::from_bool(
+            TensorData::from([[[[true, true, false, false]]]]),
+            &device,
+        );
+        let input2 = Tensor::<Backend, 4, Bool>::from_bool(
+            TensorData::from([[[[true, false, true, false]]]]),
+            &device,
+        );
+
+        let output = model.forward(input1, input2).to_data();
+        let expected = TensorData::from([[[[true, false, false, false]]]]);
+
+        output.assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn or() {
+        let device = Default::default();
+        let model: or::Model<Backend> = or::Model::new(&device);
+
+        let input1 = Tensor::<Backend, 4, Bool>::from_bool(
+            TensorData::from([[[[true, true, false, false]]]]),
+            &device,
+        );
+        let input2 = Tensor::<Backend, 4, Bool>::from_bool(
+            TensorData::from([[[[true, false, true, false]]]]),
+            &device,
+        );
+
+        let output = model.forward(input1, input2).to_data();
+        let expected = TensorData::from([[[[true, true, true, false]]]]);
+
+        output.assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn optional() {
+        let device = Default::default();
+        let model: optional::Model<Backend> = optional::Model::new(&device);
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]], &device);
+        let (has_element, output) = model.forward(input);
+        let expected = TensorData::from([[1f32, 0.0, 3.0], [0.0, 5.0, 0.0]]);
+
+        assert!(has_element);
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn xor() {
+        let device = Default::default();
+        let model: xor::Model<Backend> = xor::Model::new(&device);
+
+        let input1 = Tensor::<Backend, 4, Bool>::from_bool(
+            TensorData::from([[[[true, true, false, false]]]]),
+            &device,
+        );
+        let input2 = Tensor::<Backend, 4, Bool>::from_bool(
+            TensorData::from([[[[true, false, true, false]]]]),
+            &device,
+        );
+
+        let output = model.forward(input1, input2).to_data();
+        let expected = TensorData::from([[[[false, true, true, false]]]]);
+
+        output.assert_eq(&expected, true);
+    }
+
     #[test]
     fn pad() {
         let device = Default::default();
@@ -2020,6 +3205,42 @@ mod tests {
         output.assert_eq(&expected, true);
     }
 
+    #[test]
+    fn trt_plugin_layer_norm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let device = Default::default();
+        let model: trt_plugin_layer_norm::Model<Backend> = trt_plugin_layer_norm::Model::default();
+
+        // Each row is [a, a + 1, a + 2, a + 3], normalized to [-1.5, -0.5, 0.5, 1.5] / sqrt(1.25)
+        let input = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .float()
+            .reshape([2, 3, 4]);
+        let output = model.forward(input);
+
+        // Scaled by gamma = [1, 2, 0.5, -1] and shifted by beta = [0, 0.1, 0.2, 0.3]
+        let expected = Tensor::<Backend, 3>::from_floats(
+            [[[-1.341635, -0.794424, 0.423606, -1.041635]]],
+            &device,
+        )
+        .expand([2, 3, 4]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn trt_plugin_unknown() {
+        let device = Default::default();
+        let model: trt_plugin_unknown::Model<Backend> = trt_plugin_unknown::Model::new(&device);
+
+        // The unknown plugin is a no-op, followed by a Relu
+        let input = Tensor::<Backend, 2>::from_floats([[1., -2., 3.], [-4., 5., -6.]], &device);
+        let output = model.forward(input);
+
+        let expected = TensorData::from([[1f32, 0., 3.], [0., 5., 0.]]);
+        output.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn unsqueeze_runtime_axes() {
         let device = Default::default();
@@ -2049,6 +3270,41 @@ mod tests {
         assert_eq!(Shape::from([1]), output.1.shape());
     }
 
+    #[test]
+    fn unsqueeze_scalar_gather() {
+        let device = Default::default();
+        let model: unsqueeze_scalar_gather::Model<Backend> =
+            unsqueeze_scalar_gather::Model::new(&device);
+
+        // The unsqueezed scalar selects a single row, like an embedding lookup
+        let weight = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .float()
+            .reshape([4, 3]);
+        let output = model.forward(2, weight);
+
+        output
+            .to_data()
+            .assert_eq(&TensorData::from([[6f32, 7., 8.]]), true);
+    }
+
+    #[test]
+    fn unsqueeze_scalar_to_shape() {
+        let device = Default::default();
+        let model: unsqueeze_scalar_to_shape::Model<Backend> =
+            unsqueeze_scalar_to_shape::Model::new(&device);
+
+        // The scalar is the leading dim of the target shape, then squeezed back
+        let input1 = Tensor::<Backend, 2>::ones([3, 4], &device);
+        let input2 = Tensor::<Backend, 1, Int>::arange(0..24, &device).float();
+        let (output1, output2) = model.forward(2, input1, input2.clone());
+
+        assert_eq!(output1.dims(), [2, 3, 4]);
+        output1
+            .to_data()
+            .assert_eq(&input2.reshape([2, 3, 4]).to_data(), true);
+        assert_eq!(output2, 2);
+    }
+
     #[test]
     fn cast() {
         let device = Default::default();
@@ -2103,6 +3359,136 @@ mod tests {
         assert_eq!(output_scalar, expected_scalar);
     }
 
+    #[test]
+    fn cast_saturate() {
+        let device = Default::default();
+        let model: cast_saturate::Model<Backend> = cast_saturate::Model::new(&device);
+
+        let input = Tensor::<Backend, 1>::from_floats(
+            [300., -300., 1e5, -1e5, 3e9, -3e9, 2.7, -2.7],
+            &device,
+        );
+        let (output_int8, output_int16, output_int32) = model.forward(input);
+
+        // Out of range values saturate and the fractional part is truncated toward zero
+        output_int8.to_data().assert_eq(
+            &TensorData::from([127i64, -128, 127, -128, 127, -128, 2, -2]),
+            false,
+        );
+        output_int16.to_data().assert_eq(
+            &TensorData::from([300i64, -300, 32767, -32768, 32767, -32768, 2, -2]),
+            false,
+        );
+        output_int32.to_data().assert_eq(
+            &TensorData::from([
+                300i64,
+                -300,
+                100000,
+                -100000,
+                2147483647,
+                -2147483648,
+                2,
+                -2,
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn cast_saturate_infinite_values() {
+        let device = Default::default();
+        let model: cast_saturate::Model<Backend> = cast_saturate::Model::new(&device);
+
+        let input = Tensor::<Backend, 1>::from_floats(
+            [
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                1e30,
+                -1e30,
+                f32::MAX,
+                f32::MIN,
+                1e19,
+                -1e19,
+            ],
+            &device,
+        );
+        let (output_int8, output_int16, output_int32) = model.forward(input);
+
+        // Values beyond the range of the backend integer type saturate as well
+        output_int8.to_data().assert_eq(
+            &TensorData::from([127i64, -128, 127, -128, 127, -128, 127, -128]),
+            false,
+        );
+        output_int16.to_data().assert_eq(
+            &TensorData::from([
+                32767i64, -32768, 32767, -32768, 32767, -32768, 32767, -32768,
+            ]),
+            false,
+        );
+        output_int32.to_data().assert_eq(
+            &TensorData::from([
+                2147483647i64,
+                -2147483648,
+                2147483647,
+                -2147483648,
+                2147483647,
+                -2147483648,
+                2147483647,
+                -2147483648,
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn cast_saturate_int32_bounds() {
+        let device = Default::default();
+        let model: cast_saturate::Model<Backend> = cast_saturate::Model::new(&device);
+
+        // 2^31 is the f32 nearest to `i32::MAX`, and 2^31 - 128 the largest f32 below it
+        let input = Tensor::<Backend, 1>::from_floats(
+            [
+                2147483648.0,
+                2147483520.0,
+                3e9,
+                -2147483648.0,
+                -2147483904.0,
+            ],
+            &device,
+        );
+        let (_output_int8, _output_int16, output_int32) = model.forward(input);
+
+        output_int32.to_data().assert_eq(
+            &TensorData::from([
+                2147483647i64,
+                2147483520,
+                2147483647,
+                -2147483648,
+                -2147483648,
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn cast_shape_to_tensor() {
+        let device = Default::default();
+        let model: cast_shape_to_tensor::Model<Backend> = cast_shape_to_tensor::Model::new(&device);
+
+        // The shape of the first input, as an Int tensor, expands the second input
+        let input1 = Tensor::<Backend, 3>::zeros([2, 4, 3], &device);
+        let input2 = Tensor::<Backend, 2>::from_floats([[1., 2., 3.]], &device);
+        let (output1, output2) = model.forward(input1, input2.clone());
+
+        output2
+            .to_data()
+            .assert_eq(&TensorData::from([2i64, 4, 3]), false);
+        assert_eq!(output1.dims(), [2, 4, 3]);
+        output1
+            .to_data()
+            .assert_eq(&input2.expand([2, 4, 3]).to_data(), true);
+    }
+
     #[test]
     fn mask_where() {
         let device = Default::default();
@@ -2216,6 +3602,64 @@ mod tests {
         assert_eq!(expected_shape, output.shape());
     }
 
+    #[test]
+    fn squeeze_excitation() {
+        let device = Default::default();
+        let model: squeeze_excitation::Model<Backend> = squeeze_excitation::Model::default();
+
+        // Both modules are imported as single modules
+        assert_eq!(
+            alloc::format!("{model}")
+                .matches("SqueezeExcitation")
+                .count(),
+            2
+        );
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..36, &device)
+            .float()
+            .div_scalar(10.0)
+            .reshape([1, 4, 3, 3]);
+        let input_max = input.clone().max().into_scalar();
+        let (output_relu, output_silu) = model.forward(input.clone());
+
+        // Each channel is scaled by the gate of the module
+        let scales_relu = TensorData::from([0.56218f32, 0.37754, 0.5, 0.73106]);
+        let scales_silu = TensorData::from([0.59645f32, 0.34809, 0.56555, 0.73019]);
+        let scales = |output: Tensor<Backend, 4>, input: Tensor<Backend, 4>| {
+            output.slice([0..1, 0..4, 0..1, 1..2]).flatten::<1>(0, 3)
+                / input.slice([0..1, 0..4, 0..1, 1..2]).flatten::<1>(0, 3)
+        };
+        scales(output_relu.clone(), input.clone())
+            .into_data()
+            .assert_approx_eq::<FT>(&scales_relu, Tolerance::absolute(1e-4));
+        scales(output_silu.clone(), output_relu.clone())
+            .into_data()
+            .assert_approx_eq::<FT>(&scales_silu, Tolerance::absolute(1e-4));
+
+        // The input being non-negative, the output is in `[0, input_max]`
+        for output in [output_relu, output_silu] {
+            assert!(output.clone().greater_equal_elem(0.0).all().into_scalar());
+            assert!(output.max().into_scalar() <= input_max);
+        }
+    }
+
+    #[test]
+    fn stack() {
+        let device = Default::default();
+        let model: stack::Model<Backend> = stack::Model::new(&device);
+        let input1 = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let input2 = Tensor::<Backend, 2>::from_floats([[7., 8., 9.], [10., 11., 12.]], &device);
+        let input3 = Tensor::<Backend, 2>::from_floats([[13., 14., 15.], [16., 17., 18.]], &device);
+
+        let output = model.forward(input1, input2, input3);
+        let expected = TensorData::from([
+            [[1f32, 7., 13.], [2., 8., 14.], [3., 9., 15.]],
+            [[4., 10., 16.], [5., 11., 17.], [6., 12., 18.]],
+        ]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn random_uniform() {
         let device = Default::default();
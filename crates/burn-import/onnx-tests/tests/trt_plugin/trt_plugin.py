@@ -0,0 +1,96 @@
+#!/usr/bin/env python3
+
+# used to generate models: trt_plugin_layer_norm.onnx and trt_plugin_unknown.onnx
+
+# Models re-exported from TensorRT, where fused layers are wrapped in `TRT_PluginV2` nodes of the
+# `trt.plugins` domain. The plugin type is given by the `name` attribute, and its serialized
+# fields by the binary `data` attribute.
+# - trt_plugin_layer_norm: a layer normalization plugin over the last axis of a [2, 3, 4] input.
+# - trt_plugin_unknown: an unknown plugin followed by a Relu, imported with unknown plugins allowed.
+# The models are exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def make_plugin_node(inputs, outputs, plugin, **kwargs):
+    node = onnx.helper.make_node(
+        "TRT_PluginV2",
+        inputs,
+        outputs,
+        name="/TRT_PluginV2",
+        domain="trt.plugins",
+        version="1",
+        namespace="",
+        data=b"\x00\x01\xfe\xff",
+        **kwargs,
+    )
+    # `name` is the node name argument of make_node
+    node.attribute.extend([onnx.helper.make_attribute("name", plugin)])
+    return node
+
+
+def float_tensor_info(name, shape):
+    return onnx.helper.make_value_info(
+        name=name,
+        type_proto=onnx.helper.make_tensor_type_proto(
+            elem_type=onnx.TensorProto.FLOAT, shape=shape
+        ),
+    )
+
+
+def build_model(nodes, inputs, outputs, initializer=None):
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[
+            onnx.helper.make_operatorsetid("", 16),
+            onnx.helper.make_operatorsetid("trt.plugins", 1),
+        ],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=nodes,
+            inputs=inputs,
+            outputs=outputs,
+            initializer=initializer,
+        ),
+    )
+
+
+def build_layer_norm_model():
+    return build_model(
+        nodes=[
+            make_plugin_node(
+                ["input1", "gamma", "beta"], ["output1"], "LayerNormPlugin", epsilon=1e-5
+            ),
+        ],
+        inputs=[float_tensor_info("input1", [2, 3, 4])],
+        outputs=[float_tensor_info("output1", [2, 3, 4])],
+        initializer=[
+            onnx.helper.make_tensor("gamma", onnx.TensorProto.FLOAT, [4], [1.0, 2.0, 0.5, -1.0]),
+            onnx.helper.make_tensor("beta", onnx.TensorProto.FLOAT, [4], [0.0, 0.1, 0.2, 0.3]),
+        ],
+    )
+
+
+def build_unknown_model():
+    return build_model(
+        nodes=[
+            make_plugin_node(["input1"], ["plugin1_out1"], "MyCustomPlugin"),
+            onnx.helper.make_node("Relu", ["plugin1_out1"], ["output1"], name="/Relu"),
+        ],
+        inputs=[float_tensor_info("input1", [2, 3])],
+        outputs=[float_tensor_info("output1", [2, 3])],
+    )
+
+
+def main():
+    for file_name, onnx_model in [
+        ("trt_plugin_layer_norm.onnx", build_layer_norm_model()),
+        ("trt_plugin_unknown.onnx", build_unknown_model()),
+    ]:
+        onnx.save(onnx_model, file_name)
+        print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
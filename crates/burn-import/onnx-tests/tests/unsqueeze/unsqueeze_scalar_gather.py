@@ -0,0 +1,72 @@
+#!/usr/bin/env python3
+
+# used to generate model: unsqueeze_scalar_gather.onnx
+
+# Unsqueezes a scalar token id into the indices of a Gather, like an embedding lookup. The
+# unsqueezed scalar is not a shape value and stays an int tensor.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "Constant",
+                    [],
+                    ["axes"],
+                    name="/Constant",
+                    value=onnx.helper.make_tensor("axes", onnx.TensorProto.INT64, [1], [0]),
+                ),
+                onnx.helper.make_node(
+                    "Unsqueeze", ["token_id", "axes"], ["indices"], name="/Unsqueeze"
+                ),
+                onnx.helper.make_node(
+                    "Gather", ["weight", "indices"], ["output"], name="/Gather", axis=0
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="token_id",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT64, shape=[]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="weight",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[4, 3]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[1, 3]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "unsqueeze_scalar_gather.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
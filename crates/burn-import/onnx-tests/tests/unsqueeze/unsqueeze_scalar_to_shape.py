@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+
+# used to generate model: unsqueeze_scalar_to_shape.onnx
+
+# Unsqueezes a scalar into a shape with a single dim, concatenates it with the shape of the first
+# input to reshape the second input, and squeezes it back into a scalar. The scalar and the shapes
+# are computed on the shape values instead of tensors.
+# The model is exported using onnx directly.
+
+import onnx
+import onnx.helper
+
+
+def build_model():
+    return onnx.helper.make_model(
+        ir_version=8,
+        opset_imports=[onnx.helper.make_operatorsetid("", 16)],
+        graph=onnx.helper.make_graph(
+            name="main_graph",
+            nodes=[
+                onnx.helper.make_node(
+                    "Constant",
+                    [],
+                    ["axes"],
+                    name="/Constant",
+                    value=onnx.helper.make_tensor("axes", onnx.TensorProto.INT64, [1], [0]),
+                ),
+                onnx.helper.make_node(
+                    "Unsqueeze", ["size", "axes"], ["shape1"], name="/Unsqueeze"
+                ),
+                onnx.helper.make_node("Shape", ["input1"], ["shape2"], name="/Shape"),
+                onnx.helper.make_node(
+                    "Concat", ["shape1", "shape2"], ["shape3"], name="/Concat", axis=0
+                ),
+                onnx.helper.make_node(
+                    "Reshape", ["input2", "shape3"], ["output1"], name="/Reshape"
+                ),
+                onnx.helper.make_node(
+                    "Squeeze", ["shape1", "axes"], ["output2"], name="/Squeeze"
+                ),
+            ],
+            inputs=[
+                onnx.helper.make_value_info(
+                    name="size",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT64, shape=[]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="input1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[3, 4]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="input2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[24]
+                    ),
+                ),
+            ],
+            outputs=[
+                onnx.helper.make_value_info(
+                    name="output1",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.FLOAT, shape=[2, 3, 4]
+                    ),
+                ),
+                onnx.helper.make_value_info(
+                    name="output2",
+                    type_proto=onnx.helper.make_tensor_type_proto(
+                        elem_type=onnx.TensorProto.INT64, shape=[]
+                    ),
+                ),
+            ],
+        ),
+    )
+
+
+def main():
+    onnx_model = build_model()
+    file_name = "unsqueeze_scalar_to_shape.onnx"
+
+    # Ensure valid ONNX:
+    onnx.checker.check_model(onnx_model)
+
+    onnx.save(onnx_model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+
+if __name__ == "__main__":
+    main()
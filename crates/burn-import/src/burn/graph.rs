@@ -1,14 +1,15 @@
 use super::{BurnImports, Scope, Type};
 use crate::burn::{
-    TensorKind, TensorType,
+    TensorKind, TensorType, ToTokens,
     node::{Node, NodeCodegen},
 };
+use burn::module::TensorSpec;
 use burn::record::{
     BinFileRecorder, BurnRecord, FileRecorder, NamedMpkFileRecorder, NamedMpkGzFileRecorder,
     PrecisionSettings, PrettyJsonFileRecorder, Recorder,
 };
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
 use serde::{
     Serialize,
     ser::{SerializeMap, SerializeTuple},
@@ -44,8 +45,12 @@ pub struct BurnGraph<PS: PrecisionSettings> {
     top_comment: Option<String>,
     default: Option<TokenStream>,
     blank_spaces: bool,
+    half_precision_params: bool,
+    summary: bool,
+    io_specs: Option<(Vec<TensorSpec>, Vec<TensorSpec>)>,
     graph_input_types: Vec<Type>,
     graph_output_types: Vec<Type>,
+    graph_intermediate_types: Vec<Type>,
     _ps: PhantomData<PS>,
 }
 
@@ -64,6 +69,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         self.nodes.push(node);
     }
 
+    /// The registered nodes, e.g. to nest the nodes of a subgraph in the node of another graph.
+    pub(crate) fn into_nodes(self) -> Vec<Node<PS>> {
+        self.nodes
+    }
+
     /// Save the state of each node in a record file.
     ///
     /// The `Default` trait will be implemented for the generated model, which will load the record
@@ -193,6 +203,69 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         self
     }
 
+    /// Store the parameters of the generated model in half precision.
+    ///
+    /// The float parameters are cast to `f16` once loaded, which halves the memory used by the
+    /// model. In the forward pass, the parameters of each node are upcast to `f32` where they are
+    /// used, so the computations still run in full precision.
+    /// The backend used with the generated model must support `f16` tensors.
+    ///
+    /// # Notes
+    ///
+    /// Should be set before [with_record](Self::with_record), since the generated loading functions
+    /// also cast the parameters.
+    pub fn with_half_precision_params(mut self, half_precision_params: bool) -> Self {
+        self.half_precision_params = half_precision_params;
+        self
+    }
+
+    /// Generate a `summary` method returning the [module summary](burn::module::ModuleSummary) of
+    /// the fields of the model, listing the shapes of their parameters and the total number of
+    /// parameters.
+    pub fn with_summary(mut self, summary: bool) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Generate the `input_specs` and `output_specs` functions of the model, returning the given
+    /// [descriptions](TensorSpec) of the inputs and outputs of the forward pass.
+    pub fn with_io_specs(mut self, inputs: Vec<TensorSpec>, outputs: Vec<TensorSpec>) -> Self {
+        self.io_specs = Some((inputs, outputs));
+        self
+    }
+
+    /// Generate a `forward_with_intermediates` method returning the given intermediate tensors
+    /// along with the outputs of the graph.
+    ///
+    /// The names are the ones of the variables in the generated forward pass (e.g. `relu1_out1`).
+    ///
+    /// # Notes
+    ///
+    /// Should be called after the nodes are registered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no node outputs a tensor with one of the names.
+    pub fn with_intermediates(mut self, names: &[String]) -> Self {
+        let outputs = self
+            .nodes
+            .iter()
+            .flat_map(|node| node.output_types())
+            .map(|output| (output.name().to_string(), output))
+            .collect::<HashMap<_, _>>();
+
+        self.graph_intermediate_types = names
+            .iter()
+            .map(|name| {
+                outputs
+                    .get(&Type::format_name(name))
+                    .unwrap_or_else(|| panic!("Intermediate type not found for {name}"))
+                    .clone()
+            })
+            .collect();
+        self
+    }
+
     /// Add a comment at the top of the generated file.
     pub fn with_top_comment(mut self, top_comment: Option<String>) -> Self {
         self.top_comment = top_comment;
@@ -209,6 +282,8 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         let codegen_struct = self.codegen_struct();
         let codegen_new = self.codegen_new();
         let codegen_forward = self.codegen_forward();
+        let codegen_summary = self.codegen_summary();
+        let codegen_io_specs = self.codegen_io_specs();
 
         let maybe_blank = match self.blank_spaces {
             true => quote! {
@@ -248,6 +323,10 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                 #maybe_blank
 
                 #codegen_forward
+
+                #codegen_summary
+
+                #codegen_io_specs
             }
         }
     }
@@ -258,6 +337,20 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             .iter()
             .for_each(|node| node.register_imports(&mut self.imports));
 
+        if self.summary {
+            self.imports.register("burn::module::ModuleSummary");
+        }
+
+        if self.io_specs.is_some() {
+            self.imports.register("burn::module::TensorSpec");
+            self.imports.register("burn::tensor::DType");
+        }
+
+        if self.half_precision_params {
+            self.imports.register("burn::module::FloatCaster");
+            self.imports.register("burn::tensor::FloatDType");
+        }
+
         // Combine input and output types into a single vector
         let all_types = self
             .graph_input_types
@@ -287,6 +380,9 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     fn build_scope(&mut self) {
         log::debug!("Building the scope nodes len => '{}'", self.nodes.len());
 
+        self.scope
+            .set_half_precision_params(self.half_precision_params);
+
         fn to_tensor(ty: Type) -> Option<TensorType> {
             match ty {
                 Type::Tensor(tensor) => Some(tensor),
@@ -338,8 +434,19 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             });
     }
 
+    /// Cast the parameters of the model to half precision, when enabled.
+    fn codegen_cast_params(&self) -> TokenStream {
+        match self.half_precision_params {
+            true => quote! {
+                .map(&mut FloatCaster { dtype: FloatDType::F16 })
+            },
+            false => quote! {},
+        }
+    }
+
     fn register_record_file(&mut self, file: PathBuf, recorder_str: &str) {
         self.imports.register("burn::record::Recorder");
+        let cast_params = self.codegen_cast_params();
 
         let recorder_ty = syn::parse_str::<syn::Type>(recorder_str).unwrap();
 
@@ -358,7 +465,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                     let record = #recorder_ty::new()
                         .load(file.into(), device)
                         .expect("Record file to exist.");
-                    Self::new(device).load_record(record)
+                    Self::new(device).load_record(record)#cast_params
                 }
             }
         });
@@ -372,6 +479,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         let precision_ty = syn::parse_str::<syn::Type>(&precision).unwrap();
         self.imports.register("burn::record::BinBytesRecorder");
 
+        let cast_params = self.codegen_cast_params();
         let mut file = file;
         file.set_extension(<BinFileRecorder<PS> as FileRecorder<Backend>>::file_extension());
         let file = file.to_str().unwrap();
@@ -391,7 +499,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                     .load(EMBEDDED_STATES, device)
                     .expect("Should decode state successfully");
 
-                    Self::new(device).load_record(record)
+                    Self::new(device).load_record(record)#cast_params
                 }
             }
 
@@ -402,6 +510,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         let mut body = quote! {};
         self.nodes
             .iter()
+            .flat_map(Node::flatten)
             .filter_map(|node| node.field_type())
             .map(|field| {
                 let name = field.name();
@@ -438,15 +547,18 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
         self.nodes
             .iter()
+            .flat_map(Node::flatten)
             .map(|node| node.field_init())
             .for_each(|code| body.extend(code));
 
         let fields = self
             .nodes
             .iter()
+            .flat_map(Node::flatten)
             .flat_map(|node| node.field_type())
             .map(|field| field.name().clone())
             .collect::<Vec<_>>();
+        let cast_params = self.codegen_cast_params();
 
         quote! {
             #[allow(unused_variables)]
@@ -457,15 +569,69 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                     #(#fields,)*
                     phantom: core::marker::PhantomData,
                     device: burn::module::Ignored(device.clone()),
-                }
+                }#cast_params
+            }
+        }
+    }
+
+    fn codegen_summary(&self) -> TokenStream {
+        if !self.summary {
+            return quote! {};
+        }
+
+        let names = self
+            .nodes
+            .iter()
+            .flat_map(Node::flatten)
+            .flat_map(|node| node.field_type())
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        let names_str = names.iter().map(|name| name.to_string());
+
+        // The backend can't be inferred for the modules without parameters, e.g. `Dropout`
+
+        quote! {
+            pub fn summary(&self) -> ModuleSummary {
+                ModuleSummary::new()
+                    #(.add::<B, _>(#names_str, &self.#names))*
+            }
+        }
+    }
+
+    fn codegen_io_specs(&self) -> TokenStream {
+        let Some((inputs, outputs)) = &self.io_specs else {
+            return quote! {};
+        };
+
+        let specs = |specs: &[TensorSpec]| {
+            let specs = specs.iter().map(|spec| {
+                let name = &spec.name;
+                let rank = spec.rank.to_tokens();
+                let dtype = Ident::new(&format!("{:?}", spec.dtype), Span::call_site());
+
+                quote! { TensorSpec::new(#name, #rank, DType::#dtype) }
+            });
+
+            quote! { [#(#specs),*] }
+        };
+        let num_inputs = inputs.len().to_tokens();
+        let num_outputs = outputs.len().to_tokens();
+        let inputs = specs(inputs);
+        let outputs = specs(outputs);
+
+        quote! {
+            pub fn input_specs() -> [TensorSpec; #num_inputs] {
+                #inputs
+            }
+
+            pub fn output_specs() -> [TensorSpec; #num_outputs] {
+                #outputs
             }
         }
     }
 
     fn codegen_forward(&mut self) -> TokenStream {
         let mut input_def = quote! {};
-        let mut output_type_def = quote! {};
-        let mut output_return_def = quote! {};
 
         self.graph_input_types.iter().for_each(|input| {
             let name = input.name().clone();
@@ -477,54 +643,91 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             })
         });
 
-        let multiple_output = self.graph_output_types.len() > 1;
-
-        self.graph_output_types.iter().for_each(|output| {
-            let name = output.name();
-            let ty = output.ty();
-
-            if multiple_output {
-                output_type_def.extend(quote! {
-                    #ty,
-                });
-                output_return_def.extend(quote! {
-                    #name,
-                });
-            } else {
-                output_type_def.extend(quote! {
-                    #ty
-                });
-                output_return_def.extend(quote! {
-                    #name
-                });
+        let (output_type_def, output_return_def) = Self::codegen_return(&self.graph_output_types);
+
+        // The intermediates are still used after the last node, so they are cloned when needed
+        let mut intermediates_scope = self.scope.clone();
+        for intermediate in self.graph_intermediate_types.iter() {
+            if let Type::Tensor(tensor) = intermediate {
+                intermediates_scope.tensor_register_future_use(tensor, self.nodes.len());
             }
-        });
+        }
+
+        let body = self.codegen_body(&mut self.scope.clone());
+        let forward = self.codegen_forward_fn(
+            format_ident!("forward"),
+            &input_def,
+            output_type_def.clone(),
+            quote! {
+                #body
+
+                #output_return_def
+            },
+        );
 
-        if multiple_output {
-            output_return_def = quote! {
-                (#output_return_def)
-            };
-            output_type_def = quote! {
-                (#output_type_def)
-            };
+        if self.graph_intermediate_types.is_empty() {
+            return forward;
         }
 
+        let (intermediate_type_def, intermediate_return_def) =
+            Self::codegen_return(&self.graph_intermediate_types);
+        let body = self.codegen_body(&mut intermediates_scope);
+        let forward_with_intermediates = self.codegen_forward_fn(
+            format_ident!("forward_with_intermediates"),
+            &input_def,
+            quote! { (#output_type_def, #intermediate_type_def) },
+            quote! {
+                #body
+
+                (#output_return_def, #intermediate_return_def)
+            },
+        );
+
+        quote! {
+            #forward
+
+            #forward_with_intermediates
+        }
+    }
+
+    /// Generate the type and the value returned for the given variables, as a tuple when there
+    /// are multiple variables.
+    fn codegen_return(types: &[Type]) -> (TokenStream, TokenStream) {
+        let names = types.iter().map(|ty| ty.name());
+        let tys = types.iter().map(|ty| ty.ty());
+
+        if types.len() > 1 {
+            (quote! { (#(#tys,)*) }, quote! { (#(#names,)*) })
+        } else {
+            (quote! { #(#tys)* }, quote! { #(#names)* })
+        }
+    }
+
+    fn codegen_body(&self, scope: &mut Scope) -> TokenStream {
         let mut body = quote! {};
         self.nodes
             .iter()
             .enumerate()
-            .map(|(index, node)| node.forward(&mut self.scope, index))
+            .map(|(index, node)| node.forward(scope, index))
             .for_each(|code| body.extend(code));
 
+        body
+    }
+
+    fn codegen_forward_fn(
+        &self,
+        name: Ident,
+        input_def: &TokenStream,
+        output_type_def: TokenStream,
+        body: TokenStream,
+    ) -> TokenStream {
         // TODO Return the result without a `let` binding from a block,
         // otherwise let_and_return error will be triggered by clippy.
         // For now, we just disable the warning.
         quote! {
             #[allow(clippy::let_and_return, clippy::approx_constant)]
-            pub fn forward(&self, #input_def) -> #output_type_def {
+            pub fn #name(&self, #input_def) -> #output_type_def {
                 #body
-
-                #output_return_def
             }
         }
     }
@@ -608,6 +811,7 @@ impl<PS: PrecisionSettings> Serialize for StructMap<'_, PS> {
             .0
             .nodes
             .iter()
+            .flat_map(Node::flatten)
             .filter_map(|node| node.field_type().map(|ty| (node, ty.name().clone())))
             .collect::<Vec<_>>();
         let mut map = serializer.serialize_map(Some(nodes_with_names.len()))?;
@@ -642,6 +846,7 @@ impl<PS: PrecisionSettings> Serialize for StructTuple<'_, PS> {
             .0
             .nodes
             .iter()
+            .flat_map(Node::flatten)
             .filter_map(|node| node.field_type().map(|ty| (node, ty.name().clone())))
             .collect::<Vec<_>>();
         let mut map = serializer.serialize_tuple(nodes_with_names.len())?;
@@ -0,0 +1,148 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::{nn::pool::AdaptiveMaxPool2dConfig, record::PrecisionSettings};
+
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+/// AdaptiveMaxPool2dNode is a node that performs a 2D adaptive max pooling.
+///
+/// It replaces the MaxPool2d nodes whose windows tile the input exactly.
+#[derive(Debug, Clone)]
+pub struct AdaptiveMaxPool2dNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: AdaptiveMaxPool2dConfig,
+}
+
+impl AdaptiveMaxPool2dNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        config: AdaptiveMaxPool2dConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    AdaptiveMaxPool2d
+                },
+            ),
+            input,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for AdaptiveMaxPool2dNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let output_size = self.config.output_size.to_tokens();
+        let tokens = quote! {
+            let #name = AdaptiveMaxPool2dConfig::new(#output_size)
+                .init();
+        };
+
+        Some(tokens)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = scope.field_use(&self.field.name);
+
+        quote! {
+            let #output = #field.forward(#input);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::pool::AdaptiveMaxPool2d");
+        imports.register("burn::nn::pool::AdaptiveMaxPool2dConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::AdaptiveMaxPool2d(self)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        S::serialize_none(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{adaptive_max_pool2d::AdaptiveMaxPool2dNode, test::assert_tokens},
+    };
+    use burn::{nn::pool::AdaptiveMaxPool2dConfig, record::FullPrecisionSettings};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(AdaptiveMaxPool2dNode::new(
+            "max_pool2d",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            AdaptiveMaxPool2dConfig::new([2, 3]),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::pool::AdaptiveMaxPool2d;
+            use burn::nn::pool::AdaptiveMaxPool2dConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                max_pool2d: AdaptiveMaxPool2d,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let max_pool2d = AdaptiveMaxPool2dConfig::new([2, 3])
+                        .init();
+
+                    Self {
+                        max_pool2d,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.max_pool2d.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
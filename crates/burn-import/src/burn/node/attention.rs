@@ -0,0 +1,707 @@
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::{ConstantRecord, Param, ParamId},
+    nn::{LinearRecord, attention::MultiHeadAttentionConfig, attention::MultiHeadAttentionRecord},
+    record::{PrecisionSettings, Record},
+    tensor::{Tensor, TensorData},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Node for the `com.microsoft.Attention` contrib operator, which is mapped to a
+/// [MultiHeadAttention](burn::nn::attention::MultiHeadAttention) module.
+///
+/// The operator projects the input with a single fused `[hidden_size, 3 * hidden_size]` QKV weight
+/// and does not apply an output projection, so the weight is split into the query, key and value
+/// layers and the output layer is set to the identity.
+///
+/// With past keys and values, an attention bias or a present output, the attention is computed
+/// from the layers of the module, since [MultiHeadAttention](burn::nn::attention::MultiHeadAttention)
+/// does not take or return the projected keys and values.
+#[derive(Debug, Clone)]
+pub struct AttentionNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    /// Key lengths `[batch_size]`, or mask `[batch_size, total_length]` or
+    /// `[batch_size, seq_length, total_length]` where the positions equal to zero are excluded.
+    pub mask_index: Option<TensorType>,
+    /// Keys and values of the past positions `[2, batch_size, num_heads, past_length, head_size]`.
+    pub past: Option<TensorType>,
+    /// Bias added to the attention scores `[batch_size, num_heads, seq_length, total_length]`.
+    pub attention_bias: Option<TensorType>,
+    pub output: TensorType,
+    /// Keys and values of all the positions `[2, batch_size, num_heads, total_length, head_size]`.
+    pub present: Option<TensorType>,
+    pub data_weights: TensorData,
+    pub data_bias: Option<TensorData>,
+    pub config: MultiHeadAttentionConfig,
+    pub unidirectional: bool,
+}
+
+impl AttentionNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        mask_index: Option<TensorType>,
+        past: Option<TensorType>,
+        attention_bias: Option<TensorType>,
+        output: TensorType,
+        present: Option<TensorType>,
+        data_weights: TensorData,
+        data_bias: Option<TensorData>,
+        config: MultiHeadAttentionConfig,
+        unidirectional: bool,
+    ) -> Self {
+        if let Some(mask_index) = &mask_index {
+            if !(1..=3).contains(&mask_index.rank) {
+                panic!(
+                    "Attention: only 1D, 2D and 3D mask index are supported (got rank {})",
+                    mask_index.rank
+                );
+            }
+        }
+        for key_value in past.iter().chain(present.iter()) {
+            if key_value.rank != 5 {
+                panic!(
+                    "Attention: past and present key/value must have rank 5 (got rank {})",
+                    key_value.rank
+                );
+            }
+        }
+        if let Some(attention_bias) = &attention_bias {
+            if attention_bias.rank != 4 {
+                panic!(
+                    "Attention: attention bias must have rank 4 (got rank {})",
+                    attention_bias.rank
+                );
+            }
+        }
+
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    MultiHeadAttention<B>
+                },
+            ),
+            input,
+            mask_index,
+            past,
+            attention_bias,
+            output,
+            present,
+            data_weights,
+            data_bias,
+            config,
+            unidirectional,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for AttentionNode {
+    fn input_types(&self) -> Vec<Type> {
+        [
+            Some(&self.input),
+            self.mask_index.as_ref(),
+            self.past.as_ref(),
+        ]
+        .into_iter()
+        .chain([self.attention_bias.as_ref()])
+        .flatten()
+        .map(|tensor| Type::Tensor(tensor.clone()))
+        .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [Some(&self.output), self.present.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_model = self.config.d_model.to_tokens();
+        let n_heads = self.config.n_heads.to_tokens();
+        let min_float = self.config.min_float;
+        let tokens = quote! {
+            let #name = MultiHeadAttentionConfig::new(#d_model, #n_heads)
+                .with_dropout(0.0)
+                .with_min_float(#min_float)
+                .init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let device = Default::default();
+        let d_model = self.config.d_model;
+
+        let weights = Tensor::<SerializationBackend, 2>::from_data(
+            self.data_weights.clone().convert::<PS::FloatElem>(),
+            &device,
+        )
+        .chunk(3, 1);
+        let biases = match &self.data_bias {
+            Some(bias) => Tensor::<SerializationBackend, 1>::from_data(
+                bias.clone().convert::<PS::FloatElem>(),
+                &device,
+            )
+            .chunk(3, 0)
+            .into_iter()
+            .map(Some)
+            .collect(),
+            None => vec![None; 3],
+        };
+        let mut linears = weights
+            .into_iter()
+            .zip(biases)
+            .map(|(weight, bias)| LinearRecord {
+                weight: Param::initialized(ParamId::new(), weight),
+                bias: bias.map(|bias| Param::initialized(ParamId::new(), bias)),
+            });
+
+        let record = MultiHeadAttentionRecord::<SerializationBackend> {
+            query: linears.next().unwrap(),
+            key: linears.next().unwrap(),
+            value: linears.next().unwrap(),
+            output: LinearRecord {
+                weight: Param::initialized(ParamId::new(), Tensor::eye(d_model, &device)),
+                bias: Some(Param::initialized(
+                    ParamId::new(),
+                    Tensor::zeros([d_model], &device),
+                )),
+            },
+            dropout: ConstantRecord::new(),
+            activation: ConstantRecord::new(),
+            d_model: ConstantRecord::new(),
+            n_heads: ConstantRecord::new(),
+            d_k: ConstantRecord::new(),
+            min_float: ConstantRecord::new(),
+            quiet_softmax: ConstantRecord::new(),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let field = scope.field_use(&self.field.name);
+
+        // Positions where the mask index is zero, or past the key length, are excluded from the
+        // attention
+        let mask = self.mask_index.as_ref().map(|mask_index| {
+            (
+                mask_index.rank,
+                scope.tensor_use_owned(mask_index, node_position),
+            )
+        });
+        let past = self
+            .past
+            .as_ref()
+            .map(|past| scope.tensor_use_owned(past, node_position));
+        let attention_bias = self
+            .attention_bias
+            .as_ref()
+            .map(|bias| scope.tensor_use_owned(bias, node_position));
+
+        if past.is_some() || attention_bias.is_some() || self.present.is_some() {
+            return self.forward_decomposed(input, field, mask, past, attention_bias);
+        }
+
+        let output = &self.output.name;
+        let autoregressive = quote! {
+            generate_autoregressive_mask::<B>(batch_size, seq_length, &*self.device)
+        };
+
+        let mask_pad = match &mask {
+            Some((1, mask)) => {
+                let key_lengths = Self::key_lengths_mask(mask, quote! { seq_length });
+                quote! { .mask_pad(#key_lengths) }
+            }
+            Some((2, mask)) => quote! { .mask_pad(#mask.equal_elem(0)) },
+            _ => quote! {},
+        };
+        let mask_attn = match (&mask, self.unidirectional) {
+            (Some((3, mask)), true) => {
+                quote! { .mask_attn(#mask.equal_elem(0).bool_or(#autoregressive)) }
+            }
+            (Some((3, mask)), false) => quote! { .mask_attn(#mask.equal_elem(0)) },
+            (_, true) => quote! { .mask_attn(#autoregressive) },
+            (_, false) => quote! {},
+        };
+
+        if self.unidirectional || matches!(mask, Some((1, _))) {
+            quote! {
+                let #output = {
+                    let [batch_size, seq_length, _] = #input.dims();
+                    let input = MhaInput::self_attn(#input)#mask_pad #mask_attn;
+                    #field.forward(input).context
+                };
+            }
+        } else {
+            quote! {
+                let #output = #field
+                    .forward(MhaInput::self_attn(#input)#mask_pad #mask_attn)
+                    .context;
+            }
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::attention::MultiHeadAttention");
+        imports.register("burn::nn::attention::MultiHeadAttentionConfig");
+        if matches!(&self.mask_index, Some(mask_index) if mask_index.rank == 1) {
+            imports.register("burn::tensor::Int");
+        }
+
+        if self.past.is_some() || self.attention_bias.is_some() || self.present.is_some() {
+            imports.register("burn::nn::Linear");
+            imports.register("burn::tensor::activation::softmax");
+            if self.unidirectional {
+                imports.register("burn::tensor::Bool");
+            }
+        } else {
+            imports.register("burn::nn::attention::MhaInput");
+            if self.unidirectional {
+                imports.register("burn::nn::attention::generate_autoregressive_mask");
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Attention(self)
+    }
+}
+
+impl AttentionNode {
+    /// The padding mask `[batch_size, length]` of the positions past the key lengths `[batch_size]`.
+    fn key_lengths_mask(key_lengths: &TokenStream, length: TokenStream) -> TokenStream {
+        quote! {
+            Tensor::<B, 1, Int>::arange(0..#length as i64, &*self.device)
+                .unsqueeze::<2>()
+                .expand([batch_size, #length])
+                .greater_equal(#key_lengths.unsqueeze_dim::<2>(1).expand([batch_size, #length]))
+        }
+    }
+
+    /// Compute the attention from the query, key and value layers of the module, appending the
+    /// new keys and values to the past ones.
+    fn forward_decomposed(
+        &self,
+        input: TokenStream,
+        field: TokenStream,
+        mask: Option<(usize, TokenStream)>,
+        past: Option<TokenStream>,
+        attention_bias: Option<TokenStream>,
+    ) -> TokenStream {
+        let output = &self.output.name;
+        let d_model = self.config.d_model.to_tokens();
+        let n_heads = self.config.n_heads.to_tokens();
+        let head_size = self.config.d_model / self.config.n_heads;
+        let scale = (head_size as f32).sqrt();
+        let head_size = head_size.to_tokens();
+        let min_float = self.config.min_float;
+
+        let key_value = match past {
+            Some(past) => quote! {
+                let past_length = #past.dims()[3];
+                let key = Tensor::cat(vec![#past.clone().narrow(0, 0, 1).squeeze::<4>(0), key], 2);
+                let value = Tensor::cat(vec![#past.narrow(0, 1, 1).squeeze::<4>(0), value], 2);
+            },
+            None => quote! {
+                let past_length = 0;
+            },
+        };
+        let attention_bias = attention_bias.map(|bias| {
+            quote! {
+                let scores = scores + #bias;
+            }
+        });
+        let mask = mask.map(|(rank, mask)| {
+            let mask = match rank {
+                1 => {
+                    let key_lengths = Self::key_lengths_mask(&mask, quote! { total_length });
+                    quote! { #key_lengths.reshape([batch_size, 1, 1, total_length]) }
+                }
+                2 => quote! { #mask.equal_elem(0).reshape([batch_size, 1, 1, total_length]) },
+                _ => quote! { #mask.equal_elem(0).reshape([batch_size, 1, seq_length, total_length]) },
+            };
+            quote! {
+                let scores = scores.mask_fill(#mask, #min_float);
+            }
+        });
+        // The new positions attend to all the past positions, and causally to each other
+        let causal = self.unidirectional.then(|| {
+            quote! {
+                let causal = Tensor::<B, 2, Bool>::tril_mask(
+                    [seq_length, total_length],
+                    past_length as i64,
+                    &*self.device,
+                );
+                let scores = scores.mask_fill(
+                    causal.reshape([1, 1, seq_length, total_length]),
+                    #min_float,
+                );
+            }
+        });
+        let (outputs, present, result) = match &self.present {
+            Some(present) => {
+                let name = &present.name;
+                (
+                    quote! { (#output, #name) },
+                    quote! {
+                        let present = Tensor::stack::<5>(vec![key.clone(), value.clone()], 0);
+                    },
+                    quote! { (context, present) },
+                )
+            }
+            None => (quote! { #output }, quote! {}, quote! { context }),
+        };
+
+        quote! {
+            let #outputs = {
+                let [batch_size, seq_length, _] = #input.dims();
+                let attention = &#field;
+                let heads = |linear: &Linear<B>, input: Tensor<B, 3>| {
+                    linear
+                        .forward(input)
+                        .reshape([batch_size, seq_length, #n_heads, #head_size])
+                        .swap_dims(1, 2)
+                };
+                let query = heads(&attention.query, #input.clone());
+                let key = heads(&attention.key, #input.clone());
+                let value = heads(&attention.value, #input);
+                #key_value
+                let total_length = past_length + seq_length;
+                #present
+
+                let scores = query.matmul(key.swap_dims(2, 3)).div_scalar(#scale);
+                #attention_bias
+                #mask
+                #causal
+                let context = softmax(scores, 3)
+                    .matmul(value)
+                    .swap_dims(1, 2)
+                    .reshape([batch_size, seq_length, #d_model]);
+
+                #result
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    fn attention_node(mask_index: Option<TensorType>, unidirectional: bool) -> AttentionNode {
+        AttentionNode::new(
+            "attention",
+            TensorType::new_float("input", 3),
+            mask_index,
+            None,
+            None,
+            TensorType::new_float("output", 3),
+            None,
+            TensorData::from([[2f32; 24]; 8]),
+            None,
+            MultiHeadAttentionConfig::new(8, 2),
+            unidirectional,
+        )
+    }
+
+    #[test]
+    fn test_codegen_mask_index() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(attention_node(Some(TensorType::new_int("mask", 2)), false));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "mask".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::attention::MhaInput;
+            use burn::nn::attention::MultiHeadAttention;
+            use burn::nn::attention::MultiHeadAttentionConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                attention: MultiHeadAttention<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let attention = MultiHeadAttentionConfig::new(8, 2)
+                        .with_dropout(0.0)
+                        .with_min_float(-10000f64)
+                        .init(device);
+
+                    Self {
+                        attention,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>, mask: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+                    let output = self
+                        .attention
+                        .forward(MhaInput::self_attn(input).mask_pad(mask.equal_elem(0)))
+                        .context;
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_unidirectional() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(attention_node(None, true));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::attention::MhaInput;
+            use burn::nn::attention::MultiHeadAttention;
+            use burn::nn::attention::MultiHeadAttentionConfig;
+            use burn::nn::attention::generate_autoregressive_mask;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                attention: MultiHeadAttention<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let attention = MultiHeadAttentionConfig::new(8, 2)
+                        .with_dropout(0.0)
+                        .with_min_float(-10000f64)
+                        .init(device);
+
+                    Self {
+                        attention,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let output = {
+                        let [batch_size, seq_length, _] = input.dims();
+                        let input = MhaInput::self_attn(input).mask_attn(
+                            generate_autoregressive_mask::<B>(batch_size, seq_length, &*self.device)
+                        );
+                        self.attention.forward(input).context
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_key_lengths() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(attention_node(Some(TensorType::new_int("mask", 1)), false));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "mask".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::attention::MhaInput;
+            use burn::nn::attention::MultiHeadAttention;
+            use burn::nn::attention::MultiHeadAttentionConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                attention: MultiHeadAttention<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let attention = MultiHeadAttentionConfig::new(8, 2)
+                        .with_dropout(0.0)
+                        .with_min_float(-10000f64)
+                        .init(device);
+
+                    Self {
+                        attention,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>, mask: Tensor<B, 1, Int>) -> Tensor<B, 3> {
+                    let output = {
+                        let [batch_size, seq_length, _] = input.dims();
+                        let input = MhaInput::self_attn(input).mask_pad(
+                            Tensor::<B, 1, Int>::arange(0..seq_length as i64, &*self.device)
+                                .unsqueeze::<2>()
+                                .expand([batch_size, seq_length])
+                                .greater_equal(mask.unsqueeze_dim::<2>(1).expand([batch_size, seq_length]))
+                        );
+                        self.attention.forward(input).context
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_past_present() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(AttentionNode::new(
+            "attention",
+            TensorType::new_float("input", 3),
+            None,
+            Some(TensorType::new_float("past", 5)),
+            None,
+            TensorType::new_float("output", 3),
+            Some(TensorType::new_float("present", 5)),
+            TensorData::from([[2f32; 24]; 8]),
+            None,
+            MultiHeadAttentionConfig::new(8, 2),
+            true,
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "past".to_string()],
+            vec!["output".to_string(), "present".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Bool;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Linear;
+            use burn::nn::attention::MultiHeadAttention;
+            use burn::nn::attention::MultiHeadAttentionConfig;
+            use burn::tensor::activation::softmax;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                attention: MultiHeadAttention<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let attention = MultiHeadAttentionConfig::new(8, 2)
+                        .with_dropout(0.0)
+                        .with_min_float(-10000f64)
+                        .init(device);
+
+                    Self {
+                        attention,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    past: Tensor<B, 5>,
+                ) -> (Tensor<B, 3>, Tensor<B, 5>) {
+                    let (output, present) = {
+                        let [batch_size, seq_length, _] = input.dims();
+                        let attention = &self.attention;
+                        let heads = |linear: &Linear<B>, input: Tensor<B, 3>| {
+                            linear
+                                .forward(input)
+                                .reshape([batch_size, seq_length, 2, 4])
+                                .swap_dims(1, 2)
+                        };
+                        let query = heads(&attention.query, input.clone());
+                        let key = heads(&attention.key, input.clone());
+                        let value = heads(&attention.value, input);
+                        let past_length = past.dims()[3];
+                        let key = Tensor::cat(vec![past.clone().narrow(0, 0, 1).squeeze::<4>(0), key], 2);
+                        let value = Tensor::cat(vec![past.narrow(0, 1, 1).squeeze::<4>(0), value], 2);
+                        let total_length = past_length + seq_length;
+                        let present = Tensor::stack::<5>(vec![key.clone(), value.clone()], 0);
+
+                        let scores = query.matmul(key.swap_dims(2, 3)).div_scalar(2f32);
+                        let causal = Tensor::<B, 2, Bool>::tril_mask(
+                            [seq_length, total_length],
+                            past_length as i64,
+                            &*self.device,
+                        );
+                        let scores = scores.mask_fill(
+                            causal.reshape([1, 1, seq_length, total_length]),
+                            -10000f64,
+                        );
+                        let context = softmax(scores, 3)
+                            .matmul(value)
+                            .swap_dims(1, 2)
+                            .reshape([batch_size, seq_length, 8]);
+
+                        (context, present)
+                    };
+
+                    (output, present)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,30 +1,53 @@
 use std::marker::PhantomData;
 
 use super::{
-    argmax::ArgMaxNode, avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode,
-    batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
-    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode,
+    adaptive_max_pool2d::AdaptiveMaxPool2dNode, argmax::ArgMaxNode, attention::AttentionNode,
+    avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode,
+    binary::BinaryNode, clip::ClipNode, concat::ConcatNode, conditional::IfNode,
+    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv_integer::ConvIntegerNode,
     conv_transpose_1d::ConvTranspose1dNode, conv_transpose_2d::ConvTranspose2dNode,
     conv_transpose_3d::ConvTranspose3dNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
-    conv3d::Conv3dNode, dropout::DropoutNode, expand::ExpandNode, floor::FloorNode,
-    gather::GatherNode, gather_elements::GatherElementsNode, gemm::GemmNode,
-    global_avg_pool::GlobalAvgPoolNode, layer_norm::LayerNormNode, linear::LinearNode,
-    mask_where::WhereNode, matmul::MatmulNode, max_pool1d::MaxPool1dNode,
-    max_pool2d::MaxPool2dNode, mean::MeanNode, one_hot::OneHotNode, pad::PadNode, prelu::PReluNode,
-    random_normal::RandomNormalNode, random_normal_like::RandomNormalLikeNode,
-    random_uniform::RandomUniformNode, random_uniform_like::RandomUniformLikeNode,
-    range::RangeNode, reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode, split::SplitNode,
-    squeeze::SqueezeNode, sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode,
-    unary::UnaryNode, unsqueeze::UnsqueezeNode,
+    conv3d::Conv3dNode, convnext_block::ConvNeXtBlockNode,
+    depthwise_separable_conv2d::DepthwiseSeparableConv2dNode, dropout::DropoutNode,
+    expand::ExpandNode, floor::FloorNode, gather::GatherNode, gather_elements::GatherElementsNode,
+    gemm::GemmNode, global_avg_pool::GlobalAvgPoolNode, hardmax::HardmaxNode,
+    hinge_embedding_loss::HingeEmbeddingLossNode, identity::IdentityNode,
+    layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
+    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode, one_hot::OneHotNode,
+    optional::OptionalNode, pad::PadNode, prelu::PReluNode, random_normal::RandomNormalNode,
+    random_normal_like::RandomNormalLikeNode, random_uniform::RandomUniformNode,
+    random_uniform_like::RandomUniformLikeNode, range::RangeNode,
+    relative_position_bias::RelativePositionBiasNode, repeat_interleave::RepeatInterleaveNode,
+    reshape::ReshapeNode, resize::ResizeNode, scatter_elements::ScatterElementsNode,
+    sinusoidal_pos_encoding::SinusoidalPositionalEncodingNode, slice::SliceNode,
+    softmax_cross_entropy_loss::SoftmaxCrossEntropyLossNode, split::SplitNode,
+    squeeze::SqueezeNode, squeeze_excitation::SqueezeExcitationNode, stack::StackNode,
+    sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode, unary::UnaryNode,
+    unsqueeze::UnsqueezeNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
-use burn::record::PrecisionSettings;
+use burn::{
+    module::{Param, ParamId},
+    record::PrecisionSettings,
+    tensor::{Tensor, TensorData},
+};
 use proc_macro2::TokenStream;
 use serde::Serialize;
 
 /// Backend used for serialization.
 pub type SerializationBackend = burn_ndarray::NdArray<f32>;
 
+/// Initializes a parameter of a record from its data, converted to the float precision of the
+/// settings.
+pub(crate) fn param<PS: PrecisionSettings, const D: usize>(
+    data: &TensorData,
+) -> Param<Tensor<SerializationBackend, D>> {
+    Param::initialized(
+        ParamId::new(),
+        Tensor::from_data(data.clone().convert::<PS::FloatElem>(), &Default::default()),
+    )
+}
+
 /// Codegen trait that should be implemented by all [node](Node) entries.
 pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
     /// All types that are used as inputs during the forward pass.
@@ -84,7 +107,9 @@ pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
 
 #[derive(Debug, Clone)]
 pub enum Node<PS: PrecisionSettings> {
+    AdaptiveMaxPool2d(AdaptiveMaxPool2dNode),
     ArgMax(ArgMaxNode),
+    Attention(AttentionNode),
     AvgPool1d(AvgPool1dNode),
     AvgPool2d(AvgPool2dNode),
     BatchNorm(BatchNormNode),
@@ -95,9 +120,12 @@ pub enum Node<PS: PrecisionSettings> {
     Conv1d(Conv1dNode),
     Conv2d(Conv2dNode),
     Conv3d(Conv3dNode),
+    ConvInteger(ConvIntegerNode),
     ConvTranspose1d(ConvTranspose1dNode),
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
+    ConvNeXtBlock(ConvNeXtBlockNode),
+    DepthwiseSeparableConv2d(DepthwiseSeparableConv2dNode),
     PRelu(PReluNode),
     Dropout(DropoutNode),
     Expand(ExpandNode),
@@ -106,6 +134,10 @@ pub enum Node<PS: PrecisionSettings> {
     GatherElements(GatherElementsNode),
     Gemm(GemmNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    Hardmax(HardmaxNode),
+    HingeEmbeddingLoss(HingeEmbeddingLossNode),
+    Identity(IdentityNode),
+    If(IfNode<PS>),
     LayerNorm(LayerNormNode),
     Linear(LinearNode),
     Matmul(MatmulNode),
@@ -113,12 +145,20 @@ pub enum Node<PS: PrecisionSettings> {
     MaxPool2d(MaxPool2dNode),
     Mean(MeanNode),
     OneHot(OneHotNode),
+    Optional(OptionalNode),
     Pad(PadNode),
     Range(RangeNode),
+    RelativePositionBias(RelativePositionBiasNode),
+    RepeatInterleave(RepeatInterleaveNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
+    ScatterElements(ScatterElementsNode),
+    SinusoidalPositionalEncoding(SinusoidalPositionalEncodingNode),
     Slice(SliceNode),
+    SoftmaxCrossEntropyLoss(SoftmaxCrossEntropyLossNode),
     Squeeze(SqueezeNode),
+    SqueezeExcitation(SqueezeExcitationNode),
+    Stack(StackNode),
     Split(SplitNode),
     Sum(SumNode),
     Tile(TileNode),
@@ -141,7 +181,9 @@ macro_rules! match_all {
     ($self:expr, $func:expr) => {{
         #[allow(clippy::redundant_closure_call)]
         match $self {
+            Node::AdaptiveMaxPool2d(node) => $func(node),
             Node::ArgMax(node) => $func(node),
+            Node::Attention(node) => $func(node),
             Node::AvgPool1d(node) => $func(node),
             Node::AvgPool2d(node) => $func(node),
             Node::BatchNorm(node) => $func(node),
@@ -152,9 +194,12 @@ macro_rules! match_all {
             Node::Conv1d(node) => $func(node),
             Node::Conv2d(node) => $func(node),
             Node::Conv3d(node) => $func(node),
+            Node::ConvInteger(node) => $func(node),
             Node::ConvTranspose1d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
+            Node::ConvNeXtBlock(node) => $func(node),
+            Node::DepthwiseSeparableConv2d(node) => $func(node),
             Node::PRelu(node) => $func(node),
             Node::Dropout(node) => $func(node),
             Node::Expand(node) => $func(node),
@@ -163,6 +208,10 @@ macro_rules! match_all {
             Node::GatherElements(node) => $func(node),
             Node::Gemm(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::Hardmax(node) => $func(node),
+            Node::HingeEmbeddingLoss(node) => $func(node),
+            Node::Identity(node) => $func(node),
+            Node::If(node) => $func(node),
             Node::LayerNorm(node) => $func(node),
             Node::Linear(node) => $func(node),
             Node::Matmul(node) => $func(node),
@@ -170,12 +219,20 @@ macro_rules! match_all {
             Node::MaxPool2d(node) => $func(node),
             Node::Mean(node) => $func(node),
             Node::OneHot(node) => $func(node),
+            Node::Optional(node) => $func(node),
             Node::Pad(node) => $func(node),
             Node::Range(node) => $func(node),
+            Node::RelativePositionBias(node) => $func(node),
+            Node::RepeatInterleave(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
+            Node::ScatterElements(node) => $func(node),
+            Node::SinusoidalPositionalEncoding(node) => $func(node),
             Node::Slice(node) => $func(node),
+            Node::SoftmaxCrossEntropyLoss(node) => $func(node),
             Node::Squeeze(node) => $func(node),
+            Node::SqueezeExcitation(node) => $func(node),
+            Node::Stack(node) => $func(node),
             Node::Sum(node) => $func(node),
             Node::Tile(node) => $func(node),
             Node::TopK(node) => $func(node),
@@ -206,7 +263,9 @@ impl<PS: PrecisionSettings> Serialize for Node<PS> {
 impl<PS: PrecisionSettings> Node<PS> {
     pub fn name(&self) -> &str {
         match self {
+            Node::AdaptiveMaxPool2d(_) => "adaptive_max_pool2d",
             Node::ArgMax(_) => "argmax",
+            Node::Attention(_) => "attention",
             Node::AvgPool1d(_) => "avg_pool1d",
             Node::AvgPool2d(_) => "avg_pool2d",
             Node::BatchNorm(_) => "batch_norm",
@@ -217,9 +276,12 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv1d(_) => "conv1d",
             Node::Conv2d(_) => "conv2d",
             Node::Conv3d(_) => "conv3d",
+            Node::ConvInteger(_) => "conv_integer",
             Node::ConvTranspose1d(_) => "conv_transpose1d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
+            Node::ConvNeXtBlock(_) => "convnext_block",
+            Node::DepthwiseSeparableConv2d(_) => "depthwise_separable_conv2d",
             Node::PRelu(_) => "prelu",
             Node::Dropout(_) => "dropout",
             Node::Expand(_) => "expand",
@@ -228,6 +290,10 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::GatherElements(_) => "gather_elements",
             Node::Gemm(_) => "gemm",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::Hardmax(_) => "hardmax",
+            Node::HingeEmbeddingLoss(_) => "hinge_embedding_loss",
+            Node::Identity(_) => "identity",
+            Node::If(_) => "if",
             Node::LayerNorm(_) => "layer_norm",
             Node::Linear(_) => "linear",
             Node::Matmul(_) => "matmul",
@@ -235,12 +301,20 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Mean(_) => "mean",
             Node::OneHot(_) => "one_hot",
+            Node::Optional(optional) => optional.kind.as_str(),
             Node::Pad(_) => "pad",
             Node::Range(_) => "range",
+            Node::RelativePositionBias(_) => "relative_position_bias",
+            Node::RepeatInterleave(_) => "repeat_interleave",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
+            Node::ScatterElements(_) => "scatter_elements",
+            Node::SinusoidalPositionalEncoding(_) => "sinusoidal_positional_encoding",
             Node::Slice(_) => "slice",
+            Node::SoftmaxCrossEntropyLoss(_) => "softmax_cross_entropy_loss",
             Node::Squeeze(_) => "squeeze",
+            Node::SqueezeExcitation(_) => "squeeze_excitation",
+            Node::Stack(_) => "stack",
             Node::Sum(_) => "add",
             Node::Tile(_) => "tile",
             Node::TopK(_) => "top_k",
@@ -257,6 +331,17 @@ impl<PS: PrecisionSettings> Node<PS> {
             _ => unimplemented!(),
         }
     }
+
+    /// The node followed by the nodes nested in it, e.g. the nodes of the branches of an
+    /// [If](IfNode) node.
+    pub fn flatten(&self) -> Vec<&Node<PS>> {
+        let mut nodes = vec![self];
+        if let Node::If(node) = self {
+            let branches = node.then_branch.nodes.iter().chain(&node.else_branch.nodes);
+            nodes.extend(branches.flat_map(Node::flatten));
+        }
+        nodes
+    }
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for Node<PS> {
@@ -365,6 +450,7 @@ pub(crate) mod tests {
         graph.register(MatmulNode::new(
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 4),
+            None,
             TensorType::new_float("tensor3", 4),
         ));
         graph.register(Conv2dNode::new(
@@ -431,6 +517,92 @@ pub(crate) mod tests {
         assert_tokens(graph.codegen(), expected);
     }
 
+    #[test]
+    fn test_codegen_intermediates() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            None,
+            TensorType::new_float("tensor3", 4),
+        ));
+        graph.register(Conv2dNode::new(
+            "conv2d",
+            TensorType::new_float("tensor3", 4),
+            TensorType::new_float("tensor4", 4),
+            TensorData::from([2f32]),
+            None,
+            Conv2dConfig::new([3, 3], [3, 3]).with_padding(PaddingConfig2d::Valid),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor4".to_string()],
+        );
+        let graph = graph.with_intermediates(&["tensor3".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::conv::Conv2dConfig;
+            use burn::nn::conv::Conv2d;
+            use burn::nn::PaddingConfig2d;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                conv2d: Conv2d<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let conv2d = Conv2dConfig::new([3, 3], [3, 3])
+                        .with_stride([1, 1])
+                        .with_padding(PaddingConfig2d::Valid)
+                        .with_dilation([1, 1])
+                        .with_groups(1)
+                        .with_bias(true)
+                        .init(device);
+
+                    Self {
+                        conv2d,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    tensor2: Tensor<B, 4>
+                ) -> Tensor<B, 4> {
+                    let tensor3 = tensor1.matmul(tensor2);
+                    let tensor4 = self.conv2d.forward(tensor3);
+
+                    tensor4
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward_with_intermediates(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    tensor2: Tensor<B, 4>
+                ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+                    let tensor3 = tensor1.matmul(tensor2);
+                    let tensor4 = self.conv2d.forward(tensor3.clone());
+
+                    (tensor4, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
     #[test]
     fn test_codegen_clone_tensor() {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
@@ -438,6 +610,7 @@ pub(crate) mod tests {
         graph.register(MatmulNode::new(
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 4),
+            None,
             TensorType::new_float("tensor3", 4),
         ));
         graph.register(Conv2dNode::new(
@@ -451,6 +624,7 @@ pub(crate) mod tests {
         graph.register(MatmulNode::new(
             TensorType::new_float("tensor3", 4),
             TensorType::new_float("tensor4", 4),
+            None,
             TensorType::new_float("output", 4),
         ));
 
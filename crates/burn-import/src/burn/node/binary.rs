@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, Type};
+use crate::burn::{BurnImports, Scope, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -20,6 +20,9 @@ pub enum BinaryType {
     GreaterOrEqual,
     Less,
     LessOrEqual,
+    And,
+    Or,
+    Xor,
 }
 
 impl BinaryType {
@@ -38,6 +41,9 @@ impl BinaryType {
             BinaryType::GreaterOrEqual => "greater_equal",
             BinaryType::Less => "lower",
             BinaryType::LessOrEqual => "lower_equal",
+            BinaryType::And => "logical_and",
+            BinaryType::Or => "logical_or",
+            BinaryType::Xor => "logical_xor",
         }
     }
 }
@@ -87,7 +93,11 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
-            _ => panic!("lhs must be a tensor or scalar"),
+            Type::Shape(shape) => {
+                let name = shape.name.clone();
+                quote! { #name }
+            }
+            _ => panic!("lhs must be a tensor, scalar or shape"),
         };
 
         // Get the rhs name in the form of token stream
@@ -97,7 +107,11 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
-            _ => panic!("rhs must be a tensor or scalar"),
+            Type::Shape(shape) => {
+                let name = shape.name.clone();
+                quote! { #name }
+            }
+            _ => panic!("rhs must be a tensor, scalar or shape"),
         };
 
         let output = &self.output.name();
@@ -111,10 +125,65 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
     fn into_node(self) -> Node<PS> {
         Node::Binary(self)
     }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        // Logical operators on tensors produce boolean tensors.
+        if matches!(
+            self.binary_type,
+            BinaryType::And | BinaryType::Or | BinaryType::Xor
+        ) && matches!(self.output, Type::Tensor(_))
+        {
+            imports.register("burn::tensor::Bool");
+        }
+    }
 }
 
 impl BinaryNode {
+    /// Arithmetic on shapes, with a scalar or another shape, emitted as array arithmetic so the
+    /// result stays a shape instead of a tensor.
+    ///
+    /// The dims are computed as signed integers, and a negative result panics instead of
+    /// wrapping around.
+    fn shape_arithmetic(lhs: &Type, rhs: &Type, op: fn() -> TokenStream) -> Option<FnPointer> {
+        fn dim(value: TokenStream) -> TokenStream {
+            quote! {
+                usize::try_from(#value).expect("Shape arithmetic should produce non-negative dims")
+            }
+        }
+
+        let function: FnPointer = match (lhs, rhs) {
+            (Type::Shape(shape), Type::Shape(_)) => {
+                let rank = shape.rank;
+                Arc::new(move |lhs, rhs| {
+                    let (rank, op) = (rank.to_tokens(), op());
+                    let dim = dim(quote! { #lhs[i] as i64 #op #rhs[i] as i64 });
+                    quote! { core::array::from_fn::<usize, #rank, _>(|i| #dim) }
+                })
+            }
+            (Type::Shape(_), Type::Scalar(_)) => Arc::new(move |lhs, rhs| {
+                let op = op();
+                let dim = dim(quote! { dim as i64 #op #rhs as i64 });
+                quote! { #lhs.map(|dim| #dim) }
+            }),
+            (Type::Scalar(_), Type::Shape(_)) => Arc::new(move |lhs, rhs| {
+                let op = op();
+                let dim = dim(quote! { #lhs as i64 #op dim as i64 });
+                quote! { #rhs.map(|dim| #dim) }
+            }),
+            (Type::Shape(_), _) | (_, Type::Shape(_)) => {
+                panic!("Shape arithmetic is supported with a scalar or a shape only")
+            }
+            _ => return None,
+        };
+
+        Some(function)
+    }
+
     pub(crate) fn add(lhs: Type, rhs: Type, output: Type) -> Self {
+        if let Some(function) = Self::shape_arithmetic(&lhs, &rhs, || quote! { + }) {
+            return Self::new(lhs, rhs, output, BinaryType::Add, function);
+        }
+
         let function = match (&lhs, &rhs) {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.add(#rhs) },
             (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.add_scalar(#rhs) },
@@ -127,6 +196,10 @@ impl BinaryNode {
     }
 
     pub(crate) fn sub(lhs: Type, rhs: Type, output: Type) -> Self {
+        if let Some(function) = Self::shape_arithmetic(&lhs, &rhs, || quote! { - }) {
+            return Self::new(lhs, rhs, output, BinaryType::Sub, function);
+        }
+
         let function = match (&lhs, &rhs) {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.sub(#rhs) },
             (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.sub_scalar(#rhs) },
@@ -139,6 +212,10 @@ impl BinaryNode {
     }
 
     pub(crate) fn mul(lhs: Type, rhs: Type, output: Type) -> Self {
+        if let Some(function) = Self::shape_arithmetic(&lhs, &rhs, || quote! { * }) {
+            return Self::new(lhs, rhs, output, BinaryType::Mul, function);
+        }
+
         let function = match (&lhs, &rhs) {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.mul(#rhs) },
             (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.mul_scalar(#rhs) },
@@ -151,6 +228,10 @@ impl BinaryNode {
     }
 
     pub(crate) fn div(lhs: Type, rhs: Type, output: Type) -> Self {
+        if let Some(function) = Self::shape_arithmetic(&lhs, &rhs, || quote! { / }) {
+            return Self::new(lhs, rhs, output, BinaryType::Div, function);
+        }
+
         let function = match (&lhs, &rhs) {
             (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.div(#rhs) },
             (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.div_scalar(#rhs) },
@@ -274,6 +355,33 @@ impl BinaryNode {
             Arc::new(function),
         )
     }
+
+    pub(crate) fn logical_and(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.logical_and(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs && #rhs },
+            (lhs, rhs) => panic!("logical_and is not supported for {lhs:?} and {rhs:?}"),
+        };
+        Self::new(lhs, rhs, output, BinaryType::And, Arc::new(function))
+    }
+
+    pub(crate) fn logical_or(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.logical_or(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs || #rhs },
+            (lhs, rhs) => panic!("logical_or is not supported for {lhs:?} or {rhs:?}"),
+        };
+        Self::new(lhs, rhs, output, BinaryType::Or, Arc::new(function))
+    }
+
+    pub(crate) fn logical_xor(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.logical_xor(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs ^ #rhs },
+            (lhs, rhs) => panic!("logical_xor is not supported for {lhs:?} xor {rhs:?}"),
+        };
+        Self::new(lhs, rhs, output, BinaryType::Xor, Arc::new(function))
+    }
 }
 
 #[cfg(test)]
@@ -285,7 +393,7 @@ mod tests {
     use crate::burn::graph::BurnGraph;
     use crate::burn::node::test::assert_tokens;
     use crate::burn::node::tests::one_node_graph;
-    use crate::burn::{ScalarKind, ScalarType, TensorType};
+    use crate::burn::{ScalarKind, ScalarType, ShapeType, TensorType};
 
     macro_rules! test_binary_operator_on_tensors {
     ($operator:ident) => {{
@@ -429,6 +537,75 @@ mod tests {
         test_binary_operator_on_scalar_and_scalar!(div, /);
     }
 
+    #[test]
+    fn test_binary_codegen_mul_shape_scalar() {
+        one_node_graph(
+            BinaryNode::mul(
+                Type::Shape(ShapeType::new("shape1", 3)),
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+                Type::Shape(ShapeType::new("shape2", 3)),
+            ),
+            quote! {
+                pub fn forward(&self, shape1: [usize; 3], scalar1: i64) -> [usize; 3] {
+                    let shape2 = shape1.map(|dim| {
+                        usize::try_from(dim as i64 * scalar1 as i64)
+                            .expect("Shape arithmetic should produce non-negative dims")
+                    });
+
+                    shape2
+                }
+            },
+            vec!["shape1".to_string(), "scalar1".to_string()],
+            vec!["shape2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_binary_codegen_sub_scalar_shape() {
+        one_node_graph(
+            BinaryNode::sub(
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+                Type::Shape(ShapeType::new("shape1", 2)),
+                Type::Shape(ShapeType::new("shape2", 2)),
+            ),
+            quote! {
+                pub fn forward(&self, scalar1: i64, shape1: [usize; 2]) -> [usize; 2] {
+                    let shape2 = shape1.map(|dim| {
+                        usize::try_from(scalar1 as i64 - dim as i64)
+                            .expect("Shape arithmetic should produce non-negative dims")
+                    });
+
+                    shape2
+                }
+            },
+            vec!["scalar1".to_string(), "shape1".to_string()],
+            vec!["shape2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_binary_codegen_add_shapes() {
+        one_node_graph(
+            BinaryNode::add(
+                Type::Shape(ShapeType::new("shape1", 2)),
+                Type::Shape(ShapeType::new("shape2", 2)),
+                Type::Shape(ShapeType::new("shape3", 2)),
+            ),
+            quote! {
+                pub fn forward(&self, shape1: [usize; 2], shape2: [usize; 2]) -> [usize; 2] {
+                    let shape3 = core::array::from_fn::<usize, 2, _>(|i| {
+                        usize::try_from(shape1[i] as i64 + shape2[i] as i64)
+                            .expect("Shape arithmetic should produce non-negative dims")
+                    });
+
+                    shape3
+                }
+            },
+            vec!["shape1".to_string(), "shape2".to_string()],
+            vec!["shape3".to_string()],
+        );
+    }
+
     #[test]
     fn test_binary_codegen_min() {
         test_binary_operator_on_tensors!(min_pair);
@@ -537,4 +714,80 @@ mod tests {
     fn test_binary_codegen_equal_scalars() {
         test_binary_operator_on_scalar_and_scalar!(equal, ==);
     }
+
+    macro_rules! test_binary_operator_on_bool_tensors {
+        ($operator:ident) => {{
+            one_node_graph(
+                BinaryNode::$operator(
+                    Type::Tensor(TensorType::new_bool("tensor1", 4)),
+                    Type::Tensor(TensorType::new_bool("tensor2", 4)),
+                    Type::Tensor(TensorType::new_bool("tensor3", 4)),
+                ),
+                quote! {
+                    pub fn forward(
+                        &self,
+                        tensor1: Tensor<B, 4, Bool>,
+                        tensor2: Tensor<B, 4, Bool>
+                    ) -> Tensor<B, 4, Bool> {
+                        let tensor3 = tensor1.$operator(tensor2);
+
+                        tensor3
+                    }
+                },
+                vec!["tensor1".to_string(), "tensor2".to_string()],
+                vec!["tensor3".to_string()],
+            );
+        }};
+    }
+
+    macro_rules! test_binary_operator_on_bool_scalars {
+        ($operator:ident, $scalar_operator:tt) => {{
+            one_node_graph(
+                BinaryNode::$operator(
+                    Type::Scalar(ScalarType::new("scalar1", ScalarKind::Bool)),
+                    Type::Scalar(ScalarType::new("scalar2", ScalarKind::Bool)),
+                    Type::Scalar(ScalarType::new("scalar3", ScalarKind::Bool)),
+                ),
+                quote! {
+                    pub fn forward(&self, scalar1: bool, scalar2: bool) -> bool {
+                        let scalar3 = scalar1 $scalar_operator scalar2;
+
+                        scalar3
+                    }
+                },
+                vec!["scalar1".to_string(), "scalar2".to_string()],
+                vec!["scalar3".to_string()],
+            );
+        }};
+    }
+
+    #[test]
+    fn test_binary_codegen_logical_and() {
+        test_binary_operator_on_bool_tensors!(logical_and);
+    }
+
+    #[test]
+    fn test_binary_codegen_logical_and_scalars() {
+        test_binary_operator_on_bool_scalars!(logical_and, &&);
+    }
+
+    #[test]
+    fn test_binary_codegen_logical_or() {
+        test_binary_operator_on_bool_tensors!(logical_or);
+    }
+
+    #[test]
+    fn test_binary_codegen_logical_or_scalars() {
+        test_binary_operator_on_bool_scalars!(logical_or, ||);
+    }
+
+    #[test]
+    fn test_binary_codegen_logical_xor() {
+        test_binary_operator_on_bool_tensors!(logical_xor);
+    }
+
+    #[test]
+    fn test_binary_codegen_logical_xor_scalars() {
+        test_binary_operator_on_bool_scalars!(logical_xor, ^);
+    }
 }
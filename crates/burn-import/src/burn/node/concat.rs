@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ToTokens, Type};
 
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
@@ -7,31 +7,84 @@ use quote::quote;
 
 #[derive(Debug, Clone, new)]
 pub struct ConcatNode {
-    pub inputs: Vec<TensorType>,
-    pub output: TensorType,
+    pub inputs: Vec<Type>,
+    pub output: Type,
     pub dim: usize,
+    /// The shape each input is expanded to before the concatenation, if it has a size-1
+    /// dimension broadcast against the other inputs.
+    #[new(default)]
+    pub expand_shapes: Vec<Option<Vec<usize>>>,
+}
+
+impl ConcatNode {
+    /// Expands the inputs to the given shapes before the concatenation.
+    pub fn with_expand_shapes(mut self, expand_shapes: Vec<Option<Vec<usize>>>) -> Self {
+        self.expand_shapes = expand_shapes;
+        self
+    }
+
+    /// Concatenates shapes, and scalars as single dimensions, into a new shape array so the
+    /// result can be consumed by Reshape or Expand without a round trip through a tensor.
+    fn forward_shapes(&self, rank: usize) -> TokenStream {
+        let output = &self.output.name();
+        let rank = rank.to_tokens();
+
+        let mut start = 0;
+        let copies = self.inputs.iter().map(|input| match input {
+            Type::Shape(shape) => {
+                let name = &shape.name;
+                let (from, to) = (start.to_tokens(), (start + shape.rank).to_tokens());
+                start += shape.rank;
+                quote! { #output[#from..#to].copy_from_slice(&#name); }
+            }
+            Type::Scalar(scalar) => {
+                let name = &scalar.name;
+                let index = start.to_tokens();
+                start += 1;
+                quote! { #output[#index] = #name as usize; }
+            }
+            _ => panic!("Concat of shapes needs shape or scalar inputs, got {input:?}"),
+        });
+        let copies: Vec<_> = copies.collect();
+
+        quote! {
+            let mut #output: [usize; #rank] = [0; #rank];
+            #(#copies)*
+        }
+    }
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for ConcatNode {
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        vec![self.output.clone()]
     }
 
     fn input_types(&self) -> Vec<Type> {
-        self.inputs
-            .iter()
-            .map(|t| Type::Tensor(t.clone()))
-            .collect()
+        self.inputs.clone()
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        if let Type::Shape(output) = &self.output {
+            return self.forward_shapes(output.rank);
+        }
+
         let dim = self.dim.to_tokens();
-        let inputs = self
-            .inputs
-            .iter()
-            .map(|t| scope.tensor_use_owned(t, node_position));
+        let inputs = self.inputs.iter().enumerate().map(|(i, t)| {
+            let Type::Tensor(t) = t else {
+                panic!("Concat needs tensor inputs when the output is a tensor, got {t:?}");
+            };
+            let input = scope.tensor_use_owned(t, node_position);
+
+            match self.expand_shapes.get(i).cloned().flatten() {
+                Some(shape) => {
+                    let shape = shape.to_tokens();
+                    quote! { #input.expand(#shape) }
+                }
+                None => input,
+            }
+        });
 
-        let output = &self.output.name;
+        let output = &self.output.name();
 
         quote! {
             let #output = burn::tensor::Tensor::cat([#(#inputs),*].into(), #dim);
@@ -50,7 +103,7 @@ mod tests {
 
     use super::*;
     use crate::burn::{
-        TensorType,
+        ScalarKind, ScalarType, ShapeType, TensorType,
         graph::BurnGraph,
         node::{concat::ConcatNode, test::assert_tokens},
     };
@@ -61,10 +114,10 @@ mod tests {
 
         graph.register(ConcatNode::new(
             vec![
-                TensorType::new_float("tensor1", 4),
-                TensorType::new_float("tensor2", 4),
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
             ],
-            TensorType::new_float("tensor3", 4),
+            Type::Tensor(TensorType::new_float("tensor3", 4)),
             1,
         ));
 
@@ -109,4 +162,118 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_concat_broadcast() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(
+            ConcatNode::new(
+                vec![
+                    Type::Tensor(TensorType::new_float("tensor1", 3)),
+                    Type::Tensor(TensorType::new_float("tensor2", 3)),
+                ],
+                Type::Tensor(TensorType::new_float("tensor3", 3)),
+                1,
+            )
+            .with_expand_shapes(vec![Some(vec![2, 1, 3]), None]),
+        );
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    tensor2: Tensor<B, 3>
+                ) -> Tensor<B, 3> {
+                    let tensor3 = burn::tensor::Tensor::cat(
+                        [tensor1.expand([2, 1, 3]), tensor2].into(),
+                        1
+                    );
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_concat_shapes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ConcatNode::new(
+            vec![
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+                Type::Shape(ShapeType::new("shape1", 2)),
+            ],
+            Type::Shape(ShapeType::new("shape2", 3)),
+            0,
+        ));
+
+        graph.register_input_output(
+            vec!["scalar1".to_string(), "shape1".to_string()],
+            vec!["shape2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, scalar1: i64, shape1: [usize; 2]) -> [usize; 3] {
+                    let mut shape2: [usize; 3] = [0; 3];
+                    shape2[0] = scalar1 as usize;
+                    shape2[1..3].copy_from_slice(&shape1);
+
+                    shape2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
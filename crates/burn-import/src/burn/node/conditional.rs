@@ -0,0 +1,251 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The nodes of a branch of an [If](IfNode) and the outputs they produce.
+#[derive(Debug, Clone, new)]
+pub struct IfBranch<PS: PrecisionSettings> {
+    pub nodes: Vec<Node<PS>>,
+    pub outputs: Vec<Type>,
+}
+
+/// Node for the ONNX `If` operator, running one of two subgraphs depending on a boolean condition.
+///
+/// The nodes of the branches are generated inline in an `if cond { ... } else { ... }` block,
+/// while their fields are declared on the model like the ones of any other node.
+#[derive(Debug, Clone, new)]
+pub struct IfNode<PS: PrecisionSettings> {
+    pub condition: Type,
+    /// The values of the enclosing graph used by the branches.
+    pub captured: Vec<Type>,
+    pub then_branch: IfBranch<PS>,
+    pub else_branch: IfBranch<PS>,
+    pub outputs: Vec<Type>,
+}
+
+impl<PS: PrecisionSettings> IfBranch<PS> {
+    fn codegen(&self, parent: &Scope, captured: &[(TensorType, bool)]) -> TokenStream {
+        let mut scope = parent.nested();
+
+        // The captured tensors still used after the If node must be cloned by the branch
+        for (tensor, used_after) in captured {
+            scope.tensor_register_variable(tensor, 0);
+            if *used_after {
+                scope.tensor_register_future_use(tensor, self.nodes.len());
+            }
+        }
+
+        for (node_position, node) in self.nodes.iter().enumerate() {
+            for tensor in node.output_types().iter().filter_map(as_tensor) {
+                scope.tensor_register_variable(tensor, node_position + 1);
+            }
+            for tensor in node.input_types().iter().filter_map(as_tensor) {
+                scope.tensor_register_future_use(tensor, node_position);
+            }
+        }
+        for tensor in self.outputs.iter().filter_map(as_tensor) {
+            scope.tensor_register_future_use(tensor, self.nodes.len());
+        }
+
+        let mut body = quote! {};
+        for (node_position, node) in self.nodes.iter().enumerate() {
+            body.extend(node.forward(&mut scope, node_position));
+        }
+
+        let outputs = self.outputs.iter().map(Type::name);
+        let output = match self.outputs.len() {
+            1 => quote! { #(#outputs)* },
+            _ => quote! { (#(#outputs),*) },
+        };
+
+        quote! {
+            #body
+            #output
+        }
+    }
+}
+
+fn as_tensor(ty: &Type) -> Option<&TensorType> {
+    match ty {
+        Type::Tensor(tensor) => Some(tensor),
+        _ => None,
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for IfNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![self.condition.clone()];
+        inputs.extend(self.captured.iter().cloned());
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let condition = match &self.condition {
+            Type::Scalar(scalar) => {
+                let name = &scalar.name;
+                quote! { #name }
+            }
+            Type::Tensor(tensor) => {
+                let tensor = scope.tensor_use_owned(tensor, node_position);
+                quote! { #tensor.into_scalar().elem::<bool>() }
+            }
+            _ => panic!("If: the condition must be a boolean scalar or tensor"),
+        };
+
+        let captured: Vec<_> = self
+            .captured
+            .iter()
+            .filter_map(as_tensor)
+            .map(|tensor| {
+                let used_after = scope.tensor_use_nested(tensor, node_position);
+                (tensor.clone(), used_after)
+            })
+            .collect();
+        let then_branch = self.then_branch.codegen(scope, &captured);
+        let else_branch = self.else_branch.codegen(scope, &captured);
+
+        let outputs = self.outputs.iter().map(Type::name);
+        let output = match self.outputs.len() {
+            1 => quote! { #(#outputs)* },
+            _ => quote! { (#(#outputs),*) },
+        };
+
+        quote! {
+            let #output = if #condition {
+                #then_branch
+            } else {
+                #else_branch
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if matches!(self.condition, Type::Tensor(_)) {
+            imports.register("burn::tensor::ElementConversion");
+        }
+
+        let branches = [&self.then_branch, &self.else_branch];
+        for node in branches.iter().flat_map(|branch| branch.nodes.iter()) {
+            node.register_imports(imports);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::If(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::{record::FullPrecisionSettings, tensor::TensorData};
+
+    use super::*;
+    use crate::burn::{
+        ScalarKind, ScalarType, TensorType,
+        graph::BurnGraph,
+        node::{binary::BinaryNode, linear::LinearNode, test::assert_tokens},
+    };
+    use burn::nn::LinearConfig;
+
+    fn linear(name: &str, input: &str, output: &str) -> Node<FullPrecisionSettings> {
+        LinearNode::new(
+            name,
+            TensorType::new_float(input, 2),
+            TensorType::new_float(output, 2),
+            TensorData::from([[1f32, 2.0], [3.0, 4.0]]),
+            None,
+            LinearConfig::new(2, 2).with_bias(false),
+        )
+        .into_node()
+    }
+
+    #[test]
+    fn test_codegen_if() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        let then_branch = IfBranch::new(
+            vec![linear("linear1", "x", "linear1_out1")],
+            vec![Type::Tensor(TensorType::new_float("linear1_out1", 2))],
+        );
+        let else_branch = IfBranch::new(
+            vec![linear("linear2", "x", "linear2_out1")],
+            vec![Type::Tensor(TensorType::new_float("linear2_out1", 2))],
+        );
+        graph.register(IfNode::new(
+            Type::Scalar(ScalarType::new("cond", ScalarKind::Bool)),
+            vec![Type::Tensor(TensorType::new_float("x", 2))],
+            then_branch,
+            else_branch,
+            vec![Type::Tensor(TensorType::new_float("y", 2))],
+        ));
+        graph.register(BinaryNode::add(
+            Type::Tensor(TensorType::new_float("y", 2)),
+            Type::Tensor(TensorType::new_float("x", 2)),
+            Type::Tensor(TensorType::new_float("output", 2)),
+        ));
+
+        graph.register_input_output(
+            vec!["cond".to_string(), "x".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::nn::Linear;
+            use burn::nn::LinearConfig;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                linear1: Linear<B>,
+                linear2: Linear<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let linear1 = LinearConfig::new(2, 2)
+                        .with_bias(false)
+                        .init(device);
+                    let linear2 = LinearConfig::new(2, 2)
+                        .with_bias(false)
+                        .init(device);
+
+                    Self {
+                        linear1,
+                        linear2,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, cond: bool, x: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let y = if cond {
+                        let linear1_out1 = self.linear1.forward(x.clone());
+                        linear1_out1
+                    } else {
+                        let linear2_out1 = self.linear2.forward(x.clone());
+                        linear2_out1
+                    };
+                    let output = y.add(x);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -161,14 +161,16 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode {
         }
     }
 
-    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+    fn forward(&self, scope: &mut Scope, _node_position: usize) -> TokenStream {
         let name = Ident::new(self.name.as_ref(), Span::call_site());
         let output = self.output.name();
 
         match &self.value {
             ConstantValue::Tensor(_, _) => {
+                let field = scope.field_use(&name);
+
                 quote! {
-                    let #output = self.#name.val();
+                    let #output = #field.val();
                 }
             }
             _ => {
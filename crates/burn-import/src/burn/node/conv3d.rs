@@ -109,10 +109,10 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv3dNode {
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let field = &self.field.name;
+        let field = scope.field_use(&self.field.name);
 
         quote! {
-            let #output = self.#field.forward(#input);
+            let #output = #field.forward(#input);
         }
     }
     fn register_imports(&self, imports: &mut BurnImports) {
@@ -0,0 +1,318 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ParamId,
+    nn::{PaddingConfig2d, conv::Conv2dConfig},
+    record::{ParamSerde, PrecisionSettings},
+    tensor::TensorData,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Integer 2D convolution, accumulating the products of the zero point shifted input and weights
+/// in an int32 output.
+///
+/// The weights are shifted at import time and the multiply-accumulate runs on int tensors, one
+/// kernel position at a time, so the accumulator is exact whatever the float precision settings.
+///
+/// Burn has no integer convolution: `conv2d` only takes float tensors, whose f32 accumulator
+/// loses precision past 2^24, and the quantized tensor ops don't provide a convolution either, so
+/// the products are gathered with `select` and summed on int tensors instead. This costs one
+/// gather and one broadcast multiply per kernel position rather than a single kernel launch.
+#[derive(Debug, Clone)]
+pub struct ConvIntegerNode {
+    pub field: TensorType,
+    pub input: TensorType,
+    pub output: TensorType,
+    /// The weights, with the weight zero point already subtracted.
+    pub data_weights: TensorData,
+    pub input_zero_point: i32,
+    pub config: Conv2dConfig,
+}
+
+impl ConvIntegerNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        data_weights: TensorData,
+        input_zero_point: i32,
+        config: Conv2dConfig,
+    ) -> Self {
+        Self {
+            field: TensorType::new_int(name, 4),
+            input,
+            output,
+            data_weights,
+            input_zero_point,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ConvIntegerNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Tensor(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let ty = self.field.ty();
+        let shape = self.data_weights.shape.to_tokens();
+
+        let tokens = quote! {
+            let #name: burn::module::Param<#ty> = burn::module::Param::uninitialized(
+                burn::module::ParamId::new(),
+                move |device, _require_grad| Tensor::<B, 4, Int>::zeros(#shape, &device),
+                device.clone(),
+                false
+            );
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = self.data_weights.clone().convert::<PS::IntElem>();
+        let data = ParamSerde::new(ParamId::new().to_string(), data);
+
+        data.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = scope.field_use(&self.field.name);
+
+        let [
+            out_channels,
+            channels_per_group,
+            kernel_height,
+            kernel_width,
+        ] = [0, 1, 2, 3].map(|dim| self.data_weights.shape[dim]);
+        let [dilation_height, dilation_width] = self.config.dilation;
+        let span_height = (dilation_height * (kernel_height - 1) + 1).to_tokens();
+        let span_width = (dilation_width * (kernel_width - 1) + 1).to_tokens();
+        let out_channels_per_group = (out_channels / self.config.groups).to_tokens();
+        let groups = self.config.groups.to_tokens();
+        let [
+            out_channels,
+            channels_per_group,
+            kernel_height,
+            kernel_width,
+        ] = [
+            out_channels,
+            channels_per_group,
+            kernel_height,
+            kernel_width,
+        ]
+        .map(|dim| dim.to_tokens());
+        let dilation = self.config.dilation.to_tokens();
+        let stride = self.config.stride.to_tokens();
+        let padding = match self.config.padding {
+            PaddingConfig2d::Valid => quote! { (0, 0, 0, 0) },
+            PaddingConfig2d::Explicit(height, width) => {
+                let [height, width] = [height, width].map(|dim| dim.to_tokens());
+                quote! { (#width, #width, #height, #height) }
+            }
+            PaddingConfig2d::Same => panic!("ConvInteger: same padding is not supported"),
+        };
+
+        // Padding with zeros after the shift is padding with the zero point, as ONNX does
+        let input = match self.input_zero_point {
+            0 => quote! { #input },
+            zero_point => quote! { #input.sub_scalar(#zero_point) },
+        };
+
+        // For each kernel position, the strided input positions are multiplied with the weights
+        // of every group and summed over the input channels of the group.
+        quote! {
+            let #output = {
+                let input = #input.pad(#padding, 0);
+                let [stride_height, stride_width] = #stride;
+                let [dilation_height, dilation_width] = #dilation;
+                let device = input.device();
+                let [batch_size, _, height, width] = input.dims();
+                let out_height = (height - #span_height) / stride_height + 1;
+                let out_width = (width - #span_width) / stride_width + 1;
+                let weight = #field.val();
+
+                let mut output = Tensor::<B, 6, Int>::zeros(
+                    [batch_size, #groups, #out_channels_per_group, 1, out_height, out_width],
+                    &device,
+                );
+                for kernel_row in 0..#kernel_height {
+                    for kernel_col in 0..#kernel_width {
+                        let row = (kernel_row * dilation_height) as i64;
+                        let col = (kernel_col * dilation_width) as i64;
+                        let rows = Tensor::arange_step(
+                            row..row + (stride_height * (out_height - 1) + 1) as i64,
+                            stride_height,
+                            &device,
+                        );
+                        let cols = Tensor::arange_step(
+                            col..col + (stride_width * (out_width - 1) + 1) as i64,
+                            stride_width,
+                            &device,
+                        );
+                        let input = input.clone().select(2, rows).select(3, cols).reshape([
+                            batch_size,
+                            #groups,
+                            1,
+                            #channels_per_group,
+                            out_height,
+                            out_width,
+                        ]);
+                        let weight = weight
+                            .clone()
+                            .slice([
+                                0..#out_channels,
+                                0..#channels_per_group,
+                                kernel_row..kernel_row + 1,
+                                kernel_col..kernel_col + 1,
+                            ])
+                            .reshape([1, #groups, #out_channels_per_group, #channels_per_group, 1, 1]);
+                        output = output + input.mul(weight).sum_dim(3);
+                    }
+                }
+
+                output.reshape([batch_size, #out_channels, out_height, out_width])
+            };
+        }
+    }
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::Int");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ConvInteger(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{conv_integer::ConvIntegerNode, test::assert_tokens},
+    };
+    use burn::{nn::PaddingConfig2d, nn::conv::Conv2dConfig, record::FullPrecisionSettings};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ConvIntegerNode::new(
+            "conv_integer",
+            TensorType::new_int("input", 4),
+            TensorType::new_int("output", 4),
+            TensorData::new(vec![2i32; 36], [4, 1, 3, 3]),
+            128,
+            Conv2dConfig::new([2, 4], [3, 3])
+                .with_stride([2, 1])
+                .with_padding(PaddingConfig2d::Explicit(1, 0))
+                .with_groups(2),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                conv_integer: burn::module::Param<Tensor<B, 4, Int>>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let conv_integer: burn::module::Param<Tensor<B, 4, Int>> = burn::module::Param::uninitialized(
+                        burn::module::ParamId::new(),
+                        move |device, _require_grad| Tensor::<B, 4, Int>::zeros([4, 1, 3, 3], &device),
+                        device.clone(),
+                        false
+                    );
+
+                    Self {
+                        conv_integer,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4, Int>) -> Tensor<B, 4, Int> {
+                    let output = {
+                        let input = input.sub_scalar(128i32).pad((0, 0, 1, 1), 0);
+                        let [stride_height, stride_width] = [2, 1];
+                        let [dilation_height, dilation_width] = [1, 1];
+                        let device = input.device();
+                        let [batch_size, _, height, width] = input.dims();
+                        let out_height = (height - 3) / stride_height + 1;
+                        let out_width = (width - 3) / stride_width + 1;
+                        let weight = self.conv_integer.val();
+
+                        let mut output = Tensor::<B, 6, Int>::zeros(
+                            [batch_size, 2, 2, 1, out_height, out_width],
+                            &device,
+                        );
+                        for kernel_row in 0..3 {
+                            for kernel_col in 0..3 {
+                                let row = (kernel_row * dilation_height) as i64;
+                                let col = (kernel_col * dilation_width) as i64;
+                                let rows = Tensor::arange_step(
+                                    row..row + (stride_height * (out_height - 1) + 1) as i64,
+                                    stride_height,
+                                    &device,
+                                );
+                                let cols = Tensor::arange_step(
+                                    col..col + (stride_width * (out_width - 1) + 1) as i64,
+                                    stride_width,
+                                    &device,
+                                );
+                                let input = input.clone().select(2, rows).select(3, cols).reshape([
+                                    batch_size,
+                                    2,
+                                    1,
+                                    1,
+                                    out_height,
+                                    out_width,
+                                ]);
+                                let weight = weight
+                                    .clone()
+                                    .slice([
+                                        0..4,
+                                        0..1,
+                                        kernel_row..kernel_row + 1,
+                                        kernel_col..kernel_col + 1,
+                                    ])
+                                    .reshape([1, 2, 2, 1, 1, 1]);
+                                output = output + input.mul(weight).sum_dim(3);
+                            }
+                        }
+
+                        output.reshape([batch_size, 4, out_height, out_width])
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
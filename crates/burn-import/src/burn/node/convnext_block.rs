@@ -0,0 +1,220 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+use burn::{
+    module::ConstantRecord,
+    nn::{
+        LayerNormRecord, LinearRecord,
+        conv::{Conv2dRecord, ConvNeXtBlockConfig, ConvNeXtBlockRecord},
+    },
+    record::{PrecisionSettings, Record},
+    tensor::TensorData,
+};
+
+use super::{Node, NodeCodegen, SerializationBackend, param};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+/// The parameters of a [ConvNeXtBlockNode], in the layout of the ONNX weights.
+#[allow(clippy::too_many_arguments)]
+#[derive(Debug, Clone, new)]
+pub struct ConvNeXtBlockData {
+    pub dwconv_weight: TensorData,
+    pub dwconv_bias: TensorData,
+    pub norm_gamma: TensorData,
+    pub norm_beta: TensorData,
+    pub pwconv1_weight: TensorData,
+    pub pwconv1_bias: TensorData,
+    pub pwconv2_weight: TensorData,
+    pub pwconv2_bias: TensorData,
+    pub gamma: Option<TensorData>,
+}
+
+/// Node for a ConvNeXt block recognized from its depthwise convolution and feed-forward layer,
+/// which is mapped to a [ConvNeXtBlock](burn::nn::conv::ConvNeXtBlock) module.
+#[derive(Debug, Clone)]
+pub struct ConvNeXtBlockNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub data: Box<ConvNeXtBlockData>,
+    pub config: ConvNeXtBlockConfig,
+}
+
+impl ConvNeXtBlockNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        data: ConvNeXtBlockData,
+        config: ConvNeXtBlockConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    ConvNeXtBlock<B>
+                },
+            ),
+            input,
+            output,
+            data: Box::new(data),
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ConvNeXtBlockNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let dim = self.config.dim.to_tokens();
+        let layer_scale_init = self.config.layer_scale_init;
+        let epsilon = self.config.epsilon;
+
+        let tokens = quote! {
+            let #name = ConvNeXtBlockConfig::new(#dim)
+                .with_layer_scale_init(#layer_scale_init)
+                .with_epsilon(#epsilon)
+                .init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = &self.data;
+
+        let record = ConvNeXtBlockRecord::<SerializationBackend> {
+            dwconv: Conv2dRecord {
+                weight: param::<PS, 4>(&data.dwconv_weight),
+                bias: Some(param::<PS, 1>(&data.dwconv_bias)),
+                stride: [ConstantRecord::new(); 2],
+                kernel_size: [ConstantRecord::new(); 2],
+                dilation: [ConstantRecord::new(); 2],
+                groups: ConstantRecord::new(),
+                padding: ConstantRecord::new(),
+            },
+            norm: LayerNormRecord {
+                gamma: param::<PS, 1>(&data.norm_gamma),
+                beta: param::<PS, 1>(&data.norm_beta),
+                epsilon: ConstantRecord::new(),
+            },
+            pwconv1: LinearRecord {
+                weight: param::<PS, 2>(&data.pwconv1_weight),
+                bias: Some(param::<PS, 1>(&data.pwconv1_bias)),
+            },
+            pwconv2: LinearRecord {
+                weight: param::<PS, 2>(&data.pwconv2_weight),
+                bias: Some(param::<PS, 1>(&data.pwconv2_bias)),
+            },
+            gamma: data.gamma.as_ref().map(param::<PS, 1>),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = scope.field_use(&self.field.name);
+
+        quote! {
+            let #output = #field.forward(#input);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::conv::ConvNeXtBlock");
+        imports.register("burn::nn::conv::ConvNeXtBlockConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ConvNeXtBlock(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let zeros = |shape: &[usize]| TensorData::zeros::<f32, _>(shape.to_vec());
+
+        graph.register(ConvNeXtBlockNode::new(
+            "block",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            ConvNeXtBlockData::new(
+                zeros(&[2, 1, 7, 7]),
+                zeros(&[2]),
+                zeros(&[2]),
+                zeros(&[2]),
+                zeros(&[2, 8]),
+                zeros(&[8]),
+                zeros(&[8, 2]),
+                zeros(&[2]),
+                Some(zeros(&[2])),
+            ),
+            ConvNeXtBlockConfig::new(2),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::nn::conv::ConvNeXtBlock;
+            use burn::nn::conv::ConvNeXtBlockConfig;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                block: ConvNeXtBlock<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let block = ConvNeXtBlockConfig::new(2)
+                        .with_layer_scale_init(0.000001f64)
+                        .with_epsilon(0.000001f64)
+                        .init(device);
+
+                    Self {
+                        block,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.block.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,211 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+use burn::{
+    module::ConstantRecord,
+    nn::conv::{Conv2dRecord, DepthwiseSeparableConv2dConfig, DepthwiseSeparableConv2dRecord},
+    record::{PrecisionSettings, Record},
+    tensor::TensorData,
+};
+
+use super::{Node, NodeCodegen, SerializationBackend, param};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+/// The parameters of a [DepthwiseSeparableConv2dNode], in the layout of the ONNX weights.
+#[derive(Debug, Clone, new)]
+pub struct DepthwiseSeparableConv2dData {
+    pub depthwise_weight: TensorData,
+    pub depthwise_bias: Option<TensorData>,
+    pub pointwise_weight: TensorData,
+    pub pointwise_bias: Option<TensorData>,
+}
+
+/// Node for a depthwise convolution followed by a pointwise convolution, which is mapped to a
+/// [DepthwiseSeparableConv2d](burn::nn::conv::DepthwiseSeparableConv2d) module.
+#[derive(Debug, Clone)]
+pub struct DepthwiseSeparableConv2dNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub data: Box<DepthwiseSeparableConv2dData>,
+    pub config: DepthwiseSeparableConv2dConfig,
+}
+
+impl DepthwiseSeparableConv2dNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        data: DepthwiseSeparableConv2dData,
+        config: DepthwiseSeparableConv2dConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    DepthwiseSeparableConv2d<B>
+                },
+            ),
+            input,
+            output,
+            data: Box::new(data),
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DepthwiseSeparableConv2dNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let channels = self.config.channels.to_tokens();
+        let kernel_size = self.config.kernel_size.to_tokens();
+        let stride = self.config.stride.to_tokens();
+        let dilation = self.config.dilation.to_tokens();
+        let padding = self.config.padding.to_tokens();
+        let bias = self.config.bias;
+
+        let tokens = quote! {
+            let #name = DepthwiseSeparableConv2dConfig::new(#channels, #kernel_size)
+                .with_stride(#stride)
+                .with_padding(#padding)
+                .with_dilation(#dilation)
+                .with_bias(#bias)
+                .init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = &self.data;
+
+        let record = DepthwiseSeparableConv2dRecord::<SerializationBackend> {
+            depthwise: conv2d_record::<PS>(&data.depthwise_weight, data.depthwise_bias.as_ref()),
+            pointwise: conv2d_record::<PS>(&data.pointwise_weight, data.pointwise_bias.as_ref()),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = scope.field_use(&self.field.name);
+
+        quote! {
+            let #output = #field.forward(#input);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::PaddingConfig2d");
+        imports.register("burn::nn::conv::DepthwiseSeparableConv2d");
+        imports.register("burn::nn::conv::DepthwiseSeparableConv2dConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DepthwiseSeparableConv2d(self)
+    }
+}
+
+/// The record of a convolution of the module from its weight and bias.
+fn conv2d_record<PS: PrecisionSettings>(
+    weight: &TensorData,
+    bias: Option<&TensorData>,
+) -> Conv2dRecord<SerializationBackend> {
+    Conv2dRecord {
+        weight: param::<PS, 4>(weight),
+        bias: bias.map(param::<PS, 1>),
+        stride: [ConstantRecord::new(); 2],
+        kernel_size: [ConstantRecord::new(); 2],
+        dilation: [ConstantRecord::new(); 2],
+        groups: ConstantRecord::new(),
+        padding: ConstantRecord::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+    use burn::{nn::PaddingConfig2d, record::FullPrecisionSettings};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let zeros = |shape: &[usize]| TensorData::zeros::<f32, _>(shape.to_vec());
+
+        graph.register(DepthwiseSeparableConv2dNode::new(
+            "conv",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            DepthwiseSeparableConv2dData::new(
+                zeros(&[2, 1, 3, 3]),
+                Some(zeros(&[2])),
+                zeros(&[4, 2, 1, 1]),
+                Some(zeros(&[4])),
+            ),
+            DepthwiseSeparableConv2dConfig::new([2, 4], [3, 3])
+                .with_padding(PaddingConfig2d::Explicit(1, 1)),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::nn::PaddingConfig2d;
+            use burn::nn::conv::DepthwiseSeparableConv2d;
+            use burn::nn::conv::DepthwiseSeparableConv2dConfig;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                conv: DepthwiseSeparableConv2d<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let conv = DepthwiseSeparableConv2dConfig::new([2, 4], [3, 3])
+                        .with_stride([1, 1])
+                        .with_padding(PaddingConfig2d::Explicit(1, 1))
+                        .with_dilation([1, 1])
+                        .with_bias(true)
+                        .init(device);
+
+                    Self {
+                        conv,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.conv.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -2,6 +2,7 @@ use super::{Node, NodeCodegen};
 use crate::burn::{BurnImports, ScalarKind, ToTokens, Type};
 
 use burn::record::PrecisionSettings;
+use proc_macro2::Literal;
 use quote::quote;
 
 #[derive(Debug, Clone, new)]
@@ -26,6 +27,26 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
         scope: &mut crate::burn::Scope,
         node_position: usize,
     ) -> proc_macro2::TokenStream {
+        // A single dim of a shape is read on the host, without uploading the shape to the device
+        if let (Type::Shape(shape), Type::Scalar(index), Type::Scalar(output)) =
+            (&self.input, &self.index, &self.output)
+        {
+            let shape_name = &shape.name;
+            let index = &index.name;
+            let output_name = &output.name;
+            let output_ty = output.ty();
+            // Negative indices are counted from the end, the others are bounds checked by the
+            // array indexing
+            let rank = Literal::i64_unsuffixed(shape.rank as i64);
+
+            return quote! {
+                let #output_name = {
+                    let index = if #index < 0 { #index + #rank } else { #index };
+                    #shape_name[index as usize] as #output_ty
+                };
+            };
+        }
+
         let dim = self.dim.to_tokens();
         let input_rank = match &self.input {
             Type::Tensor(in_tensor) => in_tensor.rank,
@@ -54,7 +75,6 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
                         let indices = Tensor::<B, 1, _>::from_data([#index], &*self.device);
                         let gathered = Tensor::select(#input, #dim, indices);
                         let #output = gathered.into_scalar().to_i32();
-                        #output
                     },
                     ScalarKind::Int64 => quote! {
                         let indices = Tensor::<B, 1, _>::from_data([#index], &*self.device);
@@ -137,8 +157,9 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
     }
 
     fn register_imports(&self, imports: &mut BurnImports) {
-        match &self.output {
-            Type::Scalar(_) => {
+        match (&self.input, &self.output) {
+            (Type::Shape(_), Type::Scalar(_)) => {}
+            (_, Type::Scalar(_)) => {
                 imports.register("burn::tensor::cast::ToElement");
             }
             _ => {}
@@ -340,6 +361,58 @@ mod tests {
         assert_tokens(graph.codegen(), expected);
     }
 
+    #[test]
+    fn test_codegen_gather_shape_scalar_idx() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            Type::Shape(ShapeType::new("shape1", 3)),
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+            Type::Scalar(ScalarType::new("scalar2", ScalarKind::Int64)),
+            0,
+        ));
+
+        graph.register_input_output(
+            vec!["shape1".to_string(), "scalar1".to_string()],
+            vec!["scalar2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, shape1: [usize; 3], scalar1: i64) -> i64 {
+                    let scalar2 = {
+                        let index = if scalar1 < 0 { scalar1 + 3 } else { scalar1 };
+                        shape1[index as usize] as i64
+                    };
+
+                    scalar2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
     #[test]
     fn test_codegen_gather_scalar_idx() {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
@@ -0,0 +1,103 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX `Hardmax` operator, outputting a one-hot along `axis` at the position of the
+/// first maximum.
+#[derive(Debug, Clone, new)]
+pub struct HardmaxNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub axis: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for HardmaxNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let axis = self.axis.to_tokens();
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        // argmax returns the index of the first maximum on ties
+        quote! {
+            let #output = {
+                let input = #input;
+                let indices = input.clone().argmax(#axis);
+                let ones = indices.ones_like().float();
+                input.zeros_like().scatter(#axis, indices, ones)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Hardmax(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+
+    #[test]
+    fn test_codegen_hardmax() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(HardmaxNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            1,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let indices = input.clone().argmax(1);
+                        let ones = indices.ones_like().float();
+                        input.zeros_like().scatter(1, indices, ones)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,191 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::{nn::loss::HingeEmbeddingLossConfig, record::PrecisionSettings};
+
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+
+/// How the losses of the elements are reduced by a [HingeEmbeddingLossNode].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LossReduction {
+    /// The loss of each element is returned.
+    None,
+    /// The mean of the losses is returned.
+    Mean,
+    /// The sum of the losses is returned.
+    Sum,
+}
+
+/// Node for a hinge embedding loss recognized from its decomposition, which is mapped to a
+/// [HingeEmbeddingLoss](burn::nn::loss::HingeEmbeddingLoss).
+#[derive(Debug, Clone, new)]
+pub struct HingeEmbeddingLossNode {
+    pub input: TensorType,
+    pub target: TensorType,
+    pub output: TensorType,
+    pub config: HingeEmbeddingLossConfig,
+    pub reduction: LossReduction,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for HingeEmbeddingLossNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.target.clone()),
+        ]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let target = scope.tensor_use_owned(&self.target, node_position);
+        let output = &self.output.name;
+        let margin = self.config.margin;
+
+        match self.reduction {
+            LossReduction::None => quote! {
+                let #output = HingeEmbeddingLossConfig::new()
+                    .with_margin(#margin)
+                    .init()
+                    .forward_no_reduction(#input, #target);
+            },
+            LossReduction::Mean => quote! {
+                let #output = hinge_embedding_loss(#input, #target, #margin, Reduction::Mean);
+            },
+            LossReduction::Sum => quote! {
+                let #output = hinge_embedding_loss(#input, #target, #margin, Reduction::Sum);
+            },
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        match self.reduction {
+            LossReduction::None => {
+                imports.register("burn::nn::loss::HingeEmbeddingLossConfig");
+            }
+            LossReduction::Mean | LossReduction::Sum => {
+                imports.register("burn::nn::loss::hinge_embedding_loss");
+                imports.register("burn::nn::loss::Reduction");
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::HingeEmbeddingLoss(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(HingeEmbeddingLossNode::new(
+            TensorType::new_float("input", 2),
+            TensorType::new_float("target", 2),
+            TensorType::new_float("output", 1),
+            HingeEmbeddingLossConfig::new().with_margin(2.0),
+            LossReduction::Mean,
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "target".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::nn::loss::Reduction;
+            use burn::nn::loss::hinge_embedding_loss;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 2>, target: Tensor<B, 2>) -> Tensor<B, 1> {
+                    let output = hinge_embedding_loss(input, target, 2f64, Reduction::Mean);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_no_reduction() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(HingeEmbeddingLossNode::new(
+            TensorType::new_float("input", 2),
+            TensorType::new_float("target", 2),
+            TensorType::new_float("output", 2),
+            HingeEmbeddingLossConfig::new(),
+            LossReduction::None,
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "target".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::nn::loss::HingeEmbeddingLossConfig;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 2>, target: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let output = HingeEmbeddingLossConfig::new()
+                        .with_margin(1f64)
+                        .init()
+                        .forward_no_reduction(input, target);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,110 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node that forwards its input unchanged, preserving its type (tensor, scalar or shape).
+#[derive(Debug, Clone, new)]
+pub struct IdentityNode {
+    pub input: Type,
+    pub output: Type,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for IdentityNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![self.output.clone()]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![self.input.clone()]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = match &self.input {
+            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+            Type::Scalar(scalar) => {
+                let name = &scalar.name;
+                quote! { #name }
+            }
+            Type::Shape(shape) => {
+                let name = &shape.name;
+                quote! { #name }
+            }
+            _ => panic!("Identity: unsupported input type {:?}", self.input),
+        };
+        let output = &self.output.name();
+
+        quote! {
+            let #output = #input;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Identity(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::node::tests::one_node_graph;
+    use crate::burn::{ScalarKind, ScalarType, ShapeType, TensorType};
+
+    #[test]
+    fn test_codegen_identity_tensor() {
+        one_node_graph(
+            IdentityNode::new(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1;
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_codegen_identity_scalar() {
+        one_node_graph(
+            IdentityNode::new(
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Float32)),
+                Type::Scalar(ScalarType::new("scalar2", ScalarKind::Float32)),
+            ),
+            quote! {
+                pub fn forward(&self, scalar1: f32) -> f32 {
+                    let scalar2 = scalar1;
+
+                    scalar2
+                }
+            },
+            vec!["scalar1".to_string()],
+            vec!["scalar2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_codegen_identity_shape() {
+        one_node_graph(
+            IdentityNode::new(
+                Type::Shape(ShapeType::new("shape1", 3)),
+                Type::Shape(ShapeType::new("shape2", 3)),
+            ),
+            quote! {
+                pub fn forward(&self, shape1: [usize; 3]) -> [usize; 3] {
+                    let shape2 = shape1;
+
+                    shape2
+                }
+            },
+            vec!["shape1".to_string()],
+            vec!["shape2".to_string()],
+        );
+    }
+}
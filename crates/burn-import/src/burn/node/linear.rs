@@ -96,10 +96,10 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for LinearNode {
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let field = &self.field.name;
+        let field = scope.field_use(&self.field.name);
 
         quote! {
-            let #output = self.#field.forward(#input);
+            let #output = #field.forward(#input);
         }
     }
 
@@ -117,7 +117,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for LinearNode {
 mod tests {
     use super::*;
     use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
-    use burn::record::FullPrecisionSettings;
+    use burn::{module::TensorSpec, record::FullPrecisionSettings, tensor::DType};
 
     #[test]
     fn test_codegen() {
@@ -173,4 +173,193 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_half_precision_params() {
+        let mut graph =
+            BurnGraph::<FullPrecisionSettings>::default().with_half_precision_params(true);
+
+        graph.register(LinearNode::new(
+            "linear",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            TensorData::from([2f32]),
+            None,
+            LinearConfig::new(128, 128),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::module::FloatCaster;
+            use burn::nn::Linear;
+            use burn::nn::LinearConfig;
+            use burn::tensor::FloatDType;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                linear: Linear<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let linear = LinearConfig::new(128, 128)
+                        .with_bias(true)
+                        .init(device);
+
+                    Self {
+                        linear,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                    .map(&mut FloatCaster { dtype: FloatDType::F16 })
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self
+                        .linear
+                        .clone()
+                        .map(&mut FloatCaster { dtype: FloatDType::F32 })
+                        .forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_summary() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default().with_summary(true);
+
+        graph.register(LinearNode::new(
+            "linear",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            TensorData::from([2f32]),
+            None,
+            LinearConfig::new(128, 128),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::module::ModuleSummary;
+            use burn::nn::Linear;
+            use burn::nn::LinearConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                linear: Linear<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let linear = LinearConfig::new(128, 128)
+                        .with_bias(true)
+                        .init(device);
+
+                    Self {
+                        linear,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.linear.forward(input);
+
+                    output
+                }
+                pub fn summary(&self) -> ModuleSummary {
+                    ModuleSummary::new()
+                        .add::<B, _>("linear", &self.linear)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_io_specs() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default().with_io_specs(
+            vec![TensorSpec::new("input", 4, DType::F32)],
+            vec![TensorSpec::new("output", 4, DType::F32)],
+        );
+
+        graph.register(LinearNode::new(
+            "linear",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            TensorData::from([2f32]),
+            None,
+            LinearConfig::new(128, 128),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::module::TensorSpec;
+            use burn::nn::Linear;
+            use burn::nn::LinearConfig;
+            use burn::tensor::DType;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                linear: Linear<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let linear = LinearConfig::new(128, 128)
+                        .with_bias(true)
+                        .init(device);
+
+                    Self {
+                        linear,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.linear.forward(input);
+
+                    output
+                }
+                pub fn input_specs() -> [TensorSpec; 1] {
+                    [TensorSpec::new("input", 4, DType::F32)]
+                }
+                pub fn output_specs() -> [TensorSpec; 1] {
+                    [TensorSpec::new("output", 4, DType::F32)]
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -10,15 +10,27 @@ use quote::quote;
 pub struct MatmulNode {
     pub lhs: TensorType,
     pub rhs: TensorType,
+    /// The bias added to the product, fused from the Add node following the MatMul node.
+    pub bias: Option<TensorType>,
     pub output: TensorType,
 }
 
 impl MatmulNode {
-    pub fn new(lhs: TensorType, rhs: TensorType, output: TensorType) -> Self {
+    pub fn new(
+        lhs: TensorType,
+        rhs: TensorType,
+        bias: Option<TensorType>,
+        output: TensorType,
+    ) -> Self {
         if lhs.kind != TensorKind::Float {
             panic!("MatMul is only implemented for float tensors");
         }
-        Self { lhs, rhs, output }
+        Self {
+            lhs,
+            rhs,
+            bias,
+            output,
+        }
     }
 }
 
@@ -28,10 +40,16 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MatmulNode {
     }
 
     fn input_types(&self) -> Vec<Type> {
-        vec![
+        let mut inputs = vec![
             Type::Tensor(self.lhs.clone()),
             Type::Tensor(self.rhs.clone()),
-        ]
+        ];
+
+        if let Some(bias) = &self.bias {
+            inputs.push(Type::Tensor(bias.clone()));
+        }
+
+        inputs
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
@@ -43,23 +61,23 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MatmulNode {
         let rhs_dim = self.rhs.rank;
 
         // Support broadcasting for missing dimensions
-        match lhs_dim.cmp(&rhs_dim) {
+        let product = match lhs_dim.cmp(&rhs_dim) {
             Ordering::Greater => {
-                // Alternate unsqueeze(0) -> unsqueeze(-1) -> unsqueeze(0) -> ...
-                let axes = (0..lhs_dim - rhs_dim)
-                    .map(|i| if i % 2 == 0 { 0 } else { -1 })
-                    .collect::<Vec<i64>>();
-                let axes = axes.to_tokens();
-
                 if rhs_dim == 1 {
-                    // Matrix-vector product: squeeze(-1)
+                    // Matrix-vector product: the vector is expanded to a column [.., 1, k, 1]
+                    // and the appended dimension is squeezed from the output
+                    let mut axes = [0i64].repeat(lhs_dim - 2);
+                    axes.push(-1);
+                    let axes = axes.to_tokens();
                     let squeeze_dim = lhs_dim - 1;
                     quote! {
-                        let #output = #lhs.matmul(#rhs.unsqueeze_dims(&#axes)).squeeze(#squeeze_dim);
+                        #lhs.matmul(#rhs.unsqueeze_dims(&#axes)).squeeze(#squeeze_dim)
                     }
                 } else {
+                    // Prepend the missing batch dimensions: unsqueeze(0)
+                    let axes = [0i64].repeat(lhs_dim - rhs_dim).to_tokens();
                     quote! {
-                        let #output = #lhs.matmul(#rhs.unsqueeze_dims(&#axes));
+                        #lhs.matmul(#rhs.unsqueeze_dims(&#axes))
                     }
                 }
             }
@@ -68,19 +86,41 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MatmulNode {
                 let axes = [0i64].repeat(rhs_dim - lhs_dim).to_tokens();
 
                 if lhs_dim == 1 {
-                    // Vector-matrix product: squeeze(-2)
+                    // Vector-matrix product: the vector is expanded to a row [.., 1, 1, k]
+                    // and the prepended dimension is squeezed from the output
                     let squeeze_dim = rhs_dim - 2;
                     quote! {
-                        let #output = #lhs.unsqueeze_dims(&#axes).matmul(#rhs).squeeze(#squeeze_dim);
+                        #lhs.unsqueeze_dims(&#axes).matmul(#rhs).squeeze(#squeeze_dim)
                     }
                 } else {
                     quote! {
-                        let #output = #lhs.unsqueeze_dims(&#axes).matmul(#rhs);
+                        #lhs.unsqueeze_dims(&#axes).matmul(#rhs)
                     }
                 }
             }
             Ordering::Equal => quote! {
-                let #output = #lhs.matmul(#rhs);
+                #lhs.matmul(#rhs)
+            },
+        };
+
+        match &self.bias {
+            Some(bias) => {
+                let bias_name = scope.tensor_use_owned(bias, node_position);
+                // The bias is broadcast to the product over the leading dimensions
+                let bias = if bias.rank < self.output.rank {
+                    let rank = self.output.rank.to_tokens();
+                    quote! { #bias_name.unsqueeze::<#rank>() }
+                } else {
+                    quote! { #bias_name }
+                };
+
+                // The bias is added in the same expression as the product, like a linear layer
+                quote! {
+                    let #output = #product.add(#bias);
+                }
+            }
+            None => quote! {
+                let #output = #product;
             },
         }
     }
@@ -109,6 +149,7 @@ mod tests {
         graph.register(MatmulNode::new(
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 4),
+            None,
             TensorType::new_float("tensor3", 4),
         ));
 
@@ -154,6 +195,64 @@ mod tests {
         assert_tokens(graph.codegen(), expected);
     }
 
+    #[test]
+    fn test_codegen_matmul_bias() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            Some(TensorType::new_float("bias", 1)),
+            TensorType::new_float("tensor3", 4),
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "bias".to_string(),
+            ],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    tensor2: Tensor<B, 4>,
+                    bias: Tensor<B, 1>
+                ) -> Tensor<B, 4> {
+                    let tensor3 = tensor1.matmul(tensor2).add(bias.unsqueeze::<4>());
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
     #[test]
     fn test_codegen_matmul_matrix_vector() {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
@@ -161,6 +260,7 @@ mod tests {
         graph.register(MatmulNode::new(
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 1),
+            None,
             TensorType::new_float("tensor3", 3),
         ));
 
@@ -196,7 +296,113 @@ mod tests {
                     tensor1: Tensor<B, 4>,
                     tensor2: Tensor<B, 1>
                 ) -> Tensor<B, 3> {
-                    let tensor3 = tensor1.matmul(tensor2.unsqueeze_dims(&[0, -1, 0])).squeeze(3usize);
+                    let tensor3 = tensor1.matmul(tensor2.unsqueeze_dims(&[0, 0, -1])).squeeze(3usize);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_matmul_matrix_vector_2d() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 1),
+            None,
+            TensorType::new_float("tensor3", 1),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 1>
+                ) -> Tensor<B, 1> {
+                    let tensor3 = tensor1.matmul(tensor2.unsqueeze_dims(&[-1])).squeeze(1usize);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_matmul_broadcast_rhs() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 2),
+            None,
+            TensorType::new_float("tensor3", 4),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    tensor2: Tensor<B, 2>
+                ) -> Tensor<B, 4> {
+                    let tensor3 = tensor1.matmul(tensor2.unsqueeze_dims(&[0, 0]));
 
                     tensor3
                 }
@@ -213,6 +419,7 @@ mod tests {
         graph.register(MatmulNode::new(
             TensorType::new_float("tensor1", 1),
             TensorType::new_float("tensor2", 4),
+            None,
             TensorType::new_float("tensor3", 3),
         ));
 
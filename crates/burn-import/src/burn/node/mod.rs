@@ -1,20 +1,26 @@
 mod base;
 
+pub(crate) mod adaptive_max_pool2d;
 pub(crate) mod argmax;
+pub(crate) mod attention;
 pub(crate) mod avg_pool1d;
 pub(crate) mod avg_pool2d;
 pub(crate) mod batch_norm;
 pub(crate) mod binary;
 pub(crate) mod clip;
 pub(crate) mod concat;
+pub(crate) mod conditional;
 pub(crate) mod constant;
 pub(crate) mod constant_of_shape;
 pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod conv3d;
+pub(crate) mod conv_integer;
 pub(crate) mod conv_transpose_1d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
+pub(crate) mod convnext_block;
+pub(crate) mod depthwise_separable_conv2d;
 pub(crate) mod dropout;
 pub(crate) mod expand;
 pub(crate) mod floor;
@@ -22,6 +28,9 @@ pub(crate) mod gather;
 pub(crate) mod gather_elements;
 pub(crate) mod gemm;
 pub(crate) mod global_avg_pool;
+pub(crate) mod hardmax;
+pub(crate) mod hinge_embedding_loss;
+pub(crate) mod identity;
 pub(crate) mod layer_norm;
 pub(crate) mod linear;
 pub(crate) mod mask_where;
@@ -30,6 +39,7 @@ pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
 pub(crate) mod mean;
 pub(crate) mod one_hot;
+pub(crate) mod optional;
 pub(crate) mod pad;
 pub(crate) mod prelu;
 pub(crate) mod random_normal;
@@ -37,11 +47,18 @@ pub(crate) mod random_normal_like;
 pub(crate) mod random_uniform;
 pub(crate) mod random_uniform_like;
 pub(crate) mod range;
+pub(crate) mod relative_position_bias;
+pub(crate) mod repeat_interleave;
 pub(crate) mod reshape;
 pub(crate) mod resize;
+pub(crate) mod scatter_elements;
+pub(crate) mod sinusoidal_pos_encoding;
 pub(crate) mod slice;
+pub(crate) mod softmax_cross_entropy_loss;
 pub(crate) mod split;
 pub(crate) mod squeeze;
+pub(crate) mod squeeze_excitation;
+pub(crate) mod stack;
 pub(crate) mod sum;
 pub(crate) mod tile;
 pub(crate) mod top_k;
@@ -0,0 +1,183 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the operators on optional values, represented as [Option] in the generated code.
+#[derive(Debug, Clone, new)]
+pub struct OptionalNode {
+    pub input: Type,
+    pub output: Type,
+    pub kind: OptionalNodeKind,
+}
+
+/// Type of optional node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionalNodeKind {
+    /// Wraps the input in an optional (`Optional`).
+    Wrap,
+    /// Whether the optional input holds a value (`OptionalHasElement`).
+    HasElement,
+    /// Unwraps the value of the optional input (`OptionalGetElement`).
+    GetElement,
+}
+
+impl OptionalNodeKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Wrap => "optional",
+            Self::HasElement => "optional_has_element",
+            Self::GetElement => "optional_get_element",
+        }
+    }
+}
+
+impl OptionalNode {
+    fn input(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        match &self.input {
+            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+            input => {
+                let name = input.name();
+                quote! { #name }
+            }
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for OptionalNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![self.output.clone()]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![self.input.clone()]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let output = self.output.name();
+        let is_optional = matches!(self.input, Type::Other(_));
+        let input = self.input(scope, node_position);
+
+        match self.kind {
+            OptionalNodeKind::Wrap => quote! {
+                let #output = Some(#input);
+            },
+            OptionalNodeKind::HasElement if is_optional => quote! {
+                let #output = #input.is_some();
+            },
+            // A value that is not optional is always present
+            OptionalNodeKind::HasElement => quote! {
+                let #output = true;
+            },
+            // Optionals are not tracked by the scope, so the input may still be used afterward
+            OptionalNodeKind::GetElement if is_optional => quote! {
+                let #output = #input.clone().unwrap();
+            },
+            OptionalNodeKind::GetElement => quote! {
+                let #output = #input;
+            },
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Optional(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        OtherType, ScalarKind, ScalarType, TensorType,
+        graph::BurnGraph,
+        node::{test::assert_tokens, tests::one_node_graph, unary::UnaryNode},
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_optional() {
+        let optional = Type::Other(OtherType::new("optional1", quote! { Option<Tensor<B, 2>> }));
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(OptionalNode::new(
+            Type::Tensor(TensorType::new_float("tensor1", 2)),
+            optional.clone(),
+            OptionalNodeKind::Wrap,
+        ));
+        graph.register(OptionalNode::new(
+            optional.clone(),
+            Type::Scalar(ScalarType::new("has_element", ScalarKind::Bool)),
+            OptionalNodeKind::HasElement,
+        ));
+        graph.register(OptionalNode::new(
+            optional,
+            Type::Tensor(TensorType::new_float("tensor2", 2)),
+            OptionalNodeKind::GetElement,
+        ));
+        graph.register(UnaryNode::relu(
+            Type::Tensor(TensorType::new_float("tensor2", 2)),
+            Type::Tensor(TensorType::new_float("tensor3", 2)),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["has_element".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (bool, Tensor<B, 2>) {
+                    let optional1 = Some(tensor1);
+                    let has_element = optional1.is_some();
+                    let tensor2 = optional1.clone().unwrap();
+                    let tensor3 = burn::tensor::activation::relu(tensor2);
+
+                    (has_element, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_optional_has_element_non_optional_input() {
+        one_node_graph(
+            OptionalNode::new(
+                Type::Tensor(TensorType::new_float("tensor1", 2)),
+                Type::Scalar(ScalarType::new("has_element", ScalarKind::Bool)),
+                OptionalNodeKind::HasElement,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> bool {
+                    let has_element = true;
+
+                    has_element
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["has_element".to_string()],
+        );
+    }
+}
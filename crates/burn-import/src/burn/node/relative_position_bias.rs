@@ -0,0 +1,195 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+use burn::{
+    module::{ConstantRecord, Param, ParamId},
+    nn::{RelativePositionBiasConfig, RelativePositionBiasRecord},
+    record::{PrecisionSettings, Record},
+    tensor::{Tensor, TensorData},
+};
+
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+/// Node for a T5 relative position bias recognized from the lookup of its embedding table, which
+/// is mapped to a [RelativePositionBias](burn::nn::RelativePositionBias) module.
+#[derive(Debug, Clone)]
+pub struct RelativePositionBiasNode {
+    pub field: OtherType,
+    pub output: TensorType,
+    pub data_weights: TensorData,
+    pub config: RelativePositionBiasConfig,
+    pub query_len: usize,
+    pub key_len: usize,
+}
+
+impl RelativePositionBiasNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        output: TensorType,
+        data_weights: TensorData,
+        config: RelativePositionBiasConfig,
+        query_len: usize,
+        key_len: usize,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    RelativePositionBias<B>
+                },
+            ),
+            output,
+            data_weights,
+            config,
+            query_len,
+            key_len,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for RelativePositionBiasNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let num_heads = self.config.num_heads.to_tokens();
+        let num_buckets = self.config.num_buckets.to_tokens();
+        let max_distance = self.config.max_distance.to_tokens();
+        let bidirectional = self.config.bidirectional;
+        let tokens = quote! {
+            let #name = RelativePositionBiasConfig::new(#num_heads)
+                .with_num_buckets(#num_buckets)
+                .with_max_distance(#max_distance)
+                .with_bidirectional(#bidirectional)
+                .init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let device = Default::default();
+        let record = RelativePositionBiasRecord::<SerializationBackend> {
+            weight: Param::initialized(
+                ParamId::new(),
+                Tensor::from_data(
+                    self.data_weights.clone().convert::<PS::FloatElem>(),
+                    &device,
+                ),
+            ),
+            max_distance: ConstantRecord::new(),
+            bidirectional: ConstantRecord::new(),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, _node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+        let field = scope.field_use(&self.field.name);
+        let query_len = self.query_len.to_tokens();
+        let key_len = self.key_len.to_tokens();
+
+        quote! {
+            let #output = #field.forward(#query_len, #key_len);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::RelativePositionBias");
+        imports.register("burn::nn::RelativePositionBiasConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::RelativePositionBias(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{binary::BinaryNode, test::assert_tokens},
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(RelativePositionBiasNode::new(
+            "bias",
+            TensorType::new_float("bias", 3),
+            TensorData::from([[0f32, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 7.0]]),
+            RelativePositionBiasConfig::new(2)
+                .with_num_buckets(4)
+                .with_max_distance(16),
+            3,
+            5,
+        ));
+        graph.register(BinaryNode::add(
+            Type::Tensor(TensorType::new_float("scores", 3)),
+            Type::Tensor(TensorType::new_float("bias", 3)),
+            Type::Tensor(TensorType::new_float("output", 3)),
+        ));
+
+        graph.register_input_output(vec!["scores".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::RelativePositionBias;
+            use burn::nn::RelativePositionBiasConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                bias: RelativePositionBias<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let bias = RelativePositionBiasConfig::new(2)
+                        .with_num_buckets(4)
+                        .with_max_distance(16)
+                        .with_bidirectional(true)
+                        .init(device);
+
+                    Self {
+                        bias,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, scores: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let bias = self.bias.forward(3, 5);
+                    let output = scores.add(bias);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,244 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct RepeatInterleaveNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub repeats: RepeatInterleaveRepeats,
+    pub axis: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum RepeatInterleaveRepeats {
+    Static(Vec<usize>),
+    Runtime(Type),
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for RepeatInterleaveNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let input = Type::Tensor(self.input.clone());
+        // The repeats are only an input of the forward pass when they aren't known statically
+        match &self.repeats {
+            RepeatInterleaveRepeats::Static(_) => vec![input],
+            RepeatInterleaveRepeats::Runtime(rt_type) => vec![input, rt_type.clone()],
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let axis = self.axis.to_tokens();
+
+        match &self.repeats {
+            RepeatInterleaveRepeats::Static(repeats) if repeats.len() == 1 => {
+                let repeats = repeats[0].to_tokens();
+
+                quote! {
+                    let #output = #input.repeat_interleave_scalar(#repeats, #axis);
+                }
+            }
+            RepeatInterleaveRepeats::Static(repeats) => {
+                let repeats = repeats.iter().map(|r| r.to_tokens());
+
+                quote! {
+                    let #output = #input.repeat_interleave(
+                        Tensor::<B, 1, Int>::from_data([#(#repeats),*], &*self.device),
+                        #axis,
+                    );
+                }
+            }
+            RepeatInterleaveRepeats::Runtime(Type::Tensor(repeats)) => {
+                let repeats = scope.tensor_use_owned(repeats, node_position);
+
+                quote! {
+                    let #output = #input.repeat_interleave(#repeats, #axis);
+                }
+            }
+            RepeatInterleaveRepeats::Runtime(Type::Scalar(repeats)) => {
+                let repeats = &repeats.name;
+
+                quote! {
+                    let #output = #input.repeat_interleave_scalar(#repeats as usize, #axis);
+                }
+            }
+            _ => panic!("Invalid repeats source {:?}", self.repeats),
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if matches!(&self.repeats, RepeatInterleaveRepeats::Static(repeats) if repeats.len() > 1) {
+            imports.register("burn::tensor::Int");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::RepeatInterleave(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{repeat_interleave::RepeatInterleaveNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_repeat_interleave_static() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(RepeatInterleaveNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            RepeatInterleaveRepeats::Static(vec![1, 2, 3]),
+            1,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.repeat_interleave(
+                        Tensor::<B, 1, Int>::from_data([1, 2, 3], &*self.device),
+                        1,
+                    );
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_repeat_interleave_static_scalar() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(RepeatInterleaveNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            RepeatInterleaveRepeats::Static(vec![2]),
+            0,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.repeat_interleave_scalar(2, 0);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_repeat_interleave_runtime() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(RepeatInterleaveNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            RepeatInterleaveRepeats::Runtime(Type::Tensor(TensorType::new_int("repeats", 1))),
+            0,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "repeats".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    repeats: Tensor<B, 1, Int>,
+                ) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.repeat_interleave(repeats, 0);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,14 +1,23 @@
 use super::{Node, NodeCodegen};
 use crate::burn::{Scope, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
 #[derive(Debug, Clone, new)]
 pub struct ReshapeNode {
     pub input: TensorType,
     pub output: TensorType,
-    pub shape: Vec<i64>,
+    pub shape: ReshapeShape,
+    /// Whether a zero in the shape is a literal zero-size dimension instead of a copy of the
+    /// input dimension.
+    pub allowzero: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ReshapeShape {
+    Static(Vec<i64>),
+    Runtime(Type),
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
@@ -17,16 +26,69 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
     }
 
     fn input_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.input.clone())]
+        let input = Type::Tensor(self.input.clone());
+        match &self.shape {
+            ReshapeShape::Static(_) => vec![input],
+            ReshapeShape::Runtime(rt_type) => vec![input, rt_type.clone()],
+        }
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let shape_values = &self.shape.to_tokens();
-
-        quote! {
-            let #output = #input.reshape(#shape_values);
+        let output_rank = self.output.rank;
+
+        match &self.shape {
+            ReshapeShape::Static(shape) => {
+                let shape_values = if self.allowzero && shape.contains(&0) {
+                    // The shape has no inferred dimension, so the dimensions are used as-is
+                    let dims = shape
+                        .iter()
+                        .map(|&dim| Literal::usize_suffixed(dim as usize));
+                    quote! { [#(#dims),*] }
+                } else {
+                    shape.to_tokens()
+                };
+
+                quote! {
+                    let #output = #input.reshape(#shape_values);
+                }
+            }
+            ReshapeShape::Runtime(Type::Tensor(shape_tensor)) => {
+                // Since we don't take ownership of the shape_tensor, `tensor_use_owned` is not needed here.
+                let tensor_name = &shape_tensor.name;
+                // The shape tensor must be downloaded from device to CPU, where the inferred (-1)
+                // and copied (0) dimensions are resolved by reshape.
+                let reshape = if self.allowzero {
+                    quote! {
+                        if dims.contains(&0) {
+                            #input.reshape(dims.map(|dim| dim as usize))
+                        } else {
+                            #input.reshape(dims)
+                        }
+                    }
+                } else {
+                    quote! { #input.reshape(dims) }
+                };
+
+                quote! {
+                    let #output = {
+                        let dims = TryInto::<[i32; #output_rank]>::try_into(
+                            #tensor_name.to_data().convert::<i32>().as_slice::<i32>().unwrap()
+                        )
+                        .unwrap();
+                        #reshape
+                    };
+                }
+            }
+            ReshapeShape::Runtime(Type::Shape(shape)) => {
+                // Shape dimensions are known sizes, so they can be passed directly to reshape.
+                let shape_name = &shape.name;
+                quote! {
+                    let #output = #input.reshape(#shape_name);
+                }
+            }
+            _ => panic!("Invalid shape source {:?}", self.shape),
         }
     }
 
@@ -53,7 +115,8 @@ mod tests {
         graph.register(ReshapeNode::new(
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 4),
-            [4, 4, 4, 4].into(),
+            ReshapeShape::Static([4, 4, 4, 4].into()),
+            false,
         ));
 
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
@@ -89,4 +152,104 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_allowzero() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ReshapeNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 3),
+            ReshapeShape::Static([2, 0, 3].into()),
+            true,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.reshape([2usize, 0usize, 3usize]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_runtime_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ReshapeNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 2),
+            ReshapeShape::Runtime(Type::Tensor(TensorType::new_int("shape", 1))),
+            false,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "shape".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, shape: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let dims = TryInto::<[i32; 2usize]>::try_into(
+                            shape.to_data().convert::<i32>().as_slice::<i32>().unwrap()
+                        )
+                        .unwrap();
+                        tensor1.reshape(dims)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -10,8 +10,20 @@ pub struct ResizeNode {
     pub input: TensorType,
     pub output: TensorType,
     mode: String,
-    scales: Vec<f32>,
-    sizes: Vec<usize>,
+    target: ResizeTarget,
+}
+
+/// The output size or the scale factors of the spatial dimensions of a resize node.
+#[derive(Debug, Clone)]
+pub enum ResizeTarget {
+    /// Known when importing, without the batch and channel dimensions.
+    Static { scales: Vec<f32>, sizes: Vec<usize> },
+    /// Only known at runtime, where the sizes are used when they aren't empty and the scales
+    /// otherwise.
+    Runtime {
+        scales: Option<TensorType>,
+        sizes: Option<TensorType>,
+    },
 }
 
 impl ResizeNode {
@@ -20,8 +32,7 @@ impl ResizeNode {
         input: TensorType,
         output: TensorType,
         mode: String,
-        scales: Vec<f32>,
-        sizes: Vec<usize>,
+        target: ResizeTarget,
     ) -> Self {
         let ty = if input.rank == 3 {
             quote! {
@@ -40,8 +51,81 @@ impl ResizeNode {
             input,
             output,
             mode,
-            scales,
-            sizes,
+            target,
+        }
+    }
+
+    fn mode_tokens(&self) -> TokenStream {
+        match self.mode.as_str() {
+            "nearest" => quote! { InterpolateMode::Nearest },
+            "linear" => quote! { InterpolateMode::Linear },
+            "cubic" => quote! { InterpolateMode::Cubic },
+            _ => panic!("Unsupported mode for resize node"),
+        }
+    }
+
+    /// The forward pass when the sizes or scales are inputs, where the interpolate module is
+    /// created once they are read.
+    fn runtime_forward(
+        &self,
+        input: TokenStream,
+        scales: &Option<TensorType>,
+        sizes: &Option<TensorType>,
+    ) -> TokenStream {
+        let output = &self.output.name;
+        let mode = self.mode_tokens();
+
+        let (config, with_sizes, with_scales) = if self.input.rank == 3 {
+            (
+                quote! { Interpolate1dConfig::new() },
+                quote! { with_output_size(Some(sizes[2] as usize)) },
+                quote! { with_scale_factor(Some(scales[2])) },
+            )
+        } else {
+            (
+                quote! { Interpolate2dConfig::new() },
+                quote! { with_output_size(Some([sizes[2] as usize, sizes[3] as usize])) },
+                quote! { with_scale_factor(Some([scales[2], scales[3]])) },
+            )
+        };
+
+        // The values are downloaded from the device, including the batch and channel dimensions
+        let read_sizes = sizes.as_ref().map(|sizes| {
+            let sizes = &sizes.name;
+            quote! {
+                let sizes = #sizes.to_data().convert::<i64>();
+                let sizes = sizes.as_slice::<i64>().unwrap();
+            }
+        });
+        let read_scales = scales.as_ref().map(|scales| {
+            let scales = &scales.name;
+            quote! {
+                let scales = #scales.to_data().convert::<f32>();
+                let scales = scales.as_slice::<f32>().unwrap();
+            }
+        });
+
+        let config = match (&read_sizes, &read_scales) {
+            (Some(_), Some(_)) => quote! {
+                if !sizes.is_empty() {
+                    #config.#with_sizes
+                } else {
+                    #config.#with_scales
+                }
+            },
+            (Some(_), None) => quote! { #config.#with_sizes },
+            (None, Some(_)) => quote! { #config.#with_scales },
+            (None, None) => panic!("Resize: either scales or sizes input is required"),
+        };
+
+        quote! {
+            let #output = {
+                #read_sizes
+                #read_scales
+                let interpolate = #config.with_mode(#mode).init();
+
+                interpolate.forward(#input)
+            };
         }
     }
 }
@@ -52,32 +136,38 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
     }
 
     fn input_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.input.clone())]
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+
+        if let ResizeTarget::Runtime { scales, sizes } = &self.target {
+            inputs.extend(scales.iter().chain(sizes).cloned().map(Type::Tensor));
+        }
+
+        inputs
     }
 
     fn field_type(&self) -> Option<Type> {
-        Some(Type::Other(self.field.clone()))
+        match self.target {
+            ResizeTarget::Static { .. } => Some(Type::Other(self.field.clone())),
+            ResizeTarget::Runtime { .. } => None,
+        }
     }
 
     fn field_init(&self) -> Option<TokenStream> {
-        let name = &self.field.name;
-
-        let mode = match self.mode.as_str() {
-            "nearest" => quote! { InterpolateMode::Nearest },
-            "linear" => quote! { InterpolateMode::Linear },
-            "cubic" => quote! { InterpolateMode::Cubic },
-            _ => panic!("Unsupported mode for resize node"),
+        let ResizeTarget::Static { scales, sizes } = &self.target else {
+            return None;
         };
+        let name = &self.field.name;
+        let mode = self.mode_tokens();
 
         let tokens = if self.input.rank == 3 {
-            let size = if let Some(size) = self.sizes.first() {
+            let size = if let Some(size) = sizes.first() {
                 let size = size.to_tokens();
                 quote! { Some(#size) }
             } else {
                 quote! { None }
             };
 
-            let scale_factor = if let Some(scale) = self.scales.first() {
+            let scale_factor = if let Some(scale) = scales.first() {
                 let scale = scale.to_tokens();
                 quote! { Some(#scale) }
             } else {
@@ -92,17 +182,17 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
                     .init();
             }
         } else if self.input.rank == 4 {
-            let size = if self.sizes.len() == 2 {
-                let h = self.sizes[0].to_tokens();
-                let w = self.sizes[1].to_tokens();
+            let size = if sizes.len() == 2 {
+                let h = sizes[0].to_tokens();
+                let w = sizes[1].to_tokens();
                 quote! { Some([#h, #w]) }
             } else {
                 quote! { None }
             };
 
-            let scale_factor = if self.scales.len() == 2 {
-                let h = self.scales[0].to_tokens();
-                let w = self.scales[1].to_tokens();
+            let scale_factor = if scales.len() == 2 {
+                let h = scales[0].to_tokens();
+                let w = scales[1].to_tokens();
                 quote! { Some([#h, #w]) }
             } else {
                 quote! { None }
@@ -123,13 +213,20 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
     }
 
     fn register_imports(&self, imports: &mut crate::burn::BurnImports) {
+        // The module type is only needed for the field of the static resize
+        let is_static = matches!(self.target, ResizeTarget::Static { .. });
+
         imports.register("burn::nn::interpolate::InterpolateMode");
         if self.input.rank == 3 {
             imports.register("burn::nn::interpolate::Interpolate1dConfig");
-            imports.register("burn::nn::interpolate::Interpolate1d");
+            if is_static {
+                imports.register("burn::nn::interpolate::Interpolate1d");
+            }
         } else if self.input.rank == 4 {
             imports.register("burn::nn::interpolate::Interpolate2dConfig");
-            imports.register("burn::nn::interpolate::Interpolate2d");
+            if is_static {
+                imports.register("burn::nn::interpolate::Interpolate2d");
+            }
         } else {
             panic!("Unsupported input rank for resize node");
         }
@@ -141,11 +238,17 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
-        let output = &self.output.name;
-        let field = &self.field.name;
 
-        quote! {
-            let #output = self.#field.forward(#input);
+        match &self.target {
+            ResizeTarget::Static { .. } => {
+                let output = &self.output.name;
+                let field = scope.field_use(&self.field.name);
+
+                quote! {
+                    let #output = #field.forward(#input);
+                }
+            }
+            ResizeTarget::Runtime { scales, sizes } => self.runtime_forward(input, scales, sizes),
         }
     }
 
@@ -174,8 +277,10 @@ mod tests {
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 4),
             "nearest".to_string(),
-            vec![0.5, 0.5],
-            vec![],
+            ResizeTarget::Static {
+                scales: vec![0.5, 0.5],
+                sizes: vec![],
+            },
         ));
 
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
@@ -228,8 +333,10 @@ mod tests {
             TensorType::new_float("tensor1", 3),
             TensorType::new_float("tensor2", 3),
             "cubic".to_string(),
-            vec![2.0],
-            vec![20],
+            ResizeTarget::Static {
+                scales: vec![2.0],
+                sizes: vec![20],
+            },
         ));
 
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
@@ -272,4 +379,80 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_nodes_runtime() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ResizeNode::new(
+            "resize",
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            "linear".to_string(),
+            ResizeTarget::Runtime {
+                scales: Some(TensorType::new_float("scales", 1)),
+                sizes: Some(TensorType::new_int("sizes", 1)),
+            },
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "scales".to_string(),
+                "sizes".to_string(),
+            ],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::nn::interpolate::Interpolate2dConfig;
+            use burn::nn::interpolate::InterpolateMode;
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 4>,
+                    scales: Tensor<B, 1>,
+                    sizes: Tensor<B, 1, Int>,
+                ) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let sizes = sizes.to_data().convert::<i64>();
+                        let sizes = sizes.as_slice::<i64>().unwrap();
+                        let scales = scales.to_data().convert::<f32>();
+                        let scales = scales.as_slice::<f32>().unwrap();
+                        let interpolate = if !sizes.is_empty() {
+                            Interpolate2dConfig::new()
+                                .with_output_size(Some([sizes[2] as usize, sizes[3] as usize]))
+                        } else {
+                            Interpolate2dConfig::new().with_scale_factor(Some([scales[2], scales[3]]))
+                        }
+                        .with_mode(InterpolateMode::Linear)
+                        .init();
+
+                        interpolate.forward(tensor1)
+                    };
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -0,0 +1,258 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use burn::tensor::IndexReduceMode;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ScatterElementsNode {
+    pub input: TensorType,
+    pub index: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub dim: usize,
+    /// The reduction applied to the updates, or `None` to replace the values.
+    pub reduction: Option<IndexReduceMode>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterElementsNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<crate::burn::Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.index.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(
+        &self,
+        scope: &mut crate::burn::Scope,
+        node_position: usize,
+    ) -> proc_macro2::TokenStream {
+        let dim = self.dim.to_tokens();
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let index = scope.tensor_use_owned(&self.index, node_position);
+        let updates = scope.tensor_use_owned(&self.updates, node_position);
+        let output = &self.output.name;
+
+        match self.reduction {
+            // The indexed values are first masked out, so scattering the updates replaces them.
+            None => quote! {
+                let #output = {
+                    let written = #input
+                        .zeros_like()
+                        .scatter(#dim, #index.clone(), #updates.ones_like())
+                        .bool();
+                    #input.mask_fill(written, 0).scatter(#dim, #index, #updates)
+                };
+            },
+            Some(IndexReduceMode::Sum) => quote! {
+                let #output = #input.scatter(#dim, #index, #updates);
+            },
+            Some(mode) => {
+                let mode = match mode {
+                    IndexReduceMode::Prod => quote! { IndexReduceMode::Prod },
+                    IndexReduceMode::AMax => quote! { IndexReduceMode::AMax },
+                    IndexReduceMode::AMin => quote! { IndexReduceMode::AMin },
+                    IndexReduceMode::Sum => unreachable!(),
+                };
+
+                quote! {
+                    let #output = #input.index_reduce(#dim, #index, #updates, #mode);
+                }
+            }
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let Some(IndexReduceMode::Prod | IndexReduceMode::AMax | IndexReduceMode::AMin) =
+            self.reduction
+        {
+            imports.register("burn::tensor::IndexReduceMode");
+        }
+    }
+
+    fn into_node(self) -> super::Node<PS> {
+        Node::ScatterElements(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{scatter_elements::ScatterElementsNode, test::assert_tokens},
+    };
+
+    fn scatter_elements_graph(
+        reduction: Option<IndexReduceMode>,
+    ) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterElementsNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_int("tensor2", 2),
+            TensorType::new_float("tensor3", 2),
+            TensorType::new_float("tensor4", 2),
+            1,
+            reduction,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        graph
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements() {
+        let graph = scatter_elements_graph(None);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2, Int>,
+                    tensor3: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    let tensor4 = {
+                        let written = tensor1
+                            .zeros_like()
+                            .scatter(1, tensor2.clone(), tensor3.ones_like())
+                            .bool();
+                        tensor1.mask_fill(written, 0).scatter(1, tensor2, tensor3)
+                    };
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements_add() {
+        let graph = scatter_elements_graph(Some(IndexReduceMode::Sum));
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2, Int>,
+                    tensor3: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    let tensor4 = tensor1.scatter(1, tensor2, tensor3);
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements_max() {
+        let graph = scatter_elements_graph(Some(IndexReduceMode::AMax));
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::IndexReduceMode;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2, Int>,
+                    tensor3: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    let tensor4 = tensor1.index_reduce(1, tensor2, tensor3, IndexReduceMode::AMax);
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
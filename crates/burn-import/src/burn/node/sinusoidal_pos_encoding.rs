@@ -0,0 +1,162 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+use burn::{
+    module::ConstantRecord,
+    nn::{SinusoidalPositionalEncodingConfig, SinusoidalPositionalEncodingRecord},
+    record::{PrecisionSettings, Record},
+};
+
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+/// Node for a sinusoidal positional encoding recognized from a constant table added to the input,
+/// which is mapped to a [SinusoidalPositionalEncoding](burn::nn::SinusoidalPositionalEncoding)
+/// module.
+#[derive(Debug, Clone)]
+pub struct SinusoidalPositionalEncodingNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: SinusoidalPositionalEncodingConfig,
+}
+
+impl SinusoidalPositionalEncodingNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        config: SinusoidalPositionalEncodingConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    SinusoidalPositionalEncoding<B>
+                },
+            ),
+            input,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SinusoidalPositionalEncodingNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_model = self.config.d_model.to_tokens();
+        let max_len = self.config.max_len.to_tokens();
+        let tokens = quote! {
+            let #name = SinusoidalPositionalEncodingConfig::new(#d_model)
+                .with_max_len(#max_len)
+                .init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // The sinusoids are generated when the module is initialized
+        let record = SinusoidalPositionalEncodingRecord::<SerializationBackend> {
+            sinusoids: ConstantRecord::new(),
+            amplitude: None,
+            max_len: ConstantRecord::new(),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = scope.field_use(&self.field.name);
+
+        quote! {
+            let #output = #field.forward(#input);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::SinusoidalPositionalEncoding");
+        imports.register("burn::nn::SinusoidalPositionalEncodingConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::SinusoidalPositionalEncoding(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SinusoidalPositionalEncodingNode::new(
+            "pos_encoding",
+            TensorType::new_float("input", 3),
+            TensorType::new_float("output", 3),
+            SinusoidalPositionalEncodingConfig::new(8).with_max_len(16),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::SinusoidalPositionalEncoding;
+            use burn::nn::SinusoidalPositionalEncodingConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                pos_encoding: SinusoidalPositionalEncoding<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let pos_encoding = SinusoidalPositionalEncodingConfig::new(8)
+                        .with_max_len(16)
+                        .init(device);
+
+                    Self {
+                        pos_encoding,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let output = self.pos_encoding.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
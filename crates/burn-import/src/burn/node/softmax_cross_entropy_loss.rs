@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::record::PrecisionSettings;
+
+use super::{Node, NodeCodegen, hinge_embedding_loss::LossReduction};
+use crate::burn::{BurnImports, Scope, TensorType, Type};
+
+/// Node for the cross-entropy between the softmax of the scores and the class labels, mapped to
+/// [cross_entropy_with_label_smoothing](burn::tensor::module::cross_entropy_with_label_smoothing)
+/// without smoothing.
+#[derive(Debug, Clone, new)]
+pub struct SoftmaxCrossEntropyLossNode {
+    pub scores: TensorType,
+    pub labels: TensorType,
+    pub output: TensorType,
+    pub reduction: LossReduction,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SoftmaxCrossEntropyLossNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.scores.clone()),
+            Type::Tensor(self.labels.clone()),
+        ]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let scores = scope.tensor_use_owned(&self.scores, node_position);
+        let labels = scope.tensor_use_owned(&self.labels, node_position);
+        let output = &self.output.name;
+
+        match self.reduction {
+            LossReduction::None => quote! {
+                let #output = burn::tensor::module::cross_entropy_with_label_smoothing(
+                    #scores,
+                    #labels,
+                    0.0,
+                );
+            },
+            LossReduction::Mean => quote! {
+                let #output = cross_entropy_with_label_smoothing(#scores, #labels, 0.0, Reduction::Mean);
+            },
+            LossReduction::Sum => quote! {
+                let #output = cross_entropy_with_label_smoothing(#scores, #labels, 0.0, Reduction::Sum);
+            },
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        match self.reduction {
+            LossReduction::None => {}
+            LossReduction::Mean | LossReduction::Sum => {
+                imports.register("burn::nn::loss::cross_entropy_with_label_smoothing");
+                imports.register("burn::nn::loss::Reduction");
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::SoftmaxCrossEntropyLoss(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+
+    #[test]
+    fn test_codegen_softmax_cross_entropy_loss() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SoftmaxCrossEntropyLossNode::new(
+            TensorType::new_float("scores", 2),
+            TensorType::new_int("labels", 1),
+            TensorType::new_float("loss", 1),
+            LossReduction::Mean,
+        ));
+
+        graph.register_input_output(
+            vec!["scores".to_string(), "labels".to_string()],
+            vec!["loss".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::loss::Reduction;
+            use burn::nn::loss::cross_entropy_with_label_smoothing;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, scores: Tensor<B, 2>, labels: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+                    let loss = cross_entropy_with_label_smoothing(scores, labels, 0.0, Reduction::Mean);
+
+                    loss
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,33 +1,51 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
 
 #[derive(Debug, Clone, new)]
 pub struct SqueezeNode {
-    pub input: TensorType,
-    pub output: TensorType,
+    pub input: Type,
+    pub output: Type,
     pub axes: Vec<i64>,
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for SqueezeNode {
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        vec![self.output.clone()]
     }
 
     fn input_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.input.clone())]
+        vec![self.input.clone()]
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
-        let output = &self.output.name;
+        match (&self.input, &self.output) {
+            (Type::Tensor(input), Type::Tensor(output)) => {
+                let input = scope.tensor_use_owned(input, node_position);
+                let output = &output.name;
 
-        let axes_arg = &self.axes.to_tokens();
+                let axes_arg = &self.axes.to_tokens();
 
-        quote! {
-            let #output = #input.squeeze_dims(&#axes_arg);
+                quote! {
+                    let #output = #input.squeeze_dims(&#axes_arg);
+                }
+            }
+            // The single dim of a shape is read as a scalar, without a device tensor
+            (Type::Shape(input), Type::Scalar(output)) => {
+                let input = &input.name;
+                let output_ty = output.ty();
+                let output = &output.name;
+
+                quote! {
+                    let #output = #input[0] as #output_ty;
+                }
+            }
+            _ => panic!(
+                "SqueezeNode received unsupported types: expected tensor to tensor or shape to scalar but got {:?} to {:?}",
+                self.input, self.output
+            ),
         }
     }
 
@@ -42,7 +60,7 @@ mod tests {
 
     use super::*;
     use crate::burn::{
-        TensorType,
+        ScalarKind, ScalarType, ShapeType, TensorType,
         graph::BurnGraph,
         node::{squeeze::SqueezeNode, test::assert_tokens},
     };
@@ -52,8 +70,8 @@ mod tests {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
 
         graph.register(SqueezeNode::new(
-            TensorType::new_float("tensor1", 3),
-            TensorType::new_float("tensor2", 2),
+            Type::Tensor(TensorType::new_float("tensor1", 3)),
+            Type::Tensor(TensorType::new_float("tensor2", 2)),
             [1].into(),
         ));
 
@@ -89,4 +107,47 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_shape_to_scalar() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SqueezeNode::new(
+            Type::Shape(ShapeType::new("shape1", 1)),
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+            [0].into(),
+        ));
+
+        graph.register_input_output(vec!["shape1".to_string()], vec!["scalar1".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, shape1: [usize; 1]) -> i64 {
+                    let scalar1 = shape1[0] as i64;
+                    scalar1
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
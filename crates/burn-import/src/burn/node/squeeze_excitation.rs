@@ -0,0 +1,192 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+use burn::{
+    module::ConstantRecord,
+    nn::{
+        LinearRecord, SqueezeExcitationActivation, SqueezeExcitationConfig, SqueezeExcitationRecord,
+    },
+    record::{PrecisionSettings, Record},
+    tensor::TensorData,
+};
+
+use super::{Node, NodeCodegen, SerializationBackend, param};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+/// The parameters of a [SqueezeExcitationNode], in the layout of linear layers.
+#[derive(Debug, Clone, new)]
+pub struct SqueezeExcitationData {
+    pub fc1_weight: TensorData,
+    pub fc1_bias: TensorData,
+    pub fc2_weight: TensorData,
+    pub fc2_bias: TensorData,
+}
+
+/// Node for a squeeze-and-excitation recognized from its global average pooling and pointwise
+/// convolutions, which is mapped to a [SqueezeExcitation](burn::nn::SqueezeExcitation) module.
+#[derive(Debug, Clone)]
+pub struct SqueezeExcitationNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub data: SqueezeExcitationData,
+    pub config: SqueezeExcitationConfig,
+}
+
+impl SqueezeExcitationNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        data: SqueezeExcitationData,
+        config: SqueezeExcitationConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    SqueezeExcitation<B>
+                },
+            ),
+            input,
+            output,
+            data,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SqueezeExcitationNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let channels = self.config.channels.to_tokens();
+        let squeeze_channels = self.config.squeeze_channels.to_tokens();
+        let activation = match self.config.activation {
+            SqueezeExcitationActivation::Relu => quote! { SqueezeExcitationActivation::Relu },
+            SqueezeExcitationActivation::Silu => quote! { SqueezeExcitationActivation::Silu },
+        };
+
+        let tokens = quote! {
+            let #name = SqueezeExcitationConfig::new(#channels, #squeeze_channels)
+                .with_activation(#activation)
+                .init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = &self.data;
+
+        let record = SqueezeExcitationRecord::<SerializationBackend> {
+            fc1: LinearRecord {
+                weight: param::<PS, 2>(&data.fc1_weight),
+                bias: Some(param::<PS, 1>(&data.fc1_bias)),
+            },
+            fc2: LinearRecord {
+                weight: param::<PS, 2>(&data.fc2_weight),
+                bias: Some(param::<PS, 1>(&data.fc2_bias)),
+            },
+            activation: ConstantRecord::new(),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = scope.field_use(&self.field.name);
+
+        quote! {
+            let #output = #field.forward(#input);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::SqueezeExcitation");
+        imports.register("burn::nn::SqueezeExcitationActivation");
+        imports.register("burn::nn::SqueezeExcitationConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::SqueezeExcitation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{TensorType, graph::BurnGraph, node::test::assert_tokens};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let zeros = |shape: &[usize]| TensorData::zeros::<f32, _>(shape.to_vec());
+
+        graph.register(SqueezeExcitationNode::new(
+            "se",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            SqueezeExcitationData::new(zeros(&[8, 2]), zeros(&[2]), zeros(&[2, 8]), zeros(&[8])),
+            SqueezeExcitationConfig::new(8, 2).with_activation(SqueezeExcitationActivation::Silu),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::nn::SqueezeExcitation;
+            use burn::nn::SqueezeExcitationActivation;
+            use burn::nn::SqueezeExcitationConfig;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                se: SqueezeExcitation<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let se = SqueezeExcitationConfig::new(8, 2)
+                        .with_activation(SqueezeExcitationActivation::Silu)
+                        .init(device);
+
+                    Self {
+                        se,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.se.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,121 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Stacks tensors of the same shape along a new dimension, fused from the Unsqueeze and Concat
+/// nodes exported for `torch.stack`.
+#[derive(Debug, Clone, new)]
+pub struct StackNode {
+    pub inputs: Vec<TensorType>,
+    pub output: TensorType,
+    pub dim: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for StackNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|t| Type::Tensor(t.clone()))
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let dim = self.dim.to_tokens();
+        let rank = self.output.rank.to_tokens();
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|t| scope.tensor_use_owned(t, node_position));
+
+        let output = &self.output.name;
+
+        quote! {
+            let #output = Tensor::stack::<#rank>([#(#inputs),*].into(), #dim);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Stack(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{stack::StackNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_stack() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(StackNode::new(
+            vec![
+                TensorType::new_float("tensor1", 2),
+                TensorType::new_float("tensor2", 2),
+                TensorType::new_float("tensor3", 2),
+            ],
+            TensorType::new_float("tensor4", 3),
+            2,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2>,
+                    tensor3: Tensor<B, 2>
+                ) -> Tensor<B, 3> {
+                    let tensor4 = Tensor::stack::<3>([tensor1, tensor2, tensor3].into(), 2);
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,7 +1,7 @@
 use super::{Node, NodeCodegen};
 use crate::burn::{BurnImports, Scope, TensorKind, ToTokens, Type};
 use burn::record::PrecisionSettings;
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 use std::rc::Rc;
 
@@ -121,7 +121,11 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
-            _ => panic!("lhs must be a tensor or scalar"),
+            Type::Shape(shape) => {
+                let name = shape.name.clone();
+                quote! { #name }
+            }
+            _ => panic!("lhs must be a tensor, scalar or shape"),
         };
 
         let output = &self.output.name();
@@ -162,6 +166,9 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
                 if input_kind == TensorKind::Int || output_kind == TensorKind::Int {
                     imports.register("burn::tensor::Int");
                 }
+                if let Type::Shape(_) = self.output {
+                    imports.register("alloc::vec::Vec");
+                }
             }
             _ => {}
         }
@@ -296,11 +303,14 @@ impl UnaryNode {
 
     pub(crate) fn not(input: Type, output: Type) -> Self {
         // Not ONNX operator is constrained to bool tensors, so no need to check the type.
-        let function = move |input| quote! { #input.bool_not()};
+        let function = move |input| quote! { #input.logical_not() };
         Self::new(input, output, UnaryNodeKind::Not, Rc::new(function))
     }
 
     /// Casts the input to the output type.
+    ///
+    /// Casting to the same type (e.g. float to float) forwards the input as is, without any cast
+    /// call in the generated code.
     pub(crate) fn cast(input: Type, output: Type) -> Self {
         match (input.clone(), output.clone()) {
             (Type::Scalar(input_scalar), Type::Scalar(output_scalar)) => {
@@ -348,69 +358,150 @@ impl UnaryNode {
                     )
                 }
             }
-            _ => panic!("output must be a tensor or scalar"),
-        }
-    }
+            (Type::Shape(shape), Type::Tensor(output_tensor)) => {
+                // The shape is uploaded as an Int tensor, then cast like any Int tensor
+                let tensor = shape.to_tensor();
+                let function = match output_tensor.kind {
+                    TensorKind::Bool => quote! { #tensor.bool() },
+                    TensorKind::Int => tensor,
+                    TensorKind::Float => quote! { #tensor.float() },
+                };
 
-    pub(crate) fn reduce_max(input: Type, output: Type, dim: Option<usize>) -> Self {
-        if let Type::Tensor(ref tensor) = output {
-            if let Some(dim) = dim {
-                if tensor.kind == TensorKind::Bool {
-                    // Max is only implemented on numeric tensors
-                    panic!("ReduceMax is not supported for boolean");
+                Self::new(
+                    input,
+                    output,
+                    UnaryNodeKind::Cast(Some(TensorKind::Int), Some(output_tensor.kind)),
+                    Rc::new(move |_| function.clone()),
+                )
+            }
+            (Type::Tensor(input_tensor), Type::Shape(_)) => {
+                if input_tensor.rank != 1 || input_tensor.kind != TensorKind::Int {
+                    panic!(
+                        "Cast to a shape needs a rank 1 Int tensor, got a rank {} {:?} tensor",
+                        input_tensor.rank, input_tensor.kind
+                    );
                 }
 
-                // ReduceMax, keepdims=1, axes=[dim]
-                let dim = dim.to_tokens();
+                // The dims are read back from the device, the length is checked against the rank
+                // of the shape when converted to an array
                 Self::new(
                     input,
                     output,
-                    UnaryNodeKind::ReduceMax,
-                    Rc::new(move |input| quote! { #input.max_dim(#dim) }),
+                    UnaryNodeKind::Cast(Some(TensorKind::Int), Some(TensorKind::Int)),
+                    Rc::new(|input| {
+                        quote! {
+                            #input.to_data().iter::<i64>().map(|dim| dim as usize).collect::<Vec<_>>()
+                        }
+                    }),
                 )
-            } else {
-                // ReduceMax, keepdims=0, axes=None
+            }
+            _ => panic!("Cast needs matching scalar or tensor types, or a shape and a tensor"),
+        }
+    }
+
+    /// Casts a float tensor to an integer tensor, saturating at the `[min, max]` range of the
+    /// target integer type.
+    ///
+    /// The fractional part is truncated toward zero, so `2.7` becomes `2` and `-2.7` becomes `-2`,
+    /// while values outside of the range are clamped to `min` and `max` instead of wrapping around.
+    /// Other casts are handled like [cast](Self::cast).
+    pub(crate) fn saturating_cast(input: Type, output: Type, min: i64, max: i64) -> Self {
+        match (&input, &output) {
+            (Type::Tensor(input_tensor), Type::Tensor(output_tensor))
+                if input_tensor.kind == TensorKind::Float
+                    && output_tensor.kind == TensorKind::Int =>
+            {
+                // The conversion of values outside of the range of the backend integer type
+                // overflows, so they are clamped before it. `i32::MAX as f32` rounds up to 2^31,
+                // which the 64-bit backend integer type still holds, and the integer clamp then
+                // produces the exact bounds of the target type.
+                let (min_float, max_float) = (
+                    Literal::f64_unsuffixed(min as f32 as f64),
+                    Literal::f64_unsuffixed(max as f32 as f64),
+                );
                 Self::new(
                     input,
                     output,
-                    UnaryNodeKind::ReduceMax,
-                    Rc::new(move |input| quote! { #input.max() }),
+                    UnaryNodeKind::Cast(Some(TensorKind::Float), Some(TensorKind::Int)),
+                    Rc::new(move |input| {
+                        quote! {
+                            #input.clamp(#min_float, #max_float).int().clamp(#min, #max)
+                        }
+                    }),
                 )
             }
+            _ => Self::cast(input, output),
+        }
+    }
+
+    pub(crate) fn reduce_max(input: Type, output: Type, dims: Vec<usize>, keepdims: bool) -> Self {
+        if let Type::Tensor(ref tensor) = output {
+            if tensor.kind == TensorKind::Bool {
+                // Max is only implemented on numeric tensors
+                panic!("ReduceMax is not supported for boolean");
+            }
+
+            let function = Self::reduce_dims(
+                &input,
+                tensor.rank,
+                dims,
+                keepdims,
+                [quote! { max }, quote! { max_dim }, quote! { amax }],
+            );
+            Self::new(input, output, UnaryNodeKind::ReduceMax, function)
         } else {
             panic!("ReduceMax only supports tensor output");
         }
     }
 
-    pub(crate) fn reduce_min(input: Type, output: Type, dim: Option<usize>) -> Self {
+    pub(crate) fn reduce_min(input: Type, output: Type, dims: Vec<usize>, keepdims: bool) -> Self {
         if let Type::Tensor(ref tensor) = output {
-            if let Some(dim) = dim {
-                if tensor.kind == TensorKind::Bool {
-                    // Min is only implemented on numeric tensors
-                    panic!("ReduceMin is not supported for boolean");
-                }
-                // ReduceMin, keepdims=1, axes=[dim]
-                let dim = dim.to_tokens();
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceMin,
-                    Rc::new(move |input| quote! { #input.min_dim(#dim) }),
-                )
-            } else {
-                // ReduceMin, keepdims=0, axes=None
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceMin,
-                    Rc::new(move |input| quote! { #input.min() }),
-                )
+            if tensor.kind == TensorKind::Bool {
+                // Min is only implemented on numeric tensors
+                panic!("ReduceMin is not supported for boolean");
             }
+
+            let function = Self::reduce_dims(
+                &input,
+                tensor.rank,
+                dims,
+                keepdims,
+                [quote! { min }, quote! { min_dim }, quote! { amin }],
+            );
+            Self::new(input, output, UnaryNodeKind::ReduceMin, function)
         } else {
             panic!("ReduceMin only supports tensor output");
         }
     }
 
+    /// Reduce the input over `dims` with the aggregation over all the elements, over one dim or
+    /// over several dims, e.g. `max`, `max_dim` and `amax`.
+    fn reduce_dims(
+        input: &Type,
+        output_rank: usize,
+        dims: Vec<usize>,
+        keepdims: bool,
+        [all, dim, multi_dims]: [TokenStream; 3],
+    ) -> FnPointer {
+        let input_rank = match input {
+            Type::Tensor(tensor) => tensor.rank,
+            _ => panic!("Reduce only supports tensor input"),
+        };
+
+        if !keepdims && dims.len() == input_rank {
+            // keepdims=0, all the axes
+            Rc::new(move |input| quote! { #input.#all() })
+        } else if keepdims && dims.len() == 1 {
+            // keepdims=1, axes=[dim]
+            let dim_arg = dims[0].to_tokens();
+            Rc::new(move |input| quote! { #input.#dim(#dim_arg) })
+        } else {
+            let rank = output_rank.to_tokens();
+            let dims = dims.iter().map(|dim| dim.to_tokens()).collect::<Vec<_>>();
+            Rc::new(move |input| quote! { #input.#multi_dims::<#rank>(&[#(#dims),*], #keepdims) })
+        }
+    }
+
     pub(crate) fn reduce_mean(input: Type, output: Type, dim: Option<usize>) -> Self {
         // ReduceMean is constrained to numeric tensors, so no need to check for bool.
         if let Type::Tensor(_) = output {
@@ -437,31 +528,21 @@ impl UnaryNode {
         }
     }
 
-    pub(crate) fn reduce_prod(input: Type, output: Type, dim: Option<usize>) -> Self {
+    pub(crate) fn reduce_prod(input: Type, output: Type, dims: Vec<usize>, keepdims: bool) -> Self {
         if let Type::Tensor(ref tensor) = output {
-            if let Some(dim) = dim {
-                if tensor.kind == TensorKind::Bool {
-                    // Prod is only implemented on numeric tensors
-                    panic!("ReduceProd is not supported for boolean");
-                }
-
-                // ReduceProd, keepdims=1, axes=[dim]
-                let dim = dim.to_tokens();
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceProd,
-                    Rc::new(move |input| quote! { #input.prod_dim(#dim) }),
-                )
-            } else {
-                // ReduceProd, keepdims=0, axes=None
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceProd,
-                    Rc::new(move |input| quote! { #input.prod() }),
-                )
+            if tensor.kind == TensorKind::Bool {
+                // Prod is only implemented on numeric tensors
+                panic!("ReduceProd is not supported for boolean");
             }
+
+            let function = Self::reduce_dims(
+                &input,
+                tensor.rank,
+                dims,
+                keepdims,
+                [quote! { prod }, quote! { prod_dim }, quote! { prod_dims }],
+            );
+            Self::new(input, output, UnaryNodeKind::ReduceProd, function)
         } else {
             panic!("ReduceProd only supports tensor output");
         }
@@ -746,7 +827,8 @@ mod tests {
             UnaryNode::reduce_max(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 4)),
-                Some(1),
+                vec![1],
+                true,
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -763,7 +845,8 @@ mod tests {
             UnaryNode::reduce_max(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 1)),
-                None,
+                vec![0, 1, 2, 3],
+                false,
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 1> {
@@ -775,6 +858,42 @@ mod tests {
             vec!["tensor1".to_string()],
             vec!["tensor2".to_string()],
         );
+
+        one_node_graph(
+            UnaryNode::reduce_max(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                vec![1, 2],
+                true,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.amax::<4>(&[1, 2], true);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        one_node_graph(
+            UnaryNode::reduce_max(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                vec![1, 2],
+                false,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.amax::<2>(&[1, 2], false);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
     }
 
     #[test]
@@ -783,7 +902,8 @@ mod tests {
             UnaryNode::reduce_min(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 4)),
-                Some(1),
+                vec![1],
+                true,
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -800,7 +920,8 @@ mod tests {
             UnaryNode::reduce_min(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 1)),
-                None,
+                vec![0, 1, 2, 3],
+                false,
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 1> {
@@ -812,6 +933,42 @@ mod tests {
             vec!["tensor1".to_string()],
             vec!["tensor2".to_string()],
         );
+
+        one_node_graph(
+            UnaryNode::reduce_min(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                vec![1, 2],
+                true,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.amin::<4>(&[1, 2], true);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        one_node_graph(
+            UnaryNode::reduce_min(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                vec![1, 2],
+                false,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.amin::<2>(&[1, 2], false);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
     }
 
     #[test]
@@ -857,7 +1014,8 @@ mod tests {
             UnaryNode::reduce_prod(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 4)),
-                Some(1),
+                vec![1],
+                true,
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -874,7 +1032,8 @@ mod tests {
             UnaryNode::reduce_prod(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 1)),
-                None,
+                vec![0, 1, 2, 3],
+                false,
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 1> {
@@ -886,6 +1045,24 @@ mod tests {
             vec!["tensor1".to_string()],
             vec!["tensor2".to_string()],
         );
+
+        one_node_graph(
+            UnaryNode::reduce_prod(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 3)),
+                vec![2],
+                false,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.prod_dims::<3>(&[2], false);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
     }
 
     #[test]
@@ -1023,6 +1200,156 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_cast_same_type() {
+        one_node_graph(
+            UnaryNode::cast(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1;
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+        one_node_graph(
+            UnaryNode::cast(
+                Type::Tensor(TensorType::new_int("tensor1", 2)),
+                Type::Tensor(TensorType::new_int("tensor2", 2)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2, Int>) -> Tensor<B, 2, Int> {
+                    let tensor2 = tensor1;
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+        one_node_graph(
+            UnaryNode::cast(
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Float32)),
+                Type::Scalar(ScalarType::new("scalar2", ScalarKind::Float32)),
+            ),
+            quote! {
+                pub fn forward(&self, scalar1: f32) -> f32 {
+                    let scalar2 = scalar1;
+
+                    scalar2
+                }
+            },
+            vec!["scalar1".to_string()],
+            vec!["scalar2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_cast_shape() {
+        one_node_graph(
+            UnaryNode::cast(
+                Type::Shape(ShapeType::new("shape1", 3)),
+                Type::Tensor(TensorType::new_int("tensor1", 1)),
+            ),
+            quote! {
+                pub fn forward(&self, shape1: [usize; 3]) -> Tensor<B, 1, Int> {
+                    let tensor1 = Tensor::<B, 1, burn::tensor::Int>::from_data(
+                        &shape1 as &[_],
+                        &*self.device
+                    );
+
+                    tensor1
+                }
+            },
+            vec!["shape1".to_string()],
+            vec!["tensor1".to_string()],
+        );
+        one_node_graph(
+            UnaryNode::cast(
+                Type::Tensor(TensorType::new_int("tensor1", 1)),
+                Type::Shape(ShapeType::new("shape1", 3)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 1, Int>) -> [usize; 3] {
+                    let shape1: [usize; 3] = tensor1
+                        .to_data()
+                        .iter::<i64>()
+                        .map(|dim| dim as usize)
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .unwrap();
+
+                    shape1
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["shape1".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_saturating_cast() {
+        one_node_graph(
+            UnaryNode::saturating_cast(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_int("tensor2", 4)),
+                i8::MIN as i64,
+                i8::MAX as i64,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4, Int> {
+                    let tensor2 = tensor1.clamp(-128.0, 127.0).int().clamp(-128i64, 127i64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+        one_node_graph(
+            UnaryNode::saturating_cast(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_int("tensor2", 4)),
+                i32::MIN as i64,
+                i32::MAX as i64,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4, Int> {
+                    let tensor2 = tensor1
+                        .clamp(-2147483648.0, 2147483648.0)
+                        .int()
+                        .clamp(-2147483648i64, 2147483647i64);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+        one_node_graph(
+            UnaryNode::saturating_cast(
+                Type::Tensor(TensorType::new_int("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                i8::MIN as i64,
+                i8::MAX as i64,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4, Int>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.float();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_cos() {
         one_node_graph(
@@ -1165,7 +1492,7 @@ mod tests {
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4, Bool>) -> Tensor<B, 4, Bool> {
-                    let tensor2 = tensor1.bool_not();
+                    let tensor2 = tensor1.logical_not();
 
                     tensor2
                 }
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, Scope, TensorKind, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -7,7 +7,7 @@ use quote::quote;
 #[derive(Debug, Clone, new)]
 pub struct UnsqueezeNode {
     pub input: Type,
-    pub output: TensorType,
+    pub output: Type,
     pub axes: UnsqueezeAxes,
 }
 
@@ -19,7 +19,7 @@ pub enum UnsqueezeAxes {
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        vec![self.output.clone()]
     }
 
     fn input_types(&self) -> Vec<Type> {
@@ -30,8 +30,29 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
         }
     }
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let output_name = &self.output.name;
-        let output_rank = self.output.rank.to_tokens();
+        let output = match &self.output {
+            Type::Tensor(output) => output,
+            Type::Shape(output) => {
+                // An integer scalar becomes a shape with a single dim, without a device tensor
+                let Type::Scalar(scalar) = &self.input else {
+                    panic!(
+                        "UnsqueezeNode can only produce a shape from a scalar, got {:?}",
+                        self.input
+                    );
+                };
+                let scalar_name = &scalar.name;
+                let output_name = &output.name;
+                return quote! {
+                    let #output_name: [usize; 1] = [#scalar_name as usize];
+                };
+            }
+            _ => panic!(
+                "UnsqueezeNode received unsupported output type: expected tensor or shape but got {:?}",
+                self.output
+            ),
+        };
+        let output_name = &output.name;
+        let output_rank = output.rank.to_tokens();
 
         let axes = match &self.axes {
             UnsqueezeAxes::Static(static_axes) => static_axes.to_tokens(),
@@ -56,8 +77,13 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
             }
             Type::Scalar(scalar) => {
                 let scalar_name = &scalar.name;
-                quote! {
-                    let #output_name = Tensor::<B, #output_rank>::from_data([#scalar_name.elem::<B::FloatElem>()], &self.device).unsqueeze();
+                match output.kind {
+                    TensorKind::Int => quote! {
+                        let #output_name = Tensor::<B, #output_rank, Int>::from_data([#scalar_name.elem::<B::IntElem>()], &self.device).unsqueeze();
+                    },
+                    _ => quote! {
+                        let #output_name = Tensor::<B, #output_rank>::from_data([#scalar_name.elem::<B::FloatElem>()], &self.device).unsqueeze();
+                    },
                 }
             }
             _ => panic!(
@@ -72,9 +98,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
     }
 
     fn register_imports(&self, imports: &mut BurnImports) {
-        match &self.input {
-            Type::Scalar(_) => {
+        match (&self.input, &self.output) {
+            (Type::Scalar(_), Type::Tensor(output)) => {
                 imports.register("burn::tensor::ElementConversion");
+                if output.kind == TensorKind::Int {
+                    imports.register("burn::tensor::Int");
+                }
             }
             _ => {}
         }
@@ -94,7 +123,7 @@ mod tests {
 
     use super::*;
     use crate::burn::{
-        TensorType, Type,
+        ScalarKind, ScalarType, ShapeType, TensorType, Type,
         graph::BurnGraph,
         node::{test::assert_tokens, unsqueeze::UnsqueezeNode},
     };
@@ -105,7 +134,7 @@ mod tests {
 
         graph.register(UnsqueezeNode::new(
             Type::Tensor(TensorType::new_float("tensor1", 3)),
-            TensorType::new_float("tensor2", 5),
+            Type::Tensor(TensorType::new_float("tensor2", 5)),
             UnsqueezeAxes::Static([0, 4].into()),
         ));
 
@@ -141,4 +170,47 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_scalar_to_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnsqueezeNode::new(
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+            Type::Shape(ShapeType::new("shape1", 1)),
+            UnsqueezeAxes::Static([0].into()),
+        ));
+
+        graph.register_input_output(vec!["scalar1".to_string()], vec!["shape1".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, scalar1: i64) -> [usize; 1] {
+                    let shape1: [usize; 1] = [scalar1 as usize];
+                    shape1
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -8,6 +8,7 @@ use std::collections::HashMap;
 #[derive(Clone, Debug, Default)]
 pub struct Scope {
     variables: HashMap<Ident, TensorVariable>,
+    half_precision_params: bool,
 }
 
 #[derive(Clone, Debug, new)]
@@ -17,6 +18,33 @@ struct TensorVariable {
 }
 
 impl Scope {
+    /// Set whether the parameters of the model are stored in half precision.
+    pub fn set_half_precision_params(&mut self, half_precision_params: bool) {
+        self.half_precision_params = half_precision_params;
+    }
+
+    /// Create an empty scope for the nodes of a nested block, keeping the settings of this scope.
+    pub fn nested(&self) -> Self {
+        Self {
+            variables: HashMap::new(),
+            half_precision_params: self.half_precision_params,
+        }
+    }
+
+    /// Use a field of the model, upcasting its float parameters to full precision when they are
+    /// stored in half precision.
+    pub fn field_use(&self, field: &Ident) -> TokenStream {
+        if self.half_precision_params {
+            quote! {
+                self.#field.clone().map(&mut FloatCaster { dtype: FloatDType::F32 })
+            }
+        } else {
+            quote! {
+                self.#field
+            }
+        }
+    }
+
     /// Declare a new tensor variable.
     pub fn tensor_register_variable(&mut self, tensor: &TensorType, node_position: usize) {
         if let Some(variable) = self.variables.get_mut(&tensor.name) {
@@ -68,4 +96,18 @@ impl Scope {
             panic!("No variable with name {}", &tensor.name);
         }
     }
+
+    /// Use a tensor variable inside a nested block with its own scope, returning whether the tensor
+    /// will still be used afterward.
+    pub fn tensor_use_nested(&mut self, tensor: &TensorType, node_position: usize) -> bool {
+        if let Some(variable) = self.variables.get_mut(&tensor.name) {
+            if node_position >= variable.node_position {
+                variable.references -= 1;
+            }
+
+            variable.references > 0
+        } else {
+            panic!("No variable with name {}", &tensor.name);
+        }
+    }
 }
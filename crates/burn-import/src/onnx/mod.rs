@@ -1,3 +1,4 @@
 mod op_configuration;
 mod to_burn;
+mod trt_plugin;
 pub use to_burn::*;
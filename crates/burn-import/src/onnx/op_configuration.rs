@@ -3,19 +3,28 @@
 
 use burn::nn::{
     BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
-    PaddingConfig2d, PaddingConfig3d,
+    PaddingConfig2d, PaddingConfig3d, RelativePositionBiasConfig,
+    SinusoidalPositionalEncodingConfig, SqueezeExcitationActivation, SqueezeExcitationConfig,
+    attention::MultiHeadAttentionConfig,
     conv::{
-        Conv1dConfig, Conv2dConfig, Conv3dConfig, ConvTranspose1dConfig, ConvTranspose2dConfig,
-        ConvTranspose3dConfig,
+        Conv1dConfig, Conv2dConfig, Conv3dConfig, ConvNeXtBlockConfig, ConvTranspose1dConfig,
+        ConvTranspose2dConfig, ConvTranspose3dConfig, DepthwiseSeparableConv2dConfig,
+    },
+    loss::HingeEmbeddingLossConfig,
+    pool::{
+        AdaptiveMaxPool2dConfig, AvgPool1dConfig, AvgPool2dConfig, MaxPool1dConfig, MaxPool2dConfig,
     },
-    pool::{AvgPool1dConfig, AvgPool2dConfig, MaxPool1dConfig, MaxPool2dConfig},
 };
 
 use crate::burn::node::{
-    expand::ExpandShape, pad::PadConfig, split::SplitConfig, tile::TileConfig, top_k::TopKConfig,
-    trilu::TriluConfig, unsqueeze::UnsqueezeAxes,
+    expand::ExpandShape, hinge_embedding_loss::LossReduction, pad::PadConfig,
+    repeat_interleave::RepeatInterleaveRepeats, reshape::ReshapeShape, resize::ResizeTarget,
+    split::SplitConfig, tile::TileConfig, top_k::TopKConfig, trilu::TriluConfig,
+    unsqueeze::UnsqueezeAxes,
 };
-use onnx_ir::ir::{ArgType, AttributeValue, Data, ElementType, Node, TensorData};
+use burn::tensor::IndexReduceMode;
+use onnx_ir::ir::{ArgType, AttributeValue, Data, ElementType, Node, OnnxGraph, TensorData};
+use onnx_ir::node::concat::{concat_axis, concat_broadcast_shape};
 
 /// Create a Conv1dConfig from the attributes of the node
 pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
@@ -106,6 +115,139 @@ pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
     .with_padding(padding)
 }
 
+/// Create a Conv2dConfig, without bias, and the zero points of the input and the weights from
+/// the attributes and the constant inputs of a ConvInteger node
+///
+/// The weight zero point is either per tensor or per output channel, and absent zero points are 0.
+pub fn conv_integer_config(curr: &Node) -> (Conv2dConfig, i32, Vec<i32>) {
+    let weight_shape = &curr.inputs[1]
+        .value
+        .as_ref()
+        .expect("ConvInteger: weight tensor must be present")
+        .shape;
+    if weight_shape.len() != 4 {
+        panic!(
+            "ConvInteger: only 2D convolutions are supported, got weights of rank {}",
+            weight_shape.len()
+        );
+    }
+
+    let zero_point = |index: usize| match curr.inputs.get(index) {
+        Some(input) if !input.name.is_empty() => input
+            .value
+            .as_ref()
+            .unwrap_or_else(|| panic!("ConvInteger: zero point {} must be constant", input.name))
+            .data
+            .clone()
+            .into_i32s(),
+        _ => vec![0],
+    };
+    let input_zero_point = zero_point(2);
+    if input_zero_point.len() != 1 {
+        panic!("ConvInteger: the input zero point must be a scalar");
+    }
+
+    let weight_zero_point = zero_point(3);
+    let out_channels = weight_shape[0];
+    if weight_zero_point.len() != 1 && weight_zero_point.len() != out_channels {
+        panic!(
+            "ConvInteger: the weight zero point must be a scalar or have one value per output channel ({out_channels}), got {} values",
+            weight_zero_point.len()
+        );
+    }
+
+    // The zero points are inputs, unlike the bias of Conv
+    let config = conv2d_config(curr).with_bias(false);
+
+    (config, input_zero_point[0], weight_zero_point)
+}
+
+/// Create a ConvNeXtBlockConfig from the depthwise convolution weights and the attributes of the node
+pub fn convnext_block_config(curr: &Node) -> ConvNeXtBlockConfig {
+    let weight_shape = &curr.inputs[1]
+        .value
+        .as_ref()
+        .expect("ConvNeXtBlock: depthwise convolution weight tensor must be present")
+        .shape;
+
+    // The layer scale is the last parameter when present, its value is loaded with the weights
+    let layer_scale_init = if curr.inputs.len() == 10 { 1e-6 } else { 0.0 };
+    let epsilon = curr
+        .attrs
+        .get("epsilon")
+        .map_or(1e-5, |epsilon| epsilon.clone().into_f32() as f64);
+
+    ConvNeXtBlockConfig::new(weight_shape[0])
+        .with_layer_scale_init(layer_scale_init)
+        .with_epsilon(epsilon)
+}
+
+/// Create a DepthwiseSeparableConv2dConfig from the weights of both convolutions and the attributes
+/// of the depthwise convolution
+pub fn depthwise_separable_conv2d_config(curr: &Node) -> DepthwiseSeparableConv2dConfig {
+    let mut kernel_shape = Vec::new();
+    let mut strides = vec![1, 1];
+    let mut pads = vec![0, 0, 0, 0];
+    let mut dilations = vec![1, 1];
+
+    let weight_shape = |index: usize| {
+        curr.inputs[index]
+            .value
+            .as_ref()
+            .expect("DepthwiseSeparableConv2d: weight tensors must be present")
+            .shape[0]
+    };
+
+    // the biases of both convolutions are present if any is
+    let bias = curr.inputs.len() == 5;
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => kernel_shape = value.clone().into_i64s(),
+            "strides" => strides = value.clone().into_i64s(),
+            "pads" => pads = value.clone().into_i64s(),
+            "dilations" => dilations = value.clone().into_i64s(),
+            _ => {}
+        }
+    }
+
+    // the channels are inverted in the weight tensors
+    let channels_in = weight_shape(1);
+    let channels_out = weight_shape(2);
+
+    let padding = padding_config_2d(&pads);
+
+    DepthwiseSeparableConv2dConfig::new(
+        [channels_in, channels_out],
+        [kernel_shape[0] as usize, kernel_shape[1] as usize],
+    )
+    .with_stride([strides[0] as usize, strides[1] as usize])
+    .with_dilation([dilations[0] as usize, dilations[1] as usize])
+    .with_bias(bias)
+    .with_padding(padding)
+}
+
+/// Create a SqueezeExcitationConfig from the weights of the first linear layer and the
+/// activation of the node
+pub fn squeeze_excitation_config(curr: &Node) -> SqueezeExcitationConfig {
+    let weight_shape = &curr.inputs[1]
+        .value
+        .as_ref()
+        .expect("SqueezeExcitation: weight tensor must be present")
+        .shape;
+
+    let activation = match curr.attrs.get("activation") {
+        Some(activation) => match activation.clone().into_string().as_str() {
+            "relu" => SqueezeExcitationActivation::Relu,
+            "silu" => SqueezeExcitationActivation::Silu,
+            activation => panic!("SqueezeExcitation: unsupported activation {activation}"),
+        },
+        None => SqueezeExcitationActivation::Relu,
+    };
+
+    SqueezeExcitationConfig::new(weight_shape[0], weight_shape[1]).with_activation(activation)
+}
+
 /// Create a Conv3dConfig from the attributes of the node
 pub fn conv3d_config(curr: &Node) -> Conv3dConfig {
     let mut kernel_shape = Vec::new(); // TODO default inferred from weight tensor per spec
@@ -216,6 +358,58 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
         .with_dilation([dilations[0] as usize, dilations[1] as usize])
 }
 
+/// Create an AdaptiveMaxPool2dConfig from the attributes of a MaxPool2d node pooling its whole
+/// input, in which case the max pooling is simplified to a global adaptive one.
+///
+/// This requires a known input shape, unit dilations, no padding (`auto_pad=VALID` or zero pads)
+/// and a kernel spanning the whole spatial input. The `1x1` output of the adaptive pooling then
+/// holds for any input resolution, unlike a kernel frozen to the exported shape. Returns `None`
+/// for any other max pooling, which is kept as a MaxPool2d.
+pub fn adaptive_max_pool2d_config(curr: &Node) -> Option<AdaptiveMaxPool2dConfig> {
+    let input_shape = match &curr.inputs.first()?.ty {
+        ArgType::Tensor(tensor) => tensor.static_shape.clone()?,
+        _ => return None,
+    };
+    if input_shape.len() != 4 {
+        return None;
+    }
+
+    let mut kernel_shape = Vec::new();
+    let mut pads = vec![0, 0, 0, 0];
+    let mut dilations = vec![1, 1];
+    let mut auto_pad = "NOTSET".to_string();
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => kernel_shape = value.clone().into_i64s(),
+            "pads" => pads = value.clone().into_i64s(),
+            "dilations" => dilations = value.clone().into_i64s(),
+            "auto_pad" => auto_pad = value.clone().into_string(),
+            _ => {}
+        }
+    }
+
+    let is_valid = match auto_pad.as_str() {
+        "VALID" => true,
+        "NOTSET" => pads.iter().all(|pad| *pad == 0),
+        _ => false,
+    };
+    if !is_valid || dilations.iter().any(|dilation| *dilation != 1) {
+        return None;
+    }
+
+    let is_global = kernel_shape.len() == 2
+        && kernel_shape
+            .iter()
+            .zip(&input_shape[2..])
+            .all(|(kernel_size, input_size)| *kernel_size as usize == *input_size);
+    if !is_global {
+        return None;
+    }
+
+    Some(AdaptiveMaxPool2dConfig::new([1, 1]))
+}
+
 pub fn conv_transpose1d_config(curr: &Node) -> ConvTranspose1dConfig {
     let mut attrs = curr.attrs.clone();
 
@@ -660,8 +854,11 @@ pub fn dropout_config(node: &Node) -> DropoutConfig {
 }
 
 /// Create log_softmax config from the attributes of the node
+///
+/// Like Softmax, LogSoftmax is applied along `axis` directly (opset 13 semantics), where a
+/// negative axis is counted from the last dimension.
 pub fn log_softmax_config(node: &Node) -> usize {
-    // the axis is the last dimension (Default: 1 per ONNX spec)
+    // the axis is the last dimension (Default: -1 per ONNX spec since opset 13)
     let mut axis: i64 = -1;
 
     // check if the node has only one input
@@ -695,8 +892,12 @@ pub fn log_softmax_config(node: &Node) -> usize {
 }
 
 /// Create softmax config from the attributes of the node
+///
+/// Softmax is applied along `axis` directly, following the semantics introduced in opset 13.
+/// Older opsets, which coerced the input to 2D around `axis`, are rejected when parsing the model
+/// (see `MIN_OPSET_VERSION`), so their semantics don't need to be handled here.
 pub fn softmax_config(node: &Node) -> usize {
-    // the axis is the last dimension (Default: 1 per ONNX spec)
+    // the axis is the last dimension (Default: -1 per ONNX spec since opset 13)
     let mut axis: i64 = -1;
 
     // check if the node has only one input
@@ -729,6 +930,45 @@ pub fn softmax_config(node: &Node) -> usize {
     axis as usize
 }
 
+/// Create hardmax config from the attributes of the node
+///
+/// The one-hot is computed along `axis` directly, following the semantics introduced in opset 13.
+pub fn hardmax_config(node: &Node) -> usize {
+    // the axis is the last dimension (Default: -1 per ONNX spec since opset 13)
+    let mut axis: i64 = -1;
+
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Hardmax: only tensor input is valid"),
+    };
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "axis" {
+            axis = value.clone().into_i64();
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.rank as i64;
+    }
+
+    axis as usize
+}
+
+/// Extract the branches of an If node from its `then_branch` and `else_branch` attributes
+pub fn if_config(node: &Node) -> (OnnxGraph, OnnxGraph) {
+    let branch = |name: &str| {
+        node.attrs
+            .get(name)
+            .unwrap_or_else(|| panic!("If: the {name} attribute is required"))
+            .clone()
+            .into_graph()
+    };
+
+    (branch("then_branch"), branch("else_branch"))
+}
+
 /// Create argmax config from the attributes of the node
 pub fn argmax_config(node: &Node) -> usize {
     let mut axis: i64 = 0;
@@ -782,30 +1022,37 @@ pub fn argmax_config(node: &Node) -> usize {
 }
 
 /// Create concat config from the attributes of the node
-pub fn concat_config(node: &Node) -> usize {
-    // the axis is the last dimension (Default: 1 per ONNX spec)
-    let mut axis: i64 = 1;
+///
+/// Returns the concatenation axis, and for each input the shape it must be expanded to when it
+/// has a size-1 dimension broadcast against the other inputs. The broadcast is only resolved when
+/// the shapes of all the inputs are known.
+pub fn concat_config(node: &Node) -> (usize, Vec<Option<Vec<usize>>>) {
+    let axis = concat_axis(node);
 
-    // extract the shape of the input tensor
-    let tensor = match node.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
-    };
+    let shapes = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.static_shape.clone(),
+            _ => panic!("Only tensor input is valid"),
+        })
+        .collect::<Option<Vec<_>>>();
 
-    // extract the attributes
-    for (key, value) in node.attrs.iter() {
-        match key.as_str() {
-            "axis" => axis = value.clone().into_i64(),
-            _ => {}
-        }
-    }
+    let Some(shapes) = shapes else {
+        return (axis, vec![None; node.inputs.len()]);
+    };
 
-    // if axis is negative, it is counted from the end
-    if axis < 0 {
-        axis += tensor.rank as i64;
-    }
+    let output_shape = concat_broadcast_shape(&shapes, axis);
+    let expand_shapes = shapes
+        .into_iter()
+        .map(|shape| {
+            let mut target = output_shape.clone();
+            target[axis] = shape[axis];
+            (target != shape).then_some(target)
+        })
+        .collect();
 
-    axis as usize
+    (axis, expand_shapes)
 }
 
 /// Create a BatchNormConfig from the attributes of the node
@@ -871,6 +1118,82 @@ pub fn layer_norm_config(node: &Node) -> (LayerNormConfig, bool) {
     )
 }
 
+/// Create a MultiHeadAttentionConfig from the attributes of a `com.microsoft.Attention` node,
+/// along with whether the attention is unidirectional (causal).
+pub fn attention_config(node: &Node) -> (MultiHeadAttentionConfig, bool) {
+    let weight_shape = node
+        .inputs
+        .get(1)
+        .and_then(|input| input.value.as_ref())
+        .expect("Attention: fused QKV weight tensor must be present")
+        .shape
+        .clone();
+
+    if weight_shape.len() != 2 || weight_shape[1] != 3 * weight_shape[0] {
+        panic!(
+            "Attention: fused QKV weight must have shape [hidden_size, 3 * hidden_size] (got {:?})",
+            weight_shape
+        );
+    }
+
+    // Optional inputs are skipped in ONNX by giving them an empty name
+    if node
+        .inputs
+        .get(6)
+        .is_some_and(|input| !input.name.is_empty())
+    {
+        panic!(
+            "Attention: past_sequence_length input is not supported, \
+            the past length is the length of the past key/value input"
+        );
+    }
+
+    let hidden_size = weight_shape[0];
+    let mut num_heads = None;
+    let mut unidirectional = false;
+    let mut mask_filter_value = -10000.0;
+    let mut scale = None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "num_heads" => num_heads = Some(value.clone().into_i64() as usize),
+            "unidirectional" => unidirectional = value.clone().into_i64() != 0,
+            "mask_filter_value" => mask_filter_value = value.clone().into_f32(),
+            "qkv_hidden_sizes" => {
+                let sizes = value.clone().into_i64s();
+                if sizes.iter().any(|&size| size as usize != hidden_size) {
+                    panic!(
+                        "Attention: query, key and value hidden sizes must be equal to {} (got {:?})",
+                        hidden_size, sizes
+                    );
+                }
+            }
+            "do_rotary" if value.clone().into_i64() != 0 => {
+                panic!("Attention: rotary embeddings are not supported")
+            }
+            "scale" => scale = Some(value.clone().into_f32()),
+            _ => {}
+        }
+    }
+
+    let num_heads = num_heads.expect("Attention: num_heads attribute must be present");
+
+    // A scale of zero means the default 1/sqrt(head_size), which is the only one supported
+    if let Some(scale) = scale.filter(|&scale| scale != 0.0) {
+        let head_size = hidden_size / num_heads;
+        if (scale - 1.0 / (head_size as f32).sqrt()).abs() > f32::EPSILON {
+            panic!("Attention: only the default 1/sqrt(head_size) scale is supported");
+        }
+    }
+
+    (
+        MultiHeadAttentionConfig::new(hidden_size, num_heads)
+            .with_dropout(0.0)
+            .with_min_float(mask_filter_value as f64),
+        unidirectional,
+    )
+}
+
 /// Create a TileConfig from the attributes of the node
 pub fn tile_config(node: &Node) -> TileConfig {
     let repeat = node
@@ -1228,7 +1551,114 @@ pub fn hard_sigmoid_config(node: &Node) -> (f64, f64) {
     (alpha, beta)
 }
 
-pub fn reshape_config(node: &Node) -> Vec<i64> {
+/// Create a HingeEmbeddingLossConfig from the margin input of the node, returning it with the
+/// reduction of the loss
+pub fn hinge_embedding_loss_config(node: &Node) -> (HingeEmbeddingLossConfig, LossReduction) {
+    let margin = node
+        .inputs
+        .get(2)
+        .and_then(|arg| arg.value.clone())
+        .expect("HingeEmbeddingLoss: the margin must be a constant");
+    let margin = match margin.data {
+        data @ (Data::Float16s(_) | Data::Float32s(_) | Data::Float64s(_)) => data.into_f64s()[0],
+        Data::Float16(value) => f32::from(value) as f64,
+        Data::Float32(value) => value as f64,
+        Data::Float64(value) => value,
+        data => panic!("HingeEmbeddingLoss: invalid margin {data:?}"),
+    };
+
+    (
+        HingeEmbeddingLossConfig::new().with_margin(margin),
+        loss_reduction(node, "HingeEmbeddingLoss"),
+    )
+}
+
+/// Get the reduction of a SoftmaxCrossEntropyLoss node, which is only supported for class labels
+/// of 2D scores, without weights nor ignored index
+pub fn softmax_cross_entropy_loss_config(node: &Node) -> LossReduction {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(scores) if scores.rank == 2 => {}
+        ty => panic!("SoftmaxCrossEntropyLoss: only 2D scores are supported, got {ty:?}"),
+    }
+    if node.inputs.len() > 2 {
+        panic!("SoftmaxCrossEntropyLoss: class weights are not supported");
+    }
+    if node.attrs.contains_key("ignore_index") {
+        panic!("SoftmaxCrossEntropyLoss: the ignore_index attribute is not supported");
+    }
+    if node.outputs.len() > 1 {
+        panic!("SoftmaxCrossEntropyLoss: the log_prob output is not supported");
+    }
+
+    loss_reduction(node, "SoftmaxCrossEntropyLoss")
+}
+
+/// Get the reduction of a loss node from its `reduction` attribute (`mean` by default)
+fn loss_reduction(node: &Node, op: &str) -> LossReduction {
+    match node.attrs.get("reduction") {
+        Some(AttributeValue::String(reduction)) => match reduction.as_str() {
+            "none" => LossReduction::None,
+            "mean" => LossReduction::Mean,
+            "sum" => LossReduction::Sum,
+            reduction => panic!("{op}: unsupported reduction {reduction}"),
+        },
+        _ => LossReduction::Mean,
+    }
+}
+
+/// Create the repeat interleave config, returning the repeats and the axis along which the
+/// elements are repeated.
+pub fn repeat_interleave_config(node: &Node) -> (RepeatInterleaveRepeats, usize) {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.rank,
+        _ => panic!("RepeatInterleave: only tensor input is valid"),
+    };
+
+    let mut axis = match node.attrs.get("axis") {
+        Some(axis) => axis.clone().into_i64(),
+        None => 0,
+    };
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += rank as i64;
+    }
+
+    let repeats = match &node.inputs[1].value {
+        Some(TensorData { data, .. }) => {
+            let repeats = match data.clone() {
+                Data::Int32(repeats) => vec![repeats as i64],
+                Data::Int64(repeats) => vec![repeats],
+                data => data.into_i64s(),
+            };
+            assert!(
+                repeats.iter().all(|&times| times >= 0),
+                "RepeatInterleave: the repeats must not be negative (got {repeats:?})"
+            );
+
+            RepeatInterleaveRepeats::Static(repeats.into_iter().map(|x| x as usize).collect())
+        }
+        None => match &node.inputs[1].ty {
+            ArgType::Tensor(tensor) => {
+                assert_eq!(
+                    tensor.rank, 1,
+                    "RepeatInterleave: repeats tensor must be 1D"
+                );
+                RepeatInterleaveRepeats::Runtime(crate::burn::Type::from(&node.inputs[1]))
+            }
+            ArgType::Scalar(_) => {
+                RepeatInterleaveRepeats::Runtime(crate::burn::Type::from(&node.inputs[1]))
+            }
+            _ => panic!("RepeatInterleave: only tensor or scalar repeats are valid"),
+        },
+    };
+
+    (repeats, axis as usize)
+}
+
+/// Create the reshape config, returning the target shape and whether a zero in the shape is a
+/// literal zero-size dimension (`allowzero`) instead of a copy of the input dimension.
+pub fn reshape_config(node: &Node) -> (ReshapeShape, bool) {
     let mut allowzero = 0;
 
     for (key, value) in node.attrs.iter() {
@@ -1238,27 +1668,50 @@ pub fn reshape_config(node: &Node) -> Vec<i64> {
         }
     }
 
-    // Burn does not support zero size shape (0 means false in ONNX)
     // (see https://onnx.ai/onnx/operators/onnx__Reshape.html#attributes)
-    if allowzero != 0 {
-        panic!("Zero shape size is not supported");
-    }
+    let allowzero = allowzero != 0;
 
     // TODO: check "shape" attribute
-    if node.inputs.len() != 2 || node.inputs[1].value.is_none() {
+    if node.inputs.len() != 2 {
         panic!("Reshape: shape tensor must be present for {:?}", node);
     }
 
-    match &node.inputs[1].value {
-        Some(TensorData { data, shape, .. }) => {
-            assert_eq!(shape.len(), 1, "Reshape: shape tensor must be 1D");
-            data.clone().into_i64s()
+    match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => {
+            assert_eq!(tensor.rank, 1, "Reshape: shape tensor must be 1D");
+        }
+        ArgType::Shape(_) => {
+            // Shapes are always 1-D int64 data, so nothing to assert here
         }
         _ => panic!("Only tensor input is valid for shape"),
     }
+
+    let shape = match &node.inputs[1].value {
+        Some(TensorData { data, .. }) => data.clone().into_i64s(),
+        None => {
+            // The shape is only known at runtime, where it is fetched and validated by reshape
+            return (
+                ReshapeShape::Runtime(crate::burn::Type::from(&node.inputs[1])),
+                allowzero,
+            );
+        }
+    };
+
+    assert!(
+        shape.iter().filter(|&&dim| dim == -1).count() <= 1,
+        "Reshape: at most one dimension of the shape can be inferred (-1), got {shape:?}"
+    );
+
+    if allowzero && shape.contains(&0) && shape.contains(&-1) {
+        panic!(
+            "Reshape: the shape cannot contain both 0 and -1 when allowzero is set, got {shape:?}"
+        );
+    }
+
+    (ReshapeShape::Static(shape), allowzero)
 }
 
-pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>) {
+pub fn resize_config(node: &Node) -> (String, ResizeTarget) {
     let mut mode: String = "".to_string();
 
     let mut scales: Vec<f32>;
@@ -1329,6 +1782,22 @@ pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>) {
         })
         .unwrap_or_default();
 
+    // Exporters provide either the scales or the sizes, leaving the other input empty
+    let runtime_input = |index: usize| {
+        node.inputs
+            .get(index)
+            .filter(|input| !input.name.is_empty() && input.value.is_none())
+            .map(|input| match &input.ty {
+                ArgType::Tensor(tensor) => {
+                    assert_eq!(tensor.rank, 1, "Resize: scales and sizes must be 1D");
+                    crate::burn::TensorType::from(input)
+                }
+                _ => panic!("Resize: only tensor input is valid for scales and sizes"),
+            })
+    };
+    let runtime_scales = runtime_input(2);
+    let runtime_sizes = runtime_input(3);
+
     scales = node
         .inputs
         .get(2)
@@ -1365,6 +1834,21 @@ pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>) {
         panic!("Resize: roi input is not supported")
     }
 
+    if runtime_scales.is_some() || runtime_sizes.is_some() {
+        if !scales.is_empty() || !sizes.is_empty() {
+            panic!("Resize: scales and sizes must both be either constant or runtime inputs")
+        }
+
+        // The sizes are used when provided at runtime, and the scales otherwise
+        return (
+            mode,
+            ResizeTarget::Runtime {
+                scales: runtime_scales,
+                sizes: runtime_sizes,
+            },
+        );
+    }
+
     if scales.is_empty() && sizes.is_empty() {
         panic!("Resize: either scales or sizes input is required")
     }
@@ -1383,7 +1867,7 @@ pub fn resize_config(node: &Node) -> (String, Vec<f32>, Vec<usize>) {
         sizes = sizes.iter().skip(2).cloned().collect();
     }
 
-    (mode, scales, sizes)
+    (mode, ResizeTarget::Static { scales, sizes })
 }
 
 //Note this function should only execute if the second input is a constant
@@ -1474,51 +1958,21 @@ pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
     (min_result, max_result)
 }
 
-pub fn reduce_max_config(node: &Node) -> Option<usize> {
-    let mut axes = Vec::new();
-    let mut keepdims = 1;
-
-    let tensor = match node.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
-    };
-
-    // Extract the attributes
-    for (key, value) in node.attrs.iter() {
-        match key.as_str() {
-            "axes" => axes = value.clone().into_i64s(),
-            "keepdims" => keepdims = value.clone().into_i64(),
-            _ => {}
-        }
-    }
-
-    if axes.len() > 1 {
-        panic!("ReduceMax: reducing on multiple dimensions is not supported")
-    }
-
-    if axes.is_empty() && keepdims == 1 {
-        panic!("ReduceMax: axes must be provided with keepdims")
-    }
-
-    if !axes.is_empty() && keepdims == 0 {
-        // Not supported in Burn
-        panic!("ReduceMax: the reduce operation must preserve the reduced dimension")
-    }
-
-    if axes.is_empty() {
-        None
-    } else {
-        let mut dim = axes[0];
+/// Create the dims and keepdims config of a ReduceMax node from its attributes
+///
+/// All the dims are reduced when no axes are provided.
+pub fn reduce_max_config(node: &Node) -> (Vec<usize>, bool) {
+    reduce_dims_config(node)
+}
 
-        if dim < 0 {
-            // Accepted range is [-r, r-1] where r = rank(data) but Burn only supports positive dim
-            dim += tensor.rank as i64;
-        }
-        Some(dim as usize)
-    }
+/// Create the dims and keepdims config of a ReduceMin node from its attributes
+///
+/// All the dims are reduced when no axes are provided.
+pub fn reduce_min_config(node: &Node) -> (Vec<usize>, bool) {
+    reduce_dims_config(node)
 }
 
-pub fn reduce_min_config(node: &Node) -> Option<usize> {
+fn reduce_dims_config(node: &Node) -> (Vec<usize>, bool) {
     let mut axes = Vec::new();
     let mut keepdims = 1;
 
@@ -1536,28 +1990,22 @@ pub fn reduce_min_config(node: &Node) -> Option<usize> {
         }
     }
 
-    if axes.len() > 1 {
-        panic!("ReduceMin: reducing on multiple dimensions is not supported")
-    }
-
-    if axes.is_empty() && keepdims == 1 {
-        panic!("ReduceMin: axes must be provided with keepdims")
-    }
+    // Accepted range is [-r, r-1] where r = rank(data) but Burn only supports positive dim
+    let mut dims: Vec<usize> = axes
+        .into_iter()
+        .map(|axis| match axis < 0 {
+            true => (axis + tensor.rank as i64) as usize,
+            false => axis as usize,
+        })
+        .collect();
+    dims.sort_unstable();
+    dims.dedup();
 
-    if !axes.is_empty() && keepdims == 0 {
-        panic!("ReduceMin: the reduce operation must preserve the reduced dimension")
+    if dims.is_empty() {
+        dims = (0..tensor.rank).collect();
     }
 
-    if axes.is_empty() {
-        None
-    } else {
-        let mut dim = axes[0];
-
-        if dim < 0 {
-            dim += tensor.rank as i64;
-        }
-        Some(dim as usize)
-    }
+    (dims, keepdims == 1)
 }
 
 pub fn reduce_mean_config(node: &Node) -> Option<usize> {
@@ -1604,49 +2052,12 @@ pub fn reduce_mean_config(node: &Node) -> Option<usize> {
     }
 }
 
-pub fn reduce_prod_config(node: &Node) -> Option<usize> {
-    let mut axes = Vec::new();
-    let mut keepdims = 1;
-
-    let tensor = match node.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
-    };
-
-    // Extract the attributes
-    for (key, value) in node.attrs.iter() {
-        match key.as_str() {
-            "axes" => axes = value.clone().into_i64s(),
-            "keepdims" => keepdims = value.clone().into_i64(),
-            // TODO: handle noop_with_empty_axes (opset 18)
-            _ => {}
-        }
-    }
-
-    if axes.len() > 1 {
-        panic!("ReduceProd: reducing on multiple dimensions is not supported")
-    }
-
-    if axes.is_empty() && keepdims == 1 {
-        panic!("ReduceProd: axes must be provided with keepdims")
-    }
-
-    if !axes.is_empty() && keepdims == 0 {
-        // Not supported in Burn
-        panic!("ReduceProd: the reduce operation must preserve the reduced dimension")
-    }
-
-    if axes.is_empty() {
-        None
-    } else {
-        let mut dim = axes[0];
-
-        if dim < 0 {
-            // Accepted range is [-r, r-1] where r = rank(data) but Burn only supports positive dim
-            dim += tensor.rank as i64;
-        }
-        Some(dim as usize)
-    }
+/// Create the dims and keepdims config of a ReduceProd node from its attributes
+///
+/// All the dims are reduced when no axes are provided.
+pub fn reduce_prod_config(node: &Node) -> (Vec<usize>, bool) {
+    // TODO: handle noop_with_empty_axes (opset 18)
+    reduce_dims_config(node)
 }
 
 pub fn reduce_sum_config(node: &Node) -> Option<usize> {
@@ -1738,9 +2149,98 @@ pub fn shape_config(curr: &Node) -> (usize, usize) {
         end_dim += tensor.rank as i64;
     }
 
+    // Out of range axes are clamped, an empty range returns an empty shape
+    let start_dim = start_dim.clamp(0, tensor.rank as i64);
+    let end_dim = end_dim.clamp(start_dim, tensor.rank as i64);
+
     (start_dim as usize, end_dim as usize)
 }
 
+/// Create a RelativePositionBiasConfig from the bias table and the attributes of the node
+///
+/// Returns the config, the query length and the key length.
+pub fn relative_position_bias_config(node: &Node) -> (RelativePositionBiasConfig, usize, usize) {
+    let table = node.inputs[0]
+        .value
+        .as_ref()
+        .expect("RelativePositionBias: bias table is required");
+    let [num_buckets, num_heads] = table.shape[..] else {
+        panic!(
+            "RelativePositionBias: invalid bias table shape {:?}",
+            table.shape
+        );
+    };
+    let attr = |key: &str| node.attrs[key].clone().into_i64();
+
+    let config = RelativePositionBiasConfig::new(num_heads)
+        .with_num_buckets(num_buckets)
+        .with_max_distance(attr("max_distance") as usize)
+        .with_bidirectional(attr("bidirectional") != 0);
+
+    (config, attr("query_len") as usize, attr("key_len") as usize)
+}
+
+/// Create a SinusoidalPositionalEncodingConfig from the sinusoids table of the node
+pub fn sinusoidal_positional_encoding_config(node: &Node) -> SinusoidalPositionalEncodingConfig {
+    let table = node.inputs[1]
+        .value
+        .as_ref()
+        .expect("SinusoidalPositionalEncoding: sinusoids table is required");
+
+    // The table is either [seq_length, d_model] or [1, seq_length, d_model]
+    let [max_len, d_model] = table.shape[table.shape.len() - 2..] else {
+        panic!(
+            "SinusoidalPositionalEncoding: invalid sinusoids table shape {:?}",
+            table.shape
+        );
+    };
+
+    SinusoidalPositionalEncodingConfig::new(d_model).with_max_len(max_len)
+}
+
+/// Create a ScatterElements config from the attributes of the node
+///
+/// Returns the axis and the reduction, which is `None` when the updates replace the values.
+pub fn scatter_elements_config(curr: &Node) -> (usize, Option<IndexReduceMode>) {
+    if curr.inputs.len() != 3 {
+        panic!("ScatterElements: data, indices and updates must be present");
+    }
+
+    let input_dim = match &curr.inputs.first().unwrap().ty {
+        ArgType::Tensor(tensor) => tensor.rank as i64,
+        other => panic!(
+            "ScatterElements: only tensor input is valid, got {:?}",
+            other
+        ),
+    };
+
+    let mut dim: i64 = 0;
+    let mut reduction = None;
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "axis" => dim = value.clone().into_i64(),
+            "reduction" => {
+                reduction = match value.clone().into_string().as_str() {
+                    "none" => None,
+                    "add" => Some(IndexReduceMode::Sum),
+                    "mul" => Some(IndexReduceMode::Prod),
+                    "max" => Some(IndexReduceMode::AMax),
+                    "min" => Some(IndexReduceMode::AMin),
+                    other => panic!("ScatterElements: unsupported reduction {other}"),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // if dim is negative, it is counted from the end
+    if dim < 0 {
+        dim += input_dim;
+    }
+
+    (dim as usize, reduction)
+}
+
 pub fn transpose_config(curr: &Node) -> Vec<i64> {
     if curr.inputs.len() != 1 {
         panic!(
@@ -1786,6 +2286,15 @@ pub fn squeeze_config(curr: &Node) -> Vec<i64> {
 
     axes
 }
+/// Get the dimension of a Stack node, along which the tensors are stacked
+pub fn stack_config(node: &Node) -> usize {
+    node.attrs
+        .get("axis")
+        .expect("Stack: the axis is set when the node is recognized")
+        .clone()
+        .into_i64() as usize
+}
+
 pub fn split_config(node: &Node) -> SplitConfig {
     // Initialize the axis to split along (default is 0 as per ONNX specification)
     let mut axis: i64 = 0;
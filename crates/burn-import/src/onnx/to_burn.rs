@@ -5,32 +5,42 @@ use std::{
 };
 
 use burn::{
-    nn::PReluConfig,
+    module::TensorSpec,
+    nn::{PReluConfig, pool::AdaptiveMaxPool2dConfig},
     record::{FullPrecisionSettings, HalfPrecisionSettings, PrecisionSettings},
-    tensor::{Element, TensorData},
+    tensor::{DType, Element, IndexReduceMode, TensorData},
 };
 use log::warn;
+use quote::quote;
 
 use crate::{
     burn::{
-        ScalarKind, ScalarType, ShapeType, TensorKind, TensorType, Type,
+        OtherType, ScalarKind, ScalarType, ShapeType, TensorKind, TensorType, Type,
         graph::BurnGraph,
         node::{
+            adaptive_max_pool2d::AdaptiveMaxPool2dNode,
             argmax::ArgMaxNode,
+            attention::AttentionNode,
             avg_pool1d::AvgPool1dNode,
             avg_pool2d::AvgPool2dNode,
             batch_norm::BatchNormNode,
             binary::BinaryNode,
             clip::ClipNode,
             concat::ConcatNode,
+            conditional::{IfBranch, IfNode},
             constant::{ConstantNode, ConstantValue},
             constant_of_shape::ConstantOfShapeNode,
+            conv_integer::ConvIntegerNode,
             conv_transpose_1d::ConvTranspose1dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             conv_transpose_3d::ConvTranspose3dNode,
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
             conv3d::Conv3dNode,
+            convnext_block::{ConvNeXtBlockData, ConvNeXtBlockNode},
+            depthwise_separable_conv2d::{
+                DepthwiseSeparableConv2dData, DepthwiseSeparableConv2dNode,
+            },
             dropout::DropoutNode,
             expand::ExpandNode,
             floor::FloorNode,
@@ -38,6 +48,9 @@ use crate::{
             gather_elements::GatherElementsNode,
             gemm::GemmNode,
             global_avg_pool::GlobalAvgPoolNode,
+            hardmax::HardmaxNode,
+            hinge_embedding_loss::HingeEmbeddingLossNode,
+            identity::IdentityNode,
             layer_norm::LayerNormNode,
             linear::LinearNode,
             mask_where::WhereNode,
@@ -45,6 +58,7 @@ use crate::{
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
             one_hot::OneHotNode,
+            optional::{OptionalNode, OptionalNodeKind},
             pad::PadNode,
             prelu::PReluNode,
             random_normal::RandomNormalNode,
@@ -52,11 +66,18 @@ use crate::{
             random_uniform::RandomUniformNode,
             random_uniform_like::RandomUniformLikeNode,
             range::RangeNode,
+            relative_position_bias::RelativePositionBiasNode,
+            repeat_interleave::RepeatInterleaveNode,
             reshape::ReshapeNode,
             resize::ResizeNode,
+            scatter_elements::ScatterElementsNode,
+            sinusoidal_pos_encoding::SinusoidalPositionalEncodingNode,
             slice::SliceNode,
+            softmax_cross_entropy_loss::SoftmaxCrossEntropyLossNode,
             split::SplitNode,
             squeeze::SqueezeNode,
+            squeeze_excitation::{SqueezeExcitationData, SqueezeExcitationNode},
+            stack::StackNode,
             sum::SumNode,
             tile::TileNode,
             top_k::TopKNode,
@@ -70,15 +91,21 @@ use crate::{
 };
 
 use super::op_configuration::{
-    argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
-    concat_config, conv_transpose1d_config, conv_transpose2d_config, conv_transpose3d_config,
-    conv1d_config, conv2d_config, conv3d_config, dropout_config, expand_config, flatten_config,
-    gather_config, gemm_config, hard_sigmoid_config, layer_norm_config, leaky_relu_config,
+    adaptive_max_pool2d_config, argmax_config, attention_config, avg_pool1d_config,
+    avg_pool2d_config, batch_norm_config, clip_config, concat_config, conv_integer_config,
+    conv_transpose1d_config, conv_transpose2d_config, conv_transpose3d_config, conv1d_config,
+    conv2d_config, conv3d_config, convnext_block_config, depthwise_separable_conv2d_config,
+    dropout_config, expand_config, flatten_config, gather_config, gemm_config, hard_sigmoid_config,
+    hardmax_config, hinge_embedding_loss_config, if_config, layer_norm_config, leaky_relu_config,
     linear_config, log_softmax_config, max_pool1d_config, max_pool2d_config, one_hot_config,
     pad_config, reduce_max_config, reduce_mean_config, reduce_min_config, reduce_prod_config,
-    reduce_sum_config, reshape_config, resize_config, shape_config, softmax_config, split_config,
-    squeeze_config, tile_config, top_k_config, transpose_config, trilu_config, unsqueeze_config,
+    reduce_sum_config, relative_position_bias_config, repeat_interleave_config, reshape_config,
+    resize_config, scatter_elements_config, shape_config, sinusoidal_positional_encoding_config,
+    softmax_config, softmax_cross_entropy_loss_config, split_config, squeeze_config,
+    squeeze_excitation_config, stack_config, tile_config, top_k_config, transpose_config,
+    trilu_config, unsqueeze_config,
 };
+use super::trt_plugin::substitute_trt_plugins;
 use onnx_ir::{
     convert_constant_value,
     ir::{
@@ -100,8 +127,11 @@ pub struct ModelGen {
     inputs: Vec<PathBuf>,
     development: bool,
     half_precision: bool,
+    half_precision_params: bool,
     record_type: RecordType,
     embed_states: bool,
+    allow_unknown_plugins: bool,
+    intermediates: Vec<String>,
 }
 
 impl ModelGen {
@@ -156,6 +186,21 @@ impl ModelGen {
         self
     }
 
+    /// Specify whether the generated model stores its parameters in half precision.
+    ///
+    /// The parameters are cast to `f16` once loaded, which reduces the memory used by the model.
+    /// In the forward pass, each parameter is upcast to `f32` where it is used, so the model still
+    /// computes in full precision. The backend must support `f16` tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `half_precision_params` - If true, parameters are stored in half precision. Otherwise,
+    ///   they are stored in the backend float precision.
+    pub fn half_precision_params(&mut self, half_precision_params: bool) -> &mut Self {
+        self.half_precision_params = half_precision_params;
+        self
+    }
+
     /// Specify the type of the record to be saved.
     ///
     /// # Arguments
@@ -177,6 +222,35 @@ impl ModelGen {
         self
     }
 
+    /// Specify whether TensorRT plugins that can't be converted are allowed.
+    ///
+    /// Models re-exported from TensorRT wrap fused layers in `TRT_PluginV2` nodes. Known plugins
+    /// (e.g. the layer normalization plugin) are replaced by their Burn equivalent.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_unknown_plugins` - If true, the other plugins with a single output are passed
+    ///   through as no-ops forwarding their first input, with a warning. Otherwise, they cause the
+    ///   conversion to fail.
+    pub fn allow_unknown_plugins(&mut self, allow_unknown_plugins: bool) -> &mut Self {
+        self.allow_unknown_plugins = allow_unknown_plugins;
+        self
+    }
+
+    /// Add an intermediate tensor returned by the generated `forward_with_intermediates` method.
+    ///
+    /// When intermediates are added, the generated model has a `forward_with_intermediates` method
+    /// returning the outputs and the intermediate tensors (in the order they are added), which is
+    /// useful for debugging without re-exporting the ONNX model with extra outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tensor in the generated `forward` method (e.g. `relu1_out1`).
+    pub fn intermediate(&mut self, name: &str) -> &mut Self {
+        self.intermediates.push(name.to_string());
+        self
+    }
+
     /// Run code generation.
     fn run(&self, is_build_script: bool) {
         log::info!("Starting to convert ONNX to Burn");
@@ -217,7 +291,8 @@ impl ModelGen {
         log::debug!("Development mode: {:?}", self.development);
         log::debug!("Output file: {:?}", out_file);
 
-        let graph = parse_onnx(input.as_ref());
+        let mut graph = parse_onnx(input.as_ref());
+        substitute_trt_plugins(&mut graph, self.allow_unknown_plugins);
 
         if self.development {
             // save onnx graph as a debug file
@@ -243,6 +318,9 @@ impl ModelGen {
         let code = if self.half_precision {
             graph
                 .into_burn::<HalfPrecisionSettings>()
+                .with_half_precision_params(self.half_precision_params)
+                .with_summary(true)
+                .with_intermediates(&self.intermediates)
                 .with_record(out_file.clone(), self.record_type, self.embed_states)
                 .with_blank_space(blank_space)
                 .with_top_comment(top_comment)
@@ -250,6 +328,9 @@ impl ModelGen {
         } else {
             graph
                 .into_burn::<FullPrecisionSettings>()
+                .with_half_precision_params(self.half_precision_params)
+                .with_summary(true)
+                .with_intermediates(&self.intermediates)
                 .with_record(out_file.clone(), self.record_type, self.embed_states)
                 .with_blank_space(blank_space)
                 .with_top_comment(top_comment)
@@ -270,11 +351,52 @@ impl ParsedOnnxGraph {
         let mut graph = BurnGraph::<PS>::default();
 
         let mut unsupported_ops = vec![];
+        Self::register_nodes(&mut graph, self.0.nodes, &mut unsupported_ops);
 
-        for node in self.0.nodes {
+        if !unsupported_ops.is_empty() {
+            panic!("Unsupported ops: {:?}", unsupported_ops);
+        }
+
+        // Get input and output names
+        let input_names = self
+            .0
+            .inputs
+            .iter()
+            .map(|input| input.name.clone())
+            .collect::<Vec<_>>();
+
+        let output_names = self
+            .0
+            .outputs
+            .iter()
+            .map(|output| output.name.clone())
+            .collect::<Vec<_>>();
+
+        // Register inputs and outputs with the graph
+        graph.register_input_output(input_names, output_names);
+
+        let input_specs = self.0.inputs.iter().map(arg_to_tensor_spec).collect();
+        let output_specs = self.0.outputs.iter().map(arg_to_tensor_spec).collect();
+
+        graph.with_io_specs(input_specs, output_specs)
+    }
+
+    /// Converts the ONNX nodes and registers them into the Burn graph, collecting the types of the
+    /// nodes that are not supported.
+    fn register_nodes<PS: PrecisionSettings + 'static>(
+        graph: &mut BurnGraph<PS>,
+        nodes: Vec<Node>,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) {
+        for node in nodes {
             match node.node_type {
                 NodeType::Add => graph.register(Self::add_conversion(node)),
                 NodeType::ArgMax => graph.register(Self::argmax_conversion(node)),
+                NodeType::Hardmax => graph.register(Self::hardmax_conversion(node)),
+                NodeType::HingeEmbeddingLoss => {
+                    graph.register(Self::hinge_embedding_loss_conversion(node))
+                }
+                NodeType::Attention => graph.register(Self::attention_conversion::<PS>(node)),
                 NodeType::Sub => graph.register(Self::sub_conversion(node)),
                 NodeType::Mul => graph.register(Self::mul_conversion(node)),
                 NodeType::Div => graph.register(Self::div_conversion(node)),
@@ -288,10 +410,16 @@ impl ParsedOnnxGraph {
                 NodeType::Cosh => graph.register(Self::cosh_conversion(node)),
                 NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
                 NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
+                NodeType::ConvInteger => graph.register(Self::conv_integer_conversion::<PS>(node)),
                 NodeType::Conv3d => graph.register(Self::conv3d_conversion::<PS>(node)),
                 NodeType::Max => graph.register(Self::max_conversion(node)),
                 NodeType::MaxPool1d => graph.register(Self::max_pool1d_conversion(node)),
-                NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
+                NodeType::MaxPool2d => match adaptive_max_pool2d_config(&node) {
+                    Some(config) => {
+                        graph.register(Self::adaptive_max_pool2d_conversion(node, config))
+                    }
+                    None => graph.register(Self::max_pool2d_conversion(node)),
+                },
                 NodeType::Mean => graph.register(Self::mean_conversion(node)),
                 NodeType::PRelu => graph.register(Self::prelu_conversion::<PS>(node)),
                 NodeType::AveragePool1d => graph.register(Self::avg_pool_1d_conversion(node)),
@@ -299,7 +427,21 @@ impl ParsedOnnxGraph {
                 NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
                 NodeType::Neg => graph.register(Self::neg_conversion(node)),
                 NodeType::Not => graph.register(Self::not_conversion(node)),
+                NodeType::And => graph.register(Self::and_conversion(node)),
+                NodeType::Or => graph.register(Self::or_conversion(node)),
+                NodeType::Xor => graph.register(Self::xor_conversion(node)),
                 NodeType::OneHot => graph.register(Self::one_hot_conversion(node)),
+                NodeType::Optional => {
+                    graph.register(Self::optional_conversion(node, OptionalNodeKind::Wrap))
+                }
+                NodeType::OptionalHasElement => graph.register(Self::optional_conversion(
+                    node,
+                    OptionalNodeKind::HasElement,
+                )),
+                NodeType::OptionalGetElement => graph.register(Self::optional_conversion(
+                    node,
+                    OptionalNodeKind::GetElement,
+                )),
                 NodeType::Greater => graph.register(Self::greater_conversion(node)),
                 NodeType::GreaterOrEqual => graph.register(Self::greater_or_equal_conversion(node)),
                 NodeType::Less => graph.register(Self::less_conversion(node)),
@@ -308,6 +450,12 @@ impl ParsedOnnxGraph {
                     graph.register(Self::layer_norm_conversion::<PS>(node))
                 }
                 NodeType::Linear => graph.register(Self::linear_conversion::<PS>(node)),
+                NodeType::SinusoidalPositionalEncoding => {
+                    graph.register(Self::sinusoidal_positional_encoding_conversion(node))
+                }
+                NodeType::RelativePositionBias => {
+                    graph.register(Self::relative_position_bias_conversion::<PS>(node))
+                }
                 NodeType::BatchNormalization => {
                     graph.register(Self::batch_norm_conversion::<PS>(node))
                 }
@@ -316,11 +464,17 @@ impl ParsedOnnxGraph {
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
                 NodeType::Gather => graph.register(Self::gather_conversion(node)),
                 NodeType::GatherElements => graph.register(Self::gather_elements_conversion(node)),
+                NodeType::ScatterElements => {
+                    graph.register(Self::scatter_elements_conversion(node))
+                }
                 NodeType::HardSigmoid => graph.register(Self::hard_sigmoid_conversion(node)),
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
                 NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
                 NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
+                NodeType::SoftmaxCrossEntropyLoss => {
+                    graph.register(Self::softmax_cross_entropy_loss_conversion(node))
+                }
                 NodeType::Sqrt => graph.register(Self::sqrt_conversion(node)),
                 NodeType::Tan => graph.register(Self::tan_conversion(node)),
                 NodeType::Tanh => graph.register(Self::tanh_conversion(node)),
@@ -332,6 +486,9 @@ impl ParsedOnnxGraph {
                 NodeType::ReduceMean => graph.register(Self::reduce_mean_conversion(node)),
                 NodeType::ReduceProd => graph.register(Self::reduce_prod_conversion(node)),
                 NodeType::ReduceSum => graph.register(Self::reduce_sum_conversion(node)),
+                NodeType::RepeatInterleave => {
+                    graph.register(Self::repeat_interleave_conversion(node))
+                }
                 NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
                 NodeType::Resize => graph.register(Self::resize_conversion(node)),
                 NodeType::Reciprocal => graph.register(Self::reciprocal_conversion(node)),
@@ -345,6 +502,7 @@ impl ParsedOnnxGraph {
                 NodeType::Concat => graph.register(Self::concat_conversion(node)),
                 NodeType::Cast => graph.register(Self::cast_conversion(node)),
                 NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+                NodeType::Identity => graph.register(Self::identity_conversion(node)),
                 NodeType::GlobalAveragePool => {
                     graph.register(Self::global_avg_pool_conversion(node))
                 }
@@ -357,12 +515,22 @@ impl ParsedOnnxGraph {
                 NodeType::ConvTranspose3d => {
                     graph.register(Self::conv_transpose3d_conversion::<PS>(node))
                 }
+                NodeType::ConvNeXtBlock => {
+                    graph.register(Self::convnext_block_conversion::<PS>(node))
+                }
+                NodeType::DepthwiseSeparableConv2d => {
+                    graph.register(Self::depthwise_separable_conv2d_conversion::<PS>(node))
+                }
+                NodeType::SqueezeExcitation => {
+                    graph.register(Self::squeeze_excitation_conversion::<PS>(node))
+                }
                 NodeType::Pad => graph.register(Self::pad_conversion(node)),
                 NodeType::Pow => graph.register(Self::pow_conversion(node)),
                 NodeType::Unsqueeze => graph.register(Self::unsqueeze_conversion(node)),
                 NodeType::Where => graph.register(Self::where_conversion(node)),
                 NodeType::Sign => graph.register(Self::sign_conversion(node)),
                 NodeType::Squeeze => graph.register(Self::squeeze_conversion(node)),
+                NodeType::Stack => graph.register(Self::stack_conversion(node)),
                 NodeType::RandomUniform => graph.register(Self::random_uniform_conversion(node)),
                 NodeType::RandomUniformLike => {
                     graph.register(Self::random_uniform_like_conversion(node))
@@ -379,33 +547,10 @@ impl ParsedOnnxGraph {
                 }
                 NodeType::Split => graph.register(Self::split_conversion(node)),
                 NodeType::Gemm => graph.register(Self::gemm_conversion(node)),
+                NodeType::If => graph.register(Self::if_conversion::<PS>(node, unsupported_ops)),
                 node_type => unsupported_ops.push(node_type),
             }
         }
-
-        if !unsupported_ops.is_empty() {
-            panic!("Unsupported ops: {:?}", unsupported_ops);
-        }
-
-        // Get input and output names
-        let input_names = self
-            .0
-            .inputs
-            .iter()
-            .map(|input| input.name.clone())
-            .collect::<Vec<_>>();
-
-        let output_names = self
-            .0
-            .outputs
-            .iter()
-            .map(|output| output.name.clone())
-            .collect::<Vec<_>>();
-
-        // Register inputs and outputs with the graph
-        graph.register_input_output(input_names, output_names);
-
-        graph
     }
 
     fn constant_conversion<PS: PrecisionSettings>(node: Node) -> ConstantNode {
@@ -447,6 +592,7 @@ impl ParsedOnnxGraph {
                 _ => panic!("Unsupported constant tensor type: {:?} ", elem_type),
             },
             ArgType::Shape(_) => panic!("Shape is not supported as constant value."),
+            ArgType::Optional(_) => panic!("Optional is not supported as constant value."),
         };
 
         ConstantNode::new(node.name.clone(), const_value, Type::from(output))
@@ -628,9 +774,10 @@ impl ParsedOnnxGraph {
     fn matmul_conversion(node: Node) -> MatmulNode {
         let lhs = TensorType::from(node.inputs.first().unwrap());
         let rhs = TensorType::from(node.inputs.get(1).unwrap());
+        let bias = node.inputs.get(2).map(TensorType::from);
         let output = TensorType::from(node.outputs.first().unwrap());
 
-        MatmulNode::new(lhs, rhs, output)
+        MatmulNode::new(lhs, rhs, bias, output)
     }
 
     fn equal_conversion(node: Node) -> BinaryNode {
@@ -719,6 +866,24 @@ impl ParsedOnnxGraph {
         GatherElementsNode::new(input, index, output, dim)
     }
 
+    fn scatter_elements_conversion(node: Node) -> ScatterElementsNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let index = TensorType::from(node.inputs.get(1).unwrap());
+        let updates = TensorType::from(node.inputs.get(2).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let (dim, reduction) = scatter_elements_config(&node);
+
+        let index_reduce = matches!(
+            reduction,
+            Some(IndexReduceMode::Prod | IndexReduceMode::AMax | IndexReduceMode::AMin)
+        );
+        if index_reduce && !matches!(input.kind, TensorKind::Float) {
+            panic!("ScatterElements: mul, max and min reductions only support float tensors");
+        }
+
+        ScatterElementsNode::new(input, index, updates, output, dim, reduction)
+    }
+
     fn transpose_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
@@ -731,15 +896,37 @@ impl ParsedOnnxGraph {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
 
-        UnaryNode::cast(input, output)
+        // Float to integer casts saturate at the bounds of the target integer type, as ONNX Cast
+        let bounds = match &node.outputs.first().unwrap().ty {
+            ArgType::Tensor(tensor) => match tensor.elem_type {
+                ElementType::Int8 => Some((i8::MIN as i64, i8::MAX as i64)),
+                ElementType::Int16 => Some((i16::MIN as i64, i16::MAX as i64)),
+                ElementType::Int32 => Some((i32::MIN as i64, i32::MAX as i64)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match bounds {
+            Some((min, max)) => UnaryNode::saturating_cast(input, output, min, max),
+            None => UnaryNode::cast(input, output),
+        }
+    }
+
+    fn repeat_interleave_conversion(node: Node) -> RepeatInterleaveNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let (repeats, axis) = repeat_interleave_config(&node);
+
+        RepeatInterleaveNode::new(input, output, repeats, axis)
     }
 
     fn reshape_conversion(node: Node) -> ReshapeNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
-        let shape = reshape_config(&node);
+        let (shape, allowzero) = reshape_config(&node);
 
-        ReshapeNode::new(input, output, shape)
+        ReshapeNode::new(input, output, shape, allowzero)
     }
 
     fn resize_conversion(node: Node) -> ResizeNode {
@@ -749,9 +936,9 @@ impl ParsedOnnxGraph {
 
         let output = TensorType::from(node.outputs.first().unwrap());
 
-        let (mode, scales, sizes) = resize_config(&node);
+        let (mode, target) = resize_config(&node);
 
-        ResizeNode::new(name, input, output, mode, scales, sizes)
+        ResizeNode::new(name, input, output, mode, target)
     }
 
     fn min_conversion(node: Node) -> BinaryNode {
@@ -788,17 +975,17 @@ impl ParsedOnnxGraph {
     fn reduce_max_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
-        let dim = reduce_max_config(&node);
+        let (dims, keepdims) = reduce_max_config(&node);
 
-        UnaryNode::reduce_max(input, output, dim)
+        UnaryNode::reduce_max(input, output, dims, keepdims)
     }
 
     fn reduce_min_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
-        let dim = reduce_min_config(&node);
+        let (dims, keepdims) = reduce_min_config(&node);
 
-        UnaryNode::reduce_min(input, output, dim)
+        UnaryNode::reduce_min(input, output, dims, keepdims)
     }
 
     fn reduce_mean_conversion(node: Node) -> UnaryNode {
@@ -812,9 +999,9 @@ impl ParsedOnnxGraph {
     fn reduce_prod_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
-        let dim = reduce_prod_config(&node);
+        let (dims, keepdims) = reduce_prod_config(&node);
 
-        UnaryNode::reduce_prod(input, output, dim)
+        UnaryNode::reduce_prod(input, output, dims, keepdims)
     }
 
     fn reduce_sum_conversion(node: Node) -> UnaryNode {
@@ -835,11 +1022,33 @@ impl ParsedOnnxGraph {
 
     fn unsqueeze_conversion(node: Node) -> UnsqueezeNode {
         let input = Type::from(node.inputs.first().unwrap());
-        let output = TensorType::from(node.outputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
         let axes = unsqueeze_config(&node);
         UnsqueezeNode::new(input, output, axes)
     }
 
+    fn if_conversion<PS: PrecisionSettings + 'static>(
+        node: Node,
+        unsupported_ops: &mut Vec<NodeType>,
+    ) -> IfNode<PS> {
+        let condition = Type::from(node.inputs.first().unwrap());
+        let captured = node.inputs.iter().skip(1).map(Type::from).collect();
+        let outputs = node.outputs.iter().map(Type::from).collect();
+        let (then_branch, else_branch) = if_config(&node);
+
+        let mut branch_conversion = |branch: OnnxGraph| {
+            let mut graph = BurnGraph::<PS>::default();
+            Self::register_nodes(&mut graph, branch.nodes, unsupported_ops);
+            let outputs = branch.outputs.iter().map(Type::from).collect();
+
+            IfBranch::new(graph.into_nodes(), outputs)
+        };
+        let then_branch = branch_conversion(then_branch);
+        let else_branch = branch_conversion(else_branch);
+
+        IfNode::new(condition, captured, then_branch, else_branch, outputs)
+    }
+
     fn where_conversion(node: Node) -> WhereNode {
         let condition = Type::from(node.inputs.first().unwrap());
         let x = Type::from(node.inputs.get(1).unwrap());
@@ -945,13 +1154,25 @@ impl ParsedOnnxGraph {
         ArgMaxNode::new(input, output, axis)
     }
 
+    fn hardmax_conversion(node: Node) -> HardmaxNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let axis = hardmax_config(&node);
+
+        HardmaxNode::new(input, output, axis)
+    }
+
     fn concat_conversion(node: Node) -> ConcatNode {
-        let inputs = node.inputs.iter().map(TensorType::from).collect();
+        let inputs = node.inputs.iter().map(Type::from).collect();
 
-        let output = TensorType::from(node.outputs.first().unwrap());
-        let dim = concat_config(&node);
+        let output = Type::from(node.outputs.first().unwrap());
+        if let Type::Shape(_) = output {
+            // Shapes are concatenated along their only dimension
+            return ConcatNode::new(inputs, output, 0);
+        }
+        let (dim, expand_shapes) = concat_config(&node);
 
-        ConcatNode::new(inputs, output, dim)
+        ConcatNode::new(inputs, output, dim).with_expand_shapes(expand_shapes)
     }
 
     fn linear_conversion<PS: PrecisionSettings>(node: Node) -> LinearNode {
@@ -967,6 +1188,28 @@ impl ParsedOnnxGraph {
         LinearNode::new(name, input, output, weight, bias, config)
     }
 
+    fn sinusoidal_positional_encoding_conversion(node: Node) -> SinusoidalPositionalEncodingNode {
+        let name = &node.name;
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = sinusoidal_positional_encoding_config(&node);
+
+        SinusoidalPositionalEncodingNode::new(name, input, output, config)
+    }
+
+    fn relative_position_bias_conversion<PS: PrecisionSettings>(
+        node: Node,
+    ) -> RelativePositionBiasNode {
+        let name = &node.name;
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let (config, query_len, key_len) = relative_position_bias_config(&node);
+
+        let weight =
+            extract_data_serialize::<PS::FloatElem>(0, &node).expect("Bias table is required");
+
+        RelativePositionBiasNode::new(name, output, weight, config, query_len, key_len)
+    }
+
     fn dropout_conversion(node: Node) -> DropoutNode {
         let name = &node.name;
         let input = TensorType::from(node.inputs.first().unwrap());
@@ -1004,6 +1247,48 @@ impl ParsedOnnxGraph {
         )
     }
 
+    fn attention_conversion<PS: PrecisionSettings>(node: Node) -> AttentionNode {
+        let (config, unidirectional) = attention_config(&node);
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        // Optional inputs and outputs, skipped when their name is empty
+        let optional_input = |index: usize| {
+            node.inputs
+                .get(index)
+                .filter(|input| !input.name.is_empty())
+                .map(TensorType::from)
+        };
+        let mask_index = optional_input(3);
+        let past = optional_input(4);
+        let attention_bias = optional_input(5);
+        let present = node
+            .outputs
+            .get(1)
+            .filter(|output| !output.name.is_empty())
+            .map(TensorType::from);
+
+        // Fused QKV weight of shape [hidden_size, 3 * hidden_size]
+        let weights =
+            extract_data_serialize::<PS::FloatElem>(1, &node).expect("Weight is required");
+        let bias = extract_data_serialize::<PS::FloatElem>(2, &node);
+
+        let name = &node.name;
+
+        AttentionNode::new(
+            name,
+            input,
+            mask_index,
+            past,
+            attention_bias,
+            output,
+            present,
+            weights,
+            bias,
+            config,
+            unidirectional,
+        )
+    }
+
     fn layer_norm_conversion<PS: PrecisionSettings>(node: Node) -> LayerNormNode {
         let (config, full_precision) = layer_norm_config(&node);
         let input = TensorType::from(node.inputs.first().unwrap());
@@ -1051,6 +1336,30 @@ impl ParsedOnnxGraph {
         Conv2dNode::new(name, input, output, weight, bias, config)
     }
 
+    fn conv_integer_conversion<PS: PrecisionSettings>(node: Node) -> ConvIntegerNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let (config, input_zero_point, weight_zero_point) = conv_integer_config(&node);
+
+        // Subtract the weight zero point, per tensor or per output channel, at import
+        let weight = node.inputs[1].value.clone().unwrap();
+        let channel_size = weight.shape[1..].iter().product::<usize>();
+        let values: Vec<i32> = weight
+            .data
+            .into_i32s()
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| match weight_zero_point.as_slice() {
+                [zero_point] => value - zero_point,
+                zero_points => value - zero_points[i / channel_size],
+            })
+            .collect();
+        let weight = serialize_data::<PS::IntElem>(Data::Int32s(values), weight.shape);
+
+        let name = &node.name;
+        ConvIntegerNode::new(name, input, output, weight, input_zero_point, config)
+    }
+
     fn conv3d_conversion<PS: PrecisionSettings>(node: Node) -> Conv3dNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1085,6 +1394,17 @@ impl ParsedOnnxGraph {
         MaxPool2dNode::new(name, input, output, config)
     }
 
+    fn adaptive_max_pool2d_conversion(
+        node: Node,
+        config: AdaptiveMaxPool2dConfig,
+    ) -> AdaptiveMaxPool2dNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        let name = &node.name;
+        AdaptiveMaxPool2dNode::new(name, input, output, config)
+    }
+
     fn mean_conversion(node: Node) -> MeanNode {
         let inputs = node.inputs.iter().map(TensorType::from).collect();
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1157,6 +1477,82 @@ impl ParsedOnnxGraph {
         let name = &node.name;
         ConvTranspose3dNode::new(name, input, output, weight, bias, config)
     }
+
+    fn convnext_block_conversion<PS: PrecisionSettings>(node: Node) -> ConvNeXtBlockNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = convnext_block_config(&node);
+
+        let param = |index: usize| {
+            extract_data_serialize::<PS::FloatElem>(index, &node)
+                .expect("ConvNeXtBlock: the parameters are required")
+        };
+        let data = ConvNeXtBlockData::new(
+            param(1),
+            param(2),
+            param(3),
+            param(4),
+            param(5),
+            param(6),
+            param(7),
+            param(8),
+            extract_data_serialize::<PS::FloatElem>(9, &node),
+        );
+
+        let name = &node.name;
+        ConvNeXtBlockNode::new(name, input, output, data, config)
+    }
+
+    fn depthwise_separable_conv2d_conversion<PS: PrecisionSettings>(
+        node: Node,
+    ) -> DepthwiseSeparableConv2dNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = depthwise_separable_conv2d_config(&node);
+
+        // The biases of both convolutions follow the weights when present
+        let data = DepthwiseSeparableConv2dData::new(
+            extract_data_serialize::<PS::FloatElem>(1, &node).unwrap(),
+            extract_data_serialize::<PS::FloatElem>(3, &node),
+            extract_data_serialize::<PS::FloatElem>(2, &node).unwrap(),
+            extract_data_serialize::<PS::FloatElem>(4, &node),
+        );
+
+        let name = &node.name;
+        DepthwiseSeparableConv2dNode::new(name, input, output, data, config)
+    }
+    fn squeeze_excitation_conversion<PS: PrecisionSettings>(node: Node) -> SqueezeExcitationNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = squeeze_excitation_config(&node);
+
+        let param = |index: usize| {
+            extract_data_serialize::<PS::FloatElem>(index, &node)
+                .expect("SqueezeExcitation: the parameters are required")
+        };
+        let data = SqueezeExcitationData::new(param(1), param(2), param(3), param(4));
+
+        let name = &node.name;
+        SqueezeExcitationNode::new(name, input, output, data, config)
+    }
+    fn hinge_embedding_loss_conversion(node: Node) -> HingeEmbeddingLossNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let target = TensorType::from(node.inputs.get(1).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let (config, reduction) = hinge_embedding_loss_config(&node);
+
+        HingeEmbeddingLossNode::new(input, target, output, config, reduction)
+    }
+
+    fn softmax_cross_entropy_loss_conversion(node: Node) -> SoftmaxCrossEntropyLossNode {
+        let scores = TensorType::from(node.inputs.first().unwrap());
+        let labels = TensorType::from(node.inputs.get(1).unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let reduction = softmax_cross_entropy_loss_config(&node);
+
+        SoftmaxCrossEntropyLossNode::new(scores, labels, output, reduction)
+    }
+
     fn avg_pool_1d_conversion(node: Node) -> AvgPool1dNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1184,6 +1580,13 @@ impl ParsedOnnxGraph {
         GlobalAvgPoolNode::new(name, input, output)
     }
 
+    fn identity_conversion(node: Node) -> IdentityNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        IdentityNode::new(input, output)
+    }
+
     fn cos_conversion(node: Node) -> UnaryNode {
         let input = Type::from(node.inputs.first().unwrap());
         let output = Type::from(node.outputs.first().unwrap());
@@ -1225,6 +1628,27 @@ impl ParsedOnnxGraph {
         UnaryNode::not(input, output)
     }
 
+    fn and_conversion(node: Node) -> BinaryNode {
+        let lhs = Type::from(node.inputs.first().unwrap());
+        let rhs = Type::from(node.inputs.get(1).unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        BinaryNode::logical_and(lhs, rhs, output)
+    }
+
+    fn or_conversion(node: Node) -> BinaryNode {
+        let lhs = Type::from(node.inputs.first().unwrap());
+        let rhs = Type::from(node.inputs.get(1).unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        BinaryNode::logical_or(lhs, rhs, output)
+    }
+
+    fn xor_conversion(node: Node) -> BinaryNode {
+        let lhs = Type::from(node.inputs.first().unwrap());
+        let rhs = Type::from(node.inputs.get(1).unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        BinaryNode::logical_xor(lhs, rhs, output)
+    }
+
     fn greater_conversion(node: Node) -> BinaryNode {
         let lhs = Type::from(node.inputs.first().unwrap());
         let rhs = Type::from(node.inputs.get(1).unwrap());
@@ -1287,13 +1711,25 @@ impl ParsedOnnxGraph {
     }
 
     fn squeeze_conversion(node: Node) -> SqueezeNode {
-        let input = TensorType::from(node.inputs.first().unwrap());
-        let output = TensorType::from(node.outputs.first().unwrap());
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+        if let Type::Shape(_) = input {
+            // Shapes only have a single axis, already validated by the rank inference
+            return SqueezeNode::new(input, output, vec![0]);
+        }
         let axes = squeeze_config(&node);
 
         SqueezeNode::new(input, output, axes)
     }
 
+    fn stack_conversion(node: Node) -> StackNode {
+        let inputs = node.inputs.iter().map(TensorType::from).collect();
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let dim = stack_config(&node);
+
+        StackNode::new(inputs, output, dim)
+    }
+
     fn tile_conversion(node: Node) -> TileNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1337,6 +1773,13 @@ impl ParsedOnnxGraph {
         OneHotNode::new(input, output, num_classes, values, values_type, axis)
     }
 
+    fn optional_conversion(node: Node, kind: OptionalNodeKind) -> OptionalNode {
+        let input = Type::from(node.inputs.first().unwrap());
+        let output = Type::from(node.outputs.first().unwrap());
+
+        OptionalNode::new(input, output, kind)
+    }
+
     fn floor_conversion(node: Node) -> FloorNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1404,7 +1847,12 @@ impl From<&OnnxArgument> for TensorType {
                 ..
             }) => TensorType::new_float(arg.name.clone(), *rank),
             ArgType::Tensor(OnnxTensorType {
-                elem_type: ElementType::Int32 | ElementType::Int64,
+                elem_type:
+                    ElementType::Int8
+                    | ElementType::Uint8
+                    | ElementType::Int16
+                    | ElementType::Int32
+                    | ElementType::Int64,
                 rank,
                 ..
             }) => TensorType::new_int(arg.name.clone(), *rank),
@@ -1439,7 +1887,48 @@ impl From<&OnnxArgument> for Type {
                 Type::Scalar(ScalarType::new(arg.name.clone(), elem_type.into()))
             }
             ArgType::Shape(rank) => Type::Shape(ShapeType::new(arg.name.clone(), *rank)),
+            ArgType::Optional(ty) => {
+                let value = Type::from(&OnnxArgument {
+                    ty: *ty.clone(),
+                    ..arg.clone()
+                });
+                let value_ty = value.ty();
+                Type::Other(OtherType::new(&arg.name, quote! { Option<#value_ty> }))
+            }
+        }
+    }
+}
+
+fn arg_to_tensor_spec(arg: &OnnxArgument) -> TensorSpec {
+    let name = Type::format_name(&arg.name);
+
+    match &arg.ty {
+        ArgType::Tensor(tensor) => {
+            TensorSpec::new(&name, tensor.rank, elem_type_to_dtype(&tensor.elem_type))
         }
+        ArgType::Scalar(elem_type) => TensorSpec::new(&name, 0, elem_type_to_dtype(elem_type)),
+        // Shapes are 1-D int64 tensors in ONNX
+        ArgType::Shape(_) => TensorSpec::new(&name, 1, DType::I64),
+        // The spec of an optional is the one of its value
+        ArgType::Optional(ty) => arg_to_tensor_spec(&OnnxArgument {
+            ty: *ty.clone(),
+            ..arg.clone()
+        }),
+    }
+}
+
+fn elem_type_to_dtype(elem_type: &ElementType) -> DType {
+    match elem_type {
+        ElementType::Float16 => DType::F16,
+        ElementType::Float32 => DType::F32,
+        ElementType::Float64 => DType::F64,
+        ElementType::Int8 => DType::I8,
+        ElementType::Uint8 => DType::U8,
+        ElementType::Int16 => DType::I16,
+        ElementType::Int32 => DType::I32,
+        ElementType::Int64 => DType::I64,
+        ElementType::Bool => DType::Bool,
+        ElementType::String => panic!("String tensor unsupported"),
     }
 }
 
@@ -1453,6 +1942,9 @@ impl From<&ElementType> for ScalarKind {
             ElementType::Bool => ScalarKind::Bool,
             ElementType::String => panic!("String tensor unsupported"),
             ElementType::Float16 => panic!("Float16 tensor unsupported"),
+            ElementType::Int8 | ElementType::Uint8 | ElementType::Int16 => {
+                panic!("{elem_type:?} scalar unsupported")
+            }
         }
     }
 }
@@ -1462,6 +1954,9 @@ impl From<ElementType> for TensorKind {
         match elem_type {
             ElementType::Float32 => TensorKind::Float,
             ElementType::Float64 => TensorKind::Float,
+            ElementType::Int8 => TensorKind::Int,
+            ElementType::Uint8 => TensorKind::Int,
+            ElementType::Int16 => TensorKind::Int,
             ElementType::Int32 => TensorKind::Int,
             ElementType::Int64 => TensorKind::Int,
             ElementType::Bool => TensorKind::Bool,
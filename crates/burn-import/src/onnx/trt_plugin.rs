@@ -0,0 +1,170 @@
+use onnx_ir::{
+    OnnxGraph,
+    ir::{Node, NodeType},
+};
+
+/// Plugin types of the TensorRT layer normalization, mapped to `LayerNormalization`.
+const LAYER_NORM_PLUGINS: [&str; 3] = [
+    "LayerNorm",
+    "LayerNormPlugin",
+    "CustomLayerNormPluginDynamic",
+];
+
+/// Plugin types recognized but without a Burn equivalent yet.
+const UNSUPPORTED_PLUGINS: [&str; 2] = ["EfficientNMS_TRT", "EfficientNMSPlugin"];
+
+/// Replaces the `TRT_PluginV2` nodes of a TensorRT-exported model by Burn-native equivalents.
+///
+/// The plugin type is read from the `name` attribute of the node. When `allow_unknown` is set,
+/// single output plugins that can't be substituted are passed through as no-ops, forwarding their
+/// first input, so partially supported models still compile. Otherwise, they cause a panic.
+///
+/// # Panics
+///
+/// If a plugin can't be substituted and either `allow_unknown` isn't set or the plugin has
+/// multiple outputs, whose consumers would be left without inputs by a passthrough.
+pub(crate) fn substitute_trt_plugins(graph: &mut OnnxGraph, allow_unknown: bool) {
+    for node in graph
+        .nodes
+        .iter_mut()
+        .filter(|node| node.node_type == NodeType::TrtPluginV2)
+    {
+        let plugin = node
+            .attrs
+            .get("name")
+            .map(|value| value.clone().into_string())
+            .unwrap_or_default();
+
+        if LAYER_NORM_PLUGINS.contains(&plugin.as_str()) {
+            log::debug!(
+                "TRT_PluginV2 {} substituted by LayerNormalization",
+                node.name
+            );
+            node.node_type = NodeType::LayerNormalization;
+            // Drop the plugin fields (e.g. `data`), keeping the ones used by LayerNormalization
+            node.attrs
+                .retain(|key, _| key == "epsilon" || key == "axis");
+        } else if allow_unknown && node.outputs.len() > 1 {
+            panic!(
+                "TRT_PluginV2 {}: plugin `{plugin}` has {} outputs and can't be passed through \
+                as a no-op.",
+                node.name,
+                node.outputs.len()
+            );
+        } else if allow_unknown {
+            log::warn!(
+                "TRT_PluginV2 {}: plugin `{plugin}` is not supported, passing its first input through",
+                node.name
+            );
+            node.node_type = NodeType::Identity;
+            node.inputs.truncate(1);
+            node.attrs.clear();
+        } else {
+            panic!("{}", unsupported_plugin_message(node, &plugin));
+        }
+    }
+}
+
+fn unsupported_plugin_message(node: &Node, plugin: &str) -> String {
+    let reason = if UNSUPPORTED_PLUGINS.contains(&plugin) {
+        "has no Burn equivalent"
+    } else {
+        "is unknown"
+    };
+
+    format!(
+        "TRT_PluginV2 {}: plugin `{plugin}` {reason}. \
+        Use `ModelGen::allow_unknown_plugins` to pass it through as a no-op.",
+        node.name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use onnx_ir::ir::{ArgType, Argument, AttributeValue, ElementType, TensorType};
+
+    fn tensor_arg(name: &str) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorType {
+                elem_type: ElementType::Float32,
+                rank: 3,
+                static_shape: None,
+            }),
+            value: None,
+            passed: true,
+        }
+    }
+
+    fn plugin_graph(plugin: &str) -> OnnxGraph {
+        let node = Node {
+            node_type: NodeType::TrtPluginV2,
+            name: "plugin1".to_string(),
+            inputs: vec![tensor_arg("input"), tensor_arg("gamma"), tensor_arg("beta")],
+            outputs: vec![tensor_arg("output")],
+            attrs: [
+                ("name", AttributeValue::String(plugin.to_string())),
+                ("version", AttributeValue::String("1".to_string())),
+                ("epsilon", AttributeValue::Float32(1e-6)),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+        };
+
+        OnnxGraph {
+            nodes: vec![node],
+            inputs: vec![tensor_arg("input")],
+            outputs: vec![tensor_arg("output")],
+        }
+    }
+
+    #[test]
+    fn layer_norm_plugin_substituted() {
+        let mut graph = plugin_graph("LayerNormPlugin");
+
+        substitute_trt_plugins(&mut graph, false);
+
+        let node = &graph.nodes[0];
+        assert_eq!(node.node_type, NodeType::LayerNormalization);
+        assert_eq!(node.inputs.len(), 3);
+        assert_eq!(node.attrs.len(), 1);
+        assert_eq!(node.attrs["epsilon"].clone().into_f32(), 1e-6);
+    }
+
+    #[test]
+    fn unknown_plugin_passed_through() {
+        let mut graph = plugin_graph("MyCustomPlugin");
+
+        substitute_trt_plugins(&mut graph, true);
+
+        let node = &graph.nodes[0];
+        assert_eq!(node.node_type, NodeType::Identity);
+        assert_eq!(node.inputs.len(), 1);
+        assert!(node.attrs.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "plugin `EfficientNMS_TRT` has 4 outputs")]
+    fn multi_output_plugin_not_passed_through() {
+        let mut graph = plugin_graph("EfficientNMS_TRT");
+        graph.nodes[0].outputs = ["num_detections", "boxes", "scores", "classes"]
+            .map(tensor_arg)
+            .to_vec();
+
+        substitute_trt_plugins(&mut graph, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "plugin `MyCustomPlugin` is unknown")]
+    fn unknown_plugin_rejected() {
+        substitute_trt_plugins(&mut plugin_graph("MyCustomPlugin"), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "plugin `EfficientNMS_TRT` has no Burn equivalent")]
+    fn nms_plugin_rejected() {
+        substitute_trt_plugins(&mut plugin_graph("EfficientNMS_TRT"), false);
+    }
+}
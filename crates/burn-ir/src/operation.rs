@@ -7,7 +7,7 @@ use alloc::boxed::Box;
 use alloc::{string::String, vec, vec::Vec};
 
 use burn_tensor::{
-    DType, Distribution, Element,
+    DType, Distribution, Element, IndexReduceMode,
     ops::{
         ConvOptions, ConvTransposeOptions, DeformConvOptions, InterpolateMode, InterpolateOptions,
     },
@@ -362,6 +362,11 @@ pub enum NumericOperationIr<E> {
     Scatter(ScatterOpIr),
     /// Operation corresponding to:
     ///
+    /// Float => [index reduce](burn_tensor::ops::FloatTensorOps::float_index_reduce).
+    /// Int => [index reduce](burn_tensor::ops::IntTensorOps::int_index_reduce).
+    IndexReduce(IndexReduceOpIr),
+    /// Operation corresponding to:
+    ///
     /// Float => [select](burn_tensor::ops::FloatTensorOps::float_select).
     /// Int => [select](burn_tensor::ops::IntTensorOps::int_select).
     Select(SelectOpIr),
@@ -702,6 +707,48 @@ pub struct ScatterOpIr {
     pub out: TensorIr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum IndexReduceModeIr {
+    Sum,
+    Prod,
+    AMax,
+    AMin,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct IndexReduceOpIr {
+    pub tensor: TensorIr,
+    pub dim: usize,
+    pub indices: TensorIr,
+    pub value: TensorIr,
+    pub mode: IndexReduceModeIr,
+    pub out: TensorIr,
+}
+
+impl From<IndexReduceModeIr> for IndexReduceMode {
+    fn from(val: IndexReduceModeIr) -> Self {
+        match val {
+            IndexReduceModeIr::Sum => Self::Sum,
+            IndexReduceModeIr::Prod => Self::Prod,
+            IndexReduceModeIr::AMax => Self::AMax,
+            IndexReduceModeIr::AMin => Self::AMin,
+        }
+    }
+}
+
+impl From<IndexReduceMode> for IndexReduceModeIr {
+    fn from(val: IndexReduceMode) -> Self {
+        match val {
+            IndexReduceMode::Sum => Self::Sum,
+            IndexReduceMode::Prod => Self::Prod,
+            IndexReduceMode::AMax => Self::AMax,
+            IndexReduceMode::AMin => Self::AMin,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct SelectOpIr {
@@ -1469,6 +1516,9 @@ impl<E: Element> NumericOperationIr<E> {
             NumericOperationIr::Scatter(repr) => {
                 vec![&repr.tensor, &repr.indices, &repr.value, &repr.out]
             }
+            NumericOperationIr::IndexReduce(repr) => {
+                vec![&repr.tensor, &repr.indices, &repr.value, &repr.out]
+            }
             NumericOperationIr::Select(repr) => {
                 vec![&repr.tensor, &repr.indices, &repr.out]
             }
@@ -1843,6 +1893,7 @@ impl<E> core::hash::Hash for NumericOperationIr<E> {
             NumericOperationIr::Full(repr) => repr.0.hash(state),
             NumericOperationIr::Gather(repr) => repr.hash(state),
             NumericOperationIr::Scatter(repr) => repr.hash(state),
+            NumericOperationIr::IndexReduce(repr) => repr.hash(state),
             NumericOperationIr::Select(repr) => repr.hash(state),
             NumericOperationIr::SelectAssign(repr) => repr.hash(state),
             NumericOperationIr::MaskWhere(repr) => repr.hash(state),
@@ -1,5 +1,6 @@
 use alloc::{vec, vec::Vec};
 use burn_tensor::ElementConversion;
+use burn_tensor::IndexReduceMode;
 use burn_tensor::TensorData;
 use burn_tensor::TensorMetadata;
 #[cfg(feature = "simd")]
@@ -154,6 +155,23 @@ where
         NdArrayTensor { array }
     }
 
+    /// Repeats each element along the given dimension by broadcasting a new axis after it.
+    pub(crate) fn repeat_interleave(
+        tensor: NdArrayTensor<E>,
+        dim: usize,
+        repeats: usize,
+    ) -> NdArrayTensor<E> {
+        let mut shape = tensor.shape();
+        let mut expanded = shape.dims.clone();
+        expanded.insert(dim + 1, repeats);
+        shape.dims[dim] *= repeats;
+
+        let array = tensor.array.insert_axis(Axis(dim + 1));
+        let tensor = Self::expand(NdArrayTensor::new(array), Shape::from(expanded));
+
+        Self::reshape(tensor, shape)
+    }
+
     pub fn flip(tensor: NdArrayTensor<E>, axes: &[usize]) -> NdArrayTensor<E> {
         let slice_items: Vec<_> = (0..tensor.shape().num_dims())
             .map(|i| {
@@ -552,10 +570,20 @@ where
     }
 
     pub fn scatter<I: NdArrayElement>(
+        dim: usize,
+        tensor: NdArrayTensor<E>,
+        indices: NdArrayTensor<I>,
+        value: NdArrayTensor<E>,
+    ) -> NdArrayTensor<E> {
+        Self::index_reduce(dim, tensor, indices, value, IndexReduceMode::Sum)
+    }
+
+    pub fn index_reduce<I: NdArrayElement>(
         dim: usize,
         mut tensor: NdArrayTensor<E>,
         mut indices: NdArrayTensor<I>,
         mut value: NdArrayTensor<E>,
+        mode: IndexReduceMode,
     ) -> NdArrayTensor<E> {
         let ndims = tensor.shape().num_dims();
         if dim != ndims - 1 {
@@ -590,7 +618,16 @@ where
 
             for (i, index) in indices.iter().enumerate() {
                 let index = index.elem::<i64>() as usize;
-                tensor[[b, index]] += value[[b, i]];
+                let current = tensor[[b, index]];
+                let value = value[[b, i]];
+
+                tensor[[b, index]] = match mode {
+                    IndexReduceMode::Sum => current + value,
+                    IndexReduceMode::Prod => current * value,
+                    IndexReduceMode::AMax if value > current => value,
+                    IndexReduceMode::AMin if value < current => value,
+                    IndexReduceMode::AMax | IndexReduceMode::AMin => current,
+                };
             }
         }
 
@@ -749,6 +786,8 @@ where
     }
 
     pub fn clamp(tensor: NdArrayTensor<E>, min: E, max: E) -> NdArrayTensor<E> {
+        // Floats are not dispatched to SIMD, since the vector min and max return the non-NaN
+        // operand while clamp must propagate NaN.
         let mut tensor = dispatch_binary_scalar_simd!(
             E,
             VecClamp,
@@ -760,10 +799,8 @@ where
             i16,
             u32,
             i32,
-            f32,
             u64,
-            i64,
-            f64
+            i64
         );
 
         tensor.array.mapv_inplace(|x| match x < min {
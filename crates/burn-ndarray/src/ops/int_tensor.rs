@@ -2,6 +2,7 @@
 use alloc::vec::Vec;
 use burn_common::rand::get_seeded_rng;
 use burn_tensor::Distribution;
+use burn_tensor::IndexReduceMode;
 use burn_tensor::ops::FloatTensor;
 use burn_tensor::ops::IntTensorOps;
 
@@ -226,6 +227,16 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         NdArrayMathOps::scatter(dim, tensor, indices, value)
     }
 
+    fn int_index_reduce(
+        dim: usize,
+        tensor: NdArrayTensor<I>,
+        indices: NdArrayTensor<I>,
+        value: NdArrayTensor<I>,
+        mode: IndexReduceMode,
+    ) -> NdArrayTensor<I> {
+        NdArrayMathOps::index_reduce(dim, tensor, indices, value, mode)
+    }
+
     fn int_select(
         tensor: NdArrayTensor<I>,
         dim: usize,
@@ -337,6 +348,14 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         NdArrayOps::expand(tensor, shape)
     }
 
+    fn int_repeat_interleave(
+        tensor: NdArrayTensor<I>,
+        dim: usize,
+        repeats: usize,
+    ) -> NdArrayTensor<I> {
+        NdArrayOps::repeat_interleave(tensor, dim, repeats)
+    }
+
     fn bitwise_and(lhs: NdArrayTensor<I>, rhs: NdArrayTensor<I>) -> NdArrayTensor<I> {
         NdArrayBitOps::bitand(lhs, rhs)
     }
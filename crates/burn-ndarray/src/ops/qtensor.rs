@@ -1,12 +1,13 @@
 use alloc::vec;
+use alloc::vec::Vec;
 use core::ops::Range;
 
 use burn_tensor::{
-    DType, Shape, TensorData, TensorMetadata,
-    ops::{FloatTensor, IntTensor, QTensorOps, QuantizedTensor},
+    DType, ElementConversion, Shape, TensorData, TensorMetadata, TensorPrimitive,
+    ops::{FloatElem, FloatTensor, FloatTensorOps, IntTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        QParams, QuantInputType, QuantLevel, QuantMode, QuantScheme,
-        QuantizationParametersPrimitive, QuantizationStrategy, QuantizedBytes,
+        AffineQuantization, QParams, QuantInputType, QuantLevel, QuantMode, QuantPropagation,
+        QuantScheme, QuantizationParametersPrimitive, QuantizationStrategy, QuantizedBytes,
         SymmetricQuantization,
     },
 };
@@ -32,6 +33,30 @@ fn into_data_f(tensor: NdArrayTensorFloat) -> TensorData {
     }
 }
 
+/// Clamps the quantized values between the bounds quantized with the parameters of the tensor,
+/// which remain the same.
+fn q_clamp_quantized<Q: QuantElement>(
+    tensor: NdArrayQTensor<Q>,
+    min: Option<f32>,
+    max: Option<f32>,
+) -> NdArrayQTensor<Q> {
+    let strategy = tensor.strategy();
+    let quantize = |value: f32| strategy.quantize(&[value])[0].elem::<Q>();
+
+    let qtensor = match (min.map(quantize), max.map(quantize)) {
+        (Some(min), Some(max)) => NdArrayMathOps::clamp(tensor.qtensor, min, max),
+        (Some(min), None) => NdArrayMathOps::clamp_min(tensor.qtensor, min),
+        (None, Some(max)) => NdArrayMathOps::clamp_max(tensor.qtensor, max),
+        (None, None) => tensor.qtensor,
+    };
+
+    NdArrayQTensor {
+        qtensor,
+        scheme: tensor.scheme,
+        qparams: tensor.qparams,
+    }
+}
+
 impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<Self>
     for NdArray<E, I, Q>
 {
@@ -49,7 +74,7 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
                 match scheme {
                     QuantScheme {
                         level: QuantLevel::Tensor,
-                        mode: QuantMode::Symmetric,
+                        mode: QuantMode::Symmetric | QuantMode::Affine,
                         q_type: QuantInputType::QInt8,
                         ..
                     } => {
@@ -57,12 +82,14 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
                         let (values, qparams) = q_bytes.into_vec_i8();
                         let data = TensorData::new(values, shape);
 
+                        let offsets = qparams.offset.unwrap_or_default();
                         let qparams = qparams
                             .scale
                             .into_iter()
-                            .map(|scale| QParams {
+                            .enumerate()
+                            .map(|(i, scale)| QParams {
                                 scale,
-                                offset: None,
+                                offset: offsets.get(i).map(|offset| offset.elem::<Q>()),
                             })
                             .collect();
 
@@ -105,6 +132,28 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
                     }],
                 )
             }
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => {
+                let scale = into_data_f(qparams.scale).iter().next().unwrap();
+                let offset: i8 = into_data(qparams.offset.unwrap())
+                    .iter::<i32>()
+                    .next()
+                    .unwrap()
+                    .elem();
+                (
+                    QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
+                        scale, offset,
+                    )),
+                    vec![QParams {
+                        scale,
+                        offset: Some(offset.elem::<Q>()),
+                    }],
+                )
+            }
         };
 
         let shape = tensor.shape();
@@ -156,7 +205,10 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
         let strategy = tensor.strategy();
         let shape = tensor.qtensor.shape();
         let values = tensor.qtensor.array.into_iter().collect();
-        TensorData::quantized(values, shape, strategy)
+        let mut data = TensorData::quantized(values, shape, strategy);
+        // The strategy only describes how the values are quantized, keep the whole scheme
+        data.dtype = DType::QFloat(tensor.scheme);
+        data
     }
 
     fn q_swap_dims(
@@ -234,4 +286,68 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> QTensorOps<S
             qparams: tensor.qparams,
         }
     }
+
+    fn q_cat(tensors: Vec<QuantizedTensor<Self>>, dim: usize) -> QuantizedTensor<Self> {
+        let scheme = tensors[0].scheme;
+        let qparams = tensors[0].qparams.clone();
+
+        // Operands sharing the same parameters are concatenated on the quantized values
+        if tensors
+            .iter()
+            .all(|tensor| tensor.scheme == scheme && tensor.qparams == qparams)
+        {
+            let qtensors = tensors.into_iter().map(|tensor| tensor.qtensor).collect();
+            return NdArrayQTensor {
+                qtensor: NdArrayOps::cat(qtensors, dim),
+                scheme,
+                qparams,
+            };
+        }
+
+        // Otherwise they are requantized to a shared scale covering all of them
+        let tensors = tensors.into_iter().map(Self::dequantize).collect();
+        Self::quantize_dynamic(Self::float_cat(tensors, dim), &scheme)
+    }
+
+    fn q_clamp_min(tensor: QuantizedTensor<Self>, min: FloatElem<Self>) -> TensorPrimitive<Self> {
+        Self::q_propagate(q_clamp_quantized(tensor, Some(min.elem()), None))
+    }
+
+    fn q_clamp_max(tensor: QuantizedTensor<Self>, max: FloatElem<Self>) -> TensorPrimitive<Self> {
+        Self::q_propagate(q_clamp_quantized(tensor, None, Some(max.elem())))
+    }
+
+    fn q_clamp(
+        tensor: QuantizedTensor<Self>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> TensorPrimitive<Self> {
+        Self::q_propagate(q_clamp_quantized(
+            tensor,
+            Some(min.elem()),
+            Some(max.elem()),
+        ))
+    }
+
+    fn q_relu(tensor: QuantizedTensor<Self>) -> TensorPrimitive<Self> {
+        // The zero-point is exactly representable, so the negative values are clamped to it
+        let zero_point = tensor.qparams[0].offset.unwrap_or(0.elem());
+
+        Self::q_propagate(NdArrayQTensor {
+            qtensor: NdArrayMathOps::clamp_min(tensor.qtensor, zero_point),
+            scheme: tensor.scheme,
+            qparams: tensor.qparams,
+        })
+    }
+}
+
+impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> NdArray<E, I, Q> {
+    /// Returns the output of an operation on the quantized values, dequantized unless the scheme
+    /// propagates quantization.
+    fn q_propagate(tensor: QuantizedTensor<Self>) -> TensorPrimitive<Self> {
+        match tensor.scheme.propagation {
+            QuantPropagation::Propagate => TensorPrimitive::QFloat(tensor),
+            QuantPropagation::Inhibit => TensorPrimitive::Float(Self::dequantize(tensor)),
+        }
+    }
 }
@@ -12,7 +12,7 @@ use crate::{NdArrayDevice, NdArrayTensorFloat, SEED, execute_with_float_dtype};
 
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
-use burn_tensor::{DType, Distribution, FloatDType};
+use burn_tensor::{DType, Distribution, FloatDType, IndexReduceMode};
 use burn_tensor::{ElementConversion, Shape, TensorData, backend::Backend, ops::FloatTensorOps};
 
 #[cfg(not(feature = "std"))]
@@ -167,6 +167,18 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         ))
     }
 
+    fn float_index_reduce(
+        dim: usize,
+        tensor: FloatTensor<Self>,
+        indices: NdArrayTensor<I>,
+        value: FloatTensor<Self>,
+        mode: IndexReduceMode,
+    ) -> FloatTensor<Self> {
+        execute_with_float_dtype!((tensor, value), |tensor, value| {
+            NdArrayMathOps::index_reduce(dim, tensor, indices, value, mode)
+        })
+    }
+
     fn float_select(
         tensor: FloatTensor<Self>,
         dim: usize,
@@ -336,6 +348,17 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::prod_dim(tensor, dim))
     }
 
+    fn float_frobenius_norm(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
+            // Accumulate the squares in a single pass, without the intermediate tensors
+            let sum = tensor.array.fold(0.0f64, |sum, value| {
+                let value = value.to_f64();
+                sum + value * value
+            });
+            NdArrayTensor::from_data(TensorData::from([sum.sqrt().elem::<E>()]))
+        })
+    }
+
     fn float_log1p(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
             let array = tensor.array.mapv_into(|a| a.log1p_elem()).into_shared();
@@ -541,6 +564,16 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         execute_with_float_dtype!(tensor, |tensor| NdArrayOps::expand(tensor, shape))
     }
 
+    fn float_repeat_interleave(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+        repeats: usize,
+    ) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| NdArrayOps::repeat_interleave(
+            tensor, dim, repeats
+        ))
+    }
+
     fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
         fn cast<E1: FloatNdArrayElement, E2: FloatNdArrayElement>(
             tensor: &NdArrayTensor<E1>,
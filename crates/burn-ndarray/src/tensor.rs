@@ -3,8 +3,8 @@ use core::mem;
 use burn_tensor::{
     DType, Element, Shape, TensorData, TensorMetadata,
     quantization::{
-        QParams, QTensorPrimitive, QuantInputType, QuantLevel, QuantMode, QuantScheme,
-        QuantizationStrategy, SymmetricQuantization,
+        AffineQuantization, QParams, QTensorPrimitive, QuantInputType, QuantLevel, QuantMode,
+        QuantScheme, QuantizationStrategy, SymmetricQuantization,
     },
 };
 
@@ -335,6 +335,15 @@ impl<Q: QuantElement> NdArrayQTensor<Q> {
             } => QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
                 self.qparams[0].scale,
             )),
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
+                self.qparams[0].scale,
+                self.qparams[0].offset.unwrap().elem::<i8>(),
+            )),
         }
     }
 }
@@ -0,0 +1,47 @@
+//! Checks that the specialized Frobenius norm allocates less memory than the composed version.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use burn_ndarray::NdArray;
+use burn_tensor::{Distribution, Tensor, Tolerance};
+
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocated_bytes<T>(func: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let output = func();
+    (output, ALLOCATED.load(Ordering::Relaxed) - before)
+}
+
+#[test]
+fn norm_frob_allocates_less_than_composed_norm() {
+    let device = Default::default();
+    let tensor = Tensor::<NdArray, 2>::random([256, 256], Distribution::Default, &device);
+
+    let (composed, composed_bytes) =
+        allocated_bytes(|| tensor.clone().powi_scalar(2).sum().sqrt().into_data());
+    let (norm, norm_bytes) = allocated_bytes(|| tensor.clone().norm_frob().into_data());
+
+    norm.assert_approx_eq::<f32>(&composed, Tolerance::default());
+    assert!(
+        (norm_bytes as f64) <= 0.7 * composed_bytes as f64,
+        "The Frobenius norm allocated {norm_bytes} bytes, the composed norm {composed_bytes} bytes"
+    );
+}
@@ -450,6 +450,20 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::float_scatter(desc.dim, tensor, indices, value);
                     handles.register_float_tensor::<B>(&desc.out.id, output);
                 }
+                NumericOperationIr::IndexReduce(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.tensor);
+                    let indices = handles.get_int_tensor::<B>(&desc.indices);
+                    let value = handles.get_float_tensor::<B>(&desc.value);
+
+                    let output = B::float_index_reduce(
+                        desc.dim,
+                        tensor,
+                        indices,
+                        value,
+                        desc.mode.clone().into(),
+                    );
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                }
                 NumericOperationIr::Select(desc) => {
                     let tensor = handles.get_float_tensor::<B>(&desc.tensor);
                     let indices = handles.get_int_tensor::<B>(&desc.indices);
@@ -638,6 +652,20 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::int_scatter(desc.dim, tensor, indices, value);
                     handles.register_int_tensor::<B>(&desc.out.id, output);
                 }
+                NumericOperationIr::IndexReduce(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.tensor);
+                    let indices = handles.get_int_tensor::<B>(&desc.indices);
+                    let value = handles.get_int_tensor::<B>(&desc.value);
+
+                    let output = B::int_index_reduce(
+                        desc.dim,
+                        tensor,
+                        indices,
+                        value,
+                        desc.mode.clone().into(),
+                    );
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                }
                 NumericOperationIr::Select(desc) => {
                     let tensor = handles.get_int_tensor::<B>(&desc.tensor);
                     let indices = handles.get_int_tensor::<B>(&desc.indices);
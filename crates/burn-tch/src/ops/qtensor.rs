@@ -31,6 +31,16 @@ fn quantize<E: TchElement, Q: QuantElement>(
             q_type: QuantInputType::QInt8,
             ..
         } => tensor.quantize_per_tensor(qparams.scale.elem(), 0, tch::Kind::QInt8),
+        QuantScheme {
+            level: QuantLevel::Tensor,
+            mode: QuantMode::Affine,
+            q_type: QuantInputType::QInt8,
+            ..
+        } => tensor.quantize_per_tensor(
+            qparams.scale.elem(),
+            qparams.offset.unwrap().elem(),
+            tch::Kind::QInt8,
+        ),
     }
 }
 
@@ -96,6 +106,16 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                 &tch::Tensor::zeros_like(&qparams.scale.tensor),
                 tch::Kind::QInt8,
             ),
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => tensor.tensor.quantize_per_tensor_tensor_qparams(
+                &qparams.scale.tensor,
+                &qparams.offset.unwrap().tensor,
+                tch::Kind::QInt8,
+            ),
         };
 
         TchQTensor {
@@ -119,6 +139,14 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                     .tensor
                     .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false)
             }
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => tensor
+                .tensor
+                .quantize_per_tensor_dynamic(tch::Kind::QInt8, /*reduce_range*/ false),
         };
 
         TchQTensor {
@@ -159,7 +187,10 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         // To get the integer values we have to call `int_repr()`
         let values: Result<Vec<i8>, tch::TchError> = tensor.qtensor.tensor.int_repr().try_into();
 
-        TensorData::quantized(values.unwrap(), shape, strategy)
+        let mut data = TensorData::quantized(values.unwrap(), shape, strategy);
+        // The strategy only describes how the values are quantized, keep the whole scheme
+        data.dtype = DType::QFloat(tensor.scheme);
+        data
     }
 
     fn q_swap_dims(
@@ -2,8 +2,8 @@ use crate::{LibTorchDevice, TchElement};
 use burn_tensor::{
     DType, Shape, TensorData, TensorMetadata,
     quantization::{
-        QTensorPrimitive, QuantInputType, QuantLevel, QuantMode, QuantScheme, QuantizationStrategy,
-        SymmetricQuantization,
+        AffineQuantization, QTensorPrimitive, QuantInputType, QuantLevel, QuantMode, QuantScheme,
+        QuantizationStrategy, SymmetricQuantization,
     },
 };
 use libc::c_void;
@@ -342,6 +342,19 @@ impl TchQTensor {
                     scale as f32,
                 ))
             }
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => {
+                let scale = self.qtensor.tensor.q_scale();
+                let offset = self.qtensor.tensor.q_zero_point();
+                QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
+                    scale as f32,
+                    offset as i8,
+                ))
+            }
         }
     }
 }
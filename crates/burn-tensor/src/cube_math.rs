@@ -0,0 +1,31 @@
+//! Math functions shared by the kernels of the fused and unfused operations of the cubecl backends.
+
+use cubecl::prelude::*;
+
+/// The error function, using the rational approximation of Eigen for single precision.
+///
+/// It is accurate to a few ULPs, also relative to the output for the inputs close to zero, while
+/// the approximation of the cubecl polyfill has a relative error up to `1e-4` there.
+#[cube]
+pub fn erf<F: Float>(input: Line<F>) -> Line<F> {
+    // The approximation is exact to single precision past the clamped range
+    let x = Line::clamp(input, Line::new(F::new(-4.0)), Line::new(F::new(4.0)));
+    let x2 = x * x;
+
+    let mut p = Line::new(F::new(-2.7261423e-10));
+    p = p * x2 + Line::new(F::new(2.7706814e-8));
+    p = p * x2 + Line::new(F::new(-2.101024e-6));
+    p = p * x2 + Line::new(F::new(-5.6925064e-5));
+    p = p * x2 + Line::new(F::new(-7.3499063e-4));
+    p = p * x2 + Line::new(F::new(-2.9546e-3));
+    p = p * x2 + Line::new(F::new(-1.6096033e-2));
+    p *= x;
+
+    let mut q = Line::new(F::new(-1.45660718e-5));
+    q = q * x2 + Line::new(F::new(-2.1337405e-4));
+    q = q * x2 + Line::new(F::new(-1.682827e-3));
+    q = q * x2 + Line::new(F::new(-7.3733292e-3));
+    q = q * x2 + Line::new(F::new(-1.4264739e-2));
+
+    p / q
+}
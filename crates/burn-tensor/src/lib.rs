@@ -30,6 +30,9 @@ pub use burn_common::reader::*; // Useful so that backends don't have to add `bu
 #[cfg(feature = "cubecl")]
 pub use cubecl::flex32;
 
+#[cfg(feature = "cubecl")]
+pub mod cube_math;
+
 #[cfg(feature = "cubecl")]
 mod cube {
     use cubecl::ir::{Elem, FloatKind, IntKind, UIntKind};
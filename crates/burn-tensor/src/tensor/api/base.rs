@@ -177,7 +177,8 @@ where
     /// The tensor has the same data and number of elements as the input.
     ///
     /// A `-1` in the shape is used to infer the remaining dimensions, e.g.: `[2, -1]`
-    /// will reshape the tensor with [2, 3, 4] dimensions to [2, 12].
+    /// will reshape the tensor with [2, 3, 4] dimensions to [2, 12]. The shape can be given
+    /// as `[i32; D2]` or `[i64; D2]` to use `-1` and `0`, or as `[usize; D2]` for exact dimensions.
     ///
     /// A `0` in the shape instructs to keep the current dimension from the original tensor,
     /// e.g.: `[2, 0, 4]` will reshape the tensor with [2, 3, 4] dimensions to [2, 3, 4].
@@ -192,6 +193,7 @@ where
     /// - If the tensor contains more than one `-1` in the shape.
     /// - If the tensor contains values that are not positive (other than -1).
     /// - If the shape does not match the number of elements of the original shape.
+    /// - If the `-1` dimension cannot be inferred because the other dimensions have no elements.
     ///
     /// # Example
     ///
@@ -468,6 +470,99 @@ where
         Tensor::new(K::flip(self.primitive, &transformed_axes))
     }
 
+    /// Rearranges the channels of the tensor into blocks of `upscale_factor x upscale_factor`
+    /// spatial elements, as done by sub-pixel convolutions to upscale images.
+    ///
+    /// This is the equivalent of [`torch.nn.functional.pixel_shuffle`](https://pytorch.org/docs/stable/generated/torch.nn.functional.pixel_shuffle.html)
+    /// and the inverse of [pixel_unshuffle](Tensor::pixel_unshuffle).
+    ///
+    /// # Arguments
+    ///
+    /// * `upscale_factor` - The factor by which the height and the width are increased.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[..., channels * upscale_factor^2, height, width]`
+    /// * output: `[..., channels, height * upscale_factor, width * upscale_factor]`
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than 3 dimensions, if the upscale factor is zero or if the number
+    /// of channels isn't divisible by the square of the upscale factor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::arange(0..8, &device).reshape([1, 4, 1, 2]);
+    ///
+    ///     // [[[[0, 2, 1, 3],
+    ///     //    [4, 6, 5, 7]]]]
+    ///     // The resulting tensor will have dimensions [1, 1, 2, 4].
+    ///     let shuffled = tensor.pixel_shuffle(2);
+    ///     println!("{shuffled}");
+    /// }
+    /// ```
+    pub fn pixel_shuffle(self, upscale_factor: usize) -> Tensor<B, D, K> {
+        let dims = self.dims();
+        check!(TensorCheck::pixel_shuffle(&dims, upscale_factor));
+
+        let factor = upscale_factor;
+        let [channels, height, width] = [dims[D - 3], dims[D - 2], dims[D - 1]];
+        let batch_size = dims[..D - 3].iter().product();
+        let channels_out = channels / (factor * factor);
+
+        let mut shape = dims;
+        shape[D - 3] = channels_out;
+        shape[D - 2] = height * factor;
+        shape[D - 1] = width * factor;
+
+        self.reshape([batch_size, channels_out, factor, factor, height, width])
+            .permute([0, 1, 4, 2, 5, 3])
+            .reshape(shape)
+    }
+
+    /// Rearranges blocks of `downscale_factor x downscale_factor` spatial elements of the tensor
+    /// into channels, the inverse of [pixel_shuffle](Tensor::pixel_shuffle).
+    ///
+    /// This is the equivalent of [`torch.nn.functional.pixel_unshuffle`](https://pytorch.org/docs/stable/generated/torch.nn.functional.pixel_unshuffle.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `downscale_factor` - The factor by which the height and the width are decreased.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[..., channels, height * downscale_factor, width * downscale_factor]`
+    /// * output: `[..., channels * downscale_factor^2, height, width]`
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than 3 dimensions, if the downscale factor is zero or if the height
+    /// or the width isn't divisible by the downscale factor.
+    pub fn pixel_unshuffle(self, downscale_factor: usize) -> Tensor<B, D, K> {
+        let dims = self.dims();
+        check!(TensorCheck::pixel_unshuffle(&dims, downscale_factor));
+
+        let factor = downscale_factor;
+        let [channels, height, width] = [dims[D - 3], dims[D - 2], dims[D - 1]];
+        let batch_size = dims[..D - 3].iter().product();
+        let [height_out, width_out] = [height / factor, width / factor];
+
+        let mut shape = dims;
+        shape[D - 3] = channels * factor * factor;
+        shape[D - 2] = height_out;
+        shape[D - 1] = width_out;
+
+        self.reshape([batch_size, channels, height_out, factor, width_out, factor])
+            .permute([0, 1, 3, 5, 2, 4])
+            .reshape(shape)
+    }
+
     /// Flatten the tensor along a given range of dimensions.
     ///
     /// This function collapses the specified range of dimensions into a single dimension,
@@ -897,10 +992,13 @@ where
     /// Returns a copy of the current tensor with the selected elements changed to the new ones at
     /// the selected indices.
     ///
+    /// The values are broadcast to the shape of the slice, so a dimension of size 1 is repeated
+    /// over all the selected elements of that dimension.
+    ///
     /// # Panics
     ///
     /// - If a range exceeds the number of elements on a dimension.
-    /// - If the given values don't match the given ranges.
+    /// - If the given values can't be broadcast to the given ranges.
     ///
     /// # Example
     ///
@@ -912,16 +1010,29 @@ where
     ///     let device = B::Device::default();
     ///     let tensor = Tensor::<B, 3>::ones([2, 3, 3], &device);
     ///     let values = Tensor::<B, 3>::zeros([1, 1, 1], &device);
-    ///     let tensor_sliced = tensor.slice_assign([0..1, 0..1, 0..1], values);
+    ///     let tensor_sliced = tensor.slice_assign([0..1, 0..2, 0..3], values);
     ///     println!("{:?}", tensor_sliced.dims()); // [2, 3, 3]
     /// }
     /// ```
     pub fn slice_assign<const D2: usize>(self, ranges: [Range<usize>; D2], values: Self) -> Self {
+        let shape = self.shape();
         check!(TensorCheck::slice_assign::<D, D2>(
-            &self.shape(),
+            &shape,
             &values.shape(),
             &ranges
         ));
+
+        // The dimensions without range are assigned entirely
+        let shape_slice: [usize; D] = core::array::from_fn(|i| match ranges.get(i) {
+            Some(range) => range.end - range.start,
+            None => shape.dims[i],
+        });
+        let values = if values.dims() == shape_slice {
+            values
+        } else {
+            values.expand(shape_slice)
+        };
+
         Self::new(K::slice_assign(self.primitive, &ranges, values.primitive))
     }
 
@@ -935,6 +1046,22 @@ where
         Self::new(K::to_device(self.primitive, device))
     }
 
+    /// Move the tensor to the given device without blocking on the transfer.
+    ///
+    /// The returned future resolves to the tensor on the given device, so the transfer can be
+    /// overlapped with other computations until the tensor is first needed. Like
+    /// [to_device](Tensor::to_device), the tensor stays tracked by autodiff.
+    ///
+    /// # Note
+    ///
+    /// The transfer only runs in the background on backends that can read device data
+    /// asynchronously, like the cubecl backends. On CPU backends, and on backends wrapping another
+    /// one such as autodiff and fusion, it degrades to a synchronous transfer done before the
+    /// future is returned.
+    pub async fn to_device_async(self, device: &B::Device) -> Self {
+        Self::new(K::to_device_async(self.primitive, device).await)
+    }
+
     /// Converts the data of the current tensor.
     ///
     /// # Note
@@ -1530,6 +1657,50 @@ where
         Tensor::new(K::any_dim(self.primitive, dim))
     }
 
+    /// Tests if any element in the `tensor` evaluates to True along all the given dimensions at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - The axes along which to test.
+    /// * `keepdim` - If the tested dimensions are kept with a size of 1, otherwise they are
+    ///   removed and `D2` must be `D - dims.len()`.
+    ///
+    /// # Panics
+    ///
+    /// If `dims` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Bool};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor =
+    ///         Tensor::<B, 2, Bool>::from_data([[true, false, false], [false, false, false]], &device);
+    ///     // Check if any element in each row evaluates to True.
+    ///     // [true, false]
+    ///     let any: Tensor<B, 1, Bool> = tensor.any_dims(&[1], false);
+    ///     println!("{any}");
+    /// }
+    /// ```
+    pub fn any_dims<const D2: usize>(self, dims: &[usize], keepdim: bool) -> Tensor<B, D2, Bool> {
+        check!(TensorCheck::test_dims::<D, D2>("Any", dims, keepdim));
+
+        let (dim, others) = dims.split_first().unwrap();
+        let tensor = others
+            .iter()
+            .fold(self.any_dim(*dim), |tensor, dim| tensor.any_dim(*dim));
+
+        if keepdim {
+            Tensor::new(tensor.primitive)
+        } else {
+            let dims: Vec<isize> = dims.iter().map(|dim| *dim as isize).collect();
+            tensor.squeeze_dims(&dims)
+        }
+    }
+
     /// Tests if all elements in the `tensor` evaluate to True.
     ///
     /// # Arguments
@@ -1594,6 +1765,50 @@ where
         Tensor::new(K::all_dim(self.primitive, dim))
     }
 
+    /// Tests if all elements in the `tensor` evaluate to True along all the given dimensions at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - The axes along which to test.
+    /// * `keepdim` - If the tested dimensions are kept with a size of 1, otherwise they are
+    ///   removed and `D2` must be `D - dims.len()`.
+    ///
+    /// # Panics
+    ///
+    /// If `dims` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Bool};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor =
+    ///         Tensor::<B, 2, Bool>::from_data([[true, true, false], [true, true, true]], &device);
+    ///     // Check if all elements in each row evaluate to True.
+    ///     // [false, true]
+    ///     let all: Tensor<B, 1, Bool> = tensor.all_dims(&[1], false);
+    ///     println!("{all}");
+    /// }
+    /// ```
+    pub fn all_dims<const D2: usize>(self, dims: &[usize], keepdim: bool) -> Tensor<B, D2, Bool> {
+        check!(TensorCheck::test_dims::<D, D2>("All", dims, keepdim));
+
+        let (dim, others) = dims.split_first().unwrap();
+        let tensor = others
+            .iter()
+            .fold(self.all_dim(*dim), |tensor, dim| tensor.all_dim(*dim));
+
+        if keepdim {
+            Tensor::new(tensor.primitive)
+        } else {
+            let dims: Vec<isize> = dims.iter().map(|dim| *dim as isize).collect();
+            tensor.squeeze_dims(&dims)
+        }
+    }
+
     /// Convert the tensor into a scalar.
     ///
     /// # Panics
@@ -1683,6 +1898,43 @@ where
 
         Tensor::<B, D2, K>::new(K::expand(self.primitive, shape))
     }
+
+    /// Broadcast the tensor to the given explicit shape.
+    ///
+    /// Unlike [expand](Tensor::expand), every dimension of the target shape must be given, and
+    /// each dimension of the tensor must either be of size one or match the target size.
+    /// Dimensions are aligned from the right, so new leading dimensions can be added.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape to broadcast the tensor to.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension of the tensor is neither one nor equal to the target size, or if the target
+    /// shape has fewer dimensions than the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     // Create a 2D tensor with dimensions [1, 3]
+    ///     let tensor = Tensor::<B, 2>::from_data([[1., 2., 3.]], &device);
+    ///     // Broadcast the tensor to the shape [4, 3]
+    ///     let broadcasted = tensor.broadcast_to([4, 3]);
+    ///     println!("{}", broadcasted);
+    /// }
+    /// ```
+    pub fn broadcast_to<const D2: usize>(self, shape: [usize; D2]) -> Tensor<B, D2, K> {
+        let shape = Shape::from(shape);
+        check!(TensorCheck::broadcast_to::<D, D2>(&self.shape(), &shape));
+
+        Tensor::<B, D2, K>::new(K::expand(self.primitive, shape))
+    }
 }
 
 /// Iterator given by (Tensor::iter_dim).
@@ -2206,6 +2458,30 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn to_device(tensor: Self::Primitive, device: &B::Device) -> Self::Primitive;
 
+    /// Moves the tensor to the given device without blocking on the transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device on which the tensor will be moved.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the tensor on the given device.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For moving a tensor to a device, users should prefer the [Tensor::to_device_async](Tensor::to_device_async)
+    /// function, which is more high-level and designed for public use.
+    fn to_device_async(
+        tensor: Self::Primitive,
+        device: &B::Device,
+    ) -> impl Future<Output = Self::Primitive> + Send;
+
     /// Extracts the data from the tensor asynchronously.
     ///
     /// # Arguments
@@ -2527,6 +2803,17 @@ impl<B: Backend> BasicOps<B> for Float {
         }
     }
 
+    async fn to_device_async(tensor: Self::Primitive, device: &Device<B>) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                TensorPrimitive::Float(B::float_to_device_async(tensor, device).await)
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                TensorPrimitive::QFloat(B::q_to_device(tensor, device))
+            }
+        }
+    }
+
     async fn into_data_async(tensor: Self::Primitive) -> TensorData {
         match tensor {
             TensorPrimitive::Float(tensor) => B::float_into_data(tensor).await,
@@ -2674,6 +2961,10 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_to_device(tensor, device)
     }
 
+    async fn to_device_async(tensor: Self::Primitive, device: &Device<B>) -> Self::Primitive {
+        B::int_to_device_async(tensor, device).await
+    }
+
     async fn into_data_async(tensor: Self::Primitive) -> TensorData {
         B::int_into_data(tensor).await
     }
@@ -2778,6 +3069,10 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_to_device(tensor, device)
     }
 
+    async fn to_device_async(tensor: Self::Primitive, device: &Device<B>) -> Self::Primitive {
+        B::bool_to_device_async(tensor, device).await
+    }
+
     async fn into_data_async(tensor: Self::Primitive) -> TensorData {
         B::bool_into_data(tensor).await
     }
@@ -2963,15 +3258,24 @@ impl<const D2: usize> ReshapeArgs<D2> for [usize; D2] {
 }
 
 impl<const D2: usize> ReshapeArgs<D2> for [i32; D2] {
+    fn into_shape<B: Backend, const D: usize, K: BasicOps<B>>(
+        self,
+        tensor: &Tensor<B, D, K>,
+    ) -> Shape {
+        ReshapeArgs::<D2>::into_shape(self.map(|dim| dim as i64), tensor)
+    }
+}
+
+impl<const D2: usize> ReshapeArgs<D2> for [i64; D2] {
     fn into_shape<B: Backend, const D: usize, K: BasicOps<B>>(
         self,
         tensor: &Tensor<B, D, K>,
     ) -> Shape {
         // Validate the reshape arguments
-        check!(TensorCheck::reshape_args_i32(&self));
+        check!(TensorCheck::reshape_args_i64(&self));
 
         // Temporary shape
-        let mut new_shape: [i32; D2] = [1; D2];
+        let mut new_shape: [i64; D2] = [1; D2];
 
         // We need to find the index of the 0 dimension and
         // replace it with the actual dimension value.
@@ -2979,7 +3283,7 @@ impl<const D2: usize> ReshapeArgs<D2> for [i32; D2] {
             if s != 0 {
                 new_shape[i] = s;
             } else {
-                new_shape[i] = tensor.dims()[i] as i32;
+                new_shape[i] = tensor.dims()[i] as i64;
             }
         }
 
@@ -2995,18 +3299,18 @@ impl<const D2: usize> ReshapeArgs<D2> for [i32; D2] {
                     product *= s;
                 }
             }
-            let product_current = tensor.shape().num_elements() as i32;
-
-            new_shape[index] = product_current / product;
+            let product_current = tensor.shape().num_elements() as i64;
 
             // Check if the reshape is valid
-            if product_current % product != 0 {
+            if product == 0 || product_current % product != 0 {
                 panic!(
                     "Cannot reshape tensor of shape {:?} to shape {:?}",
                     tensor.shape(),
-                    new_shape
+                    self
                 );
             }
+
+            new_shape[index] = product_current / product;
         };
 
         // Convert each element to usize
@@ -1,4 +1,6 @@
-use crate::{Bool, Int, Shape, Tensor, TensorData, TensorPrimitive, backend::Backend};
+use crate::{
+    Bool, Distribution, Int, Shape, Tensor, TensorData, TensorPrimitive, backend::Backend,
+};
 use alloc::{vec, vec::Vec};
 
 use crate::try_read_sync;
@@ -24,6 +26,77 @@ where
         Self::new(B::bool_from_data(data.convert::<B::BoolElem>(), device))
     }
 
+    /// Samples a boolean tensor where each element is `true` with the probability given by the
+    /// corresponding element of `probs`, whose values must be between 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let probs = Tensor::<B, 1>::from_floats([0.0, 0.5, 1.0], &device);
+    ///     let samples = Tensor::<B, 1, Bool>::bernoulli(probs);
+    ///     println!("{samples}");
+    ///     // [false, true, true] or [false, false, true]
+    /// }
+    /// ```
+    pub fn bernoulli(probs: Tensor<B, D>) -> Self {
+        let samples = Tensor::<B, D>::random(probs.shape(), Distribution::Default, &probs.device());
+
+        samples.lower(probs)
+    }
+
+    /// Samples a boolean tensor where each element is `true` with the probability
+    /// `sigmoid(logit)`, given by the logit (log-odds) of the corresponding element of `logits`.
+    ///
+    /// This is more numerically stable than computing the probabilities first, since logistic
+    /// noise `log(u) - log(1 - u)` with `u ~ U(0, 1)` is compared to the logits directly. An
+    /// infinite logit always samples the same value.
+    ///
+    /// See [bernoulli_logits_straight_through](Tensor::bernoulli_logits_straight_through) to
+    /// differentiate through the samples.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let logits = Tensor::<B, 1>::from_floats([-100.0, 0.0, 100.0], &device);
+    ///     let samples = Tensor::<B, 1, Bool>::bernoulli_logits(logits);
+    ///     println!("{samples}");
+    ///     // [false, true, true] or [false, false, true]
+    /// }
+    /// ```
+    pub fn bernoulli_logits(logits: Tensor<B, D>) -> Self {
+        let samples =
+            Tensor::<B, D>::random(logits.shape(), Distribution::Default, &logits.device());
+        let noise = samples.clone().log() - samples.neg().log1p();
+
+        noise.lower(logits)
+    }
+
+    /// Samples a boolean tensor of the given shape where each element is `true` with the
+    /// probability `prob`.
+    ///
+    /// # Panics
+    ///
+    /// If the probability isn't between 0 and 1.
+    pub fn bernoulli_scalar<S: Into<Shape>>(prob: f64, shape: S, device: &B::Device) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&prob),
+            "Bernoulli probability should be between 0 and 1, but got {prob}"
+        );
+        let samples = Tensor::<B, D>::random(shape, Distribution::Default, device);
+
+        samples.lower_elem(prob)
+    }
+
     /// Convert the bool tensor into an int tensor.
     pub fn int(self) -> Tensor<B, D, Int> {
         Tensor::new(B::bool_into_int(self.primitive))
@@ -49,6 +122,59 @@ where
         Tensor::new(B::bool_or(self.primitive, rhs.primitive))
     }
 
+    /// Performs logical xor (`^`) on two boolean tensors
+    pub fn bool_xor(self, rhs: Tensor<B, D, Bool>) -> Tensor<B, D, Bool> {
+        Tensor::new(B::bool_xor(self.primitive, rhs.primitive))
+    }
+
+    /// Computes the element-wise logical and of two boolean tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other boolean tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor where each element is `true` if both input elements are `true`.
+    pub fn logical_and(self, other: Tensor<B, D, Bool>) -> Tensor<B, D, Bool> {
+        self.bool_and(other)
+    }
+
+    /// Computes the element-wise logical or of two boolean tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other boolean tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor where each element is `true` if at least one input element is `true`.
+    pub fn logical_or(self, other: Tensor<B, D, Bool>) -> Tensor<B, D, Bool> {
+        self.bool_or(other)
+    }
+
+    /// Computes the element-wise logical xor of two boolean tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other boolean tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor where each element is `true` if exactly one input element is `true`.
+    pub fn logical_xor(self, other: Tensor<B, D, Bool>) -> Tensor<B, D, Bool> {
+        self.bool_xor(other)
+    }
+
+    /// Computes the element-wise logical negation of the boolean tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor where each element is the negation of the input element.
+    pub fn logical_not(self) -> Tensor<B, D, Bool> {
+        self.bool_not()
+    }
+
     /// Compute the indices of the elements that are non-zero.
     ///
     /// # Returns
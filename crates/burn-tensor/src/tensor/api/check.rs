@@ -1,4 +1,4 @@
-use crate::{BasicOps, Numeric, Shape, Tensor, backend::Backend, cast::ToElement};
+use crate::{BasicOps, InterpolateSize, Numeric, Shape, Tensor, backend::Backend, cast::ToElement};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -170,7 +170,7 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn reshape_args_i32<const D: usize>(target: &[i32; D]) -> Self {
+    pub(crate) fn reshape_args_i64<const D: usize>(target: &[i64; D]) -> Self {
         let mut check = Self::Ok;
 
         if target.iter().any(|&dim| dim < -1) {
@@ -568,6 +568,71 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn pixel_shuffle(dims: &[usize], upscale_factor: usize) -> Self {
+        let check = Self::Ok;
+
+        if let Some(error) = Self::pixel_rearrange_error(dims, upscale_factor) {
+            return check.register("pixel_shuffle", error);
+        }
+
+        let channels = dims[dims.len() - 3];
+        let block_size = upscale_factor * upscale_factor;
+        if channels % block_size != 0 {
+            return check.register(
+                "pixel_shuffle",
+                TensorError::new(
+                    "The number of channels must be divisible by the square of the upscale factor.",
+                )
+                .details(format!(
+                    "The number of channels {channels} isn't divisible by {upscale_factor}^2 = {block_size}.",
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pixel_unshuffle(dims: &[usize], downscale_factor: usize) -> Self {
+        let check = Self::Ok;
+
+        if let Some(error) = Self::pixel_rearrange_error(dims, downscale_factor) {
+            return check.register("pixel_unshuffle", error);
+        }
+
+        let [height, width] = [dims[dims.len() - 2], dims[dims.len() - 1]];
+        if height % downscale_factor != 0 || width % downscale_factor != 0 {
+            return check.register(
+                "pixel_unshuffle",
+                TensorError::new(
+                    "The height and the width must be divisible by the downscale factor.",
+                )
+                .details(format!(
+                    "The spatial dimensions {height}x{width} aren't divisible by {downscale_factor}.",
+                )),
+            );
+        }
+
+        check
+    }
+
+    /// The error of the dimensions and the factor of a pixel shuffle or unshuffle, if any.
+    fn pixel_rearrange_error(dims: &[usize], factor: usize) -> Option<TensorError> {
+        if dims.len() < 3 {
+            return Some(
+                TensorError::new("The tensor must have at least 3 dimensions.").details(format!(
+                    "The tensor has {} dimensions, expected [..., channels, height, width].",
+                    dims.len()
+                )),
+            );
+        }
+
+        if factor == 0 {
+            return Some(TensorError::new("The factor must be greater than 0."));
+        }
+
+        None
+    }
+
     pub(crate) fn matmul<B: Backend, const D: usize>(
         lhs: &Tensor<B, D>,
         rhs: &Tensor<B, D>,
@@ -711,6 +776,68 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn pad_sequence<B: Backend>(tensors: &[Tensor<B, 2>]) -> Self {
+        let check = Self::Ok;
+
+        if tensors.is_empty() {
+            return check.register(
+                "Pad Sequence",
+                TensorError::new("Can't pad an empty list of sequences."),
+            );
+        }
+
+        let [_, features] = tensors[0].dims();
+
+        if tensors.iter().any(|tensor| tensor.dims()[1] != features) {
+            return check.register(
+                "Pad Sequence",
+                TensorError::new("Can't pad sequences with different numbers of features.")
+                    .details(format!(
+                        "Sequences shapes: {:?}",
+                        tensors.iter().map(Tensor::shape).collect::<Vec<_>>()
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pack_padded_sequence(
+        batch_size: usize,
+        max_length: usize,
+        lengths: &[usize],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if lengths.len() != batch_size {
+            check = check.register(
+                "Pack Padded Sequence",
+                TensorError::new("The number of lengths should be equal to the batch size.")
+                    .details(format!(
+                        "Batch size: {batch_size}, number of lengths: {}.",
+                        lengths.len()
+                    )),
+            );
+        }
+
+        if lengths
+            .iter()
+            .any(|length| *length == 0 || *length > max_length)
+        {
+            check = check.register(
+                "Pack Padded Sequence",
+                TensorError::new(
+                    "The lengths should be greater than zero and not exceed the padded length.",
+                )
+                .details(format!(
+                    "Padded length: {max_length}, lengths: {lengths:?}."
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn slice<const D1: usize, const D2: usize>(
         shape: &Shape,
         ranges: &[Range<usize>; D2],
@@ -813,12 +940,12 @@ impl TensorCheck {
                 );
             }
 
-            if range.end - range.start != d_tensor_value {
+            if range.end - range.start != d_tensor_value && d_tensor_value != 1 {
                 check = check.register(
                     "Slice Assign",
                     TensorError::new(
                         "The value tensor must match the amount of elements selected with the \
-                         ranges array",
+                         ranges array, or have a single element to be broadcast",
                     )
                     .details(format!(
                         "The range ({}..{}) doesn't match the number of elements of the value \
@@ -852,11 +979,66 @@ impl TensorCheck {
             }
         }
 
+        for i in D2..D1 {
+            let d_tensor = shape.dims[i];
+            let d_tensor_value = shape_value.dims[i];
+
+            if d_tensor_value != d_tensor && d_tensor_value != 1 {
+                check = check.register(
+                    "Slice Assign",
+                    TensorError::new(
+                        "The value tensor must match the size of the dimensions without range, or \
+                         have a single element to be broadcast",
+                    )
+                    .details(format!(
+                        "The size of the value tensor ({d_tensor_value}) doesn't match the size \
+                         of the tensor ({d_tensor}) at dimension {i}. Current tensor shape {:?}, \
+                         value tensor shape {:?}, provided ranges {:?}.",
+                        shape.dims, shape_value.dims, ranges,
+                    )),
+                );
+            }
+        }
+
         check
     }
 
     pub(crate) fn gather<const D: usize>(dim: usize, shape: &Shape, shape_indices: &Shape) -> Self {
-        Self::check_gather_scatter_indices::<D>(Self::Ok, "Gather", dim, shape, shape_indices)
+        let ops = "Gather";
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't index a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        for i in 0..D {
+            if i == dim {
+                continue;
+            }
+
+            let tensor_dim_i = shape.dims[i];
+            let indices_dim_i = shape_indices.dims[i];
+
+            if indices_dim_i > tensor_dim_i {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The index tensor shape should not be larger than the tensor shape."
+                            .to_string(),
+                    )
+                    .details(format!(
+                        "The shape is larger at dimension {i}: {indices_dim_i} > {tensor_dim_i}"
+                    )),
+                );
+            }
+        }
+
+        check
     }
 
     pub(crate) fn scatter<const D: usize>(
@@ -886,6 +1068,137 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn index_put<const D: usize>(shape_indices: &Shape, shape_values: &Shape) -> Self {
+        let ops = "IndexPut";
+        let mut check = Self::Ok;
+
+        if shape_indices.dims[1] != D {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The indices should have a coordinate for each dimension of the tensor."
+                        .to_string(),
+                )
+                .details(format!(
+                    "The indices have coordinates of size {} for a tensor with {D} dimensions.",
+                    shape_indices.dims[1]
+                )),
+            );
+        }
+
+        if shape_indices.dims[0] != shape_values.dims[0] {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The indices should have a coordinate for each value.".to_string(),
+                )
+                .details(format!(
+                    "The number of coordinates differs from the number of values: {} != {}",
+                    shape_indices.dims[0], shape_values.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pad_to_multiple<const D: usize>(dim: usize, multiple: usize) -> Self {
+        let ops = "PadToMultiple";
+        let mut check = Self::dim_ops::<D>(ops, dim);
+
+        if multiple == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The multiple should be greater than zero.".to_string()),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn interpolate<const D: usize>(dims: &[usize], size: &InterpolateSize) -> Self {
+        let ops = "Interpolate";
+        let mut check = Self::Ok;
+
+        if D != 3 && D != 4 {
+            return check.register(
+                ops,
+                TensorError::new("Only tensors with 3 or 4 dimensions can be interpolated.")
+                    .details(format!("Got a tensor of rank {D}.")),
+            );
+        }
+
+        if size.len() != D - 2 {
+            return check.register(
+                ops,
+                TensorError::new("The size should have a value for each spatial dimension.")
+                    .details(format!("Got {size:?} for {} spatial dimensions.", D - 2)),
+            );
+        }
+
+        let output_size = size.output_size(&dims[2..]);
+        if output_size.contains(&0) {
+            check = check.register(
+                ops,
+                TensorError::new("The output of the interpolation should not be empty.").details(
+                    format!(
+                        "Got the output size {output_size:?} for the input size {:?}.",
+                        &dims[2..]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn prune_by_magnitude(sparsity: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if !(0.0..=1.0).contains(&sparsity) {
+            check = check.register(
+                "PruneByMagnitude",
+                TensorError::new("The sparsity should be between 0 and 1.")
+                    .details(format!("Got the sparsity {sparsity}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn repeat_interleave<const D: usize>(
+        dim: usize,
+        dim_size: usize,
+        repeats: &[i64],
+    ) -> Self {
+        let ops = "RepeatInterleave";
+        let mut check = Self::dim_ops::<D>(ops, dim);
+
+        if repeats.len() != 1 && repeats.len() != dim_size {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The repeats should have a single value or one for each element of the dimension."
+                        .to_string(),
+                )
+                .details(format!(
+                    "Got {} repeats for a dimension of size {dim_size}.",
+                    repeats.len()
+                )),
+            );
+        }
+
+        if repeats.iter().any(|&times| times < 0) {
+            check = check.register(
+                ops,
+                TensorError::new("The repeats should not be negative.".to_string())
+                    .details(format!("Got the repeats {repeats:?}.")),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn select<const D: usize>(dim: usize) -> Self {
         Self::check_select_basic::<D>(Self::Ok, "select", dim)
     }
@@ -1002,6 +1315,64 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn aggregate_dims<const D: usize, const D2: usize>(
+        ops: &str,
+        dims: &[usize],
+        keepdim: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if dims.iter().any(|dim| *dim >= D) {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't aggregate a tensor with ({D}) dimensions on axes ({dims:?})"
+                )),
+            );
+        }
+
+        if (1..dims.len()).any(|i| dims[..i].contains(&dims[i])) {
+            check = check.register(
+                ops,
+                TensorError::new(format!("Can't aggregate on duplicated axes ({dims:?})")),
+            );
+        }
+
+        let expected = if keepdim {
+            D
+        } else {
+            D.saturating_sub(dims.len())
+        };
+        if D2 != expected {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The output rank ({D2}) should be {expected} when aggregating a tensor with \
+                     ({D}) dimensions on axes ({dims:?}) with keepdim={keepdim}"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn test_dims<const D: usize, const D2: usize>(
+        ops: &str,
+        dims: &[usize],
+        keepdim: bool,
+    ) -> Self {
+        let mut check = Self::aggregate_dims::<D, D2>(ops, dims, keepdim);
+
+        if dims.is_empty() {
+            check = check.register(
+                ops,
+                TensorError::new("Can't test a tensor along an empty list of axes"),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn sort_dim<const D: usize>(ops: &str, dim: usize) -> Self {
         let mut check = Self::Ok;
 
@@ -1195,6 +1566,46 @@ impl TensorCheck {
         check
     }
 
+    /// Checks if the tensor can be broadcasted to the given shape, with every dimension either of
+    /// size one or equal to the target size.
+    pub fn broadcast_to<const D1: usize, const D2: usize>(shape: &Shape, to: &Shape) -> Self {
+        let mut check = TensorCheck::Ok;
+
+        if D2 < D1 {
+            return check.register(
+                "Broadcast To",
+                TensorError::new(
+                    "The target shape must have at least as many dimensions as the tensor.",
+                )
+                .details(format!(
+                    "Tensor shape {:?}, Target shape {:?}.",
+                    shape.dims, to.dims,
+                )),
+            );
+        }
+
+        // Align the dimensions from the right, the leading target dimensions are new.
+        let offset = D2 - D1;
+        for (i, (&d_shape, &d_to)) in shape.dims.iter().zip(&to.dims[offset..]).enumerate() {
+            if d_shape != d_to && d_shape != 1 {
+                check = check.register(
+                    "Broadcast To",
+                    TensorError::new(
+                        "The provided tensor can't be broadcasted to the target shape.",
+                    )
+                    .details(format!(
+                        "Incompatible size at dimension '{}' => '{} != {}', only dimensions of \
+                         size 1 can be broadcasted. Tensor shape {:?}, Target shape {:?}.",
+                        i, d_shape, d_to, shape.dims, to.dims,
+                    )),
+                );
+                break;
+            }
+        }
+
+        check
+    }
+
     /// Checks if input is compatible with convolution weights.
     pub fn conv<const D1: usize, const D2: usize>(
         ops: &str,
@@ -1233,6 +1644,96 @@ impl TensorCheck {
         }
         check
     }
+
+    /// Checks if the sampling grid is compatible with the input of an N-D grid sample.
+    pub fn grid_sample_nd<const D: usize>(x: [usize; D], grid: [usize; D]) -> Self {
+        let mut check = TensorCheck::Ok;
+        if D < 3 {
+            check = check.register(
+                "grid_sample_nd",
+                TensorError::new("Input tensor must have at least one spatial dimension.")
+                    .details(format!("got rank: {D}, expected: >= 3")),
+            );
+            return check;
+        }
+        if x[0] != grid[0] {
+            check = check.register(
+                "grid_sample_nd",
+                TensorError::new("Batch size of input tensor and grid must be equal.")
+                    .details(format!("got: {}, expected: {}", grid[0], x[0])),
+            );
+        }
+        if grid[D - 1] != D - 2 {
+            check = check.register(
+                "grid_sample_nd",
+                TensorError::new(
+                    "Last dimension of grid must match the number of spatial dimensions.",
+                )
+                .details(format!("got: {}, expected: {}", grid[D - 1], D - 2)),
+            );
+        }
+        check
+    }
+
+    pub fn flash_attn_varlen(
+        q: [usize; 2],
+        k: [usize; 2],
+        v: [usize; 2],
+        cu_seqlens_q: [usize; 1],
+        cu_seqlens_k: [usize; 1],
+    ) -> Self {
+        let mut check = TensorCheck::Ok;
+        if q[1] != k[1] {
+            check = check.register(
+                "flash_attn_varlen",
+                TensorError::new("Head dimension of queries and keys must be equal.")
+                    .details(format!("got: {}, expected: {}", k[1], q[1])),
+            );
+        }
+        if k[0] != v[0] {
+            check = check.register(
+                "flash_attn_varlen",
+                TensorError::new("Number of keys and values must be equal.")
+                    .details(format!("got: {}, expected: {}", v[0], k[0])),
+            );
+        }
+        if cu_seqlens_q[0] != cu_seqlens_k[0] || cu_seqlens_q[0] < 2 {
+            check = check.register(
+                "flash_attn_varlen",
+                TensorError::new(
+                    "Cumulative sequence lengths must describe the same number of sequences.",
+                )
+                .details(format!(
+                    "got lengths: {} and {}, expected: equal and >= 2",
+                    cu_seqlens_q[0], cu_seqlens_k[0]
+                )),
+            );
+        }
+        check
+    }
+
+    pub fn cross_entropy_with_label_smoothing(
+        logits: [usize; 2],
+        targets: [usize; 1],
+        smoothing: f64,
+    ) -> Self {
+        let mut check = TensorCheck::Ok;
+        if logits[0] != targets[0] {
+            check = check.register(
+                "cross_entropy_with_label_smoothing",
+                TensorError::new("Number of logits and targets must be equal.")
+                    .details(format!("got: {}, expected: {}", targets[0], logits[0])),
+            );
+        }
+        if !(0.0..=1.0).contains(&smoothing) {
+            check = check.register(
+                "cross_entropy_with_label_smoothing",
+                TensorError::new("Label smoothing must be between 0 and 1.")
+                    .details(format!("got: {smoothing}")),
+            );
+        }
+        check
+    }
 }
 
 pub(crate) struct FailedTensorCheck {
@@ -4,8 +4,9 @@ use crate::quantization::{QTensorPrimitive, QuantScheme, QuantizationParameters}
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Distribution, TensorData};
+use crate::{Bool, ElementConversion, Int, PackedSequence, TensorPrimitive};
 use crate::{FloatDType, check};
-use crate::{Int, TensorPrimitive};
+use alloc::vec::Vec;
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -169,6 +170,8 @@ where
     /// Returns a new tensor with the same shape and device as the current tensor and the data
     /// cast to Integer.
     ///
+    /// The fractional part is truncated toward zero, so `-2.7` becomes `-2`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -251,6 +254,157 @@ where
         (var, mean)
     }
 
+    /// Computes the p-norm of the tensor over all the dimensions except `dim`.
+    ///
+    /// The result keeps the rank of the tensor, with a size of 1 for every dimension except `dim`,
+    /// so it can be broadcast against the original tensor. A `p` of [f64::INFINITY] computes the
+    /// maximum absolute value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 0.0], [4.0, 1.0]], &device);
+    ///     let norm = tensor.norm_except_dim(1, 2.0);
+    ///     println!("{norm}");
+    ///     // [[5.0, 1.0]]
+    /// }
+    /// ```
+    pub fn norm_except_dim(self, dim: usize, p: f64) -> Self {
+        check!(TensorCheck::dim_ops::<D>("norm_except_dim", dim));
+
+        let dims = (0..D).filter(|&d| d != dim);
+        let tensor = self.abs();
+
+        if p == f64::INFINITY {
+            dims.fold(tensor, |tensor, d| tensor.max_dim(d))
+        } else if p == 2.0 {
+            dims.fold(tensor.powi_scalar(2), |tensor, d| tensor.sum_dim(d))
+                .sqrt()
+        } else {
+            dims.fold(tensor.powf_scalar(p), |tensor, d| tensor.sum_dim(d))
+                .powf_scalar(1.0 / p)
+        }
+    }
+
+    /// Computes the Frobenius norm of the tensor, the L2 norm of all its elements.
+    ///
+    /// Backends may compute it in a single pass, without the intermediate tensors of
+    /// `tensor.powi_scalar(2).sum().sqrt()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 0.0], [4.0, 12.0]], &device);
+    ///     let norm = tensor.norm_frob();
+    ///     println!("{norm}");
+    ///     // [13.0]
+    /// }
+    /// ```
+    pub fn norm_frob(self) -> Tensor<B, 1> {
+        Tensor::new(TensorPrimitive::Float(B::float_frobenius_norm(
+            self.primitive.tensor(),
+        )))
+    }
+
+    /// Normalizes the tensor to a unit L2 norm over all the dimensions except `dim`.
+    ///
+    /// Each slice along `dim` is divided by its [norm](Tensor::norm_except_dim), which is the
+    /// direction of the weight normalization reparameterization `w = g * v / ||v||`.
+    pub fn unit_normalize(self, dim: usize) -> Self {
+        let norm = self.clone().norm_except_dim(dim, 2.0);
+
+        self / norm
+    }
+
+    /// Zeroes the `sparsity` fraction of the elements with the smallest magnitude, as the
+    /// unstructured magnitude pruning of a weight.
+    ///
+    /// Like PyTorch, the number of zeroed elements is the fraction of the number of elements
+    /// rounded to the nearest integer.
+    ///
+    /// # Panics
+    ///
+    /// If the sparsity isn't between 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[0.5, -4.0], [-1.0, 2.0]], &device);
+    ///     let pruned = tensor.prune_by_magnitude(0.5);
+    ///     println!("{pruned}");
+    ///     // [[0.0, -4.0], [0.0, 2.0]]
+    /// }
+    /// ```
+    pub fn prune_by_magnitude(self, sparsity: f64) -> Self {
+        check!(TensorCheck::prune_by_magnitude(sparsity));
+
+        let shape = self.shape();
+        let num_elements = shape.num_elements();
+        let num_kept = num_elements - (sparsity * num_elements as f64).round() as usize;
+
+        if num_kept == num_elements {
+            return self;
+        }
+        if num_kept == 0 {
+            return self.zeros_like();
+        }
+
+        // The indices of the largest magnitudes, which are the elements kept
+        let device = self.device();
+        let (_, indices) = self
+            .clone()
+            .abs()
+            .reshape([num_elements])
+            .topk_with_indices(num_kept, 0);
+        let keep = Tensor::<B, 1>::zeros([num_elements], &device)
+            .scatter(0, indices, Tensor::ones([num_kept], &device))
+            .greater_elem(0.0)
+            .reshape(shape);
+
+        self.mask_fill(keep.bool_not(), 0.0)
+    }
+
+    /// Clamp element wise between the given min and max values, replacing the NaN elements with
+    /// the given value.
+    ///
+    /// Unlike [clamp](Tensor::clamp), which propagates the NaN elements, the output contains no
+    /// NaN as long as `nan_value` isn't NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([-2.0, f32::NAN, 0.5, 3.0], &device);
+    ///     let tensor = tensor.clamp_nan_to(-1.0, 1.0, 0.0);
+    ///     println!("{tensor}");
+    ///     // [-1.0, 0.0, 0.5, 1.0]
+    /// }
+    /// ```
+    pub fn clamp_nan_to<E: ElementConversion>(self, min: E, max: E, nan_value: E) -> Self {
+        let nan_mask = self.is_nan();
+
+        self.clamp(min, max).mask_fill(nan_mask, nan_value)
+    }
+
     /// Converts a tensor to the specified floating point data type.
     ///
     /// # Warning
@@ -263,6 +417,22 @@ where
         )))
     }
 
+    /// Samples `1.0` with the probability `sigmoid(logit)` and `0.0` otherwise for each logit
+    /// (log-odds) of the tensor, as [bernoulli_logits](Tensor::bernoulli_logits) does.
+    ///
+    /// The samples are differentiable with the straight-through estimator: the backward pass
+    /// treats the sampling as the identity, so the gradient of the samples with respect to the
+    /// logits is one. Infinite logits, whose samples are certain, get no gradient.
+    pub fn bernoulli_logits_straight_through(self) -> Self {
+        let logits = self.clone().detach();
+        let samples = Tensor::<B, D, Bool>::bernoulli_logits(logits.clone()).float();
+
+        // Zero in the forward pass, but carries the gradient of the logits. The infinite logits
+        // are masked out, since subtracting them from themselves gives NaN.
+        let infinite = logits.clone().abs().equal_elem(f32::INFINITY);
+        samples + (self - logits).mask_fill(infinite, 0.0)
+    }
+
     /// Detach the current tensor from the autodiff graph.
     ///
     /// This function does nothing when autodiff is not enabled.
@@ -307,7 +477,10 @@ where
 
     /// Applies the relu function to the tensor.
     pub(crate) fn relu(self) -> Self {
-        Self::new(TensorPrimitive::Float(B::relu(self.primitive.tensor())))
+        match self.primitive {
+            TensorPrimitive::Float(tensor) => Self::new(TensorPrimitive::Float(B::relu(tensor))),
+            TensorPrimitive::QFloat(tensor) => Self::new(B::q_relu(tensor)),
+        }
     }
 
     /// Calculate covaraince matrix between different entries alongside a given dimension.
@@ -348,6 +521,78 @@ where
         )))
     }
 
+    /// Adds two tensors together and quantizes the result with the given quantization parameters.
+    ///
+    /// The operands may be quantized with different quantization parameters, or not quantized at
+    /// all. The sum is computed in the floating point precision before being quantized, which lets
+    /// quantized residual connections choose the scale of their output.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to add.
+    /// * `scheme` - The quantization scheme of the output.
+    /// * `qparams` - The pre-computed quantization parameters of the output.
+    ///
+    /// # Returns
+    ///
+    /// The quantized sum of the two tensors.
+    pub fn add_requantize(
+        self,
+        other: Self,
+        scheme: &QuantScheme,
+        qparams: QuantizationParameters<B>,
+    ) -> Tensor<B, D> {
+        check!(TensorCheck::binary_ops_ew("Add", &self, &other));
+        let qtensor = match (self.primitive, other.primitive) {
+            (TensorPrimitive::QFloat(lhs), TensorPrimitive::QFloat(rhs)) => {
+                B::q_add_requantize(lhs, rhs, scheme, qparams.into())
+            }
+            (lhs, rhs) => B::quantize(
+                B::float_add(lhs.tensor(), rhs.tensor()),
+                scheme,
+                qparams.into(),
+            ),
+        };
+
+        Tensor::new(TensorPrimitive::QFloat(qtensor))
+    }
+
+    /// Multiplies two tensors together element-wise and quantizes the result with the given
+    /// quantization parameters.
+    ///
+    /// The operands may be quantized with different quantization parameters, or not quantized at
+    /// all. The product is computed in the floating point precision before being quantized.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to multiply.
+    /// * `scheme` - The quantization scheme of the output.
+    /// * `qparams` - The pre-computed quantization parameters of the output.
+    ///
+    /// # Returns
+    ///
+    /// The quantized element-wise product of the two tensors.
+    pub fn mul_requantize(
+        self,
+        other: Self,
+        scheme: &QuantScheme,
+        qparams: QuantizationParameters<B>,
+    ) -> Tensor<B, D> {
+        check!(TensorCheck::binary_ops_ew("Mul", &self, &other));
+        let qtensor = match (self.primitive, other.primitive) {
+            (TensorPrimitive::QFloat(lhs), TensorPrimitive::QFloat(rhs)) => {
+                B::q_mul_requantize(lhs, rhs, scheme, qparams.into())
+            }
+            (lhs, rhs) => B::quantize(
+                B::float_mul(lhs.tensor(), rhs.tensor()),
+                scheme,
+                qparams.into(),
+            ),
+        };
+
+        Tensor::new(TensorPrimitive::QFloat(qtensor))
+    }
+
     /// Dynamically convert the tensor to a lower precision data type based on the quantization scheme.
     ///
     /// # Arguments
@@ -378,3 +623,31 @@ where
         Tensor::new(TensorPrimitive::Float(self.primitive.tensor()))
     }
 }
+
+impl<B> Tensor<B, 3>
+where
+    B: Backend,
+{
+    /// Pads a list of variable-length sequences of shape `[seq_length, features]` to the length
+    /// of the longest one and stacks them.
+    ///
+    /// See [pad_sequence](crate::pad_sequence) for more details.
+    pub fn pad_sequence(
+        tensors: Vec<Tensor<B, 2>>,
+        batch_first: bool,
+        padding_value: f64,
+    ) -> (Self, Tensor<B, 1, Int>) {
+        crate::pad_sequence(tensors, batch_first, padding_value)
+    }
+
+    /// Packs the padded batch of variable-length sequences, removing the padded elements.
+    ///
+    /// See [pack_padded_sequence](crate::pack_padded_sequence) for more details.
+    pub fn pack_padded_sequence(
+        self,
+        lengths: Tensor<B, 1, Int>,
+        batch_first: bool,
+    ) -> PackedSequence<B> {
+        crate::pack_padded_sequence(self, lengths, batch_first)
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    Element, TensorData,
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+};
+use alloc::vec::Vec;
+use burn_common::reader::try_read_sync;
+use core::ops::{Add, Mul};
+
+/// The reduction applied by [index_reduce](crate::Tensor::index_reduce) when multiple values are
+/// assigned to the same position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexReduceMode {
+    /// Sum of the values, equivalent to [scatter](crate::Tensor::scatter).
+    Sum,
+    /// Product of the values.
+    Prod,
+    /// Maximum of the values.
+    AMax,
+    /// Minimum of the values.
+    AMin,
+}
+
+impl IndexReduceMode {
+    /// Reduce two values.
+    pub fn reduce<E>(&self, lhs: E, rhs: E) -> E
+    where
+        E: Add<Output = E> + Mul<Output = E> + PartialOrd,
+    {
+        match self {
+            IndexReduceMode::Sum => lhs + rhs,
+            IndexReduceMode::Prod => lhs * rhs,
+            IndexReduceMode::AMax if rhs > lhs => rhs,
+            IndexReduceMode::AMin if rhs < lhs => rhs,
+            IndexReduceMode::AMax | IndexReduceMode::AMin => lhs,
+        }
+    }
+}
+
+/// Reduce the `value` elements into the input `tensor` at the positions given by `indices`
+/// along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to reduce.
+/// * `indices` - The indices of the positions to reduce into, with the same shape as `value`.
+///   Negative indices count from the end of the dimension.
+/// * `value` - The values to reduce.
+/// * `mode` - The reduction to apply.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each position is the reduction of its
+/// original value with all the values assigned to it.
+///
+/// # Remarks
+///
+/// The reduction is computed on the host in `f64`, after synchronously reading the tensors. This
+/// is the last resort of [float_index_reduce](crate::ops::FloatTensorOps::float_index_reduce),
+/// the ndarray and cubecl backends reduce on the device instead.
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// # Panics
+///
+/// If an index is out of the bounds of the dimension.
+pub fn index_reduce<B: Backend>(
+    tensor: FloatTensor<B>,
+    dim: usize,
+    indices: IntTensor<B>,
+    value: FloatTensor<B>,
+    mode: IndexReduceMode,
+) -> FloatTensor<B> {
+    let device = B::float_device(&tensor);
+    let read = "Failed to synchronously read tensor data. This operation is not supported until this backend has an index reduce implementation.";
+    let data = try_read_sync(B::float_into_data(tensor)).expect(read);
+    let indices = try_read_sync(B::int_into_data(indices)).expect(read);
+    let value = try_read_sync(B::float_into_data(value)).expect(read);
+
+    B::float_from_data(
+        index_reduce_data::<f64>(data, dim, indices, value, mode),
+        &device,
+    )
+}
+
+/// Reduce the `value` elements into the input int `tensor` at the positions given by `indices`
+/// along the given dimension.
+///
+/// See [index_reduce] for the arguments, the reduction is computed on the host in `i64`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// # Panics
+///
+/// If an index is out of the bounds of the dimension.
+pub fn int_index_reduce<B: Backend>(
+    tensor: IntTensor<B>,
+    dim: usize,
+    indices: IntTensor<B>,
+    value: IntTensor<B>,
+    mode: IndexReduceMode,
+) -> IntTensor<B> {
+    let device = B::int_device(&tensor);
+    let read = "Failed to synchronously read tensor data. This operation is not supported until this backend has an index reduce implementation.";
+    let data = try_read_sync(B::int_into_data(tensor)).expect(read);
+    let indices = try_read_sync(B::int_into_data(indices)).expect(read);
+    let value = try_read_sync(B::int_into_data(value)).expect(read);
+
+    B::int_from_data(
+        index_reduce_data::<i64>(data, dim, indices, value, mode),
+        &device,
+    )
+}
+
+fn index_reduce_data<E>(
+    data: TensorData,
+    dim: usize,
+    indices: TensorData,
+    value: TensorData,
+    mode: IndexReduceMode,
+) -> TensorData
+where
+    E: Element + Add<Output = E> + Mul<Output = E> + PartialOrd,
+{
+    let dtype = data.dtype;
+    let shape = data.shape.clone();
+    let value_shape = value.shape.clone();
+    let dim_size = shape[dim] as i64;
+    let mut output = data.iter::<E>().collect::<Vec<_>>();
+
+    // Row-major strides of the output tensor
+    let mut strides = alloc::vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+
+    for (position, (index, value)) in indices.iter::<i64>().zip(value.iter::<E>()).enumerate() {
+        let normalized = if index < 0 { index + dim_size } else { index };
+        assert!(
+            (0..dim_size).contains(&normalized),
+            "Index {index} is out of bounds for dimension {dim} of size {dim_size} in index_reduce"
+        );
+        let index = normalized as usize;
+
+        // Unravel the position in the value tensor, replacing the coordinate along `dim`
+        let mut remainder = position;
+        let mut offset = 0;
+        for i in (0..value_shape.len()).rev() {
+            let coordinate = remainder % value_shape[i];
+            remainder /= value_shape[i];
+
+            let coordinate = if i == dim { index } else { coordinate };
+            offset += coordinate * strides[i];
+        }
+
+        output[offset] = mode.reduce(output[offset], value);
+    }
+
+    TensorData::new(output, shape).convert_dtype(dtype)
+}
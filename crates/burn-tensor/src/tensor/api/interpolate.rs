@@ -0,0 +1,217 @@
+use crate::{
+    Int, Tensor, TensorData,
+    backend::Backend,
+    check,
+    check::TensorCheck,
+    module,
+    ops::{InterpolateMode, InterpolateOptions},
+};
+use alloc::{vec, vec::Vec};
+
+/// The output size of an [interpolation](Tensor::interpolate), with a value for each spatial
+/// dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterpolateSize {
+    /// The size of each spatial dimension of the output.
+    Size(Vec<usize>),
+    /// The factor multiplying the size of each spatial dimension of the input, rounded down.
+    Scale(Vec<f64>),
+}
+
+impl InterpolateSize {
+    /// The number of spatial dimensions.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            InterpolateSize::Size(size) => size.len(),
+            InterpolateSize::Scale(scale) => scale.len(),
+        }
+    }
+
+    /// The output size of the given spatial dimensions of the input.
+    pub(crate) fn output_size(&self, input_size: &[usize]) -> Vec<usize> {
+        match self {
+            InterpolateSize::Size(size) => size.clone(),
+            InterpolateSize::Scale(scale) => input_size
+                .iter()
+                .zip(scale)
+                .map(|(&size, &scale)| (size as f64 * scale).floor() as usize)
+                .collect(),
+        }
+    }
+}
+
+impl<const N: usize> From<[usize; N]> for InterpolateSize {
+    fn from(size: [usize; N]) -> Self {
+        InterpolateSize::Size(size.to_vec())
+    }
+}
+
+impl<const N: usize> From<[f64; N]> for InterpolateSize {
+    fn from(scale: [f64; N]) -> Self {
+        InterpolateSize::Scale(scale.to_vec())
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Resizes the spatial dimensions of a `[batch_size, channels, width]` or
+    /// `[batch_size, channels, height, width]` tensor to the given size, or by the given scale
+    /// factors.
+    ///
+    /// The [bilinear](InterpolateMode::Bilinear) mode is a linear interpolation for the tensors
+    /// with a single spatial dimension.
+    ///
+    /// Like PyTorch, when `align_corners` is true the centers of the corner elements of the input
+    /// and the output are aligned, and otherwise the outer edges of the corner elements are
+    /// aligned (half-pixel centers). The
+    /// [nearest](InterpolateMode::Nearest) mode ignores `align_corners` and uses the element at
+    /// `floor(output_index * input_size / output_size)`.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have 3 or 4 dimensions, if the size doesn't have a value for each
+    /// spatial dimension, or if the output would be empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    /// use burn_tensor::ops::InterpolateMode;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+    ///     let upsampled = tensor.clone().interpolate([4, 4], InterpolateMode::Bilinear, false);
+    ///     let downsampled = tensor.interpolate([0.5, 0.5], InterpolateMode::Nearest, false);
+    ///     println!("{upsampled}");
+    ///     // [[[[1.0, 1.25, 1.75, 2.0],
+    ///     //    [1.5, 1.75, 2.25, 2.5],
+    ///     //    [2.5, 2.75, 3.25, 3.5],
+    ///     //    [3.0, 3.25, 3.75, 4.0]]]]
+    ///     println!("{downsampled}");
+    ///     // [[[[1.0]]]]
+    /// }
+    /// ```
+    pub fn interpolate<S: Into<InterpolateSize>>(
+        self,
+        size: S,
+        mode: InterpolateMode,
+        align_corners: bool,
+    ) -> Self {
+        let size = size.into();
+        let dims = self.dims();
+        check!(TensorCheck::interpolate::<D>(&dims, &size));
+
+        let output_size = size.output_size(&dims[2..]);
+        let [batch_size, channels] = [dims[0], dims[1]];
+
+        // The tensors with a single spatial dimension are interpolated with a height of one
+        let ([in_height, in_width], [out_height, out_width]) = match D {
+            3 => ([1, dims[2]], [1, output_size[0]]),
+            _ => ([dims[2], dims[3]], [output_size[0], output_size[1]]),
+        };
+        let x = self.reshape([batch_size, channels, in_height, in_width]);
+
+        // The interpolation of the backends aligns the corners
+        let output = if align_corners || matches!(mode, InterpolateMode::Nearest) {
+            module::interpolate(x, [out_height, out_width], InterpolateOptions::new(mode))
+        } else {
+            // Interpolating each dimension separately is equivalent for the linear and cubic modes
+            let x = interpolate_half_pixel(x, 2, out_height, &mode);
+            interpolate_half_pixel(x, 3, out_width, &mode)
+        };
+
+        let mut output_dims = dims;
+        output_dims[2..].copy_from_slice(&output_size);
+
+        output.reshape(output_dims)
+    }
+}
+
+/// Interpolates a dimension with half-pixel centers, as the weighted sum of the input elements
+/// selected by each tap.
+fn interpolate_half_pixel<B: Backend>(
+    x: Tensor<B, 4>,
+    dim: usize,
+    output_size: usize,
+    mode: &InterpolateMode,
+) -> Tensor<B, 4> {
+    let input_size = x.dims()[dim];
+    if input_size == output_size {
+        return x;
+    }
+
+    let device = x.device();
+    let mut weights_shape = [1; 4];
+    weights_shape[dim] = output_size;
+
+    half_pixel_taps(input_size, output_size, mode)
+        .into_iter()
+        .map(|(indices, weights)| {
+            let indices =
+                Tensor::<B, 1, Int>::from_data(TensorData::new(indices, [output_size]), &device);
+            let weights =
+                Tensor::<B, 1>::from_data(TensorData::new(weights, [output_size]), &device)
+                    .reshape(weights_shape);
+
+            x.clone().select(dim, indices) * weights
+        })
+        .reduce(|output, tap| output + tap)
+        .unwrap()
+}
+
+/// The input index and the weight of each output element for each tap of the interpolation,
+/// following PyTorch.
+fn half_pixel_taps(
+    input_size: usize,
+    output_size: usize,
+    mode: &InterpolateMode,
+) -> Vec<(Vec<i64>, Vec<f32>)> {
+    let num_taps = match mode {
+        InterpolateMode::Bicubic => 4,
+        _ => 2,
+    };
+    let mut taps = vec![
+        (
+            Vec::with_capacity(output_size),
+            Vec::with_capacity(output_size)
+        );
+        num_taps
+    ];
+    let ratio = input_size as f64 / output_size as f64;
+    let last = input_size as i64 - 1;
+
+    for index in 0..output_size {
+        let source = (index as f64 + 0.5) * ratio - 0.5;
+
+        let (first, weights) = match mode {
+            InterpolateMode::Bicubic => {
+                let first = source.floor();
+                (first as i64 - 1, cubic_weights(source - first).to_vec())
+            }
+            _ => {
+                let source = source.max(0.0);
+                let first = source.floor();
+                let t = source - first;
+                (first as i64, vec![1.0 - t, t])
+            }
+        };
+
+        for (tap, weight) in weights.into_iter().enumerate() {
+            taps[tap].0.push((first + tap as i64).clamp(0, last));
+            taps[tap].1.push(weight as f32);
+        }
+    }
+
+    taps
+}
+
+/// The weights of the four taps of the cubic convolution for the fractional position `t`, with
+/// `A = -0.75` like PyTorch.
+fn cubic_weights(t: f64) -> [f64; 4] {
+    const A: f64 = -0.75;
+    let near = |x: f64| ((A + 2.0) * x - (A + 3.0)) * x * x + 1.0;
+    let far = |x: f64| ((A * x - 5.0 * A) * x + 8.0 * A) * x - 4.0 * A;
+
+    [far(t + 1.0), near(t), near(1.0 - t), far(2.0 - t)]
+}
@@ -6,9 +6,12 @@ mod base;
 mod bool;
 mod cartesian_grid;
 mod float;
+mod index_reduce;
 mod int;
+mod interpolate;
 mod kind;
 mod numeric;
+mod sequence;
 mod slice;
 mod sort;
 mod transaction;
@@ -17,8 +20,11 @@ pub use argwhere::argwhere_data;
 pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
+pub use index_reduce::{IndexReduceMode, index_reduce, int_index_reduce};
+pub use interpolate::InterpolateSize;
 pub use kind::*;
 pub use numeric::*;
+pub use sequence::{PackedSequence, pack_padded_sequence, pad_packed_sequence, pad_sequence};
 pub use slice::*;
 pub use sort::{argsort, sort, sort_with_indices};
 pub use transaction::*;
@@ -1,11 +1,11 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use crate::{alloc::borrow::ToOwned, cast::ToElement};
 
 use crate::TensorPrimitive;
 use crate::{
-    BasicOps, Bool, Distribution, Element, ElementConversion, Float, Int, Shape, Tensor,
-    TensorKind,
+    BasicOps, Bool, Distribution, Element, ElementConversion, Float, IndexReduceMode, Int, Shape,
+    Tensor, TensorKind,
     backend::Backend,
     check,
     check::TensorCheck,
@@ -599,6 +599,97 @@ where
         Self::new(K::prod_dim(self.primitive, dim))
     }
 
+    /// Aggregate all elements along the given dimensions at once with the product operation.
+    ///
+    /// The product over an empty dimension is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - The dimensions to reduce.
+    /// * `keepdim` - If the reduced dimensions are kept with a size of 1, otherwise they are
+    ///   removed and `D2` must be `D - dims.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///   let tensor: Tensor<B, 1> = tensor.prod_dims(&[1], false);
+    ///   println!("{tensor}");
+    ///   // [-6.0, 270.0]
+    /// }
+    /// ```
+    pub fn prod_dims<const D2: usize>(self, dims: &[usize], keepdim: bool) -> Tensor<B, D2, K> {
+        check!(TensorCheck::aggregate_dims::<D, D2>("Prod", dims, keepdim));
+
+        let mut shape = self.shape();
+        let tensor = if dims.iter().any(|dim| shape.dims[*dim] == 0) {
+            dims.iter().for_each(|dim| shape.dims[*dim] = 1);
+            Self::ones(shape, &self.device())
+        } else {
+            dims.iter().fold(self, |tensor, dim| tensor.prod_dim(*dim))
+        };
+
+        if keepdim {
+            Tensor::new(tensor.primitive)
+        } else {
+            let dims: Vec<isize> = dims.iter().map(|dim| *dim as isize).collect();
+            tensor.squeeze_dims(&dims)
+        }
+    }
+
+    /// Counts the nonzero elements of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, 0.0, 3.0], [0.0, 0.0, 6.0]], &device);
+    ///    let tensor = tensor.count_nonzero();
+    ///    println!("{tensor}");
+    ///    // [3]
+    /// }
+    /// ```
+    pub fn count_nonzero(self) -> Tensor<B, 1, Int> {
+        self.not_equal_elem(0).int().sum()
+    }
+
+    /// Counts the nonzero elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to count the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, 0.0, 3.0], [0.0, 0.0, 6.0]], &device);
+    ///    let counts = tensor.clone().count_nonzero_dim(0);
+    ///    println!("{counts}");
+    ///    // [[1, 0, 2]]
+    ///    let counts = tensor.count_nonzero_dim(1);
+    ///    println!("{counts}");
+    ///    // [[2], [1]]
+    /// }
+    /// ```
+    pub fn count_nonzero_dim(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::aggregate_dim::<D>("CountNonzero", dim));
+        self.not_equal_elem(0).int().sum_dim(dim)
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Arguments
@@ -908,22 +999,39 @@ where
     /// `output[i, j, k] = input[i, indices[i, j, k], k]; // dim = 1`
     /// `output[i, j, k] = input[i, j, indices[i, j, k]]; // dim = 2`
     ///
+    /// Unlike [select](Tensor::select), each element of the output picks its own index along the
+    /// dimension, as done by `torch.gather` and the `GatherElements` ONNX operator.
+    ///
     /// # Notes
     ///
-    /// The index tensor should have the same shape as the original tensor except for the dim
-    /// specified.
+    /// The index tensor should have the same rank as the original tensor, and its size can't be
+    /// larger than the size of the original tensor for the dimensions other than the dim
+    /// specified. The output has the shape of the index tensor.
     ///
     /// # Warning
     /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
     /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
     pub fn gather(self, dim: usize, indices: Tensor<B, D, Int>) -> Self {
-        check!(TensorCheck::gather::<D>(
-            dim,
-            &self.shape(),
-            &indices.shape()
-        ));
+        let shape = self.shape();
+        let shape_indices = indices.shape();
+        check!(TensorCheck::gather::<D>(dim, &shape, &shape_indices));
+
+        // Only the part of the tensor covered by the indices is gathered from
+        let is_covered = (0..D).all(|i| i == dim || shape_indices.dims[i] == shape.dims[i]);
+        let tensor = if is_covered {
+            self
+        } else {
+            let ranges: [_; D] = core::array::from_fn(|i| {
+                if i == dim {
+                    0..shape.dims[i]
+                } else {
+                    0..shape_indices.dims[i]
+                }
+            });
+            self.slice(ranges)
+        };
 
-        Self::new(K::gather(dim, self.primitive, indices.primitive))
+        Self::new(K::gather(dim, tensor.primitive, indices.primitive))
     }
 
     /// Assign the gathered elements corresponding to the given indices along the specified dimension
@@ -961,6 +1069,52 @@ where
         ))
     }
 
+    /// Reduce the source elements into the tensor at the positions given by the index tensor along
+    /// the specified dimension, generalizing [scatter](Tensor::scatter) to other reductions.
+    ///
+    /// The original values of the tensor are included in the reduction. Example using a 3D tensor:
+    ///
+    /// `input[index[i, j, k], j, k] = reduce(input[index[i, j, k], j, k], source[i, j, k]); // dim = 0`
+    /// `input[i, index[i, j, k], k] = reduce(input[i, index[i, j, k], k], source[i, j, k]); // dim = 1`
+    /// `input[i, j, index[i, j, k]] = reduce(input[i, j, index[i, j, k]], source[i, j, k]); // dim = 2`
+    ///
+    /// # Notes
+    ///
+    /// The index tensor should have the same shape as the original tensor except for the specified
+    /// dimension. The source and index tensors should have the same shape. Negative indices count
+    /// from the end of the dimension, like in ONNX `ScatterElements`.
+    ///
+    /// # Warning
+    /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
+    /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
+    pub fn index_reduce(
+        self,
+        dim: usize,
+        index: Tensor<B, D, Int>,
+        source: Self,
+        reduce: IndexReduceMode,
+    ) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &index.shape(),
+            &source.shape()
+        ));
+
+        let size = self.dims()[dim] as i64;
+        let index = index
+            .clone()
+            .mask_where(index.clone().lower_elem(0), index.add_scalar(size));
+
+        Self::new(K::index_reduce(
+            dim,
+            self.primitive,
+            index.primitive,
+            source.primitive,
+            reduce,
+        ))
+    }
+
     /// Select the tensor elements along the given dimension corresponding to the given indices.
     ///
     /// Example using a 3D tensor:
@@ -1021,6 +1175,172 @@ where
         ))
     }
 
+    /// Write the values to the tensor at the given coordinates, or add them to the tensor when
+    /// accumulating.
+    ///
+    /// Each row of the indices is the coordinate of an element of the tensor:
+    ///
+    /// `input[indices[i, 0], ..., indices[i, D - 1]] = values[i]; // accumulate = false`
+    /// `input[indices[i, 0], ..., indices[i, D - 1]] += values[i]; // accumulate = true`
+    ///
+    /// When a coordinate is repeated, the values are summed when accumulating, otherwise the last
+    /// value is written.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The coordinates of shape `[num_indices, D]`.
+    /// * `values` - The values of shape `[num_indices]`.
+    /// * `accumulate` - If the values should be added to the tensor instead of replacing its
+    ///   elements.
+    ///
+    /// # Warning
+    /// Not all backends have runtime bound checks for the indices, so make sure the they are valid.
+    /// Otherwise, out of bounds indices could lead to unexpected results instead of panicking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Int};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::zeros([2, 3], &device);
+    ///   let indices = Tensor::<B, 2, Int>::from_data([[0, 1], [1, 2], [0, 1]], &device);
+    ///   let values = Tensor::<B, 1>::from_data([1.0, 2.0, 3.0], &device);
+    ///   let tensor = tensor.index_put(indices, values, true);
+    ///   println!("{tensor}");
+    ///   // [[0.0, 4.0, 0.0], [0.0, 0.0, 2.0]]
+    /// }
+    /// ```
+    pub fn index_put(
+        self,
+        indices: Tensor<B, 2, Int>,
+        values: Tensor<B, 1, K>,
+        accumulate: bool,
+    ) -> Self {
+        check!(TensorCheck::index_put::<D>(
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        let shape = self.shape();
+        let device = self.device();
+        let [num_indices, _] = indices.dims();
+
+        // The coordinates are converted to indices of the flattened tensor with row-major strides
+        let mut strides = [1i64; D];
+        for i in (0..D.saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * shape.dims[i + 1] as i64;
+        }
+        let strides = Tensor::<B, 1, Int>::from_data(strides, &device);
+        let indices = indices.mul(strides.unsqueeze()).sum_dim(1).squeeze::<1>(1);
+        let tensor = self.reshape([shape.num_elements()]);
+
+        let tensor = if accumulate {
+            tensor.scatter(0, indices, values)
+        } else {
+            // Only the last value of a repeated coordinate is written, which is the one with the
+            // largest position id reduced into its element
+            let positions = Tensor::<B, 1, Int>::arange(1..num_indices as i64 + 1, &device);
+            let last_positions = Tensor::<B, 1, Int>::zeros([shape.num_elements()], &device)
+                .index_reduce(0, indices.clone(), positions.clone(), IndexReduceMode::AMax);
+            let overwritten = last_positions
+                .clone()
+                .gather(0, indices.clone())
+                .not_equal(positions);
+            let values = values.mask_fill(overwritten, 0);
+
+            let written = last_positions.greater_elem(0);
+            tensor.mask_fill(written, 0).scatter(0, indices, values)
+        };
+
+        tensor.reshape(shape)
+    }
+
+    /// Repeat each element of the tensor along the given dimension `repeats` times.
+    ///
+    /// Unlike [repeat_dim](Tensor::repeat_dim), which repeats the whole dimension, the repeated
+    /// elements are kept next to each other:
+    ///
+    /// `output[i, j] = input[i / repeats, j]; // dim = 0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///   let tensor = tensor.repeat_interleave_scalar(2, 1);
+    ///   println!("{tensor}");
+    ///   // [[1.0, 1.0, 2.0, 2.0], [3.0, 3.0, 4.0, 4.0]]
+    /// }
+    /// ```
+    pub fn repeat_interleave_scalar(self, repeats: usize, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("RepeatInterleave", dim));
+
+        Self::new(K::repeat_interleave(self.primitive, dim, repeats))
+    }
+
+    /// Repeat each element of the tensor along the given dimension the number of times given by
+    /// the corresponding value of `repeats`.
+    ///
+    /// The repeats should have one value for each element of the dimension, or a single value
+    /// used for all of them like [repeat_interleave_scalar](Tensor::repeat_interleave_scalar).
+    ///
+    /// # Note
+    ///
+    /// The repeats are read from the device to compute the size of the output, which blocks
+    /// until they are available.
+    ///
+    /// Only a single repeat value uses the backend repeat interleave operation. Otherwise, the
+    /// output positions depend on the repeats, so the elements are gathered with
+    /// [select](Tensor::select).
+    ///
+    /// # Panics
+    ///
+    /// If the number of repeats doesn't match the size of the dimension or a repeat is negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 1>::from_data([1.0, 2.0, 3.0], &device);
+    ///   let repeats = Tensor::<B, 1, Int>::from_data([2, 0, 1], &device);
+    ///   let tensor = tensor.repeat_interleave(repeats, 0);
+    ///   println!("{tensor}");
+    ///   // [1.0, 1.0, 3.0]
+    /// }
+    /// ```
+    pub fn repeat_interleave(self, repeats: Tensor<B, 1, Int>, dim: usize) -> Self {
+        let repeats = repeats.into_data().iter::<i64>().collect::<Vec<_>>();
+        check!(TensorCheck::repeat_interleave::<D>(
+            dim,
+            self.dims().get(dim).copied().unwrap_or_default(),
+            &repeats
+        ));
+
+        if let [repeats] = repeats.as_slice() {
+            return self.repeat_interleave_scalar(*repeats as usize, dim);
+        }
+
+        let indices = repeats
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &times)| core::iter::repeat_n(index as i64, times as usize))
+            .collect::<Vec<_>>();
+        let indices = Tensor::<B, 1, Int>::from_data(indices.as_slice(), &self.device());
+
+        self.select(dim, indices)
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -1114,6 +1434,41 @@ where
         (tensor, index)
     }
 
+    /// Find the maximum value along all the given dimensions at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - The dimensions to reduce.
+    /// * `keepdim` - If the reduced dimensions are kept with a size of 1, otherwise they are
+    ///   removed and `D2` must be `D - dims.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///   let tensor: Tensor<B, 1> = tensor.amax(&[0], false);
+    ///   println!("{tensor}");
+    ///   // [5.0, 9.0, 6.0]
+    /// }
+    /// ```
+    pub fn amax<const D2: usize>(self, dims: &[usize], keepdim: bool) -> Tensor<B, D2, K> {
+        check!(TensorCheck::aggregate_dims::<D, D2>("Amax", dims, keepdim));
+
+        let tensor = dims.iter().fold(self, |tensor, dim| tensor.max_dim(*dim));
+
+        if keepdim {
+            Tensor::new(tensor.primitive)
+        } else {
+            let dims: Vec<isize> = dims.iter().map(|dim| *dim as isize).collect();
+            tensor.squeeze_dims(&dims)
+        }
+    }
+
     /// Finds the maximum pair wise values with another tensor.
     ///
     /// # Arguments
@@ -1279,6 +1634,41 @@ where
         (tensor, index)
     }
 
+    /// Find the minimum value along all the given dimensions at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - The dimensions to reduce.
+    /// * `keepdim` - If the reduced dimensions are kept with a size of 1, otherwise they are
+    ///   removed and `D2` must be `D - dims.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///   let device = B::Device::default();
+    ///   let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///   let tensor: Tensor<B, 1> = tensor.amin(&[0], false);
+    ///   println!("{tensor}");
+    ///   // [1.0, -2.0, 3.0]
+    /// }
+    /// ```
+    pub fn amin<const D2: usize>(self, dims: &[usize], keepdim: bool) -> Tensor<B, D2, K> {
+        check!(TensorCheck::aggregate_dims::<D, D2>("Amin", dims, keepdim));
+
+        let tensor = dims.iter().fold(self, |tensor, dim| tensor.min_dim(*dim));
+
+        if keepdim {
+            Tensor::new(tensor.primitive)
+        } else {
+            let dims: Vec<isize> = dims.iter().map(|dim| *dim as isize).collect();
+            tensor.squeeze_dims(&dims)
+        }
+    }
+
     /// Finds the minimum pair wise values with another tensor.
     ///
     /// # Arguments
@@ -1311,6 +1701,10 @@ where
 
     /// Clamp element wise between the given min and max values.
     ///
+    /// For float tensors, the NaN elements are propagated: the output is NaN where the input is
+    /// NaN, like the `Clip` operator of ONNX and `torch.clamp`. Use
+    /// [clamp_nan_to](Tensor::clamp_nan_to) to replace them instead.
+    ///
     /// # Arguments
     ///
     /// * `min` - The minimum value.
@@ -2105,6 +2499,61 @@ where
         // Assign the original tensor data to the appropriate slice of the padded tensor
         padded_tensor.slice_assign(ranges, self)
     }
+
+    /// Pad the end of the given dimension with the given value, so that its size becomes the next
+    /// multiple of `multiple`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to pad.
+    /// * `multiple` - The multiple the size of the dimension is padded to.
+    /// * `value` - The value to pad the tensor with.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the padded tensor and the amount of padding added to the dimension,
+    /// which is zero when the size is already a multiple.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or the multiple is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///    let (tensor, padding) = tensor.pad_to_multiple(1, 2, 0.0);
+    ///    println!("{tensor} {padding}");
+    ///    // [[1.0, 2.0, 3.0, 0.0], [4.0, 5.0, 6.0, 0.0]] 1
+    /// }
+    /// ```
+    pub fn pad_to_multiple<E: ElementConversion>(
+        self,
+        dim: usize,
+        multiple: usize,
+        value: E,
+    ) -> (Tensor<B, D, K>, usize) {
+        check!(TensorCheck::pad_to_multiple::<D>(dim, multiple));
+
+        let size = self.dims()[dim];
+        let padding = size.next_multiple_of(multiple) - size;
+
+        if padding == 0 {
+            return (self, 0);
+        }
+
+        let mut padding_dims = self.dims();
+        padding_dims[dim] = padding;
+        let padding_tensor = Tensor::full(padding_dims, value, &self.device());
+
+        (Tensor::cat(vec![self, padding_tensor], dim), padding)
+    }
+
     /// Create a one hot tensor.
     ///
     /// # Example
@@ -3083,6 +3532,38 @@ where
         values: Self::Primitive,
     ) -> Self::Primitive;
 
+    /// Reduces elements into a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to reduce elements.
+    /// * `tensor` - The tensor to reduce elements into.
+    /// * `indices` - The indices of the positions to reduce into.
+    /// * `values` - The values to reduce into the tensor.
+    /// * `mode` - The reduction applied at each position.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the reduction of
+    /// the corresponding element of the input tensor with all the values assigned to it.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For reducing elements into a tensor along an axis, users should prefer the
+    /// [Tensor::index_reduce](Tensor::index_reduce) function, which is more high-level and designed
+    /// for public use.
+    fn index_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        mode: IndexReduceMode,
+    ) -> Self::Primitive;
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -3138,6 +3619,30 @@ where
         values: Self::Primitive,
     ) -> Self::Primitive;
 
+    /// Repeats each element of the tensor along the given dimension, keeping the copies of an
+    /// element next to each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to repeat.
+    /// * `repeats` - The number of times to repeat each element.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with each element of the given dimension repeated.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For repeating the elements of a tensor, users should prefer the
+    /// [Tensor::repeat_interleave_scalar](Tensor::repeat_interleave_scalar) function, which is more
+    /// high-level and designed for public use.
+    fn repeat_interleave(tensor: Self::Primitive, dim: usize, repeats: usize) -> Self::Primitive;
+
     /// Gets the indices of the maximum elements of a tensor along an axis.
     ///
     /// # Arguments
@@ -3706,6 +4211,11 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Self::Primitive {
         B::int_select_assign(tensor, dim, indices.primitive, values)
     }
+
+    fn repeat_interleave(tensor: Self::Primitive, dim: usize, repeats: usize) -> Self::Primitive {
+        B::int_repeat_interleave(tensor, dim, repeats)
+    }
+
     fn gather(
         dim: usize,
         tensor: Self::Primitive,
@@ -3723,6 +4233,16 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_scatter(dim, tensor, indices, values)
     }
 
+    fn index_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        mode: IndexReduceMode,
+    ) -> Self::Primitive {
+        B::int_index_reduce(dim, tensor, indices, values, mode)
+    }
+
     fn argmax(tensor: Self::Primitive, dim: usize) -> IntTensor<B> {
         B::int_argmax(tensor, dim)
     }
@@ -4058,6 +4578,17 @@ impl<B: Backend> Numeric<B> for Float {
         ))
     }
 
+    fn repeat_interleave(tensor: Self::Primitive, dim: usize, repeats: usize) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                TensorPrimitive::Float(B::float_repeat_interleave(tensor, dim, repeats))
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                TensorPrimitive::QFloat(B::q_repeat_interleave(tensor, dim, repeats))
+            }
+        }
+    }
+
     fn gather(
         dim: usize,
         tensor: Self::Primitive,
@@ -4087,6 +4618,22 @@ impl<B: Backend> Numeric<B> for Float {
         ))
     }
 
+    fn index_reduce(
+        dim: usize,
+        tensor: Self::Primitive,
+        indices: B::IntTensorPrimitive,
+        values: Self::Primitive,
+        mode: IndexReduceMode,
+    ) -> Self::Primitive {
+        TensorPrimitive::Float(B::float_index_reduce(
+            dim,
+            tensor.tensor(),
+            indices,
+            values.tensor(),
+            mode,
+        ))
+    }
+
     fn argmax(tensor: Self::Primitive, dim: usize) -> IntTensor<B> {
         match tensor {
             TensorPrimitive::Float(tensor) => B::float_argmax(tensor, dim),
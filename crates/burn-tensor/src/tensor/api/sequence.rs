@@ -0,0 +1,204 @@
+use crate::check::TensorCheck;
+use crate::{Int, Tensor, TensorData, backend::Backend, check};
+use alloc::vec::Vec;
+
+/// A batch of variable-length sequences stored without padding, as created by
+/// [pack_padded_sequence].
+///
+/// The elements are ordered by time step first, then by sequence: the first element of every
+/// sequence comes first, followed by the second element of every sequence that has one, and so on.
+#[derive(Clone, Debug)]
+pub struct PackedSequence<B: Backend> {
+    /// The elements of all the sequences of shape `[sum(lengths), features]`.
+    pub data: Tensor<B, 2>,
+    /// The number of sequences having an element at each time step.
+    pub batch_sizes: Vec<usize>,
+    /// The length of each sequence, in the order of the batch.
+    pub lengths: Vec<usize>,
+}
+
+/// Pads a list of variable-length sequences to the length of the longest one and stacks them.
+///
+/// The output is pre-allocated with `padding_value` and each sequence is copied into its slice.
+///
+/// # Arguments
+///
+/// * `tensors` - The sequences of shape `[seq_length, features]`.
+/// * `batch_first` - If the output is `[batch_size, max_length, features]` instead of
+///   `[max_length, batch_size, features]`.
+/// * `padding_value` - The value of the padded elements.
+///
+/// # Returns
+///
+/// The padded batch and the original length of each sequence.
+///
+/// # Panics
+///
+/// - If `tensors` is an empty vector.
+/// - If the sequences don't have the same number of features.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, pad_sequence};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let a = Tensor::<B, 2>::from_data([[1.0], [2.0], [3.0]], &device);
+///     let b = Tensor::<B, 2>::from_data([[4.0]], &device);
+///
+///     // [[[1.0], [2.0], [3.0]], [[4.0], [0.0], [0.0]]] and [3, 1]
+///     let (padded, lengths) = pad_sequence(vec![a, b], true, 0.0);
+///     println!("{padded} {lengths}");
+/// }
+/// ```
+pub fn pad_sequence<B: Backend>(
+    tensors: Vec<Tensor<B, 2>>,
+    batch_first: bool,
+    padding_value: f64,
+) -> (Tensor<B, 3>, Tensor<B, 1, Int>) {
+    check!(TensorCheck::pad_sequence(&tensors));
+
+    let device = tensors[0].device();
+    let [_, features] = tensors[0].dims();
+    let lengths: Vec<usize> = tensors.iter().map(|tensor| tensor.dims()[0]).collect();
+    let batch_size = lengths.len();
+    let max_length = lengths.iter().copied().max().unwrap();
+
+    let shape = match batch_first {
+        true => [batch_size, max_length, features],
+        false => [max_length, batch_size, features],
+    };
+    let mut output = Tensor::full(shape, padding_value, &device);
+
+    for (i, (tensor, length)) in tensors.into_iter().zip(lengths.iter().copied()).enumerate() {
+        if length == 0 {
+            continue;
+        }
+
+        output = match batch_first {
+            true => output.slice_assign([i..i + 1, 0..length, 0..features], tensor.unsqueeze()),
+            false => {
+                output.slice_assign([0..length, i..i + 1, 0..features], tensor.unsqueeze_dim(1))
+            }
+        };
+    }
+
+    let lengths = lengths.into_iter().map(|length| length as i64).collect();
+    let lengths = Tensor::from_data(TensorData::new(lengths, [batch_size]), &device);
+
+    (output, lengths)
+}
+
+/// Packs a padded batch of variable-length sequences, removing the padded elements.
+///
+/// This is the inverse of [pad_packed_sequence].
+///
+/// # Arguments
+///
+/// * `padded` - The padded batch of shape `[batch_size, max_length, features]` if `batch_first`,
+///   `[max_length, batch_size, features]` otherwise.
+/// * `lengths` - The length of each sequence of shape `[batch_size]`.
+/// * `batch_first` - If the batch dimension of `padded` comes first.
+///
+/// # Panics
+///
+/// - If the number of lengths is not the batch size.
+/// - If a length is zero or greater than the padded length.
+pub fn pack_padded_sequence<B: Backend>(
+    padded: Tensor<B, 3>,
+    lengths: Tensor<B, 1, Int>,
+    batch_first: bool,
+) -> PackedSequence<B> {
+    let lengths: Vec<usize> = lengths
+        .into_data()
+        .iter::<i64>()
+        .map(|length| length as usize)
+        .collect();
+    let [batch_size, max_length, features] = match batch_first {
+        true => padded.dims(),
+        false => {
+            let [max_length, batch_size, features] = padded.dims();
+            [batch_size, max_length, features]
+        }
+    };
+    check!(TensorCheck::pack_padded_sequence(
+        batch_size, max_length, &lengths
+    ));
+
+    let mut batch_sizes = Vec::new();
+    let mut indices = Vec::new();
+    for step in 0..max_length {
+        let indices_before = indices.len();
+        for (i, length) in lengths.iter().enumerate() {
+            if *length > step {
+                let index = match batch_first {
+                    true => i * max_length + step,
+                    false => step * batch_size + i,
+                };
+                indices.push(index as i64);
+            }
+        }
+
+        match indices.len() - indices_before {
+            0 => break,
+            size => batch_sizes.push(size),
+        }
+    }
+
+    let device = padded.device();
+    let num_indices = indices.len();
+    let indices = Tensor::from_data(TensorData::new(indices, [num_indices]), &device);
+    let data = padded
+        .reshape([batch_size * max_length, features])
+        .select(0, indices);
+
+    PackedSequence {
+        data,
+        batch_sizes,
+        lengths,
+    }
+}
+
+/// Pads a packed batch of variable-length sequences.
+///
+/// This is the inverse of [pack_padded_sequence], the output is the same as [pad_sequence].
+pub fn pad_packed_sequence<B: Backend>(
+    packed: PackedSequence<B>,
+    batch_first: bool,
+    padding_value: f64,
+) -> (Tensor<B, 3>, Tensor<B, 1, Int>) {
+    let device = packed.data.device();
+
+    // Start of each time step in the packed data
+    let offsets: Vec<usize> = packed
+        .batch_sizes
+        .iter()
+        .scan(0, |offset, size| {
+            let start = *offset;
+            *offset += size;
+            Some(start)
+        })
+        .collect();
+
+    let sequences = packed
+        .lengths
+        .iter()
+        .enumerate()
+        .map(|(i, length)| {
+            // The sequence is after the sequences before it still active at each time step
+            let indices: Vec<i64> = (0..*length)
+                .map(|step| {
+                    let position = packed.lengths[..i].iter().filter(|l| **l > step).count();
+                    (offsets[step] + position) as i64
+                })
+                .collect();
+            let indices = Tensor::from_data(TensorData::new(indices, [*length]), &device);
+
+            packed.data.clone().select(0, indices)
+        })
+        .collect();
+
+    pad_sequence(sequences, batch_first, padding_value)
+}
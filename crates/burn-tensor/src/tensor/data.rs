@@ -254,7 +254,7 @@ impl TensorData {
                 DType::QFloat(scheme) => match scheme {
                     QuantScheme {
                         level: QuantLevel::Tensor,
-                        mode: QuantMode::Symmetric,
+                        mode: QuantMode::Symmetric | QuantMode::Affine,
                         q_type: QuantInputType::QInt8,
                         ..
                     } => {
@@ -815,7 +815,7 @@ impl core::fmt::Display for TensorData {
             DType::QFloat(scheme) => match scheme {
                 QuantScheme {
                     level: QuantLevel::Tensor,
-                    mode: QuantMode::Symmetric,
+                    mode: QuantMode::Symmetric | QuantMode::Affine,
                     q_type: QuantInputType::QInt8,
                     ..
                 } => {
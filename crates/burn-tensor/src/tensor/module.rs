@@ -3,7 +3,10 @@ use crate::{
     backend::Backend,
     check,
     check::TensorCheck,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
+    ops::{
+        ConvOptions, ConvTransposeOptions, FlashAttnVarlenOptions, InterpolateOptions,
+        UnfoldOptions,
+    },
 };
 
 use super::ops::DeformConvOptions;
@@ -91,6 +94,76 @@ where
     )))
 }
 
+/// Applies a [1D convolution](crate::ops::ModuleOps::conv1d) with a padding that can differ at
+/// the start and at the end of the input.
+///
+/// The `(start, end)` padding replaces the padding of the options. The part of the padding that
+/// is the same on both sides is applied by the convolution, while the rest is added to the input
+/// with zeros.
+pub fn conv1d_with_padding<B>(
+    x: Tensor<B, 3>,
+    weight: Tensor<B, 3>,
+    bias: Option<Tensor<B, 1>>,
+    padding: [(usize, usize); 1],
+    options: ConvOptions<1>,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    let [(start, end)] = padding;
+    let symmetric = start.min(end);
+    let x = x.pad((start - symmetric, end - symmetric, 0, 0), 0.0);
+
+    conv1d(
+        x,
+        weight,
+        bias,
+        ConvOptions {
+            padding: [symmetric],
+            ..options
+        },
+    )
+}
+
+/// Applies a [2D convolution](crate::ops::ModuleOps::conv2d) with a padding that can differ at
+/// the start and at the end of each spatial dimension.
+///
+/// The `(start, end)` padding of the height and the width replaces the padding of the options.
+/// The part of the padding that is the same on both sides is applied by the convolution, while
+/// the rest is added to the input with zeros.
+pub fn conv2d_with_padding<B>(
+    x: Tensor<B, 4>,
+    weight: Tensor<B, 4>,
+    bias: Option<Tensor<B, 1>>,
+    padding: [(usize, usize); 2],
+    options: ConvOptions<2>,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let symmetric = padding.map(|(start, end)| start.min(end));
+    let [(top, bottom), (left, right)] = padding;
+    let x = x.pad(
+        (
+            left - symmetric[1],
+            right - symmetric[1],
+            top - symmetric[0],
+            bottom - symmetric[0],
+        ),
+        0.0,
+    );
+
+    conv2d(
+        x,
+        weight,
+        bias,
+        ConvOptions {
+            padding: symmetric,
+            ..options
+        },
+    )
+}
+
 /// Applies a [Deformable 2D convolution](crate::ops::ModuleOps::deform_conv2d).
 pub fn deform_conv2d<B>(
     x: Tensor<B, 4>,
@@ -331,6 +404,22 @@ where
     )))
 }
 
+/// Applies a [2D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool2d).
+///
+/// Like PyTorch, the output index `i` of a dimension of size `input_size` covers the input range
+/// `floor(i * input_size / output_size)..ceil((i + 1) * input_size / output_size)`, which is a
+/// kernel of `input_size / output_size` elements with the same stride when the input size is
+/// divisible by the output size.
+pub fn adaptive_max_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::adaptive_max_pool2d(
+        x.primitive.tensor(),
+        output_size,
+    )))
+}
+
 /// Applies a [1D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool1d).
 pub fn adaptive_avg_pool1d<B>(x: Tensor<B, 3>, output_size: usize) -> Tensor<B, 3>
 where
@@ -357,3 +446,185 @@ where
         options,
     )))
 }
+
+/// Samples the input at the locations given by the grid using N-D linear interpolation
+/// (bilinear in 2D, trilinear in 3D, ...).
+///
+/// Generalizes `grid_sample` to any number of spatial dimensions, to sample feature grids at
+/// arbitrary coordinates (e.g. neural radiance fields and occupancy networks).
+///
+/// The grid coordinates are normalized to `[-1, 1]`, where `-1` and `1` are the centers of the
+/// first and last elements of each spatial dimension (`align_corners = true`). Coordinates outside
+/// of this range are clamped to the border. As in PyTorch, the last dimension of the grid is in
+/// reverse spatial order: `grid[..., 0]` indexes the last spatial dimension of the input
+/// (e.g. `x` for the width).
+///
+/// # Shapes
+///
+/// * x: `[batch_size, channels, d_1, ..., d_n]`
+/// * grid: `[batch_size, d_1_out, ..., d_n_out, n]`
+/// * output: `[batch_size, channels, d_1_out, ..., d_n_out]`
+pub fn grid_sample_nd<B, const D: usize>(x: Tensor<B, D>, grid: Tensor<B, D>) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    let x_dims = x.dims();
+    let grid_dims = grid.dims();
+    check!(TensorCheck::grid_sample_nd(x_dims, grid_dims));
+
+    let n = D - 2;
+    let [batch_size, channels] = [x_dims[0], x_dims[1]];
+    let spatial = &x_dims[2..];
+    let num_points = grid_dims[1..D - 1].iter().product::<usize>();
+    let device = x.device();
+
+    let x = x.reshape([batch_size, channels, spatial.iter().product()]);
+    let grid = grid.reshape([batch_size, num_points, n]);
+
+    // Row-major strides of the flattened spatial dimensions
+    let mut strides = alloc::vec![1; n];
+    for i in (0..n.saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * spatial[i + 1];
+    }
+
+    // Lower and upper neighbor indices, and interpolation weight of the upper neighbor
+    let neighbors = (0..n)
+        .map(|i| {
+            let size = spatial[i];
+            let max = (size - 1) as f32;
+            let position = grid
+                .clone()
+                .narrow(2, n - 1 - i, 1)
+                .reshape([batch_size, num_points])
+                .add_scalar(1.0)
+                .mul_scalar(max / 2.0)
+                .clamp(0.0, max);
+            let lower = position.clone().floor();
+            let weight = position - lower.clone();
+            let lower = lower.int();
+            let upper = lower.clone().add_scalar(1).clamp_max(size as i32 - 1);
+
+            (lower, upper, weight)
+        })
+        .collect::<alloc::vec::Vec<_>>();
+
+    // Sum the contributions of the 2^n corners surrounding each point
+    let mut output = Tensor::<B, 3>::zeros([batch_size, channels, num_points], &device);
+    for corner in 0..(1usize << n) {
+        let mut index = Tensor::<B, 2, Int>::zeros([batch_size, num_points], &device);
+        let mut weight = Tensor::<B, 2>::ones([batch_size, num_points], &device);
+
+        for (i, (lower, upper, upper_weight)) in neighbors.iter().enumerate() {
+            let (neighbor, neighbor_weight) = if corner & (1 << i) != 0 {
+                (upper.clone(), upper_weight.clone())
+            } else {
+                (lower.clone(), upper_weight.clone().neg().add_scalar(1.0))
+            };
+            index = index + neighbor.mul_scalar(strides[i] as i32);
+            weight = weight * neighbor_weight;
+        }
+
+        let index = index
+            .unsqueeze_dim::<3>(1)
+            .expand([batch_size, channels, num_points]);
+        output = output + x.clone().gather(2, index) * weight.unsqueeze_dim(1);
+    }
+
+    let mut output_dims = [batch_size; D];
+    output_dims[1] = channels;
+    output_dims[2..].copy_from_slice(&grid_dims[1..D - 1]);
+
+    output.reshape(output_dims)
+}
+
+/// Computes the cross-entropy between the logits and the targets with label smoothing, as a
+/// single operation.
+///
+/// Each target is smoothed to `(1 - smoothing) * one_hot(target) + smoothing / num_classes`. The
+/// loss is computed without materializing the smoothed targets or the log probabilities, and the
+/// gradient of the logits is computed directly as `softmax(logits) - smoothed_targets`.
+///
+/// # Shapes
+///
+/// * logits: `[batch_size, num_classes]`
+/// * targets: `[batch_size]`
+/// * output: `[batch_size]`, the loss of each sample
+pub fn cross_entropy_with_label_smoothing<B>(
+    logits: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+    smoothing: f64,
+) -> Tensor<B, 1>
+where
+    B: Backend,
+{
+    check!(TensorCheck::cross_entropy_with_label_smoothing(
+        logits.dims(),
+        targets.dims(),
+        smoothing,
+    ));
+
+    Tensor::new(TensorPrimitive::Float(
+        B::cross_entropy_with_label_smoothing(
+            logits.primitive.tensor(),
+            targets.primitive,
+            smoothing,
+        ),
+    ))
+}
+
+/// Computes attention over sequences of different lengths packed together, without padding.
+///
+/// The sequences are concatenated along the first dimension, and the sequence `i` spans the rows
+/// `cu_seqlens[i]..cu_seqlens[i + 1]`, where `cu_seqlens` holds the cumulative sequence lengths
+/// starting with `0`. Each query only attends to the keys of its own sequence.
+///
+/// # Arguments
+///
+/// * `max_seqlen_q`, `max_seqlen_k` - The longest query and key sequence lengths, used to size the
+///   work of the kernels and the padded batch of the default implementation. They must be at least
+///   the length of every sequence.
+/// * `dropout` - Dropout probability applied to the attention weights.
+/// * `softmax_scale` - Scale applied to the scores, `1 / sqrt(head_dim)` when `None`.
+/// * `causal` - Whether each query only attends to the keys up to its own position. When query
+///   and key sequences have different lengths, the mask is aligned to the end of the sequences.
+///
+/// # Shapes
+///
+/// * q: `[total_q, head_dim]`
+/// * k: `[total_k, head_dim]`
+/// * v: `[total_k, value_dim]`
+/// * cu_seqlens_q, cu_seqlens_k: `[num_seqs + 1]`
+/// * output: `[total_q, value_dim]`
+#[allow(clippy::too_many_arguments)]
+pub fn flash_attn_varlen<B>(
+    q: Tensor<B, 2>,
+    k: Tensor<B, 2>,
+    v: Tensor<B, 2>,
+    cu_seqlens_q: Tensor<B, 1, Int>,
+    cu_seqlens_k: Tensor<B, 1, Int>,
+    max_seqlen_q: usize,
+    max_seqlen_k: usize,
+    dropout: f64,
+    softmax_scale: Option<f64>,
+    causal: bool,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    check!(TensorCheck::flash_attn_varlen(
+        q.dims(),
+        k.dims(),
+        v.dims(),
+        cu_seqlens_q.dims(),
+        cu_seqlens_k.dims(),
+    ));
+
+    Tensor::new(TensorPrimitive::Float(B::flash_attn_varlen(
+        q.primitive.tensor(),
+        k.primitive.tensor(),
+        v.primitive.tensor(),
+        cu_seqlens_q.primitive,
+        cu_seqlens_k.primitive,
+        FlashAttnVarlenOptions::new(max_seqlen_q, max_seqlen_k, dropout, softmax_scale, causal),
+    )))
+}
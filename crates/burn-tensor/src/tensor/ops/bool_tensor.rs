@@ -83,6 +83,31 @@ pub trait BoolTensorOps<B: Backend> {
     /// Moves the tensor to the device.
     fn bool_to_device(tensor: BoolTensor<B>, device: &Device<B>) -> BoolTensor<B>;
 
+    /// Moves the tensor to the given device without blocking on the transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device to move the tensor to.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the tensor on the given device.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation moves the tensor synchronously with
+    /// [bool_to_device](Self::bool_to_device) before returning a ready future. Backends that
+    /// can read the data of a device asynchronously should override it.
+    fn bool_to_device_async(
+        tensor: BoolTensor<B>,
+        device: &Device<B>,
+    ) -> impl Future<Output = BoolTensor<B>> + Send {
+        let tensor = B::bool_to_device(tensor, device);
+
+        async move { tensor }
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -215,6 +240,20 @@ pub trait BoolTensorOps<B: Backend> {
     /// The tensor with the result of the logical or.
     fn bool_or(tensor: BoolTensor<B>, rhs: BoolTensor<B>) -> BoolTensor<B>;
 
+    /// Executes the logical xor operation on two boolean tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical xor.
+    fn bool_xor(lhs: BoolTensor<B>, rhs: BoolTensor<B>) -> BoolTensor<B> {
+        B::bool_not_equal(lhs, rhs)
+    }
+
     /// Transposes a bool tensor.
     ///
     /// # Arguments
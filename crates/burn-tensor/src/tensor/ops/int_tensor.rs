@@ -1,11 +1,12 @@
 use super::cat::cat_with_slice_assign;
 use super::repeat_dim::repeat_with_slice_assign;
+use super::repeat_interleave::repeat_interleave_indices;
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use crate::{Distribution, ElementConversion, Int, TensorData, backend::Backend, tensor::Shape};
 use alloc::vec::Vec;
 use core::ops::Range;
 
-use crate::{TensorMetadata, argsort, sort, sort_with_indices};
+use crate::{IndexReduceMode, TensorMetadata, argsort, int_index_reduce, sort, sort_with_indices};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -59,6 +60,31 @@ pub trait IntTensorOps<B: Backend> {
     /// Moves the tensor to the given device.
     fn int_to_device(tensor: IntTensor<B>, device: &Device<B>) -> IntTensor<B>;
 
+    /// Moves the tensor to the given device without blocking on the transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device to move the tensor to.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the tensor on the given device.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation moves the tensor synchronously with
+    /// [int_to_device](Self::int_to_device) before returning a ready future. Backends that
+    /// can read the data of a device asynchronously should override it.
+    fn int_to_device_async(
+        tensor: IntTensor<B>,
+        device: &Device<B>,
+    ) -> impl Future<Output = IntTensor<B>> + Send {
+        let tensor = B::int_to_device(tensor, device);
+
+        async move { tensor }
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -169,6 +195,39 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B>,
     ) -> IntTensor<B>;
 
+    /// Reduce elements into a tensor at the given indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce into.
+    /// * `tensor` - The tensor to reduce into.
+    /// * `indices` - The indices to reduce into.
+    /// * `value` - The values to reduce.
+    /// * `mode` - The reduction applied at each position.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the reduced elements, including its original values in the reduction.
+    ///
+    /// # Remarks
+    ///
+    /// The sum is computed with [int_scatter](Self::int_scatter). The other reductions fall back
+    /// to [int_index_reduce](crate::int_index_reduce), which reads the tensors synchronously and
+    /// reduces them on the host. This is only a last resort for backends without a
+    /// scatter-reduce kernel, so backends should override this method.
+    fn int_index_reduce(
+        dim: usize,
+        tensor: IntTensor<B>,
+        indices: IntTensor<B>,
+        value: IntTensor<B>,
+        mode: IndexReduceMode,
+    ) -> IntTensor<B> {
+        match mode {
+            IndexReduceMode::Sum => B::int_scatter(dim, tensor, indices, value),
+            _ => int_index_reduce::<B>(tensor, dim, indices, value, mode),
+        }
+    }
+
     /// Select tensor elements along the given dimension corresponding to the given indices.
     ///
     /// # Arguments
@@ -217,6 +276,25 @@ pub trait IntTensorOps<B: Backend> {
         repeat_with_slice_assign::<B, Int>(tensor, dim, times)
     }
 
+    /// Repeat each element of the tensor along the given dimension, keeping the copies of an
+    /// element next to each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to repeat.
+    /// * `repeats` - The number of times to repeat each element.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with each element of the given dimension repeated.
+    fn int_repeat_interleave(tensor: IntTensor<B>, dim: usize, repeats: usize) -> IntTensor<B> {
+        let size = tensor.shape().dims[dim];
+        let indices = repeat_interleave_indices::<B>(size, repeats, &B::int_device(&tensor));
+
+        B::int_select(tensor, dim, indices)
+    }
+
     /// Concatenates the given tensors along the given dimension.
     ///
     /// # Arguments
@@ -0,0 +1,144 @@
+use crate::{
+    Distribution, ElementConversion, Shape, TensorMetadata,
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+};
+
+use super::FlashAttnVarlenOptions;
+
+/// Variable-length attention computed with regular tensor operations.
+///
+/// The packed sequences are scattered into a zero-padded batch of `max_seqlen_q` queries and
+/// `max_seqlen_k` keys per sequence, so the scores take `[num_seqs, max_seqlen_q, max_seqlen_k]`
+/// memory instead of covering every pair of packed tokens. The padded keys are masked out, and
+/// the padded queries are dropped when gathering the output back.
+pub fn flash_attn_varlen<B: Backend>(
+    q: FloatTensor<B>,
+    k: FloatTensor<B>,
+    v: FloatTensor<B>,
+    cu_seqlens_q: IntTensor<B>,
+    cu_seqlens_k: IntTensor<B>,
+    options: FlashAttnVarlenOptions,
+) -> FloatTensor<B> {
+    let [total_q, head_dim] = q.shape().dims();
+    let [total_k, _] = k.shape().dims();
+    let [_, value_dim] = v.shape().dims();
+    let [num_seqs] = cu_seqlens_q.shape().dims();
+    let num_seqs = num_seqs - 1;
+    let (max_q, max_k) = (options.max_seqlen_q, options.max_seqlen_k);
+    let device = B::float_device(&q);
+
+    let (seq_q, pos_q) = sequence_positions::<B>(cu_seqlens_q.clone(), total_q, num_seqs);
+    let (seq_k, pos_k) = sequence_positions::<B>(cu_seqlens_k.clone(), total_k, num_seqs);
+    let padded_q = padded_rows::<B>(seq_q, pos_q, max_q);
+    let padded_k = padded_rows::<B>(seq_k, pos_k, max_k);
+
+    let pad = |tensor: FloatTensor<B>, rows: IntTensor<B>, max_len: usize, dim: usize| {
+        let padded = B::float_zeros(Shape::new([num_seqs * max_len, dim]), &device);
+        let padded = B::float_select_assign(padded, 0, rows, tensor);
+        B::float_reshape(padded, Shape::new([num_seqs, max_len, dim]))
+    };
+    let q = pad(q, padded_q.clone(), max_q, head_dim);
+    let k = pad(k, padded_k.clone(), max_k, head_dim);
+    let v = pad(v, padded_k, max_k, value_dim);
+
+    let scale = options
+        .softmax_scale
+        .unwrap_or(1.0 / (head_dim as f64).sqrt());
+    let scores = B::float_matmul(q, B::float_transpose(k));
+    let scores = B::float_mul_scalar(scores, scale.elem());
+
+    let shape = Shape::new([num_seqs, max_q, max_k]);
+    let expand = |tensor: IntTensor<B>, dims: [usize; 3]| {
+        B::int_expand(B::int_reshape(tensor, Shape::new(dims)), shape.clone())
+    };
+    let lengths_k = sequence_lengths::<B>(cu_seqlens_k, num_seqs);
+    let key = expand(B::int_arange(0..max_k as i64, &device), [1, 1, max_k]);
+
+    let mut allowed = B::int_lower(key.clone(), expand(lengths_k.clone(), [num_seqs, 1, 1]));
+    if options.causal {
+        // Bottom-right aligned: the last query of a sequence attends to all of its keys
+        let offsets = B::int_sub(lengths_k, sequence_lengths::<B>(cu_seqlens_q, num_seqs));
+        let limit = B::int_add(
+            expand(B::int_arange(0..max_q as i64, &device), [1, max_q, 1]),
+            expand(offsets, [num_seqs, 1, 1]),
+        );
+        allowed = B::bool_and(allowed, B::int_lower_equal(key, limit));
+    }
+
+    let output = masked_softmax_matmul::<B>(scores, allowed, v, options.dropout);
+    let output = B::float_reshape(output, Shape::new([num_seqs * max_q, value_dim]));
+    B::float_select(output, 0, padded_q)
+}
+
+/// Returns the row of each packed token in the padded batch, flattened over the sequences.
+fn padded_rows<B: Backend>(seq: IntTensor<B>, pos: IntTensor<B>, max_len: usize) -> IntTensor<B> {
+    B::int_add(B::int_mul_scalar(seq, (max_len as i64).elem()), pos)
+}
+
+/// Returns the sequence index and the position within its sequence of each packed token.
+#[allow(clippy::single_range_in_vec_init)]
+fn sequence_positions<B: Backend>(
+    cu_seqlens: IntTensor<B>,
+    total: usize,
+    num_seqs: usize,
+) -> (IntTensor<B>, IntTensor<B>) {
+    let device = B::int_device(&cu_seqlens);
+    let shape = Shape::new([total, num_seqs]);
+    let starts = B::int_slice(cu_seqlens.clone(), &[0..num_seqs]);
+    let ends = B::int_slice(cu_seqlens, &[1..num_seqs + 1]);
+
+    let index = B::int_arange(0..total as i64, &device);
+    let past_end = B::int_greater_equal(
+        B::int_expand(
+            B::int_reshape(index.clone(), Shape::new([total, 1])),
+            shape.clone(),
+        ),
+        B::int_expand(B::int_reshape(ends, Shape::new([1, num_seqs])), shape),
+    );
+    let seq = B::int_sum_dim(B::bool_into_int(past_end), 1);
+    let seq = B::int_reshape(seq, Shape::new([total]));
+    let pos = B::int_sub(index, B::int_gather(0, starts, seq.clone()));
+
+    (seq, pos)
+}
+
+#[allow(clippy::single_range_in_vec_init)]
+fn sequence_lengths<B: Backend>(cu_seqlens: IntTensor<B>, num_seqs: usize) -> IntTensor<B> {
+    B::int_sub(
+        B::int_slice(cu_seqlens.clone(), &[1..num_seqs + 1]),
+        B::int_slice(cu_seqlens, &[0..num_seqs]),
+    )
+}
+
+fn masked_softmax_matmul<B: Backend>(
+    scores: FloatTensor<B>,
+    allowed: BoolTensor<B>,
+    v: FloatTensor<B>,
+    dropout: f64,
+) -> FloatTensor<B> {
+    let scores = B::float_mask_fill(scores, B::bool_not(allowed), f32::NEG_INFINITY.elem());
+
+    // Queries without any key (empty key sequence) produce zeros instead of NaNs
+    let max = B::float_max_dim(scores.clone(), 2);
+    let max = B::float_mask_fill(
+        max.clone(),
+        B::float_equal_elem(max, f32::NEG_INFINITY.elem()),
+        0.elem(),
+    );
+    let weights = B::float_exp(B::float_sub(scores, max));
+    let sum = B::float_sum_dim(weights.clone(), 2);
+    let sum = B::float_mask_fill(sum.clone(), B::float_equal_elem(sum, 0.elem()), 1.elem());
+    let mut weights = B::float_div(weights, sum);
+
+    if dropout > 0.0 {
+        let keep = B::float_random(
+            weights.shape(),
+            Distribution::Bernoulli(1.0 - dropout),
+            &B::float_device(&weights),
+        );
+        weights = B::float_mul(weights, B::float_div_scalar(keep, (1.0 - dropout).elem()));
+    }
+
+    B::float_matmul(weights, v)
+}
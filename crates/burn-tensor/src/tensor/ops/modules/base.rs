@@ -1,6 +1,6 @@
 use core::num::NonZeroUsize;
 
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{attention, conv, loss, pool, unfold::unfold4d_using_conv2d};
 use crate::{
     Shape, TensorMetadata,
     backend::Backend,
@@ -250,6 +250,30 @@ pub struct InterpolateOptions {
     pub mode: InterpolateMode,
 }
 
+/// Variable-length flash attention options.
+#[derive(new, Debug, Clone, PartialEq)]
+pub struct FlashAttnVarlenOptions {
+    /// Maximum query sequence length in the batch.
+    pub max_seqlen_q: usize,
+
+    /// Maximum key sequence length in the batch.
+    pub max_seqlen_k: usize,
+
+    /// Dropout probability applied to the attention weights.
+    ///
+    /// The cubecl reference kernel doesn't apply dropout, so the cubecl backends fall back to the
+    /// padded implementation using regular tensor operations when it is non-zero, which
+    /// materializes `[num_seqs, max_seqlen_q, max_seqlen_k]` scores.
+    pub dropout: f64,
+
+    /// Scale applied to the attention scores, `1 / sqrt(head_dim)` when `None`.
+    pub softmax_scale: Option<f64>,
+
+    /// Whether each query only attends to the keys up to its own position, aligned to the end
+    /// of the key sequence.
+    pub causal: bool,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [interpolate](ModuleOps::interpolate).
 #[derive(new)]
 pub struct InterpolateBackward<B: Backend> {
@@ -306,6 +330,35 @@ pub trait ModuleOps<B: Backend> {
 
         B::float_select_assign(grad, 0, indices, output_grad)
     }
+
+    /// Cross-entropy between the logits and the targets smoothed with `smoothing`, without
+    /// materializing the smoothed one-hot targets.
+    ///
+    /// # Shapes
+    ///
+    /// logits: `[batch_size, num_classes]`,
+    /// targets: `[batch_size]`,
+    /// output: `[batch_size]`, the loss of each sample.
+    fn cross_entropy_with_label_smoothing(
+        logits: FloatTensor<B>,
+        targets: IntTensor<B>,
+        smoothing: f64,
+    ) -> FloatTensor<B> {
+        loss::cross_entropy_with_label_smoothing::<B>(logits, targets, smoothing)
+    }
+
+    /// Backward pass for the
+    /// [cross_entropy_with_label_smoothing](ModuleOps::cross_entropy_with_label_smoothing)
+    /// operation, returning the gradient of the logits.
+    fn cross_entropy_with_label_smoothing_backward(
+        logits: FloatTensor<B>,
+        targets: IntTensor<B>,
+        smoothing: f64,
+        grad: FloatTensor<B>,
+    ) -> FloatTensor<B> {
+        loss::cross_entropy_with_label_smoothing_backward::<B>(logits, targets, smoothing, grad)
+    }
+
     /// One dimensional convolution.
     ///
     /// # Shapes
@@ -648,6 +701,14 @@ pub trait ModuleOps<B: Backend> {
     fn adaptive_avg_pool2d(x: FloatTensor<B>, output_size: [usize; 2]) -> FloatTensor<B>;
     /// Backward pass for the [adaptive avg pooling 2d](ModuleOps::adaptive_avg_pool2d) operation.
     fn adaptive_avg_pool2d_backward(x: FloatTensor<B>, grad: FloatTensor<B>) -> FloatTensor<B>;
+    /// Two dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d(x: FloatTensor<B>, output_size: [usize; 2]) -> FloatTensor<B> {
+        pool::adaptive_max_pool2d_from_max_dim::<B>(x, output_size)
+    }
     /// One dimensional adaptive avg pooling.
     ///
     /// # Shapes
@@ -764,6 +825,27 @@ pub trait ModuleOps<B: Backend> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<B>;
+
+    /// Attention over sequences of different lengths packed along the first dimension.
+    ///
+    /// The sequence `i` spans the rows `cu_seqlens[i]..cu_seqlens[i + 1]` of the packed tensors.
+    ///
+    /// # Shapes
+    ///
+    /// q: `[total_q, head_dim]`,
+    /// k: `[total_k, head_dim]`,
+    /// v: `[total_k, value_dim]`,
+    /// cu_seqlens_q, cu_seqlens_k: `[num_seqs + 1]`,
+    fn flash_attn_varlen(
+        q: FloatTensor<B>,
+        k: FloatTensor<B>,
+        v: FloatTensor<B>,
+        cu_seqlens_q: IntTensor<B>,
+        cu_seqlens_k: IntTensor<B>,
+        options: FlashAttnVarlenOptions,
+    ) -> FloatTensor<B> {
+        attention::flash_attn_varlen::<B>(q, k, v, cu_seqlens_q, cu_seqlens_k, options)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,60 @@
+use crate::{
+    ElementConversion, Shape, TensorMetadata,
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+};
+
+/// Cross-entropy with label smoothing computed with regular tensor operations.
+///
+/// The loss `-sum(q * log_softmax(x))` of the smoothed targets
+/// `q = (1 - smoothing) * one_hot + smoothing / num_classes` is computed as
+/// `logsumexp(x) - (1 - smoothing) * x[target] - smoothing * mean(x)`, so neither the smoothed
+/// targets nor the log probabilities are materialized.
+pub fn cross_entropy_with_label_smoothing<B: Backend>(
+    logits: FloatTensor<B>,
+    targets: IntTensor<B>,
+    smoothing: f64,
+) -> FloatTensor<B> {
+    let [batch_size, _] = logits.shape().dims();
+    let targets = B::int_reshape(targets, Shape::new([batch_size, 1]));
+
+    let target_logits = B::float_gather(1, logits.clone(), targets);
+    let target_logits = B::float_mul_scalar(target_logits, (1.0 - smoothing).elem());
+    let mut loss = B::float_sub(log_sum_exp::<B>(logits.clone()), target_logits);
+    if smoothing != 0.0 {
+        let mean_logits = B::float_mean_dim(logits, 1);
+        loss = B::float_sub(loss, B::float_mul_scalar(mean_logits, smoothing.elem()));
+    }
+
+    B::float_reshape(loss, Shape::new([batch_size]))
+}
+
+/// Gradient of [cross_entropy_with_label_smoothing] with respect to the logits,
+/// `(softmax(x) - q) * grad`.
+pub fn cross_entropy_with_label_smoothing_backward<B: Backend>(
+    logits: FloatTensor<B>,
+    targets: IntTensor<B>,
+    smoothing: f64,
+    grad: FloatTensor<B>,
+) -> FloatTensor<B> {
+    let [batch_size, num_classes] = logits.shape().dims();
+    let targets = B::int_reshape(targets, Shape::new([batch_size, 1]));
+    let grad = B::float_reshape(grad, Shape::new([batch_size, 1]));
+
+    let softmax = B::float_exp(B::float_sub(logits.clone(), log_sum_exp::<B>(logits)));
+    let uniform = (smoothing / num_classes as f64).elem();
+    let grad_logits = B::float_mul(B::float_sub_scalar(softmax, uniform), grad.clone());
+
+    // The rest of the target probability is only subtracted at the target classes
+    let grad_targets = B::float_mul_scalar(grad, (smoothing - 1.0).elem());
+    B::float_scatter(1, grad_logits, targets, grad_targets)
+}
+
+/// The `[batch_size, 1]` log of the sum of the exponentials of each row, shifted by the maximum
+/// of the row for numerical stability.
+fn log_sum_exp<B: Backend>(logits: FloatTensor<B>) -> FloatTensor<B> {
+    let max = B::float_max_dim(logits.clone(), 1);
+    let exp = B::float_exp(B::float_sub(logits, max.clone()));
+
+    B::float_add(B::float_log(B::float_sum_dim(exp, 1)), max)
+}
@@ -1,10 +1,16 @@
 /// Module with convolution operations.
 pub mod conv;
 
+/// Module with attention operations.
+pub mod attention;
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with loss operations.
+pub mod loss;
 /// Module with repeat operation
 pub(crate) mod repeat_dim;
+/// Module with repeat interleave operation
+pub(crate) mod repeat_interleave;
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
@@ -1,3 +1,6 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
 use crate::{
     Shape, TensorMetadata,
     backend::Backend,
@@ -84,6 +87,41 @@ pub(crate) fn adaptive_avg_pool1d_backward_from_2d<B: Backend>(
     B::float_reshape(grad_x, Shape::from([batch_size, channels, length_in]))
 }
 
+pub(crate) fn adaptive_max_pool2d_from_max_dim<B: Backend>(
+    x: FloatTensor<B>,
+    output_size: [usize; 2],
+) -> FloatTensor<B> {
+    // The max over a bin is separable, so the rows are reduced before the columns, both as the
+    // last dimension.
+    let x = B::float_swap_dims(x, 2, 3);
+    let x = adaptive_max_last_dim::<B>(x, output_size[0]);
+    let x = B::float_swap_dims(x, 2, 3);
+
+    adaptive_max_last_dim::<B>(x, output_size[1])
+}
+
+/// Reduce the last dimension to `output_size` bins with the max of each bin, the bin `i` spanning
+/// `floor(i * size / output_size)..ceil((i + 1) * size / output_size)`.
+fn adaptive_max_last_dim<B: Backend>(x: FloatTensor<B>, output_size: usize) -> FloatTensor<B> {
+    let shape = x.shape();
+    let dim = shape.num_dims() - 1;
+    let size = shape.dims[dim];
+    let mut ranges: Vec<Range<usize>> = shape.dims.iter().map(|size| 0..*size).collect();
+
+    let bins = (0..output_size)
+        .map(|i| {
+            let start = (i * size) / output_size;
+            let end = ((i + 1) * size).div_ceil(output_size);
+            ranges[dim] = start..end;
+
+            let bin = B::float_slice(x.clone(), &ranges);
+            B::float_max_dim(bin, dim)
+        })
+        .collect();
+
+    B::float_cat(bins, dim)
+}
+
 pub(crate) fn max_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B>,
     kernel_size: usize,
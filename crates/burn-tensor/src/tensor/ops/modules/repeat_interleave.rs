@@ -0,0 +1,12 @@
+use crate::{ElementConversion, backend::Backend, ops::IntTensor};
+
+/// The indices selecting each element of a dimension of the given size `repeats` times in a row.
+pub(crate) fn repeat_interleave_indices<B: Backend>(
+    size: usize,
+    repeats: usize,
+    device: &B::Device,
+) -> IntTensor<B> {
+    let indices = B::int_arange(0..(size * repeats) as i64, device);
+
+    B::int_div_scalar(indices, (repeats as i64).elem())
+}
@@ -10,6 +10,7 @@ use crate::{
     },
 };
 
+use super::repeat_interleave::repeat_interleave_indices;
 use super::{BoolTensor, FloatElem, FloatTensor, IntElem, IntTensor, QuantizedTensor};
 
 /// Automatically applies `dequantization -> float operation -> quantization`.
@@ -330,6 +331,29 @@ pub trait QTensorOps<B: Backend> {
         )
     }
 
+    /// Repeat each element of the tensor along the given dimension, keeping the copies of an
+    /// element next to each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to repeat.
+    /// * `repeats` - The number of times to repeat each element.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with each element of the given dimension repeated.
+    fn q_repeat_interleave(
+        tensor: QuantizedTensor<B>,
+        dim: usize,
+        repeats: usize,
+    ) -> QuantizedTensor<B> {
+        let size = tensor.shape().dims[dim];
+        let indices = repeat_interleave_indices::<B>(size, repeats, &B::q_device(&tensor));
+
+        B::q_select(tensor, dim, indices)
+    }
+
     /// Adds two tensors together.
     ///
     /// # Arguments
@@ -349,6 +373,33 @@ pub trait QTensorOps<B: Backend> {
         )
     }
 
+    /// Adds two tensors together and requantizes the result.
+    ///
+    /// The operands may have different quantization parameters: they are dequantized so that the
+    /// sum is computed in the higher precision floating point type, which is then quantized with
+    /// the given scheme and parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    /// * `scheme` - The quantization scheme of the output.
+    /// * `qparams` - The quantization parameters of the output.
+    ///
+    /// # Returns
+    ///
+    /// The quantized result of adding the two tensors together.
+    fn q_add_requantize(
+        lhs: QuantizedTensor<B>,
+        rhs: QuantizedTensor<B>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> QuantizedTensor<B> {
+        let out_f = B::float_add(Self::dequantize(lhs), Self::dequantize(rhs));
+
+        Self::quantize(out_f, scheme, qparams)
+    }
+
     /// Adds a scalar to a tensor.
     ///
     /// # Arguments
@@ -426,6 +477,25 @@ pub trait QTensorOps<B: Backend> {
         )
     }
 
+    /// Applies the ReLU activation function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn q_relu(tensor: QuantizedTensor<B>) -> TensorPrimitive<B> {
+        // Default implementation. Backends can clamp the quantized values to the zero-point since
+        // it is exactly representable and the qparams remain the same.
+        dequant_op_flow!(
+            ty Self,
+            float_op |tensor| B::relu(tensor),
+            tensor
+        )
+    }
+
     /// Subtracts two tensors.
     ///
     /// # Arguments
@@ -473,6 +543,33 @@ pub trait QTensorOps<B: Backend> {
         )
     }
 
+    /// Multiplies two tensors together element-wise and requantizes the result.
+    ///
+    /// The operands may have different quantization parameters: they are dequantized so that the
+    /// product is computed in the higher precision floating point type, which is then quantized
+    /// with the given scheme and parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    /// * `scheme` - The quantization scheme of the output.
+    /// * `qparams` - The quantization parameters of the output.
+    ///
+    /// # Returns
+    ///
+    /// The quantized result of multiplying the two tensors together.
+    fn q_mul_requantize(
+        lhs: QuantizedTensor<B>,
+        rhs: QuantizedTensor<B>,
+        scheme: &QuantScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> QuantizedTensor<B> {
+        let out_f = B::float_mul(Self::dequantize(lhs), Self::dequantize(rhs));
+
+        Self::quantize(out_f, scheme, qparams)
+    }
+
     /// Multiplies a tensor by a scalar.
     ///
     /// # Arguments
@@ -957,6 +1054,8 @@ pub trait QTensorOps<B: Backend> {
     ///
     /// A tensor with the concatenated tensors along `dim`.
     fn q_cat(tensors: Vec<QuantizedTensor<B>>, dim: usize) -> QuantizedTensor<B> {
+        // Default implementation. The operands are requantized to a shared scale covering all of
+        // them, backends can concatenate the quantized values when the qparams are the same.
         // Heuristic: prioritize first tensor scheme
         let scheme = *tensors.first().unwrap().scheme();
 
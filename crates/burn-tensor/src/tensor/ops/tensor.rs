@@ -1,12 +1,13 @@
 use super::cat::cat_with_slice_assign;
 use super::repeat_dim::repeat_with_slice_assign;
+use super::repeat_interleave::repeat_interleave_indices;
 use super::{BoolTensor, Device, FloatElem, FloatTensor, IntElem, IntTensor};
 use crate::{Distribution, ElementConversion, Float, TensorData, backend::Backend, tensor::Shape};
 use crate::{FloatDType, TensorMetadata, TensorPrimitive};
 use alloc::vec::Vec;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{IndexReduceMode, argsort, index_reduce, sort, sort_with_indices};
 
 /// Operations on float tensors.
 pub trait FloatTensorOps<B: Backend> {
@@ -113,6 +114,31 @@ pub trait FloatTensorOps<B: Backend> {
     /// The tensor on the given device.
     fn float_to_device(tensor: FloatTensor<B>, device: &Device<B>) -> FloatTensor<B>;
 
+    /// Moves the tensor to the given device without blocking on the transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device to move the tensor to.
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the tensor on the given device.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation moves the tensor synchronously with
+    /// [float_to_device](Self::float_to_device) before returning a ready future. Backends that
+    /// can read the data of a device asynchronously should override it.
+    fn float_to_device_async(
+        tensor: FloatTensor<B>,
+        device: &Device<B>,
+    ) -> impl Future<Output = FloatTensor<B>> + Send {
+        let tensor = B::float_to_device(tensor, device);
+
+        async move { tensor }
+    }
+
     /// Converts float tensor to int tensor.
     ///
     /// # Arguments
@@ -151,6 +177,29 @@ pub trait FloatTensorOps<B: Backend> {
         repeat_with_slice_assign::<B, Float>(TensorPrimitive::Float(tensor), dim, times).tensor()
     }
 
+    /// Repeat each element of the tensor along the given dimension, keeping the copies of an
+    /// element next to each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to repeat.
+    /// * `repeats` - The number of times to repeat each element.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with each element of the given dimension repeated.
+    fn float_repeat_interleave(
+        tensor: FloatTensor<B>,
+        dim: usize,
+        repeats: usize,
+    ) -> FloatTensor<B> {
+        let size = tensor.shape().dims[dim];
+        let indices = repeat_interleave_indices::<B>(size, repeats, &B::float_device(&tensor));
+
+        B::float_select(tensor, dim, indices)
+    }
+
     /// Adds two tensors together.
     ///
     /// # Arguments
@@ -209,6 +258,8 @@ pub trait FloatTensorOps<B: Backend> {
 
     /// Clamps a tensor between a minimum and maximum value.
     ///
+    /// The NaN elements of the tensor must be propagated to the output.
+    ///
     /// # Arguments
     ///
     /// * `tensor` - The tensor to clamp.
@@ -421,6 +472,40 @@ pub trait FloatTensorOps<B: Backend> {
         value: FloatTensor<B>,
     ) -> FloatTensor<B>;
 
+    /// Reduce elements into a tensor at the given indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to reduce into.
+    /// * `tensor` - The tensor to reduce into.
+    /// * `indices` - The indices to reduce into.
+    /// * `value` - The values to reduce.
+    /// * `mode` - The reduction applied at each position.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the reduced elements, including its original values in the reduction.
+    ///
+    /// # Remarks
+    ///
+    /// The sum is computed with [float_scatter](Self::float_scatter). The other reductions fall
+    /// back to [index_reduce](crate::index_reduce), which reads the tensors synchronously and
+    /// reduces them on the host in `f64`. This is only a last resort for backends without a
+    /// scatter-reduce kernel: it blocks on the device and panics when the backend can't read
+    /// synchronously, so backends should override this method.
+    fn float_index_reduce(
+        dim: usize,
+        tensor: FloatTensor<B>,
+        indices: IntTensor<B>,
+        value: FloatTensor<B>,
+        mode: IndexReduceMode,
+    ) -> FloatTensor<B> {
+        match mode {
+            IndexReduceMode::Sum => B::float_scatter(dim, tensor, indices, value),
+            _ => index_reduce::<B>(tensor, dim, indices, value, mode),
+        }
+    }
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -736,6 +821,22 @@ pub trait FloatTensorOps<B: Backend> {
         B::float_exp(B::float_sum_dim(B::float_log(tensor), dim))
     }
 
+    /// Frobenius norm of a tensor, the square root of the sum of the squares of all elements.
+    ///
+    /// Backends can override it to compute the norm in a single pass (e.g. with BLAS `nrm2`)
+    /// instead of allocating the intermediate tensors of the default implementation.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the norm of.
+    ///
+    /// # Returns
+    ///
+    /// A scalar tensor with the Frobenius norm of `tensor`.
+    fn float_frobenius_norm(tensor: FloatTensor<B>) -> FloatTensor<B> {
+        B::float_sqrt(B::float_sum(B::float_mul(tensor.clone(), tensor)))
+    }
+
     /// Mean of all elements in a tensor.
     ///
     /// # Arguments
@@ -4,8 +4,8 @@ use crate::{Bytes, Element};
 use alloc::vec::Vec;
 
 use super::{
-    QParams, QuantInputType, QuantLevel, QuantMode, QuantScheme, QuantizationStrategy,
-    SymmetricQuantization, pack_i8s_to_u32s, unpack_u32s_to_i8s,
+    AffineQuantization, QParams, QuantInputType, QuantLevel, QuantMode, QuantScheme,
+    QuantizationStrategy, SymmetricQuantization, pack_i8s_to_u32s, unpack_u32s_to_i8s,
 };
 
 /// Quantized data bytes representation.
@@ -33,15 +33,24 @@ impl QuantizedBytes {
         let num_elements = value.len();
         let scheme = strategy.scheme();
 
+        if TypeId::of::<E>() == TypeId::of::<i8>() {
+            // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
+            let u32s = pack_i8s_to_u32s(bytemuck::allocation::cast_vec(value));
+            bytes = Bytes::from_elems(u32s);
+        } else {
+            panic!("Invalid quantized type");
+        }
+
         match strategy {
             QuantizationStrategy::PerTensorSymmetricInt8(quant) => {
-                if TypeId::of::<E>() == TypeId::of::<i8>() {
-                    // Re-interpret `Vec<E>` as `Vec<i8>` with `Vec::from_raw_parts`
-                    let u32s = pack_i8s_to_u32s(bytemuck::allocation::cast_vec(value));
-                    bytes = Bytes::from_elems(u32s);
-                } else {
-                    panic!("Invalid quantized type");
-                }
+                let scale_bytes = bytemuck::bytes_of(&quant.scale);
+                bytes.extend_from_byte_slice_aligned(scale_bytes, align_of::<f32>());
+            }
+            QuantizationStrategy::PerTensorAffineInt8(quant) => {
+                // The offset is stored as i32 to keep the parameters aligned
+                let offset = quant.offset as i32;
+                let offset_bytes = bytemuck::bytes_of(&offset);
+                bytes.extend_from_byte_slice_aligned(offset_bytes, align_of::<i32>());
                 let scale_bytes = bytemuck::bytes_of(&quant.scale);
                 bytes.extend_from_byte_slice_aligned(scale_bytes, align_of::<f32>());
             }
@@ -57,6 +66,7 @@ impl QuantizedBytes {
     /// Returns the int8 quantized values with the quantization parameters.
     pub fn into_vec_i8(self) -> (Vec<i8>, QParams<Vec<f32>, Vec<i8>>) {
         let numel = self.num_elements;
+        let scheme = self.scheme;
         let (values, (qparams, num_params)) = self.split_values_off();
 
         let values = unpack_u32s_to_i8s(values, numel);
@@ -74,7 +84,15 @@ impl QuantizedBytes {
         let scales_size = scale_size * num_params;
 
         let scale = bytemuck::cast_slice(&qparams_bytes[total_bytes - scales_size..]).to_vec();
-        let offset = None;
+        let offset = match scheme.mode {
+            QuantMode::Symmetric => None,
+            QuantMode::Affine => {
+                // offset is stored as i32
+                let offsets: &[i32] =
+                    bytemuck::cast_slice(&qparams_bytes[..total_bytes - scales_size]);
+                Some(offsets.iter().map(|&offset| offset as i8).collect())
+            }
+        };
 
         (values, QParams { scale, offset })
     }
@@ -105,8 +123,12 @@ impl QuantizedBytes {
             QuantLevel::Tensor => 1,
         };
 
-        let scale_size = num_params; // f32 scale is the same number of bytes as u32
-        let values_end = values.len() - scale_size;
+        // f32 scale and i32 offset are the same number of bytes as u32
+        let qparams_size = match self.scheme.mode {
+            QuantMode::Symmetric => num_params,
+            QuantMode::Affine => 2 * num_params,
+        };
+        let values_end = values.len() - qparams_size;
 
         let qparams = values.split_off(values_end);
 
@@ -128,6 +150,20 @@ impl QuantizedBytes {
                 );
                 (strategy.dequantize(&values), qparams)
             }
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => {
+                let (values, qparams) = self.into_vec_i8();
+                let offset = qparams.offset.as_ref().unwrap()[0];
+                let strategy = QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
+                    qparams.scale[0],
+                    offset,
+                ));
+                (strategy.dequantize(&values), qparams)
+            }
         }
     }
 }
@@ -186,4 +222,24 @@ mod tests {
 
         assert_eq!(q_values, values);
     }
+
+    #[test]
+    fn should_pack_unpack_quantization_parameters_per_tensor_affine() {
+        // Quantized [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]
+        let scale = 0.019607844;
+        let offset = -128;
+        let values = vec![-128i8, -77, -26, 25, 76, 127];
+
+        let q_bytes = QuantizedBytes::new(
+            values.clone(),
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(scale, offset)),
+        );
+
+        let (q_values, qparams) = q_bytes.into_vec_i8();
+
+        assert_eq!(qparams.scale, vec![scale]);
+        assert_eq!(qparams.offset, Some(vec![offset]));
+
+        assert_eq!(q_values, values);
+    }
 }
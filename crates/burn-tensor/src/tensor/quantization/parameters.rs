@@ -4,7 +4,7 @@ use crate::{Int, Tensor, backend::Backend};
 pub type QuantizationParameters<B> = QParams<Tensor<B, 1>, Tensor<B, 1, Int>>;
 
 /// The quantization tensor data parameters.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct QParams<S, O> {
     /// The scaling factor.
     pub scale: S,
@@ -83,6 +83,11 @@ pub enum QuantInputType {
 pub enum QuantMode {
     /// Symmetric or scale quantization.
     Symmetric,
+    /// Affine or asymmetric quantization, with a zero-point offset.
+    ///
+    /// The whole quantized range covers the values between the minimum and the maximum, which is
+    /// more precise than the symmetric mode for one-sided ranges (e.g., after a ReLU).
+    Affine,
 }
 
 /// Quantization accumulator precision. This is the precision to used when accumulating values
@@ -161,6 +166,32 @@ impl QuantScheme {
                     offset: None,
                 }
             }
+            QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                ..
+            } => {
+                // Quantized range `[a, b]`
+                let a = i8::MIN as i32;
+                let b = i8::MAX as i32;
+
+                // The range is extended to contain 0, so that it is exactly representable
+                let min = range.min.clamp_max(0);
+                let max = range.max.clamp_min(0);
+
+                // Compute scale and offset to convert an input value in range `[min, max]`
+                let scale = max.sub(min.clone()).div_scalar(b - a);
+                // If the range is empty (most likely due to a tensor full of zeros), we arbitrarily
+                // adjust the scale to 0.1 to avoid division by zero, the offset then being `a`.
+                let scale = scale.clone().mask_fill(scale.equal_elem(0), 0.1);
+                let offset = min.div(scale.clone()).neg().add_scalar(a).round().int();
+
+                QuantizationParameters {
+                    scale,
+                    offset: Some(offset),
+                }
+            }
         }
     }
 
@@ -12,6 +12,8 @@ use super::{
 pub enum QuantizationStrategy {
     /// Per-tensor `int8` symmetric quantization.
     PerTensorSymmetricInt8(SymmetricQuantization<f32, i8>),
+    /// Per-tensor `int8` affine/asymmetric quantization.
+    PerTensorAffineInt8(AffineQuantization<f32, i8>),
 }
 
 impl QuantizationStrategy {
@@ -19,6 +21,7 @@ impl QuantizationStrategy {
     pub fn quantize(&self, values: &[f32]) -> Vec<i8> {
         match self {
             QuantizationStrategy::PerTensorSymmetricInt8(strategy) => strategy.quantize(values),
+            QuantizationStrategy::PerTensorAffineInt8(strategy) => strategy.quantize(values),
         }
     }
 
@@ -26,6 +29,7 @@ impl QuantizationStrategy {
     pub fn dequantize(&self, values: &[i8]) -> Vec<f32> {
         match self {
             QuantizationStrategy::PerTensorSymmetricInt8(strategy) => strategy.dequantize(values),
+            QuantizationStrategy::PerTensorAffineInt8(strategy) => strategy.dequantize(values),
         }
     }
 }
@@ -41,6 +45,13 @@ impl QuantizationStrategy {
                 acc_precision: QuantAccPrecision::Full,
                 propagation: QuantPropagation::Inhibit,
             },
+            QuantizationStrategy::PerTensorAffineInt8(_) => QuantScheme {
+                level: QuantLevel::Tensor,
+                mode: QuantMode::Affine,
+                q_type: QuantInputType::QInt8,
+                acc_precision: QuantAccPrecision::Full,
+                propagation: QuantPropagation::Inhibit,
+            },
         }
     }
 }
@@ -146,6 +157,82 @@ impl<E: Float + Send + Sync, Q: PrimInt + Signed + Send + Sync> PartialEq
 
 impl<E: Float + Send + Sync, Q: PrimInt + Signed + Send + Sync> Eq for SymmetricQuantization<E, Q> {}
 
+/// Affine quantization scheme.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AffineQuantization<E: Float + Send + Sync, Q: PrimInt + Send + Sync> {
+    /// The scaling factor.
+    pub scale: E,
+    /// The zero-point offset.
+    pub offset: Q,
+}
+
+impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> AffineQuantization<E, Q> {
+    /// Initialize an affine quantization scheme with the given parameters.
+    pub fn init(scale: E, offset: Q) -> Self {
+        Self {
+            scale: valid_scale(scale),
+            offset,
+        }
+    }
+}
+
+impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> Quantization<E, Q>
+    for AffineQuantization<E, Q>
+{
+    fn new(alpha: E, beta: E) -> Self {
+        let (a, b) = Self::range();
+        let a = E::from(a).unwrap();
+        let b = E::from(b).unwrap();
+
+        // We extend the `[alpha, beta]` interval to ensure that it contains 0.
+        // Otherwise, we would not meet the requirement that 0 be an exactly
+        // representable value (zero-point).
+        let alpha = alpha.min(E::zero());
+        let beta = beta.max(E::zero());
+
+        // Compute scale and offset to convert a floating point value in range `[alpha, beta]` to the quantized range
+        let scale = valid_scale((beta - alpha) / (b - a));
+        let offset = Q::from(a - (alpha / scale).round()).unwrap();
+        Self { scale, offset }
+    }
+
+    fn quantize(&self, values: &[E]) -> Vec<Q> {
+        values.iter().map(|x| self.quantize_one(*x)).collect()
+    }
+
+    fn dequantize(&self, values: &[Q]) -> Vec<E> {
+        values.iter().map(|x_q| self.dequantize_one(*x_q)).collect()
+    }
+
+    fn quantize_one(&self, value: E) -> Q {
+        let (a, b) = Self::range();
+        let a = E::from(a).unwrap();
+        let b = E::from(b).unwrap();
+
+        // x_q = clamp(round(x / scale + offset), a, b)
+        let z = E::from(self.offset).unwrap();
+        Q::from(value.div(self.scale).round().add(z).clamp(a, b)).unwrap()
+    }
+
+    fn dequantize_one(&self, value: Q) -> E {
+        // x = scale * (x_q - offset)
+        // The difference is computed in the floating point type to avoid overflowing `Q`
+        self.scale * (E::from(value).unwrap() - E::from(self.offset).unwrap())
+    }
+
+    fn range() -> (Q, Q) {
+        (Q::min_value(), Q::max_value())
+    }
+}
+
+impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> PartialEq for AffineQuantization<E, Q> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale && self.offset == other.offset
+    }
+}
+
+impl<E: Float + Send + Sync, Q: PrimInt + Send + Sync> Eq for AffineQuantization<E, Q> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,4 +253,20 @@ mod tests {
 
         assert_eq!(d, expected_d);
     }
+
+    #[test]
+    fn test_int8_affine_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![-128, -39, 72, 127];
+        let expected_d = vec![-1.8039216, -1.0011765, 0.0, 0.49607843];
+
+        let affine = AffineQuantization::<f32, i8>::new(-1.8, 0.5);
+
+        let q: Vec<i8> = affine.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = affine.dequantize(&expected_q);
+
+        assert_eq!(d, expected_d);
+    }
 }
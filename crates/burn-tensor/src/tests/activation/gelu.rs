@@ -21,4 +21,28 @@ mod tests {
             Tolerance::rel_abs(1e-5, 1e-3).set_half_precision_relative(1e-3),
         );
     }
+
+    #[test]
+    fn test_gelu_accuracy() {
+        // Sweep of [-10, 10], with the exact reference instead of the tanh approximation
+        let tensor = TestTensor::<1>::from([
+            -10.0, -9.5, -9.0, -8.5, -8.0, -7.5, -7.0, -6.5, -6.0, -5.5, -5.0, -4.5, -4.0, -3.5,
+            -3.0, -2.5, -2.0, -1.5, -1.0, -0.5, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5,
+            5.0, 5.5, 6.0, 6.5, 7.0, 7.5, 8.0, 8.5, 9.0, 9.5, 10.0,
+        ]);
+        let output = activation::gelu(tensor);
+        let expected = TensorData::from([
+            0.0, 0.0, 0.0, 0.0, -5e-15, -2e-13, -9e-12, -3e-10, -6e-9, -1e-7, -1.4e-6, -1.53e-5,
+            -1.267e-4, -8.142e-4, -0.0040497, -0.0155242, -0.0455003, -0.1002108, -0.1586553,
+            -0.1542688, 0.0, 0.345731, 0.841345, 1.39979, 1.9545, 2.484476, 2.99595, 3.499186,
+            3.99987, 4.499985, 5.0, 5.5, 6.0, 6.5, 7.0, 7.5, 8.0, 8.5, 9.0, 9.5, 10.0,
+        ]);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::rel_abs(1e-5, 1e-5)
+                .set_half_precision_relative(1e-2)
+                .set_half_precision_absolute(1e-3),
+        );
+    }
 }
@@ -118,6 +118,7 @@ macro_rules! testgen_quantization {
         burn_tensor::testgen_q_powf_scalar!();
         burn_tensor::testgen_q_powf!();
         burn_tensor::testgen_q_recip!();
+        burn_tensor::testgen_q_relu!();
         burn_tensor::testgen_q_remainder!();
         burn_tensor::testgen_q_repeat_dim!();
         burn_tensor::testgen_q_reshape!();
@@ -174,12 +175,19 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_adaptive_max_pool2d!();
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_grid_sample_nd!();
+        burn_tensor::testgen_module_flash_attn_varlen!();
+        burn_tensor::testgen_module_cross_entropy_with_label_smoothing!();
 
         // test ops
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_index_put!();
+        burn_tensor::testgen_index_reduce!();
+        burn_tensor::testgen_interpolate!();
         burn_tensor::testgen_narrow!();
         burn_tensor::testgen_add!();
         burn_tensor::testgen_aggregation!();
@@ -211,21 +219,25 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_neg!();
         burn_tensor::testgen_one_hot!();
         burn_tensor::testgen_powf_scalar!();
+        burn_tensor::testgen_prune!();
         burn_tensor::testgen_random!();
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat_dim!();
         burn_tensor::testgen_repeat!();
+        burn_tensor::testgen_repeat_interleave!();
         burn_tensor::testgen_reshape!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_sinh!();
         burn_tensor::testgen_slice!();
         burn_tensor::testgen_stack!();
         burn_tensor::testgen_sqrt!();
+        burn_tensor::testgen_norm!();
         burn_tensor::testgen_abs!();
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_tan!();
         burn_tensor::testgen_tanh!();
+        burn_tensor::testgen_to_device!();
         burn_tensor::testgen_transpose!();
         burn_tensor::testgen_tri!();
         burn_tensor::testgen_powf!();
@@ -234,6 +246,7 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_permute!();
         burn_tensor::testgen_movedim!();
         burn_tensor::testgen_flip!();
+        burn_tensor::testgen_pixel_shuffle!();
         burn_tensor::testgen_bool!();
         burn_tensor::testgen_argwhere_nonzero!();
         burn_tensor::testgen_sign!();
@@ -243,6 +256,7 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_topk!();
         burn_tensor::testgen_remainder!();
         burn_tensor::testgen_cartesian_grid!();
+        burn_tensor::testgen_pad_sequence!();
         burn_tensor::testgen_nan!();
         burn_tensor::testgen_round!();
         burn_tensor::testgen_floor!();
@@ -365,7 +379,11 @@ macro_rules! as_type {
 pub mod qtensor {
     use core::marker::PhantomData;
 
-    use crate::{Tensor, TensorData, backend::Backend, quantization::QuantScheme};
+    use crate::{
+        Tensor, TensorData,
+        backend::Backend,
+        quantization::{QuantMode, QuantScheme},
+    };
 
     pub struct QTensor<B: Backend, const D: usize> {
         b: PhantomData<B>,
@@ -383,5 +401,11 @@ pub mod qtensor {
             Tensor::from_floats(floats, &Default::default())
                 .quantize_dynamic(&QuantScheme::default())
         }
+
+        /// Creates a quantized int8 tensor from the floating point data using per-tensor affine quantization.
+        pub fn int8_affine<F: Into<TensorData>>(floats: F) -> Tensor<B, D> {
+            Tensor::from_floats(floats, &Default::default())
+                .quantize_dynamic(&QuantScheme::default().set_mode(QuantMode::Affine))
+        }
     }
 }
@@ -0,0 +1,127 @@
+#[burn_tensor_testgen::testgen(module_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{adaptive_max_pool2d, max_pool2d};
+    use burn_tensor::{Shape, Tensor, TensorData};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_adaptive_max_pool2d_simple() {
+        let test = AdaptiveMaxPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 8,
+            width: 6,
+            height_out: 4,
+            width_out: 4,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [7., 8., 10., 11.],
+                [19., 20., 22., 23.],
+                [31., 32., 34., 35.],
+                [43., 44., 46., 47.],
+            ],
+            [
+                [55., 56., 58., 59.],
+                [67., 68., 70., 71.],
+                [79., 80., 82., 83.],
+                [91., 92., 94., 95.],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_dyn_filter_size() {
+        let test = AdaptiveMaxPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 5,
+            width: 7,
+            height_out: 3,
+            width_out: 2,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [[10., 13.], [24., 27.], [31., 34.]],
+            [[45., 48.], [59., 62.], [66., 69.]],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_bigger_output() {
+        let test = AdaptiveMaxPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 4,
+            width: 3,
+            height_out: 5,
+            width_out: 4,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [0., 1., 2., 2.],
+                [3., 4., 5., 5.],
+                [6., 7., 8., 8.],
+                [9., 10., 11., 11.],
+                [9., 10., 11., 11.],
+            ],
+            [
+                [12., 13., 14., 14.],
+                [15., 16., 17., 17.],
+                [18., 19., 20., 20.],
+                [21., 22., 23., 23.],
+                [21., 22., 23., 23.],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_divisible_matches_max_pool2d() {
+        let x = TestTensor::<4>::from([[[
+            [1., 9., 3., 4., -2., 0.],
+            [5., 2., 8., 7., 6., -1.],
+            [0., 3., 2., 11., 4., 5.],
+            [4., 10., -3., 1., 2., 12.],
+        ]]]);
+
+        let output = adaptive_max_pool2d(x.clone(), [2, 3]);
+        let output_max_pool = max_pool2d(x, [2, 2], [2, 2], [0, 0], [1, 1]);
+
+        let expected = TensorData::from([[[[9., 8., 6.], [10., 11., 12.]]]]);
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&output_max_pool.into_data(), Tolerance::default());
+    }
+
+    struct AdaptiveMaxPool2dTestCase {
+        batch_size: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        height_out: usize,
+        width_out: usize,
+    }
+
+    impl AdaptiveMaxPool2dTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape::<4, _>(shape_x)
+                    .into_data(),
+            );
+            let output = adaptive_max_pool2d(x, [self.height_out, self.width_out]);
+
+            y.to_data()
+                .assert_approx_eq::<FT>(&output.into_data(), Tolerance::default());
+        }
+    }
+}
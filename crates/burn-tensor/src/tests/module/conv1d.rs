@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(module_conv1d)]
 mod tests {
     use super::*;
-    use burn_tensor::module::conv1d;
+    use burn_tensor::module::{conv1d, conv1d_with_padding};
     use burn_tensor::ops::ConvOptions;
     use burn_tensor::{Shape, Tensor};
     use burn_tensor::{Tolerance, ops::FloatElem};
@@ -90,6 +90,29 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_conv1d_asymmetric_padding() {
+        let test = Conv1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 0,
+            stride: 1,
+            dilation: 2,
+            groups: 1,
+            length: 4,
+        };
+
+        test.assert_output_with_padding(
+            [(2, 1)],
+            TestTensor::from([
+                [[50., 62., 38.], [123., 159., 111.]],
+                [[146., 158., 102.], [411., 447., 367.]],
+            ]),
+        );
+    }
+
     struct Conv1dTestCase {
         batch_size: usize,
         channels_in: usize,
@@ -104,6 +127,24 @@ mod tests {
 
     impl Conv1dTestCase {
         fn assert_output(self, y: TestTensor<3>) {
+            let (x, weight, bias) = self.inputs();
+            let output = conv1d(x, weight, Some(bias), self.options());
+
+            let tolerance = Tolerance::relative(1e-5).set_half_precision_relative(1e-3);
+            y.to_data()
+                .assert_approx_eq::<FT>(&output.into_data(), tolerance);
+        }
+
+        fn assert_output_with_padding(self, padding: [(usize, usize); 1], y: TestTensor<3>) {
+            let (x, weight, bias) = self.inputs();
+            let output = conv1d_with_padding(x, weight, Some(bias), padding, self.options());
+
+            let tolerance = Tolerance::relative(1e-5).set_half_precision_relative(1e-3);
+            y.to_data()
+                .assert_approx_eq::<FT>(&output.into_data(), tolerance);
+        }
+
+        fn inputs(&self) -> (TestTensor<3>, TestTensor<3>, TestTensor<1>) {
             let shape_x = Shape::new([self.batch_size, self.channels_in, self.length]);
             let shape_weight = Shape::new([
                 self.channels_out,
@@ -127,16 +168,12 @@ mod tests {
                     .into_data(),
                 &device,
             );
-            let output = conv1d(
-                x,
-                weight,
-                Some(bias),
-                ConvOptions::new([self.stride], [self.padding], [self.dilation], self.groups),
-            );
 
-            let tolerance = Tolerance::relative(1e-5).set_half_precision_relative(1e-3);
-            y.to_data()
-                .assert_approx_eq::<FT>(&output.into_data(), tolerance);
+            (x, weight, bias)
+        }
+
+        fn options(&self) -> ConvOptions<1> {
+            ConvOptions::new([self.stride], [self.padding], [self.dilation], self.groups)
         }
     }
 }
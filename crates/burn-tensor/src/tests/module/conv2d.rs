@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(module_conv2d)]
 mod tests {
     use super::*;
-    use burn_tensor::module::conv2d;
+    use burn_tensor::module::{conv2d, conv2d_with_padding};
     use burn_tensor::ops::ConvOptions;
     use burn_tensor::{Shape, Tensor};
     use burn_tensor::{Tolerance, ops::FloatElem};
@@ -387,6 +387,69 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn test_conv2d_groups_dilation() {
+        let test = Conv2dTestCase {
+            batch_size: 1,
+            channels_in: 4,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            padding_1: 1,
+            padding_2: 1,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 2,
+            dilation_2: 2,
+            groups: 2,
+            height: 4,
+            width: 5,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [200., 370., 388., 406., 184.],
+                [356., 652., 680., 708., 316.],
+                [436., 792., 820., 848., 376.],
+                [166., 292., 302., 312., 132.],
+            ],
+            [
+                [1497., 2883., 2933., 2983., 1433.],
+                [2789., 5357., 5449., 5541., 2653.],
+                [3029., 5817., 5909., 6001., 2873.],
+                [1383., 2645., 2687., 2729., 1301.],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv2d_asymmetric_padding() {
+        let test = Conv2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            padding_1: 0,
+            padding_2: 0,
+            stride_1: 1,
+            stride_2: 2,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 1,
+            height: 3,
+            width: 4,
+        };
+
+        test.assert_output_with_padding(
+            [(1, 0), (0, 2)],
+            TestTensor::from([[
+                [[406., 294.], [854., 590.], [1118., 750.]],
+                [[911., 703.], [2151., 1599.], [2991., 2143.]],
+            ]]),
+        );
+    }
+
     struct Conv2dTestCase {
         batch_size: usize,
         channels_in: usize,
@@ -406,6 +469,22 @@ mod tests {
 
     impl Conv2dTestCase {
         fn assert_output(self, y: TestTensor<4>) {
+            let (x, weight, bias) = self.inputs();
+            let output = conv2d(x, weight, Some(bias), self.options());
+
+            y.to_data()
+                .assert_approx_eq::<FT>(&output.into_data(), Tolerance::default());
+        }
+
+        fn assert_output_with_padding(self, padding: [(usize, usize); 2], y: TestTensor<4>) {
+            let (x, weight, bias) = self.inputs();
+            let output = conv2d_with_padding(x, weight, Some(bias), padding, self.options());
+
+            y.to_data()
+                .assert_approx_eq::<FT>(&output.into_data(), Tolerance::default());
+        }
+
+        fn inputs(&self) -> (TestTensor<4>, TestTensor<4>, TestTensor<1>) {
             let shape_x = Shape::new([self.batch_size, self.channels_in, self.height, self.width]);
             let shape_weight = Shape::new([
                 self.channels_out,
@@ -427,20 +506,17 @@ mod tests {
                     .reshape::<4, _>(shape_x)
                     .into_data(),
             );
-            let output = conv2d(
-                x,
-                weight,
-                Some(bias),
-                ConvOptions::new(
-                    [self.stride_1, self.stride_2],
-                    [self.padding_1, self.padding_2],
-                    [self.dilation_1, self.dilation_2],
-                    self.groups,
-                ),
-            );
 
-            y.to_data()
-                .assert_approx_eq::<FT>(&output.into_data(), Tolerance::default());
+            (x, weight, bias)
+        }
+
+        fn options(&self) -> ConvOptions<2> {
+            ConvOptions::new(
+                [self.stride_1, self.stride_2],
+                [self.padding_1, self.padding_2],
+                [self.dilation_1, self.dilation_2],
+                self.groups,
+            )
         }
     }
 }
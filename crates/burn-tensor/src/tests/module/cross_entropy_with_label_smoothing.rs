@@ -0,0 +1,71 @@
+#[burn_tensor_testgen::testgen(module_cross_entropy_with_label_smoothing)]
+mod tests {
+    use super::*;
+    use burn_tensor::activation::log_softmax;
+    use burn_tensor::module::cross_entropy_with_label_smoothing;
+    use burn_tensor::{Tensor, TensorData, Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    fn logits() -> TestTensor<2> {
+        TestTensor::from([
+            [0.2, -1.3, 2.1, 0.4],
+            [1.5, 0.3, -0.7, -2.2],
+            [-0.4, 0.9, 0.1, 1.8],
+        ])
+    }
+
+    fn targets() -> TestTensorInt<1> {
+        TestTensorInt::from([2, 0, 1])
+    }
+
+    #[test]
+    fn test_without_smoothing_matches_cross_entropy() {
+        let logits = logits();
+
+        let output = cross_entropy_with_label_smoothing(logits.clone(), targets(), 0.0);
+
+        let expected = log_softmax(logits, 1)
+            .gather(1, targets().reshape([3, 1]))
+            .reshape([3])
+            .neg();
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_matches_smoothed_targets() {
+        let smoothing = 0.1;
+        let logits = logits();
+
+        let output = cross_entropy_with_label_smoothing(logits.clone(), targets(), smoothing);
+
+        let one_hot = TestTensor::<2>::from([
+            [0.0, 0.0, 1.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+        ]);
+        let smoothed_targets = one_hot
+            .mul_scalar(1.0 - smoothing)
+            .add_scalar(smoothing / 4.0);
+        let expected = (log_softmax(logits, 1) * smoothed_targets)
+            .sum_dim(1)
+            .reshape([3])
+            .neg();
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn test_large_logits_are_stable() {
+        let logits = TestTensor::<2>::from([[1000.0, 0.0, -1000.0]]);
+        let targets = TestTensorInt::<1>::from([0]);
+
+        let output = cross_entropy_with_label_smoothing(logits, targets, 0.0);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0]), Tolerance::default());
+    }
+}
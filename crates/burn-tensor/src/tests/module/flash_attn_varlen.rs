@@ -0,0 +1,237 @@
+#[burn_tensor_testgen::testgen(module_flash_attn_varlen)]
+mod tests {
+    use super::*;
+    use burn_tensor::activation::softmax;
+    use burn_tensor::module::flash_attn_varlen;
+    use burn_tensor::{Tensor, TensorData, Tolerance, backend::Backend, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_flash_attn_varlen_matches_padded_attention() {
+        let test = FlashAttnVarlenTestCase {
+            seqlens_q: vec![3, 1, 4],
+            seqlens_k: vec![3, 1, 4],
+            head_dim: 4,
+            value_dim: 3,
+            softmax_scale: None,
+            causal: false,
+        };
+
+        test.assert_output();
+    }
+
+    #[test]
+    fn test_flash_attn_varlen_different_key_lengths() {
+        let test = FlashAttnVarlenTestCase {
+            seqlens_q: vec![2, 3, 1],
+            seqlens_k: vec![5, 3, 4],
+            head_dim: 3,
+            value_dim: 2,
+            softmax_scale: None,
+            causal: false,
+        };
+
+        test.assert_output();
+    }
+
+    #[test]
+    fn test_flash_attn_varlen_causal() {
+        let test = FlashAttnVarlenTestCase {
+            seqlens_q: vec![3, 1, 4],
+            seqlens_k: vec![3, 1, 4],
+            head_dim: 4,
+            value_dim: 3,
+            softmax_scale: None,
+            causal: true,
+        };
+
+        test.assert_output();
+    }
+
+    #[test]
+    fn test_flash_attn_varlen_causal_different_key_lengths() {
+        let test = FlashAttnVarlenTestCase {
+            seqlens_q: vec![2, 3, 1],
+            seqlens_k: vec![5, 3, 4],
+            head_dim: 3,
+            value_dim: 2,
+            softmax_scale: None,
+            causal: true,
+        };
+
+        test.assert_output();
+    }
+
+    #[test]
+    fn test_flash_attn_varlen_softmax_scale() {
+        let test = FlashAttnVarlenTestCase {
+            seqlens_q: vec![2, 4],
+            seqlens_k: vec![3, 4],
+            head_dim: 2,
+            value_dim: 2,
+            softmax_scale: Some(1.5),
+            causal: false,
+        };
+
+        test.assert_output();
+    }
+
+    #[test]
+    fn test_flash_attn_varlen_single_key_returns_value() {
+        let device = Default::default();
+        let q = TestTensor::<2>::from_floats([[1.0, 2.0], [-3.0, 0.5], [0.0, 1.0]], &device);
+        let k = TestTensor::<2>::from_floats([[0.5, -1.0], [2.0, 1.0]], &device);
+        let v = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let cu_seqlens_q = TestTensorInt::<1>::from_ints([0, 2, 3], &device);
+        let cu_seqlens_k = TestTensorInt::<1>::from_ints([0, 1, 2], &device);
+
+        let output = flash_attn_varlen(q, k, v, cu_seqlens_q, cu_seqlens_k, 2, 1, 0.0, None, false);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_flash_attn_varlen_mismatched_sequences() {
+        let device = Default::default();
+        let q = TestTensor::<2>::zeros([4, 2], &device);
+        let k = TestTensor::<2>::zeros([4, 2], &device);
+        let v = TestTensor::<2>::zeros([4, 2], &device);
+        let cu_seqlens_q = TestTensorInt::<1>::from_ints([0, 2, 4], &device);
+        let cu_seqlens_k = TestTensorInt::<1>::from_ints([0, 4], &device);
+
+        let _output =
+            flash_attn_varlen(q, k, v, cu_seqlens_q, cu_seqlens_k, 2, 4, 0.0, None, false);
+    }
+
+    struct FlashAttnVarlenTestCase {
+        seqlens_q: Vec<usize>,
+        seqlens_k: Vec<usize>,
+        head_dim: usize,
+        value_dim: usize,
+        softmax_scale: Option<f64>,
+        causal: bool,
+    }
+
+    impl FlashAttnVarlenTestCase {
+        fn assert_output(self) {
+            let device = Default::default();
+            let total_q = self.seqlens_q.iter().sum::<usize>();
+            let total_k = self.seqlens_k.iter().sum::<usize>();
+            let max_q = *self.seqlens_q.iter().max().unwrap();
+            let max_k = *self.seqlens_k.iter().max().unwrap();
+
+            let q = Self::packed([total_q, self.head_dim], 0.37);
+            let k = Self::packed([total_k, self.head_dim], 0.61);
+            let v = Self::packed([total_k, self.value_dim], 0.23);
+
+            let output = flash_attn_varlen(
+                q.clone(),
+                k.clone(),
+                v.clone(),
+                Self::cumulative(&self.seqlens_q),
+                Self::cumulative(&self.seqlens_k),
+                max_q,
+                max_k,
+                0.0,
+                self.softmax_scale,
+                self.causal,
+            );
+
+            let expected = self.padded_attention(q, k, v, max_q, max_k, &device);
+
+            assert_eq!(output.dims(), [total_q, self.value_dim]);
+            output
+                .into_data()
+                .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::default());
+        }
+
+        /// Reference attention computed on the zero-padded batch, with padded keys masked out.
+        fn padded_attention(
+            &self,
+            q: TestTensor<2>,
+            k: TestTensor<2>,
+            v: TestTensor<2>,
+            max_q: usize,
+            max_k: usize,
+            device: &<TestBackend as Backend>::Device,
+        ) -> TestTensor<2> {
+            let num_seqs = self.seqlens_q.len();
+            let q = Self::pad(q, &self.seqlens_q, max_q);
+            let k = Self::pad(k, &self.seqlens_k, max_k);
+            let v = Self::pad(v, &self.seqlens_k, max_k);
+
+            let mut mask = Vec::with_capacity(num_seqs * max_q * max_k);
+            for (&len_q, &len_k) in self.seqlens_q.iter().zip(&self.seqlens_k) {
+                for i in 0..max_q {
+                    for j in 0..max_k {
+                        let masked = j >= len_k || (self.causal && j + len_q > i + len_k);
+                        mask.push(masked);
+                    }
+                }
+            }
+            let mask = TestTensorBool::<3>::from_data(
+                TensorData::new(mask, [num_seqs, max_q, max_k]),
+                device,
+            );
+
+            let scale = self
+                .softmax_scale
+                .unwrap_or(1.0 / (self.head_dim as f64).sqrt());
+            let scores = q.matmul(k.swap_dims(1, 2)).mul_scalar(scale);
+            let weights = softmax(scores.mask_fill(mask, f32::NEG_INFINITY), 2);
+            let output = weights.matmul(v);
+
+            // Drop the padded queries
+            let outputs = self
+                .seqlens_q
+                .iter()
+                .enumerate()
+                .map(|(i, &len)| {
+                    output
+                        .clone()
+                        .slice([i..i + 1, 0..len, 0..self.value_dim])
+                        .reshape([len, self.value_dim])
+                })
+                .collect();
+            Tensor::cat(outputs, 0)
+        }
+
+        fn pad(tensor: TestTensor<2>, seqlens: &[usize], max_len: usize) -> TestTensor<3> {
+            let [_, dim] = tensor.dims();
+            let mut start = 0;
+            let sequences = seqlens
+                .iter()
+                .map(|&len| {
+                    let sequence = tensor.clone().slice([start..start + len, 0..dim]);
+                    start += len;
+                    TestTensor::<2>::zeros([max_len, dim], &tensor.device())
+                        .slice_assign([0..len, 0..dim], sequence)
+                })
+                .collect();
+            Tensor::stack(sequences, 0)
+        }
+
+        fn packed(shape: [usize; 2], frequency: f64) -> TestTensor<2> {
+            TestTensorInt::<1>::arange(0..(shape[0] * shape[1]) as i64, &Default::default())
+                .float()
+                .mul_scalar(frequency)
+                .sin()
+                .reshape(shape)
+        }
+
+        fn cumulative(seqlens: &[usize]) -> TestTensorInt<1> {
+            let mut cu_seqlens = vec![0];
+            for len in seqlens {
+                cu_seqlens.push(cu_seqlens.last().unwrap() + *len as i64);
+            }
+            TestTensorInt::<1>::from_data(
+                TensorData::new(cu_seqlens, [seqlens.len() + 1]),
+                &Default::default(),
+            )
+        }
+    }
+}
@@ -0,0 +1,114 @@
+#[burn_tensor_testgen::testgen(module_grid_sample_nd)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_nd;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_grid_sample_nd_integer_coordinates_2d() {
+        let device = Default::default();
+        let input = TestTensor::<4>::from_floats(
+            [[
+                [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+                [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+            ]],
+            &device,
+        );
+        // (x, y) in [-1, 1] landing on the elements (row 1, col 2), (row 0, col 0) and (row 1, col 1)
+        let grid =
+            TestTensor::<4>::from_floats([[[[1.0, 1.0], [-1.0, -1.0], [0.0, 1.0]]]], &device);
+
+        let output = grid_sample_nd(input, grid);
+
+        output.into_data().assert_eq(
+            &TestTensor::<4>::from_floats([[[[5.0, 0.0, 4.0]], [[11.0, 6.0, 10.0]]]], &device)
+                .into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_nd_integer_coordinates_3d() {
+        let device = Default::default();
+        let [depth, height, width] = [2, 3, 5];
+        let input = TestTensorInt::<1>::arange(0..(depth * height * width) as i64, &device)
+            .float()
+            .reshape([1, 1, depth, height, width]);
+
+        // Every element of the input, indexed as (x, y, z) normalized to [-1, 1]
+        let mut points = Vec::new();
+        let mut expected = Vec::new();
+        for z in 0..depth {
+            for y in 0..height {
+                for x in 0..width {
+                    let normalize =
+                        |i: usize, size: usize| 2.0 * i as f32 / (size - 1) as f32 - 1.0;
+                    points.extend([
+                        normalize(x, width),
+                        normalize(y, height),
+                        normalize(z, depth),
+                    ]);
+                    expected.push(((z * height + y) * width + x) as f32);
+                }
+            }
+        }
+        let num_points = expected.len();
+        let grid = TestTensor::<1>::from_floats(points.as_slice(), &device)
+            .reshape([1, 1, 1, num_points, 3]);
+
+        let output = grid_sample_nd(input, grid);
+
+        output.into_data().assert_eq(
+            &TestTensor::<1>::from_floats(expected.as_slice(), &device)
+                .reshape([1, 1, 1, 1, num_points])
+                .into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_nd_trilinear() {
+        let device = Default::default();
+        // f(z, y, x) = x + 2y + 4z on a 2x2x2 grid, which is reproduced exactly by trilinear interpolation
+        let input = TestTensor::<5>::from_floats(
+            [[[[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]]]],
+            &device,
+        );
+        let grid = TestTensor::<5>::from_floats(
+            [[[[[0.0, 0.0, 0.0], [-0.5, 0.5, 1.0], [1.0, -1.0, -0.5]]]]],
+            &device,
+        );
+
+        let output = grid_sample_nd(input, grid);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TestTensor::<5>::from_floats([[[[[3.5, 5.75, 2.0]]]]], &device).into_data(),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_nd_clamps_out_of_bounds() {
+        let device = Default::default();
+        let input = TestTensor::<3>::from_floats([[[1.0, 2.0, 4.0]]], &device);
+        let grid = TestTensor::<3>::from_floats([[[-3.0], [0.5], [2.0]]], &device);
+
+        let output = grid_sample_nd(input, grid);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TestTensor::<3>::from_floats([[[1.0, 3.0, 4.0]]], &device).into_data(),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_sample_nd_invalid_grid() {
+        let device = Default::default();
+        let input = TestTensor::<4>::zeros([1, 1, 2, 2], &device);
+        let grid = TestTensor::<4>::zeros([1, 2, 2, 3], &device);
+
+        let _ = grid_sample_nd(input, grid);
+    }
+}
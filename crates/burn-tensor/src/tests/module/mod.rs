@@ -1,5 +1,6 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
 mod avgpool1d;
 mod avgpool2d;
 mod bicubic_interpolate;
@@ -10,8 +11,11 @@ mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod conv_transpose3d;
+mod cross_entropy_with_label_smoothing;
 mod deform_conv2d;
+mod flash_attn_varlen;
 mod forward;
+mod grid_sample_nd;
 mod maxpool1d;
 mod maxpool2d;
 mod nearest_interpolate;
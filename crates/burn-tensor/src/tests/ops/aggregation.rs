@@ -231,6 +231,86 @@ mod tests {
             .assert_eq(&TensorData::from([[0], [60]]), false);
     }
 
+    #[test]
+    fn test_prod_dims_float() {
+        let tensor = TestTensor::<2>::from([[2.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.clone().prod_dims::<2>(&[0], true);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[6.0, 4.0, 10.0]]), Tolerance::default());
+
+        let output = tensor.clone().prod_dims::<1>(&[0], false);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([6.0, 4.0, 10.0]), Tolerance::default());
+
+        let output = tensor.clone().prod_dims::<2>(&[1], true);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[4.0], [60.0]]), Tolerance::default());
+
+        let output = tensor.prod_dims::<1>(&[1], false);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([4.0, 60.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn test_prod_dims_empty_dim_is_one() {
+        let tensor = TestTensor::<2>::empty([2, 0], &Default::default());
+
+        let output = tensor.clone().prod_dims::<1>(&[1], false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 1.0]), false);
+
+        let output = tensor.prod_dims::<2>(&[0], true);
+        assert_eq!(output.shape(), Shape::new([1, 0]));
+    }
+
+    #[test]
+    fn test_count_nonzero() {
+        let tensor = TestTensor::<2>::from([[1.0, 0.0, 3.0], [0.0, 0.0, -6.0]]);
+
+        let output = tensor.clone().count_nonzero();
+        output.into_data().assert_eq(&TensorData::from([3]), false);
+
+        let output = tensor.count_nonzero_dim(0);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0, 2]]), false);
+    }
+
+    #[test]
+    fn test_count_nonzero_int() {
+        let tensor = TestTensorInt::<2>::from([[0, 2, 0], [4, 5, 0]]);
+
+        let output = tensor.clone().count_nonzero();
+        output.into_data().assert_eq(&TensorData::from([3]), false);
+
+        let output = tensor.count_nonzero_dim(1);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [2]]), false);
+    }
+
+    #[test]
+    #[ignore = "Not implemented for all backends yet"]
+    fn test_prod_dims_int() {
+        let tensor = TestTensorInt::<2>::from([[2, 1, 2], [3, 4, 5]]);
+
+        let output = tensor.clone().prod_dims::<1>(&[0], false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([6, 4, 10]), false);
+
+        let output = tensor.prod_dims::<2>(&[1], true);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[4], [60]]), false);
+    }
+
     #[test]
     fn test_sum_dim_2d() {
         let tensor =
@@ -69,4 +69,33 @@ mod tests {
 
         TensorData::from([true]).assert_eq(&all.clone().into_data(), false);
     }
+
+    #[test]
+    fn test_all_dims() {
+        let tensor = TestTensorBool::<2>::from([
+            [true, true, true],
+            [true, false, true],
+            [false, false, false],
+        ]);
+
+        let data_actual = tensor.clone().all_dims::<1>(&[1], false).into_data();
+        let data_expected = TensorData::from([true, false, false]);
+        data_expected.assert_eq(&data_actual, false);
+
+        let data_actual = tensor.clone().all_dims::<2>(&[1], true).into_data();
+        let data_expected = TensorData::from([[true], [false], [false]]);
+        data_expected.assert_eq(&data_actual, false);
+
+        let data_actual = tensor.all_dims::<2>(&[0, 1], true).into_data();
+        let data_expected = TensorData::from([[false]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_all_dims_is_empty() {
+        let tensor = TestTensorBool::<2>::from([[true, false]]);
+
+        let _ = tensor.all_dims::<2>(&[], true);
+    }
 }
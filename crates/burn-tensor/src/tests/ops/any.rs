@@ -58,4 +58,33 @@ mod tests {
         let data_expected = TensorData::from([[false], [true]]);
         data_expected.assert_eq(&data_actual, false);
     }
+
+    #[test]
+    fn test_any_dims() {
+        let tensor = TestTensorBool::<2>::from([
+            [false, false, false],
+            [true, false, false],
+            [false, true, true],
+        ]);
+
+        let data_actual = tensor.clone().any_dims::<1>(&[1], false).into_data();
+        let data_expected = TensorData::from([false, true, true]);
+        data_expected.assert_eq(&data_actual, false);
+
+        let data_actual = tensor.clone().any_dims::<2>(&[1], true).into_data();
+        let data_expected = TensorData::from([[false], [true], [true]]);
+        data_expected.assert_eq(&data_actual, false);
+
+        let data_actual = tensor.any_dims::<2>(&[0, 1], true).into_data();
+        let data_expected = TensorData::from([[true]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_any_dims_is_empty() {
+        let tensor = TestTensorBool::<2>::from([[true, false]]);
+
+        let _ = tensor.any_dims::<2>(&[], true);
+    }
 }
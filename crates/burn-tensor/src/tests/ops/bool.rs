@@ -36,4 +36,78 @@ mod tests {
         let data_expected = TensorData::from([[true, true, false], [true, false, true]]);
         data_expected.assert_eq(&data_actual, false);
     }
+
+    #[test]
+    fn test_bool_xor() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, false, true]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, true, false], [false, false, true]]);
+        let data_actual = tensor1.bool_xor(tensor2).into_data();
+        let data_expected = TensorData::from([[true, false, false], [true, false, false]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
+
+    #[test]
+    fn test_logical_and() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, false, true]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, true, false], [false, false, true]]);
+        let data_actual = tensor1.logical_and(tensor2).into_data();
+        let data_expected = TensorData::from([[false, true, false], [false, false, true]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
+
+    #[test]
+    fn test_logical_or() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, false, true]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, true, false], [false, false, true]]);
+        let data_actual = tensor1.logical_or(tensor2).into_data();
+        let data_expected = TensorData::from([[true, true, false], [true, false, true]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
+
+    #[test]
+    fn test_logical_xor() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, false, true]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, true, false], [false, false, true]]);
+        let data_actual = tensor1.logical_xor(tensor2).into_data();
+        let data_expected = TensorData::from([[true, false, false], [true, false, false]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
+
+    #[test]
+    fn test_logical_not() {
+        let tensor = TestTensorBool::<2>::from([[false, true, false], [true, false, true]]);
+        let data_actual = tensor.logical_not().into_data();
+        let data_expected = TensorData::from([[true, false, true], [false, true, false]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
+
+    #[test]
+    fn test_logical_masks_with_mask_where() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]]);
+        let zeros = TestTensor::<2>::zeros([2, 3], &Default::default());
+
+        // Keep positive values that are lower than 4, or any negative value in the first column.
+        let positive = tensor.clone().greater_elem(0.0);
+        let small = tensor.clone().lower_elem(4.0);
+        let first_col = TestTensorBool::<2>::from([[true, false, false], [true, false, false]]);
+        let mask = positive
+            .clone()
+            .logical_and(small)
+            .logical_or(first_col.logical_and(positive.logical_not()));
+
+        let data_actual = zeros.mask_where(mask, tensor).into_data();
+        let data_expected = TensorData::from([[1.0, 0.0, 3.0], [-4.0, 0.0, 0.0]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
+
+    #[test]
+    fn test_logical_xor_mask_fill() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+        let mask1 = TestTensorBool::<2>::from([[true, true, false], [false, true, false]]);
+        let mask2 = TestTensorBool::<2>::from([[true, false, false], [true, true, false]]);
+
+        let data_actual = tensor.mask_fill(mask1.logical_xor(mask2), 0).into_data();
+        let data_expected = TensorData::from([[1, 0, 3], [0, 5, 6]]);
+        data_expected.assert_eq(&data_actual, false);
+    }
 }
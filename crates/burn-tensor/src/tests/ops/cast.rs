@@ -13,6 +13,14 @@ mod tests {
         tensor.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn cast_float_to_int_truncates_toward_zero() {
+        let tensor = TestTensor::<1>::from([-2.7, -0.5, 0.5, 2.7]).int();
+        let expected = TensorData::from([-2, 0, 0, 2]);
+
+        tensor.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn cast_int_to_float_tensor() {
         let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]).float();
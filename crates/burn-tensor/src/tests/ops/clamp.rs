@@ -2,6 +2,8 @@
 mod tests {
     use super::*;
     use burn_tensor::{Int, Tensor, TensorData};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
 
     #[test]
     fn clamp_min() {
@@ -81,4 +83,67 @@ mod tests {
             false,
         );
     }
+
+    #[test]
+    fn clamp_should_propagate_nan() {
+        let tensor = TestTensor::<2>::from([[f32::NAN, -2.0, 0.5], [3.0, f32::NAN, 1.0]]);
+
+        let output = tensor.clamp(-1.0, 1.0);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[f32::NAN, -1.0, 0.5], [1.0, f32::NAN, 1.0]]),
+            Tolerance::default(),
+        );
+    }
+
+    #[test]
+    fn clamp_nan_to_should_replace_nan() {
+        let tensor = TestTensor::<2>::from([[f32::NAN, -2.0, 0.5], [3.0, f32::NAN, 1.0]]);
+
+        let output = tensor.clamp_nan_to(-1.0, 1.0, 0.0);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0.0, -1.0, 0.5], [1.0, 0.0, 1.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn clamp_should_propagate_nan_large_tensor() {
+        // Large enough for backends to use vectorized kernels
+        let values: Vec<f32> = (0..64)
+            .map(|i| match i % 5 {
+                0 => f32::NAN,
+                _ => (i - 32) as f32 / 8.0,
+            })
+            .collect();
+        let tensor = TestTensor::<1>::from(TensorData::new(values.clone(), [64]));
+
+        let output = tensor.clone().clamp(-1.0, 1.0);
+        let expected: Vec<f32> = values
+            .iter()
+            .map(|value| match value.is_nan() {
+                true => f32::NAN,
+                false => value.clamp(-1.0, 1.0),
+            })
+            .collect();
+
+        output.into_data().assert_approx_eq::<FT>(
+            &TensorData::new(expected.clone(), [64]),
+            Tolerance::default(),
+        );
+
+        let output = tensor.clamp_nan_to(-1.0, 1.0, 0.0);
+        let expected: Vec<f32> = expected
+            .into_iter()
+            .map(|value| match value.is_nan() {
+                true => 0.0,
+                false => value,
+            })
+            .collect();
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::new(expected, [64]), Tolerance::default());
+    }
 }
@@ -33,4 +33,29 @@ mod tests {
             .into_data()
             .assert_approx_eq::<FT>(&expected, Tolerance::default());
     }
+
+    #[test]
+    fn erf_accuracy() {
+        // Sweep of [-10, 10], and of small inputs where the relative error matters
+        let tensor = TestTensor::<1>::from([
+            -10.0, -9.5, -9.0, -8.5, -8.0, -7.5, -7.0, -6.5, -6.0, -5.5, -5.0, -4.5, -4.0, -3.5,
+            -3.0, -2.5, -2.0, -1.5, -1.0, -0.5, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5,
+            5.0, 5.5, 6.0, 6.5, 7.0, 7.5, 8.0, 8.5, 9.0, 9.5, 10.0, 1e-4, 1e-3, 1e-2,
+        ]);
+
+        let output = tensor.erf();
+        let expected = TensorData::from([
+            -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0, -1.0,
+            -0.999978, -0.999593, -0.995322, -0.966105, -0.8427, -0.5205, 0.0, 0.5205, 0.8427,
+            0.966105, 0.995322, 0.999593, 0.999978, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+            1.0, 1.0, 1.0, 1.0, 1.0, 1.12838e-4, 0.00112838, 0.0112834,
+        ]);
+
+        output.into_data().assert_approx_eq::<FT>(
+            &expected,
+            Tolerance::rel_abs(1e-5, 1e-6)
+                .set_half_precision_relative(1e-2)
+                .set_half_precision_absolute(1e-3),
+        );
+    }
 }
@@ -122,6 +122,48 @@ mod tests {
         let _expanded_tensor = tensor.expand([-1, 3]);
     }
 
+    #[test]
+    fn broadcast_to_2d() {
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0]], &Default::default());
+        let output = tensor.broadcast_to([4, 3]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [1.0, 2.0, 3.0],
+                [1.0, 2.0, 3.0],
+                [1.0, 2.0, 3.0],
+                [1.0, 2.0, 3.0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn broadcast_to_new_leading_dim_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2]);
+        let output = tensor.broadcast_to([2, 1, 2]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[1, 2]], [[1, 2]]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_broadcast_to_incompatible_dim() {
+        let tensor =
+            TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &Default::default());
+        let _output = tensor.broadcast_to([4, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_broadcast_to_smaller_dim() {
+        // Unlike expand, a dimension can't be broadcasted down to size one
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &Default::default());
+        let _output = tensor.broadcast_to([2, 1]);
+    }
+
     /// Regression test for https://github.com/tracel-ai/burn/issues/2091
     #[test]
     fn inplace_op_after_expand() {
@@ -91,6 +91,53 @@ mod tests {
             .assert_eq(&TensorData::from([[1.0], [5.0]]), false);
     }
 
+    #[test]
+    fn should_gather_2d_dim0_smaller_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[1, 0], [1, 1], [0, 1]], &device);
+
+        let output = tensor.gather(0, indices);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[3.0, 1.0], [3.0, 4.0], [0.0, 4.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_gather_2d_dim1_smaller_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[2, 0, 2, 1]], &device);
+
+        let output = tensor.gather(1, indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 0.0, 2.0, 1.0]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_should_panic_on_larger_indices_shape() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[0], [1], [0]], &device);
+
+        let _output = tensor.gather(1, indices);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_should_panic_on_invalid_dim() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 1, 0], [1, 0, 1]], &device);
+
+        let _output = tensor.gather(2, indices);
+    }
+
     #[test]
     fn should_scatter_1d() {
         let device = Default::default();
@@ -0,0 +1,104 @@
+#[burn_tensor_testgen::testgen(index_put)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_index_put_2d() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 2], [1, 0], [1, 2]], &device);
+        let values = TestTensor::from_floats([6.0, 7.0, 8.0], &device);
+
+        let output = tensor.index_put(indices, values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 1.0, 6.0], [7.0, 4.0, 8.0]]), false);
+    }
+
+    #[test]
+    fn should_index_put_last_value_of_repeated_coordinates() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 1], [1, 1], [0, 1], [0, 1]], &device);
+        let values = TestTensor::from_floats([6.0, 7.0, 8.0, 9.0], &device);
+
+        let output = tensor.index_put(indices, values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 9.0, 2.0], [3.0, 7.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_index_put_accumulate_repeated_coordinates() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 1], [1, 1], [0, 1], [0, 1]], &device);
+        let values = TestTensor::from_floats([6.0, 7.0, 8.0, 9.0], &device);
+
+        let output = tensor.index_put(indices, values, true);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0.0, 24.0, 2.0], [3.0, 11.0, 5.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_index_put_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1], [2, 3]], &device);
+        let indices = TestTensorInt::from_ints([[1, 0], [1, 0], [0, 1]], &device);
+        let values = TestTensorInt::from_ints([4, 5, 6], &device);
+
+        let output = tensor
+            .clone()
+            .index_put(indices.clone(), values.clone(), false);
+        let output_accumulate = tensor.index_put(indices, values, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 6], [5, 3]]), false);
+        output_accumulate
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 7], [11, 3]]), false);
+    }
+
+    #[test]
+    fn should_index_put_last_value_of_many_repeated_coordinates() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::zeros([2, 2], &device);
+        // Each element is written more than a thousand times, and the last writes have position
+        // ids that can't be ordered exactly with half precision floats.
+        let num_indices = 4100;
+        let flat = TestTensorInt::<1>::arange(0..num_indices, &device).remainder_scalar(4);
+        let indices = TestTensorInt::cat(
+            vec![
+                flat.clone().div_scalar(2).unsqueeze_dim(1),
+                flat.remainder_scalar(2).unsqueeze_dim(1),
+            ],
+            1,
+        );
+        let values = TestTensorInt::<1>::arange(0..num_indices, &device).add_scalar(1 << 24);
+
+        let output = tensor.index_put(indices, values, false);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[16781312, 16781313], [16781314, 16781315]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_coordinates_do_not_match_the_rank() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+        let indices = TestTensorInt::from_ints([[0, 1, 0]], &device);
+        let values = TestTensor::from_floats([1.0], &device);
+
+        let _output = tensor.index_put(indices, values, false);
+    }
+}
@@ -0,0 +1,135 @@
+#[burn_tensor_testgen::testgen(index_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{IndexReduceMode, TensorData};
+
+    #[test]
+    fn should_index_reduce_sum_like_scatter() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+        let source = TestTensor::from_floats([5.0, 4.0, 3.0, 2.0], &device);
+        let index = TestTensorInt::from_ints([1, 0, 1, 1], &device);
+
+        let output =
+            tensor
+                .clone()
+                .index_reduce(0, index.clone(), source.clone(), IndexReduceMode::Sum);
+        let expected = tensor.scatter(0, index, source);
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn should_index_reduce_prod_1d() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+        let source = TestTensor::from_floats([5.0, 4.0, 3.0, 2.0], &device);
+        let index = TestTensorInt::from_ints([1, 0, 1, 1], &device);
+
+        let output = tensor.index_reduce(0, index, source, IndexReduceMode::Prod);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 60.0, 3.0]), false);
+    }
+
+    #[test]
+    fn should_index_reduce_amax_2d_dim1() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let source = TestTensor::from_floats([[6.0, -1.0], [2.0, 7.0]], &device);
+        let index = TestTensorInt::from_ints([[0, 2], [1, 1]], &device);
+
+        let output = tensor.index_reduce(1, index, source, IndexReduceMode::AMax);
+
+        // Original values are kept when larger than the reduced source values
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[6.0, 1.0, 2.0], [3.0, 7.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_index_reduce_amin_2d_dim0() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let source = TestTensor::from_floats(
+            [[-1.0, 8.0, 1.0], [6.0, -2.0, 4.0], [2.0, 3.0, -3.0]],
+            &device,
+        );
+        let index = TestTensorInt::from_ints([[0, 1, 1], [1, 1, 0], [1, 0, 0]], &device);
+
+        let output = tensor.index_reduce(0, index, source, IndexReduceMode::AMin);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[-1.0, 1.0, -3.0], [2.0, -2.0, 1.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_index_reduce_amax_3d_dim2() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::zeros([2, 1, 2], &device);
+        let source = TestTensor::from_floats([[[1.0, 3.0, 2.0]], [[-1.0, -2.0, 4.0]]], &device);
+        let index = TestTensorInt::from_ints([[[0, 1, 1]], [[0, 0, 1]]], &device);
+
+        let output = tensor.index_reduce(2, index, source, IndexReduceMode::AMax);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[1.0, 3.0]], [[0.0, 4.0]]]), false);
+    }
+
+    #[test]
+    fn should_index_reduce_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1, 2], [3, 4, 5]], &device);
+        let source = TestTensorInt::from_ints([[6, -1], [2, 7]], &device);
+        let index = TestTensorInt::from_ints([[2, 2], [0, 0]], &device);
+
+        let output_max =
+            tensor
+                .clone()
+                .index_reduce(1, index.clone(), source.clone(), IndexReduceMode::AMax);
+        let output_prod = tensor.index_reduce(1, index, source, IndexReduceMode::Prod);
+
+        output_max
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 6], [7, 4, 5]]), false);
+        output_prod
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, -12], [42, 4, 5]]), false);
+    }
+
+    #[test]
+    fn should_index_reduce_negative_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+        let source = TestTensor::from_floats([5.0, 4.0, 3.0, 2.0], &device);
+        let index = TestTensorInt::from_ints([-2, 0, 1, -2], &device);
+
+        let output_prod =
+            tensor
+                .clone()
+                .index_reduce(0, index.clone(), source.clone(), IndexReduceMode::Prod);
+        let output_sum = tensor.index_reduce(0, index, source, IndexReduceMode::Sum);
+
+        output_prod
+            .into_data()
+            .assert_eq(&TensorData::from([4.0, 60.0, 3.0]), false);
+        output_sum
+            .into_data()
+            .assert_eq(&TensorData::from([5.0, 12.0, 3.0]), false);
+    }
+
+    #[test]
+    #[should_panic = "Index 3 is out of bounds for dimension 0 of size 3 in index_reduce"]
+    fn should_panic_when_index_reduce_index_out_of_bounds() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+        let source = TestTensor::from_floats([5.0, 4.0], &device);
+        let index = TestTensorInt::from_ints([1, 3], &device);
+
+        let _ = tensor.index_reduce(0, index, source, IndexReduceMode::Prod);
+    }
+}
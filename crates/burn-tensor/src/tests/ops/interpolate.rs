@@ -0,0 +1,101 @@
+#[burn_tensor_testgen::testgen(interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::ops::InterpolateMode;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_upsample_2d_bilinear() {
+        let tensor = TestTensor::<4>::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = tensor.interpolate([4, 4], InterpolateMode::Bilinear, false);
+
+        // Reference from torch.nn.functional.interpolate(mode="bilinear", align_corners=False)
+        let expected = TensorData::from([[[
+            [1.0, 1.25, 1.75, 2.0],
+            [1.5, 1.75, 2.25, 2.5],
+            [2.5, 2.75, 3.25, 3.5],
+            [3.0, 3.25, 3.75, 4.0],
+        ]]]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_upsample_2d_bilinear_align_corners() {
+        let tensor = TestTensor::<4>::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = tensor.interpolate([2.0, 1.5], InterpolateMode::Bilinear, true);
+
+        // Reference from torch.nn.functional.interpolate(mode="bilinear", align_corners=True)
+        let expected = TensorData::from([[[
+            [1.0, 1.5, 2.0],
+            [1.6666666, 2.1666666, 2.6666666],
+            [2.3333333, 2.8333333, 3.3333333],
+            [3.0, 3.5, 4.0],
+        ]]]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_upsample_2d_nearest() {
+        let tensor = TestTensor::<4>::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = tensor.interpolate([2.0, 2.0], InterpolateMode::Nearest, false);
+
+        // Reference from torch.nn.functional.interpolate(mode="nearest")
+        let expected = TensorData::from([[[
+            [1.0, 1.0, 2.0, 2.0],
+            [1.0, 1.0, 2.0, 2.0],
+            [3.0, 3.0, 4.0, 4.0],
+            [3.0, 3.0, 4.0, 4.0],
+        ]]]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_upsample_2d_bicubic() {
+        let tensor = TestTensor::<4>::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = tensor.interpolate([4, 4], InterpolateMode::Bicubic, false);
+
+        // Reference from torch.nn.functional.interpolate(mode="bicubic", align_corners=False)
+        let expected = TensorData::from([[[
+            [0.68359375, 1.015625, 1.5625, 1.89453125],
+            [1.34765625, 1.6796875, 2.2265625, 2.55859375],
+            [2.44140625, 2.7734375, 3.3203125, 3.65234375],
+            [3.10546875, 3.4375, 3.984375, 4.31640625],
+        ]]]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_upsample_1d_linear() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0, 3.0]]]);
+
+        let output = tensor.interpolate([2.0], InterpolateMode::Bilinear, false);
+
+        // Reference from torch.nn.functional.interpolate(mode="linear", align_corners=False)
+        let expected = TensorData::from([[[1.0, 1.25, 1.75, 2.25, 2.75, 3.0]]]);
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    #[should_panic = "The size should have a value for each spatial dimension"]
+    fn should_panic_when_size_doesnt_match_spatial_dims() {
+        let tensor = TestTensor::<3>::from([[[1.0, 2.0, 3.0]]]);
+
+        let _output = tensor.interpolate([4, 4], InterpolateMode::Nearest, false);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(maxmin)]
 mod tests {
     use super::*;
-    use burn_tensor::{Tensor, TensorData};
+    use burn_tensor::{Int, Tensor, TensorData};
     use burn_tensor::{Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
 
@@ -190,4 +190,72 @@ mod tests {
 
         output.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn test_amax_3d_dims_0_2() {
+        let tensor = TestTensor::<3>::from_floats(
+            [
+                [[1., 7.], [-3., 4.], [0., 2.]],
+                [[5., -1.], [8., 6.], [-2., 9.]],
+            ],
+            &Default::default(),
+        );
+
+        let output: Tensor<TestBackend, 3> = tensor.clone().amax(&[0, 2], true);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[7.], [8.], [9.]]]), false);
+
+        let output: Tensor<TestBackend, 1> = tensor.amax(&[2, 0], false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([7., 8., 9.]), false);
+    }
+
+    #[test]
+    fn test_amin_3d_dims_0_2() {
+        let tensor = TestTensor::<3>::from_floats(
+            [
+                [[1., 7.], [-3., 4.], [0., 2.]],
+                [[5., -1.], [8., 6.], [-2., 9.]],
+            ],
+            &Default::default(),
+        );
+
+        let output: Tensor<TestBackend, 3> = tensor.clone().amin(&[0, 2], true);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[-1.], [-3.], [-2.]]]), false);
+
+        let output: Tensor<TestBackend, 1> = tensor.amin(&[0, 2], false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-1., -3., -2.]), false);
+    }
+
+    #[test]
+    fn test_amax_amin_int_3d_dims_0_2() {
+        let tensor = TestTensorInt::<3>::from_ints(
+            [[[1, 7], [-3, 4], [0, 2]], [[5, -1], [8, 6], [-2, 9]]],
+            &Default::default(),
+        );
+
+        let output: Tensor<TestBackend, 1, Int> = tensor.clone().amax(&[0, 2], false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([7, 8, 9]), false);
+
+        let output: Tensor<TestBackend, 1, Int> = tensor.amin(&[0, 2], false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-1, -3, -2]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_amax_should_panic_on_wrong_output_rank() {
+        let tensor = TestTensor::<3>::zeros([2, 3, 2], &Default::default());
+
+        let _: Tensor<TestBackend, 2> = tensor.amax(&[0, 2], false);
+    }
 }
@@ -28,7 +28,10 @@ mod flip;
 mod floor;
 mod full;
 mod gather_scatter;
+mod index_put;
+mod index_reduce;
 mod init;
+mod interpolate;
 mod iter_dim;
 mod log;
 mod log1p;
@@ -41,17 +44,22 @@ mod mul;
 mod nan;
 mod narrow;
 mod neg;
+mod norm;
 mod one_hot;
+mod pad_sequence;
 mod padding;
 mod permute;
+mod pixel_shuffle;
 mod powf;
 mod powf_scalar;
 mod prod;
+mod prune;
 mod random;
 mod recip;
 mod remainder;
 mod repeat;
 mod repeat_dim;
+mod repeat_interleave;
 mod reshape;
 mod round;
 mod select;
@@ -67,6 +75,7 @@ mod stack;
 mod sub;
 mod tan;
 mod tanh;
+mod to_device;
 mod topk;
 mod transpose;
 mod tri;
@@ -82,6 +82,19 @@ mod tests {
         permuted.into_data().assert_eq(&tensor.into_data(), false);
     }
 
+    #[test]
+    fn movedim_first_to_last_matches_permute() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..24, &device)
+            .reshape([2, 3, 4])
+            .float();
+
+        let moved = tensor.clone().movedim(0, -1);
+        let permuted = tensor.permute([1, 2, 0]);
+
+        moved.into_data().assert_eq(&permuted.into_data(), true);
+    }
+
     #[test]
     fn vec_input_int() {
         let device = Default::default();
@@ -0,0 +1,88 @@
+#[burn_tensor_testgen::testgen(norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn should_support_norm_except_dim_l2() {
+        let tensor = TestTensor::<3>::from([[[3.0, 0.0], [4.0, 0.0]], [[0.0, 2.0], [0.0, -1.0]]]);
+
+        let output = tensor.norm_except_dim(2, 2.0);
+        let expected = TensorData::from([[[5.0, 2.236068]]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_norm_except_dim_first_dim() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 2.0], [0.0, 3.0, -4.0]]);
+
+        let output = tensor.norm_except_dim(0, 2.0);
+        let expected = TensorData::from([[3.0], [5.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_norm_except_dim_l1() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 2.0], [0.0, 3.0, -4.0]]);
+
+        let output = tensor.norm_except_dim(0, 1.0);
+        let expected = TensorData::from([[5.0], [7.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_norm_except_dim_inf() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 2.0], [0.0, 3.0, -4.0]]);
+
+        let output = tensor.norm_except_dim(1, f64::INFINITY);
+        let expected = TensorData::from([[1.0, 3.0, 4.0]]);
+
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_unit_normalize() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0, 2.0], [0.0, 3.0, -4.0]]);
+
+        let output = tensor.unit_normalize(0);
+        let expected = TensorData::from([[1.0 / 3.0, -2.0 / 3.0, 2.0 / 3.0], [0.0, 0.6, -0.8]]);
+
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        output
+            .norm_except_dim(0, 2.0)
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([[1.0], [1.0]]), Tolerance::default());
+    }
+
+    #[test]
+    fn should_support_norm_frob() {
+        let tensor = TestTensor::<3>::from([[[3.0, 0.0], [4.0, 0.0]], [[0.0, 2.0], [0.0, -1.0]]]);
+
+        let output = tensor.clone().norm_frob();
+        let expected = tensor.powi_scalar(2).sum().sqrt().into_data();
+
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        output
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([5.477226]), Tolerance::default());
+    }
+}
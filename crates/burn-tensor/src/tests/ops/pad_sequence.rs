@@ -0,0 +1,129 @@
+#[burn_tensor_testgen::testgen(pad_sequence)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use burn_tensor::{Tensor, TensorData, pad_packed_sequence};
+
+    fn sequences(lengths: &[usize]) -> Vec<TestTensor<2>> {
+        let device = Default::default();
+        let mut offset = 0;
+
+        lengths
+            .iter()
+            .map(|length| {
+                let start = offset as i64;
+                offset += length * 2;
+                TestTensorInt::<1>::arange(start..offset as i64, &device)
+                    .float()
+                    .reshape([*length, 2])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_pad_sequence_batch_first() {
+        let (padded, lengths) = Tensor::pad_sequence(sequences(&[2, 3, 1]), true, -1.0);
+
+        padded.into_data().assert_eq(
+            &TensorData::from([
+                [[0.0, 1.0], [2.0, 3.0], [-1.0, -1.0]],
+                [[4.0, 5.0], [6.0, 7.0], [8.0, 9.0]],
+                [[10.0, 11.0], [-1.0, -1.0], [-1.0, -1.0]],
+            ]),
+            false,
+        );
+        lengths
+            .into_data()
+            .assert_eq(&TensorData::from([2, 3, 1]), false);
+    }
+
+    #[test]
+    fn should_pad_sequence_time_first() {
+        let (padded, lengths) = Tensor::pad_sequence(sequences(&[2, 3, 1]), false, 0.0);
+
+        padded.into_data().assert_eq(
+            &TensorData::from([
+                [[0.0, 1.0], [4.0, 5.0], [10.0, 11.0]],
+                [[2.0, 3.0], [6.0, 7.0], [0.0, 0.0]],
+                [[0.0, 0.0], [8.0, 9.0], [0.0, 0.0]],
+            ]),
+            false,
+        );
+        lengths
+            .into_data()
+            .assert_eq(&TensorData::from([2, 3, 1]), false);
+    }
+
+    #[test]
+    fn should_pack_padded_sequence_by_time_step() {
+        let (padded, lengths) = Tensor::pad_sequence(sequences(&[2, 3, 1]), true, 0.0);
+
+        let packed = padded.pack_padded_sequence(lengths, true);
+
+        assert_eq!(packed.batch_sizes, [3, 2, 1]);
+        assert_eq!(packed.lengths, [2, 3, 1]);
+        packed.data.into_data().assert_eq(
+            &TensorData::from([
+                [0.0, 1.0],
+                [4.0, 5.0],
+                [10.0, 11.0],
+                [2.0, 3.0],
+                [6.0, 7.0],
+                [8.0, 9.0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_unpack_packed_sequence() {
+        // Every sequence length from 1 through 10, in a shuffled order
+        let lengths = [7, 1, 10, 4, 2, 9, 5, 3, 8, 6];
+
+        for batch_first in [true, false] {
+            let sequences = sequences(&lengths);
+            let (padded, lengths) = Tensor::pad_sequence(sequences.clone(), batch_first, 0.0);
+
+            let packed = padded
+                .clone()
+                .pack_padded_sequence(lengths.clone(), batch_first);
+            let (unpacked, unpacked_lengths) = pad_packed_sequence(packed, batch_first, 0.0);
+
+            unpacked.to_data().assert_eq(&padded.into_data(), true);
+            unpacked_lengths
+                .into_data()
+                .assert_eq(&lengths.into_data(), true);
+
+            // The original sequences are recovered from the padded batch
+            let unpacked = match batch_first {
+                true => unpacked,
+                false => unpacked.swap_dims(0, 1),
+            };
+            for (i, sequence) in sequences.into_iter().enumerate() {
+                let [length, features] = sequence.dims();
+                unpacked
+                    .clone()
+                    .slice([i..i + 1, 0..length, 0..features])
+                    .squeeze::<2>(0)
+                    .into_data()
+                    .assert_eq(&sequence.into_data(), true);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_padding_empty_list() {
+        let _ = Tensor::pad_sequence(Vec::<TestTensor<2>>::new(), true, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_packing_length_exceeds_padded_length() {
+        let device = Default::default();
+        let padded = TestTensor::<3>::zeros([2, 3, 1], &device);
+        let lengths = TestTensorInt::<1>::from_data([2, 4], &device);
+
+        let _ = padded.pack_padded_sequence(lengths, true);
+    }
+}
@@ -99,4 +99,53 @@ mod tests {
         ]]]));
         padded_tensor.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn padding_to_multiple_test() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]]);
+
+        let (padded_tensor, padding) = tensor.pad_to_multiple(1, 4, FloatType::new(1.1));
+
+        let expected = TensorData::from(as_type!(FloatType: [
+            [0.0, 1.0, 2.0, 3.0, 4.0, 1.1, 1.1, 1.1],
+            [5.0, 6.0, 7.0, 8.0, 9.0, 1.1, 1.1, 1.1],
+        ]));
+        assert_eq!(padding, 3);
+        padded_tensor.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_to_multiple_integer_test() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2, 3, 4], [5, 6, 7, 8, 9]]);
+
+        let (padded_tensor, padding) = tensor.pad_to_multiple(1, 4, IntType::new(10));
+
+        let expected = TensorData::from(as_type!(IntType: [
+            [0, 1, 2, 3, 4, 10, 10, 10],
+            [5, 6, 7, 8, 9, 10, 10, 10],
+        ]));
+        assert_eq!(padding, 3);
+        padded_tensor.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_to_multiple_already_multiple_test() {
+        let tensor = TestTensor::<1>::from([0.0, 1.0, 2.0, 3.0]);
+
+        let (padded_tensor, padding) = tensor.pad_to_multiple(0, 4, FloatType::new(1.1));
+
+        assert_eq!(padding, 0);
+        padded_tensor.into_data().assert_eq(
+            &TensorData::from(as_type!(FloatType: [0.0, 1.0, 2.0, 3.0])),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn padding_to_multiple_of_zero_should_panic() {
+        let tensor = TestTensor::<1>::from([0.0, 1.0, 2.0]);
+
+        let _ = tensor.pad_to_multiple(0, 0, FloatType::new(0.0));
+    }
 }
@@ -89,6 +89,34 @@ mod tests {
         permuted.into_data().assert_eq(&tensor.into_data(), false);
     }
 
+    #[test]
+    fn permute_inner_axes() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..24, &device)
+            .reshape([2, 3, 4])
+            .float();
+
+        let permuted = tensor.clone().permute([0, 2, 1]);
+
+        // from pytorch:
+        // import torch; torch.arange(0, 24).reshape(2, 3, 4).permute(0, 2, 1).float()
+        let expected = TensorData::from([
+            [[0., 4., 8.], [1., 5., 9.], [2., 6., 10.], [3., 7., 11.]],
+            [
+                [12., 16., 20.],
+                [13., 17., 21.],
+                [14., 18., 22.],
+                [15., 19., 23.],
+            ],
+        ]);
+
+        permuted.into_data().assert_eq(&expected, false);
+
+        // Test with negative axes
+        let permuted = tensor.permute([0, -1, -2]);
+        permuted.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     #[should_panic]
     fn edge_repeated_axes() {
@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(pixel_shuffle)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn pixel_shuffle_should_match_pytorch() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..16, &device).reshape([1, 4, 2, 2]);
+
+        let shuffled = tensor.pixel_shuffle(2);
+        // from pytorch:
+        // import torch; torch.nn.functional.pixel_shuffle(torch.arange(16).reshape(1, 4, 2, 2), 2)
+        let expected =
+            TensorData::from([[[[0, 4, 1, 5], [8, 12, 9, 13], [2, 6, 3, 7], [10, 14, 11, 15]]]]);
+
+        shuffled.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn pixel_unshuffle_should_match_pytorch() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..16, &device).reshape([1, 1, 4, 4]);
+
+        let unshuffled = tensor.pixel_unshuffle(2);
+        // from pytorch:
+        // import torch; torch.nn.functional.pixel_unshuffle(torch.arange(16).reshape(1, 1, 4, 4), 2)
+        let expected = TensorData::from([[
+            [[0, 2], [8, 10]],
+            [[1, 3], [9, 11]],
+            [[4, 6], [12, 14]],
+            [[5, 7], [13, 15]],
+        ]]);
+
+        unshuffled.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn pixel_shuffle_unshuffle_round_trip() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..216, &device)
+            .reshape([2, 3, 9, 2, 2])
+            .float();
+
+        let shuffled = tensor.clone().pixel_shuffle(3);
+        assert_eq!(shuffled.dims(), [2, 3, 1, 6, 6]);
+
+        let output = shuffled.pixel_unshuffle(3);
+        output.into_data().assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    fn pixel_shuffle_3d() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..16, &device).reshape([4, 2, 2]);
+
+        let output = tensor.clone().pixel_shuffle(2);
+        let expected = tensor.unsqueeze::<4>().pixel_shuffle(2).squeeze::<3>(0);
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic = "number of channels must be divisible"]
+    fn pixel_shuffle_should_panic_when_channels_not_divisible() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..24, &device).reshape([1, 6, 2, 2]);
+
+        let _output = tensor.pixel_shuffle(2);
+    }
+
+    #[test]
+    #[should_panic = "must be divisible by the downscale factor"]
+    fn pixel_unshuffle_should_panic_when_size_not_divisible() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..12, &device).reshape([1, 1, 3, 4]);
+
+        let _output = tensor.pixel_unshuffle(2);
+    }
+}
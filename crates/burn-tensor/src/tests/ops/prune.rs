@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(prune)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor, TensorData, cast::ToElement};
+
+    #[test]
+    fn should_zero_smallest_magnitudes() {
+        let tensor = TestTensor::<2>::from([[0.5, -4.0, 3.0], [-1.0, 2.0, -0.1]]);
+
+        let output = tensor.prune_by_magnitude(0.5);
+
+        let expected = TensorData::from([[0.0, -4.0, 3.0], [0.0, 2.0, 0.0]]);
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_prune_sparsity_fraction() {
+        let tensor = TestTensor::<3>::random(
+            [4, 8, 16],
+            Distribution::Normal(0.0, 1.0),
+            &Default::default(),
+        );
+
+        for (sparsity, num_zeros) in [(0.0, 0), (0.3, 154), (0.9, 461), (1.0, 512)] {
+            let output = tensor.clone().prune_by_magnitude(sparsity);
+
+            let zeros = output.equal_elem(0.0).int().sum().into_scalar().to_i64();
+            assert_eq!(zeros, num_zeros, "sparsity {sparsity}");
+        }
+    }
+
+    #[test]
+    fn should_keep_largest_magnitudes() {
+        let tensor = TestTensor::<2>::random(
+            [16, 16],
+            Distribution::Normal(0.0, 1.0),
+            &Default::default(),
+        );
+
+        let output = tensor.clone().prune_by_magnitude(0.75);
+
+        // Every kept magnitude is at least as large as every pruned magnitude
+        let kept = output.clone().not_equal_elem(0.0);
+        let magnitudes = tensor.abs();
+        let min_kept = magnitudes
+            .clone()
+            .mask_fill(kept.clone().bool_not(), f32::INFINITY)
+            .min()
+            .into_scalar()
+            .to_f64();
+        let max_pruned = magnitudes.mask_fill(kept, 0.0).max().into_scalar().to_f64();
+        assert!(min_kept >= max_pruned);
+    }
+
+    #[test]
+    #[should_panic = "The sparsity should be between 0 and 1"]
+    fn should_panic_with_invalid_sparsity() {
+        let _output =
+            Tensor::<TestBackend, 1>::ones([4], &Default::default()).prune_by_magnitude(1.5);
+    }
+}
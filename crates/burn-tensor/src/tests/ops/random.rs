@@ -1,7 +1,9 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor, cast::ToElement, tests::Float};
+    use burn_tensor::{
+        Bool, Distribution, Tensor, backend::Backend, cast::ToElement, tests::Float,
+    };
 
     #[test]
     fn rand_default() {
@@ -35,4 +37,103 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [FloatType::new(1f32); 20].into());
     }
+
+    #[test]
+    fn bernoulli_scalar_mean_should_match_probability() {
+        TestBackend::seed(0);
+        let num_samples = 10_000;
+        let prob = 0.3;
+        let samples = Tensor::<TestBackend, 1, Bool>::bernoulli_scalar(
+            prob,
+            [num_samples],
+            &Default::default(),
+        );
+
+        let mean = samples.float().mean().into_scalar().to_f64();
+
+        // Within 3 standard deviations of the mean of the samples
+        let std = (prob * (1.0 - prob) / num_samples as f64).sqrt();
+        assert!(
+            (mean - prob).abs() < 3.0 * std,
+            "The mean {mean} of the samples should be close to {prob}"
+        );
+    }
+
+    #[test]
+    fn bernoulli_should_sample_with_element_probabilities() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let probs = TestTensor::<2>::from([[0.0, 1.0], [1.0, 0.0]]).repeat_dim(1, 100);
+
+        let samples = Tensor::<TestBackend, 2, Bool>::bernoulli(probs);
+
+        let expected = TestTensor::<2>::from([[0.0, 1.0], [1.0, 0.0]])
+            .repeat_dim(1, 100)
+            .bool();
+        samples.into_data().assert_eq(&expected.into_data(), false);
+
+        let probs = TestTensor::<1>::full([10_000], 0.7, &device);
+        let mean = Tensor::<TestBackend, 1, Bool>::bernoulli(probs)
+            .float()
+            .mean()
+            .into_scalar()
+            .to_f64();
+        let std = (0.7 * 0.3 / 10_000f64).sqrt();
+        assert!((mean - 0.7).abs() < 3.0 * std);
+    }
+
+    #[test]
+    fn bernoulli_logits_should_be_deterministic_for_extreme_logits() {
+        let device = Default::default();
+        let logits = TestTensor::<2>::from([[100.0, -100.0], [1000.0, -1000.0]]).repeat_dim(1, 100);
+
+        let samples = Tensor::<TestBackend, 2, Bool>::bernoulli_logits(logits);
+
+        let expected = TestTensor::<2>::from([[1.0, 0.0], [1.0, 0.0]])
+            .repeat_dim(1, 100)
+            .bool();
+        samples.into_data().assert_eq(&expected.into_data(), false);
+
+        let logits = Tensor::cat(
+            vec![
+                TestTensor::<1>::full([100], f32::INFINITY, &device),
+                TestTensor::<1>::full([100], f32::NEG_INFINITY, &device),
+            ],
+            0,
+        );
+        let samples = Tensor::<TestBackend, 1, Bool>::bernoulli_logits(logits);
+
+        let expected = Tensor::cat(
+            vec![
+                TestTensor::<1>::ones([100], &device),
+                TestTensor::<1>::zeros([100], &device),
+            ],
+            0,
+        )
+        .bool();
+        samples.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn bernoulli_logits_mean_should_match_sigmoid() {
+        TestBackend::seed(0);
+        let logits = TestTensor::<1>::full([10_000], 0.5, &Default::default());
+
+        let mean = Tensor::<TestBackend, 1, Bool>::bernoulli_logits(logits)
+            .float()
+            .mean()
+            .into_scalar()
+            .to_f64();
+
+        let prob = 1.0 / (1.0 + (-0.5f64).exp());
+        let std = (prob * (1.0 - prob) / 10_000f64).sqrt();
+        assert!((mean - prob).abs() < 3.0 * std);
+    }
+
+    #[test]
+    #[should_panic = "Bernoulli probability should be between 0 and 1"]
+    fn bernoulli_scalar_should_panic_with_invalid_probability() {
+        let _samples =
+            Tensor::<TestBackend, 1, Bool>::bernoulli_scalar(1.5, [10], &Default::default());
+    }
 }
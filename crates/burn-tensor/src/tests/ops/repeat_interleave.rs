@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(repeat_interleave)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_repeat_interleave_scalar_first_dim() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0], [2.0, 3.0]], &device);
+
+        let output = tensor.repeat_interleave_scalar(2, 0);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0.0, 1.0], [0.0, 1.0], [2.0, 3.0], [2.0, 3.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_repeat_interleave_scalar_last_dim() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1], [2, 3]], &device);
+
+        let output = tensor.repeat_interleave_scalar(3, 1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0, 0, 0, 1, 1, 1], [2, 2, 2, 3, 3, 3]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_repeat_interleave_scalar_middle_dim_of_transposed_tensor() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_floats(
+            [[[0.0, 1.0], [2.0, 3.0]], [[4.0, 5.0], [6.0, 7.0]]],
+            &device,
+        )
+        .swap_dims(1, 2);
+
+        let output = tensor.repeat_interleave_scalar(2, 1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [[0.0, 2.0], [0.0, 2.0], [1.0, 3.0], [1.0, 3.0]],
+                [[4.0, 6.0], [4.0, 6.0], [5.0, 7.0], [5.0, 7.0]],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_repeat_interleave_with_repeats_per_element() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let repeats = TestTensorInt::from_ints([1, 0, 2], &device);
+
+        let output = tensor.repeat_interleave(repeats, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 2.0, 2.0], [3.0, 5.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_repeat_interleave_with_single_repeat() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([0.0, 1.0, 2.0], &device);
+        let repeats = TestTensorInt::from_ints([2], &device);
+
+        let output = tensor.repeat_interleave(repeats, 0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 0.0, 1.0, 1.0, 2.0, 2.0]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_repeats_dont_match_dim() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([0.0, 1.0, 2.0], &device);
+        let repeats = TestTensorInt::from_ints([1, 2], &device);
+
+        let _output = tensor.repeat_interleave(repeats, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_negative_repeats() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([0.0, 1.0], &device);
+        let repeats = TestTensorInt::from_ints([1, -1], &device);
+
+        let _output = tensor.repeat_interleave(repeats, 0);
+    }
+}
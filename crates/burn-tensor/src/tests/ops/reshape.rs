@@ -216,6 +216,48 @@ mod tests {
         assert_eq!(reshaped.shape(), [4, 3].into());
     }
 
+    #[test]
+    fn should_infer_leading_dim() {
+        let tensor = TestTensorInt::<1>::arange(0..24, &Default::default()).reshape([2, 3, 4]);
+
+        let reshaped = tensor.clone().reshape([-1, 4]);
+
+        assert_eq!(reshaped.dims(), [6, 4]);
+        reshaped
+            .into_data()
+            .assert_eq(&tensor.reshape([6usize, 4]).into_data(), false);
+    }
+
+    #[test]
+    fn should_infer_trailing_dim() {
+        let tensor = TestTensorInt::<1>::arange(0..24, &Default::default()).reshape([2, 3, 4]);
+
+        let reshaped = tensor.clone().reshape([6, -1]);
+
+        assert_eq!(reshaped.dims(), [6, 4]);
+        reshaped
+            .into_data()
+            .assert_eq(&tensor.reshape([6usize, 4]).into_data(), false);
+    }
+
+    #[test]
+    fn should_support_i64_shape() {
+        let tensor = TestTensor::<3>::ones([2, 3, 4], &Default::default());
+
+        let shape: [i64; 2] = [0, -1];
+        let reshaped = tensor.reshape(shape);
+
+        assert_eq!(reshaped.dims(), [2, 12]);
+    }
+
+    #[test]
+    #[should_panic = "Cannot reshape tensor"]
+    fn should_panic_when_inferred_dim_is_ambiguous() {
+        let tensor = TestTensor::<2>::empty([0, 3], &Default::default());
+
+        let _ = tensor.reshape([0, -1]);
+    }
+
     #[test]
     fn should_not_corrupt_after_slice() {
         let zeros = TestTensor::<1>::zeros([2], &Default::default());
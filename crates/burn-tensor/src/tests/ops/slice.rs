@@ -120,6 +120,46 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_support_slice_assign_row() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([3, 3], &device);
+        let row = TestTensor::<2>::from_data([[1.0, 2.0, 3.0]], &device);
+
+        let output = tensor.slice_assign([1..2], row);
+        let expected = TensorData::from([[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_slice_assign_broadcast_scalar() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([3, 4], &device);
+        let value = TestTensor::<2>::from_data([[7.0]], &device);
+
+        let output = tensor.slice_assign([0..2, 1..3], value);
+        let expected = TensorData::from([
+            [0.0, 7.0, 7.0, 0.0],
+            [0.0, 7.0, 7.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_slice_assign_broadcast_row() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::zeros([3, 3], &device);
+        let row = TestTensorInt::<2>::from_data([[1, 2, 3]], &device);
+
+        let output = tensor.slice_assign([1..3], row);
+        let expected = TensorData::from([[0, 0, 0], [1, 2, 3], [1, 2, 3]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn slice_should_not_corrupt_potentially_inplace_operations() {
         let tensor = TestTensorInt::<1>::from_data([1, 2, 3, 4, 5], &Default::default());
@@ -222,6 +262,16 @@ mod tests {
         output.into_data().assert_eq(&data, false);
     }
 
+    #[test]
+    #[should_panic]
+    fn should_panic_when_slice_assign_values_cannot_be_broadcast() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([3, 4], &device);
+        let values = TestTensor::<2>::ones([2, 3], &device);
+
+        let _output = tensor.slice_assign([0..2, 0..2], values);
+    }
+
     #[test]
     #[should_panic]
     fn should_panic_when_slice_is_desc() {
@@ -52,6 +52,41 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_support_stack_ops_three_tensors_dim0() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let tensor_2 = TestTensor::<2>::from_data([[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]], &device);
+        let tensor_3 =
+            TestTensor::<2>::from_data([[13.0, 14.0, 15.0], [16.0, 17.0, 18.0]], &device);
+
+        let output = Tensor::stack::<3>(vec![tensor_1, tensor_2, tensor_3], 0);
+        let expected = TensorData::from([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]],
+            [[13.0, 14.0, 15.0], [16.0, 17.0, 18.0]],
+        ]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_stack_ops_three_tensors_dim2() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let tensor_2 = TestTensor::<2>::from_data([[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]], &device);
+        let tensor_3 =
+            TestTensor::<2>::from_data([[13.0, 14.0, 15.0], [16.0, 17.0, 18.0]], &device);
+
+        let output = Tensor::stack::<3>(vec![tensor_1, tensor_2, tensor_3], 2);
+        let expected = TensorData::from([
+            [[1.0, 7.0, 13.0], [2.0, 8.0, 14.0], [3.0, 9.0, 15.0]],
+            [[4.0, 10.0, 16.0], [5.0, 11.0, 17.0], [6.0, 12.0, 18.0]],
+        ]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn should_support_stack_ops_3d() {
         let device = Default::default();
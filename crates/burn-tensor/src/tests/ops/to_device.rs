@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(to_device)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, try_read_sync};
+
+    #[test]
+    fn should_move_float_tensor_to_default_device() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+
+        let output = tensor.to_device(&device);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_move_float_tensor_to_default_device_async() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let dtype = tensor.dtype();
+
+        let output = try_read_sync(tensor.to_device_async(&device)).unwrap();
+
+        assert_eq!(output.device(), device);
+        assert_eq!(output.dtype(), dtype);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_move_int_and_bool_tensors_to_default_device_async() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, -2, 3], &device);
+
+        let int_output = try_read_sync(tensor.clone().to_device_async(&device)).unwrap();
+        let bool_output = try_read_sync(tensor.greater_elem(0).to_device_async(&device)).unwrap();
+
+        int_output
+            .into_data()
+            .assert_eq(&TensorData::from([1, -2, 3]), false);
+        bool_output
+            .into_data()
+            .assert_eq(&TensorData::from([true, false, true]), false);
+    }
+}
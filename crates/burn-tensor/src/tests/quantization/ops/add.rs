@@ -1,7 +1,8 @@
 #[burn_tensor_testgen::testgen(q_add)]
 mod tests {
     use super::*;
-    use burn_tensor::TensorData;
+    use burn_tensor::quantization::{Calibration, QuantMode, QuantScheme};
+    use burn_tensor::{DType, TensorData};
     use burn_tensor::{Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
 
@@ -94,4 +95,28 @@ mod tests {
             Tolerance::absolute(1e-1),
         );
     }
+
+    #[test]
+    fn test_add_requantize_different_qparams() {
+        // The operands are quantized with different scales and zero points
+        let tensor_1 =
+            QTensor::<TestBackend, 2>::int8_symmetric([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor_2 = QTensor::<TestBackend, 2>::int8_affine([[-1.0, 0.5, 2.0], [3.5, 6.0, 8.0]]);
+        let expected = TestTensor::<2>::from_floats(
+            [[-1.0, 1.5, 4.0], [6.5, 10.0, 13.0]],
+            &Default::default(),
+        );
+        let scheme = QuantScheme::default().set_mode(QuantMode::Affine);
+        let qparams =
+            scheme.compute_q_params(scheme.compute_range(&expected, &Calibration::MinMax));
+
+        let output = tensor_1.add_requantize(tensor_2, &scheme, qparams);
+
+        assert_eq!(output.dtype(), DType::QFloat(scheme));
+        // Precision 1 to approximate de/quantization errors
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.into_data(), Tolerance::absolute(1e-1));
+    }
 }
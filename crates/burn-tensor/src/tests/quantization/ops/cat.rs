@@ -2,7 +2,8 @@
 mod tests {
     use super::*;
     use alloc::vec;
-    use burn_tensor::TensorData;
+    use burn_tensor::quantization::{QuantScheme, QuantizationParameters};
+    use burn_tensor::{DType, Tensor, TensorData};
     use burn_tensor::{Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
 
@@ -51,6 +52,47 @@ mod tests {
             .assert_approx_eq::<FT>(&expected, Tolerance::absolute(1e-1));
     }
 
+    #[test]
+    fn should_requantize_operands_with_different_scales() {
+        // The scales are 1 / 127 and 8 / 127
+        let tensor_1 = QTensor::<TestBackend, 2>::int8([[-1.0, 0.5, 1.0]]);
+        let tensor_2 = QTensor::<TestBackend, 2>::int8([[-4.0, 2.0, 8.0]]);
+
+        let output = TestTensor::cat(vec![tensor_1, tensor_2], 0);
+        let expected = TensorData::from([[-1.0, 0.5, 1.0], [-4.0, 2.0, 8.0]]);
+
+        // The operands share the scale covering both of them
+        assert!(matches!(output.dtype(), DType::QFloat(_)));
+        // Precision 1 to approximate de/quantization errors
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::absolute(1e-1));
+    }
+
+    #[test]
+    fn should_keep_values_of_operands_with_the_same_qparams() {
+        let device = Default::default();
+        let scheme = QuantScheme::default();
+        let qparams = || QuantizationParameters {
+            scale: Tensor::from_floats([0.05], &device),
+            offset: None,
+        };
+        let tensor_1 =
+            TestTensor::<2>::from_floats([[6.35, -1.0]], &device).quantize(&scheme, qparams());
+        let tensor_2 =
+            TestTensor::<2>::from_floats([[0.5, 2.0]], &device).quantize(&scheme, qparams());
+
+        let output = TestTensor::cat(vec![tensor_1, tensor_2], 0);
+        let expected = TensorData::from([[6.35, -1.0], [0.5, 2.0]]);
+
+        // The values are on the grid of the shared scale
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+
     #[test]
     #[should_panic]
     fn should_panic_when_dimensions_are_not_the_same() {
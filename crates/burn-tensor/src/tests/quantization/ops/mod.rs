@@ -31,6 +31,7 @@ mod powf;
 mod powf_scalar;
 mod quantize;
 mod recip;
+mod relu;
 mod remainder;
 mod repeat_dim;
 mod reshape;
@@ -59,4 +59,17 @@ mod tests {
         // Test with an invalid axis
         let _ = tensor.permute([3, 0, 1]);
     }
+
+    #[test]
+    fn permute_should_keep_quantized_values() {
+        let tensor = QTensor::<TestBackend, 3>::int8([[[0.0, -1.5, 2.0], [3.3, 4.0, -5.0]]]);
+
+        let permuted = tensor.clone().permute([2, 0, 1]);
+
+        // The values are moved without requantization
+        permuted
+            .dequantize()
+            .into_data()
+            .assert_eq(&tensor.dequantize().permute([2, 0, 1]).into_data(), false);
+    }
 }
@@ -4,10 +4,10 @@ mod tests {
     use super::*;
     use alloc::{vec, vec::Vec};
     use burn_tensor::quantization::{
-        QParams, QuantScheme, QuantizationParameters, QuantizationStrategy, QuantizedBytes,
-        SymmetricQuantization,
+        AffineQuantization, QParams, QuantMode, QuantScheme, QuantizationParameters,
+        QuantizationStrategy, QuantizedBytes, SymmetricQuantization,
     };
-    use burn_tensor::{DType, Tensor, TensorData};
+    use burn_tensor::{DType, ElementConversion, Tensor, TensorData};
     use burn_tensor::{Tolerance, ops::FloatElem};
     type FT = FloatElem<TestBackend>;
 
@@ -68,6 +68,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_support_quantize_affine_int8() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        let scheme = QuantScheme::default().set_mode(QuantMode::Affine);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.009_019_608], &device),
+            offset: Some(Tensor::from_ints([72], &device)),
+        };
+
+        let x_q = tensor.clone().quantize(&scheme, qparams);
+
+        let x_q_data = x_q.to_data();
+        let expected = TensorData::quantized(
+            vec![-128i8, -39, 72, 127],
+            [4],
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(0.009_019_608, 72)),
+        );
+
+        // Values equality
+        x_q_data.assert_eq(&expected, true);
+
+        // Quantization parameters check
+        let qparams = get_q_params(x_q_data);
+        let expected = get_q_params(expected);
+        assert_eq!(qparams.scale.len(), 1);
+        assert_eq!(qparams.scale, expected.scale);
+        assert_eq!(qparams.offset, Some(vec![72]));
+        assert_eq!(qparams.offset, expected.offset);
+
+        // Dequantize
+        let x = x_q.dequantize();
+
+        // Precision 2 for dequantization errors
+        x.into_data().assert_approx_eq::<FT>(
+            &tensor.into_data(),
+            Tolerance::absolute(1e-1).set_relative(1e-2),
+        );
+    }
+
+    #[test]
+    fn should_quantize_positive_values_more_precisely_with_affine_scheme() {
+        let device = Default::default();
+        // All-positive values, e.g. after a ReLU, only use half of the symmetric quantized range
+        let tensor = TestTensorInt::<1>::arange(0..101, &device)
+            .float()
+            .div_scalar(100);
+
+        let max_error = |mode: QuantMode| {
+            let scheme = QuantScheme::default().set_mode(mode);
+            let x = tensor.clone().quantize_dynamic(&scheme).dequantize();
+            (x - tensor.clone()).abs().max().into_scalar().elem::<f32>()
+        };
+        let error_symmetric = max_error(QuantMode::Symmetric);
+        let error_affine = max_error(QuantMode::Affine);
+
+        // The affine scale is `1 / 255`, so the rounding error is at most half of it
+        assert!(error_affine <= 0.5 / 255. + 1e-6, "{error_affine}");
+        assert!(
+            error_affine < error_symmetric,
+            "{error_affine} >= {error_symmetric}"
+        );
+    }
+
     #[test]
     fn should_support_quantize_dynamic_int8() {
         let device = Default::default();
@@ -86,4 +150,24 @@ mod tests {
 
         x_q.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_support_quantize_dynamic_affine_int8_all_zeros() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::zeros([4], &device);
+        let scheme = QuantScheme::default().set_mode(QuantMode::Affine);
+
+        let x_q = tensor.clone().quantize_dynamic(&scheme);
+
+        let expected = TensorData::quantized(
+            vec![-128i8, -128, -128, -128],
+            [4],
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(0.1, -128)),
+        );
+        x_q.clone().into_data().assert_eq(&expected, false);
+
+        x_q.dequantize()
+            .into_data()
+            .assert_eq(&tensor.into_data(), false);
+    }
 }
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(q_relu)]
+mod tests {
+    use super::*;
+    use burn_tensor::quantization::{QuantPropagation, QuantScheme};
+    use burn_tensor::{DType, TensorData, activation};
+    use burn_tensor::{Tolerance, ops::FloatElem};
+    type FT = FloatElem<TestBackend>;
+
+    #[test]
+    fn test_q_relu_d2() {
+        let tensor = QTensor::<TestBackend, 2>::int8([[0.0, -1.0, 2.0], [3.0, -4.0, -5.0]]);
+
+        let output = activation::relu(tensor);
+
+        // Precision 1 to approximate de/quantization errors
+        output.dequantize().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.0, 0.0, 2.0], [3.0, 0.0, 0.0]]),
+            Tolerance::absolute(1e-1),
+        );
+    }
+
+    #[test]
+    fn test_q_relu_affine_d2() {
+        let tensor = QTensor::<TestBackend, 2>::int8_affine([[0.0, -1.0, 2.0], [3.0, -4.0, -5.0]]);
+
+        let output = activation::relu(tensor);
+
+        // Precision 1 to approximate de/quantization errors
+        output.dequantize().into_data().assert_approx_eq::<FT>(
+            &TensorData::from([[0.0, 0.0, 2.0], [3.0, 0.0, 0.0]]),
+            Tolerance::absolute(1e-1),
+        );
+    }
+
+    #[test]
+    fn test_q_relu_matches_float_relu() {
+        let scheme = QuantScheme::default().set_propagation(QuantPropagation::Propagate);
+        let tensor = TestTensor::<2>::from_floats(
+            [[-1.0, 2.0, 0.7], [-0.5, 5.0, -3.3]],
+            &Default::default(),
+        )
+        .quantize_dynamic(&scheme);
+
+        let output = activation::relu(tensor.clone());
+
+        // The positive values are kept on the same quantization grid
+        assert!(matches!(output.to_data().dtype, DType::QFloat(_)));
+        output.dequantize().into_data().assert_approx_eq::<FT>(
+            &activation::relu(tensor.dequantize()).into_data(),
+            Tolerance::default(),
+        );
+    }
+}
@@ -23,6 +23,20 @@ mod tests {
         output.dequantize().into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_keep_quantized_values() {
+        let tensor = QTensor::<TestBackend, 2>::int8([[0.0, -1.5, 2.0], [3.3, 4.0, -5.0]]);
+
+        let output = tensor.clone().reshape([3, 2]);
+
+        // The quantized values and parameters are carried through unchanged
+        assert_eq!(output.to_data().as_bytes(), tensor.to_data().as_bytes());
+        output
+            .dequantize()
+            .into_data()
+            .assert_eq(&tensor.dequantize().reshape([3, 2]).into_data(), false);
+    }
+
     #[test]
     fn should_support_dim_infererence() {
         let tensor = QTensor::<TestBackend, 1>::int8([
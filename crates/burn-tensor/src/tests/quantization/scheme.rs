@@ -29,6 +29,28 @@ mod tests {
         assert!(qparams.offset.is_none());
     }
 
+    #[test]
+    fn per_tensor_affine_int8() {
+        let device = Default::default();
+        let scheme = QuantScheme::default().set_mode(QuantMode::Affine);
+        let range = CalibrationRange {
+            min: TestTensor::<1>::from_floats([-1.8], &device),
+            max: TestTensor::<1>::from_floats([0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.009_019_608]), Tolerance::default());
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([72]), false);
+    }
+
     #[test]
     fn quant_scheme_should_propagate() {
         let device = Default::default();
@@ -1,8 +1,9 @@
 use crate::LearnerSummaryConfig;
 use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{EarlyStoppingStrategy, TrainCallback};
 use crate::metric::store::EventStoreClient;
+use crate::profiler::{ChromeTraceExporter, Profiler};
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
 use burn_core::optim::Optimizer;
@@ -25,9 +26,11 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
     pub(crate) early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    pub(crate) callbacks: Vec<Box<dyn TrainCallback<LC::Model>>>,
     pub(crate) event_processor: LC::EventProcessor,
     pub(crate) event_store: Arc<EventStoreClient>,
     pub(crate) summary: Option<LearnerSummaryConfig>,
+    pub(crate) profiling: Option<(Profiler, ChromeTraceExporter)>,
 }
 
 #[derive(new)]
@@ -8,12 +8,13 @@ use crate::checkpoint::{
     KeepLastNCheckpoints, MetricCheckpointingStrategy,
 };
 use crate::components::LearnerComponentsMarker;
-use crate::learner::EarlyStoppingStrategy;
 use crate::learner::base::TrainingInterrupter;
+use crate::learner::{EarlyStoppingStrategy, TrainCallback};
 use crate::logger::{FileMetricLogger, MetricLogger};
 use crate::metric::processor::{AsyncProcessor, FullEventProcessor, ItemLazy, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
+use crate::profiler::{ChromeTraceExporter, Profiler};
 use crate::renderer::{MetricsRenderer, default_renderer};
 use crate::{
     ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCheckpointer,
@@ -57,8 +58,10 @@ where
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy>,
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    callbacks: Vec<Box<dyn TrainCallback<M>>>,
     summary_metrics: HashSet<String>,
     summary: bool,
+    profiling: Option<PathBuf>,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -105,8 +108,10 @@ where
                     .build(),
             ),
             early_stopping: None,
+            callbacks: Vec::new(),
             summary_metrics: HashSet::new(),
             summary: false,
+            profiling: None,
         }
     }
 
@@ -239,6 +244,17 @@ where
         self
     }
 
+    /// Register a [callback](TrainCallback) hooked into the training loop.
+    ///
+    /// The callbacks are called in the order they are registered.
+    pub fn callback<C>(mut self, callback: C) -> Self
+    where
+        C: TrainCallback<M> + 'static,
+    {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -284,6 +300,16 @@ where
         self
     }
 
+    /// Enable the profiling of the training loop.
+    ///
+    /// The time taken by the training, validation and checkpointing of each epoch is written at the
+    /// end of `.fit()` to the given file as a [Chrome trace](ChromeTraceExporter), which can be
+    /// opened in `chrome://tracing`.
+    pub fn enable_profiling(mut self, path: impl AsRef<Path>) -> Self {
+        self.profiling = Some(path.as_ref().to_path_buf());
+        self
+    }
+
     /// Create the [learner](Learner) from a [model](AutodiffModule) and an [optimizer](Optimizer).
     /// The [learning rate scheduler](LrScheduler) can also be a simple
     /// [learning rate](burn_core::LearningRate).
@@ -361,7 +387,11 @@ where
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
+            callbacks: self.callbacks,
             summary,
+            profiling: self
+                .profiling
+                .map(|path| (Profiler::new(), ChromeTraceExporter::new(path))),
         }
     }
 }
@@ -0,0 +1,26 @@
+/// A callback hooked into the training loop of the [learner](crate::Learner).
+///
+/// All the methods do nothing by default, so a callback only implements the events it is
+/// interested in.
+pub trait TrainCallback<M> {
+    /// Adjust the learning rate given by the learning rate scheduler for an iteration of the
+    /// training epoch.
+    fn learning_rate(&self, _epoch: usize, lr: f64) -> f64 {
+        lr
+    }
+
+    /// Called with the updated model at the end of each training epoch, returning the model the
+    /// training continues with, before [on_train_epoch_end](Self::on_train_epoch_end) and the
+    /// validation.
+    fn map_model(&mut self, model: M, _epoch: usize) -> M {
+        model
+    }
+
+    /// Called with the updated model at the end of each training epoch.
+    fn on_train_epoch_end(&mut self, _model: &M, _epoch: usize) {}
+
+    /// Called once the training is over, returning the model returned by the learner.
+    fn on_train_end(&mut self, model: M) -> M {
+        model
+    }
+}
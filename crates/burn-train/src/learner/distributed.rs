@@ -0,0 +1,347 @@
+use crate::{TrainOutput, TrainStep};
+use burn_core::data::dataset::Dataset;
+use burn_core::data::dataset::transform::PartialDataset;
+use burn_core::module::{AutodiffModule, ModuleVisitor, ParamId};
+use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::{Tensor, TensorData};
+use std::marker::PhantomData;
+use std::sync::{Arc, Condvar, Mutex};
+
+/// Collective communication between the processes (ranks) of a distributed training run.
+///
+/// Implement this trait to plug a transport such as MPI or NCCL into the
+/// [distributed trainer](DistributedTrainer). The [in-memory communicator](InMemoryCommunicator)
+/// can be used to run multiple ranks as threads of a single process.
+pub trait Communicator: Send {
+    /// The rank of the current process, in `0..world_size`.
+    fn rank(&self) -> usize;
+
+    /// The number of processes taking part in the training.
+    fn world_size(&self) -> usize;
+
+    /// Sums the given data element-wise over all the ranks.
+    ///
+    /// Every rank must call this function with data of the same shape, and blocks until all the
+    /// ranks have contributed. The sum is returned to every rank.
+    fn all_reduce_sum(&self, data: TensorData) -> TensorData;
+}
+
+#[derive(Default)]
+struct AllReduceState {
+    sum: Option<Vec<f64>>,
+    result: Vec<f64>,
+    arrived: usize,
+    generation: usize,
+}
+
+/// A [communicator](Communicator) between ranks running as threads of the same process.
+#[derive(Clone)]
+pub struct InMemoryCommunicator {
+    rank: usize,
+    world_size: usize,
+    state: Arc<(Mutex<AllReduceState>, Condvar)>,
+}
+
+impl InMemoryCommunicator {
+    /// Creates the communicators of a group of `world_size` ranks, indexed by rank.
+    ///
+    /// # Panics
+    ///
+    /// If `world_size` is zero.
+    pub fn group(world_size: usize) -> Vec<Self> {
+        assert!(world_size > 0, "The world size must be at least 1");
+        let state = Arc::new((Mutex::new(AllReduceState::default()), Condvar::new()));
+
+        (0..world_size)
+            .map(|rank| Self {
+                rank,
+                world_size,
+                state: state.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Communicator for InMemoryCommunicator {
+    fn rank(&self) -> usize {
+        self.rank
+    }
+
+    fn world_size(&self) -> usize {
+        self.world_size
+    }
+
+    fn all_reduce_sum(&self, data: TensorData) -> TensorData {
+        let (lock, condvar) = &*self.state;
+        let mut state = lock.lock().unwrap();
+        let generation = state.generation;
+
+        match state.sum.as_mut() {
+            Some(sum) => {
+                assert_eq!(
+                    sum.len(),
+                    data.num_elements(),
+                    "All ranks must reduce data of the same size"
+                );
+                sum.iter_mut()
+                    .zip(data.iter::<f64>())
+                    .for_each(|(acc, value)| *acc += value);
+            }
+            None => state.sum = Some(data.iter::<f64>().collect()),
+        }
+        state.arrived += 1;
+
+        if state.arrived == self.world_size {
+            // The result is only overwritten by the next reduction, once every rank has arrived
+            // again, and so after every rank has read it.
+            state.result = state.sum.take().unwrap();
+            state.arrived = 0;
+            state.generation += 1;
+            condvar.notify_all();
+        } else {
+            while state.generation == generation {
+                state = condvar.wait(state).unwrap();
+            }
+        }
+
+        TensorData::new(state.result.clone(), data.shape.clone()).convert_dtype(data.dtype)
+    }
+}
+
+/// Data-parallel trainer, where each rank trains a replica of the model on its own shard of the
+/// data.
+///
+/// After the forward and backward passes of a [training step](TrainStep), the gradients are
+/// averaged over all the ranks with the [communicator](Communicator) before the optimizer step,
+/// so the replicas stay identical as long as they start from the same weights.
+pub struct DistributedTrainer<B: AutodiffBackend, C: Communicator> {
+    communicator: C,
+    _backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend, C: Communicator> DistributedTrainer<B, C> {
+    /// Creates a new distributed trainer for the rank of the given communicator.
+    pub fn new(communicator: C) -> Self {
+        Self {
+            communicator,
+            _backend: PhantomData,
+        }
+    }
+
+    /// The rank of the current process.
+    pub fn rank(&self) -> usize {
+        self.communicator.rank()
+    }
+
+    /// The number of processes taking part in the training.
+    pub fn world_size(&self) -> usize {
+        self.communicator.world_size()
+    }
+
+    /// The contiguous shard of the dataset assigned to the current rank.
+    ///
+    /// All the shards have the same length, so that every rank runs the same number of steps.
+    /// The last `dataset.len() % world_size` items are dropped.
+    pub fn shard<D, I>(&self, dataset: Arc<D>) -> PartialDataset<Arc<D>, I>
+    where
+        D: Dataset<I>,
+    {
+        let shard_size = dataset.len() / self.world_size();
+        let start = self.rank() * shard_size;
+
+        PartialDataset::new(dataset, start, start + shard_size)
+    }
+
+    /// Averages the gradients of the model parameters over all the ranks.
+    ///
+    /// Every rank must have gradients for the same parameters.
+    pub fn all_reduce<M: AutodiffModule<B>>(
+        &self,
+        model: &M,
+        mut grads: GradientsParams,
+    ) -> GradientsParams {
+        let mut visitor = GradientsAllReduce::<M, B, C> {
+            communicator: &self.communicator,
+            grads: &mut grads,
+            phantom: PhantomData,
+        };
+        model.visit(&mut visitor);
+
+        grads
+    }
+
+    /// Runs a training step on the item of the current rank, then updates the model with the
+    /// gradients averaged over all the ranks.
+    ///
+    /// Every rank must call this function the same number of times.
+    pub fn step<M, O, TI, TO>(&self, model: M, optim: &mut O, lr: f64, item: TI) -> (M, TO)
+    where
+        M: AutodiffModule<B> + TrainStep<TI, TO>,
+        O: Optimizer<M, B>,
+    {
+        let TrainOutput { grads, item } = model.step(item);
+        let grads = self.all_reduce(&model, grads);
+
+        (model.optimize(optim, lr, grads), item)
+    }
+}
+
+struct GradientsAllReduce<'a, M, B, C> {
+    communicator: &'a C,
+    grads: &'a mut GradientsParams,
+    phantom: PhantomData<(M, B)>,
+}
+
+impl<B, M, C> ModuleVisitor<B> for GradientsAllReduce<'_, M, B, C>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    C: Communicator,
+{
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+
+        let device = grad.device();
+        let sum = self.communicator.all_reduce_sum(grad.into_data());
+        let mean = Tensor::<B::InnerBackend, D>::from_data(sum, &device)
+            .div_scalar(self.communicator.world_size() as f64);
+
+        self.grads.register::<B::InnerBackend, D>(id, mean);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RegressionOutput, TestAutodiffBackend};
+    use burn_core as burn;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::module::Module;
+    use burn_core::nn::loss::{MseLoss, Reduction};
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::SgdConfig;
+    use burn_core::tensor::backend::Backend;
+    use burn_core::tensor::{Tolerance, ops::FloatElem};
+    use std::thread;
+
+    type B = TestAutodiffBackend;
+    type FT = FloatElem<B>;
+
+    const LR: f64 = 0.1;
+    const NUM_STEPS: usize = 3;
+
+    #[derive(Module, Debug)]
+    struct Regressor<B: Backend> {
+        linear: Linear<B>,
+    }
+
+    type Batch<B> = (Tensor<B, 2>, Tensor<B, 2>);
+
+    impl<B: AutodiffBackend> TrainStep<Batch<B>, RegressionOutput<B>> for Regressor<B> {
+        fn step(&self, (inputs, targets): Batch<B>) -> TrainOutput<RegressionOutput<B>> {
+            let output = self.linear.forward(inputs);
+            let loss = MseLoss::new().forward(output.clone(), targets.clone(), Reduction::Mean);
+
+            TrainOutput::new(
+                self,
+                loss.backward(),
+                RegressionOutput::new(loss, output, targets),
+            )
+        }
+    }
+
+    fn batch(start: usize, end: usize) -> Batch<B> {
+        let device = Default::default();
+        let inputs = Tensor::<B, 2>::from_floats(
+            [[1.0, 2.0], [-1.0, 0.5], [0.0, -3.0], [2.0, 1.0]],
+            &device,
+        );
+        let targets = Tensor::<B, 2>::from_floats([[1.0], [0.0], [-2.0], [3.0]], &device);
+
+        (
+            inputs.slice([start..end, 0..2]),
+            targets.slice([start..end, 0..1]),
+        )
+    }
+
+    fn train<C: Communicator>(
+        trainer: &DistributedTrainer<B, C>,
+        mut model: Regressor<B>,
+        (start, end): (usize, usize),
+    ) -> Regressor<B> {
+        let mut optim = SgdConfig::new().init();
+
+        for _ in 0..NUM_STEPS {
+            (model, _) = trainer.step(model, &mut optim, LR, batch(start, end));
+        }
+
+        model
+    }
+
+    #[test]
+    fn test_gradient_averaging_matches_single_process_double_batch() {
+        let model = Regressor::<B> {
+            linear: LinearConfig::new(2, 1).init(&Default::default()),
+        };
+
+        let replicas = InMemoryCommunicator::group(2)
+            .into_iter()
+            .map(|communicator| {
+                let model = model.clone();
+                thread::spawn(move || {
+                    let trainer = DistributedTrainer::new(communicator);
+                    let start = trainer.rank() * 2;
+                    train(&trainer, model, (start, start + 2))
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        let single = InMemoryCommunicator::group(1).remove(0);
+        let expected = train(&DistributedTrainer::new(single), model, (0, 4));
+
+        for replica in replicas {
+            replica
+                .linear
+                .weight
+                .val()
+                .into_data()
+                .assert_approx_eq::<FT>(
+                    &expected.linear.weight.val().into_data(),
+                    Tolerance::default(),
+                );
+            replica
+                .linear
+                .bias
+                .unwrap()
+                .val()
+                .into_data()
+                .assert_approx_eq::<FT>(
+                    &expected.linear.bias.as_ref().unwrap().val().into_data(),
+                    Tolerance::default(),
+                );
+        }
+    }
+
+    #[test]
+    fn test_shards_have_equal_length() {
+        let dataset = Arc::new(InMemDataset::new((0..7).collect::<Vec<i32>>()));
+
+        let shards = InMemoryCommunicator::group(3)
+            .into_iter()
+            .map(|communicator| {
+                DistributedTrainer::<B, _>::new(communicator)
+                    .shard(dataset.clone())
+                    .iter()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(shards, vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+    }
+}
@@ -7,7 +7,7 @@ use burn_core::{
 use std::sync::Arc;
 
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{MultiDevicesTrainStep, TrainCallback, TrainStep, ValidStep};
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
 
 /// A validation epoch.
@@ -84,6 +84,7 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks adjusting the learning rate.
     ///
     /// # Returns
     ///
@@ -95,6 +96,7 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
         interrupter: &TrainingInterrupter,
+        callbacks: &[Box<dyn TrainCallback<LC::Model>>],
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -110,7 +112,9 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
 
         while let Some(item) = iterator.next() {
             iteration += 1;
-            let lr = scheduler.step();
+            let lr = callbacks.iter().fold(scheduler.step(), |lr, callback| {
+                callback.learning_rate(self.epoch, lr)
+            });
             log::info!("Iteration {}", iteration);
 
             let progress = iterator.progress();
@@ -164,10 +168,12 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
     /// * `devices` - The devices to use.
+    /// * `callbacks` - The callbacks adjusting the learning rate.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents<Backend = B>, TO>(
         &mut self,
         mut model: LC::Model,
@@ -176,6 +182,7 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
         processor: &mut LC::EventProcessor,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
+        callbacks: &[Box<dyn TrainCallback<LC::Model>>],
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -209,7 +216,9 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
 
             for item in items {
                 iteration += 1;
-                let lr = lr_scheduler.step();
+                let lr = callbacks.iter().fold(lr_scheduler.step(), |lr, callback| {
+                    callback.learning_rate(self.epoch, lr)
+                });
 
                 // TODO: aggregate multi device (all-reduce)
                 let grads = item.grads.to_device(&device_main, &model);
@@ -0,0 +1,166 @@
+use burn_core::tensor::{Distribution, Tensor, backend::AutodiffBackend};
+
+/// The step of the central finite difference estimating the norm of the critic gradients.
+const FINITE_DIFFERENCE_STEP: f64 = 1e-3;
+
+/// The gradient penalty of Wasserstein GANs (WGAN-GP), `lambda * (||∇D(x̂)||₂ - center)²` averaged
+/// over random interpolations `x̂` between real and fake samples.
+///
+/// The penalty is meant to be added to the loss of the critic (discriminator) in
+/// [step](crate::TrainStep::step) before the backward pass, see
+/// [add_to_loss](GradientPenalty::add_to_loss).
+///
+/// # Notes
+///
+/// The autodiff backend doesn't compute second order derivatives, so the input gradients can't be
+/// differentiated with respect to the critic parameters. The norm of the input gradient `g` is the
+/// derivative of the critic along `u = g / ||g||`, which is instead estimated with a central
+/// finite difference of the critic along `u`, differentiated like any other forward pass.
+#[derive(new, Clone, Debug)]
+pub struct GradientPenalty {
+    /// The target norm of the gradients.
+    pub center: f64,
+    /// The weight of the penalty in the loss.
+    pub lambda: f64,
+}
+
+impl Default for GradientPenalty {
+    /// The penalty of the WGAN-GP paper, with a center of 1 and a lambda of 10.
+    fn default() -> Self {
+        Self::new(1.0, 10.0)
+    }
+}
+
+impl GradientPenalty {
+    /// Computes the gradient penalty of the critic.
+    ///
+    /// # Arguments
+    ///
+    /// * `critic` - The forward pass of the critic, returning a score of shape `[batch_size, 1]`.
+    /// * `real` - The real samples, with the batch as first dimension.
+    /// * `fake` - The generated samples, of the same shape as `real`.
+    ///
+    /// # Returns
+    ///
+    /// The penalty of shape `[1]`.
+    pub fn penalty<B, const D: usize, F>(
+        &self,
+        critic: F,
+        real: Tensor<B, D>,
+        fake: Tensor<B, D>,
+    ) -> Tensor<B, 1>
+    where
+        B: AutodiffBackend,
+        F: Fn(Tensor<B, D>) -> Tensor<B, 2>,
+    {
+        let device = real.device();
+        let batch_size = real.dims()[0];
+        let mut sample_shape = [1; D];
+        sample_shape[0] = batch_size;
+
+        // One interpolation factor per sample
+        let alpha =
+            Tensor::<B::InnerBackend, D>::random(sample_shape, Distribution::Default, &device);
+        let interpolated = real.inner() * alpha.clone() + fake.inner() * (alpha.neg() + 1.0);
+
+        let input = Tensor::<B, D>::from_inner(interpolated.clone()).require_grad();
+        let grads = critic(input.clone()).sum().backward();
+        let gradients = input.grad(&grads).unwrap();
+
+        let norm = gradients
+            .clone()
+            .reshape([batch_size as i32, -1])
+            .powi_scalar(2)
+            .sum_dim(1)
+            .sqrt()
+            .clamp_min(f32::EPSILON);
+        let step = (gradients / norm.reshape(sample_shape)).mul_scalar(FINITE_DIFFERENCE_STEP);
+
+        let forward = critic(Tensor::from_inner(interpolated.clone() + step.clone()));
+        let backward = critic(Tensor::from_inner(interpolated - step));
+        let norm = (forward - backward)
+            .div_scalar(2.0 * FINITE_DIFFERENCE_STEP)
+            .reshape([batch_size]);
+
+        norm.sub_scalar(self.center)
+            .powi_scalar(2)
+            .mean()
+            .mul_scalar(self.lambda)
+    }
+
+    /// Adds the gradient penalty of the critic to its loss.
+    ///
+    /// See [penalty](GradientPenalty::penalty) for the arguments.
+    pub fn add_to_loss<B, const D: usize, F>(
+        &self,
+        loss: Tensor<B, 1>,
+        critic: F,
+        real: Tensor<B, D>,
+        fake: Tensor<B, D>,
+    ) -> Tensor<B, 1>
+    where
+        B: AutodiffBackend,
+        F: Fn(Tensor<B, D>) -> Tensor<B, 2>,
+    {
+        loss + self.penalty(critic, real, fake)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::tensor::{TensorData, Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestAutodiffBackend>;
+
+    fn samples() -> [Tensor<TestAutodiffBackend, 2>; 2] {
+        let device = Default::default();
+        [
+            Tensor::random([4, 3], Distribution::Default, &device),
+            Tensor::random([4, 3], Distribution::Default, &device),
+        ]
+    }
+
+    /// A linear critic, its gradients with respect to the input are the weights.
+    fn linear(
+        weight: Tensor<TestAutodiffBackend, 1>,
+    ) -> impl Fn(Tensor<TestAutodiffBackend, 2>) -> Tensor<TestAutodiffBackend, 2> {
+        move |input| input.matmul(weight.clone().unsqueeze_dim(1))
+    }
+
+    #[test]
+    fn penalty_is_zero_with_unit_norm_gradients() {
+        let weight = Tensor::from_floats([0.6, 0.8, 0.0], &Default::default());
+        let [real, fake] = samples();
+
+        let penalty = GradientPenalty::default().penalty(linear(weight), real, fake);
+
+        penalty
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([0.0]), Tolerance::absolute(1e-4));
+    }
+
+    #[test]
+    fn penalty_is_differentiated_with_respect_to_the_critic() {
+        let weight = Tensor::from_floats([1.2, 1.6, 0.0], &Default::default()).require_grad();
+        let [real, fake] = samples();
+        let loss = Tensor::zeros([1], &Default::default());
+
+        let loss = GradientPenalty::default().add_to_loss(loss, linear(weight.clone()), real, fake);
+        let grads = loss.clone().backward();
+
+        // lambda * (||w|| - 1)² with ||w|| = 2
+        loss.into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([10.0]), Tolerance::rel_abs(1e-2, 1e-2));
+        // 2 * lambda * (||w|| - 1) * w / ||w||
+        weight
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq::<FT>(
+                &TensorData::from([12.0, 16.0, 0.0]),
+                Tolerance::rel_abs(1e-2, 1e-2),
+            );
+    }
+}
@@ -0,0 +1,275 @@
+use crate::TrainStep;
+use crate::metric::{Adaptor, LossInput};
+use burn_core::data::dataloader::DataLoader;
+use burn_core::module::AutodiffModule;
+use burn_core::optim::Optimizer;
+use burn_core::tensor::ElementConversion;
+use burn_core::tensor::backend::AutodiffBackend;
+use std::sync::Arc;
+
+/// The range test stops early once the loss exceeds the best loss by this factor.
+const DIVERGENCE_FACTOR: f64 = 4.0;
+
+/// Learning rate range test, used to find a good learning rate for a model before training.
+///
+/// The model is trained for `num_iterations` steps while the learning rate grows exponentially
+/// from `min_lr` to `max_lr`. The loss is recorded at each step, and the suggested learning rate
+/// is the one where the loss decreases the fastest.
+///
+/// Reference: [Cyclical Learning Rates for Training Neural Networks](https://arxiv.org/abs/1506.01186)
+#[derive(Debug, Clone)]
+pub struct LearningRateFinder {
+    min_lr: f64,
+    max_lr: f64,
+    num_iterations: usize,
+}
+
+/// The losses recorded by a [learning rate finder](LearningRateFinder) run.
+#[derive(Debug, Clone)]
+pub struct LearningRateFinderResult {
+    /// The learning rate used at each iteration.
+    pub learning_rates: Vec<f64>,
+    /// The training loss obtained at each iteration, before the optimizer step.
+    pub losses: Vec<f64>,
+}
+
+impl LearningRateFinder {
+    /// Create a new learning rate finder.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_lr` - The learning rate of the first iteration.
+    /// * `max_lr` - The learning rate of the last iteration.
+    /// * `num_iterations` - The number of training steps to run.
+    ///
+    /// # Panics
+    ///
+    /// If the learning rates are not positive with `min_lr < max_lr`, or if fewer than two
+    /// iterations are requested.
+    pub fn new(min_lr: f64, max_lr: f64, num_iterations: usize) -> Self {
+        assert!(
+            min_lr > 0.0 && min_lr < max_lr,
+            "The learning rate range must be positive with min_lr < max_lr, got [{min_lr}, {max_lr}]"
+        );
+        assert!(
+            num_iterations >= 2,
+            "The learning rate finder needs at least 2 iterations, got {num_iterations}"
+        );
+
+        Self {
+            min_lr,
+            max_lr,
+            num_iterations,
+        }
+    }
+
+    /// The learning rate used at the given iteration.
+    fn learning_rate(&self, iteration: usize) -> f64 {
+        let progress = iteration as f64 / (self.num_iterations - 1) as f64;
+        self.min_lr * (self.max_lr / self.min_lr).powf(progress)
+    }
+
+    /// Run the range test.
+    ///
+    /// The dataloader is iterated as many times as needed to reach the number of iterations. The
+    /// test stops early if the loss diverges.
+    ///
+    /// The model and the optimizer are updated during the test, so a fresh model should be used
+    /// for the actual training.
+    pub fn run<B, M, O, I, TO>(
+        &self,
+        mut model: M,
+        mut optim: O,
+        dataloader: Arc<dyn DataLoader<B, I>>,
+    ) -> LearningRateFinderResult
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B> + TrainStep<I, TO>,
+        O: Optimizer<M, B>,
+        TO: Adaptor<LossInput<B>>,
+    {
+        let mut learning_rates = Vec::with_capacity(self.num_iterations);
+        let mut losses = Vec::with_capacity(self.num_iterations);
+        let mut best_loss = f64::INFINITY;
+
+        'outer: while learning_rates.len() < self.num_iterations {
+            let mut iterator = dataloader.iter().peekable();
+
+            if iterator.peek().is_none() {
+                log::warn!("Learning rate finder stopped: the dataloader is empty.");
+                break;
+            }
+
+            for item in iterator {
+                let lr = self.learning_rate(learning_rates.len());
+                let output = model.step(item);
+                let loss = output
+                    .item
+                    .adapt()
+                    .tensor
+                    .mean()
+                    .into_scalar()
+                    .elem::<f64>();
+
+                learning_rates.push(lr);
+                losses.push(loss);
+
+                if !loss.is_finite() || loss > best_loss * DIVERGENCE_FACTOR {
+                    log::info!("Learning rate finder stopped: the loss diverged at lr {lr}.");
+                    break 'outer;
+                }
+                best_loss = best_loss.min(loss);
+
+                model = model.optimize(&mut optim, lr, output.grads);
+
+                if learning_rates.len() == self.num_iterations {
+                    break 'outer;
+                }
+            }
+        }
+
+        LearningRateFinderResult {
+            learning_rates,
+            losses,
+        }
+    }
+}
+
+impl LearningRateFinderResult {
+    /// The suggested learning rate, where the loss decreases the fastest.
+    ///
+    /// The decrease is measured on the logarithm of the loss, which is the relative improvement
+    /// obtained by each optimizer step. Returns `None` if fewer than two finite and positive
+    /// losses were recorded.
+    pub fn suggestion(&self) -> Option<f64> {
+        self.losses
+            .windows(2)
+            .zip(self.learning_rates.iter())
+            .filter(|(losses, _)| losses.iter().all(|loss| loss.is_finite() && *loss > 0.0))
+            .map(|(losses, lr)| (losses[1].ln() - losses[0].ln(), *lr))
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, lr)| lr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TrainOutput};
+    use burn_core as burn;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataloader::batcher::Batcher;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::module::{Module, Param};
+    use burn_core::optim::SgdConfig;
+    use burn_core::tensor::{Tensor, backend::Backend};
+
+    /// Curvature of the toy quadratic `a * (w - target)^2`.
+    const CURVATURE: f64 = 2.0;
+    const TARGET: f32 = 3.0;
+
+    #[derive(Module, Debug)]
+    struct Quadratic<B: Backend> {
+        weight: Param<Tensor<B, 1>>,
+    }
+
+    struct QuadraticOutput<B: Backend> {
+        loss: Tensor<B, 1>,
+    }
+
+    impl<B: Backend> Adaptor<LossInput<B>> for QuadraticOutput<B> {
+        fn adapt(&self) -> LossInput<B> {
+            LossInput::new(self.loss.clone())
+        }
+    }
+
+    impl<B: AutodiffBackend> TrainStep<Tensor<B, 1>, QuadraticOutput<B>> for Quadratic<B> {
+        fn step(&self, target: Tensor<B, 1>) -> TrainOutput<QuadraticOutput<B>> {
+            let loss = (self.weight.val() - target)
+                .powi_scalar(2)
+                .mul_scalar(CURVATURE)
+                .sum();
+
+            TrainOutput::new(self, loss.backward(), QuadraticOutput { loss })
+        }
+    }
+
+    #[derive(Clone)]
+    struct TargetBatcher;
+
+    impl<B: Backend> Batcher<B, f32, Tensor<B, 1>> for TargetBatcher {
+        fn batch(&self, items: Vec<f32>, device: &B::Device) -> Tensor<B, 1> {
+            Tensor::from_floats(items.as_slice(), device)
+        }
+    }
+
+    fn dataloader() -> Arc<dyn DataLoader<TestAutodiffBackend, Tensor<TestAutodiffBackend, 1>>> {
+        DataLoaderBuilder::new(TargetBatcher)
+            .batch_size(1)
+            .build(InMemDataset::new(vec![TARGET; 8]))
+    }
+
+    fn model() -> Quadratic<TestAutodiffBackend> {
+        Quadratic {
+            weight: Param::from_tensor(Tensor::zeros([1], &Default::default())),
+        }
+    }
+
+    #[test]
+    fn test_suggestion_close_to_optimal_lr_on_quadratic() {
+        // Gradient descent on `a * (w - target)^2` reaches the minimum in a single step with
+        // `lr = 1 / (2a)`.
+        let optimal_lr = 1.0 / (2.0 * CURVATURE);
+        let finder = LearningRateFinder::new(1e-4, 10.0, 50);
+
+        let result = finder.run(
+            model(),
+            SgdConfig::new().init::<TestAutodiffBackend, Quadratic<TestAutodiffBackend>>(),
+            dataloader(),
+        );
+        let suggestion = result.suggestion().unwrap();
+
+        assert!(
+            suggestion > optimal_lr / 2.0 && suggestion < optimal_lr * 2.0,
+            "Suggested lr {suggestion} should be within 2x of {optimal_lr}"
+        );
+    }
+
+    #[test]
+    fn test_learning_rates_grow_exponentially() {
+        let finder = LearningRateFinder::new(1e-3, 1e-1, 3);
+
+        let result = finder.run(
+            model(),
+            SgdConfig::new().init::<TestAutodiffBackend, Quadratic<TestAutodiffBackend>>(),
+            dataloader(),
+        );
+
+        assert_eq!(result.losses.len(), 3);
+        for (lr, expected) in result.learning_rates.iter().zip([1e-3, 1e-2, 1e-1]) {
+            assert!((lr - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_stops_when_loss_diverges() {
+        let finder = LearningRateFinder::new(1e-4, 1e3, 100);
+
+        let result = finder.run(
+            model(),
+            SgdConfig::new().init::<TestAutodiffBackend, Quadratic<TestAutodiffBackend>>(),
+            dataloader(),
+        );
+
+        let (last, previous) = result.losses.split_last().unwrap();
+        let best = previous.iter().copied().fold(f64::INFINITY, f64::min);
+        assert!(result.losses.len() < 100);
+        assert!(*last > best * DIVERGENCE_FACTOR || !last.is_finite());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_range() {
+        let _ = LearningRateFinder::new(1.0, 1e-3, 10);
+    }
+}
@@ -0,0 +1,397 @@
+use burn_core::module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId};
+use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{ElementConversion, FloatDType, Tensor};
+use core::marker::PhantomData;
+
+/// Dynamic loss scale, preventing the gradients computed in half precision from underflowing.
+///
+/// The scale is multiplied by `growth_factor` after `growth_interval` consecutive steps with
+/// finite gradients, and by `backoff_factor` as soon as the gradients overflow, in which case the
+/// optimizer step is skipped.
+#[derive(Debug, Clone)]
+pub struct LossScaler {
+    scale: f64,
+    growth_factor: f64,
+    backoff_factor: f64,
+    growth_interval: usize,
+    num_finite_steps: usize,
+}
+
+impl Default for LossScaler {
+    /// A scale of `2^16`, doubled every 2000 steps with finite gradients and halved on overflow.
+    fn default() -> Self {
+        Self::new(65536.0)
+    }
+}
+
+impl LossScaler {
+    /// Create a new loss scaler starting with the given scale.
+    ///
+    /// # Panics
+    ///
+    /// If the scale is not positive.
+    pub fn new(init_scale: f64) -> Self {
+        assert!(
+            init_scale > 0.0,
+            "The loss scale must be positive, got {init_scale}"
+        );
+
+        Self {
+            scale: init_scale,
+            growth_factor: 2.0,
+            backoff_factor: 0.5,
+            growth_interval: 2000,
+            num_finite_steps: 0,
+        }
+    }
+
+    /// The factor the scale is multiplied by after `growth_interval` steps with finite gradients.
+    pub fn with_growth_factor(mut self, growth_factor: f64) -> Self {
+        assert!(
+            growth_factor > 1.0,
+            "The growth factor must be greater than 1, got {growth_factor}"
+        );
+        self.growth_factor = growth_factor;
+        self
+    }
+
+    /// The factor the scale is multiplied by when the gradients overflow.
+    pub fn with_backoff_factor(mut self, backoff_factor: f64) -> Self {
+        assert!(
+            backoff_factor > 0.0 && backoff_factor < 1.0,
+            "The backoff factor must be between 0 and 1, got {backoff_factor}"
+        );
+        self.backoff_factor = backoff_factor;
+        self
+    }
+
+    /// The number of consecutive steps with finite gradients before the scale grows.
+    pub fn with_growth_interval(mut self, growth_interval: usize) -> Self {
+        assert!(
+            growth_interval > 0,
+            "The growth interval must be positive, got {growth_interval}"
+        );
+        self.growth_interval = growth_interval;
+        self
+    }
+
+    /// The current loss scale.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Update the scale after a step, given whether its gradients were finite.
+    pub fn update(&mut self, finite: bool) {
+        if !finite {
+            self.scale *= self.backoff_factor;
+            self.num_finite_steps = 0;
+            return;
+        }
+
+        self.num_finite_steps += 1;
+        if self.num_finite_steps == self.growth_interval {
+            self.scale *= self.growth_factor;
+            self.num_finite_steps = 0;
+        }
+    }
+}
+
+/// The result of a [mixed precision](MixedPrecisionTrainer) training step.
+#[derive(Debug, Clone)]
+pub struct MixedPrecisionStep<B: Backend> {
+    /// The loss of the step, unscaled and in full precision.
+    pub loss: Tensor<B, 1>,
+    /// Whether the optimizer step was skipped because the gradients overflowed.
+    pub skipped: bool,
+}
+
+/// Mixed precision training, running the forward pass in half precision while the optimizer
+/// updates the full precision weights of the model.
+///
+/// Each [step](MixedPrecisionTrainer::step) casts the parameters of the model and the input to
+/// the half precision data type, computes the loss, and scales it by the [loss scale](LossScaler)
+/// before the backward pass. The gradients of the full precision weights are then unscaled, and
+/// the optimizer step is skipped if any of them isn't finite.
+pub struct MixedPrecisionTrainer<B: AutodiffBackend, M> {
+    model: M,
+    scaler: LossScaler,
+    dtype: FloatDType,
+    _backend: PhantomData<B>,
+}
+
+impl<B, M> MixedPrecisionTrainer<B, M>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    /// Create a new mixed precision trainer of the given full precision model, with a forward
+    /// pass in `f16` and the [default](LossScaler::default) loss scaler.
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            scaler: LossScaler::default(),
+            dtype: FloatDType::F16,
+            _backend: PhantomData,
+        }
+    }
+
+    /// Set the loss scaler.
+    pub fn with_scaler(mut self, scaler: LossScaler) -> Self {
+        self.scaler = scaler;
+        self
+    }
+
+    /// Set the data type of the forward pass, `f16` by default.
+    pub fn with_dtype(mut self, dtype: FloatDType) -> Self {
+        self.dtype = dtype;
+        self
+    }
+
+    /// The loss scaler.
+    pub fn scaler(&self) -> &LossScaler {
+        &self.scaler
+    }
+
+    /// The full precision model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Consume the trainer, returning the full precision model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    /// Cast a tensor to the data type of the forward pass.
+    ///
+    /// The input of a [step](MixedPrecisionTrainer::step) is cast automatically, other tensors
+    /// used in the forward pass, such as the targets, should be cast with this method.
+    pub fn cast<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        tensor.cast(self.dtype)
+    }
+
+    /// Run a training step.
+    ///
+    /// # Arguments
+    ///
+    /// * `optim` - The optimizer updating the full precision model.
+    /// * `lr` - The learning rate of the step.
+    /// * `input` - The input of the forward pass, cast to half precision.
+    /// * `forward` - The forward pass, computing the loss of the half precision model.
+    pub fn step<const D: usize, O, F>(
+        &mut self,
+        optim: &mut O,
+        lr: f64,
+        input: Tensor<B, D>,
+        forward: F,
+    ) -> MixedPrecisionStep<B>
+    where
+        O: Optimizer<M, B>,
+        F: FnOnce(&M, Tensor<B, D>) -> Tensor<B, 1>,
+    {
+        // The cast parameters keep the ids of the full precision ones, and their gradients flow
+        // back to the full precision weights through the cast.
+        let model = self
+            .model
+            .clone()
+            .map(&mut ParamCaster { dtype: self.dtype });
+        let loss = forward(&model, self.cast(input)).cast(FloatDType::F32);
+
+        let scale = self.scaler.scale();
+        let grads = loss.clone().mul_scalar(scale).backward();
+        let mut grads = GradientsParams::from_grads(grads, &self.model);
+
+        let mut unscaler = GradientsUnscaler::<B, M>::new(&mut grads, scale);
+        self.model.visit(&mut unscaler);
+        let finite = unscaler.into_finite();
+
+        self.scaler.update(finite);
+        if finite {
+            self.model = optim.step(lr, self.model.clone(), grads);
+        } else {
+            log::warn!(
+                "Mixed precision step skipped: the gradients overflowed with scale {scale}."
+            );
+        }
+
+        MixedPrecisionStep {
+            loss: loss.detach(),
+            skipped: !finite,
+        }
+    }
+}
+
+/// Cast the float parameters of a module to the given data type.
+struct ParamCaster {
+    dtype: FloatDType,
+}
+
+impl<B: Backend> ModuleMapper<B> for ParamCaster {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        tensor.cast(self.dtype)
+    }
+}
+
+/// Divide the gradients of each parameter by the loss scale, checking that they are finite.
+struct GradientsUnscaler<'a, B: AutodiffBackend, M> {
+    grads: &'a mut GradientsParams,
+    scale: f64,
+    check: Option<Tensor<B::InnerBackend, 1>>,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, B: AutodiffBackend, M> GradientsUnscaler<'a, B, M> {
+    fn new(grads: &'a mut GradientsParams, scale: f64) -> Self {
+        Self {
+            grads,
+            scale,
+            check: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Whether every gradient is finite, reading the check accumulated over all the parameters
+    /// back from the device once.
+    fn into_finite(self) -> bool {
+        self.check
+            .map(|check| check.into_scalar().elem::<f64>().is_finite())
+            .unwrap_or(true)
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B> for GradientsUnscaler<'_, B, M> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+        let grad = grad.div_scalar(self.scale);
+
+        // Zero times an infinite or NaN value is NaN, so the sum is only finite when every
+        // gradient is.
+        let check = grad.clone().mul_scalar(0.0).sum().cast(FloatDType::F32);
+        self.check = Some(match self.check.take() {
+            Some(previous) => previous + check,
+            None => check,
+        });
+
+        self.grads.register::<B::InnerBackend, D>(id, grad);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::SgdConfig;
+    use burn_core::tensor::{Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestAutodiffBackend>;
+
+    fn linear() -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(3, 2).init(&Default::default())
+    }
+
+    fn input() -> Tensor<TestAutodiffBackend, 2> {
+        Tensor::from_floats([[1.0, -2.0, 0.5], [0.0, 3.0, -1.0]], &Default::default())
+    }
+
+    fn loss(
+        model: &Linear<TestAutodiffBackend>,
+        input: Tensor<TestAutodiffBackend, 2>,
+    ) -> Tensor<TestAutodiffBackend, 1> {
+        model.forward(input).powi_scalar(2).mean()
+    }
+
+    /// The NdArray backend has no half precision, the forward pass is run in `f32`.
+    fn trainer(
+        scaler: LossScaler,
+    ) -> MixedPrecisionTrainer<TestAutodiffBackend, Linear<TestAutodiffBackend>> {
+        MixedPrecisionTrainer::new(linear())
+            .with_scaler(scaler)
+            .with_dtype(FloatDType::F32)
+    }
+
+    #[test]
+    fn scaled_step_matches_unscaled_step() {
+        let mut trainer = trainer(LossScaler::new(1024.0));
+        let model = trainer.model().clone();
+        let mut optim = SgdConfig::new().init();
+
+        let output = trainer.step(&mut optim, 0.1, input(), loss);
+
+        let grads = GradientsParams::from_grads(loss(&model, input()).backward(), &model);
+        let expected = SgdConfig::new().init().step(0.1, model, grads);
+
+        assert!(!output.skipped);
+        trainer
+            .into_model()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected.weight.val().into_data(), Tolerance::default());
+    }
+
+    #[test]
+    fn scale_grows_after_growth_interval() {
+        let mut trainer = trainer(LossScaler::new(8.0).with_growth_interval(3));
+        let mut optim = SgdConfig::new().init();
+
+        let mut scales = Vec::new();
+        for _ in 0..6 {
+            trainer.step(&mut optim, 0.01, input(), loss);
+            scales.push(trainer.scaler().scale());
+        }
+
+        assert_eq!(scales, [8.0, 8.0, 16.0, 16.0, 16.0, 32.0]);
+    }
+
+    #[test]
+    fn overflow_skips_step_and_backs_off() {
+        // The scaled gradients overflow f32 until the scale is small enough
+        let mut trainer = trainer(LossScaler::new(4e39));
+        let weight = trainer.model().weight.val().into_data();
+        let mut optim = SgdConfig::new().init();
+
+        let output = trainer.step(&mut optim, 0.1, input(), loss);
+
+        assert!(output.skipped);
+        assert_eq!(trainer.scaler().scale(), 2e39);
+        trainer
+            .model()
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&weight, true);
+
+        let num_skipped = (0..20)
+            .take_while(|_| trainer.step(&mut optim, 0.1, input(), loss).skipped)
+            .count();
+
+        // The scale is at most f32::MAX once the step is applied
+        assert!((3..20).contains(&num_skipped));
+        assert!(trainer.scaler().scale() < f32::MAX as f64);
+        assert_ne!(trainer.model().weight.val().into_data(), weight);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_scale() {
+        let _ = LossScaler::new(0.0);
+    }
+
+    #[test]
+    fn loss_is_unscaled() {
+        let mut trainer = trainer(LossScaler::new(1024.0));
+        let expected = loss(trainer.model(), input()).into_data();
+        let mut optim = SgdConfig::new().init();
+
+        let output = trainer.step(&mut optim, 0.1, input(), loss);
+
+        output
+            .loss
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+    }
+}
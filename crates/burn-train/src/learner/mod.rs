@@ -1,22 +1,36 @@
 mod application_logger;
 mod base;
 mod builder;
+mod callback;
 mod classification;
+mod distributed;
 mod early_stopping;
 mod epoch;
+mod gradient_penalty;
+mod lr_finder;
+mod mixed_precision;
+mod pruning;
 mod regression;
 mod step;
 mod summary;
+mod swa;
 mod train_val;
 
 pub use application_logger::*;
 pub use base::*;
 pub use builder::*;
+pub use callback::*;
 pub use classification::*;
+pub use distributed::*;
 pub use early_stopping::*;
 pub use epoch::*;
+pub use gradient_penalty::*;
+pub use lr_finder::*;
+pub use mixed_precision::*;
+pub use pruning::*;
 pub use regression::*;
 pub use step::*;
 pub use summary::*;
+pub use swa::*;
 pub use train::*;
 pub use train_val::*;
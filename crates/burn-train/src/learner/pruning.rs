@@ -0,0 +1,108 @@
+use crate::learner::TrainCallback;
+use burn_core::module::{Module, ModuleMapper, ParamId};
+use burn_core::tensor::{Tensor, backend::Backend};
+use core::marker::PhantomData;
+
+/// Unstructured magnitude pruning of the weights of the model after each training epoch.
+///
+/// The [smallest magnitudes](Tensor::prune_by_magnitude) of each parameter with at least two
+/// dimensions (e.g. the weights of the linear and convolution layers) are zeroed, while the
+/// biases and the normalization parameters are left untouched.
+///
+/// The pruned weights can be updated again by the optimizer, so the sparsity of the final model
+/// is the one reached after the last epoch.
+pub struct PruningCallback<B: Backend> {
+    sparsity: f64,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> PruningCallback<B> {
+    /// Create a new pruning callback zeroing the `sparsity` fraction of each weight.
+    ///
+    /// # Panics
+    ///
+    /// If the sparsity isn't between 0 and 1.
+    pub fn new(sparsity: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&sparsity),
+            "The sparsity must be between 0 and 1, got {sparsity}"
+        );
+
+        Self {
+            sparsity,
+            _backend: PhantomData,
+        }
+    }
+}
+
+impl<B, M> TrainCallback<M> for PruningCallback<B>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    fn map_model(&mut self, model: M, _epoch: usize) -> M {
+        model.map(&mut MagnitudePruner {
+            sparsity: self.sparsity,
+        })
+    }
+}
+
+/// Prune the weights of a module.
+struct MagnitudePruner {
+    sparsity: f64,
+}
+
+impl<B: Backend> ModuleMapper<B> for MagnitudePruner {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if D < 2 {
+            return tensor;
+        }
+
+        let is_require_grad = tensor.is_require_grad();
+        tensor
+            .set_require_grad(false)
+            .prune_by_magnitude(self.sparsity)
+            .set_require_grad(is_require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::cast::ToElement;
+
+    fn num_zeros<const D: usize>(tensor: Tensor<TestAutodiffBackend, D>) -> usize {
+        tensor.equal_elem(0.0).int().sum().into_scalar().to_usize()
+    }
+
+    #[test]
+    fn weights_are_pruned_to_sparsity() {
+        let model: Linear<TestAutodiffBackend> = LinearConfig::new(16, 8).init(&Default::default());
+        let mut pruning = PruningCallback::new(0.75);
+
+        let model = pruning.map_model(model, 1);
+
+        // 75% of the 128 weights
+        assert_eq!(num_zeros(model.weight.val()), 96);
+        assert!(model.weight.val().is_require_grad());
+    }
+
+    #[test]
+    fn biases_are_not_pruned() {
+        let model: Linear<TestAutodiffBackend> = LinearConfig::new(4, 8).init(&Default::default());
+        let bias = model.bias.as_ref().unwrap().val().into_data();
+        let mut pruning = PruningCallback::new(0.5);
+
+        let model = pruning.map_model(model, 1);
+
+        assert_eq!(model.bias.unwrap().val().into_data(), bias);
+    }
+
+    #[test]
+    #[should_panic = "The sparsity must be between 0 and 1"]
+    fn invalid_sparsity_panics() {
+        let _pruning = PruningCallback::<TestAutodiffBackend>::new(-0.1);
+    }
+}
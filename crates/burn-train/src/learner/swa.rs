@@ -0,0 +1,340 @@
+use crate::learner::TrainCallback;
+use burn_core::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use burn_core::tensor::{Tensor, backend::Backend, container::TensorContainer};
+use core::f64::consts::PI;
+use core::marker::PhantomData;
+
+/// A model whose parameters are the running average of the parameters of the models it is
+/// updated with.
+///
+/// The models must share the parameter ids of the averaged model, which is the case of the
+/// successive versions of a model updated by an optimizer.
+///
+/// # Notes
+///
+/// The running states (e.g. the batch norm statistics) are shared with the model the average is
+/// created from and aren't averaged, they should be recomputed with a forward pass of the
+/// averaged model over the training data.
+#[derive(Clone, Debug)]
+pub struct SwaModel<M> {
+    model: M,
+    num_averaged: usize,
+}
+
+impl<M> SwaModel<M> {
+    /// Create an average starting with the given model.
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            num_averaged: 1,
+        }
+    }
+
+    /// Add a model to the average.
+    pub fn update<B: Backend>(&mut self, model: &M)
+    where
+        M: Module<B>,
+    {
+        let mut collector = ParamCollector::default();
+        model.visit(&mut collector);
+
+        let mut averager = ParamAverager {
+            params: collector.params,
+            num_averaged: self.num_averaged,
+        };
+        self.model = self.model.clone().map(&mut averager);
+        self.num_averaged += 1;
+    }
+
+    /// The number of models averaged so far.
+    pub fn num_averaged(&self) -> usize {
+        self.num_averaged
+    }
+
+    /// The averaged model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Consume the average, returning the averaged model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+}
+
+/// Collect the float tensors of a module by parameter id.
+#[derive(Default)]
+struct ParamCollector {
+    params: TensorContainer<ParamId>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamCollector {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        self.params
+            .register::<B>(id, tensor.clone().set_require_grad(false).into_primitive());
+    }
+}
+
+/// Add the collected float tensors to the running average of the module parameters.
+struct ParamAverager {
+    params: TensorContainer<ParamId>,
+    num_averaged: usize,
+}
+
+impl<B: Backend> ModuleMapper<B> for ParamAverager {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(param) = self.params.remove::<B>(&id) else {
+            return tensor;
+        };
+        let param = Tensor::<B, D>::from_primitive(param);
+        let is_require_grad = tensor.is_require_grad();
+
+        let tensor = tensor.set_require_grad(false);
+        let average = tensor
+            .clone()
+            .add(param.sub(tensor).div_scalar((self.num_averaged + 1) as f64));
+
+        average.set_require_grad(is_require_grad)
+    }
+}
+
+/// The forward pass of a model run to update its batch norm statistics.
+type BatchNormUpdate<M> = Box<dyn FnMut(&M)>;
+
+/// Stochastic Weight Averaging (SWA), averaging the weights of the model over the last epochs of
+/// the training to improve generalization.
+///
+/// From epoch `swa_start`, the learning rate is annealed from the one of the learning rate
+/// scheduler to `swa_lr` over `anneal_epochs` epochs with a cosine, and the model is added to the
+/// average every `swa_freq` epochs. Once the training is over, the learner returns the averaged
+/// model, whose batch norm statistics are updated by the
+/// [forward pass](SwaCallback::with_batch_norm_update) if one is provided.
+///
+/// The average isn't saved in the checkpoints, so it starts over when the training is resumed.
+///
+/// Reference: [Averaging Weights Leads to Wider Optima and Better Generalization](https://arxiv.org/abs/1803.05407)
+pub struct SwaCallback<B: Backend, M> {
+    swa_start: usize,
+    swa_freq: usize,
+    swa_lr: f64,
+    anneal_epochs: usize,
+    swa_model: Option<SwaModel<M>>,
+    batch_norm_update: Option<BatchNormUpdate<M>>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend, M> SwaCallback<B, M> {
+    /// Create a new SWA callback.
+    ///
+    /// # Arguments
+    ///
+    /// * `swa_start` - The first epoch whose model is averaged, starting at 1.
+    /// * `swa_freq` - The number of epochs between two models added to the average.
+    /// * `swa_lr` - The learning rate once annealed.
+    /// * `anneal_epochs` - The number of epochs to anneal the learning rate over.
+    ///
+    /// # Panics
+    ///
+    /// If `swa_start` or `swa_freq` is zero, or if `swa_lr` isn't positive.
+    pub fn new(swa_start: usize, swa_freq: usize, swa_lr: f64, anneal_epochs: usize) -> Self {
+        assert!(
+            swa_start > 0 && swa_freq > 0,
+            "The SWA start epoch and frequency must be positive, got {swa_start} and {swa_freq}"
+        );
+        assert!(
+            swa_lr > 0.0,
+            "The SWA learning rate must be positive, got {swa_lr}"
+        );
+
+        Self {
+            swa_start,
+            swa_freq,
+            swa_lr,
+            anneal_epochs,
+            swa_model: None,
+            batch_norm_update: None,
+            _backend: PhantomData,
+        }
+    }
+
+    /// Set the forward pass of the averaged model over the training data, run once the training
+    /// is over to update its batch norm statistics.
+    pub fn with_batch_norm_update(mut self, update: impl FnMut(&M) + 'static) -> Self {
+        self.batch_norm_update = Some(Box::new(update));
+        self
+    }
+
+    /// The average of the models so far, if any.
+    pub fn swa_model(&self) -> Option<&SwaModel<M>> {
+        self.swa_model.as_ref()
+    }
+}
+
+impl<B, M> TrainCallback<M> for SwaCallback<B, M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    fn learning_rate(&self, epoch: usize, lr: f64) -> f64 {
+        if epoch < self.swa_start {
+            return lr;
+        }
+
+        let progress = match self.anneal_epochs {
+            0 => 1.0,
+            epochs => ((epoch - self.swa_start + 1) as f64 / epochs as f64).min(1.0),
+        };
+        let alpha = (1.0 - (PI * progress).cos()) / 2.0;
+
+        self.swa_lr * alpha + lr * (1.0 - alpha)
+    }
+
+    fn on_train_epoch_end(&mut self, model: &M, epoch: usize) {
+        if epoch < self.swa_start || (epoch - self.swa_start) % self.swa_freq != 0 {
+            return;
+        }
+
+        match &mut self.swa_model {
+            Some(swa_model) => swa_model.update::<B>(model),
+            None => self.swa_model = Some(SwaModel::new(model.clone())),
+        }
+    }
+
+    fn on_train_end(&mut self, model: M) -> M {
+        let Some(swa_model) = self.swa_model.take() else {
+            log::warn!("No model was averaged, the training ended before the SWA start epoch");
+            return model;
+        };
+
+        log::info!("Using the average of {} models", swa_model.num_averaged());
+        let swa_model = swa_model.into_model();
+        if let Some(update) = &mut self.batch_norm_update {
+            update(&swa_model);
+        }
+
+        swa_model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::{TensorData, Tolerance, ops::FloatElem};
+
+    type FT = FloatElem<TestAutodiffBackend>;
+
+    /// Fill all the parameters of a module with a value, keeping their ids.
+    struct Fill(f64);
+
+    impl<B: Backend> ModuleMapper<B> for Fill {
+        fn map_float<const D: usize>(
+            &mut self,
+            _id: ParamId,
+            tensor: Tensor<B, D>,
+        ) -> Tensor<B, D> {
+            let is_require_grad = tensor.is_require_grad();
+            tensor
+                .set_require_grad(false)
+                .zeros_like()
+                .add_scalar(self.0)
+                .set_require_grad(is_require_grad)
+        }
+    }
+
+    fn linear() -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(2, 2).init(&Default::default())
+    }
+
+    #[test]
+    fn swa_weights_differ_from_final_weights() {
+        let model = linear();
+        let mut swa = SwaCallback::new(2, 1, 0.05, 0);
+
+        for epoch in 1..=3 {
+            let model = model.clone().map(&mut Fill(epoch as f64));
+            swa.on_train_epoch_end(&model, epoch);
+        }
+        let final_model = model.map(&mut Fill(3.0));
+        let swa_model = swa.on_train_end(final_model.clone());
+
+        // The models of the epochs 2 and 3 are averaged
+        let expected = TensorData::from([[2.5f32, 2.5], [2.5, 2.5]]);
+        swa_model
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&expected, Tolerance::default());
+        assert_ne!(
+            swa_model.weight.val().into_data(),
+            final_model.weight.val().into_data()
+        );
+        assert!(swa_model.weight.val().is_require_grad());
+    }
+
+    #[test]
+    fn models_are_averaged_every_swa_freq_epochs() {
+        let model = linear();
+        let mut swa = SwaCallback::new(1, 2, 0.05, 0);
+
+        for epoch in 1..=5 {
+            let model = model.clone().map(&mut Fill(epoch as f64));
+            swa.on_train_epoch_end(&model, epoch);
+        }
+
+        // The models of the epochs 1, 3 and 5 are averaged
+        let swa_model = swa.swa_model().unwrap();
+        assert_eq!(swa_model.num_averaged(), 3);
+        swa_model
+            .model()
+            .bias
+            .as_ref()
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq::<FT>(&TensorData::from([3.0f32, 3.0]), Tolerance::default());
+    }
+
+    #[test]
+    fn training_ending_before_swa_start_returns_final_model() {
+        let model = linear();
+        let mut swa = SwaCallback::new(5, 1, 0.05, 0);
+        swa.on_train_epoch_end(&model, 1);
+
+        let final_model = swa.on_train_end(model.clone());
+
+        assert_eq!(
+            final_model.weight.val().into_data(),
+            model.weight.val().into_data()
+        );
+    }
+
+    #[test]
+    fn batch_norm_update_runs_on_swa_model() {
+        let model = linear();
+        let updated = std::rc::Rc::new(std::cell::Cell::new(false));
+        let mut swa = SwaCallback::new(1, 1, 0.05, 0).with_batch_norm_update({
+            let updated = updated.clone();
+            move |_: &Linear<TestAutodiffBackend>| updated.set(true)
+        });
+
+        swa.on_train_epoch_end(&model, 1);
+        let _ = swa.on_train_end(model);
+
+        assert!(updated.get());
+    }
+
+    #[test]
+    fn learning_rate_is_annealed_to_swa_lr() {
+        let swa =
+            SwaCallback::<TestAutodiffBackend, Linear<TestAutodiffBackend>>::new(3, 1, 0.1, 2);
+        let lr = |epoch| swa.learning_rate(epoch, 1.0);
+
+        assert_eq!(lr(2), 1.0);
+        // Halfway through the annealing
+        assert!((lr(3) - 0.55).abs() < 1e-9);
+        assert!((lr(4) - 0.1).abs() < 1e-9);
+        assert!((lr(10) - 0.1).abs() < 1e-9);
+    }
+}
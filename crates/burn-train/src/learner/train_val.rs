@@ -1,11 +1,12 @@
 use crate::components::{LearnerComponents, TrainBackend, ValidBackend};
 use crate::metric::processor::{Event, EventProcessor};
+use crate::profiler::{ChromeTraceExporter, Profiler};
 use crate::{Learner, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::data::dataloader::split::split_dataloader;
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
-use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
 use std::sync::Arc;
 
 /// A training output.
@@ -161,44 +162,77 @@ impl<LC: LearnerComponents> Learner<LC> {
         );
 
         for epoch in starting_epoch..self.num_epochs + 1 {
-            if self.devices.len() > 1 {
-                (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
-                    &mut self.event_processor,
-                    self.devices.clone(),
-                    &self.interrupter,
-                )
-            } else {
-                (self.model, self.optim) = epoch_train.run::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
-                    &mut self.event_processor,
-                    &self.interrupter,
-                );
-            }
+            (self.model, self.optim) = profile::<TrainBackend<LC>, _>(
+                &mut self.profiling,
+                "train",
+                epoch,
+                &self.devices,
+                || {
+                    if self.devices.len() > 1 {
+                        epoch_train.run_multi_device::<LC, OutputTrain>(
+                            self.model,
+                            self.optim,
+                            &mut self.lr_scheduler,
+                            &mut self.event_processor,
+                            self.devices.clone(),
+                            &self.interrupter,
+                            &self.callbacks,
+                        )
+                    } else {
+                        epoch_train.run::<LC, OutputTrain>(
+                            self.model,
+                            self.optim,
+                            &mut self.lr_scheduler,
+                            &mut self.event_processor,
+                            &self.interrupter,
+                            &self.callbacks,
+                        )
+                    }
+                },
+            );
 
             if self.interrupter.should_stop() {
                 break;
             }
 
+            for callback in self.callbacks.iter_mut() {
+                self.model = callback.map_model(self.model, epoch);
+            }
+            for callback in self.callbacks.iter_mut() {
+                callback.on_train_epoch_end(&self.model, epoch);
+            }
+
             // TODO: multi-device validation?
             let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
-            epoch_valid.run::<LC, OutputValid>(
-                &self.model,
-                &mut self.event_processor,
-                &self.interrupter,
+            profile::<ValidBackend<LC>, _>(
+                &mut self.profiling,
+                "valid",
+                epoch,
+                &self.devices,
+                || {
+                    epoch_valid.run::<LC, OutputValid>(
+                        &self.model,
+                        &mut self.event_processor,
+                        &self.interrupter,
+                    )
+                },
             );
 
             if let Some(checkpointer) = &mut self.checkpointer {
-                checkpointer.checkpoint(
-                    &self.model,
-                    &self.optim,
-                    &self.lr_scheduler,
+                profile::<TrainBackend<LC>, _>(
+                    &mut self.profiling,
+                    "checkpoint",
                     epoch,
-                    &self.event_store,
+                    &self.devices,
+                    || {
+                        checkpointer.checkpoint(
+                            &self.model,
+                            &self.optim,
+                            &self.lr_scheduler,
+                            epoch,
+                            &self.event_store,
+                        )
+                    },
                 );
             }
 
@@ -209,9 +243,20 @@ impl<LC: LearnerComponents> Learner<LC> {
             }
         }
 
+        for callback in self.callbacks.iter_mut() {
+            self.model = callback.on_train_end(self.model);
+        }
+
         // Signal training end. For the TUI renderer, this handles the exit & return to main screen.
         self.event_processor.process_train(Event::End);
 
+        if let Some((profiler, exporter)) = &self.profiling {
+            match exporter.export(profiler.report()) {
+                Ok(()) => log::info!("Profiling trace written to {}", exporter.path().display()),
+                Err(err) => log::error!("Could not write the profiling trace:\n{err}"),
+            }
+        }
+
         // Display learner summary
         if let Some(summary) = self.summary {
             match summary.init() {
@@ -225,3 +270,17 @@ impl<LC: LearnerComponents> Learner<LC> {
         self.model
     }
 }
+
+/// Run a step of the training loop, recording the time it takes when profiling is enabled.
+fn profile<B: Backend, T>(
+    profiling: &mut Option<(Profiler, ChromeTraceExporter)>,
+    name: &str,
+    epoch: usize,
+    devices: &[B::Device],
+    step: impl FnOnce() -> T,
+) -> T {
+    match profiling {
+        Some((profiler, _)) => profiler.profile::<B, T>(name, epoch, devices, step),
+        None => step(),
+    }
+}
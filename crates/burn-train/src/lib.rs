@@ -20,6 +20,9 @@ pub mod logger;
 /// The metric module.
 pub mod metric;
 
+/// The profiler module.
+pub mod profiler;
+
 mod learner;
 
 pub use learner::*;
@@ -27,6 +30,9 @@ pub use learner::*;
 #[cfg(test)]
 pub(crate) type TestBackend = burn_ndarray::NdArray<f32>;
 
+#[cfg(test)]
+pub(crate) type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
+
 #[cfg(test)]
 pub(crate) mod tests {
     use crate::TestBackend;
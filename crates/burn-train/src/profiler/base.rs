@@ -0,0 +1,124 @@
+use burn_core::tensor::backend::Backend;
+use std::time::{Duration, Instant};
+
+/// The timing of a step of the training loop recorded by a [profiler](Profiler).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileEvent {
+    /// The name of the step.
+    pub name: String,
+    /// The epoch of the step.
+    pub epoch: usize,
+    /// The time the step started at, relative to the creation of the profiler.
+    pub start: Duration,
+    /// The time the step took.
+    pub duration: Duration,
+}
+
+/// The timings recorded by a [profiler](Profiler), in the order the steps ended.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfilingReport {
+    /// The recorded events.
+    pub events: Vec<ProfileEvent>,
+}
+
+impl ProfilingReport {
+    /// The total time taken by the steps with the given name.
+    pub fn total(&self, name: &str) -> Duration {
+        self.events
+            .iter()
+            .filter(|event| event.name == name)
+            .map(|event| event.duration)
+            .sum()
+    }
+}
+
+/// Records the time taken by the steps of the training loop.
+///
+/// The timings are measured on the host, the devices being synchronized before the end of each
+/// step so that the work queued by asynchronous backends is included in the step that queued it.
+pub struct Profiler {
+    origin: Instant,
+    report: ProfilingReport,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler {
+    /// Create a new profiler, the events starting relative to its creation.
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            report: ProfilingReport::default(),
+        }
+    }
+
+    /// Run the step, recording the time it takes until the given devices are done with it.
+    pub fn profile<B: Backend, T>(
+        &mut self,
+        name: &str,
+        epoch: usize,
+        devices: &[B::Device],
+        step: impl FnOnce() -> T,
+    ) -> T {
+        let start = Instant::now();
+        let output = step();
+        for device in devices {
+            B::sync(device);
+        }
+        let duration = start.elapsed();
+
+        self.report.events.push(ProfileEvent {
+            name: name.to_string(),
+            epoch,
+            start: start.duration_since(self.origin),
+            duration,
+        });
+
+        output
+    }
+
+    /// The timings recorded so far.
+    pub fn report(&self) -> &ProfilingReport {
+        &self.report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn profiler_should_record_steps_in_order() {
+        let mut profiler = Profiler::new();
+        let devices = [Default::default()];
+
+        let output = profiler.profile::<TestBackend, _>("train", 1, &devices, || {
+            std::thread::sleep(Duration::from_millis(2));
+            5
+        });
+        profiler.profile::<TestBackend, _>("valid", 1, &devices, || ());
+        profiler.profile::<TestBackend, _>("train", 2, &devices, || ());
+
+        let report = profiler.report();
+        assert_eq!(output, 5);
+        assert_eq!(
+            report
+                .events
+                .iter()
+                .map(|event| (event.name.as_str(), event.epoch))
+                .collect::<Vec<_>>(),
+            [("train", 1), ("valid", 1), ("train", 2)]
+        );
+        assert!(report.events[0].duration >= Duration::from_millis(2));
+        assert!(report.events[1].start >= report.events[0].start + report.events[0].duration);
+        assert_eq!(
+            report.total("train"),
+            report.events[0].duration + report.events[2].duration
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use super::ProfilingReport;
+
+/// Exports a [profiling report](ProfilingReport) to the JSON trace event format of Chrome, which
+/// can be opened in `chrome://tracing` or Perfetto.
+#[derive(Debug, Clone)]
+pub struct ChromeTraceExporter {
+    path: PathBuf,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace {
+    trace_events: Vec<ChromeTraceEvent>,
+    display_time_unit: &'static str,
+}
+
+#[derive(Serialize)]
+struct ChromeTraceEvent {
+    name: String,
+    cat: &'static str,
+    /// The phase of the event, `X` being a complete event with a duration.
+    ph: &'static str,
+    /// The start of the event in microseconds.
+    ts: f64,
+    /// The duration of the event in microseconds.
+    dur: f64,
+    pid: u32,
+    tid: u32,
+    args: ChromeTraceArgs,
+}
+
+#[derive(Serialize)]
+struct ChromeTraceArgs {
+    epoch: usize,
+}
+
+impl ChromeTraceExporter {
+    /// Create an exporter writing the trace to the given file.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// The file the trace is written to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write the trace of the report to the file of the exporter.
+    pub fn export(&self, report: &ProfilingReport) -> std::io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, Self::to_json(report))
+    }
+
+    /// Serialize the report to the Chrome trace event format.
+    pub fn to_json(report: &ProfilingReport) -> String {
+        let pid = std::process::id();
+        let trace = ChromeTrace {
+            trace_events: report
+                .events
+                .iter()
+                .map(|event| ChromeTraceEvent {
+                    name: event.name.clone(),
+                    cat: "learner",
+                    ph: "X",
+                    ts: event.start.as_secs_f64() * 1e6,
+                    dur: event.duration.as_secs_f64() * 1e6,
+                    pid,
+                    tid: 0,
+                    args: ChromeTraceArgs { epoch: event.epoch },
+                })
+                .collect(),
+            display_time_unit: "ms",
+        };
+
+        serde_json::to_string(&trace).expect("Can serialize the trace")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profiler::ProfileEvent;
+    use std::time::Duration;
+
+    fn report() -> ProfilingReport {
+        ProfilingReport {
+            events: vec![
+                ProfileEvent {
+                    name: "train".to_string(),
+                    epoch: 1,
+                    start: Duration::from_micros(10),
+                    duration: Duration::from_millis(3),
+                },
+                ProfileEvent {
+                    name: "valid".to_string(),
+                    epoch: 1,
+                    start: Duration::from_micros(3020),
+                    duration: Duration::from_micros(500),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn exported_json_should_have_complete_events() {
+        let json = ChromeTraceExporter::to_json(&report());
+
+        let trace: serde_json::Value = serde_json::from_str(&json).expect("Valid JSON");
+        let events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(events.len(), 2);
+        for event in events {
+            assert_eq!(event["ph"], "X");
+            assert!(event["ts"].is_number());
+            assert!(event["dur"].is_number());
+            assert_eq!(event["pid"], std::process::id());
+        }
+        assert_eq!(events[0]["name"], "train");
+        assert_eq!(events[0]["ts"], 10.0);
+        assert_eq!(events[0]["dur"], 3000.0);
+        assert_eq!(events[1]["name"], "valid");
+        assert_eq!(events[1]["args"]["epoch"], 1);
+    }
+
+    #[test]
+    fn export_should_write_the_trace() {
+        let path = std::env::temp_dir()
+            .join(format!("burn-train-profiling-{}", std::process::id()))
+            .join("trace.json");
+        let exporter = ChromeTraceExporter::new(&path);
+
+        exporter.export(&report()).unwrap();
+
+        let json = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(json, ChromeTraceExporter::to_json(&report()));
+    }
+}
@@ -0,0 +1,5 @@
+mod base;
+mod chrome;
+
+pub use base::*;
+pub use chrome::*;
@@ -1,12 +1,15 @@
-use std::{iter::Peekable, slice::Iter};
+use std::{collections::HashMap, iter::Peekable, slice::Iter};
+
+use half::f16;
 
 use super::{
-    from_onnx::GraphData,
+    from_onnx::{GraphData, convert_constant_value},
     ir::{AttributeValue, Node, NodeType},
     proto_conversion::convert_node_proto,
     protos::NodeProto,
 };
-use crate::ir::{ArgType, Data, TensorData};
+use crate::ir::{ArgType, Argument, Data, TensorData, TensorType};
+use crate::node::concat::concat_axis;
 
 /// The function transforms the graph into a new one where the nodes are coalesced into a single node.
 pub fn coalesce(
@@ -16,10 +19,33 @@ pub fn coalesce(
 ) {
     #[allow(clippy::single_match)]
     match node.node_type {
-        NodeType::Gemm => convert_gemm_to_linear(node),
+        NodeType::Conv1d | NodeType::Conv3d => {
+            fuse_conv_batch_norm(node, nodes_iter, graph_data);
+        }
+        NodeType::Conv2d => {
+            convert_conv2d_to_convnext_block(node, nodes_iter, graph_data);
+            if node.node_type == NodeType::Conv2d {
+                fuse_conv_batch_norm(node, nodes_iter, graph_data);
+                convert_conv2d_to_depthwise_separable_conv2d(node, nodes_iter, graph_data);
+            }
+        }
+        NodeType::Gemm => {
+            convert_gemm_to_linear(node);
+            fuse_bias_add(node, nodes_iter, graph_data);
+        }
         NodeType::MatMul => {
             convert_matmul_to_linear(node, nodes_iter, graph_data);
         }
+        NodeType::Add => convert_add_to_positional_encoding(node),
+        NodeType::Gather => {
+            convert_gather_to_relative_position_bias(node, nodes_iter, graph_data);
+        }
+        NodeType::Sub => {
+            convert_sub_to_hinge_embedding_loss(node, nodes_iter, graph_data);
+        }
+        NodeType::GlobalAveragePool => {
+            convert_global_average_pool_to_squeeze_excitation(node, nodes_iter, graph_data);
+        }
         _ => {}
     }
 }
@@ -136,8 +162,9 @@ pub(crate) fn convert_matmul_to_linear(
         panic!("MatMul node must have 2 inputs");
     }
 
-    // if the second input does not have a value, it is not a weight, then proceed to the next node
+    // if the second input does not have a value, it is not a weight, then only fuse a bias
     if node.inputs[1].value.is_none() {
+        fuse_bias_add(node, iter_mut, graph_data);
         return;
     }
 
@@ -188,3 +215,1362 @@ fn convert_and_remove_add_node(bias_node: &Node, current_node: &mut Node) {
         .name
         .clone_from(&bias_node.outputs[0].name);
 }
+
+/// This function fuses the Add node adding a bias to the product of a MatMul or Gemm node which
+/// isn't converted to a Linear node, so the product and the bias are computed by a single node.
+///
+/// The bias must be a tensor computed by the graph with a rank no greater than the one of the
+/// product, so it is broadcast to the product, and the product must only be used by the Add node.
+pub(crate) fn fuse_bias_add(
+    node: &mut Node,
+    iter_mut: &mut Peekable<Iter<NodeProto>>,
+    graph_data: &GraphData,
+) {
+    let product_rank = match (node.node_type.clone(), &node.inputs[..]) {
+        (NodeType::MatMul, [lhs, rhs]) => match (&lhs.ty, &rhs.ty) {
+            (ArgType::Tensor(lhs), ArgType::Tensor(rhs)) if lhs.rank == 1 || rhs.rank == 1 => {
+                lhs.rank.max(rhs.rank).max(2) - 1
+            }
+            (ArgType::Tensor(lhs), ArgType::Tensor(rhs)) => lhs.rank.max(rhs.rank),
+            _ => return,
+        },
+        (NodeType::Gemm, [_, _]) => 2,
+        _ => return,
+    };
+
+    let Some(peek_node) = iter_mut.peek() else {
+        return;
+    };
+    let peek_node = convert_node_proto(peek_node, graph_data);
+    let product = &node.outputs[0].name;
+    if peek_node.node_type != NodeType::Add {
+        return;
+    }
+    let Some(bias) = other_input(&peek_node, product) else {
+        return;
+    };
+    match &bias.ty {
+        ArgType::Tensor(tensor) if bias.value.is_none() && tensor.rank <= product_rank => {}
+        _ => return,
+    }
+
+    let is_product_shared = graph_data.get_graph_output(product).is_some()
+        || iter_mut
+            .clone()
+            .skip(1)
+            .any(|proto| proto.input.contains(product));
+    if is_product_shared {
+        return;
+    }
+
+    log::debug!("fusing the bias of {} into {}", peek_node.name, node.name);
+    node.inputs.push(bias.clone());
+    node.outputs[0].name.clone_from(&peek_node.outputs[0].name);
+    if node.node_type == NodeType::Gemm {
+        node.attrs
+            .insert("beta".to_string(), AttributeValue::Float32(1.0));
+    }
+
+    let _ = iter_mut.next();
+}
+
+/// This function folds the BatchNormalization node following a convolution into the weight and
+/// the bias of the convolution if possible.
+///
+/// At inference, the batch norm computes `(x - mean) * scale / sqrt(var + epsilon) + bias` for
+/// each output channel of the convolution, which is the same as scaling the kernels of the
+/// channel and shifting its bias. The parameters of both nodes must be constants and the output of
+/// the convolution must only be used by the batch norm, which is consumed.
+pub(crate) fn fuse_conv_batch_norm(
+    node: &mut Node,
+    iter_mut: &mut Peekable<Iter<NodeProto>>,
+    graph_data: &GraphData,
+) {
+    let Some(weight) = node.inputs.get(1).and_then(|weight| weight.value.as_ref()) else {
+        return;
+    };
+    let channels = weight.shape[0];
+    let kernel_size: usize = weight.shape[1..].iter().product();
+    let Some(weights) = float_values(weight) else {
+        return;
+    };
+    let biases = match node.inputs.get(2) {
+        Some(bias) => match bias.value.as_ref().and_then(float_values) {
+            Some(biases) => biases,
+            None => return,
+        },
+        None => vec![0.0; channels],
+    };
+
+    let Some(peek_node) = iter_mut.peek() else {
+        return;
+    };
+    let norm = convert_node_proto(peek_node, graph_data);
+    let output = &node.outputs[0].name;
+    let is_training = norm
+        .attrs
+        .get("training_mode")
+        .is_some_and(|mode| mode.clone().into_i64() != 0);
+    if norm.node_type != NodeType::BatchNormalization
+        || norm.inputs.len() != 5
+        || norm.outputs.len() != 1
+        || is_training
+        || norm.inputs[0].name != *output
+        || !norm.inputs[1..]
+            .iter()
+            .all(|arg| has_shape(arg, &[channels]))
+    {
+        return;
+    }
+    let params: Option<Vec<_>> = norm.inputs[1..]
+        .iter()
+        .map(|arg| arg.value.as_ref().and_then(float_values))
+        .collect();
+    let Some([scale, shift, mean, var]) = params.as_deref() else {
+        return;
+    };
+
+    let is_output_shared = graph_data.get_graph_output(output).is_some()
+        || iter_mut
+            .clone()
+            .skip(1)
+            .any(|proto| proto.input.contains(output));
+    if is_output_shared {
+        return;
+    }
+
+    let epsilon = norm
+        .attrs
+        .get("epsilon")
+        .map_or(1e-5, |epsilon| epsilon.clone().into_f32()) as f64;
+    let factors: Vec<f64> = scale
+        .iter()
+        .zip(var)
+        .map(|(scale, var)| scale / (var + epsilon).sqrt())
+        .collect();
+    let weights = weights
+        .chunks(kernel_size)
+        .zip(&factors)
+        .flat_map(|(kernel, factor)| kernel.iter().map(move |value| value * factor))
+        .collect();
+    let biases = (0..channels)
+        .map(|channel| (biases[channel] - mean[channel]) * factors[channel] + shift[channel])
+        .collect();
+
+    log::debug!("fusing the batch norm {} into {}", norm.name, node.name);
+    let weight = node.inputs[1].value.as_mut().unwrap();
+    weight.data = float_data_like(&weight.data, weights);
+    match node.inputs.get_mut(2) {
+        Some(bias) => {
+            let bias = bias.value.as_mut().unwrap();
+            bias.data = float_data_like(&bias.data, biases);
+        }
+        None => {
+            // The shift of the batch norm has the shape and the type of the bias
+            let mut bias = norm.inputs[2].clone();
+            let value = bias.value.as_mut().unwrap();
+            value.data = float_data_like(&value.data, biases);
+            node.inputs.push(bias);
+        }
+    }
+    node.outputs[0].name.clone_from(&norm.outputs[0].name);
+
+    let _ = iter_mut.next();
+}
+
+/// Max time scale of the sinusoidal positional encoding recognized in the graph.
+const POSITIONAL_ENCODING_MAX_TIMESCALE: usize = 10_000;
+
+/// This function converts an Add node into a SinusoidalPositionalEncoding node if possible.
+///
+/// Sinusoidal positional encodings are computed from `sin` and `cos` of the positions, which
+/// exporters fold into a constant table added to the `[batch_size, seq_length, d_model]` input.
+/// The Add node is converted when its constant input holds those sinusoids, with the layout of the
+/// reference implementation (interleaved `sin` and `cos` for each frequency).
+pub(crate) fn convert_add_to_positional_encoding(node: &mut Node) {
+    if node.inputs.len() != 2 {
+        return;
+    }
+
+    // The table can be on either side of the Add node
+    let table_index = match (&node.inputs[0].value, &node.inputs[1].value) {
+        (None, Some(_)) => 1,
+        (Some(_), None) => 0,
+        _ => return,
+    };
+    let input_index = 1 - table_index;
+
+    if !matches!(&node.inputs[input_index].ty, ArgType::Tensor(tensor) if tensor.rank == 3) {
+        return;
+    }
+
+    let table = node.inputs[table_index].value.as_ref().unwrap();
+    if !matches!(
+        table.data,
+        Data::Float16s(_) | Data::Float32s(_) | Data::Float64s(_)
+    ) {
+        return;
+    }
+
+    let (length, d_model) = match table.shape.as_slice() {
+        [1, length, d_model] | [length, d_model] => (*length, *d_model),
+        _ => return,
+    };
+    if length == 0 || d_model == 0 || d_model % 2 != 0 {
+        return;
+    }
+
+    let values = table.data.clone().into_f64s();
+    let is_sinusoids = sinusoids(length, d_model)
+        .zip(values)
+        .all(|(expected, value)| (expected - value).abs() <= 1e-4);
+
+    if is_sinusoids {
+        log::debug!("Converting {} into a positional encoding", node.name);
+        node.node_type = NodeType::SinusoidalPositionalEncoding;
+
+        // The input comes first, followed by the table
+        if table_index == 0 {
+            node.inputs.swap(0, 1);
+        }
+    }
+}
+
+/// Sinusoids of the positional encoding introduced in "Attention is all you need", flattened from
+/// a `[length, d_model]` table.
+fn sinusoids(length: usize, d_model: usize) -> impl Iterator<Item = f64> {
+    let log_timescale_increment = -(POSITIONAL_ENCODING_MAX_TIMESCALE as f64).ln() / d_model as f64;
+
+    (0..length).flat_map(move |position| {
+        (0..d_model).step_by(2).flat_map(move |k| {
+            let angle = (k as f64 * log_timescale_increment).exp() * position as f64;
+            [angle.sin(), angle.cos()]
+        })
+    })
+}
+
+/// This function converts a Gather node into a RelativePositionBias node if possible.
+///
+/// T5 looks up the bias of each attention head in a `[num_buckets, num_heads]` table, indexed by
+/// the bucket of the relative position of each key and query, which exporters fold into constant
+/// indices. The pattern `Gather -> (Reshape) -> Transpose` producing a
+/// `[num_heads, query_len, key_len]` bias is converted when the indices hold the buckets of T5,
+/// either as a `[query_len, key_len]` tensor or flattened and reshaped afterwards. The following
+/// nodes are consumed.
+pub(crate) fn convert_gather_to_relative_position_bias(
+    node: &mut Node,
+    iter_mut: &mut Peekable<Iter<NodeProto>>,
+    graph_data: &GraphData,
+) {
+    let axis = node
+        .attrs
+        .get("axis")
+        .map(|value| value.clone().into_i64())
+        .unwrap_or(0);
+    if node.inputs.len() != 2 || axis != 0 {
+        return;
+    }
+
+    let (Some(table), Some(indices)) = (&node.inputs[0].value, &node.inputs[1].value) else {
+        return;
+    };
+    if !matches!(
+        table.data,
+        Data::Float16s(_) | Data::Float32s(_) | Data::Float64s(_)
+    ) || !matches!(indices.data, Data::Int32s(_) | Data::Int64s(_))
+    {
+        return;
+    }
+    let [num_buckets, num_heads] = table.shape[..] else {
+        return;
+    };
+
+    // The nodes following the Gather, which must be its only consumers
+    let mut lookahead = iter_mut.clone();
+    let mut consumed = Vec::new();
+    let mut next_node = |input: &str, node_type: NodeType| {
+        let proto = lookahead.next()?;
+        let next = convert_node_proto(proto, graph_data);
+        let is_only_consumer = next.node_type == node_type
+            && next.inputs[0].name == input
+            && !lookahead
+                .clone()
+                .any(|proto| proto.input.iter().any(|name| name == input));
+
+        is_only_consumer.then_some(next)
+    };
+
+    let (query_len, key_len) = match indices.shape[..] {
+        [query_len, key_len] => (query_len, key_len),
+        [length] => {
+            let Some(reshape) = next_node(&node.outputs[0].name, NodeType::Reshape) else {
+                return;
+            };
+            let Some(shape) = reshape.inputs.get(1).and_then(|input| input.value.as_ref()) else {
+                return;
+            };
+            let shape = shape.data.clone().into_i64s();
+            let (query_len, key_len) = match shape[..] {
+                [query_len, -1, _] if query_len > 0 => {
+                    (query_len as usize, length / query_len as usize)
+                }
+                [-1, key_len, _] if key_len > 0 => (length / key_len as usize, key_len as usize),
+                [query_len, key_len, _] => (query_len as usize, key_len as usize),
+                _ => return,
+            };
+            if query_len * key_len != length || ![-1, num_heads as i64].contains(&shape[2]) {
+                return;
+            }
+            consumed.push(reshape);
+            (query_len, key_len)
+        }
+        _ => return,
+    };
+
+    let input = consumed
+        .last()
+        .map_or(&node.outputs[0].name, |reshape| &reshape.outputs[0].name)
+        .clone();
+    let Some(transpose) = next_node(&input, NodeType::Transpose) else {
+        return;
+    };
+    let is_heads_first = transpose
+        .attrs
+        .get("perm")
+        .is_some_and(|perm| perm.clone().into_i64s() == [2, 0, 1]);
+    if !is_heads_first {
+        return;
+    }
+
+    let buckets = indices.data.clone().into_i64s();
+    let relative_positions = (0..query_len)
+        .flat_map(|query| (0..key_len).map(move |key| key as i64 - query as i64))
+        .zip(buckets.iter().copied())
+        .collect::<Vec<_>>();
+    let config = [true, false].into_iter().find_map(|bidirectional| {
+        relative_position_max_distance(&relative_positions, bidirectional, num_buckets)
+            .map(|max_distance| (bidirectional, max_distance))
+    });
+    let Some((bidirectional, max_distance)) = config else {
+        return;
+    };
+
+    log::debug!(
+        "Converting {} into a relative position bias (bidirectional: {bidirectional}, max distance: {max_distance})",
+        node.name
+    );
+    node.node_type = NodeType::RelativePositionBias;
+    node.inputs.truncate(1);
+    node.attrs = [
+        ("query_len", query_len),
+        ("key_len", key_len),
+        ("max_distance", max_distance),
+        ("bidirectional", bidirectional as usize),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), AttributeValue::Int64(value as i64)))
+    .collect();
+    node.outputs[0].name.clone_from(&transpose.outputs[0].name);
+
+    // The Reshape and Transpose nodes are never stored
+    for _ in 0..=consumed.len() {
+        let _ = iter_mut.next();
+    }
+}
+
+/// Finds the max distance of the T5 buckets of the given relative positions (`key - query`).
+///
+/// A log-spaced bucket grows as the max distance shrinks, so each one bounds the max distance from
+/// below given the distance it was computed for. T5 defaults to a max distance of 128, which is
+/// kept when it matches, otherwise the smallest max distance above all the bounds is used. The
+/// candidates are checked against the buckets, since those are computed in single precision.
+fn relative_position_max_distance(
+    relative_positions: &[(i64, i64)],
+    bidirectional: bool,
+    num_buckets: usize,
+) -> Option<usize> {
+    let (num_buckets_side, max_exact) = if bidirectional {
+        (num_buckets as i64 / 2, num_buckets as i64 / 4)
+    } else {
+        (num_buckets as i64, num_buckets as i64 / 2)
+    };
+    if max_exact == 0 {
+        return None;
+    }
+
+    // Exclusive lower bound of `max_distance`
+    let mut lower = max_exact as f64;
+    for &(relative_position, bucket) in relative_positions {
+        let (offset, distance) = if bidirectional {
+            let offset = if relative_position > 0 {
+                num_buckets_side
+            } else {
+                0
+            };
+            (offset, relative_position.abs())
+        } else {
+            (0, (-relative_position).max(0))
+        };
+        if distance < max_exact {
+            continue;
+        }
+
+        // The bucket is `max_exact + floor(scale / ln(max_distance / max_exact))`
+        let large = bucket - offset - max_exact;
+        let scale =
+            (distance as f64 / max_exact as f64).ln() * (num_buckets_side - max_exact) as f64;
+        if large < 0 || large > num_buckets_side - 1 - max_exact {
+            return None;
+        }
+        if large < num_buckets_side - 1 - max_exact {
+            lower = lower.max(max_exact as f64 * (scale / (large + 1) as f64).exp());
+        }
+    }
+
+    let smallest = lower.floor() as usize + 1;
+    [128, smallest - 1, smallest, smallest + 1]
+        .into_iter()
+        .filter(|&max_distance| max_distance as i64 > max_exact)
+        .find(|&max_distance| {
+            relative_positions
+                .iter()
+                .all(|&(relative_position, bucket)| {
+                    relative_position_bucket(
+                        relative_position,
+                        bidirectional,
+                        num_buckets,
+                        max_distance,
+                    ) == bucket
+                })
+        })
+}
+
+/// Bucket of a relative position (`key - query`), computed like T5 in single precision.
+fn relative_position_bucket(
+    relative_position: i64,
+    bidirectional: bool,
+    num_buckets: usize,
+    max_distance: usize,
+) -> i64 {
+    let (num_buckets, offset, distance) = if bidirectional {
+        let num_buckets = num_buckets as i64 / 2;
+        let offset = if relative_position > 0 {
+            num_buckets
+        } else {
+            0
+        };
+        (num_buckets, offset, relative_position.abs())
+    } else {
+        (num_buckets as i64, 0, (-relative_position).max(0))
+    };
+
+    let max_exact = num_buckets / 2;
+    if distance < max_exact {
+        return offset + distance;
+    }
+
+    let log_ratio = (max_distance as f32 / max_exact as f32).ln();
+    let large = ((distance as f32 / max_exact as f32).ln() / log_ratio
+        * (num_buckets - max_exact) as f32) as i64
+        + max_exact;
+
+    offset + large.min(num_buckets - 1)
+}
+
+/// The size of the kernel of the depthwise convolution of a ConvNeXt block.
+const CONVNEXT_KERNEL_SIZE: usize = 7;
+
+/// The factor by which the feed-forward layer of a ConvNeXt block widens the channels.
+const CONVNEXT_EXPANSION: usize = 4;
+
+/// This function converts a Conv2d node into a ConvNeXtBlock node if possible.
+///
+/// Exporters flatten the block of ConvNeXt into its operators: a `7x7` depthwise convolution, a
+/// Transpose to channels last, a LayerNormalization, a feed-forward layer
+/// (`MatMul -> Add -> GELU -> MatMul -> Add`, GELU being either a single node or its `Erf`
+/// decomposition), an optional layer scale (Mul), a Transpose back to channels first and the
+/// residual Add. The following nodes and the constants they use are consumed when they match
+/// this pattern and their intermediate results aren't used elsewhere.
+pub(crate) fn convert_conv2d_to_convnext_block(
+    node: &mut Node,
+    iter_mut: &mut Peekable<Iter<NodeProto>>,
+    graph_data: &GraphData,
+) {
+    let mut matcher = NodeMatcher::new(iter_mut.clone(), graph_data);
+    let Some((params, epsilon, output)) = match_convnext_block(node, &mut matcher) else {
+        return;
+    };
+
+    log::debug!("Converting {} into a ConvNeXt block", node.name);
+    node.node_type = NodeType::ConvNeXtBlock;
+    node.inputs.extend(params);
+    node.attrs = [("epsilon".to_string(), AttributeValue::Float32(epsilon))]
+        .into_iter()
+        .collect();
+    node.outputs[0].name = output;
+
+    // The nodes of the block are never stored
+    for _ in 0..matcher.consumed {
+        let _ = iter_mut.next();
+    }
+}
+
+/// Matches the nodes of a ConvNeXt block following its depthwise convolution.
+///
+/// Returns the parameters of the block following the ones of the convolution (layer norm scale
+/// and bias, weights and biases of the feed-forward layer, and the layer scale if any), the
+/// epsilon of the layer norm and the output of the block.
+fn match_convnext_block(
+    conv: &Node,
+    matcher: &mut NodeMatcher,
+) -> Option<(Vec<Argument>, f32, String)> {
+    let weight = conv.inputs.get(1)?.value.as_ref()?;
+    conv.inputs.get(2)?.value.as_ref()?;
+    let [dim, 1, CONVNEXT_KERNEL_SIZE, CONVNEXT_KERNEL_SIZE] = weight.shape[..] else {
+        return None;
+    };
+    let d_hidden = CONVNEXT_EXPANSION * dim;
+    let padding = (CONVNEXT_KERNEL_SIZE / 2) as i64;
+
+    let attr = |key: &str, default: &[i64]| {
+        conv.attrs
+            .get(key)
+            .map_or(default.to_vec(), |value| value.clone().into_i64s())
+    };
+    let is_depthwise = conv
+        .attrs
+        .get("group")
+        .is_some_and(|group| group.clone().into_i64() == dim as i64);
+    if !is_depthwise
+        || attr("pads", &[0; 4]) != [padding; 4]
+        || attr("strides", &[1, 1]) != [1, 1]
+        || attr("dilations", &[1, 1]) != [1, 1]
+    {
+        return None;
+    }
+
+    // Channels last
+    let transpose = matcher.next(&conv.outputs[0].name)?;
+    if !is_transpose(&transpose, &[0, 2, 3, 1]) {
+        return None;
+    }
+
+    let norm = matcher.next(&transpose.outputs[0].name)?;
+    let axis = norm
+        .attrs
+        .get("axis")
+        .map_or(-1, |axis| axis.clone().into_i64());
+    if norm.node_type != NodeType::LayerNormalization
+        || norm.inputs.len() != 3
+        || ![-1, 3].contains(&axis)
+        || !norm.inputs[1..].iter().all(|arg| has_shape(arg, &[dim]))
+    {
+        return None;
+    }
+    let epsilon = norm
+        .attrs
+        .get("epsilon")
+        .map_or(1e-5, |epsilon| epsilon.clone().into_f32());
+
+    let (weight1, bias1, x) = matcher.linear(&norm.outputs[0].name)?;
+    let x = matcher.gelu(&x)?;
+    let (weight2, bias2, x) = matcher.linear(&x)?;
+    if !has_shape(&weight1, &[dim, d_hidden])
+        || !has_shape(&bias1, &[d_hidden])
+        || !has_shape(&weight2, &[d_hidden, dim])
+        || !has_shape(&bias2, &[dim])
+    {
+        return None;
+    }
+
+    let mut params = vec![
+        norm.inputs[1].clone(),
+        norm.inputs[2].clone(),
+        weight1,
+        bias1,
+        weight2,
+        bias2,
+    ];
+
+    // The layer scale is optional
+    let mut next = matcher.next(&x)?;
+    if next.node_type == NodeType::Mul {
+        let gamma = matcher.operand(&next, &x)?;
+        if !has_shape(&gamma, &[dim]) {
+            return None;
+        }
+        params.push(gamma);
+        next = matcher.next(&next.outputs[0].name)?;
+    }
+
+    // Channels first
+    if !is_transpose(&next, &[0, 3, 1, 2]) {
+        return None;
+    }
+
+    let residual = matcher.next(&next.outputs[0].name)?;
+    let shortcut = other_input(&residual, &next.outputs[0].name)?;
+    if residual.node_type != NodeType::Add
+        || shortcut.name != conv.inputs[0].name
+        || !matcher.is_self_contained()
+    {
+        return None;
+    }
+
+    Some((params, epsilon, residual.outputs[0].name.clone()))
+}
+
+/// This function converts a Conv2d node into a DepthwiseSeparableConv2d node if possible.
+///
+/// A depthwise convolution (a single kernel for each input channel, i.e. `group` being the number
+/// of channels) directly followed by a pointwise `1x1` convolution is the depthwise separable
+/// convolution of MobileNet-like models. The pointwise convolution is consumed when it matches
+/// this pattern and the output of the depthwise convolution isn't used elsewhere.
+///
+/// The inputs of the node are the input, the weights of both convolutions and, if any convolution
+/// has a bias, the biases of both convolutions (zeros for a missing one).
+pub(crate) fn convert_conv2d_to_depthwise_separable_conv2d(
+    node: &mut Node,
+    iter_mut: &mut Peekable<Iter<NodeProto>>,
+    graph_data: &GraphData,
+) {
+    let mut matcher = NodeMatcher::new(iter_mut.clone(), graph_data);
+    let Some(pointwise) = match_pointwise_conv2d(node, &mut matcher) else {
+        return;
+    };
+
+    log::debug!(
+        "Converting {} and {} into a depthwise separable convolution",
+        node.name,
+        pointwise.name
+    );
+    let depthwise_weight = node.inputs[1].clone();
+    let pointwise_weight = pointwise.inputs[1].clone();
+    let biases = match (node.inputs.get(2), pointwise.inputs.get(2)) {
+        (None, None) => vec![],
+        (depthwise_bias, pointwise_bias) => vec![
+            depthwise_bias
+                .cloned()
+                .unwrap_or_else(|| zero_bias(&depthwise_weight)),
+            pointwise_bias
+                .cloned()
+                .unwrap_or_else(|| zero_bias(&pointwise_weight)),
+        ],
+    };
+    node.node_type = NodeType::DepthwiseSeparableConv2d;
+    node.inputs = [
+        vec![node.inputs[0].clone(), depthwise_weight, pointwise_weight],
+        biases,
+    ]
+    .concat();
+    node.outputs[0].name.clone_from(&pointwise.outputs[0].name);
+
+    for _ in 0..matcher.consumed {
+        let _ = iter_mut.next();
+    }
+}
+
+/// Matches the pointwise convolution following a depthwise convolution.
+fn match_pointwise_conv2d(depthwise: &Node, matcher: &mut NodeMatcher) -> Option<Node> {
+    let weight = depthwise.inputs.get(1)?.value.as_ref()?;
+    let [channels, 1, _, _] = weight.shape[..] else {
+        return None;
+    };
+    let is_depthwise = depthwise
+        .attrs
+        .get("group")
+        .is_some_and(|group| group.clone().into_i64() == channels as i64);
+    let has_constant_bias =
+        |conv: &Node| conv.inputs.get(2).is_none_or(|bias| bias.value.is_some());
+    if !is_depthwise || !has_constant_bias(depthwise) {
+        return None;
+    }
+
+    let output = &depthwise.outputs[0].name;
+    let pointwise = matcher.next(output)?;
+    let weight = pointwise.inputs.get(1)?.value.as_ref()?;
+    let attr = |key: &str, default: &[i64]| {
+        pointwise
+            .attrs
+            .get(key)
+            .map_or(default.to_vec(), |value| value.clone().into_i64s())
+    };
+    let group = pointwise
+        .attrs
+        .get("group")
+        .map_or(1, |group| group.clone().into_i64());
+    // The next node isn't remapped yet, the shape of its weight checked below makes it 2D
+    if pointwise.node_type != NodeType::Conv
+        || pointwise.inputs[0].name != *output
+        || !matches!(weight.shape[..], [_, in_channels, 1, 1] if in_channels == channels)
+        || !has_constant_bias(&pointwise)
+        || group != 1
+        || attr("pads", &[0; 4]) != [0; 4]
+        || attr("strides", &[1, 1]) != [1, 1]
+        || attr("dilations", &[1, 1]) != [1, 1]
+    {
+        return None;
+    }
+
+    // The output of the depthwise convolution must only be used by the pointwise one
+    let is_output_shared = matcher.graph_data.get_graph_output(output).is_some()
+        || matcher
+            .lookahead
+            .clone()
+            .any(|proto| proto.input.contains(output));
+    if is_output_shared {
+        return None;
+    }
+
+    Some(pointwise)
+}
+
+/// A bias of zeros for the output channels of a convolution with the given weight.
+fn zero_bias(weight: &Argument) -> Argument {
+    let value = weight.value.as_ref().unwrap();
+    let channels = value.shape[0];
+
+    let mut bias = Argument::new(format!("{}_bias", weight.name));
+    bias.value = Some(TensorData {
+        data: float_data_like(&value.data, vec![0.0; channels]),
+        shape: vec![channels],
+    });
+    if let ArgType::Tensor(tensor) = &weight.ty {
+        bias.ty = ArgType::Tensor(TensorType {
+            elem_type: tensor.elem_type.clone(),
+            rank: 1,
+            static_shape: Some(vec![channels]),
+        });
+    }
+    bias
+}
+
+/// This function converts a GlobalAveragePool node into a SqueezeExcitation node if possible.
+///
+/// Exporters flatten the squeeze-and-excitation module of torchvision into its operators: the
+/// global average pooling of the input, a pointwise `1x1` convolution reducing the channels, a
+/// Relu (or a SiLU as `Sigmoid -> Mul`), a pointwise convolution expanding the channels back, a
+/// Sigmoid and the Mul scaling the input. The following nodes are consumed when they match this
+/// pattern and their intermediate results aren't used elsewhere.
+///
+/// The inputs of the node are the input and the weights and biases of both convolutions in the
+/// layout of linear layers (zeros for a missing bias).
+pub(crate) fn convert_global_average_pool_to_squeeze_excitation(
+    node: &mut Node,
+    iter_mut: &mut Peekable<Iter<NodeProto>>,
+    graph_data: &GraphData,
+) {
+    let mut matcher = NodeMatcher::new(iter_mut.clone(), graph_data);
+    let Some((params, activation, output)) = match_squeeze_excitation(node, &mut matcher) else {
+        return;
+    };
+
+    log::debug!("Converting {} into a squeeze-and-excitation", node.name);
+    node.node_type = NodeType::SqueezeExcitation;
+    node.inputs.extend(params);
+    node.attrs = [(
+        "activation".to_string(),
+        AttributeValue::String(activation.to_string()),
+    )]
+    .into_iter()
+    .collect();
+    node.outputs[0].name = output;
+
+    // The nodes of the module are never stored
+    for _ in 0..matcher.consumed {
+        let _ = iter_mut.next();
+    }
+}
+
+/// Matches the nodes of a squeeze-and-excitation following its global average pooling.
+///
+/// Returns the weights and biases of both convolutions as linear layers, the activation between
+/// them (`relu` or `silu`) and the output of the module.
+fn match_squeeze_excitation(
+    pool: &Node,
+    matcher: &mut NodeMatcher,
+) -> Option<(Vec<Argument>, &'static str, String)> {
+    let input = &pool.inputs.first()?.name;
+    if !matches!(&pool.inputs[0].ty, ArgType::Tensor(tensor) if tensor.rank == 4) {
+        return None;
+    }
+    // The pooled channels are an intermediate result as well
+    matcher.outputs.push(pool.outputs[0].name.clone());
+
+    let reduce = matcher.next(&pool.outputs[0].name)?;
+    let (weight1, bias1) = pointwise_linear(&reduce, &pool.outputs[0].name)?;
+    let [channels, squeeze_channels] = weight1.value.as_ref()?.shape[..] else {
+        return None;
+    };
+
+    let x = &reduce.outputs[0].name;
+    let activation = matcher.next(x)?;
+    let (activation, x) = match activation.node_type {
+        NodeType::Relu => ("relu", activation.outputs[0].name.clone()),
+        NodeType::Sigmoid => {
+            let mul = matcher.next(&activation.outputs[0].name)?;
+            if mul.node_type != NodeType::Mul
+                || other_input(&mul, &activation.outputs[0].name)?.name != *x
+            {
+                return None;
+            }
+            ("silu", mul.outputs[0].name.clone())
+        }
+        _ => return None,
+    };
+
+    let expand = matcher.next(&x)?;
+    let (weight2, bias2) = pointwise_linear(&expand, &x)?;
+    if !has_shape(&weight2, &[squeeze_channels, channels]) {
+        return None;
+    }
+
+    let gate = matcher.next(&expand.outputs[0].name)?;
+    if gate.node_type != NodeType::Sigmoid {
+        return None;
+    }
+    let scale = matcher.next(&gate.outputs[0].name)?;
+    if scale.node_type != NodeType::Mul
+        || other_input(&scale, &gate.outputs[0].name)?.name != *input
+        || !matcher.is_self_contained()
+    {
+        return None;
+    }
+
+    Some((
+        vec![weight1, bias1, weight2, bias2],
+        activation,
+        scale.outputs[0].name.clone(),
+    ))
+}
+
+/// Matches a pointwise `1x1` convolution of the given input with constant parameters, returning
+/// its weight and bias in the layout of a linear layer.
+fn pointwise_linear(conv: &Node, input: &str) -> Option<(Argument, Argument)> {
+    let weight = conv.inputs.get(1)?;
+    let attr = |key: &str, default: &[i64]| {
+        conv.attrs
+            .get(key)
+            .map_or(default.to_vec(), |value| value.clone().into_i64s())
+    };
+    let group = conv
+        .attrs
+        .get("group")
+        .map_or(1, |group| group.clone().into_i64());
+    // The next node isn't remapped yet, the shape of its weight checked below makes it 2D
+    if conv.node_type != NodeType::Conv
+        || conv.inputs[0].name != input
+        || !matches!(weight.value.as_ref()?.shape[..], [_, _, 1, 1])
+        || conv.inputs.get(2).is_some_and(|bias| bias.value.is_none())
+        || group != 1
+        || attr("pads", &[0; 4]) != [0; 4]
+        || attr("strides", &[1, 1]) != [1, 1]
+    {
+        return None;
+    }
+
+    let bias = conv
+        .inputs
+        .get(2)
+        .cloned()
+        .unwrap_or_else(|| zero_bias(weight));
+
+    Some((pointwise_to_linear_weight(weight)?, bias))
+}
+
+/// The weight `[channels_out, channels_in, 1, 1]` of a pointwise convolution as the weight
+/// `[channels_in, channels_out]` of a linear layer.
+fn pointwise_to_linear_weight(weight: &Argument) -> Option<Argument> {
+    let value = weight.value.as_ref()?;
+    let (channels_out, channels_in) = (value.shape[0], value.shape[1]);
+    let values = transpose_flattened(float_values(value)?, channels_out, channels_in);
+
+    let mut linear_weight = weight.clone();
+    linear_weight.value = Some(TensorData {
+        data: float_data_like(&value.data, values),
+        shape: vec![channels_in, channels_out],
+    });
+    if let ArgType::Tensor(tensor) = &mut linear_weight.ty {
+        tensor.rank = 2;
+        tensor.static_shape = Some(vec![channels_in, channels_out]);
+    }
+    Some(linear_weight)
+}
+
+/// This function converts a Sub node into a HingeEmbeddingLoss node if it computes `margin - x`
+/// for the rest of the loss as decomposed by PyTorch, i.e.
+/// `where(target != 1, max(0, margin - x), 0) + where(target != -1, x, 0)` with `max` being a Relu,
+/// Clip or Max node, optionally followed by its mean or sum. The following nodes and the constants
+/// they use are consumed when they match this pattern and their intermediate results aren't used
+/// elsewhere.
+///
+/// The margin is kept as the last input of the node, so that it is lifted like the other constants.
+pub(crate) fn convert_sub_to_hinge_embedding_loss(
+    node: &mut Node,
+    iter_mut: &mut Peekable<Iter<NodeProto>>,
+    graph_data: &GraphData,
+) {
+    let [margin, input] = &node.inputs[..] else {
+        return;
+    };
+    if margin.value.is_none() && !graph_data.is_constant_output(&margin.name) {
+        return;
+    }
+
+    let mut matcher = NodeMatcher::new(iter_mut.clone(), graph_data);
+    let Some((target, reduction, output)) =
+        match_hinge_embedding_loss(&node.outputs[0].name, &input.name, &mut matcher)
+    else {
+        return;
+    };
+
+    log::debug!("Converting {} into a hinge embedding loss", node.name);
+    node.node_type = NodeType::HingeEmbeddingLoss;
+    node.inputs = vec![input.clone(), target, margin.clone()];
+    node.attrs = [(
+        "reduction".to_string(),
+        AttributeValue::String(reduction.to_string()),
+    )]
+    .into_iter()
+    .collect();
+    node.outputs[0].name = output;
+
+    // The nodes of the loss are never stored
+    for _ in 0..matcher.consumed {
+        let _ = iter_mut.next();
+    }
+}
+
+/// Matches the nodes of a hinge embedding loss following the difference between the margin and
+/// the input.
+///
+/// Returns the target, the reduction of the loss (`none`, `mean` or `sum`) and its output.
+fn match_hinge_embedding_loss(
+    diff: &str,
+    input: &str,
+    matcher: &mut NodeMatcher,
+) -> Option<(Argument, &'static str, String)> {
+    let clamp = matcher.next(diff)?;
+    let is_clamp = match clamp.node_type {
+        NodeType::Relu => true,
+        NodeType::Clip => {
+            clamp.inputs.get(1).and_then(|min| matcher.scalar(min)) == Some(0.0)
+                && clamp.inputs.get(2).is_none_or(|max| max.name.is_empty())
+        }
+        NodeType::Max => matcher.scalar_operand(&clamp, diff) == Some(0.0),
+        _ => false,
+    };
+    if !is_clamp {
+        return None;
+    }
+
+    let (target, margin_loss) = matcher.masked_where(&clamp.outputs[0].name, 1.0)?;
+    let (other_target, input_loss) = matcher.masked_where(input, -1.0)?;
+    let add = matcher.next(&input_loss)?;
+    if add.node_type != NodeType::Add
+        || other_input(&add, &input_loss)?.name != margin_loss
+        || other_target.name != target.name
+    {
+        return None;
+    }
+
+    // The reduction is optional
+    let mut output = add.outputs[0].name.clone();
+    let mut reduction = "none";
+    let mut lookahead = matcher.clone();
+    if let Some(reduce) = lookahead.next(&output) {
+        let reduces_all = reduce.inputs.len() == 1
+            && !reduce.attrs.contains_key("axes")
+            && reduce
+                .attrs
+                .get("keepdims")
+                .is_some_and(|keepdims| keepdims.clone().into_i64() == 0);
+        let reduce_type = match reduce.node_type {
+            NodeType::ReduceMean => Some("mean"),
+            NodeType::ReduceSum => Some("sum"),
+            _ => None,
+        };
+        if let (true, Some(reduce_type)) = (reduces_all, reduce_type) {
+            output.clone_from(&reduce.outputs[0].name);
+            reduction = reduce_type;
+            *matcher = lookahead;
+        }
+    }
+
+    if !matcher.is_self_contained() {
+        return None;
+    }
+
+    Some((target, reduction, output))
+}
+
+/// This function converts the Concat nodes joining tensors unsqueezed along the concatenation axis
+/// into Stack nodes, so the new axis is created by a single op.
+///
+/// PyTorch exports `torch.stack` as an Unsqueeze node for each tensor followed by a Concat node,
+/// with the constant axes interleaved between them. Unlike the other conversions, this one runs
+/// on the processed nodes, once the axes are lifted. The unsqueezed tensors must have the same
+/// shape and only be used by the Concat node.
+///
+/// Returns the indices of the Unsqueeze nodes to remove.
+pub(crate) fn convert_unsqueeze_concat_to_stack(
+    nodes: &mut [Node],
+    graph_outputs: &[Argument],
+) -> Vec<usize> {
+    let producers: HashMap<String, usize> = nodes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, node)| {
+            node.outputs
+                .iter()
+                .map(move |output| (output.name.clone(), i))
+        })
+        .collect();
+    let mut uses: HashMap<String, usize> = HashMap::new();
+    for name in nodes
+        .iter()
+        .flat_map(|node| node.inputs.iter())
+        .chain(graph_outputs)
+        .map(|arg| arg.name.clone())
+    {
+        *uses.entry(name).or_default() += 1;
+    }
+
+    let mut unsqueezes = Vec::new();
+    for i in 0..nodes.len() {
+        let Some((axis, stacked)) = match_stack(&nodes[i], nodes, &producers, &uses) else {
+            continue;
+        };
+
+        log::debug!("Converting {} into a stack", nodes[i].name);
+        let inputs = stacked
+            .iter()
+            .map(|&unsqueeze| nodes[unsqueeze].inputs[0].clone())
+            .collect();
+        let node = &mut nodes[i];
+        node.node_type = NodeType::Stack;
+        node.inputs = inputs;
+        node.attrs = [("axis".to_string(), AttributeValue::Int64(axis as i64))]
+            .into_iter()
+            .collect();
+        unsqueezes.extend(stacked);
+    }
+
+    unsqueezes
+}
+
+/// Matches a Concat node whose inputs are all unsqueezed along its axis, returning the axis and
+/// the indices of the Unsqueeze nodes.
+fn match_stack(
+    concat: &Node,
+    nodes: &[Node],
+    producers: &HashMap<String, usize>,
+    uses: &HashMap<String, usize>,
+) -> Option<(usize, Vec<usize>)> {
+    if concat.node_type != NodeType::Concat {
+        return None;
+    }
+    let ArgType::Tensor(output) = &concat.outputs[0].ty else {
+        return None;
+    };
+    if concat.inputs.len() < 2
+        || !concat
+            .inputs
+            .iter()
+            .all(|input| matches!(input.ty, ArgType::Tensor(_)))
+    {
+        return None;
+    }
+    let axis = concat_axis(concat);
+
+    let mut stacked = Vec::with_capacity(concat.inputs.len());
+    let mut shape = None;
+    for input in concat.inputs.iter() {
+        let index = *producers.get(&input.name)?;
+        let unsqueeze = &nodes[index];
+        if unsqueeze.node_type != NodeType::Unsqueeze || uses.get(&input.name) != Some(&1) {
+            return None;
+        }
+        let axes = match unsqueeze.attrs.get("axes") {
+            Some(axes) => axes.clone().into_i64s(),
+            None => match unsqueeze.inputs.get(1)?.value.as_ref()?.data {
+                Data::Int64s(ref axes) => axes.clone(),
+                _ => return None,
+            },
+        };
+        let [unsqueeze_axis] = axes[..] else {
+            return None;
+        };
+        let unsqueeze_axis = match unsqueeze_axis < 0 {
+            true => unsqueeze_axis + output.rank as i64,
+            false => unsqueeze_axis,
+        };
+        if unsqueeze_axis != axis as i64 {
+            return None;
+        }
+
+        // The stacked tensors can't be broadcast against each other like concatenated ones
+        let ArgType::Tensor(tensor) = &unsqueeze.inputs[0].ty else {
+            return None;
+        };
+        if tensor.rank + 1 != output.rank {
+            return None;
+        }
+        if let Some(static_shape) = &tensor.static_shape {
+            if shape.get_or_insert(static_shape) != &static_shape {
+                return None;
+            }
+        }
+        stacked.push(index);
+    }
+
+    Some((axis, stacked))
+}
+
+/// Matches the nodes following a node one at a time, skipping the constants they use.
+#[derive(Clone)]
+struct NodeMatcher<'a, 'g> {
+    lookahead: Peekable<Iter<'a, NodeProto>>,
+    graph_data: &'g GraphData,
+    /// The number of nodes matched so far, constants included.
+    consumed: usize,
+    /// The outputs of the nodes matched so far.
+    outputs: Vec<String>,
+    /// The scalar values of the constants matched so far by output name.
+    scalars: HashMap<String, f64>,
+}
+
+impl<'a, 'g> NodeMatcher<'a, 'g> {
+    fn new(lookahead: Peekable<Iter<'a, NodeProto>>, graph_data: &'g GraphData) -> Self {
+        Self {
+            lookahead,
+            graph_data,
+            consumed: 0,
+            outputs: Vec::new(),
+            scalars: HashMap::new(),
+        }
+    }
+
+    /// The next node that isn't a constant, if it uses the given input.
+    fn next(&mut self, input: &str) -> Option<Node> {
+        self.next_any()
+            .filter(|next| next.inputs.iter().any(|arg| arg.name == input))
+    }
+
+    /// The next node that isn't a constant.
+    fn next_any(&mut self) -> Option<Node> {
+        loop {
+            let proto = self.lookahead.next()?;
+            let next = convert_node_proto(proto, self.graph_data);
+            self.consumed += 1;
+            self.outputs.extend(proto.output.iter().cloned());
+
+            if next.node_type != NodeType::Constant {
+                return Some(next);
+            }
+            if let Some(value) = convert_constant_value(&next).value.and_then(scalar_value) {
+                self.scalars.insert(proto.output[0].clone(), value);
+            }
+        }
+    }
+
+    /// The input of a binary node other than `input`, which must be a constant.
+    fn operand(&self, node: &Node, input: &str) -> Option<Argument> {
+        let operand = other_input(node, input)?;
+
+        (operand.value.is_some() || self.scalars.contains_key(&operand.name))
+            .then(|| operand.clone())
+    }
+
+    /// The scalar constant input of a binary node other than `input`.
+    fn scalar_operand(&self, node: &Node, input: &str) -> Option<f64> {
+        self.scalar(&self.operand(node, input)?)
+    }
+
+    /// The value of the argument if it is a scalar constant.
+    fn scalar(&self, arg: &Argument) -> Option<f64> {
+        match &arg.value {
+            Some(value) => scalar_value(value.clone()),
+            None => self.scalars.get(&arg.name).copied(),
+        }
+    }
+
+    /// Matches `where(target != value, input, 0)` (`Equal -> Not -> Where`), returning the target
+    /// and the output.
+    fn masked_where(&mut self, input: &str, value: f64) -> Option<(Argument, String)> {
+        let equal = self.next_any()?;
+        let [lhs, rhs] = &equal.inputs[..] else {
+            return None;
+        };
+        if equal.node_type != NodeType::Equal {
+            return None;
+        }
+        let target = match (self.scalar(lhs), self.scalar(rhs)) {
+            (None, Some(rhs)) if rhs == value => lhs.clone(),
+            (Some(lhs), None) if lhs == value => rhs.clone(),
+            _ => return None,
+        };
+
+        let not = self.next(&equal.outputs[0].name)?;
+        if not.node_type != NodeType::Not {
+            return None;
+        }
+        let masked = self.next(&not.outputs[0].name)?;
+        let [condition, on_true, on_false] = &masked.inputs[..] else {
+            return None;
+        };
+        if masked.node_type != NodeType::Where
+            || condition.name != not.outputs[0].name
+            || on_true.name != input
+            || self.scalar(on_false) != Some(0.0)
+        {
+            return None;
+        }
+
+        Some((target, masked.outputs[0].name.clone()))
+    }
+
+    /// Matches a linear layer (`MatMul -> Add`), returning its weight, bias and output.
+    fn linear(&mut self, input: &str) -> Option<(Argument, Argument, String)> {
+        let matmul = self.next(input)?;
+        if matmul.node_type != NodeType::MatMul || matmul.inputs[0].name != input {
+            return None;
+        }
+        let weight = matmul.inputs[1].clone();
+        weight.value.as_ref()?;
+
+        let output = &matmul.outputs[0].name;
+        let add = self.next(output)?;
+        if add.node_type != NodeType::Add {
+            return None;
+        }
+        let bias = self.operand(&add, output)?;
+        bias.value.as_ref()?;
+
+        Some((weight, bias, add.outputs[0].name.clone()))
+    }
+
+    /// Matches an exact GELU, either as a single node or as `x * 0.5 * (1 + erf(x / sqrt(2)))`,
+    /// returning its output.
+    fn gelu(&mut self, input: &str) -> Option<String> {
+        let is_close = |value: Option<f64>, expected: f64| {
+            value.is_some_and(|value| (value - expected).abs() <= 1e-4)
+        };
+
+        let node = self.next(input)?;
+        match node.node_type {
+            NodeType::Gelu => {
+                let approximate = node
+                    .attrs
+                    .get("approximate")
+                    .map(|approximate| approximate.clone().into_string());
+                matches!(approximate.as_deref(), None | Some("none"))
+                    .then(|| node.outputs[0].name.clone())
+            }
+            NodeType::Div if is_close(self.scalar_operand(&node, input), 2f64.sqrt()) => {
+                let erf = self.next(&node.outputs[0].name)?;
+                if erf.node_type != NodeType::Erf {
+                    return None;
+                }
+                let add = self.next(&erf.outputs[0].name)?;
+                if add.node_type != NodeType::Add
+                    || !is_close(self.scalar_operand(&add, &erf.outputs[0].name), 1.0)
+                {
+                    return None;
+                }
+                let mul = self.next(&add.outputs[0].name)?;
+                if mul.node_type != NodeType::Mul || !mul.inputs.iter().any(|arg| arg.name == input)
+                {
+                    return None;
+                }
+                let half = self.next(&mul.outputs[0].name)?;
+                if half.node_type != NodeType::Mul
+                    || !is_close(self.scalar_operand(&half, &mul.outputs[0].name), 0.5)
+                {
+                    return None;
+                }
+
+                Some(half.outputs[0].name.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the outputs of the nodes matched so far, except the last one, are neither used by
+    /// the other nodes nor outputs of the graph.
+    fn is_self_contained(&self) -> bool {
+        let Some((_, intermediates)) = self.outputs.split_last() else {
+            return true;
+        };
+
+        !intermediates
+            .iter()
+            .any(|name| self.graph_data.get_graph_output(name).is_some())
+            && !self
+                .lookahead
+                .clone()
+                .any(|proto| proto.input.iter().any(|name| intermediates.contains(name)))
+    }
+}
+
+/// The input of a binary node other than `input`.
+fn other_input<'n>(node: &'n Node, input: &str) -> Option<&'n Argument> {
+    let [lhs, rhs] = &node.inputs[..] else {
+        return None;
+    };
+
+    match (lhs.name == input, rhs.name == input) {
+        (true, false) => Some(rhs),
+        (false, true) => Some(lhs),
+        _ => None,
+    }
+}
+
+/// Whether the node is a Transpose with the given permutation.
+fn is_transpose(node: &Node, perm: &[i64]) -> bool {
+    node.node_type == NodeType::Transpose
+        && node
+            .attrs
+            .get("perm")
+            .is_some_and(|value| value.clone().into_i64s() == perm)
+}
+
+/// Whether the argument is a constant of the given shape.
+fn has_shape(arg: &Argument, shape: &[usize]) -> bool {
+    arg.value.as_ref().is_some_and(|value| value.shape == shape)
+}
+
+/// The value of a constant holding a single float.
+fn scalar_value(value: TensorData) -> Option<f64> {
+    match value.data {
+        Data::Float16(value) => Some(value.to_f64()),
+        Data::Float32(value) => Some(value as f64),
+        Data::Float64(value) => Some(value),
+        data @ (Data::Float16s(_) | Data::Float32s(_) | Data::Float64s(_)) => {
+            match data.into_f64s()[..] {
+                [value] => Some(value),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The values of a constant holding floats.
+fn float_values(value: &TensorData) -> Option<Vec<f64>> {
+    match &value.data {
+        data @ (Data::Float16s(_) | Data::Float32s(_) | Data::Float64s(_)) => {
+            Some(data.clone().into_f64s())
+        }
+        _ => None,
+    }
+}
+
+/// Float data with the values given, of the same type as `data`.
+fn float_data_like(data: &Data, values: Vec<f64>) -> Data {
+    match data {
+        Data::Float16s(_) => Data::Float16s(values.into_iter().map(f16::from_f64).collect()),
+        Data::Float32s(_) => Data::Float32s(values.into_iter().map(|value| value as f32).collect()),
+        _ => Data::Float64s(values),
+    }
+}
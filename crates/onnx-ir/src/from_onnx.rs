@@ -8,24 +8,30 @@ use crate::node_remap::remap_node_type;
 use crate::util::verify_opsets;
 
 use super::{
-    coalesce::coalesce,
+    coalesce::{coalesce, convert_unsqueeze_concat_to_stack},
     ir::{Data, ElementType, OnnxGraph, TensorData, TensorType},
     proto_conversion::convert_node_proto,
-    protos::{ModelProto, NodeProto, TensorProto, ValueInfoProto},
+    protos::{
+        GraphProto, ModelProto, NodeProto, TensorProto, ValueInfoProto,
+        attribute_proto::AttributeType,
+    },
 };
 
-use super::ir::{ArgType, Argument, Node, NodeType};
+use super::ir::{ArgType, Argument, AttributeValue, Node, NodeType};
 use super::rank_inference::rank_inference;
+use super::rank_validation::validate_ranks;
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 16] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 18] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::ConvInteger,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::HingeEmbeddingLoss,
     NodeType::OneHot,
     NodeType::ReduceSum,
     NodeType::Reshape,
@@ -60,6 +66,8 @@ pub struct GraphData {
     input_name_map: HashMap<String, IOEntry>,
     /// Maps the updated input name to the original input name. Required to check if the input is an initializer
     input_key_map: HashMap<String, String>,
+    /// The values of the enclosing graphs by original name, when the graph is a subgraph
+    outer_scope: HashMap<String, Argument>,
 }
 
 impl GraphData {
@@ -107,9 +115,28 @@ impl GraphData {
             processed_nodes: Vec::new(),
             input_name_map,
             input_key_map,
+            outer_scope: HashMap::new(),
         }
     }
 
+    /// Makes the values of the enclosing graphs available to the nodes of a subgraph.
+    pub(crate) fn with_outer_scope(mut self, outer_scope: HashMap<String, Argument>) -> Self {
+        self.outer_scope = outer_scope;
+        self
+    }
+
+    /// The values that can be used by a subgraph, by original name.
+    fn scope_values(&self) -> HashMap<String, Argument> {
+        let mut values = self.outer_scope.clone();
+        values.extend(self.initializers.clone());
+        values.extend(
+            self.input_name_map
+                .keys()
+                .map(|name| (name.clone(), self.init_in(name))),
+        );
+        values
+    }
+
     /// Get the value of an input from the original input name. Used during proto conversion
     pub(crate) fn init_in(&self, proto_str: &str) -> Argument {
         match self.input_name_map.get(proto_str) {
@@ -118,6 +145,8 @@ impl GraphData {
                 //need to confirm) then we could pop the initializer from the map
                 if let Some(init_arg) = self.initializers.get(proto_str) {
                     init_arg.clone()
+                } else if let Some(outer_arg) = self.outer_scope.get(proto_str) {
+                    outer_arg.clone()
                 } else {
                     log::warn!(
                         "Input {} not found, should only happen when peeking",
@@ -181,11 +210,20 @@ impl GraphData {
         (self.processed_nodes, self.inputs, outputs)
     }
 
-    /// Used to get the output of the graph by name. Only used to remap unsqueeze nodes
+    /// Used to get the output of the graph by name. Used to remap unsqueeze nodes and to keep
+    /// identity nodes producing a graph output
     pub fn get_graph_output(&self, name: &str) -> Option<&Argument> {
         self.outputs.iter().find(|x| x.name == name)
     }
 
+    /// Whether the value with the given name is the output of a Constant node processed so far.
+    /// Used to recognize constant operands before they are lifted
+    pub(crate) fn is_constant_output(&self, name: &str) -> bool {
+        self.processed_nodes
+            .iter()
+            .any(|node| node.node_type == NodeType::Constant && node.outputs[0].name == name)
+    }
+
     // Since Nodes are added at the end of conversion, the current index is the length of the processed nodes
     /// Get the current index of the processed nodes. Useful when lifting values or marking nodes for removal
     pub fn get_current_index(&self) -> usize {
@@ -208,19 +246,24 @@ pub(crate) struct OnnxGraphBuilder {
 
 impl OnnxGraphBuilder {
     pub(crate) fn build(mut self, model_proto: &ModelProto) -> OnnxGraph {
-        self.constants_types = LIFT_CONSTANTS_FOR_NODE_TYPES.into_iter().collect();
-
-        let mut graph_data = GraphData::new(
+        let graph_data = GraphData::new(
             &model_proto.graph.input,
             &model_proto.graph.output,
             &model_proto.graph.initializer,
         );
 
-        let mut node_iter = model_proto.graph.node.iter().peekable();
+        self.build_graph(&model_proto.graph, graph_data)
+    }
+
+    fn build_graph(&mut self, graph: &GraphProto, mut graph_data: GraphData) -> OnnxGraph {
+        self.constants_types = LIFT_CONSTANTS_FOR_NODE_TYPES.into_iter().collect();
+
+        let mut node_iter = graph.node.iter().peekable();
 
         while let Some(node_proto) = node_iter.next() {
             let mut node = convert_node_proto(node_proto, &graph_data);
 
+            self.handle_subgraphs(node_proto, &mut node, &graph_data);
             remap_node_type(&mut node);
             self.handle_node_renaming(&mut node);
             coalesce(&mut node, &mut node_iter, &graph_data);
@@ -230,12 +273,19 @@ impl OnnxGraphBuilder {
             // can filter, coalesce, or modify the nodes here
             // args : node, peek_iter, graph_data
             self.handle_unsqueeze(&mut node, &graph_data);
+            handle_cast_to_shape(&mut node, node_proto, graph);
 
             rank_inference(&mut node);
+            self.handle_unsqueeze_scalar(&mut node, node_proto, graph, &graph_data);
             graph_data.add_node(node);
         }
 
         let (mut processed_nodes, inputs, outputs) = graph_data.consume();
+        self.nodes_to_remove
+            .extend(convert_unsqueeze_concat_to_stack(
+                &mut processed_nodes,
+                &outputs,
+            ));
         // Remove the graph inputs/output that are not used by any node
         let mut i = 0;
         processed_nodes.retain(|_| {
@@ -254,6 +304,60 @@ impl OnnxGraphBuilder {
         }
     }
 
+    /// Parse the graph attributes of the node (e.g. the branches of `If`).
+    ///
+    /// The values of the enclosing graph used by a subgraph are appended to the node inputs, so
+    /// they are handled like any other input (e.g. the graph inputs are marked as passed).
+    fn handle_subgraphs(
+        &mut self,
+        node_proto: &NodeProto,
+        node: &mut Node,
+        graph_data: &GraphData,
+    ) {
+        for attr in node_proto
+            .attribute
+            .iter()
+            .filter(|attr| attr.type_.unwrap() == AttributeType::GRAPH)
+        {
+            let scope = graph_data.scope_values();
+            let scope_names: HashSet<String> = scope.values().map(|arg| arg.name.clone()).collect();
+            let subgraph = self.build_subgraph(&attr.g, scope);
+
+            let captured = subgraph
+                .nodes
+                .iter()
+                .flat_map(|node| node.inputs.iter())
+                .filter(|input| input.value.is_none() && scope_names.contains(&input.name));
+            for input in captured {
+                if !node.inputs.iter().any(|x| x.name == input.name) {
+                    node.inputs.push(input.clone());
+                }
+            }
+
+            node.attrs
+                .insert(attr.name.clone(), AttributeValue::Graph(subgraph));
+        }
+    }
+
+    fn build_subgraph(
+        &mut self,
+        graph: &GraphProto,
+        outer_scope: HashMap<String, Argument>,
+    ) -> OnnxGraph {
+        let graph_data = GraphData::new(&graph.input, &graph.output, &graph.initializer)
+            .with_outer_scope(outer_scope);
+
+        // The node counters are shared so that the node names are unique across all the graphs
+        let mut builder = OnnxGraphBuilder {
+            node_name_counter: std::mem::take(&mut self.node_name_counter),
+            ..Default::default()
+        };
+        let subgraph = builder.build_graph(graph, graph_data);
+        self.node_name_counter = builder.node_name_counter;
+
+        subgraph
+    }
+
     fn handle_node_renaming(&mut self, node: &mut Node) {
         log::debug!("renaming node {:?}", &node.name);
         self.node_name_counter
@@ -317,8 +421,57 @@ impl OnnxGraphBuilder {
         }
     }
 
+    /// Type an integer scalar unsqueezed to a single dim as a shape, when the value is only
+    /// consumed as a shape (e.g. concatenated with the output of `Shape` to reshape a tensor).
+    ///
+    /// Other consumers, like the indices of `Gather` or an arithmetic op with a tensor, keep the
+    /// int tensor inferred by the rank inference. A negative constant (e.g. the `-1` of a reshape)
+    /// is not a dim and also stays a tensor.
+    /// Needs to be called after the rank inference, with the original node proto.
+    fn handle_unsqueeze_scalar(
+        &self,
+        node: &mut Node,
+        node_proto: &NodeProto,
+        graph: &GraphProto,
+        graph_data: &GraphData,
+    ) {
+        if node.node_type != NodeType::Unsqueeze
+            || !matches!(
+                node.inputs[0].ty,
+                ArgType::Scalar(ElementType::Int32 | ElementType::Int64)
+            )
+            || node.outputs[0].ty.rank() != 1
+        {
+            return;
+        }
+
+        let value = match self.constants_map.get(&node.inputs[0].name) {
+            Some(const_idx) => {
+                convert_constant_value(&graph_data.processed_nodes[*const_idx]).value
+            }
+            None => node.inputs[0].value.clone(),
+        };
+        let is_negative = value.is_some_and(|value| match value.data {
+            Data::Int32(value) => value < 0,
+            Data::Int64(value) => value < 0,
+            Data::Int32s(values) => values.iter().any(|value| *value < 0),
+            Data::Int64s(values) => values.iter().any(|value| *value < 0),
+            _ => false,
+        });
+
+        if !is_negative && is_consumed_as_shape(graph, &node_proto.output[0]) {
+            node.outputs[0].ty = ArgType::Shape(1);
+            log::debug!("Unsqueeze output shape for {}: 1", node.name);
+        }
+    }
+
+    /// Remove pass-through identity nodes by renaming the inputs that consume their output.
+    /// Identity nodes producing a graph output are kept, since the output must be produced by a node.
     fn handle_identity(&mut self, node: &mut Node, graph_data: &GraphData) {
-        if node.node_type == NodeType::Identity && node.inputs[0].value.is_none() {
+        if node.node_type == NodeType::Identity
+            && node.inputs[0].value.is_none()
+            && graph_data.get_graph_output(&node.outputs[0].name).is_none()
+        {
             log::debug!("\nfound identity node:\n{:?}\n", &node);
             let i = graph_data.get_current_index();
             //map the output name to check for pass through values
@@ -336,6 +489,62 @@ impl OnnxGraphBuilder {
     }
 }
 
+/// Request a shape output for a `Cast` of an Int tensor whose output is only consumed as a shape
+/// (e.g. the shape input of `Expand`).
+///
+/// The length of the shape is resolved by the rank inference, which needs to be called afterward.
+fn handle_cast_to_shape(node: &mut Node, node_proto: &NodeProto, graph: &GraphProto) {
+    if node.node_type != NodeType::Cast
+        || !matches!(
+            &node.inputs[0].ty,
+            ArgType::Tensor(tensor)
+                if matches!(tensor.elem_type, ElementType::Int32 | ElementType::Int64)
+        )
+    {
+        return;
+    }
+
+    if is_consumed_as_shape(graph, &node_proto.output[0]) {
+        node.outputs[0].ty = ArgType::Shape(0);
+    }
+}
+
+/// Whether every consumer of the value `name` reads it as a shape: the shape input of `Reshape`
+/// or `Expand`, a `Squeeze` back to a scalar, or a `Concat` with outputs of `Shape` which is
+/// itself only consumed as a shape.
+fn is_consumed_as_shape(graph: &GraphProto, name: &str) -> bool {
+    if graph.output.iter().any(|output| output.name == name) {
+        return false;
+    }
+
+    let is_shape_output = |input: &String| {
+        graph
+            .node
+            .iter()
+            .any(|node| node.op_type == "Shape" && node.output.contains(input))
+    };
+
+    let mut consumers = graph
+        .node
+        .iter()
+        .filter(|node| node.input.iter().any(|input| input == name))
+        .peekable();
+
+    consumers.peek().is_some()
+        && consumers.all(|consumer| match consumer.op_type.as_str() {
+            "Reshape" | "Expand" => consumer.input[0] != name,
+            "Squeeze" => consumer.input.iter().skip(1).all(|input| input != name),
+            "Concat" => {
+                consumer
+                    .input
+                    .iter()
+                    .all(|input| input == name || is_shape_output(input))
+                    && is_consumed_as_shape(graph, &consumer.output[0])
+            }
+            _ => false,
+        })
+}
+
 /// Parses an ONNX model file and converts it to an intermediate representation.
 ///
 /// This function reads an ONNX model from the specified path, validates its opset version,
@@ -355,6 +564,7 @@ impl OnnxGraphBuilder {
 /// * If the ONNX model cannot be parsed
 /// * If the model uses an unsupported opset version (must be >= MIN_OPSET_VERSION)
 /// * If the nodes in the graph are not topologically sorted
+/// * If a node input rank doesn't match its producer or its operator (see [validate_ranks])
 pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
     log::info!("Parsing ONNX file: {}", onnx_path.display());
 
@@ -406,6 +616,18 @@ pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
     let builder = OnnxGraphBuilder::default();
     let graph = builder.build(&onnx_model);
 
+    if let Err(mismatches) = validate_ranks(&graph) {
+        let mismatches = mismatches
+            .iter()
+            .map(|mismatch| format!("  - {mismatch}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        panic!(
+            "Rank validation failed for ONNX file {}:\n{mismatches}",
+            onnx_path.display()
+        );
+    }
+
     log::info!("Finished parsing ONNX file: {}", onnx_path.display());
 
     graph
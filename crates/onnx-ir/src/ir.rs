@@ -66,6 +66,8 @@ pub enum ArgType {
     Scalar(ElementType),
     Shape(Rank),
     Tensor(TensorType),
+    /// A value that may be absent, wrapping the type of the value.
+    Optional(Box<ArgType>),
 }
 
 /// The type of an attribute.
@@ -79,6 +81,8 @@ pub enum AttributeValue {
     Strings(Vec<String>),
     Tensor(TensorData),
     Tensors(Vec<TensorData>),
+    /// A subgraph, e.g. a branch of `If`, whose nodes can use the values of the enclosing graph.
+    Graph(OnnxGraph),
 }
 
 pub type Attributes = HashMap<String, AttributeValue>;
@@ -88,6 +92,9 @@ pub type Attributes = HashMap<String, AttributeValue>;
 pub enum ElementType {
     Float32,
     Float64,
+    Int8,
+    Uint8,
+    Int16,
     Int32,
     Int64,
     String,
@@ -134,6 +141,7 @@ impl ArgType {
             ArgType::Scalar(_) => 0,
             ArgType::Shape(_) => 1,
             ArgType::Tensor(t) => t.rank,
+            ArgType::Optional(ty) => ty.rank(),
         }
     }
 
@@ -143,6 +151,7 @@ impl ArgType {
             ArgType::Scalar(s) => s,
             ArgType::Shape(_) => panic!("ArgType::Shape has no ElementType"),
             ArgType::Tensor(t) => &t.elem_type,
+            ArgType::Optional(ty) => ty.elem_type(),
         }
     }
 }
@@ -290,6 +299,8 @@ pub enum NodeType {
     Asinh,
     Atan,
     Atanh,
+    /// The `com.microsoft.Attention` contrib operator from ONNX Runtime.
+    Attention,
     AveragePool,
     AveragePool1d,
     AveragePool2d,
@@ -322,10 +333,16 @@ pub enum NodeType {
     ConvTranspose1d,
     ConvTranspose2d,
     ConvTranspose3d,
+    /// ConvNeXt block recognized from its depthwise convolution and feed-forward layer (not an ONNX
+    /// operator).
+    ConvNeXtBlock,
     Cos,
     Cosh,
     CumSum,
     DepthToSpace,
+    /// Depthwise separable convolution recognized from a depthwise convolution followed by a
+    /// pointwise convolution (not an ONNX operator).
+    DepthwiseSeparableConv2d,
     DequantizeLinear,
     Det,
     DFT,
@@ -359,6 +376,8 @@ pub enum NodeType {
     Hardmax,
     HardSigmoid,
     HardSwish,
+    /// Hinge embedding loss recognized from its `Where` decomposition (not an ONNX operator).
+    HingeEmbeddingLoss,
     Identity,
     If,
     Im,
@@ -425,7 +444,12 @@ pub enum NodeType {
     ReduceProd,
     ReduceSum,
     ReduceSumSquare,
+    /// T5 relative position bias recognized from its embedding lookup (not an ONNX operator).
+    RelativePositionBias,
     Relu,
+    /// The `RepeatInterleave` custom operator from the `ai.onnx.contrib` domain, repeating each
+    /// element of the input along the `axis` attribute by the counts of the `repeats` input.
+    RepeatInterleave,
     Reshape,
     Resize,
     ReverseSequence,
@@ -449,6 +473,8 @@ pub enum NodeType {
     Sigmoid,
     Sign,
     Sin,
+    /// Sinusoidal positional encoding recognized from its constant table (not an ONNX operator).
+    SinusoidalPositionalEncoding,
     Sinh,
     Size,
     Slice,
@@ -461,6 +487,12 @@ pub enum NodeType {
     SplitToSequence,
     Sqrt,
     Squeeze,
+    /// Squeeze-and-excitation recognized from a global average pooling followed by two pointwise
+    /// convolutions gating the input (not an ONNX operator).
+    SqueezeExcitation,
+    /// Concatenation of tensors unsqueezed along the concatenation axis, recognized from the
+    /// Unsqueeze and Concat nodes exported for `torch.stack` (not an ONNX operator).
+    Stack,
     STFT,
     StringNormalizer,
     Sub,
@@ -472,6 +504,10 @@ pub enum NodeType {
     Tile,
     TopK,
     Transpose,
+    /// The `TRT_PluginV2` custom operator wrapping a TensorRT plugin, found in models re-exported
+    /// from TensorRT. The plugin type is given by the `name` attribute.
+    #[strum(serialize = "TRT_PluginV2")]
+    TrtPluginV2,
     Trilu,
     Unique,
     Unsqueeze,
@@ -778,6 +814,14 @@ impl AttributeValue {
             panic!("Expected Tensors, got {:?}", self);
         }
     }
+
+    pub fn into_graph(self) -> OnnxGraph {
+        if let AttributeValue::Graph(elem) = self {
+            elem
+        } else {
+            panic!("Expected Graph, got {:?}", self);
+        }
+    }
 }
 
 /// Convert AttributeValue to an Argument
@@ -6,8 +6,10 @@ mod node_remap;
 mod proto_conversion;
 mod protos;
 mod rank_inference;
+mod rank_validation;
 pub mod util;
 
 pub use from_onnx::convert_constant_value;
 pub use from_onnx::parse_onnx;
 pub use ir::OnnxGraph;
+pub use rank_validation::{RankMismatch, validate_ranks};
@@ -0,0 +1,199 @@
+use crate::ir::{ArgType, Node, TensorType};
+
+/// Returns the concatenation axis of a Concat node, with negative values counted from the end.
+pub fn concat_axis(node: &Node) -> usize {
+    let rank = node
+        .inputs
+        .iter()
+        .find_map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => Some(tensor.rank),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("Concat: no tensor input for node {}", node.name));
+
+    // The axis is required by the ONNX spec since opset 4
+    let axis = node
+        .attrs
+        .get("axis")
+        .map(|value| value.clone().into_i64())
+        .unwrap_or(1);
+
+    if axis < 0 {
+        (axis + rank as i64) as usize
+    } else {
+        axis as usize
+    }
+}
+
+/// Computes the output shape of a concatenation along `axis`, where the inputs may differ in
+/// size-1 dimensions other than the concatenation axis.
+///
+/// A dimension other than `axis` matches when the sizes are equal or one of them is 1, in which
+/// case the input is broadcast to the other size.
+///
+/// # Panics
+///
+/// If the inputs have different ranks or if a dimension other than `axis` genuinely conflicts.
+pub fn concat_broadcast_shape(shapes: &[Vec<usize>], axis: usize) -> Vec<usize> {
+    let mut output = shapes
+        .first()
+        .expect("Concat: at least one input is required")
+        .clone();
+    output[axis] = 0;
+
+    for shape in shapes.iter() {
+        assert_eq!(
+            shape.len(),
+            output.len(),
+            "Concat: all inputs must have the same rank, got shapes {shapes:?}"
+        );
+
+        for (dim, (out, size)) in output.iter_mut().zip(shape.iter()).enumerate() {
+            if dim == axis {
+                *out += size;
+            } else if *out == 1 {
+                *out = *size;
+            } else if *size != 1 && *size != *out {
+                panic!(
+                    "Concat: inputs have conflicting sizes on dimension {dim} \
+                    (only size-1 dimensions can be broadcast), got shapes {shapes:?}"
+                );
+            }
+        }
+    }
+
+    output
+}
+
+/// Update output rank for Concat (same as first tensor input).
+///
+/// When the shapes of all the inputs are known, they are validated and the broadcast output
+/// shape is inferred.
+pub fn concat_update_outputs(node: &mut Node) {
+    log::debug!("Concat rank inference for node {}", node.name);
+
+    if node
+        .inputs
+        .iter()
+        .any(|input| matches!(input.ty, ArgType::Shape(_)))
+    {
+        concat_shapes_update_outputs(node);
+        return;
+    }
+
+    let tensor = node
+        .inputs
+        .iter()
+        .find_map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => Some(tensor.clone()),
+            _ => None,
+        })
+        .unwrap();
+
+    log::debug!("Concat using input rank for {}: {}", node.name, tensor.rank);
+
+    let static_shape = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.static_shape.clone(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|shapes| concat_broadcast_shape(&shapes, concat_axis(node)));
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type,
+        rank: tensor.rank,
+        static_shape,
+    });
+
+    log::debug!("Concat output rank for {}: {}", node.name, tensor.rank);
+}
+
+/// Update output for a Concat of shapes, where scalars count as a single dimension.
+///
+/// The output is a shape whose rank is the sum of the input ranks.
+fn concat_shapes_update_outputs(node: &mut Node) {
+    let rank = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Shape(rank) => *rank,
+            ArgType::Scalar(_) => 1,
+            ty => panic!(
+                "Concat: only shapes and scalars can be concatenated with shapes, got {ty:?} for node {}",
+                node.name
+            ),
+        })
+        .sum();
+
+    node.outputs[0].ty = ArgType::Shape(rank);
+
+    log::debug!("Concat output shape rank for {}: {}", node.name, rank);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::ir::{Argument, ElementType, NodeType};
+
+    use super::*;
+
+    fn create_test_node(inputs: Vec<ArgType>) -> Node {
+        let argument = |(i, ty)| Argument {
+            name: format!("input{i}"),
+            ty,
+            value: None,
+            passed: true,
+        };
+
+        Node {
+            node_type: NodeType::Concat,
+            name: "test_concat".to_string(),
+            inputs: inputs.into_iter().enumerate().map(argument).collect(),
+            outputs: vec![argument((0, ArgType::default()))],
+            attrs: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_concat_update_outputs_shapes() {
+        let mut node = create_test_node(vec![ArgType::Shape(1), ArgType::Shape(2)]);
+
+        concat_update_outputs(&mut node);
+
+        assert!(matches!(node.outputs[0].ty, ArgType::Shape(3)));
+    }
+
+    #[test]
+    fn test_concat_update_outputs_shape_and_scalar() {
+        let mut node =
+            create_test_node(vec![ArgType::Scalar(ElementType::Int64), ArgType::Shape(2)]);
+
+        concat_update_outputs(&mut node);
+
+        assert!(matches!(node.outputs[0].ty, ArgType::Shape(3)));
+    }
+
+    #[test]
+    fn test_concat_broadcast_shape_same_shapes() {
+        let shape = concat_broadcast_shape(&[vec![2, 1, 3], vec![2, 4, 3]], 1);
+
+        assert_eq!(shape, vec![2, 5, 3]);
+    }
+
+    #[test]
+    fn test_concat_broadcast_shape_unit_dim() {
+        let shape = concat_broadcast_shape(&[vec![1, 1, 3], vec![2, 4, 3], vec![2, 2, 1]], 1);
+
+        assert_eq!(shape, vec![2, 7, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicting sizes on dimension 0")]
+    fn test_concat_broadcast_shape_conflict() {
+        let _ = concat_broadcast_shape(&[vec![3, 1, 3], vec![2, 4, 3]], 1);
+    }
+}
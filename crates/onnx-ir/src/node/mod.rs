@@ -1 +1,2 @@
+pub mod concat;
 pub mod slice;
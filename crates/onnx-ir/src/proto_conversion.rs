@@ -1,4 +1,4 @@
-use std::str::{FromStr, from_utf8};
+use std::str::FromStr;
 
 use crate::ir::TensorType;
 
@@ -7,7 +7,7 @@ use super::ir::{
     ArgType, Argument, AttributeValue, Attributes, Data, ElementType, Node, NodeType, TensorData,
 };
 use super::protos::{
-    AttributeProto, NodeProto, TensorProto, TensorShapeProto, ValueInfoProto,
+    AttributeProto, NodeProto, TensorProto, TensorShapeProto, TypeProto, ValueInfoProto,
     attribute_proto::AttributeType, tensor_proto::DataType, tensor_shape_proto::dimension::Value,
 };
 
@@ -34,6 +34,17 @@ impl TryFrom<TensorProto> for TensorData {
                     Data::Float32s(tensor.float_data)
                 },
             ),
+            data_type @ (DataType::INT8 | DataType::UINT8) => (
+                ElementType::Int32,
+                // The 8-bit values are widened to int32, they are stored as int32 when not raw
+                if tensor.raw_data.is_empty() {
+                    Data::Int32s(tensor.int32_data)
+                } else if data_type == DataType::INT8 {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i8 as i32).collect())
+                } else {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i32).collect())
+                },
+            ),
             DataType::INT16 => {
                 // TODO : Add support for int16 by converting to int32
                 todo!("Add support for int16");
@@ -116,8 +127,7 @@ impl TryFrom<AttributeProto> for AttributeValue {
             // warning: tensor can be empty TODO: check if it is empty
             AttributeType::TENSOR => AttributeValue::Tensor(TensorData::try_from(attr.t.unwrap())?),
 
-            // Graph is not supported for now
-            // AttributeType::GRAPH => AttributeValue::Graph(attr.g),
+            // Graphs are parsed by the graph builder (see convert_vec_attrs_proto)
             AttributeType::FLOATS => AttributeValue::Float32s(attr.floats),
             AttributeType::INTS => AttributeValue::Int64s(attr.ints),
             AttributeType::STRINGS => AttributeValue::Strings(to_string_vec(attr.strings)),
@@ -137,9 +147,15 @@ impl TryFrom<AttributeProto> for AttributeValue {
 }
 
 /// Convert a vector of AttributeProto to a HashMap of AttributeValue
+///
+/// Graph attributes are skipped, they are parsed by the graph builder since their nodes can use
+/// the values of the enclosing graph.
 pub fn convert_vec_attrs_proto(attrs: Vec<AttributeProto>) -> Attributes {
     let mut result = Attributes::new();
     for attr in attrs {
+        if attr.type_.unwrap() == AttributeType::GRAPH {
+            continue;
+        }
         result.insert(attr.name.clone(), AttributeValue::try_from(attr).unwrap());
     }
     result
@@ -162,6 +178,20 @@ pub fn convert_node_proto(node: &NodeProto, graph_data: &GraphData) -> Node {
 
     let node_type = NodeType::from_str(node.op_type.as_str()).expect("Unknown node type");
 
+    if node_type == NodeType::Attention && node.domain != "com.microsoft" {
+        panic!(
+            "Attention is only supported from the com.microsoft domain (got {:?})",
+            node.domain
+        );
+    }
+
+    if node_type == NodeType::RepeatInterleave && node.domain != "ai.onnx.contrib" {
+        panic!(
+            "RepeatInterleave is only supported from the ai.onnx.contrib domain (got {:?})",
+            node.domain
+        );
+    }
+
     Node {
         node_type,
         name,
@@ -172,7 +202,8 @@ pub fn convert_node_proto(node: &NodeProto, graph_data: &GraphData) -> Node {
 }
 
 fn to_string(bytes: Vec<u8>) -> String {
-    from_utf8(bytes.as_slice()).unwrap().to_string()
+    // Some string attributes hold binary payloads (e.g. the `data` of TensorRT plugins)
+    String::from_utf8_lossy(bytes.as_slice()).into_owned()
 }
 
 fn to_string_vec(bytes: Vec<Vec<u8>>) -> Vec<String> {
@@ -189,66 +220,7 @@ impl TryFrom<ValueInfoProto> for Argument {
     fn try_from(value: ValueInfoProto) -> Result<Argument, Self::Error> {
         let name = value.name.clone();
         let proto_type = value.type_.unwrap();
-
-        if !proto_type.has_tensor_type() {
-            panic!("Unsupported argument type {:?}", proto_type);
-        }
-
-        let tensor_proto = proto_type.tensor_type();
-
-        let elem_type = match DataType::from_i32(tensor_proto.elem_type).unwrap() {
-            DataType::FLOAT => ElementType::Float32,
-            DataType::INT32 => ElementType::Int32,
-            DataType::INT64 => ElementType::Int64,
-            DataType::DOUBLE => ElementType::Float64,
-            DataType::BOOL => ElementType::Bool,
-            _ => {
-                return Err(ParseError::VariantNotFound);
-            }
-        };
-
-        let ty = if tensor_proto.shape.dim.is_empty() {
-            // tensor_proto describes a scalar
-            ArgType::Scalar(elem_type)
-        } else {
-            // tensor_proto describes a tensor
-            // Check if any dimension is None
-            let has_unknown_dim = tensor_proto.shape.dim.iter().any(|dim| {
-                match &dim.value {
-                    None => true,
-                    Some(Value::DimParam(_)) => true, // Unknown with string dimension parameter
-                    Some(Value::DimValue(_)) => false,
-                }
-            });
-
-            // TODO DT use inferred shape information
-
-            let static_shape = if has_unknown_dim {
-                None
-            } else {
-                let shape: Vec<usize> = tensor_proto
-                    .shape
-                    .dim
-                    .iter()
-                    .filter_map(|dim| {
-                        if let Some(Value::DimValue(value)) = &dim.value {
-                            Some(*value as usize)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                Some(shape)
-            };
-
-            let tensor_type = TensorType {
-                rank: tensor_proto.shape.dim.len(),
-                elem_type,
-                static_shape,
-            };
-
-            ArgType::Tensor(tensor_type)
-        };
+        let ty = convert_arg_type(&proto_type)?;
 
         Ok(Argument {
             ty,
@@ -258,3 +230,80 @@ impl TryFrom<ValueInfoProto> for Argument {
         })
     }
 }
+
+/// Convert the type of a value, which is a tensor or an optional tensor.
+fn convert_arg_type(proto_type: &TypeProto) -> Result<ArgType, ParseError> {
+    if proto_type.has_optional_type() {
+        let elem_type = proto_type
+            .optional_type()
+            .elem_type
+            .as_ref()
+            .expect("Optional type must have an element type");
+        return Ok(ArgType::Optional(Box::new(convert_arg_type(elem_type)?)));
+    }
+
+    if !proto_type.has_tensor_type() {
+        panic!("Unsupported argument type {:?}", proto_type);
+    }
+
+    let tensor_proto = proto_type.tensor_type();
+
+    let elem_type = match DataType::from_i32(tensor_proto.elem_type).unwrap() {
+        DataType::FLOAT => ElementType::Float32,
+        DataType::INT8 => ElementType::Int8,
+        DataType::UINT8 => ElementType::Uint8,
+        DataType::INT16 => ElementType::Int16,
+        DataType::INT32 => ElementType::Int32,
+        DataType::INT64 => ElementType::Int64,
+        DataType::DOUBLE => ElementType::Float64,
+        DataType::BOOL => ElementType::Bool,
+        _ => {
+            return Err(ParseError::VariantNotFound);
+        }
+    };
+
+    let ty = if tensor_proto.shape.dim.is_empty() {
+        // tensor_proto describes a scalar
+        ArgType::Scalar(elem_type)
+    } else {
+        // tensor_proto describes a tensor
+        // Check if any dimension is None
+        let has_unknown_dim = tensor_proto.shape.dim.iter().any(|dim| {
+            match &dim.value {
+                None => true,
+                Some(Value::DimParam(_)) => true, // Unknown with string dimension parameter
+                Some(Value::DimValue(_)) => false,
+            }
+        });
+
+        // TODO DT use inferred shape information
+
+        let static_shape = if has_unknown_dim {
+            None
+        } else {
+            let shape: Vec<usize> = tensor_proto
+                .shape
+                .dim
+                .iter()
+                .filter_map(|dim| {
+                    if let Some(Value::DimValue(value)) = &dim.value {
+                        Some(*value as usize)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            Some(shape)
+        };
+
+        let tensor_type = TensorType {
+            rank: tensor_proto.shape.dim.len(),
+            elem_type,
+            static_shape,
+        };
+
+        ArgType::Tensor(tensor_type)
+    };
+
+    Ok(ty)
+}
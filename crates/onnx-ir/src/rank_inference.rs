@@ -5,7 +5,7 @@ use protobuf::Enum;
 
 use crate::{
     ir::{ArgType, AttributeValue, Data, ElementType, Node, NodeType, TensorType},
-    node::slice::slice_update_output_rank,
+    node::{concat::concat_update_outputs, slice::slice_update_output_rank},
     protos::tensor_proto::DataType,
     util::shape_config,
 };
@@ -15,8 +15,10 @@ pub fn rank_inference(node: &mut Node) {
     log::debug!("Inferring rank for node: {}", node.name);
 
     match node.node_type {
-        NodeType::Add => same_as_input_broadcast(node),
+        NodeType::Add => arithmetic_update_outputs(node),
+        NodeType::And => elementwise_comparison_outputs(node),
         NodeType::ArgMax => argmax_update_outputs(node),
+        NodeType::Attention => attention_update_outputs(node),
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
         NodeType::BatchNormalization => same_as_input(node),
@@ -27,9 +29,10 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::ConstantOfShape => constant_of_shape_update_output(node),
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
+        NodeType::ConvInteger => conv_integer_update_outputs(node),
         NodeType::Cos => same_as_input(node),
         NodeType::Cosh => same_as_input(node),
-        NodeType::Div => same_as_input_broadcast(node),
+        NodeType::Div => arithmetic_update_outputs(node),
         NodeType::Dropout => same_as_input(node),
         NodeType::Equal => elementwise_comparison_outputs(node),
         NodeType::Erf => same_as_input(node),
@@ -44,9 +47,16 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Greater => elementwise_comparison_outputs(node),
         NodeType::GreaterOrEqual => elementwise_comparison_outputs(node),
         NodeType::HardSigmoid => same_as_input(node),
+        NodeType::Hardmax => same_as_input(node),
+        NodeType::HingeEmbeddingLoss => hinge_embedding_loss_update_outputs(node),
+        NodeType::Identity => same_as_input(node),
+        NodeType::If => if_update_outputs(node),
         NodeType::GlobalAveragePool => same_as_input(node),
         NodeType::ConvTranspose1d => conv_transpose1d_update_outputs(node),
         NodeType::ConvTranspose2d => conv_transpose2d_update_outputs(node),
+        NodeType::ConvNeXtBlock => same_as_input(node),
+        NodeType::SqueezeExcitation => same_as_input(node),
+        NodeType::DepthwiseSeparableConv2d => conv2d_update_outputs(node),
         NodeType::LayerNormalization => same_as_input(node),
         NodeType::LeakyRelu => same_as_input(node),
         NodeType::Less => elementwise_comparison_outputs(node),
@@ -59,10 +69,14 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
         NodeType::Min => same_as_input_broadcast(node),
-        NodeType::Mul => same_as_input(node),
+        NodeType::Mul => arithmetic_update_outputs(node),
         NodeType::Neg => same_as_input(node),
         NodeType::Not => same_as_input(node),
         NodeType::OneHot => one_hot_output_shape(node),
+        NodeType::Optional => optional_update_outputs(node),
+        NodeType::OptionalGetElement => optional_get_element_update_outputs(node),
+        NodeType::OptionalHasElement => optional_has_element_update_outputs(node),
+        NodeType::Or => elementwise_comparison_outputs(node),
         NodeType::Pad => same_as_input(node),
         NodeType::PRelu => same_as_input_broadcast(node),
         NodeType::Pow => same_as_input_broadcast(node),
@@ -77,28 +91,36 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::ReduceMean => reduce_mean_update_outputs(node),
         NodeType::ReduceProd => reduce_prod_update_outputs(node),
         NodeType::ReduceSum => reduce_sum_update_outputs(node),
+        NodeType::RelativePositionBias => relative_position_bias_update_outputs(node),
         NodeType::Relu => same_as_input(node),
+        NodeType::RepeatInterleave => same_as_input(node),
         NodeType::Reshape => reshape_update_outputs(node),
         NodeType::Resize => same_as_input(node),
+        NodeType::ScatterElements => same_as_input(node),
         NodeType::Shape => shape_update_outputs(node),
         NodeType::Sigmoid => same_as_input(node),
         NodeType::Sign => same_as_input(node),
         NodeType::Sin => same_as_input(node),
+        NodeType::SinusoidalPositionalEncoding => same_as_input(node),
         NodeType::Sinh => same_as_input(node),
         NodeType::Slice => slice_update_output_rank(node),
         NodeType::Softmax => same_as_input(node),
+        NodeType::SoftmaxCrossEntropyLoss => softmax_cross_entropy_loss_update_outputs(node),
         NodeType::Split => split_update_outputs(node),
         NodeType::Squeeze => squeeze_update_output(node),
         NodeType::Sqrt => same_as_input(node),
-        NodeType::Sub => same_as_input_broadcast(node),
+        NodeType::Sub => arithmetic_update_outputs(node),
         NodeType::Sum => same_as_input_broadcast(node),
         NodeType::Tan => same_as_input(node),
         NodeType::Tanh => same_as_input(node),
         NodeType::TopK => top_k_update_output(node),
         NodeType::Transpose => same_as_input(node),
         NodeType::Trilu => same_as_input(node),
+        // Plugins are resolved by the importer, unknown ones are passed through as no-ops
+        NodeType::TrtPluginV2 => same_as_input(node),
         NodeType::Unsqueeze => unsqueeze_update_output(node),
         NodeType::Where => where_update_outputs(node),
+        NodeType::Xor => elementwise_comparison_outputs(node),
         _ => temporary_pass_through_stub(node),
     }
 
@@ -322,7 +344,157 @@ fn linear_update_outputs(node: &mut Node) {
     }
 }
 
+/// Update output type for RelativePositionBias: `[num_heads, query_len, key_len]` biases with the
+/// element type of the table.
+fn relative_position_bias_update_outputs(node: &mut Node) {
+    log::debug!("RelativePositionBias rank inference for node {}", node.name);
+
+    let table = node.inputs[0]
+        .value
+        .as_ref()
+        .expect("RelativePositionBias: the bias table is required");
+    let ArgType::Tensor(tensor) = &node.inputs[0].ty else {
+        panic!("RelativePositionBias: the bias table must be a tensor");
+    };
+    let length = |key: &str| node.attrs[key].clone().into_i64() as usize;
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type.clone(),
+        rank: 3,
+        static_shape: Some(vec![table.shape[1], length("query_len"), length("key_len")]),
+    });
+}
+
+/// Update output type for HingeEmbeddingLoss: the loss of each element when it isn't reduced,
+/// otherwise a single value.
+fn hinge_embedding_loss_update_outputs(node: &mut Node) {
+    log::debug!("HingeEmbeddingLoss rank inference for node {}", node.name);
+
+    let ArgType::Tensor(tensor) = &node.inputs[0].ty else {
+        panic!("HingeEmbeddingLoss: the input must be a tensor");
+    };
+    let rank = match node.attrs.get("reduction") {
+        Some(AttributeValue::String(reduction)) if reduction == "none" => tensor.rank,
+        _ => 1,
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: tensor.elem_type.clone(),
+        rank,
+        static_shape: None,
+    });
+}
+
+/// Update output types for SoftmaxCrossEntropyLoss: the loss of each sample when it isn't
+/// reduced, else the reduced loss as a single element tensor, and the log probabilities.
+fn softmax_cross_entropy_loss_update_outputs(node: &mut Node) {
+    log::debug!(
+        "SoftmaxCrossEntropyLoss rank inference for node {}",
+        node.name
+    );
+
+    let ArgType::Tensor(scores) = &node.inputs[0].ty else {
+        panic!("SoftmaxCrossEntropyLoss: the scores must be a tensor");
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: scores.elem_type.clone(),
+        rank: 1,
+        static_shape: None,
+    });
+    if let Some(log_prob) = node.outputs.get_mut(1) {
+        log_prob.ty = node.inputs[0].ty.clone();
+    }
+}
+
+/// Update output types for If from the outputs of the branches, which must have the same ranks.
+fn if_update_outputs(node: &mut Node) {
+    log::debug!("If rank inference for node {}", node.name);
+
+    let branch_outputs = |key: &str| match node.attrs.get(key) {
+        Some(AttributeValue::Graph(graph)) => graph.outputs.clone(),
+        _ => panic!("If: the {key} attribute is required"),
+    };
+    let then_outputs = branch_outputs("then_branch");
+    let else_outputs = branch_outputs("else_branch");
+
+    for outputs in [&then_outputs, &else_outputs] {
+        if outputs.len() != node.outputs.len() {
+            panic!(
+                "If: the branches must produce {} outputs with their nodes, got {}",
+                node.outputs.len(),
+                outputs.len()
+            );
+        }
+    }
+
+    for ((output, then_output), else_output) in node
+        .outputs
+        .iter_mut()
+        .zip(then_outputs.iter())
+        .zip(else_outputs.iter())
+    {
+        if then_output.ty.rank() != else_output.ty.rank() {
+            panic!(
+                "If: the branch outputs {} and {} have different ranks",
+                then_output.name, else_output.name
+            );
+        }
+        output.ty = then_output.ty.clone();
+    }
+}
+
+/// Update output type for Optional, wrapping the type of its input.
+fn optional_update_outputs(node: &mut Node) {
+    log::debug!("Optional rank inference for node {}", node.name);
+
+    let input = node
+        .inputs
+        .first()
+        .filter(|input| !input.name.is_empty())
+        .unwrap_or_else(|| panic!("Optional: empty optionals without input are not supported"));
+
+    node.outputs[0].ty = ArgType::Optional(Box::new(input.ty.clone()));
+}
+
+/// Update output type for OptionalGetElement, unwrapping the type of its optional input.
+///
+/// A non-optional input is forwarded as is.
+fn optional_get_element_update_outputs(node: &mut Node) {
+    log::debug!("OptionalGetElement rank inference for node {}", node.name);
+
+    node.outputs[0].ty = match &node.inputs[0].ty {
+        ArgType::Optional(ty) => *ty.clone(),
+        ty => ty.clone(),
+    };
+}
+
+/// Update output type for OptionalHasElement, which is a boolean scalar.
+fn optional_has_element_update_outputs(node: &mut Node) {
+    log::debug!("OptionalHasElement rank inference for node {}", node.name);
+
+    node.outputs[0].ty = ArgType::Scalar(ElementType::Bool);
+}
+
+/// Update output types for Attention, the output has the shape of the input and the optional
+/// present keys and values have rank 5.
+fn attention_update_outputs(node: &mut Node) {
+    log::debug!("Attention rank inference for node {}", node.name);
+
+    same_as_input(node);
+
+    if let Some(present) = node.outputs.get_mut(1) {
+        present.ty = ArgType::Tensor(TensorType {
+            elem_type: node.inputs[0].ty.elem_type().clone(),
+            rank: 5,
+            static_shape: None,
+        });
+    }
+}
+
 /// Update output type for Cast operations, preserving rank.
+///
+/// An Int tensor cast with a shape output, when only consumed as a shape, becomes a shape.
 fn cast_update_outputs(node: &mut Node) {
     if node.inputs.len() != 1 {
         panic!("Cast: multiple inputs are not supported");
@@ -334,6 +506,8 @@ fn cast_update_outputs(node: &mut Node) {
         Some(value) => match &value {
             AttributeValue::Int64(type_id) => match DataType::from_i32(*type_id as i32).unwrap() {
                 DataType::FLOAT => ElementType::Float32,
+                DataType::INT8 => ElementType::Int8,
+                DataType::INT16 => ElementType::Int16,
                 DataType::INT32 => ElementType::Int32,
                 DataType::INT64 => ElementType::Int64,
                 DataType::DOUBLE => ElementType::Float64,
@@ -346,6 +520,23 @@ fn cast_update_outputs(node: &mut Node) {
     };
 
     match input.ty.clone() {
+        // An Int tensor only consumed as a shape becomes a shape, its length is the static length
+        // of the tensor
+        ArgType::Tensor(tensor) if matches!(output.ty, ArgType::Shape(_)) => {
+            if tensor.rank != 1 {
+                panic!(
+                    "Cast: {} casts a rank {} tensor to a shape, only rank 1 Int tensors are supported",
+                    node.name, tensor.rank
+                );
+            }
+            match tensor.static_shape.as_deref() {
+                Some([length]) => output.ty = ArgType::Shape(*length),
+                _ => panic!(
+                    "Cast: {} casts a tensor of unknown length to a shape, the length must be static",
+                    node.name
+                ),
+            }
+        }
         ArgType::Tensor(tensor) => {
             if tensor.rank == 0 {
                 // treat 0-dim tensor as scalar
@@ -361,34 +552,18 @@ fn cast_update_outputs(node: &mut Node) {
             }
         }
         ArgType::Scalar(_) => output.ty = ArgType::Scalar(elem_type),
-        _ => panic!("Cast: only scalar and tensor inputs are valid"),
+        // A shape becomes a rank 1 tensor, its length is the rank of the shape
+        ArgType::Shape(rank) => {
+            output.ty = ArgType::Tensor(TensorType {
+                elem_type,
+                rank: 1,
+                static_shape: Some(vec![rank]),
+            })
+        }
+        _ => panic!("Cast: only scalar, tensor and shape inputs are valid"),
     }
 }
 
-/// Update output rank for Concat (same as first tensor input).
-fn concat_update_outputs(node: &mut Node) {
-    log::debug!("Concat rank inference for node {}", node.name);
-
-    let tensor = node
-        .inputs
-        .iter()
-        .find_map(|input| match &input.ty {
-            ArgType::Tensor(tensor) => Some(tensor.clone()),
-            _ => None,
-        })
-        .unwrap();
-
-    log::debug!("Concat using input rank for {}: {}", node.name, tensor.rank);
-
-    node.outputs[0].ty = ArgType::Tensor(TensorType {
-        elem_type: tensor.elem_type,
-        rank: tensor.rank,
-        static_shape: None,
-    });
-
-    log::debug!("Concat output rank for {}: {}", node.name, tensor.rank);
-}
-
 /// Update output rank for Reshape based on shape input if constant, otherwise use input rank.
 fn reshape_update_outputs(node: &mut Node) {
     log::debug!("Reshape rank inference for node {}", node.name);
@@ -425,9 +600,18 @@ fn reshape_update_outputs(node: &mut Node) {
         _ => panic!("Reshape: invalid output types"),
     };
 
-    let rank = match &shape {
-        Some(s) => s.len(),
-        None => output.rank,
+    // With a runtime shape, the output rank is the length of the shape when statically known
+    let rank = match (&shape, node.inputs.get(1).map(|input| &input.ty)) {
+        (Some(s), _) => s.len(),
+        (None, Some(ArgType::Shape(rank))) => *rank,
+        (
+            None,
+            Some(ArgType::Tensor(TensorType {
+                static_shape: Some(shape),
+                ..
+            })),
+        ) if shape.len() == 1 => shape[0],
+        (None, _) => output.rank,
     };
 
     log::debug!("Reshape output rank for node {}: {}", node.name, rank);
@@ -515,6 +699,16 @@ fn squeeze_update_output(node: &mut Node) {
 
     let input_rank = match &node.inputs[0].ty {
         ArgType::Tensor(tensor) => tensor.rank,
+        ArgType::Shape(rank) => {
+            // A shape is a 1D array of dims, only a single dim can be squeezed into a scalar
+            if *rank != 1 || !matches!(axes.as_slice(), [0] | [-1]) {
+                panic!(
+                    "Squeeze: only a shape with a single dim can be squeezed on axis 0, got rank {rank} and axes {axes:?}"
+                );
+            }
+            node.outputs[0].ty = ArgType::Scalar(ElementType::Int64);
+            return;
+        }
         ty => panic!("Squeeze: invalid input type: {:?}", ty),
     };
 
@@ -530,6 +724,30 @@ fn squeeze_update_output(node: &mut Node) {
     });
 }
 
+/// Update output type for arithmetic operations (Add, Sub, Mul, Div).
+///
+/// Arithmetic on a shape, with a scalar or another shape, stays in the shape domain.
+fn arithmetic_update_outputs(node: &mut Node) {
+    let shape_rank = node.inputs.iter().find_map(|input| match &input.ty {
+        ArgType::Shape(rank) => Some(*rank),
+        _ => None,
+    });
+
+    match shape_rank {
+        Some(rank) => {
+            if node.inputs.iter().any(|input| input.ty.is_tensor()) {
+                panic!(
+                    "{}: arithmetic between a shape and a tensor is not supported",
+                    node.name
+                );
+            }
+            log::debug!("Shape result for node {} with rank {}", node.name, rank);
+            node.outputs[0].ty = ArgType::Shape(rank);
+        }
+        None => same_as_input_broadcast(node),
+    }
+}
+
 /// Update output rank for broadcasting operations (e.g., Add, Sub) to max input rank.
 fn same_as_input_broadcast(node: &mut Node) {
     log::debug!("Broadcasting operation for node {}", node.name);
@@ -855,6 +1073,23 @@ fn conv2d_update_outputs(node: &mut Node) {
     }
 }
 
+/// Update output type for ConvInteger, an int32 accumulator with the same rank as the input.
+fn conv_integer_update_outputs(node: &mut Node) {
+    log::debug!("ConvInteger rank inference for node {}", node.name);
+
+    if let ArgType::Tensor(tensor) = &node.inputs[0].ty {
+        node.outputs[0].ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Int32,
+            rank: tensor.rank,
+            static_shape: None,
+        });
+
+        log::debug!("ConvInteger output rank for {}: {}", node.name, tensor.rank);
+    } else {
+        panic!("Only tensor input is valid");
+    }
+}
+
 /// Update output rank for ConvTranspose1d (same as input).
 fn conv_transpose1d_update_outputs(node: &mut Node) {
     log::debug!("ConvTranspose1d rank inference for node {}", node.name);
@@ -977,16 +1212,7 @@ fn reduce_max_update_outputs(node: &mut Node) {
     };
     log::debug!("ReduceMax input rank for {}: {}", node.name, tensor.rank);
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
-
-    let output_rank = if dim_only { tensor.rank } else { 1 };
+    let output_rank = reduce_dims_output_rank(node, tensor.rank);
     log::debug!("ReduceMax output rank for {}: {}", node.name, output_rank);
 
     node.outputs[0].ty = ArgType::Tensor(TensorType {
@@ -1009,16 +1235,7 @@ fn reduce_min_update_outputs(node: &mut Node) {
     };
     log::debug!("ReduceMin input rank for {}: {}", node.name, tensor.rank);
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
-
-    let output_rank = if dim_only { tensor.rank } else { 1 };
+    let output_rank = reduce_dims_output_rank(node, tensor.rank);
     log::debug!("ReduceMin output rank for {}: {}", node.name, output_rank);
 
     node.outputs[0].ty = ArgType::Tensor(TensorType {
@@ -1028,6 +1245,37 @@ fn reduce_min_update_outputs(node: &mut Node) {
     });
 }
 
+/// Output rank of a reduction over the `axes` attribute, all the axes being reduced by default.
+///
+/// The reduced dims are removed when `keepdims` is 0, a rank 1 tensor is returned when no dim is
+/// left.
+fn reduce_dims_output_rank(node: &Node, rank: usize) -> usize {
+    let mut axes: Vec<i64> = match node.attrs.get("axes") {
+        Some(AttributeValue::Int64(axis)) => vec![*axis],
+        Some(AttributeValue::Int64s(axes)) => axes.clone(),
+        _ => Vec::new(),
+    };
+    let keepdims = match node.attrs.get("keepdims") {
+        Some(AttributeValue::Int64(keepdims)) => *keepdims == 1,
+        _ => true,
+    };
+
+    if keepdims {
+        return rank;
+    }
+
+    axes.iter_mut()
+        .filter(|axis| **axis < 0)
+        .for_each(|axis| *axis += rank as i64);
+    axes.sort_unstable();
+    axes.dedup();
+
+    match axes.len() {
+        0 => 1,
+        reduced => rank.saturating_sub(reduced).max(1),
+    }
+}
+
 /// Update output rank for ReduceProd based on axes.
 fn reduce_prod_update_outputs(node: &mut Node) {
     log::debug!("ReduceProd rank inference for node {}", node.name);
@@ -1041,16 +1289,7 @@ fn reduce_prod_update_outputs(node: &mut Node) {
     };
     log::debug!("ReduceProd input rank for {}: {}", node.name, tensor.rank);
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
-
-    let output_rank = if dim_only { tensor.rank } else { 1 };
+    let output_rank = reduce_dims_output_rank(node, tensor.rank);
     log::debug!("ReduceProd output rank for {}: {}", node.name, output_rank);
 
     node.outputs[0].ty = ArgType::Tensor(TensorType {
@@ -0,0 +1,244 @@
+use core::fmt;
+use std::collections::HashMap;
+
+use crate::ir::{ArgType, Node, NodeType, OnnxGraph};
+
+/// A node input whose rank is inconsistent with its producer or with its operator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RankMismatch {
+    /// The rank declared for a node input differs from the rank of the output producing it.
+    Producer {
+        /// The name of the node.
+        node: String,
+        /// The name of the input.
+        input: String,
+        /// The name of the node producing the input.
+        producer: String,
+        /// The rank declared by the node for the input.
+        declared: usize,
+        /// The rank of the producer output.
+        produced: usize,
+    },
+    /// The rank of a node input differs from the rank required by the operator.
+    Operator {
+        /// The name of the node.
+        node: String,
+        /// The type of the node.
+        node_type: NodeType,
+        /// The name of the input.
+        input: String,
+        /// The name of the node producing the input, or `None` for a graph input.
+        producer: Option<String>,
+        /// The rank required by the operator.
+        expected: usize,
+        /// The rank of the input.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for RankMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RankMismatch::Producer {
+                node,
+                input,
+                producer,
+                declared,
+                produced,
+            } => write!(
+                f,
+                "node `{node}` declares input `{input}` with rank {declared}, \
+                but its producer `{producer}` outputs rank {produced}"
+            ),
+            RankMismatch::Operator {
+                node,
+                node_type,
+                input,
+                producer,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "node `{node}` ({node_type}) expects input `{input}` to have rank {expected}, \
+                    but it has rank {actual}"
+                )?;
+                match producer {
+                    Some(producer) => write!(f, " (produced by `{producer}`)"),
+                    None => write!(f, " (graph input)"),
+                }
+            }
+        }
+    }
+}
+
+/// Checks that the rank of each node input matches the rank of the output producing it, and the
+/// rank required by operators working on a fixed number of dimensions (e.g. `Conv2d` inputs must
+/// be rank 4).
+///
+/// This catches rank inference errors (e.g. a `Reshape` to the wrong rank feeding a convolution)
+/// before code generation, where they would otherwise surface as type mismatches.
+///
+/// # Returns
+///
+/// All the mismatches found, in node order.
+pub fn validate_ranks(graph: &OnnxGraph) -> Result<(), Vec<RankMismatch>> {
+    let producers: HashMap<&str, (&str, usize)> = graph
+        .nodes
+        .iter()
+        .flat_map(|node| {
+            node.outputs
+                .iter()
+                .map(move |output| (output.name.as_str(), (node.name.as_str(), output.ty.rank())))
+        })
+        .collect();
+
+    let mut mismatches = Vec::new();
+
+    for node in graph.nodes.iter() {
+        for input in node.inputs.iter() {
+            if let Some((producer, produced)) = producers.get(input.name.as_str()) {
+                if input.ty.rank() != *produced {
+                    mismatches.push(RankMismatch::Producer {
+                        node: node.name.clone(),
+                        input: input.name.clone(),
+                        producer: producer.to_string(),
+                        declared: input.ty.rank(),
+                        produced: *produced,
+                    });
+                }
+            }
+        }
+
+        if let (Some(expected), Some(input)) = (expected_input_rank(node), node.inputs.first()) {
+            if let ArgType::Tensor(tensor) = &input.ty {
+                if tensor.rank != expected {
+                    mismatches.push(RankMismatch::Operator {
+                        node: node.name.clone(),
+                        node_type: node.node_type.clone(),
+                        input: input.name.clone(),
+                        producer: producers
+                            .get(input.name.as_str())
+                            .map(|(producer, _)| producer.to_string()),
+                        expected,
+                        actual: tensor.rank,
+                    });
+                }
+            }
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches)
+    }
+}
+
+/// The rank required for the first input of operators working on a fixed number of dimensions.
+fn expected_input_rank(node: &Node) -> Option<usize> {
+    match node.node_type {
+        NodeType::Conv1d
+        | NodeType::ConvTranspose1d
+        | NodeType::MaxPool1d
+        | NodeType::AveragePool1d => Some(3),
+        NodeType::Conv2d
+        | NodeType::ConvTranspose2d
+        | NodeType::MaxPool2d
+        | NodeType::AveragePool2d => Some(4),
+        NodeType::Conv3d | NodeType::ConvTranspose3d => Some(5),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{Argument, ElementType, TensorType};
+
+    fn tensor_arg(name: &str, rank: usize) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorType {
+                elem_type: ElementType::Float32,
+                rank,
+                static_shape: None,
+            }),
+            value: None,
+            passed: true,
+        }
+    }
+
+    fn node(node_type: NodeType, name: &str, inputs: Vec<Argument>, output: Argument) -> Node {
+        Node {
+            node_type,
+            name: name.to_string(),
+            inputs,
+            outputs: vec![output],
+            attrs: Default::default(),
+        }
+    }
+
+    /// Reshape -> Conv2d, where the reshape output has the given rank.
+    fn reshape_conv_graph(reshape_rank: usize) -> OnnxGraph {
+        OnnxGraph {
+            nodes: vec![
+                node(
+                    NodeType::Reshape,
+                    "reshape1",
+                    vec![tensor_arg("input", 2)],
+                    tensor_arg("reshape1_out1", reshape_rank),
+                ),
+                node(
+                    NodeType::Conv2d,
+                    "conv2d1",
+                    vec![tensor_arg("reshape1_out1", reshape_rank)],
+                    tensor_arg("conv2d1_out1", reshape_rank),
+                ),
+            ],
+            inputs: vec![tensor_arg("input", 2)],
+            outputs: vec![tensor_arg("conv2d1_out1", reshape_rank)],
+        }
+    }
+
+    #[test]
+    fn valid_ranks() {
+        assert_eq!(validate_ranks(&reshape_conv_graph(4)), Ok(()));
+    }
+
+    #[test]
+    fn operator_rank_mismatch() {
+        let mismatches = validate_ranks(&reshape_conv_graph(3)).unwrap_err();
+
+        assert_eq!(
+            mismatches,
+            vec![RankMismatch::Operator {
+                node: "conv2d1".to_string(),
+                node_type: NodeType::Conv2d,
+                input: "reshape1_out1".to_string(),
+                producer: Some("reshape1".to_string()),
+                expected: 4,
+                actual: 3,
+            }]
+        );
+        assert_eq!(
+            mismatches[0].to_string(),
+            "node `conv2d1` (Conv2d) expects input `reshape1_out1` to have rank 4, \
+            but it has rank 3 (produced by `reshape1`)"
+        );
+    }
+
+    #[test]
+    fn producer_rank_mismatch() {
+        let mut graph = reshape_conv_graph(4);
+        graph.nodes[0].outputs[0] = tensor_arg("reshape1_out1", 3);
+
+        let mismatches = validate_ranks(&graph).unwrap_err();
+
+        assert_eq!(
+            mismatches[0].to_string(),
+            "node `conv2d1` declares input `reshape1_out1` with rank 4, \
+            but its producer `reshape1` outputs rank 3"
+        );
+    }
+}
@@ -36,6 +36,10 @@ pub fn shape_config(curr: &Node) -> (usize, usize) {
         end_dim += tensor.rank as i64;
     }
 
+    // Out of range axes are clamped, an empty range returns an empty shape
+    let start_dim = start_dim.clamp(0, tensor.rank as i64);
+    let end_dim = end_dim.clamp(start_dim, tensor.rank as i64);
+
     (start_dim as usize, end_dim as usize)
 }
 
@@ -52,15 +56,22 @@ pub fn shape_config(curr: &Node) -> (usize, usize) {
 ///
 /// # Panics
 ///
-/// * If the domain is not the empty ONNX domain
+/// * If the domain is not the empty ONNX domain, `com.microsoft`, `trt.plugins` or
+///   `ai.onnx.contrib`
 pub fn check_opset_version(opset: &OperatorSetIdProto, min_version: i64) -> bool {
-    // For now, only empty domain (standard ONNX operators) is supported
-    if !opset.domain.is_empty() {
-        panic!("Only the standard ONNX domain is supported");
+    match opset.domain.as_str() {
+        // Return true if the opset version is greater than or equal to min_version
+        "" => opset.version >= min_version,
+        // ONNX Runtime contrib operators are versioned independently
+        "com.microsoft" => true,
+        // TensorRT plugins are wrapped in `TRT_PluginV2` nodes
+        "trt.plugins" => true,
+        // Custom operators, such as `RepeatInterleave`, aren't versioned
+        "ai.onnx.contrib" => true,
+        _ => panic!(
+            "Only the standard ONNX, com.microsoft, trt.plugins and ai.onnx.contrib domains are supported"
+        ),
     }
-
-    // Return true if the opset version is greater than or equal to min_version
-    opset.version >= min_version
 }
 
 /// Verify that all operator sets in a model are supported.
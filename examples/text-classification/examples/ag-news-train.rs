@@ -1,7 +1,7 @@
 #![recursion_limit = "256"]
 
 use burn::{
-    nn::transformer::TransformerEncoderConfig,
+    nn::transformer::{NormPlacement, TransformerEncoderConfig},
     optim::{AdamConfig, decay::WeightDecayConfig},
     tensor::backend::AutodiffBackend,
 };
@@ -19,7 +19,7 @@ type ElemType = burn::tensor::flex32;
 pub fn launch<B: AutodiffBackend>(devices: Vec<B::Device>) {
     let config = ExperimentConfig::new(
         TransformerEncoderConfig::new(256, 1024, 8, 4)
-            .with_norm_first(true)
+            .with_norm_placement(NormPlacement::Pre)
             .with_quiet_softmax(true),
         AdamConfig::new().with_weight_decay(Some(WeightDecayConfig::new(5e-5))),
     );
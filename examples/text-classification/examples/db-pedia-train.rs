@@ -1,5 +1,5 @@
 use burn::{
-    nn::transformer::TransformerEncoderConfig,
+    nn::transformer::{NormPlacement, TransformerEncoderConfig},
     optim::{AdamConfig, decay::WeightDecayConfig},
     tensor::backend::AutodiffBackend,
 };
@@ -14,7 +14,7 @@ type ElemType = burn::tensor::f16;
 
 pub fn launch<B: AutodiffBackend>(devices: Vec<B::Device>) {
     let config = ExperimentConfig::new(
-        TransformerEncoderConfig::new(256, 1024, 8, 4).with_norm_first(true),
+        TransformerEncoderConfig::new(256, 1024, 8, 4).with_norm_placement(NormPlacement::Pre),
         AdamConfig::new().with_weight_decay(Some(WeightDecayConfig::new(5e-5))),
     );
 